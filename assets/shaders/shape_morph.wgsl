@@ -5,6 +5,7 @@ struct ShapeMorphMaterial {
     shape_to: u32,
     morph_t: f32,
     rotation: f32,
+    fill: f32,  // Fraction of the shape shown, swept clockwise from the top (1.0 = all)
     color: vec4<f32>,
 }
 
@@ -163,6 +164,9 @@ fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
     // Scale up to make shapes fill the space better (UVs are -0.5 to 0.5, we want shapes to fill)
     uv = uv * 2.0;  // Now uv ranges from -1.0 to 1.0
     
+    // Determinate sweep: 0 at the top, increasing clockwise (UV y points down)
+    let sweep = fract(atan2(uv.x, -uv.y) / (2.0 * PI) + 1.0);
+    
     // Apply rotation
     uv = rotate2d(uv, material.rotation);
     
@@ -175,7 +179,10 @@ fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
     
     // Anti-aliasing using fwidth
     let edge_distance = fwidth(sdf) * 0.5;
-    let alpha = 1.0 - smoothstep(-edge_distance, edge_distance, sdf);
+    var alpha = 1.0 - smoothstep(-edge_distance, edge_distance, sdf);
+    if material.fill < 1.0 && sweep > material.fill {
+        alpha = 0.0;
+    }
     
     // Apply alpha to color
     var color = material.color;
@@ -10,7 +10,7 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
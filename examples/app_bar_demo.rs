@@ -1,20 +1,29 @@
 //! App Bar Demo
 //!
 //! Demonstrates Material Design 3 top and bottom app bars.
+//! Narrow the window to see top app bar actions collapse into the overflow menu.
 
 use bevy::prelude::*;
-use bevy_material_ui::app_bar::spawn_top_app_bar_with_right_content;
+use bevy_material_ui::app_bar::{spawn_bottom_app_bar, spawn_top_app_bar_with_right_content};
 use bevy_material_ui::prelude::*;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
+        .add_systems(Update, log_app_bar_actions)
         .run();
 }
 
+/// Actions picked from the overflow menu arrive as the same event as visible ones.
+fn log_app_bar_actions(mut actions: MessageReader<AppBarActionEvent>) {
+    for ev in actions.read() {
+        info!("App bar action: {}", ev.action);
+    }
+}
+
 fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<TelemetryConfig>) {
     commands.spawn(Camera2d);
 
@@ -39,8 +48,10 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
         TopAppBarBuilder::new("Page Title")
             .small()
             .with_navigation("menu")
-            .add_action("search", "search")
-            .add_action("more_vert", "more"),
+            .action(AppBarAction::new("search", "search").with_label("Search"))
+            .action(AppBarAction::new("share", "share").with_label("Share"))
+            .action(AppBarAction::new("favorite", "favorite").with_label("Favorite"))
+            .action(AppBarAction::new("settings", "settings").with_label("Settings")),
         |_right| {},
     );
 
@@ -88,17 +99,23 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
     commands.entity(root_id).add_child(body);
 
     // Bottom app bar (absolute positioned at bottom)
-    let bottom = commands
-        .spawn(
-            BottomAppBarBuilder::new()
-                .add_action("home", "home")
-                .add_action("favorite", "favorite")
-                .with_fab("add")
-                .elevated()
-                .build(&theme),
-        )
-        .insert_test_id("app_bar_demo/bottom", &telemetry)
-        .id();
+    let bottom = spawn_bottom_app_bar(
+        &mut commands,
+        &theme,
+        BottomAppBarBuilder::new()
+            .add_action("home", "home")
+            .add_action("favorite", "favorite")
+            .with_fab(FabBuilder::new("add"))
+            .elevated(),
+    );
+    commands
+        .entity(bottom.app_bar)
+        .insert_test_id("app_bar_demo/bottom", &telemetry);
+    if let Some(fab) = bottom.fab {
+        commands
+            .entity(fab)
+            .insert_test_id("app_bar_demo/bottom/fab", &telemetry);
+    }
 
-    commands.entity(root_id).add_child(bottom);
+    commands.entity(root_id).add_child(bottom.app_bar);
 }
@@ -1,14 +1,15 @@
 //! Badge Demo
 //!
-//! Demonstrates Material badges (dot, count, text).
+//! Demonstrates Material badges (dot, count, text) and a badge attached to an icon button.
 
 use bevy::prelude::*;
+use bevy_material_ui::icons::ICON_NOTIFICATIONS;
 use bevy_material_ui::prelude::*;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .run();
@@ -17,6 +18,8 @@ fn main() {
 fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<TelemetryConfig>) {
     commands.spawn(Camera2d);
 
+    let mut notifications_button = Entity::PLACEHOLDER;
+
     commands
         .spawn((
             Node {
@@ -109,5 +112,27 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
 
                 container.spawn_badge_text(&theme, "NEW");
             });
+
+            // Icon button with an attached count badge
+            notifications_button = root
+                .spawn(
+                    IconButtonBuilder::new(ICON_NOTIFICATIONS)
+                        .standard()
+                        .build(&theme),
+                )
+                .insert_test_id("badge_demo/container/attached", &telemetry)
+                .with_children(|button| {
+                    button.spawn(
+                        MaterialIcon::from_name(ICON_NOTIFICATIONS)
+                            .expect("embedded icon 'notifications' not found")
+                            .with_color(theme.on_surface_variant),
+                    );
+                })
+                .id();
         });
+
+    // Pinned to the button's top-right corner, following it if the button resizes.
+    BadgeBuilder::count(12)
+        .attach_to(notifications_button)
+        .spawn(&mut commands, &theme);
 }
@@ -8,7 +8,7 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .add_systems(Update, (log_chip_events_system,))
@@ -14,7 +14,7 @@ struct ResultText(Entity);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .add_systems(Update, date_picker_demo_system)
@@ -23,7 +23,7 @@ struct DialogEntities {
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .add_systems(Update, (open_dialog_system, close_dialog_system))
@@ -75,13 +75,10 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
         });
 
     let dialog_entity = commands
-        .spawn((
-            GlobalZIndex(1001),
-            DialogBuilder::new()
-                .title("Confirm Action")
-                .modal(true)
-                .build(&theme),
-        ))
+        .spawn((DialogBuilder::new()
+            .title("Confirm Action")
+            .modal(true)
+            .build(&theme),))
         .insert_test_id("dialog_demo/dialog", &telemetry)
         .with_children(|dialog| {
             dialog.spawn((
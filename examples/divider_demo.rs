@@ -1,6 +1,6 @@
 //! Divider Demo
 //!
-//! Demonstrates horizontal, inset, and vertical dividers.
+//! Demonstrates horizontal, inset, labeled, and vertical dividers.
 
 use bevy::prelude::*;
 use bevy_material_ui::prelude::*;
@@ -8,7 +8,7 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .run();
@@ -69,6 +69,8 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
                         },
                         TextColor(theme.on_surface),
                     ));
+
+                    col.spawn_divider_with(&theme, DividerBuilder::new().with_label("OR"));
                 });
 
             root.spawn((Node {
@@ -1,6 +1,6 @@
 //! Icon Button Demo
 //!
-//! Demonstrates standard/filled/tonal/outlined icon buttons.
+//! Demonstrates standard/filled/tonal/outlined and toggle icon buttons.
 
 use bevy::prelude::*;
 use bevy_material_ui::icons::{ICON_FAVORITE, ICON_SEARCH, ICON_SETTINGS};
@@ -9,7 +9,7 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .run();
@@ -47,5 +47,11 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
                 root.spawn(builder.build(&theme))
                     .insert_test_id(format!("icon_button_demo/button/{id}"), &telemetry);
             }
+
+            // Toggle: swaps between the outlined and filled heart on click.
+            root.spawn_icon_button_with(
+                &theme,
+                MaterialIconButton::new("favorite_border").with_selected_icon(ICON_FAVORITE),
+            );
         });
 }
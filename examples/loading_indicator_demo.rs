@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_material_ui::{
-    loading_indicator::{LoadingIndicatorBuilder, ShapeMorphMaterial, SpawnLoadingIndicatorChild},
+    loading_indicator::{
+        LoadingIndicatorBuilder, LoadingShape, ShapeMorphMaterial, SpawnLoadingIndicatorChild,
+    },
     telemetry::TelemetryPlugin,
     theme::MaterialTheme,
     MaterialUiPlugin,
@@ -8,7 +10,7 @@ use bevy_material_ui::{
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, MaterialUiPlugin, TelemetryPlugin))
+        .add_plugins((DefaultPlugins, MaterialUiPlugin::default(), TelemetryPlugin))
         .add_systems(Startup, setup)
         .run();
 }
@@ -140,6 +142,32 @@ fn setup(
                                 LoadingIndicatorBuilder::new().speed(2.0),
                             );
                         });
+
+                    // Custom shape sequence
+                    parent
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(16.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("Soft shapes"),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(theme.on_surface_variant),
+                            ));
+                            parent.spawn_loading_indicator_with(
+                                &theme,
+                                &mut materials,
+                                LoadingIndicatorBuilder::new()
+                                    .shapes(LoadingShape::MD3_SOFT_SEQUENCE)
+                                    .speed(0.75),
+                            );
+                        });
                 });
         });
 }
@@ -118,7 +118,7 @@ fn main() {
             state.mode,
         ))
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, attach_seed_channels_to_sliders_system)
         .add_systems(Update, attach_seed_hex_field_system)
@@ -11,7 +11,7 @@ struct RadioDemoRows(Vec<(Entity, String)>);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .insert_resource(RadioDemoRows::default())
         .add_systems(Startup, setup)
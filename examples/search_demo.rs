@@ -1,6 +1,6 @@
 //! Search Demo
 //!
-//! Demonstrates the Material search bar.
+//! Demonstrates the Material search bar and its suggestions dropdown.
 
 use bevy::prelude::*;
 use bevy_material_ui::icons::{ICON_ARROW_BACK, ICON_MENU};
@@ -9,7 +9,7 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
         .add_systems(Update, (log_search_events_system,))
@@ -73,12 +73,29 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
 fn log_search_events_system(
     mut clicks: MessageReader<SearchBarClickEvent>,
     mut queries: MessageReader<SearchQueryEvent>,
+    mut selections: MessageReader<SearchSuggestionSelected>,
+    mut suggestions: MessageWriter<UpdateSearchSuggestions>,
 ) {
     for ev in clicks.read() {
         info!("Search bar clicked: {:?}", ev.search_bar);
+
+        suggestions.write(UpdateSearchSuggestions {
+            bar: ev.search_bar,
+            items: vec![
+                SearchSuggestion::new("Material Design").leading_icon("history"),
+                SearchSuggestion::new("Material components").leading_icon("history"),
+                SearchSuggestion::new("Bevy UI")
+                    .supporting_text("Game engine UI")
+                    .leading_icon("search"),
+            ],
+        });
     }
 
     for ev in queries.read() {
         info!("Search query: '{}'", ev.query);
     }
+
+    for ev in selections.read() {
+        info!("Suggestion {} selected on {:?}", ev.index, ev.bar);
+    }
 }
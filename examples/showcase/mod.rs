@@ -19,7 +19,7 @@ struct SpinningDice;
 pub fn run() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .init_resource::<SelectedSection>()
         .init_resource::<ComponentTelemetry>()
         .add_systems(Startup, (setup_3d_scene, setup_ui, setup_telemetry))
@@ -141,7 +141,7 @@ pub fn run() {
             ..default()
         }))
         .add_plugins(FrameTimeDiagnosticsPlugin::default())
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .init_resource::<ShowcaseThemeSelection>()
         // Default seed theme (Material You purple)
         .insert_resource(MaterialTheme::from_seed(
@@ -1706,7 +1706,7 @@ fn debug_lists_visibility_system(
 
     let Some(list_entity) = list_entity else {
         *attempts += 1;
-        if *attempts == 1 || (*attempts % 30 == 0) {
+        if *attempts == 1 || attempts.is_multiple_of(30) {
             bevy::log::warn!(
                 "[lists debug] List not found yet (attempt {}). UI may not be spawned this frame.",
                 *attempts
@@ -2898,7 +2898,7 @@ fn tooltip_demo_apply_system(
 
     for mut trigger in triggers.iter_mut() {
         trigger.position = options.position;
-        trigger.delay = options.delay;
+        trigger.delay = Some(options.delay);
 
         // If a tooltip is currently visible, update its position immediately.
         if let Some(tooltip_entity) = trigger.tooltip_entity {
@@ -153,7 +153,6 @@ pub fn spawn_dialogs_section(parent: &mut ChildSpawnerCommands, theme: &Material
             let dialog_entity = section
                 .spawn((
                     DialogContainer,
-                    GlobalZIndex(1001),
                     DialogBuilder::new()
                         .title("Confirm Action")
                         .modal(true)
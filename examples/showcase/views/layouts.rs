@@ -178,7 +178,11 @@ fn spawn_adaptive_card(
     size_class: WindowSizeClass,
     test_prefix: &str,
 ) {
-    let config = layout::NavigationSuiteScaffold::default();
+    // Each card previews a fixed size class, so it must not follow the real window.
+    let config = layout::NavigationSuiteScaffold {
+        follow_window_size_class: false,
+        ..default()
+    };
     parent
         .spawn(Node {
             width: Val::Px(320.0),
@@ -68,7 +68,7 @@ pub fn spawn_list_section(
                         row,
                         &theme_clone,
                         "Multi",
-                        ListSelectionMode::Multi,
+                        ListSelectionMode::Multiple,
                         false,
                     );
                 });
@@ -376,7 +376,7 @@ pub fn spawn_list_section(
 commands.spawn((
     ListBuilder::new()
         .max_visible_items_variant(4, ListItemVariant::TwoLine)
-        .selection_mode(ListSelectionMode::Multi)  // or Single
+        .selection_mode(ListSelectionMode::Multiple)  // or Single
         .build_scrollable(),
     BackgroundColor(theme.surface_container_low),
 )).with_children(|list| {
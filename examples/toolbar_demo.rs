@@ -1,17 +1,21 @@
 //! Toolbar Demo
 //!
-//! Demonstrates the Material toolbar with navigation and actions.
+//! Demonstrates the Material toolbar with navigation and actions, including a
+//! narrow toolbar whose extra actions collapse into the overflow menu.
 
 use bevy::prelude::*;
-use bevy_material_ui::icons::{ICON_MENU, ICON_SEARCH};
+use bevy_material_ui::icons::{
+    ICON_DELETE, ICON_EDIT, ICON_FAVORITE, ICON_MENU, ICON_SEARCH, ICON_SETTINGS,
+};
 use bevy_material_ui::prelude::*;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
+        .add_systems(Update, log_toolbar_actions)
         .run();
 }
 
@@ -41,6 +45,28 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
                     .action_name(ICON_SEARCH, "search"),
             );
 
+            // Narrow toolbar: actions that don't fit move into the overflow menu.
+            root.spawn(Node {
+                width: Val::Px(320.0),
+                ..default()
+            })
+            .with_children(|narrow| {
+                narrow.spawn_toolbar_with(
+                    &theme,
+                    ToolbarBuilder::new("Photos")
+                        .navigation_icon_name(ICON_MENU)
+                        .action(ToolbarAction::new(ICON_SEARCH, "search").with_label("Search"))
+                        .action(
+                            ToolbarAction::new(ICON_FAVORITE, "favorite").with_label("Favorite"),
+                        )
+                        .action(ToolbarAction::new(ICON_EDIT, "edit").with_label("Edit"))
+                        .action(ToolbarAction::new(ICON_DELETE, "delete").with_label("Delete"))
+                        .action(
+                            ToolbarAction::new(ICON_SETTINGS, "settings").with_label("Settings"),
+                        ),
+                );
+            });
+
             // Simple body
             root.spawn((
                 Text::new("Toolbar (nav + action)"),
@@ -52,3 +78,9 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
             ));
         });
 }
+
+fn log_toolbar_actions(mut actions: MessageReader<ToolbarActionEvent>) {
+    for ev in actions.read() {
+        info!("Toolbar action: {}", ev.action);
+    }
+}
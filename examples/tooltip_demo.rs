@@ -8,12 +8,19 @@ use bevy_material_ui::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(MaterialUiPlugin)
+        .add_plugins(MaterialUiPlugin::default())
         .add_plugins(TelemetryPlugin)
         .add_systems(Startup, setup)
+        .add_systems(Update, log_rich_tooltip_actions)
         .run();
 }
 
+fn log_rich_tooltip_actions(mut events: MessageReader<RichTooltipActionEvent>) {
+    for event in events.read() {
+        info!("Rich tooltip action: {}", event.action_id);
+    }
+}
+
 fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<TelemetryConfig>) {
     commands.spawn(Camera2d);
 
@@ -54,6 +61,34 @@ fn setup(mut commands: Commands, theme: Res<MaterialTheme>, telemetry: Res<Telem
                 ));
             });
 
+            // Rich tooltip with actions: stays open so its buttons can be clicked.
+            let rich_label = "Rich Tooltip";
+            root.spawn((
+                Interaction::None,
+                TooltipTrigger::new("Rich tooltip")
+                    .rich()
+                    .with_position(TooltipPosition::Bottom),
+                RichTooltip::new("Rich tooltips can hold a title, supporting text and actions.")
+                    .with_title("Rich tooltip")
+                    .with_action_id("learn_more", "Learn more")
+                    .with_dismiss_action("Dismiss"),
+                MaterialButtonBuilder::new(rich_label)
+                    .outlined()
+                    .build(&theme),
+            ))
+            .insert_test_id("tooltip_demo/rich_button", &telemetry)
+            .with_children(|b| {
+                b.spawn((
+                    ButtonLabel,
+                    Text::new(rich_label),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(theme.primary),
+                ));
+            });
+
             // Spawn a simple icon-like node with a tooltip attached via helper.
             let help_box = root
                 .spawn((
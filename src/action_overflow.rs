@@ -0,0 +1,352 @@
+//! Overflow menus for toolbar and top app bar actions
+//!
+//! When a widget is too narrow for all of its action buttons, the trailing
+//! actions move into a menu behind a "more" button. Each widget measures the
+//! width left for its actions and stores the result in its
+//! [`ActionOverflow`]; the systems here show or hide the action buttons and
+//! menu items to match, open and close the menu, and forward picked items as
+//! the widget's own action event.
+
+use bevy::prelude::*;
+
+use crate::{
+    icons::{IconStyle, MaterialIcon, ICON_MORE_VERT},
+    menu::{MaterialMenu, MenuAnchor, MenuBuilder, MenuItemBuilder, MenuItemSelectEvent},
+    theme::MaterialTheme,
+    tokens::{Opacity, Spacing},
+    typography::TypeRole,
+};
+
+/// Icon size of the overflow button and menu items
+const OVERFLOW_ICON_SIZE: f32 = 24.0;
+
+/// Plugin that keeps action buttons and overflow menus in sync with [`ActionOverflow`]
+pub struct ActionOverflowPlugin;
+
+impl Plugin for ActionOverflowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                action_overflow_visibility_system,
+                action_overflow_button_system,
+            ),
+        );
+    }
+}
+
+/// Overflow state of a widget's actions, updated from its measured width
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionOverflow {
+    visible: usize,
+    total: usize,
+}
+
+impl ActionOverflow {
+    /// All `total` actions shown directly
+    pub(crate) fn new(total: usize) -> Self {
+        Self {
+            visible: total,
+            total,
+        }
+    }
+
+    /// How many of `total` actions stay visible in `available_width`
+    pub(crate) fn fitting(total: usize, available_width: f32, button_size: f32, gap: f32) -> Self {
+        Self {
+            visible: visible_action_count(available_width, total, button_size, gap),
+            total,
+        }
+    }
+
+    /// Number of actions shown directly in the widget
+    pub fn visible_count(&self) -> usize {
+        self.visible
+    }
+
+    /// Number of actions moved into the overflow menu
+    pub fn overflow_count(&self) -> usize {
+        self.total - self.visible
+    }
+
+    /// Whether the action at `index` is shown in the overflow menu
+    pub fn is_overflowed(&self, index: usize) -> bool {
+        index >= self.visible
+    }
+}
+
+/// A widget whose actions can move into an overflow menu
+pub(crate) trait OverflowOwner: Component {
+    /// Message written when an action is picked from the overflow menu
+    type Event: Message;
+
+    /// The event for the action `id` of the widget `owner`
+    fn action_event(owner: Entity, id: String) -> Self::Event;
+}
+
+/// An action as listed in the overflow menu
+pub(crate) struct OverflowAction<'a> {
+    pub(crate) icon: &'a str,
+    pub(crate) id: &'a str,
+    pub(crate) label: &'a str,
+    pub(crate) disabled: bool,
+}
+
+/// Position of an action button within its widget
+#[derive(Component)]
+pub(crate) struct ActionOverflowSlot {
+    pub(crate) owner: Entity,
+    pub(crate) index: usize,
+}
+
+#[derive(Component)]
+pub(crate) struct ActionOverflowButton {
+    owner: Entity,
+    menu: Entity,
+}
+
+#[derive(Component)]
+pub(crate) struct ActionOverflowItem {
+    owner: Entity,
+    index: usize,
+    id: String,
+}
+
+/// Number of actions that stay visible given the width available to them.
+///
+/// When not every action fits, one slot is given up for the overflow button.
+pub(crate) fn visible_action_count(
+    available_width: f32,
+    action_count: usize,
+    button_size: f32,
+    gap: f32,
+) -> usize {
+    let slot = button_size + gap;
+    let fit = ((available_width + gap) / slot).floor().max(0.0) as usize;
+    if action_count <= fit {
+        action_count
+    } else {
+        fit.saturating_sub(1)
+    }
+}
+
+/// Spawn the overflow button of `owner` into `row`, after its action buttons.
+///
+/// `button` is the widget's icon button bundle; the button is `size` square
+/// and hidden until an action overflows. Returns the button entity.
+pub(crate) fn spawn_action_overflow<'a>(
+    row: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    owner: Entity,
+    button: impl Bundle,
+    size: f32,
+    actions: impl IntoIterator<Item = OverflowAction<'a>>,
+) -> Entity {
+    let mut overflow = row.spawn(button);
+    overflow.insert(Node {
+        display: Display::None,
+        width: Val::Px(size),
+        height: Val::Px(size),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    });
+
+    let mut menu = Entity::PLACEHOLDER;
+    overflow.with_children(|btn| {
+        if let Some(icon) = MaterialIcon::from_name(ICON_MORE_VERT) {
+            btn.spawn((
+                icon,
+                IconStyle::outlined()
+                    .with_color(theme.on_surface_variant)
+                    .with_size(OVERFLOW_ICON_SIZE),
+            ));
+        }
+
+        let mut menu_entity = btn.spawn(
+            MenuBuilder::new()
+                .anchor(MenuAnchor::BottomRight)
+                .build(theme),
+        );
+        menu_entity.insert(Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            top: Val::Percent(100.0),
+            right: Val::Px(0.0),
+            min_width: Val::Px(crate::menu::MENU_MIN_WIDTH),
+            max_width: Val::Px(crate::menu::MENU_MAX_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::vertical(Val::Px(Spacing::SMALL)),
+            ..default()
+        });
+        menu_entity.with_children(|menu| {
+            for (index, action) in actions.into_iter().enumerate() {
+                let (text_color, icon_color) = if action.disabled {
+                    let disabled = theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
+                    (disabled, disabled)
+                } else {
+                    (theme.on_surface, theme.on_surface_variant)
+                };
+
+                menu.spawn((
+                    ActionOverflowItem {
+                        owner,
+                        index,
+                        id: action.id.to_string(),
+                    },
+                    MenuItemBuilder::new(action.label)
+                        .leading_icon(action.icon)
+                        .disabled(action.disabled)
+                        .build(theme),
+                ))
+                .with_children(|item| {
+                    if let Some(icon) = MaterialIcon::from_name(action.icon) {
+                        item.spawn((
+                            icon,
+                            IconStyle::outlined()
+                                .with_color(icon_color)
+                                .with_size(OVERFLOW_ICON_SIZE),
+                        ));
+                    }
+                    item.spawn((
+                        Text::new(action.label),
+                        TypeRole::LabelLarge.text_bundle(),
+                        TextColor(text_color),
+                    ));
+                });
+            }
+        });
+        menu = menu_entity.id();
+    });
+    overflow.insert(ActionOverflowButton { owner, menu });
+    overflow.id()
+}
+
+/// Shows the actions that fit and lists the rest in the overflow menu
+fn action_overflow_visibility_system(
+    owners: Query<&ActionOverflow>,
+    changed: Query<(), Changed<ActionOverflow>>,
+    mut nodes: Query<
+        (
+            &mut Node,
+            Option<&ActionOverflowSlot>,
+            Option<&ActionOverflowItem>,
+            Option<&ActionOverflowButton>,
+        ),
+        Or<(
+            With<ActionOverflowSlot>,
+            With<ActionOverflowItem>,
+            With<ActionOverflowButton>,
+        )>,
+    >,
+    mut menus: Query<&mut MaterialMenu>,
+) {
+    if changed.is_empty() {
+        return;
+    }
+
+    for (mut node, slot, item, button) in nodes.iter_mut() {
+        let show = if let Some(slot) = slot {
+            owners
+                .get(slot.owner)
+                .is_ok_and(|overflow| !overflow.is_overflowed(slot.index))
+        } else if let Some(item) = item {
+            owners
+                .get(item.owner)
+                .is_ok_and(|overflow| overflow.is_overflowed(item.index))
+        } else if let Some(button) = button {
+            let show = owners
+                .get(button.owner)
+                .is_ok_and(|overflow| overflow.overflow_count() > 0);
+            if !show {
+                if let Ok(mut menu) = menus.get_mut(button.menu) {
+                    if menu.open {
+                        menu.open = false;
+                    }
+                }
+            }
+            show
+        } else {
+            continue;
+        };
+
+        let display = if show { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// Opens and closes the overflow menu when the overflow button is clicked
+fn action_overflow_button_system(
+    buttons: Query<(&Interaction, &ActionOverflowButton), Changed<Interaction>>,
+    mut menus: Query<&mut MaterialMenu>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut menu) = menus.get_mut(button.menu) {
+            menu.open = !menu.open;
+        }
+    }
+}
+
+/// Forwards overflow menu selections of `O` widgets as their action event and closes the menu
+pub(crate) fn action_overflow_menu_system<O: OverflowOwner>(
+    mut selections: MessageReader<MenuItemSelectEvent>,
+    items: Query<&ActionOverflowItem>,
+    owners: Query<(), With<O>>,
+    mut menus: Query<&mut MaterialMenu>,
+    mut action_events: MessageWriter<O::Event>,
+) {
+    for ev in selections.read() {
+        let Ok(item) = items.get(ev.item_entity) else {
+            continue;
+        };
+        if !owners.contains(item.owner) {
+            continue;
+        }
+
+        action_events.write(O::action_event(item.owner, item.id.clone()));
+        if let Ok(mut menu) = menus.get_mut(ev.menu_entity) {
+            menu.open = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_overflow_counts() {
+        let overflow = ActionOverflow {
+            visible: 2,
+            total: 4,
+        };
+        assert_eq!(overflow.visible_count(), 2);
+        assert_eq!(overflow.overflow_count(), 2);
+        assert!(!overflow.is_overflowed(1));
+        assert!(overflow.is_overflowed(2));
+        assert_eq!(ActionOverflow::new(3).overflow_count(), 0);
+    }
+
+    #[test]
+    fn test_fitting_gives_a_slot_to_the_overflow_button() {
+        let slot = 48.0 + Spacing::SMALL;
+        let width = 3.0 * slot - Spacing::SMALL;
+        assert_eq!(
+            ActionOverflow::fitting(3, width, 48.0, Spacing::SMALL).visible_count(),
+            3
+        );
+        assert_eq!(
+            ActionOverflow::fitting(4, width, 48.0, Spacing::SMALL).visible_count(),
+            2
+        );
+        assert_eq!(
+            ActionOverflow::fitting(2, 0.0, 48.0, Spacing::SMALL).visible_count(),
+            0
+        );
+    }
+}
@@ -152,15 +152,21 @@ pub struct WindowSizeClassPlugin;
 impl Plugin for WindowSizeClassPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<WindowSizeClass>()
+            .add_message::<WindowSizeClassChanged>()
             // Initialize once as early as possible so the first UI layout uses real
             // window dimensions instead of the resource default (0x0).
             .add_systems(Startup, update_window_size_class)
-            .add_systems(Update, update_window_size_class);
+            .add_systems(Update, update_window_size_class)
+            .add_systems(Update, adaptive_grid_system.after(update_window_size_class));
     }
 }
 
 /// System that updates the window size class resource when window resizes
-fn update_window_size_class(windows: Query<&Window>, mut size_class: ResMut<WindowSizeClass>) {
+fn update_window_size_class(
+    windows: Query<&Window>,
+    mut size_class: ResMut<WindowSizeClass>,
+    mut changes: MessageWriter<WindowSizeClassChanged>,
+) {
     let Ok(window) = windows.single() else {
         return;
     };
@@ -175,6 +181,14 @@ fn update_window_size_class(windows: Query<&Window>, mut size_class: ResMut<Wind
         || (size_class.width_px - new_class.width_px).abs() > f32::EPSILON
         || (size_class.height_px - new_class.height_px).abs() > f32::EPSILON
     {
+        if size_class.width != new_class.width || size_class.height != new_class.height {
+            changes.write(WindowSizeClassChanged {
+                old_width: size_class.width,
+                new_width: new_class.width,
+                old_height: size_class.height,
+                new_height: new_class.height,
+            });
+        }
         *size_class = new_class;
     }
 }
@@ -187,3 +201,141 @@ pub struct WindowSizeClassChanged {
     pub old_height: WindowHeightClass,
     pub new_height: WindowHeightClass,
 }
+
+/// Grid container whose column count follows the window width class
+///
+/// Children flow into equal-width columns. Unless overridden with
+/// [`AdaptiveGrid::columns_for`], each class uses
+/// [`WindowSizeClass::content_columns`]; the gap defaults to
+/// [`WindowSizeClass::gutter`].
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_material_ui::prelude::*;
+///
+/// fn setup(mut commands: Commands, size_class: Res<WindowSizeClass>) {
+///     commands
+///         .spawn(
+///             AdaptiveGrid::new()
+///                 .columns_for(WindowWidthClass::Compact, 1)
+///                 .columns_for(WindowWidthClass::Expanded, 4)
+///                 .build(&size_class),
+///         )
+///         .with_children(|grid| {
+///             for i in 0..8 {
+///                 grid.spawn(Text::new(format!("Item {i}")));
+///             }
+///         });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(Node)]
+pub struct AdaptiveGrid {
+    /// Column overrides, indexed by width class
+    columns: [Option<u16>; 5],
+    /// Gap between rows and columns; `None` uses the window's gutter
+    pub gap: Option<f32>,
+}
+
+impl AdaptiveGrid {
+    /// Create a grid using the recommended columns for every width class
+    pub fn new() -> Self {
+        Self {
+            columns: [None; 5],
+            gap: None,
+        }
+    }
+
+    /// Use `columns` columns when the window is in `class`
+    pub fn columns_for(mut self, class: WindowWidthClass, columns: u16) -> Self {
+        self.columns[class as usize] = Some(columns.max(1));
+        self
+    }
+
+    /// Set a fixed gap between rows and columns
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    /// Number of columns for a width class
+    pub fn columns(&self, class: WindowWidthClass) -> u16 {
+        self.columns[class as usize].unwrap_or_else(|| {
+            WindowSizeClass {
+                width: class,
+                ..default()
+            }
+            .content_columns() as u16
+        })
+    }
+
+    /// Lay out `node` as this grid for the given window size class
+    pub fn apply(&self, size_class: &WindowSizeClass, node: &mut Node) {
+        let gap = Val::Px(self.gap.unwrap_or_else(|| size_class.gutter()));
+        node.grid_template_columns =
+            vec![RepeatedGridTrack::fr(self.columns(size_class.width), 1.0)];
+        node.column_gap = gap;
+        node.row_gap = gap;
+    }
+
+    /// Build the grid bundle laid out for the current window size class
+    pub fn build(self, size_class: &WindowSizeClass) -> impl Bundle {
+        let mut node = Node {
+            display: Display::Grid,
+            width: Val::Percent(100.0),
+            ..default()
+        };
+        self.apply(size_class, &mut node);
+        (self, node)
+    }
+}
+
+impl Default for AdaptiveGrid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reflow adaptive grids when they are added or changed, or when the window
+/// width class changes.
+fn adaptive_grid_system(
+    mut changes: MessageReader<WindowSizeClassChanged>,
+    size_class: Res<WindowSizeClass>,
+    mut grids: Query<(Ref<AdaptiveGrid>, &mut Node)>,
+) {
+    let class_changed = changes.read().count() > 0;
+
+    for (grid, mut node) in grids.iter_mut() {
+        if grid.is_added() && node.display == Display::Flex {
+            node.display = Display::Grid;
+        }
+        if class_changed || grid.is_changed() {
+            grid.apply(&size_class, &mut node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_grid_columns() {
+        let grid = AdaptiveGrid::new()
+            .columns_for(WindowWidthClass::Medium, 3)
+            .columns_for(WindowWidthClass::ExtraLarge, 0);
+
+        // (class, expected columns): overrides win, the rest follow the
+        // size class defaults, and zero is clamped to one column.
+        let cases = [
+            (WindowWidthClass::Compact, 1),
+            (WindowWidthClass::Medium, 3),
+            (WindowWidthClass::Expanded, 3),
+            (WindowWidthClass::Large, 4),
+            (WindowWidthClass::ExtraLarge, 1),
+        ];
+        for (class, expected) in cases {
+            assert_eq!(grid.columns(class), expected, "{class:?}");
+        }
+    }
+}
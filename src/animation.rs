@@ -3,8 +3,17 @@
 //! Provides animation utilities for Material Design transitions and transformations.
 //! Reference: <https://m3.material.io/styles/motion/overview>
 
+use bevy::ecs::query::QueryFilter;
+use bevy::picking::Pickable;
 use bevy::prelude::*;
+use bevy::ui::widget::ImageNode;
+use bevy::ui::{ComputedNode, UiGlobalTransform, UiTransform, Val2};
+use std::collections::HashMap;
 
+use crate::motion::{
+    ease, ease_emphasized, ease_emphasized_decelerate, ReducedMotion, SpringConfig,
+};
+use crate::overlay::Z_CONTAINER_TRANSFORM;
 use crate::tokens::{Duration, Easing};
 
 /// Plugin for animation system
@@ -12,7 +21,21 @@ pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (animation_system, spring_animation_system));
+        app.add_message::<ContainerTransformComplete>()
+            .add_message::<AnimationComplete>()
+            .add_message::<TriggerStaggeredReveal>()
+            .add_systems(
+                Update,
+                (
+                    animation_system::<f32>,
+                    animation_system::<Vec2>,
+                    animation_system::<Color>,
+                    spring_animation_system,
+                    property_spring_system,
+                    (container_transform_start_system, container_transform_system).chain(),
+                    staggered_reveal_system,
+                ),
+            );
     }
 }
 
@@ -20,7 +43,127 @@ impl Plugin for AnimationPlugin {
 // Components
 // ============================================================================
 
+/// A value type that [`AnimatedValue`] can tween.
+pub trait Animatable: Clone + Send + Sync + 'static {
+    /// Interpolate between `a` and `b` (`t` is already eased).
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self;
+
+    /// Write the value to the property selected by `binding`, if it applies to this type.
+    fn apply(&self, binding: AnimationBinding, targets: &mut AnimationTargets);
+}
+
+/// Property an [`AnimatedValue`] writes to each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AnimationBinding {
+    /// Not bound; read `current` yourself
+    #[default]
+    None,
+    /// `Node::width` (f32)
+    Width,
+    /// `Node::height` (f32)
+    Height,
+    /// `Node::left` (f32)
+    Left,
+    /// `Node::top` (f32)
+    Top,
+    /// `Node::left`/`Node::top` (Vec2)
+    Position,
+    /// `Node::width`/`Node::height` (Vec2)
+    Size,
+    /// `BackgroundColor` (Color)
+    BackgroundColor,
+    /// `TextColor` (Color)
+    TextColor,
+}
+
+/// How an [`AnimatedValue`] behaves when it reaches its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AnimationRepeat {
+    /// Stop at the target and write [`AnimationComplete`]
+    #[default]
+    Once,
+    /// Jump back to the start and play again
+    Loop,
+    /// Play back towards the start, then forwards again
+    PingPong,
+}
+
+/// Components an [`Animatable`] can write to.
+pub struct AnimationTargets<'a> {
+    pub node: Option<Mut<'a, Node>>,
+    pub background: Option<Mut<'a, BackgroundColor>>,
+    pub text: Option<Mut<'a, TextColor>>,
+}
+
+impl Animatable for f32 {
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        a + (b - a) * t
+    }
+
+    fn apply(&self, binding: AnimationBinding, targets: &mut AnimationTargets) {
+        let Some(node) = targets.node.as_mut() else {
+            return;
+        };
+        match binding {
+            AnimationBinding::Width => node.width = Val::Px(*self),
+            AnimationBinding::Height => node.height = Val::Px(*self),
+            AnimationBinding::Left => node.left = Val::Px(*self),
+            AnimationBinding::Top => node.top = Val::Px(*self),
+            _ => {}
+        }
+    }
+}
+
+impl Animatable for Vec2 {
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        a.lerp(*b, t)
+    }
+
+    fn apply(&self, binding: AnimationBinding, targets: &mut AnimationTargets) {
+        let Some(node) = targets.node.as_mut() else {
+            return;
+        };
+        match binding {
+            AnimationBinding::Position => {
+                node.left = Val::Px(self.x);
+                node.top = Val::Px(self.y);
+            }
+            AnimationBinding::Size => {
+                node.width = Val::Px(self.x);
+                node.height = Val::Px(self.y);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Animatable for Color {
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        // Mix in linear space so fades through transparent don't darken.
+        a.to_linear().mix(&b.to_linear(), t).into()
+    }
+
+    fn apply(&self, binding: AnimationBinding, targets: &mut AnimationTargets) {
+        match binding {
+            AnimationBinding::BackgroundColor => {
+                if let Some(background) = targets.background.as_mut() {
+                    background.0 = *self;
+                }
+            }
+            AnimationBinding::TextColor => {
+                if let Some(text) = targets.text.as_mut() {
+                    text.0 = *self;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Animated value that interpolates over time
+///
+/// Works with any [`Animatable`] (`f32`, `Vec2`, `Color`). Bind it to a property with
+/// [`AnimatedValue::bind`] to have it applied every frame.
 #[derive(Component)]
 pub struct AnimatedValue<T: Clone> {
     pub start: T,
@@ -29,6 +172,10 @@ pub struct AnimatedValue<T: Clone> {
     pub progress: f32,
     pub duration: f32,
     pub easing: Easing,
+    pub binding: AnimationBinding,
+    pub repeat: AnimationRepeat,
+    /// Playing back towards `start` (ping-pong)
+    pub reversed: bool,
 }
 
 impl<T: Clone> AnimatedValue<T> {
@@ -40,6 +187,9 @@ impl<T: Clone> AnimatedValue<T> {
             progress: 0.0,
             duration,
             easing: Easing::Standard,
+            binding: AnimationBinding::None,
+            repeat: AnimationRepeat::Once,
+            reversed: false,
         }
     }
 
@@ -48,11 +198,89 @@ impl<T: Clone> AnimatedValue<T> {
         self
     }
 
+    /// Apply the value to a property every frame
+    pub fn bind(mut self, binding: AnimationBinding) -> Self {
+        self.binding = binding;
+        self
+    }
+
+    pub fn with_repeat(mut self, repeat: AnimationRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Restart from the beginning whenever the target is reached
+    pub fn looping(self) -> Self {
+        self.with_repeat(AnimationRepeat::Loop)
+    }
+
+    /// Alternate between start and target
+    pub fn ping_pong(self) -> Self {
+        self.with_repeat(AnimationRepeat::PingPong)
+    }
+
     pub fn is_complete(&self) -> bool {
-        self.progress >= 1.0
+        self.repeat == AnimationRepeat::Once && self.progress >= 1.0
+    }
+}
+
+impl<T: Animatable> AnimatedValue<T> {
+    /// Advance by `dt` seconds; returns `true` when a one-shot animation just finished.
+    pub fn update(&mut self, dt: f32) -> bool {
+        if self.is_complete() {
+            return false;
+        }
+
+        self.progress = if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.progress + dt / self.duration).min(1.0)
+        };
+        // Land exactly on the endpoint; the easing curve is solved approximately.
+        let t = if self.progress >= 1.0 {
+            1.0
+        } else {
+            ease(self.progress, self.easing)
+        };
+        let t = if self.reversed { 1.0 - t } else { t };
+        self.current = T::interpolate(&self.start, &self.target, t);
+
+        if self.progress < 1.0 {
+            return false;
+        }
+        match self.repeat {
+            AnimationRepeat::Once => true,
+            AnimationRepeat::Loop => {
+                self.progress = 0.0;
+                false
+            }
+            AnimationRepeat::PingPong => {
+                self.progress = 0.0;
+                self.reversed = !self.reversed;
+                false
+            }
+        }
+    }
+
+    /// Jump to the target and hold there, even when repeating; returns `true` when a one-shot
+    /// animation just finished.
+    pub fn finish(&mut self) -> bool {
+        if self.progress >= 1.0 && !self.reversed {
+            return false;
+        }
+        self.progress = 1.0;
+        self.reversed = false;
+        self.current = self.target.clone();
+        self.repeat == AnimationRepeat::Once
     }
 }
 
+/// Written when a one-shot [`AnimatedValue`] reaches its target.
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct AnimationComplete {
+    pub entity: Entity,
+}
+
 /// Spring-based animation for natural motion
 #[derive(Component)]
 pub struct SpringAnimation {
@@ -113,25 +341,63 @@ impl Default for MorphAnimation {
 // Systems
 // ============================================================================
 
-fn animation_system(time: Res<Time>, mut animations: Query<&mut AnimatedValue<f32>>) {
-    for mut anim in animations.iter_mut() {
-        if anim.is_complete() {
+fn animation_system<T: Animatable>(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut animations: Query<(
+        Entity,
+        &mut AnimatedValue<T>,
+        Option<&mut Node>,
+        Option<&mut BackgroundColor>,
+        Option<&mut TextColor>,
+    )>,
+    mut complete: MessageWriter<AnimationComplete>,
+) {
+    let reduced = reduced_motion.is_some_and(|r| r.0);
+    let dt = time.delta_secs();
+
+    for (entity, mut anim, node, background, text) in animations.iter_mut() {
+        if anim.is_complete() || (reduced && anim.progress >= 1.0 && !anim.reversed) {
             continue;
         }
 
-        anim.progress += time.delta_secs() / anim.duration;
-        anim.progress = anim.progress.min(1.0);
+        let finished = if reduced {
+            anim.finish()
+        } else {
+            anim.update(dt)
+        };
+        if finished {
+            complete.write(AnimationComplete { entity });
+        }
 
-        // Apply easing
-        let t = apply_easing(anim.progress, anim.easing);
-        anim.current = anim.start + (anim.target - anim.start) * t;
+        if anim.binding != AnimationBinding::None {
+            let mut targets = AnimationTargets {
+                node,
+                background,
+                text,
+            };
+            anim.current.apply(anim.binding, &mut targets);
+        }
     }
 }
 
-fn spring_animation_system(time: Res<Time>, mut springs: Query<&mut SpringAnimation>) {
+fn spring_animation_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut springs: Query<&mut SpringAnimation>,
+) {
+    let reduced = reduced_motion.is_some_and(|r| r.0);
     let dt = time.delta_secs();
 
     for mut spring in springs.iter_mut() {
+        if reduced {
+            if spring.value != spring.target || spring.velocity != 0.0 {
+                spring.value = spring.target;
+                spring.velocity = 0.0;
+            }
+            continue;
+        }
+
         // Spring physics
         let displacement = spring.value - spring.target;
         let spring_force = -spring.stiffness * displacement;
@@ -148,18 +414,265 @@ fn spring_animation_system(time: Res<Time>, mut springs: Query<&mut SpringAnimat
     }
 }
 
-fn apply_easing(t: f32, easing: Easing) -> f32 {
-    let (_c1, c2, _c3, c4) = easing.control_points();
+// ============================================================================
+// Property Springs
+// ============================================================================
 
-    // Simplified cubic bezier approximation
-    // For production, use a proper cubic bezier solver
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let mt = 1.0 - t;
-    let mt2 = mt * mt;
-    let mt3 = mt2 * mt;
+/// A property driven by [`animate_spring`], together with its target value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpringTarget {
+    /// `Node::width` in logical pixels
+    Width(f32),
+    /// `Node::height` in logical pixels
+    Height(f32),
+    /// `Node::left` in logical pixels
+    Left(f32),
+    /// `Node::top` in logical pixels
+    Top(f32),
+    /// `Transform::translation`
+    Translation(Vec3),
+    /// `Transform::scale`
+    Scale(Vec3),
+    /// `BackgroundColor`, interpolated in linear RGBA
+    BackgroundColor(Color),
+}
 
-    mt3 * 0.0 + 3.0 * mt2 * t * c2 + 3.0 * mt * t2 * c4 + t3 * 1.0
+/// The property a [`SpringTarget`] animates, without its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpringProperty {
+    Width,
+    Height,
+    Left,
+    Top,
+    Translation,
+    Scale,
+    BackgroundColor,
+}
+
+impl SpringTarget {
+    pub fn property(&self) -> SpringProperty {
+        match self {
+            Self::Width(_) => SpringProperty::Width,
+            Self::Height(_) => SpringProperty::Height,
+            Self::Left(_) => SpringProperty::Left,
+            Self::Top(_) => SpringProperty::Top,
+            Self::Translation(_) => SpringProperty::Translation,
+            Self::Scale(_) => SpringProperty::Scale,
+            Self::BackgroundColor(_) => SpringProperty::BackgroundColor,
+        }
+    }
+
+    fn to_vec4(self) -> Vec4 {
+        match self {
+            Self::Width(v) | Self::Height(v) | Self::Left(v) | Self::Top(v) => {
+                Vec4::new(v, 0.0, 0.0, 0.0)
+            }
+            Self::Translation(v) | Self::Scale(v) => v.extend(0.0),
+            Self::BackgroundColor(color) => color.to_linear().to_vec4(),
+        }
+    }
+}
+
+impl SpringProperty {
+    /// Distance and speed below which the spring snaps to its target.
+    fn rest_threshold(self) -> f32 {
+        match self {
+            Self::Width | Self::Height | Self::Left | Self::Top | Self::Translation => 0.01,
+            Self::Scale | Self::BackgroundColor => 0.0005,
+        }
+    }
+}
+
+/// One spring-driven property.
+#[derive(Debug, Clone)]
+pub struct PropertySpring {
+    pub property: SpringProperty,
+    pub config: SpringConfig,
+    value: Vec4,
+    velocity: Vec4,
+    target: Vec4,
+}
+
+impl PropertySpring {
+    /// Advance the spring; returns `true` once it has settled on the target.
+    fn step(&mut self, dt: f32) -> bool {
+        let SpringConfig {
+            stiffness,
+            damping,
+            mass,
+        } = self.config;
+
+        let displacement = self.value - self.target;
+        let acceleration = (-stiffness * displacement - damping * self.velocity) / mass;
+        self.velocity += acceleration * dt;
+        self.value += self.velocity * dt;
+
+        let threshold = self.property.rest_threshold();
+        if (self.value - self.target).abs().max_element() < threshold
+            && self.velocity.abs().max_element() < threshold
+        {
+            self.value = self.target;
+            self.velocity = Vec4::ZERO;
+            return true;
+        }
+        false
+    }
+}
+
+/// Springs currently animating properties of an entity.
+///
+/// Added by [`animate_spring`] and removed once every spring has settled.
+#[derive(Component, Debug, Clone, Default)]
+pub struct PropertySprings {
+    pub springs: Vec<PropertySpring>,
+}
+
+/// Animate a property of `entity` towards `target` with spring physics.
+///
+/// The spring starts from the property's current value. Re-targeting a property that is
+/// already animating keeps its velocity, so interrupted motion stays continuous.
+pub fn animate_spring(
+    commands: &mut Commands,
+    entity: Entity,
+    target: SpringTarget,
+    config: SpringConfig,
+) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        let property = target.property();
+        let existing = entity_mut
+            .get::<PropertySprings>()
+            .and_then(|springs| springs.springs.iter().find(|s| s.property == property))
+            .map(|spring| (spring.value, spring.velocity));
+        let (value, velocity) = match existing {
+            Some(state) => state,
+            None => (current_value(&entity_mut, property), Vec4::ZERO),
+        };
+
+        let spring = PropertySpring {
+            property,
+            config,
+            value,
+            velocity,
+            target: target.to_vec4(),
+        };
+        match entity_mut.get_mut::<PropertySprings>() {
+            Some(mut springs) => {
+                springs.springs.retain(|s| s.property != property);
+                springs.springs.push(spring);
+            }
+            None => {
+                entity_mut.insert(PropertySprings {
+                    springs: vec![spring],
+                });
+            }
+        }
+    });
+}
+
+fn px_or(val: Val, fallback: f32) -> f32 {
+    match val {
+        Val::Px(px) => px,
+        _ => fallback,
+    }
+}
+
+fn current_value(entity: &EntityWorldMut, property: SpringProperty) -> Vec4 {
+    // Non-pixel sizes start from the laid-out size.
+    let computed = entity
+        .get::<ComputedNode>()
+        .map(|node| node.size() * node.inverse_scale_factor())
+        .unwrap_or(Vec2::ZERO);
+    let node = entity.get::<Node>();
+    let transform = entity.get::<Transform>();
+
+    match property {
+        SpringProperty::Width => Vec4::X * node.map_or(0.0, |n| px_or(n.width, computed.x)),
+        SpringProperty::Height => Vec4::X * node.map_or(0.0, |n| px_or(n.height, computed.y)),
+        SpringProperty::Left => Vec4::X * node.map_or(0.0, |n| px_or(n.left, 0.0)),
+        SpringProperty::Top => Vec4::X * node.map_or(0.0, |n| px_or(n.top, 0.0)),
+        SpringProperty::Translation => transform.map_or(Vec3::ZERO, |t| t.translation).extend(0.0),
+        SpringProperty::Scale => transform.map_or(Vec3::ONE, |t| t.scale).extend(0.0),
+        SpringProperty::BackgroundColor => entity
+            .get::<BackgroundColor>()
+            .map_or(Vec4::ZERO, |bg| bg.0.to_linear().to_vec4()),
+    }
+}
+
+fn property_spring_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut animated: Query<(
+        Entity,
+        &mut PropertySprings,
+        Option<&mut Node>,
+        Option<&mut Transform>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    let reduced = reduced_motion.is_some_and(|r| r.0);
+    let dt = time.delta_secs();
+
+    for (entity, mut springs, mut node, mut transform, mut background) in animated.iter_mut() {
+        springs.springs.retain_mut(|spring| {
+            let settled = if reduced {
+                spring.value = spring.target;
+                spring.velocity = Vec4::ZERO;
+                true
+            } else {
+                spring.step(dt)
+            };
+            let value = spring.value;
+
+            match spring.property {
+                SpringProperty::Width => {
+                    if let Some(node) = node.as_mut() {
+                        node.width = Val::Px(value.x);
+                    }
+                }
+                SpringProperty::Height => {
+                    if let Some(node) = node.as_mut() {
+                        node.height = Val::Px(value.x);
+                    }
+                }
+                SpringProperty::Left => {
+                    if let Some(node) = node.as_mut() {
+                        node.left = Val::Px(value.x);
+                    }
+                }
+                SpringProperty::Top => {
+                    if let Some(node) = node.as_mut() {
+                        node.top = Val::Px(value.x);
+                    }
+                }
+                SpringProperty::Translation => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.translation = value.truncate();
+                    }
+                }
+                SpringProperty::Scale => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.scale = value.truncate();
+                    }
+                }
+                SpringProperty::BackgroundColor => {
+                    if let Some(background) = background.as_mut() {
+                        background.0 = LinearRgba::from_vec4(value).into();
+                    }
+                }
+            }
+
+            !settled
+        });
+
+        if springs.springs.is_empty() {
+            commands.entity(entity).remove::<PropertySprings>();
+        }
+    }
 }
 
 // ============================================================================
@@ -211,3 +724,739 @@ impl Default for FabTransformation {
         Self::new()
     }
 }
+
+// ============================================================================
+// Container Transform
+// ============================================================================
+
+/// Portion of a container transform spent fading out the outgoing content.
+const CONTAINER_FADE_OUT_FRACTION: f32 = 0.3;
+
+/// Morphs the bounds, corner radius and color of `from` into `to`, fading the content of
+/// one into the other (Material "container transform").
+///
+/// Spawn this on a fresh entity. While it runs, both elements are hidden and their children
+/// are shown inside the morphing container; they are moved back when it finishes and a
+/// [`ContainerTransformComplete`] message is written. Both elements must have been laid out.
+#[derive(Component, Debug, Clone)]
+pub struct ContainerTransform {
+    pub from: Entity,
+    pub to: Entity,
+    /// Duration in seconds
+    pub duration: f32,
+    elapsed: f32,
+    state: Option<ContainerTransformState>,
+}
+
+impl ContainerTransform {
+    pub fn new(from: Entity, to: Entity) -> Self {
+        Self {
+            from,
+            to,
+            duration: Duration::MEDIUM4,
+            elapsed: 0.0,
+            state: None,
+        }
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Linear progress (0.0 to 1.0)
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+}
+
+/// Written when a [`ContainerTransform`] finishes; the container entity is despawned.
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct ContainerTransformComplete {
+    pub container: Entity,
+    pub from: Entity,
+    pub to: Entity,
+}
+
+/// Logical-pixel snapshot of an element taken when the transform starts.
+#[derive(Debug, Clone)]
+struct ContainerEndpoint {
+    rect: Rect,
+    radius: f32,
+    color: Color,
+    visibility: Visibility,
+    children: Vec<Entity>,
+    /// Layer inside the container that holds this element's children
+    layer: Entity,
+}
+
+#[derive(Debug, Clone)]
+struct ContainerTransformState {
+    from: ContainerEndpoint,
+    to: ContainerEndpoint,
+    alphas: FadeAlphas,
+}
+
+/// Colors scaled by [`fade_subtree`].
+type FadeColors = (
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut TextColor>,
+    Option<&'static mut ImageNode>,
+);
+
+/// Original alphas of faded entities: background, text, image.
+type FadeAlphas = HashMap<Entity, [Option<f32>; 3]>;
+
+/// Scale the alpha of `root` and its descendants to `factor` times their original alpha.
+///
+/// Originals are recorded in `alphas` on first touch; fade to `1.0` to restore them.
+fn fade_subtree<F: QueryFilter>(
+    root: Entity,
+    factor: f32,
+    alphas: &mut FadeAlphas,
+    children: &Query<&Children>,
+    colors: &mut Query<FadeColors, F>,
+) {
+    for entity in std::iter::once(root).chain(children.iter_descendants(root)) {
+        let Ok((bg, text, image)) = colors.get_mut(entity) else {
+            continue;
+        };
+        let original = alphas.entry(entity).or_insert([
+            bg.as_ref().map(|c| c.0.alpha()),
+            text.as_ref().map(|c| c.0.alpha()),
+            image.as_ref().map(|c| c.color.alpha()),
+        ]);
+        if let (Some(mut bg), Some(alpha)) = (bg, original[0]) {
+            bg.0.set_alpha(alpha * factor);
+        }
+        if let (Some(mut text), Some(alpha)) = (text, original[1]) {
+            text.0.set_alpha(alpha * factor);
+        }
+        if let (Some(mut image), Some(alpha)) = (image, original[2]) {
+            image.color.set_alpha(alpha * factor);
+        }
+    }
+}
+
+/// Alpha multipliers for the outgoing and incoming content ("fade through").
+fn container_fade(t: f32) -> (f32, f32) {
+    let out = 1.0 - (t / CONTAINER_FADE_OUT_FRACTION).clamp(0.0, 1.0);
+    let incoming =
+        ((t - CONTAINER_FADE_OUT_FRACTION) / (1.0 - CONTAINER_FADE_OUT_FRACTION)).clamp(0.0, 1.0);
+    (out, incoming)
+}
+
+fn lerp_rect(a: Rect, b: Rect, t: f32) -> Rect {
+    Rect {
+        min: a.min.lerp(b.min, t),
+        max: a.max.lerp(b.max, t),
+    }
+}
+
+type ContainerEndpointQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static ComputedNode,
+        &'static UiGlobalTransform,
+        Option<&'static BorderRadius>,
+        Option<&'static BackgroundColor>,
+        Option<&'static Visibility>,
+        Option<&'static Children>,
+    ),
+>;
+
+/// Measure an element in logical pixels; `None` if it has not been laid out.
+fn measure_endpoint(
+    entity: Entity,
+    endpoints: &ContainerEndpointQuery,
+) -> Option<ContainerEndpoint> {
+    let (computed, transform, radius, background, visibility, children) =
+        endpoints.get(entity).ok()?;
+
+    let scale = computed.inverse_scale_factor();
+    let radius = match radius.map(|r| r.top_left) {
+        Some(Val::Px(px)) => px,
+        _ => 0.0,
+    };
+
+    Some(ContainerEndpoint {
+        rect: Rect::from_center_size(transform.translation * scale, computed.size() * scale),
+        radius,
+        color: background.map_or(Color::NONE, |bg| bg.0),
+        visibility: visibility.copied().unwrap_or_default(),
+        children: children.map(|c| c.iter().collect()).unwrap_or_default(),
+        layer: Entity::PLACEHOLDER,
+    })
+}
+
+fn container_transform_start_system(
+    mut commands: Commands,
+    mut transforms: Query<(Entity, &mut ContainerTransform), Added<ContainerTransform>>,
+    endpoints: ContainerEndpointQuery,
+    mut complete: MessageWriter<ContainerTransformComplete>,
+) {
+    for (entity, mut transform) in transforms.iter_mut() {
+        let (Some(mut from), Some(mut to)) = (
+            measure_endpoint(transform.from, &endpoints),
+            measure_endpoint(transform.to, &endpoints),
+        ) else {
+            // Nothing to morph between; finish immediately.
+            commands.entity(entity).despawn();
+            complete.write(ContainerTransformComplete {
+                container: entity,
+                from: transform.from,
+                to: transform.to,
+            });
+            continue;
+        };
+
+        // Move each element's children into a layer of the container and hide the element.
+        for (owner, endpoint) in [(transform.from, &mut from), (transform.to, &mut to)] {
+            endpoint.layer = commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                    ChildOf(entity),
+                ))
+                .add_children(&endpoint.children)
+                .id();
+            commands.entity(owner).insert(Visibility::Hidden);
+        }
+
+        commands.entity(entity).insert((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(from.rect.min.x),
+                top: Val::Px(from.rect.min.y),
+                width: Val::Px(from.rect.width()),
+                height: Val::Px(from.rect.height()),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(from.color),
+            BorderRadius::all(Val::Px(from.radius)),
+            GlobalZIndex(Z_CONTAINER_TRANSFORM),
+            Pickable::IGNORE,
+        ));
+        transform.state = Some(ContainerTransformState {
+            from,
+            to,
+            alphas: HashMap::new(),
+        });
+    }
+}
+
+fn container_transform_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut transforms: Query<(
+        Entity,
+        &mut ContainerTransform,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderRadius,
+    )>,
+    children: Query<&Children>,
+    mut colors: Query<FadeColors, Without<ContainerTransform>>,
+    mut complete: MessageWriter<ContainerTransformComplete>,
+) {
+    let dt = if reduced_motion.is_some_and(|r| r.0) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+
+    for (entity, mut transform, mut node, mut background, mut radius) in transforms.iter_mut() {
+        transform.elapsed += dt;
+        let progress = transform.progress();
+        let (from_target, to_target) = (transform.from, transform.to);
+        let Some(state) = transform.state.as_mut() else {
+            continue;
+        };
+
+        let t = ease_emphasized(progress);
+        let rect = lerp_rect(state.from.rect, state.to.rect, t);
+        node.left = Val::Px(rect.min.x);
+        node.top = Val::Px(rect.min.y);
+        node.width = Val::Px(rect.width());
+        node.height = Val::Px(rect.height());
+        *radius = BorderRadius::all(Val::Px(
+            state.from.radius + (state.to.radius - state.from.radius) * t,
+        ));
+        background.0 = state.from.color.mix(&state.to.color, t);
+
+        let finished = progress >= 1.0;
+        // Restore original alphas on the final frame.
+        let (out_alpha, in_alpha) = if finished {
+            (1.0, 1.0)
+        } else {
+            container_fade(progress)
+        };
+        for (layer, factor) in [(state.from.layer, out_alpha), (state.to.layer, in_alpha)] {
+            fade_subtree(layer, factor, &mut state.alphas, &children, &mut colors);
+        }
+
+        if !finished {
+            continue;
+        }
+
+        for (owner, endpoint) in [(from_target, &state.from), (to_target, &state.to)] {
+            commands.entity(owner).add_children(&endpoint.children);
+            commands.entity(owner).insert(endpoint.visibility);
+        }
+        commands.entity(entity).despawn();
+        complete.write(ContainerTransformComplete {
+            container: entity,
+            from: from_target,
+            to: to_target,
+        });
+    }
+}
+
+// ============================================================================
+// Staggered Reveal
+// ============================================================================
+
+/// Direction children slide in from during a [`StaggeredReveal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RevealDirection {
+    /// Fade only
+    None,
+    /// Rise from below
+    #[default]
+    Up,
+    /// Drop from above
+    Down,
+    /// Slide in from the right
+    Left,
+    /// Slide in from the left
+    Right,
+}
+
+impl RevealDirection {
+    /// Starting offset (logical pixels) for a slide of `distance`
+    fn offset(self, distance: f32) -> Vec2 {
+        match self {
+            Self::None => Vec2::ZERO,
+            Self::Up => Vec2::new(0.0, distance),
+            Self::Down => Vec2::new(0.0, -distance),
+            Self::Left => Vec2::new(distance, 0.0),
+            Self::Right => Vec2::new(-distance, 0.0),
+        }
+    }
+}
+
+/// Fades and slides the children of this entity in, one after another.
+///
+/// Plays once when added; write [`TriggerStaggeredReveal`] to play it again.
+#[derive(Component, Debug, Clone)]
+pub struct StaggeredReveal {
+    /// Delay between consecutive children, in seconds
+    pub per_item_delay: f32,
+    pub direction: RevealDirection,
+    /// Slide distance in logical pixels
+    pub distance: f32,
+    /// Per-child animation duration, in seconds
+    pub duration: f32,
+    elapsed: f32,
+    active: bool,
+    alphas: FadeAlphas,
+}
+
+impl Default for StaggeredReveal {
+    fn default() -> Self {
+        Self::new(Duration::SHORT1, RevealDirection::Up)
+    }
+}
+
+impl StaggeredReveal {
+    pub fn new(per_item_delay: f32, direction: RevealDirection) -> Self {
+        Self {
+            per_item_delay,
+            direction,
+            distance: 24.0,
+            duration: Duration::MEDIUM4,
+            elapsed: 0.0,
+            active: true,
+            alphas: HashMap::new(),
+        }
+    }
+
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Whether the reveal is playing
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Eased progress (0.0 to 1.0) of the child at `index`
+    pub fn item_progress(&self, index: usize) -> f32 {
+        let start = index as f32 * self.per_item_delay;
+        let local = if self.duration <= 0.0 {
+            1.0
+        } else {
+            ((self.elapsed - start) / self.duration).clamp(0.0, 1.0)
+        };
+        ease_emphasized_decelerate(local)
+    }
+}
+
+/// Replays the [`StaggeredReveal`] on `parent`.
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct TriggerStaggeredReveal {
+    pub parent: Entity,
+}
+
+fn staggered_reveal_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut triggers: MessageReader<TriggerStaggeredReveal>,
+    mut reveals: Query<(&mut StaggeredReveal, &Children)>,
+    children: Query<&Children>,
+    mut transforms: Query<&mut UiTransform>,
+    mut colors: Query<FadeColors>,
+) {
+    for trigger in triggers.read() {
+        if let Ok((mut reveal, _)) = reveals.get_mut(trigger.parent) {
+            reveal.elapsed = 0.0;
+            reveal.active = true;
+        }
+    }
+
+    let dt = if reduced_motion.is_some_and(|r| r.0) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+    for (mut reveal, items) in reveals.iter_mut() {
+        if !reveal.active {
+            continue;
+        }
+        reveal.elapsed += dt;
+
+        let total = items.len().saturating_sub(1) as f32 * reveal.per_item_delay + reveal.duration;
+        let finished = reveal.elapsed >= total;
+        let offset = reveal.direction.offset(reveal.distance);
+
+        for (index, item) in items.iter().enumerate() {
+            let t = if finished {
+                1.0
+            } else {
+                reveal.item_progress(index)
+            };
+            let slide = offset * (1.0 - t);
+            match transforms.get_mut(item) {
+                Ok(mut transform) => transform.translation = Val2::px(slide.x, slide.y),
+                Err(_) => {
+                    commands.entity(item).insert(UiTransform {
+                        translation: Val2::px(slide.x, slide.y),
+                        ..default()
+                    });
+                }
+            }
+            fade_subtree(item, t, &mut reveal.alphas, &children, &mut colors);
+        }
+
+        if finished {
+            reveal.active = false;
+            reveal.alphas.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::Affine2;
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() <= expected.abs().max(1.0) * 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_animated_vec2_interpolates_start_middle_end() {
+        let mut value =
+            AnimatedValue::new(Vec2::ZERO, Vec2::new(100.0, 50.0), 1.0).with_easing(Easing::Linear);
+        assert_eq!(value.current, Vec2::ZERO);
+
+        assert!(!value.update(0.5));
+        assert_near(value.current.x, 50.0);
+        assert_near(value.current.y, 25.0);
+
+        assert!(value.update(0.5));
+        assert_eq!(value.current, Vec2::new(100.0, 50.0));
+        assert!(value.is_complete());
+    }
+
+    #[test]
+    fn test_animated_color_interpolates_in_linear_space() {
+        let mut value =
+            AnimatedValue::new(Color::BLACK, Color::WHITE, 1.0).with_easing(Easing::Linear);
+        assert_eq!(value.current, Color::BLACK);
+
+        value.update(0.5);
+        let mid = value.current.to_linear();
+        for channel in [mid.red, mid.green, mid.blue] {
+            assert_near(channel, 0.5);
+        }
+
+        value.update(0.5);
+        assert_eq!(value.current.to_linear(), Color::WHITE.to_linear());
+    }
+
+    #[test]
+    fn test_repeat_modes_wrap_around() {
+        let mut looping = AnimatedValue::new(0.0, 100.0, 1.0)
+            .with_easing(Easing::Linear)
+            .looping();
+        assert!(!looping.update(1.0));
+        assert_eq!(looping.current, 100.0);
+        looping.update(0.25);
+        assert_near(looping.current, 25.0);
+        assert!(!looping.is_complete());
+
+        let mut ping_pong = AnimatedValue::new(0.0, 100.0, 1.0)
+            .with_easing(Easing::Linear)
+            .ping_pong();
+        assert!(!ping_pong.update(1.0));
+        assert!(ping_pong.reversed);
+        ping_pong.update(0.25);
+        assert_near(ping_pong.current, 75.0);
+        ping_pong.update(0.75);
+        assert_near(ping_pong.current, 0.0);
+        assert!(!ping_pong.reversed);
+    }
+
+    fn spawn_endpoint(app: &mut App, center: Vec2, size: Vec2, color: Color) -> (Entity, Entity) {
+        let child = app
+            .world_mut()
+            .spawn((Node::default(), TextColor::WHITE))
+            .id();
+        let element = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ComputedNode {
+                    size,
+                    inverse_scale_factor: 1.0,
+                    ..ComputedNode::DEFAULT
+                },
+                UiGlobalTransform::from(Affine2::from_translation(center)),
+                BackgroundColor(color),
+                BorderRadius::all(Val::Px(8.0)),
+            ))
+            .add_child(child)
+            .id();
+        (element, child)
+    }
+
+    #[test]
+    fn test_container_transform_morphs_bounds_and_restores_children() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<ContainerTransformComplete>()
+            .add_systems(
+                Update,
+                (container_transform_start_system, container_transform_system).chain(),
+            );
+
+        let (from, from_child) = spawn_endpoint(
+            &mut app,
+            Vec2::new(50.0, 25.0),
+            Vec2::new(100.0, 50.0),
+            Color::BLACK,
+        );
+        let (to, to_child) = spawn_endpoint(
+            &mut app,
+            Vec2::new(300.0, 300.0),
+            Vec2::new(200.0, 200.0),
+            Color::WHITE,
+        );
+        let container = app
+            .world_mut()
+            .spawn(ContainerTransform::new(from, to).with_duration(1.0))
+            .id();
+
+        advance(&mut app, 0.0);
+        let node = app.world().get::<Node>(container).unwrap();
+        let (Val::Px(left), Val::Px(width)) = (node.left, node.width) else {
+            panic!("container bounds are not in pixels");
+        };
+        assert!(left.abs() < 1e-3 && (width - 100.0).abs() < 1e-3);
+        assert_eq!(
+            app.world().get::<Visibility>(from),
+            Some(&Visibility::Hidden)
+        );
+        assert_eq!(app.world().get::<Visibility>(to), Some(&Visibility::Hidden));
+        let layer = app.world().get::<ChildOf>(from_child).unwrap().parent();
+        assert_eq!(
+            app.world().get::<ChildOf>(layer).unwrap().parent(),
+            container
+        );
+
+        // Past the fade-out, the outgoing content is invisible and the container is in between.
+        advance(&mut app, 0.5);
+        let Val::Px(width) = app.world().get::<Node>(container).unwrap().width else {
+            panic!("container width is not in pixels");
+        };
+        assert!(width > 100.0 && width < 200.0, "width {width}");
+        assert_eq!(
+            app.world().get::<TextColor>(from_child).unwrap().0.alpha(),
+            0.0
+        );
+
+        advance(&mut app, 0.6);
+        assert!(app.world().get_entity(container).is_err());
+        assert_eq!(
+            app.world().get::<ChildOf>(from_child).unwrap().parent(),
+            from
+        );
+        assert_eq!(app.world().get::<ChildOf>(to_child).unwrap().parent(), to);
+        assert_eq!(
+            app.world().get::<TextColor>(from_child).unwrap().0.alpha(),
+            1.0
+        );
+        assert_eq!(
+            app.world().get::<Visibility>(to),
+            Some(&Visibility::Inherited)
+        );
+        let completions = app
+            .world()
+            .resource::<Messages<ContainerTransformComplete>>();
+        let done = completions.iter_current_update_messages().last().unwrap();
+        assert_eq!((done.container, done.from, done.to), (container, from, to));
+    }
+
+    #[test]
+    fn test_staggered_reveal_plays_children_in_order() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<TriggerStaggeredReveal>()
+            .add_systems(Update, staggered_reveal_system);
+
+        let items: Vec<Entity> = (0..3)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((Node::default(), BackgroundColor(Color::WHITE)))
+                    .id()
+            })
+            .collect();
+        let parent = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                StaggeredReveal::new(0.1, RevealDirection::Up).with_duration(0.2),
+            ))
+            .add_children(&items)
+            .id();
+        let alpha =
+            |app: &App, item: Entity| app.world().get::<BackgroundColor>(item).unwrap().0.alpha();
+        let slide = |app: &App, item: Entity| match app.world().get::<UiTransform>(item) {
+            Some(UiTransform {
+                translation:
+                    Val2 {
+                        x: Val::Px(0.0),
+                        y: Val::Px(y),
+                    },
+                ..
+            }) => *y,
+            other => panic!("unexpected transform {other:?}"),
+        };
+
+        advance(&mut app, 0.0);
+        assert!(slide(&app, items[2]) > 23.9);
+
+        // The first child is revealing while the last has not started yet.
+        advance(&mut app, 0.15);
+        assert!(alpha(&app, items[0]) > 0.5);
+        assert!(alpha(&app, items[1]) > 0.0 && alpha(&app, items[1]) < alpha(&app, items[0]));
+        assert!(alpha(&app, items[2]) < 0.01);
+
+        advance(&mut app, 0.3);
+        for item in &items {
+            assert_eq!(alpha(&app, *item), 1.0);
+            assert_eq!(slide(&app, *item), 0.0);
+        }
+        assert!(!app
+            .world()
+            .get::<StaggeredReveal>(parent)
+            .unwrap()
+            .is_active());
+
+        // Triggering replays it from the start.
+        app.world_mut()
+            .write_message(TriggerStaggeredReveal { parent });
+        advance(&mut app, 0.0);
+        assert!(app
+            .world()
+            .get::<StaggeredReveal>(parent)
+            .unwrap()
+            .is_active());
+        assert!(alpha(&app, items[0]) < 0.01);
+    }
+
+    #[test]
+    fn test_reduced_motion_jumps_to_the_end_value() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(ReducedMotion(true))
+            .add_message::<AnimationComplete>()
+            .add_systems(Update, animation_system::<f32>);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                AnimatedValue::new(0.0_f32, 120.0, 1.0).bind(AnimationBinding::Width),
+            ))
+            .id();
+        advance(&mut app, 0.0);
+
+        let value = app.world().get::<AnimatedValue<f32>>(entity).unwrap();
+        assert_eq!(value.current, 120.0);
+        assert!(value.is_complete());
+        assert_eq!(
+            app.world().get::<Node>(entity).unwrap().width,
+            Val::Px(120.0)
+        );
+        let completions = app.world().resource::<Messages<AnimationComplete>>();
+        assert_eq!(
+            completions
+                .iter_current_update_messages()
+                .last()
+                .unwrap()
+                .entity,
+            entity
+        );
+    }
+}
@@ -7,12 +7,21 @@
 
 use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
+use bevy::ui::{BoxShadow, ScrollPosition};
 
 use crate::{
+    action_overflow::{
+        action_overflow_menu_system, spawn_action_overflow, ActionOverflow, ActionOverflowSlot,
+        OverflowAction, OverflowOwner,
+    },
+    elevation::Elevation,
+    fab::FabBuilder,
     i18n::LocalizedText,
     icons::{IconStyle, MaterialIcon},
+    overlay::Z_APP_BAR_CONTROLS,
     ripple::RippleHost,
     theme::MaterialTheme,
+    title_overflow::{spawn_overflow_title, TitleOverflow},
     tokens::{CornerRadius, Spacing},
 };
 
@@ -33,11 +42,22 @@ impl Plugin for AppBarPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
+        if !app.is_plugin_added::<crate::menu::MenuPlugin>() {
+            app.add_plugins(crate::menu::MenuPlugin);
+        }
+        if !app.is_plugin_added::<crate::action_overflow::ActionOverflowPlugin>() {
+            app.add_plugins(crate::action_overflow::ActionOverflowPlugin);
+        }
         app.add_message::<AppBarNavigationEvent>()
             .add_message::<AppBarActionEvent>()
             .add_systems(
                 Update,
-                (top_app_bar_scroll_system, app_bar_interaction_system),
+                (
+                    top_app_bar_scroll_system,
+                    app_bar_interaction_system,
+                    app_bar_overflow_layout_system,
+                    action_overflow_menu_system::<TopAppBar>,
+                ),
             );
     }
 }
@@ -54,11 +74,14 @@ pub struct AppBarNavigationEvent {
 }
 
 /// Event fired when an action button is clicked
+///
+/// Actions picked from a top app bar's overflow menu fire the same event, so
+/// apps can route every action by its id alone.
 #[derive(Event, Clone, bevy::prelude::Message)]
 pub struct AppBarActionEvent {
     /// The app bar entity
     pub app_bar: Entity,
-    /// The action identifier
+    /// The action identifier (see [`AppBarAction::id`])
     pub action: String,
 }
 
@@ -104,6 +127,16 @@ pub enum BottomAppBarLayout {
     WithFab,
 }
 
+/// Where a docked FAB sits on a Bottom App Bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BottomAppBarFabPosition {
+    /// Docked inside the bar at the trailing edge (MD3 default)
+    #[default]
+    End,
+    /// Cradled over the top edge of the bar, horizontally centered
+    Center,
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -125,6 +158,8 @@ pub struct TopAppBar {
     pub scroll_offset: f32,
     /// Whether elevated (has shadow)
     pub elevated: bool,
+    /// How a title that doesn't fit is shown
+    pub title_overflow: TitleOverflow,
 }
 
 /// An action button for the app bar
@@ -132,8 +167,10 @@ pub struct TopAppBar {
 pub struct AppBarAction {
     /// Icon name
     pub icon: String,
-    /// Action identifier
+    /// Action identifier, reported by [`AppBarActionEvent`]
     pub id: String,
+    /// Label shown when the action is moved into the overflow menu
+    pub label: Option<String>,
     /// Whether disabled
     pub disabled: bool,
 }
@@ -144,15 +181,38 @@ impl AppBarAction {
         Self {
             icon: icon.into(),
             id: id.into(),
+            label: None,
             disabled: false,
         }
     }
 
+    /// Set the label used in the overflow menu
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
+
+    /// Label shown in the overflow menu (falls back to the action id)
+    pub fn menu_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(self.id.as_str())
+    }
+}
+
+/// Overflow state of a top app bar, updated from its measured width
+pub type AppBarOverflow = ActionOverflow;
+
+impl OverflowOwner for TopAppBar {
+    type Event = AppBarActionEvent;
+
+    fn action_event(app_bar: Entity, action: String) -> AppBarActionEvent {
+        AppBarActionEvent { app_bar, action }
+    }
 }
 
 impl TopAppBar {
@@ -166,6 +226,7 @@ impl TopAppBar {
             scroll_behavior: TopAppBarScrollBehavior::default(),
             scroll_offset: 0.0,
             elevated: false,
+            title_overflow: TitleOverflow::default(),
         }
     }
 
@@ -209,23 +270,41 @@ impl TopAppBar {
         self
     }
 
-    /// Get the height based on variant and scroll state
-    pub fn height(&self) -> f32 {
+    /// Set how a title that doesn't fit is shown
+    pub fn with_title_overflow(mut self, overflow: TitleOverflow) -> Self {
+        self.title_overflow = overflow;
+        self
+    }
+
+    /// Get the fully expanded height for the variant
+    pub fn expanded_height(&self) -> f32 {
         match self.variant {
             TopAppBarVariant::Small | TopAppBarVariant::CenterAligned => TOP_APP_BAR_HEIGHT_SMALL,
-            TopAppBarVariant::Medium => {
-                let collapsed = (self.scroll_offset / 100.0).clamp(0.0, 1.0);
-                TOP_APP_BAR_HEIGHT_MEDIUM
-                    - collapsed * (TOP_APP_BAR_HEIGHT_MEDIUM - TOP_APP_BAR_HEIGHT_SMALL)
-            }
-            TopAppBarVariant::Large => {
-                let collapsed = (self.scroll_offset / 100.0).clamp(0.0, 1.0);
-                TOP_APP_BAR_HEIGHT_LARGE
-                    - collapsed * (TOP_APP_BAR_HEIGHT_LARGE - TOP_APP_BAR_HEIGHT_SMALL)
-            }
+            TopAppBarVariant::Medium => TOP_APP_BAR_HEIGHT_MEDIUM,
+            TopAppBarVariant::Large => TOP_APP_BAR_HEIGHT_LARGE,
         }
     }
 
+    /// How far the bar has collapsed towards the small height (0.0 = expanded, 1.0 = collapsed)
+    pub fn collapse_fraction(&self) -> f32 {
+        let range = self.expanded_height() - TOP_APP_BAR_HEIGHT_SMALL;
+        if range <= 0.0 {
+            return 0.0;
+        }
+        (self.scroll_offset / range).clamp(0.0, 1.0)
+    }
+
+    /// Get the height based on variant and scroll state
+    pub fn height(&self) -> f32 {
+        let expanded = self.expanded_height();
+        expanded - self.collapse_fraction() * (expanded - TOP_APP_BAR_HEIGHT_SMALL)
+    }
+
+    /// Whether content has scrolled underneath the bar
+    pub fn is_scrolled(&self) -> bool {
+        self.scroll_offset > 0.0
+    }
+
     /// Get the background color
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
         if self.elevated {
@@ -258,6 +337,8 @@ pub struct BottomAppBar {
     pub has_fab: bool,
     /// FAB icon
     pub fab_icon: Option<String>,
+    /// Where the FAB is docked
+    pub fab_position: BottomAppBarFabPosition,
     /// Whether elevated
     pub elevated: bool,
 }
@@ -270,6 +351,7 @@ impl BottomAppBar {
             actions: Vec::new(),
             has_fab: false,
             fab_icon: None,
+            fab_position: BottomAppBarFabPosition::default(),
             elevated: false,
         }
     }
@@ -288,6 +370,12 @@ impl BottomAppBar {
         self
     }
 
+    /// Set where the FAB is docked
+    pub fn with_fab_position(mut self, position: BottomAppBarFabPosition) -> Self {
+        self.fab_position = position;
+        self
+    }
+
     /// Set elevated
     pub fn elevated(mut self) -> Self {
         self.elevated = true;
@@ -302,6 +390,30 @@ impl BottomAppBar {
             theme.surface_container_low
         }
     }
+
+    /// Horizontal space (in pixels) reserved for a FAB of the given size so that
+    /// action icons never collide with it.
+    pub fn fab_reserved_width(&self, fab_size: f32) -> f32 {
+        if self.has_fab {
+            fab_size + Spacing::LARGE
+        } else {
+            0.0
+        }
+    }
+
+    /// Absolute offsets `(top, horizontal)` for a docked FAB of the given size.
+    ///
+    /// For [`BottomAppBarFabPosition::End`] the horizontal value is the `right`
+    /// inset; for [`BottomAppBarFabPosition::Center`] it is the negative left
+    /// margin used to center the FAB at 50%.
+    pub fn fab_offsets(&self, fab_size: f32) -> (f32, f32) {
+        match self.fab_position {
+            BottomAppBarFabPosition::End => {
+                ((BOTTOM_APP_BAR_HEIGHT - fab_size) / 2.0, Spacing::LARGE)
+            }
+            BottomAppBarFabPosition::Center => (-fab_size / 2.0, -fab_size / 2.0),
+        }
+    }
 }
 
 impl Default for BottomAppBar {
@@ -320,10 +432,62 @@ pub struct AppBarActionButton {
     pub id: String,
 }
 
+/// Marker for the button that opens a top app bar's overflow menu
+#[derive(Component)]
+struct AppBarOverflowButton;
+
+/// Wrapper around widgets injected by `spawn_top_app_bar_with_right_content`.
+///
+/// The injected widgets share the right section with the action buttons; the
+/// wrapper gives them a single measured width to subtract from the space left
+/// for actions before deciding which ones move into the overflow menu.
+#[derive(Component)]
+struct AppBarRightContent {
+    app_bar: Entity,
+}
+
 /// Marker for app bar title
 #[derive(Component)]
 pub struct AppBarTitle;
 
+/// Marker for the absolutely positioned node that anchors a docked FAB on a Bottom App Bar
+#[derive(Component)]
+pub struct BottomAppBarFabAnchor;
+
+/// Marker for the expanded headline of medium and large top app bars.
+///
+/// This title fades out as the bar collapses under [`AppBarScrollBehavior`].
+#[derive(Component)]
+pub struct AppBarLargeTitle;
+
+/// The small-position title of a medium or large top app bar.
+///
+/// It is transparent while the bar is expanded and fades in as the
+/// [`AppBarLargeTitle`] fades out, so a fully collapsed bar still shows its title.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AppBarCollapsedTitle {
+    /// The top app bar this title belongs to
+    pub app_bar: Entity,
+}
+
+/// Links a top app bar to a scroll container so the bar reacts to scrolling.
+///
+/// Medium and large bars collapse towards [`TOP_APP_BAR_HEIGHT_SMALL`] as the
+/// container scrolls, and every bar gains its elevated container color and
+/// shadow once content scrolls underneath it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AppBarScrollBehavior {
+    /// Entity carrying the `ScrollPosition` to follow
+    pub scroll_container: Entity,
+}
+
+impl AppBarScrollBehavior {
+    /// Follow the given scroll container
+    pub fn new(scroll_container: Entity) -> Self {
+        Self { scroll_container }
+    }
+}
+
 // ============================================================================
 // Dimensions
 // ============================================================================
@@ -336,6 +500,11 @@ pub const TOP_APP_BAR_HEIGHT_MEDIUM: f32 = 112.0;
 pub const TOP_APP_BAR_HEIGHT_LARGE: f32 = 152.0;
 /// Bottom app bar height
 pub const BOTTOM_APP_BAR_HEIGHT: f32 = 80.0;
+/// Width reserved for the title before top app bar actions move into the overflow menu
+pub const APP_BAR_TITLE_MIN_WIDTH: f32 = 96.0;
+
+/// Size of the navigation and action icon buttons
+const APP_BAR_ICON_BUTTON_SIZE: f32 = 48.0;
 
 // ============================================================================
 // Builder
@@ -411,19 +580,34 @@ impl TopAppBarBuilder {
         self
     }
 
+    /// Add a fully configured action
+    pub fn action(mut self, action: AppBarAction) -> Self {
+        self.app_bar.actions.push(action);
+        self
+    }
+
     /// Set elevated
     pub fn elevated(mut self) -> Self {
         self.app_bar.elevated = true;
         self
     }
 
+    /// Set how a title that doesn't fit is shown (ellipsis by default)
+    pub fn title_overflow(mut self, overflow: TitleOverflow) -> Self {
+        self.app_bar.title_overflow = overflow;
+        self
+    }
+
     /// Build the app bar bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let height = self.app_bar.height();
         let bg_color = self.app_bar.background_color(theme);
+        let shadow = top_app_bar_elevation(self.app_bar.elevated).to_box_shadow();
+        let overflow = AppBarOverflow::new(self.app_bar.actions.len());
 
         (
             self.app_bar,
+            overflow,
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Px(height),
@@ -434,6 +618,7 @@ impl TopAppBarBuilder {
                 ..default()
             },
             BackgroundColor(bg_color),
+            shadow,
         )
     }
 }
@@ -441,6 +626,7 @@ impl TopAppBarBuilder {
 /// Builder for creating Bottom App Bars
 pub struct BottomAppBarBuilder {
     app_bar: BottomAppBar,
+    fab: Option<FabBuilder>,
 }
 
 impl BottomAppBarBuilder {
@@ -448,6 +634,7 @@ impl BottomAppBarBuilder {
     pub fn new() -> Self {
         Self {
             app_bar: BottomAppBar::new(),
+            fab: None,
         }
     }
 
@@ -457,10 +644,26 @@ impl BottomAppBarBuilder {
         self
     }
 
-    /// Add a FAB
-    pub fn with_fab(mut self, icon: impl Into<String>) -> Self {
+    /// Dock a FAB on the bar
+    ///
+    /// Use [`spawn_bottom_app_bar`] to spawn the bar together with its FAB.
+    pub fn with_fab(mut self, fab: FabBuilder) -> Self {
         self.app_bar.has_fab = true;
-        self.app_bar.fab_icon = Some(icon.into());
+        self.app_bar.fab_icon = Some(fab.fab.icon.clone());
+        self.app_bar.layout = BottomAppBarLayout::WithFab;
+        self.fab = Some(fab);
+        self
+    }
+
+    /// Dock the FAB at the trailing edge of the bar
+    pub fn fab_end(mut self) -> Self {
+        self.app_bar.fab_position = BottomAppBarFabPosition::End;
+        self
+    }
+
+    /// Cradle the FAB over the center of the bar
+    pub fn fab_center(mut self) -> Self {
+        self.app_bar.fab_position = BottomAppBarFabPosition::Center;
         self
     }
 
@@ -471,27 +674,48 @@ impl BottomAppBarBuilder {
     }
 
     /// Build the app bar bundle
+    ///
+    /// The docked FAB (if any) is not part of the bundle; use
+    /// [`spawn_bottom_app_bar`] to spawn both.
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
-        let bg_color = self.app_bar.background_color(theme);
+        let fab_size = self.fab.as_ref().map_or(0.0, |fab| fab.fab.size.size());
+        bottom_app_bar_bundle(self.app_bar, theme, fab_size)
+    }
+}
 
-        (
-            self.app_bar,
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Px(BOTTOM_APP_BAR_HEIGHT),
-                padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
-                flex_direction: FlexDirection::Row,
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::SpaceBetween,
-                position_type: PositionType::Absolute,
-                bottom: Val::Px(0.0),
-                left: Val::Px(0.0),
-                right: Val::Px(0.0),
-                ..default()
+fn bottom_app_bar_bundle(
+    app_bar: BottomAppBar,
+    theme: &MaterialTheme,
+    fab_size: f32,
+) -> impl Bundle {
+    let bg_color = app_bar.background_color(theme);
+    let reserved_end = match app_bar.fab_position {
+        BottomAppBarFabPosition::End => app_bar.fab_reserved_width(fab_size),
+        BottomAppBarFabPosition::Center => 0.0,
+    };
+
+    (
+        app_bar,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(BOTTOM_APP_BAR_HEIGHT),
+            padding: UiRect {
+                left: Val::Px(Spacing::LARGE),
+                right: Val::Px(Spacing::LARGE + reserved_end),
+                top: Val::Px(Spacing::MEDIUM),
+                bottom: Val::Px(Spacing::MEDIUM),
             },
-            BackgroundColor(bg_color),
-        )
-    }
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::SpaceBetween,
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(0.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            ..default()
+        },
+        BackgroundColor(bg_color),
+    )
 }
 
 impl Default for BottomAppBarBuilder {
@@ -571,37 +795,22 @@ impl SpawnAppBarChild for ChildSpawnerCommands<'_> {
         let title_text = builder.app_bar.title.clone();
         let title_key = builder.title_key.clone();
         let title_color = builder.app_bar.title_color(theme);
+        let title_overflow = builder.app_bar.title_overflow;
 
         self.spawn(builder.build(theme)).with_children(|bar| {
             // Title
-            if let Some(key) = title_key.as_deref() {
-                bar.spawn((
-                    Text::new(""),
-                    LocalizedText::new(key),
-                    TextFont {
-                        font_size: 22.0,
-                        ..default()
-                    },
-                    TextColor(title_color),
-                    Node {
-                        flex_grow: 1.0,
-                        ..default()
-                    },
-                ));
-            } else {
-                bar.spawn((
-                    Text::new(&title_text),
-                    TextFont {
-                        font_size: 22.0,
-                        ..default()
-                    },
-                    TextColor(title_color),
-                    Node {
-                        flex_grow: 1.0,
-                        ..default()
-                    },
-                ));
-            }
+            spawn_app_bar_title(
+                bar,
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                false,
+                &title_text,
+                title_key.as_deref(),
+                title_color,
+                title_overflow,
+            );
 
             // Additional content
             with_content(bar);
@@ -636,183 +845,18 @@ impl SpawnTopAppBarWithRightContentChild for ChildSpawnerCommands<'_> {
         let title = builder.app_bar.title.clone();
         let title_key = builder.title_key.clone();
         let title_color = builder.app_bar.title_color(theme);
+        let title_overflow = builder.app_bar.title_overflow;
         let nav_icon = builder.app_bar.navigation_icon.clone();
         let actions = builder.app_bar.actions.clone();
         let variant = builder.app_bar.variant;
 
-        self.spawn(builder.build(theme))
-            .with_children(|parent| {
-                // Left section (navigation + title for small)
-                parent
-                    .spawn(Node {
-                        flex_direction: FlexDirection::Row,
-                        align_items: AlignItems::Center,
-                        column_gap: Val::Px(Spacing::EXTRA_SMALL),
-                        ..default()
-                    })
-                    .with_children(|left| {
-                        // Navigation icon
-                        if let Some(icon) = &nav_icon {
-                            left.spawn((
-                                AppBarNavigation,
-                                Button,
-                                Interaction::None,
-                                RippleHost::new(),
-                                Node {
-                                    width: Val::Px(48.0),
-                                    height: Val::Px(48.0),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                            ))
-                            .with_children(|btn| {
-                                if let Some(icon) = MaterialIcon::from_name(icon) {
-                                    btn.spawn((
-                                        icon,
-                                        IconStyle::outlined()
-                                            .with_color(theme.on_surface)
-                                            .with_size(24.0),
-                                    ));
-                                }
-                            });
-                        }
-
-                        // Title (for Small variant)
-                        if variant == TopAppBarVariant::Small {
-                            if let Some(key) = title_key.as_deref() {
-                                left.spawn((
-                                    AppBarTitle,
-                                    Text::new(""),
-                                    LocalizedText::new(key),
-                                    TextFont {
-                                        font_size: 22.0,
-                                        ..default()
-                                    },
-                                    TextColor(title_color),
-                                ));
-                            } else {
-                                left.spawn((
-                                    AppBarTitle,
-                                    Text::new(&title),
-                                    TextFont {
-                                        font_size: 22.0,
-                                        ..default()
-                                    },
-                                    TextColor(title_color),
-                                ));
-                            }
-                        }
-                    });
-
-                // Center section (title for center-aligned)
-                if variant == TopAppBarVariant::CenterAligned {
-                    if let Some(key) = title_key.as_deref() {
-                        parent.spawn((
-                            AppBarTitle,
-                            Text::new(""),
-                            LocalizedText::new(key),
-                            TextFont {
-                                font_size: 22.0,
-                                ..default()
-                            },
-                            TextColor(title_color),
-                            Node {
-                                flex_grow: 1.0,
-                                justify_content: JustifyContent::Center,
-                                ..default()
-                            },
-                        ));
-                    } else {
-                        parent.spawn((
-                            AppBarTitle,
-                            Text::new(&title),
-                            TextFont {
-                                font_size: 22.0,
-                                ..default()
-                            },
-                            TextColor(title_color),
-                            Node {
-                                flex_grow: 1.0,
-                                justify_content: JustifyContent::Center,
-                                ..default()
-                            },
-                        ));
-                    }
-                }
-
-                // Right section (custom content + actions)
-                parent
-                    .spawn(Node {
-                        flex_direction: FlexDirection::Row,
-                        align_items: AlignItems::Center,
-                        column_gap: Val::Px(Spacing::EXTRA_SMALL),
-                        ..default()
-                    })
-                    .with_children(|right| {
-                        // Injected widgets come first (so actions stay right-most).
-                        with_right_content(right);
-
-                        for action in &actions {
-                            right
-                                .spawn((
-                                    AppBarActionButton {
-                                        id: action.id.clone(),
-                                    },
-                                    Button,
-                                    Interaction::None,
-                                    RippleHost::new(),
-                                    Node {
-                                        width: Val::Px(48.0),
-                                        height: Val::Px(48.0),
-                                        justify_content: JustifyContent::Center,
-                                        align_items: AlignItems::Center,
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::NONE),
-                                    BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                                ))
-                                .with_children(|btn| {
-                                    if let Some(icon) = MaterialIcon::from_name(&action.icon) {
-                                        btn.spawn((
-                                            icon,
-                                            IconStyle::outlined()
-                                                .with_color(theme.on_surface_variant)
-                                                .with_size(24.0),
-                                        ));
-                                    }
-                                });
-                        }
-                    });
-            })
-            .id()
-    }
-}
-
-// ============================================================================
-// Helper Functions
-// ============================================================================
-
-/// Spawn a top app bar with all children
-pub fn spawn_top_app_bar(
-    commands: &mut Commands,
-    theme: &MaterialTheme,
-    builder: TopAppBarBuilder,
-) -> Entity {
-    let title = builder.app_bar.title.clone();
-    let title_color = builder.app_bar.title_color(theme);
-    let nav_icon = builder.app_bar.navigation_icon.clone();
-    let actions = builder.app_bar.actions.clone();
-    let variant = builder.app_bar.variant;
-
-    commands
-        .spawn(builder.build(theme))
-        .with_children(|parent| {
-            // Left section (navigation + title for small/center)
+        let mut bar = self.spawn(builder.build(theme));
+        let app_bar = bar.id();
+        bar.with_children(|parent| {
+            // Left section (navigation + title for small)
             parent
                 .spawn(Node {
+                    min_width: Val::Px(0.0),
                     flex_direction: FlexDirection::Row,
                     align_items: AlignItems::Center,
                     column_gap: Val::Px(Spacing::EXTRA_SMALL),
@@ -824,10 +868,12 @@ pub fn spawn_top_app_bar(
                         left.spawn((
                             AppBarNavigation,
                             Button,
+                            Interaction::None,
                             RippleHost::new(),
                             Node {
                                 width: Val::Px(48.0),
                                 height: Val::Px(48.0),
+                                flex_shrink: 0.0,
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
                                 ..default()
@@ -849,37 +895,49 @@ pub fn spawn_top_app_bar(
 
                     // Title (for Small variant)
                     if variant == TopAppBarVariant::Small {
-                        left.spawn((
-                            AppBarTitle,
-                            Text::new(&title),
-                            TextFont {
-                                font_size: 22.0,
-                                ..default()
-                            },
-                            TextColor(title_color),
-                        ));
+                        spawn_app_bar_title(
+                            left,
+                            Node::default(),
+                            false,
+                            &title,
+                            title_key.as_deref(),
+                            title_color,
+                            title_overflow,
+                        );
                     }
+
+                    // Title once collapsed (for Medium/Large variants)
+                    spawn_collapsed_title(
+                        left,
+                        app_bar,
+                        variant,
+                        &title,
+                        title_key.as_deref(),
+                        title_color,
+                        title_overflow,
+                    );
                 });
 
             // Center section (title for center-aligned)
             if variant == TopAppBarVariant::CenterAligned {
-                parent.spawn((
-                    AppBarTitle,
-                    Text::new(&title),
-                    TextFont {
-                        font_size: 22.0,
-                        ..default()
-                    },
-                    TextColor(title_color),
+                spawn_app_bar_title(
+                    parent,
                     Node {
                         flex_grow: 1.0,
-                        justify_content: JustifyContent::Center,
                         ..default()
                     },
-                ));
+                    true,
+                    &title,
+                    title_key.as_deref(),
+                    title_color,
+                    title_overflow,
+                );
             }
 
-            // Right section (actions)
+            // Expanded headline (medium/large)
+            spawn_large_title(parent, variant, &title, title_key.as_deref(), title_color);
+
+            // Right section (custom content + actions)
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Row,
@@ -888,38 +946,460 @@ pub fn spawn_top_app_bar(
                     ..default()
                 })
                 .with_children(|right| {
-                    for action in &actions {
-                        right
-                            .spawn((
-                                AppBarActionButton {
-                                    id: action.id.clone(),
-                                },
-                                Button,
-                                RippleHost::new(),
-                                Node {
-                                    width: Val::Px(48.0),
-                                    height: Val::Px(48.0),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                            ))
-                            .with_children(|btn| {
-                                if let Some(icon) = MaterialIcon::from_name(&action.icon) {
-                                    btn.spawn((
-                                        icon,
-                                        IconStyle::outlined()
-                                            .with_color(theme.on_surface_variant)
-                                            .with_size(24.0),
-                                    ));
-                                }
-                            });
-                    }
+                    // Injected widgets come first (so actions stay right-most).
+                    right
+                        .spawn((
+                            AppBarRightContent { app_bar },
+                            Node {
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                                ..default()
+                            },
+                        ))
+                        .with_children(with_right_content);
+
+                    spawn_top_app_bar_actions(right, theme, app_bar, &actions, None);
+                });
+        });
+        app_bar
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Elevation used for a top app bar in its resting or scrolled state
+fn top_app_bar_elevation(elevated: bool) -> Elevation {
+    if elevated {
+        Elevation::Level2
+    } else {
+        Elevation::Level0
+    }
+}
+
+/// Spawn the expanded headline shown by medium and large top app bars
+fn spawn_large_title(
+    parent: &mut ChildSpawnerCommands,
+    variant: TopAppBarVariant,
+    title: &str,
+    title_key: Option<&str>,
+    title_color: Color,
+) {
+    let (font_size, bottom) = match variant {
+        TopAppBarVariant::Medium => (24.0, 20.0),
+        TopAppBarVariant::Large => (28.0, 28.0),
+        TopAppBarVariant::Small | TopAppBarVariant::CenterAligned => return,
+    };
+
+    let mut title_entity = parent.spawn((
+        AppBarLargeTitle,
+        Text::new(title),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(title_color),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(Spacing::LARGE),
+            bottom: Val::Px(bottom),
+            ..default()
+        },
+    ));
+    if let Some(key) = title_key {
+        title_entity.insert(LocalizedText::new(key));
+    }
+}
+
+/// Spawn a single-line small or center-aligned title that ellipsizes or
+/// scrolls when it overflows `container`
+fn spawn_app_bar_title(
+    parent: &mut ChildSpawnerCommands,
+    container: Node,
+    centered: bool,
+    title: &str,
+    title_key: Option<&str>,
+    title_color: Color,
+    overflow: TitleOverflow,
+) -> Entity {
+    let text = spawn_overflow_title(
+        parent,
+        container,
+        overflow,
+        centered,
+        (
+            AppBarTitle,
+            Text::new(title),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(title_color),
+        ),
+    );
+    if let Some(key) = title_key {
+        parent
+            .commands()
+            .entity(text)
+            .insert(LocalizedText::new(key));
+    }
+    text
+}
+
+/// Spawn the small-position title of a medium or large bar, hidden until the bar collapses
+fn spawn_collapsed_title(
+    parent: &mut ChildSpawnerCommands,
+    app_bar: Entity,
+    variant: TopAppBarVariant,
+    title: &str,
+    title_key: Option<&str>,
+    title_color: Color,
+    overflow: TitleOverflow,
+) {
+    if !matches!(variant, TopAppBarVariant::Medium | TopAppBarVariant::Large) {
+        return;
+    }
+    let text = spawn_app_bar_title(
+        parent,
+        Node::default(),
+        false,
+        title,
+        title_key,
+        title_color.with_alpha(0.0),
+        overflow,
+    );
+    parent
+        .commands()
+        .entity(text)
+        .insert(AppBarCollapsedTitle { app_bar });
+}
+
+/// Spawn the action buttons of a top app bar, followed by the overflow button
+/// that collects the actions which no longer fit.
+fn spawn_top_app_bar_actions(
+    right: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    app_bar: Entity,
+    actions: &[AppBarAction],
+    z_index: Option<i32>,
+) {
+    let icon_button = |display: Display| {
+        (
+            Button,
+            Interaction::None,
+            RippleHost::new(),
+            Node {
+                display,
+                width: Val::Px(APP_BAR_ICON_BUTTON_SIZE),
+                height: Val::Px(APP_BAR_ICON_BUTTON_SIZE),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Px(CornerRadius::FULL)),
+        )
+    };
+
+    for (index, action) in actions.iter().enumerate() {
+        let mut button = right.spawn((
+            AppBarActionButton {
+                id: action.id.clone(),
+            },
+            ActionOverflowSlot {
+                owner: app_bar,
+                index,
+            },
+            icon_button(Display::Flex),
+        ));
+        if let Some(z_index) = z_index {
+            button.insert(GlobalZIndex(z_index));
+        }
+        button.with_children(|btn| {
+            if let Some(icon) = MaterialIcon::from_name(&action.icon) {
+                btn.spawn((
+                    icon,
+                    IconStyle::outlined()
+                        .with_color(theme.on_surface_variant)
+                        .with_size(24.0),
+                ));
+            }
+        });
+    }
+
+    if actions.is_empty() {
+        return;
+    }
+
+    // Overflow button, hidden until actions no longer fit.
+    let overflow = spawn_action_overflow(
+        right,
+        theme,
+        app_bar,
+        (AppBarOverflowButton, icon_button(Display::None)),
+        APP_BAR_ICON_BUTTON_SIZE,
+        actions.iter().map(|action| OverflowAction {
+            icon: &action.icon,
+            id: &action.id,
+            label: action.menu_label(),
+            disabled: action.disabled,
+        }),
+    );
+    if let Some(z_index) = z_index {
+        right
+            .commands()
+            .entity(overflow)
+            .insert(GlobalZIndex(z_index));
+    }
+}
+
+/// Spawn a top app bar with all children
+pub fn spawn_top_app_bar(
+    commands: &mut Commands,
+    theme: &MaterialTheme,
+    builder: TopAppBarBuilder,
+) -> Entity {
+    let title = builder.app_bar.title.clone();
+    let title_color = builder.app_bar.title_color(theme);
+    let title_overflow = builder.app_bar.title_overflow;
+    let nav_icon = builder.app_bar.navigation_icon.clone();
+    let actions = builder.app_bar.actions.clone();
+    let variant = builder.app_bar.variant;
+
+    let mut bar = commands.spawn(builder.build(theme));
+    let app_bar = bar.id();
+    bar.with_children(|parent| {
+        // Left section (navigation + title for small/center)
+        parent
+            .spawn(Node {
+                min_width: Val::Px(0.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                ..default()
+            })
+            .with_children(|left| {
+                // Navigation icon
+                if let Some(icon) = &nav_icon {
+                    left.spawn((
+                        AppBarNavigation,
+                        Button,
+                        RippleHost::new(),
+                        Node {
+                            width: Val::Px(48.0),
+                            height: Val::Px(48.0),
+                            flex_shrink: 0.0,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                    ))
+                    .with_children(|btn| {
+                        if let Some(icon) = MaterialIcon::from_name(icon) {
+                            btn.spawn((
+                                icon,
+                                IconStyle::outlined()
+                                    .with_color(theme.on_surface)
+                                    .with_size(24.0),
+                            ));
+                        }
+                    });
+                }
+
+                // Title (for Small variant)
+                if variant == TopAppBarVariant::Small {
+                    spawn_app_bar_title(
+                        left,
+                        Node::default(),
+                        false,
+                        &title,
+                        None,
+                        title_color,
+                        title_overflow,
+                    );
+                }
+
+                // Title once collapsed (for Medium/Large variants)
+                spawn_collapsed_title(
+                    left,
+                    app_bar,
+                    variant,
+                    &title,
+                    None,
+                    title_color,
+                    title_overflow,
+                );
+            });
+
+        // Center section (title for center-aligned)
+        if variant == TopAppBarVariant::CenterAligned {
+            spawn_app_bar_title(
+                parent,
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                true,
+                &title,
+                None,
+                title_color,
+                title_overflow,
+            );
+        }
+
+        // Expanded headline (medium/large)
+        spawn_large_title(parent, variant, &title, None, title_color);
+
+        // Right section (actions)
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                ..default()
+            })
+            .with_children(|right| {
+                spawn_top_app_bar_actions(right, theme, app_bar, &actions, None);
+            });
+    });
+    app_bar
+}
+
+/// Entities spawned by [`spawn_bottom_app_bar`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BottomAppBarEntities {
+    /// The bottom app bar container
+    pub app_bar: Entity,
+    /// The docked FAB, if the builder had one
+    pub fab: Option<Entity>,
+}
+
+/// Spawn a bottom app bar with its action buttons and optional docked FAB.
+///
+/// Action icons are laid out so they never overlap the FAB: an end-docked FAB
+/// reserves space at the trailing edge, and a centered FAB splits the actions
+/// around a gap in the middle of the bar.
+pub fn spawn_bottom_app_bar(
+    commands: &mut Commands,
+    theme: &MaterialTheme,
+    builder: BottomAppBarBuilder,
+) -> BottomAppBarEntities {
+    let BottomAppBarBuilder { app_bar, fab } = builder;
+    let actions = app_bar.actions.clone();
+    let fab_size = fab.as_ref().map_or(0.0, |fab| fab.fab.size.size());
+    let fab_position = app_bar.fab_position;
+    let reserved = app_bar.fab_reserved_width(fab_size);
+    let (fab_top, fab_horizontal) = app_bar.fab_offsets(fab_size);
+
+    let split = match (fab_position, fab.is_some()) {
+        (BottomAppBarFabPosition::Center, true) => actions.len().div_ceil(2),
+        _ => actions.len(),
+    };
+
+    let spawn_actions = |row: &mut ChildSpawnerCommands, actions: &[AppBarAction]| {
+        for action in actions {
+            row.spawn((
+                AppBarActionButton {
+                    id: action.id.clone(),
+                },
+                Button,
+                Interaction::None,
+                RippleHost::new(),
+                Node {
+                    width: Val::Px(48.0),
+                    height: Val::Px(48.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(CornerRadius::FULL)),
+            ))
+            .with_children(|btn| {
+                if let Some(icon) = MaterialIcon::from_name(&action.icon) {
+                    btn.spawn((
+                        icon,
+                        IconStyle::outlined()
+                            .with_color(theme.on_surface_variant)
+                            .with_size(24.0),
+                    ));
+                }
+            });
+        }
+    };
+
+    let mut fab_entity = None;
+    let app_bar = commands
+        .spawn(bottom_app_bar_bundle(app_bar, theme, fab_size))
+        .with_children(|bar| {
+            let action_row = Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                ..default()
+            };
+
+            bar.spawn(action_row.clone())
+                .with_children(|row| spawn_actions(row, &actions[..split]));
+
+            if split < actions.len() {
+                // Gap under the cradled FAB
+                bar.spawn(Node {
+                    width: Val::Px(reserved),
+                    ..default()
+                });
+                bar.spawn(action_row)
+                    .with_children(|row| spawn_actions(row, &actions[split..]));
+            }
+
+            let Some(fab) = fab else {
+                return;
+            };
+            let icon_name = fab.fab.icon.clone();
+            let icon_color = fab.fab.content_color(theme);
+            let icon_size = fab.fab.size.icon_size();
+
+            let mut anchor = Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(fab_top),
+                ..default()
+            };
+            match fab_position {
+                BottomAppBarFabPosition::End => anchor.right = Val::Px(fab_horizontal),
+                BottomAppBarFabPosition::Center => {
+                    anchor.left = Val::Percent(50.0);
+                    anchor.margin.left = Val::Px(fab_horizontal);
+                }
+            }
+
+            bar.spawn((BottomAppBarFabAnchor, anchor, ZIndex(1)))
+                .with_children(|anchor| {
+                    let id = anchor
+                        .spawn(fab.build(theme))
+                        .with_children(|fab| {
+                            if let Some(icon) = MaterialIcon::from_name(&icon_name) {
+                                fab.spawn((
+                                    icon,
+                                    IconStyle::outlined()
+                                        .with_color(icon_color)
+                                        .with_size(icon_size),
+                                ));
+                            }
+                        })
+                        .id();
+                    fab_entity = Some(id);
                 });
         })
-        .id()
+        .id();
+
+    BottomAppBarEntities {
+        app_bar,
+        fab: fab_entity,
+    }
 }
 
 /// Spawn a top app bar with a right-side custom content slot.
@@ -933,157 +1413,226 @@ pub fn spawn_top_app_bar_with_right_content(
 ) -> Entity {
     let title = builder.app_bar.title.clone();
     let title_color = builder.app_bar.title_color(theme);
+    let title_overflow = builder.app_bar.title_overflow;
     let nav_icon = builder.app_bar.navigation_icon.clone();
     let actions = builder.app_bar.actions.clone();
     let variant = builder.app_bar.variant;
 
-    commands
-        .spawn(builder.build(theme))
-        .with_children(|parent| {
-            // Left section (navigation + title for small/center)
-            parent
-                .spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    column_gap: Val::Px(Spacing::EXTRA_SMALL),
-                    ..default()
-                })
-                .with_children(|left| {
-                    // Make the navigation area (icon + title) one large clickable target.
-                    // This matches user expectations that clicking the title behaves like "Back",
-                    // and it provides a clear hover target.
-                    if nav_icon.is_some() {
-                        left.spawn((
-                            AppBarNavigation,
-                            Button,
-                            Interaction::None,
-                            RippleHost::new(),
-                            GlobalZIndex(1002),
-                            Node {
-                                height: Val::Px(48.0),
-                                flex_direction: FlexDirection::Row,
-                                align_items: AlignItems::Center,
-                                padding: UiRect::horizontal(Val::Px(8.0)),
-                                column_gap: Val::Px(Spacing::EXTRA_SMALL),
-                                ..default()
-                            },
-                            BackgroundColor(Color::NONE),
-                            BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                        ))
-                        .with_children(|btn| {
-                            if let Some(icon_name) = &nav_icon {
-                                if let Some(icon) = MaterialIcon::from_name(icon_name) {
-                                    btn.spawn((
-                                        icon,
-                                        IconStyle::outlined()
-                                            .with_color(theme.on_surface)
-                                            .with_size(24.0),
-                                    ));
-                                }
-                            }
-
-                            if variant == TopAppBarVariant::Small {
+    let mut bar = commands.spawn(builder.build(theme));
+    let app_bar = bar.id();
+    bar.with_children(|parent| {
+        // Left section (navigation + title for small/center)
+        parent
+            .spawn(Node {
+                min_width: Val::Px(0.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                ..default()
+            })
+            .with_children(|left| {
+                // Make the navigation area (icon + title) one large clickable target.
+                // This matches user expectations that clicking the title behaves like "Back",
+                // and it provides a clear hover target.
+                if nav_icon.is_some() {
+                    left.spawn((
+                        AppBarNavigation,
+                        Button,
+                        Interaction::None,
+                        RippleHost::new(),
+                        GlobalZIndex(Z_APP_BAR_CONTROLS),
+                        Node {
+                            height: Val::Px(48.0),
+                            min_width: Val::Px(0.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Val::Px(8.0)),
+                            column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                    ))
+                    .with_children(|btn| {
+                        if let Some(icon_name) = &nav_icon {
+                            if let Some(icon) = MaterialIcon::from_name(icon_name) {
                                 btn.spawn((
-                                    AppBarTitle,
-                                    Text::new(&title),
-                                    TextFont {
-                                        font_size: 22.0,
-                                        ..default()
-                                    },
-                                    TextColor(title_color),
+                                    icon,
+                                    IconStyle::outlined()
+                                        .with_color(theme.on_surface)
+                                        .with_size(24.0),
                                 ));
                             }
-                        });
-                    } else if variant == TopAppBarVariant::Small {
-                        // No navigation: just show the title.
-                        left.spawn((
-                            AppBarTitle,
-                            Text::new(&title),
-                            TextFont {
-                                font_size: 22.0,
-                                ..default()
-                            },
-                            TextColor(title_color),
-                        ));
-                    }
-                });
-
-            // Center section (title for center-aligned)
-            if variant == TopAppBarVariant::CenterAligned {
-                parent.spawn((
-                    AppBarTitle,
-                    Text::new(&title),
-                    TextFont {
-                        font_size: 22.0,
-                        ..default()
-                    },
-                    TextColor(title_color),
-                    Node {
-                        flex_grow: 1.0,
-                        justify_content: JustifyContent::Center,
-                        ..default()
-                    },
-                ));
-            }
+                        }
 
-            // Right section (custom content + actions)
-            parent
-                .spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                        if variant == TopAppBarVariant::Small {
+                            spawn_app_bar_title(
+                                btn,
+                                Node::default(),
+                                false,
+                                &title,
+                                None,
+                                title_color,
+                                title_overflow,
+                            );
+                        }
+                        spawn_collapsed_title(
+                            btn,
+                            app_bar,
+                            variant,
+                            &title,
+                            None,
+                            title_color,
+                            title_overflow,
+                        );
+                    });
+                } else if variant == TopAppBarVariant::Small {
+                    // No navigation: just show the title.
+                    spawn_app_bar_title(
+                        left,
+                        Node::default(),
+                        false,
+                        &title,
+                        None,
+                        title_color,
+                        title_overflow,
+                    );
+                } else {
+                    spawn_collapsed_title(
+                        left,
+                        app_bar,
+                        variant,
+                        &title,
+                        None,
+                        title_color,
+                        title_overflow,
+                    );
+                }
+            });
+
+        // Center section (title for center-aligned)
+        if variant == TopAppBarVariant::CenterAligned {
+            spawn_app_bar_title(
+                parent,
+                Node {
+                    flex_grow: 1.0,
                     ..default()
-                })
-                .with_children(|right| {
-                    with_right_content(right);
-
-                    for action in &actions {
-                        right
-                            .spawn((
-                                AppBarActionButton {
-                                    id: action.id.clone(),
-                                },
-                                Button,
-                                Interaction::None,
-                                RippleHost::new(),
-                                GlobalZIndex(1002),
-                                Node {
-                                    width: Val::Px(48.0),
-                                    height: Val::Px(48.0),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                            ))
-                            .with_children(|btn| {
-                                if let Some(icon) = MaterialIcon::from_name(&action.icon) {
-                                    btn.spawn((
-                                        icon,
-                                        IconStyle::outlined()
-                                            .with_color(theme.on_surface_variant)
-                                            .with_size(24.0),
-                                    ));
-                                }
-                            });
-                    }
-                });
-        })
-        .id()
+                },
+                true,
+                &title,
+                None,
+                title_color,
+                title_overflow,
+            );
+        }
+
+        // Expanded headline (medium/large)
+        spawn_large_title(parent, variant, &title, None, title_color);
+
+        // Right section (custom content + actions)
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                ..default()
+            })
+            .with_children(|right| {
+                right
+                    .spawn((
+                        AppBarRightContent { app_bar },
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(Spacing::EXTRA_SMALL),
+                            ..default()
+                        },
+                    ))
+                    .with_children(with_right_content);
+
+                spawn_top_app_bar_actions(
+                    right,
+                    theme,
+                    app_bar,
+                    &actions,
+                    Some(Z_APP_BAR_CONTROLS),
+                );
+            });
+    });
+    app_bar
 }
 
 // ============================================================================
 // Systems
 // ============================================================================
 
-/// System to handle scroll behavior for top app bars
+/// System to collapse and elevate top app bars that follow a scroll container
 fn top_app_bar_scroll_system(
-    mut _app_bars: Query<(&mut TopAppBar, &mut Node)>,
-    // In a real implementation, this would listen to scroll events
+    theme: Option<Res<MaterialTheme>>,
+    mut app_bars: Query<
+        (
+            Entity,
+            &mut TopAppBar,
+            &AppBarScrollBehavior,
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BoxShadow,
+            Option<&Children>,
+        ),
+        Without<AppBarLargeTitle>,
+    >,
+    scroll_positions: Query<&ScrollPosition>,
+    mut large_titles: Query<
+        (&mut TextColor, &mut Node),
+        (With<AppBarLargeTitle>, Without<TopAppBar>),
+    >,
+    mut collapsed_titles: Query<(&AppBarCollapsedTitle, &mut TextColor), Without<AppBarLargeTitle>>,
 ) {
-    // Placeholder for scroll behavior implementation
-    // This would track scroll position and update the app bar height/style accordingly
+    let Some(theme) = theme else { return };
+
+    for (entity, mut app_bar, behavior, mut node, mut bg, mut shadow, children) in
+        app_bars.iter_mut()
+    {
+        let Ok(scroll) = scroll_positions.get(behavior.scroll_container) else {
+            continue;
+        };
+
+        let offset = scroll.y.max(0.0);
+        if app_bar.scroll_offset == offset && !theme.is_changed() {
+            continue;
+        }
+
+        app_bar.scroll_offset = offset;
+        app_bar.elevated = app_bar.is_scrolled();
+
+        node.height = Val::Px(app_bar.height());
+        bg.0 = app_bar.background_color(&theme);
+        *shadow = top_app_bar_elevation(app_bar.elevated).to_box_shadow();
+
+        // Cross-fade the headline into the small title position as the bar collapses.
+        let collapse = app_bar.collapse_fraction();
+        let title_color = app_bar.title_color(&theme);
+        for (title, mut color) in collapsed_titles.iter_mut() {
+            if title.app_bar == entity {
+                color.0 = title_color.with_alpha(title_color.alpha() * collapse);
+            }
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        let visibility = 1.0 - collapse;
+        for child in children.iter() {
+            if let Ok((mut color, mut title_node)) = large_titles.get_mut(child) {
+                color.0 = title_color.with_alpha(title_color.alpha() * visibility);
+                title_node.display = if visibility > 0.0 {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
 }
 
 /// System to handle app bar interactions
@@ -1091,8 +1640,12 @@ fn app_bar_interaction_system(
     theme: Res<MaterialTheme>,
     nav_buttons: Query<(Entity, &Interaction), (Changed<Interaction>, With<AppBarNavigation>)>,
     action_buttons: Query<(Entity, &Interaction, &AppBarActionButton), Changed<Interaction>>,
+    overflow_buttons: Query<
+        (Entity, &Interaction),
+        (Changed<Interaction>, With<AppBarOverflowButton>),
+    >,
     parents: Query<&ChildOf>,
-    app_bars: Query<Entity, With<TopAppBar>>,
+    app_bars: Query<Entity, Or<(With<TopAppBar>, With<BottomAppBar>)>>,
     mut bgs: Query<&mut BackgroundColor>,
     mut nav_events: MessageWriter<AppBarNavigationEvent>,
     mut action_events: MessageWriter<AppBarActionEvent>,
@@ -1149,6 +1702,62 @@ fn app_bar_interaction_system(
             }
         }
     }
+
+    // Overflow buttons only need hover feedback; the menu opens on its own.
+    for (entity, interaction) in overflow_buttons.iter() {
+        if let Ok(mut bg) = bgs.get_mut(entity) {
+            *bg = match interaction {
+                Interaction::Hovered | Interaction::Pressed => {
+                    BackgroundColor(theme.surface_container_highest)
+                }
+                Interaction::None => BackgroundColor(Color::NONE),
+            };
+        }
+    }
+}
+
+/// Measures each top app bar and moves the actions that don't fit into the overflow menu
+fn app_bar_overflow_layout_system(
+    mut app_bars: Query<(Entity, &TopAppBar, &ComputedNode, &mut AppBarOverflow)>,
+    right_content: Query<(&AppBarRightContent, &ComputedNode)>,
+) {
+    for (entity, app_bar, computed, mut overflow) in app_bars.iter_mut() {
+        let width = computed.size().x * computed.inverse_scale_factor;
+        if width <= 0.0 {
+            // Not laid out yet.
+            continue;
+        }
+
+        let mut available = width - 2.0 * Spacing::EXTRA_SMALL;
+        if app_bar.navigation_icon.is_some() {
+            available -= APP_BAR_ICON_BUTTON_SIZE + Spacing::EXTRA_SMALL;
+        }
+        if matches!(
+            app_bar.variant,
+            TopAppBarVariant::Small | TopAppBarVariant::CenterAligned
+        ) {
+            available -= APP_BAR_TITLE_MIN_WIDTH + Spacing::EXTRA_SMALL;
+        }
+        for (content, content_node) in right_content.iter() {
+            if content.app_bar != entity {
+                continue;
+            }
+            let content_width = content_node.size().x * content_node.inverse_scale_factor;
+            if content_width > 0.0 {
+                available -= content_width + Spacing::EXTRA_SMALL;
+            }
+        }
+
+        let next = AppBarOverflow::fitting(
+            app_bar.actions.len(),
+            available,
+            APP_BAR_ICON_BUTTON_SIZE,
+            Spacing::EXTRA_SMALL,
+        );
+        if *overflow != next {
+            *overflow = next;
+        }
+    }
 }
 
 // ============================================================================
@@ -1182,6 +1791,67 @@ mod tests {
         assert_eq!(large.height(), TOP_APP_BAR_HEIGHT_LARGE);
     }
 
+    #[test]
+    fn test_top_app_bar_collapse_on_scroll() {
+        let mut large = TopAppBar::new("Large").with_variant(TopAppBarVariant::Large);
+        assert_eq!(large.collapse_fraction(), 0.0);
+        assert!(!large.is_scrolled());
+
+        let range = TOP_APP_BAR_HEIGHT_LARGE - TOP_APP_BAR_HEIGHT_SMALL;
+        large.scroll_offset = range / 2.0;
+        assert_eq!(large.collapse_fraction(), 0.5);
+        assert_eq!(large.height(), TOP_APP_BAR_HEIGHT_SMALL + range / 2.0);
+        assert!(large.is_scrolled());
+
+        large.scroll_offset = range * 4.0;
+        assert_eq!(large.height(), TOP_APP_BAR_HEIGHT_SMALL);
+
+        let mut small = TopAppBar::new("Small");
+        small.scroll_offset = 500.0;
+        assert_eq!(small.collapse_fraction(), 0.0);
+        assert_eq!(small.height(), TOP_APP_BAR_HEIGHT_SMALL);
+    }
+
+    #[test]
+    fn test_collapsed_large_bar_shows_small_title() {
+        let mut app = App::new();
+        app.init_resource::<MaterialTheme>()
+            .add_systems(Update, top_app_bar_scroll_system);
+        let theme = MaterialTheme::default();
+
+        let container = app
+            .world_mut()
+            .spawn(ScrollPosition(Vec2::new(0.0, 500.0)))
+            .id();
+        let app_bar = spawn_top_app_bar(
+            &mut app.world_mut().commands(),
+            &theme,
+            TopAppBarBuilder::new("Inbox").large(),
+        );
+        app.world_mut().flush();
+        app.world_mut()
+            .entity_mut(app_bar)
+            .insert(AppBarScrollBehavior::new(container));
+        app.update();
+
+        let bar = app.world().get::<TopAppBar>(app_bar).unwrap();
+        assert_eq!(bar.collapse_fraction(), 1.0);
+        let title_alpha = bar.title_color(&theme).alpha();
+
+        let mut large = app
+            .world_mut()
+            .query_filtered::<&Node, With<AppBarLargeTitle>>();
+        assert_eq!(large.single(app.world()).unwrap().display, Display::None);
+
+        let mut collapsed = app
+            .world_mut()
+            .query::<(&AppBarCollapsedTitle, &TextColor, &Node)>();
+        let (title, color, node) = collapsed.single(app.world()).unwrap();
+        assert_eq!(title.app_bar, app_bar);
+        assert_eq!(color.0.alpha(), title_alpha);
+        assert_ne!(node.display, Display::None);
+    }
+
     #[test]
     fn test_bottom_app_bar_creation() {
         let app_bar = BottomAppBar::new()
@@ -1193,6 +1863,71 @@ mod tests {
         assert_eq!(app_bar.fab_icon, Some("add".to_string()));
     }
 
+    #[test]
+    fn test_bottom_app_bar_fab_docking() {
+        let builder = BottomAppBarBuilder::new()
+            .add_action("home", "home")
+            .with_fab(FabBuilder::new("add"));
+        assert!(builder.app_bar.has_fab);
+        assert_eq!(builder.app_bar.layout, BottomAppBarLayout::WithFab);
+        assert_eq!(builder.app_bar.fab_position, BottomAppBarFabPosition::End);
+
+        let fab_size = 56.0;
+        assert_eq!(
+            builder.app_bar.fab_reserved_width(fab_size),
+            fab_size + Spacing::LARGE
+        );
+        assert_eq!(
+            builder.app_bar.fab_offsets(fab_size),
+            ((BOTTOM_APP_BAR_HEIGHT - fab_size) / 2.0, Spacing::LARGE)
+        );
+
+        let centered = builder.fab_center();
+        assert_eq!(
+            centered.app_bar.fab_offsets(fab_size),
+            (-fab_size / 2.0, -fab_size / 2.0)
+        );
+
+        assert_eq!(BottomAppBar::new().fab_reserved_width(fab_size), 0.0);
+    }
+
+    #[test]
+    fn test_app_bar_action_label() {
+        let action = AppBarAction::new("share", "share");
+        assert_eq!(action.menu_label(), "share");
+
+        let action = action.with_label("Share");
+        assert_eq!(action.menu_label(), "Share");
+        assert_eq!(action.id, "share");
+    }
+
+    #[test]
+    fn test_app_bar_overflow_count() {
+        let slot = APP_BAR_ICON_BUTTON_SIZE + Spacing::EXTRA_SMALL;
+        let width = 3.0 * slot - Spacing::EXTRA_SMALL;
+        let fitting = |actions| {
+            AppBarOverflow::fitting(
+                actions,
+                width,
+                APP_BAR_ICON_BUTTON_SIZE,
+                Spacing::EXTRA_SMALL,
+            )
+        };
+        assert_eq!(fitting(3).visible_count(), 3);
+        // One short: the last visible slot goes to the overflow button.
+        let overflow = fitting(4);
+        assert_eq!(overflow.visible_count(), 2);
+        assert_eq!(overflow.overflow_count(), 2);
+        assert!(!overflow.is_overflowed(1));
+        assert!(overflow.is_overflowed(2));
+        assert_eq!(
+            AppBarOverflow::fitting(2, 0.0, APP_BAR_ICON_BUTTON_SIZE, Spacing::EXTRA_SMALL)
+                .visible_count(),
+            0
+        );
+        assert_eq!(AppBarOverflow::new(3).overflow_count(), 0);
+    }
+
     #[test]
     fn test_app_bar_builder() {
         let builder = TopAppBarBuilder::new("My App")
@@ -0,0 +1,392 @@
+//! Material Design 3 autocomplete text field
+//!
+//! Pairs a [`MaterialTextField`] with a [`MaterialMenu`] of suggestions that is
+//! refiltered as the user types. Arrow keys move the highlight, and Enter or a
+//! click fills the field and emits [`AutocompleteSelected`].
+//!
+//! Reference: <https://m3.material.io/components/menus/guidelines>
+
+use bevy::prelude::*;
+
+use crate::{
+    focus::wrap_highlight,
+    menu::{MaterialMenu, MaterialMenuItem, MenuBuilder, MenuItemBuilder, MenuItemSelectEvent},
+    text_field::{MaterialTextField, TextFieldChangeEvent},
+    theme::MaterialTheme,
+    tokens::Spacing,
+    typography::TypeRole,
+};
+
+/// Plugin for the autocomplete text field
+pub struct AutocompletePlugin;
+
+impl Plugin for AutocompletePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
+            app.add_plugins(crate::MaterialUiCorePlugin);
+        }
+        if !app.is_plugin_added::<crate::text_field::TextFieldPlugin>() {
+            app.add_plugins(crate::text_field::TextFieldPlugin);
+        }
+        if !app.is_plugin_added::<crate::menu::MenuPlugin>() {
+            app.add_plugins(crate::menu::MenuPlugin);
+        }
+        app.add_message::<AutocompleteSelected>().add_systems(
+            Update,
+            (
+                autocomplete_setup_system,
+                autocomplete_input_system,
+                autocomplete_keyboard_system,
+                autocomplete_click_system,
+                autocomplete_sync_system,
+            )
+                .chain(),
+        );
+    }
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// Suggestion source for an autocomplete field, called with the current text.
+pub type SuggestionProvider = fn(&str) -> Vec<String>;
+
+/// Autocomplete behavior for a [`MaterialTextField`].
+///
+/// Insert it on the field entity, e.g. with
+/// [`spawn_text_field_control_with`](crate::text_field::spawn_text_field_control_with).
+/// A suggestion menu is spawned below the field and refilled from `provider`
+/// whenever the text changes.
+#[derive(Component, Clone)]
+pub struct Autocomplete {
+    /// Returns the suggestions for the current text
+    pub provider: SuggestionProvider,
+    /// Maximum number of suggestions shown at once
+    pub max_suggestions: usize,
+    suggestions: Vec<String>,
+    highlighted: Option<usize>,
+    menu: Option<Entity>,
+}
+
+impl Autocomplete {
+    /// Create autocomplete behavior backed by `provider`
+    pub fn new(provider: SuggestionProvider) -> Self {
+        Self {
+            provider,
+            max_suggestions: AUTOCOMPLETE_MAX_SUGGESTIONS,
+            suggestions: Vec::new(),
+            highlighted: None,
+            menu: None,
+        }
+    }
+
+    /// Limit the number of suggestions shown at once
+    pub fn max_suggestions(mut self, max: usize) -> Self {
+        self.max_suggestions = max;
+        self
+    }
+
+    /// Suggestions for `text`, capped at `max_suggestions`
+    pub fn query(&self, text: &str) -> Vec<String> {
+        let mut suggestions = (self.provider)(text);
+        suggestions.truncate(self.max_suggestions);
+        suggestions
+    }
+
+    /// Suggestions currently shown in the menu
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// Keyboard-highlighted suggestion
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// The suggestion menu entity, once spawned
+    pub fn menu(&self) -> Option<Entity> {
+        self.menu
+    }
+
+    /// Move the highlight by `delta` items, wrapping around the suggestions.
+    pub fn move_highlight(&mut self, delta: i32) {
+        self.highlighted = wrap_highlight(self.highlighted, delta, self.suggestions.len());
+    }
+}
+
+/// Case-insensitive substring filter over a fixed option list.
+///
+/// Handy for building a [`SuggestionProvider`]; an empty `text` matches nothing.
+pub fn filter_suggestions(options: &[&str], text: &str) -> Vec<String> {
+    let needle = text.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    options
+        .iter()
+        .filter(|option| option.to_lowercase().contains(&needle))
+        .map(|option| option.to_string())
+        .collect()
+}
+
+/// Suggestion menu spawned for an autocomplete field
+#[derive(Component)]
+struct AutocompleteMenu;
+
+/// Suggestion row inside an autocomplete menu
+#[derive(Component)]
+struct AutocompleteItem {
+    field: Entity,
+    index: usize,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Event fired when a suggestion is committed by click or Enter
+#[derive(Event, bevy::prelude::Message, Clone)]
+pub struct AutocompleteSelected {
+    pub field: Entity,
+    pub value: String,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Default cap on the number of suggestions shown
+pub const AUTOCOMPLETE_MAX_SUGGESTIONS: usize = 5;
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Spawn the suggestion menu below newly added autocomplete fields.
+fn autocomplete_setup_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut fields: Query<(Entity, &mut Autocomplete), Added<Autocomplete>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (entity, mut autocomplete) in fields.iter_mut() {
+        let menu = commands
+            .spawn((
+                AutocompleteMenu,
+                MenuBuilder::new().build(&theme),
+                ChildOf(entity),
+            ))
+            .insert((Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::vertical(Val::Px(Spacing::SMALL)),
+                ..default()
+            },))
+            .id();
+        autocomplete.menu = Some(menu);
+    }
+}
+
+/// Refill the suggestions when the field text changes.
+fn autocomplete_input_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut changes: MessageReader<TextFieldChangeEvent>,
+    mut fields: Query<(&MaterialTextField, &mut Autocomplete)>,
+    mut menus: Query<(&mut MaterialMenu, Option<&Children>), With<AutocompleteMenu>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for ev in changes.read() {
+        let Ok((field, mut autocomplete)) = fields.get_mut(ev.entity) else {
+            continue;
+        };
+        let Some(menu_entity) = autocomplete.menu else {
+            continue;
+        };
+        let Ok((mut menu, children)) = menus.get_mut(menu_entity) else {
+            continue;
+        };
+
+        autocomplete.suggestions = autocomplete.query(&ev.value);
+        autocomplete.highlighted = None;
+
+        if let Some(children) = children {
+            for child in children.iter() {
+                commands.entity(child).despawn();
+            }
+        }
+
+        let field_entity = ev.entity;
+        let text_color = theme.on_surface;
+        let suggestions = autocomplete.suggestions.clone();
+        commands.entity(menu_entity).with_children(|menu| {
+            for (index, suggestion) in suggestions.into_iter().enumerate() {
+                menu.spawn((
+                    MenuItemBuilder::new(suggestion.clone()).build(&theme),
+                    AutocompleteItem {
+                        field: field_entity,
+                        index,
+                    },
+                ))
+                .with_children(|item| {
+                    item.spawn((
+                        Text::new(suggestion),
+                        TypeRole::LabelLarge.text_bundle(),
+                        TextColor(text_color),
+                    ));
+                });
+            }
+        });
+
+        let open = field.focused && !autocomplete.suggestions.is_empty();
+        if menu.open != open {
+            menu.open = open;
+        }
+    }
+}
+
+/// Arrow keys move the highlight and Enter commits it.
+fn autocomplete_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut fields: Query<(Entity, &mut MaterialTextField, &mut Autocomplete)>,
+    mut menus: Query<&mut MaterialMenu, With<AutocompleteMenu>>,
+    mut selected: MessageWriter<AutocompleteSelected>,
+) {
+    for (entity, mut field, mut autocomplete) in fields.iter_mut() {
+        if !field.focused {
+            continue;
+        }
+        let Some(mut menu) = autocomplete.menu.and_then(|menu| menus.get_mut(menu).ok()) else {
+            continue;
+        };
+        if !menu.open {
+            continue;
+        }
+
+        if keys.just_pressed(KeyCode::ArrowDown) {
+            autocomplete.move_highlight(1);
+        }
+        if keys.just_pressed(KeyCode::ArrowUp) {
+            autocomplete.move_highlight(-1);
+        }
+
+        if keys.just_pressed(KeyCode::Enter) {
+            let Some(value) = autocomplete
+                .highlighted
+                .and_then(|index| autocomplete.suggestions.get(index))
+                .cloned()
+            else {
+                continue;
+            };
+
+            field.value = value.clone();
+            field.has_content = !field.value.is_empty();
+            menu.open = false;
+            selected.write(AutocompleteSelected {
+                field: entity,
+                value,
+            });
+        }
+    }
+}
+
+/// Commit a suggestion when its menu row is clicked.
+fn autocomplete_click_system(
+    mut selections: MessageReader<MenuItemSelectEvent>,
+    items: Query<&AutocompleteItem>,
+    mut fields: Query<(&mut MaterialTextField, &Autocomplete)>,
+    mut menus: Query<&mut MaterialMenu, With<AutocompleteMenu>>,
+    mut selected: MessageWriter<AutocompleteSelected>,
+) {
+    for ev in selections.read() {
+        let Ok(item) = items.get(ev.item_entity) else {
+            continue;
+        };
+        let Ok((mut field, autocomplete)) = fields.get_mut(item.field) else {
+            continue;
+        };
+        let Some(value) = autocomplete.suggestions.get(item.index).cloned() else {
+            continue;
+        };
+
+        field.value = value.clone();
+        field.has_content = !field.value.is_empty();
+        if let Ok(mut menu) = menus.get_mut(ev.menu_entity) {
+            menu.open = false;
+        }
+        selected.write(AutocompleteSelected {
+            field: item.field,
+            value,
+        });
+    }
+}
+
+/// Clear the highlight of closed menus and mirror it onto the menu rows.
+fn autocomplete_sync_system(
+    mut fields: Query<&mut Autocomplete>,
+    menus: Query<&MaterialMenu, With<AutocompleteMenu>>,
+    mut items: Query<(&AutocompleteItem, &mut MaterialMenuItem)>,
+) {
+    for mut autocomplete in fields.iter_mut() {
+        let open = autocomplete
+            .menu
+            .and_then(|menu| menus.get(menu).ok())
+            .is_some_and(|menu| menu.open);
+        if !open && autocomplete.highlighted.is_some() {
+            autocomplete.highlighted = None;
+        }
+    }
+
+    for (item, mut menu_item) in items.iter_mut() {
+        let highlighted = fields
+            .get(item.field)
+            .is_ok_and(|autocomplete| autocomplete.highlighted == Some(item.index));
+        if menu_item.selected != highlighted {
+            menu_item.selected = highlighted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fruits(text: &str) -> Vec<String> {
+        filter_suggestions(&["Apple", "Apricot", "Banana", "Grape", "Pineapple"], text)
+    }
+
+    #[test]
+    fn test_filter_suggestions() {
+        assert_eq!(fruits("ap"), vec!["Apple", "Apricot", "Grape", "Pineapple"]);
+        assert_eq!(fruits("BAN"), vec!["Banana"]);
+        assert!(fruits("  ").is_empty());
+        assert!(fruits("kiwi").is_empty());
+    }
+
+    #[test]
+    fn test_query_caps_suggestions() {
+        let autocomplete = Autocomplete::new(fruits).max_suggestions(2);
+        assert_eq!(autocomplete.query("ap"), vec!["Apple", "Apricot"]);
+    }
+
+    #[test]
+    fn test_autocomplete_highlight_wraps() {
+        let mut autocomplete = Autocomplete::new(fruits);
+        autocomplete.move_highlight(1);
+        assert_eq!(autocomplete.highlighted(), None);
+
+        autocomplete.suggestions = autocomplete.query("ap");
+        autocomplete.move_highlight(-1);
+        assert_eq!(autocomplete.highlighted(), Some(3));
+        autocomplete.move_highlight(1);
+        assert_eq!(autocomplete.highlighted(), Some(0));
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! Reference: <https://m3.material.io/components/badges/overview>
 
+use bevy::ecs::system::Command;
 use bevy::prelude::*;
 
 use crate::theme::MaterialTheme;
@@ -16,7 +17,14 @@ impl Plugin for BadgePlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
-        app.add_systems(Update, (badge_style_system, badge_theme_refresh_system));
+        app.add_systems(
+            Update,
+            (
+                badge_style_system,
+                badge_theme_refresh_system,
+                attached_badge_position_system,
+            ),
+        );
     }
 }
 
@@ -45,6 +53,8 @@ pub struct MaterialBadge {
     pub size: BadgeSize,
     /// Content (number or text) - None for small dot badge
     pub content: Option<String>,
+    /// Raw count for numeric badges; formatted against `max` when displayed
+    pub count: Option<u32>,
     /// Maximum number to display (shows "99+" if exceeded)
     pub max: u32,
     /// Whether the badge is visible
@@ -57,6 +67,7 @@ impl MaterialBadge {
         Self {
             size: BadgeSize::Small,
             content: None,
+            count: None,
             max: 999,
             visible: true,
         }
@@ -67,6 +78,7 @@ impl MaterialBadge {
         Self {
             size: BadgeSize::Large,
             content: Some(Self::format_count(count, 999)),
+            count: Some(count),
             max: 999,
             visible: true,
         }
@@ -74,21 +86,16 @@ impl MaterialBadge {
 
     /// Create a large badge with text
     pub fn text(text: impl Into<String>) -> Self {
-        Self {
-            size: BadgeSize::Large,
-            content: Some(text.into()),
-            max: 999,
-            visible: true,
-        }
+        let mut badge = Self::dot();
+        badge.set_text(text);
+        badge
     }
 
     /// Set the maximum count before showing "+"
     pub fn with_max(mut self, max: u32) -> Self {
         self.max = max;
-        if let Some(ref content) = self.content {
-            if let Ok(count) = content.trim_end_matches('+').parse::<u32>() {
-                self.content = Some(Self::format_count(count, max));
-            }
+        if let Some(count) = self.count {
+            self.content = Some(Self::format_count(count, max));
         }
         self
     }
@@ -102,19 +109,34 @@ impl MaterialBadge {
     /// Update the count
     pub fn set_count(&mut self, count: u32) {
         self.size = BadgeSize::Large;
+        self.count = Some(count);
         self.content = Some(Self::format_count(count, self.max));
     }
 
     /// Update the text
+    ///
+    /// An empty string turns the badge into a dot.
     pub fn set_text(&mut self, text: impl Into<String>) {
-        self.size = BadgeSize::Large;
-        self.content = Some(text.into());
+        let text = text.into();
+        self.count = None;
+        if text.is_empty() {
+            self.set_dot();
+        } else {
+            self.size = BadgeSize::Large;
+            self.content = Some(text);
+        }
     }
 
     /// Convert to dot badge
     pub fn set_dot(&mut self) {
         self.size = BadgeSize::Small;
         self.content = None;
+        self.count = None;
+    }
+
+    /// Whether the badge renders as a small dot (no text)
+    pub fn is_dot(&self) -> bool {
+        self.formatted_content().is_none()
     }
 
     /// Get the display text
@@ -122,6 +144,25 @@ impl MaterialBadge {
         self.content.as_deref()
     }
 
+    /// Get the text to render, applying `max` to numeric content.
+    ///
+    /// Returns `None` for dot badges.
+    pub fn formatted_content(&self) -> Option<String> {
+        match self.count {
+            Some(count) => Some(Self::format_count(count, self.max)),
+            None => self.content.clone().filter(|text| !text.is_empty()),
+        }
+    }
+
+    /// Size resolved from content: large when there is text, small dot otherwise
+    pub fn resolved_size(&self) -> BadgeSize {
+        if self.is_dot() {
+            BadgeSize::Small
+        } else {
+            BadgeSize::Large
+        }
+    }
+
     /// Format a count with max limit
     fn format_count(count: u32, max: u32) -> String {
         if count > max {
@@ -143,19 +184,15 @@ impl MaterialBadge {
 
     /// Get the badge width
     pub fn width(&self) -> f32 {
-        match self.size {
-            BadgeSize::Small => BADGE_SIZE_SMALL,
-            BadgeSize::Large => {
+        match self.formatted_content() {
+            None => BADGE_SIZE_SMALL,
+            Some(content) => {
                 // Minimum width is BADGE_SIZE_LARGE, grows with content
-                if let Some(ref content) = self.content {
-                    let char_count = content.len();
-                    if char_count <= 1 {
-                        BADGE_SIZE_LARGE
-                    } else {
-                        BADGE_SIZE_LARGE + (char_count as f32 - 1.0) * 6.0
-                    }
-                } else {
+                let char_count = content.chars().count();
+                if char_count <= 1 {
                     BADGE_SIZE_LARGE
+                } else {
+                    BADGE_SIZE_LARGE + (char_count as f32 - 1.0) * 6.0
                 }
             }
         }
@@ -163,7 +200,7 @@ impl MaterialBadge {
 
     /// Get the badge height
     pub fn height(&self) -> f32 {
-        match self.size {
+        match self.resolved_size() {
             BadgeSize::Small => BADGE_SIZE_SMALL,
             BadgeSize::Large => BADGE_SIZE_LARGE,
         }
@@ -180,6 +217,16 @@ impl Default for MaterialBadge {
 #[derive(Component)]
 pub struct BadgeContent;
 
+/// A badge pinned to the top-right corner of its target (its parent).
+///
+/// The badge is positioned relative to the target's size, so it follows the corner when the
+/// target resizes. Added by [`AttachBadge`], [`spawn_badge_on`] and [`BadgeBuilder::attach_to`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AttachedBadge {
+    /// Entity the badge is attached to
+    pub target: Entity,
+}
+
 // ============================================================================
 // Dimensions
 // ============================================================================
@@ -200,6 +247,7 @@ pub const BADGE_OFFSET: f32 = -4.0;
 /// Builder for creating badges
 pub struct BadgeBuilder {
     badge: MaterialBadge,
+    target: Option<Entity>,
 }
 
 impl BadgeBuilder {
@@ -207,6 +255,7 @@ impl BadgeBuilder {
     pub fn dot() -> Self {
         Self {
             badge: MaterialBadge::dot(),
+            target: None,
         }
     }
 
@@ -214,6 +263,7 @@ impl BadgeBuilder {
     pub fn count(count: u32) -> Self {
         Self {
             badge: MaterialBadge::count(count),
+            target: None,
         }
     }
 
@@ -221,6 +271,7 @@ impl BadgeBuilder {
     pub fn text(text: impl Into<String>) -> Self {
         Self {
             badge: MaterialBadge::text(text),
+            target: None,
         }
     }
 
@@ -230,11 +281,27 @@ impl BadgeBuilder {
         self
     }
 
+    /// Attach the badge to the top-right corner of `target` (e.g. an icon button, or the
+    /// icon of a navigation item) when spawned with [`BadgeBuilder::spawn`].
+    pub fn attach_to(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Spawn the badge, attaching it to its target if one was set
+    pub fn spawn(self, commands: &mut Commands, theme: &MaterialTheme) -> Entity {
+        match self.target {
+            Some(target) => spawn_badge_on(commands, theme, self.badge, target),
+            None => spawn_badge(commands, theme, self.badge),
+        }
+    }
+
     /// Build the badge bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.badge.background_color(theme);
         let width = self.badge.width();
         let height = self.badge.height();
+        let padding = badge_padding(&self.badge);
 
         (
             self.badge,
@@ -248,7 +315,7 @@ impl BadgeBuilder {
                 min_height: Val::Px(height),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                padding: UiRect::axes(Val::Px(BADGE_PADDING), Val::Px(0.0)),
+                padding,
                 ..default()
             },
             BackgroundColor(bg_color),
@@ -257,6 +324,15 @@ impl BadgeBuilder {
     }
 }
 
+/// Dot badges have no horizontal padding so they stay at `BADGE_SIZE_SMALL`
+fn badge_padding(badge: &MaterialBadge) -> UiRect {
+    if badge.is_dot() {
+        UiRect::all(Val::Px(0.0))
+    } else {
+        UiRect::axes(Val::Px(BADGE_PADDING), Val::Px(0.0))
+    }
+}
+
 // ============================================================================
 // Spawn Traits for ChildSpawnerCommands
 // ============================================================================
@@ -279,7 +355,7 @@ pub trait SpawnBadgeChild {
 impl SpawnBadgeChild for ChildSpawnerCommands<'_> {
     fn spawn_small_badge(&mut self, theme: &MaterialTheme) {
         // Small badge is just the dot indicator without content
-        self.spawn(BadgeBuilder::count(0).build(theme));
+        self.spawn(BadgeBuilder::dot().build(theme));
     }
 
     fn spawn_badge_count(&mut self, theme: &MaterialTheme, count: u32) {
@@ -291,7 +367,7 @@ impl SpawnBadgeChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_badge_with(&mut self, theme: &MaterialTheme, builder: BadgeBuilder) {
-        let content = builder.badge.content.clone();
+        let content = builder.badge.formatted_content();
         let content_color = builder.badge.content_color(theme);
 
         self.spawn(builder.build(theme)).with_children(|badge| {
@@ -317,12 +393,13 @@ impl SpawnBadgeChild for ChildSpawnerCommands<'_> {
 
 /// Spawn a badge with content
 pub fn spawn_badge(commands: &mut Commands, theme: &MaterialTheme, badge: MaterialBadge) -> Entity {
-    let content = badge.content.clone();
+    let content = badge.formatted_content();
     let content_color = badge.content_color(theme);
     let bg_color = badge.background_color(theme);
     let width = badge.width();
     let height = badge.height();
     let visible = badge.visible;
+    let padding = badge_padding(&badge);
 
     let mut entity = commands.spawn((
         badge,
@@ -336,7 +413,7 @@ pub fn spawn_badge(commands: &mut Commands, theme: &MaterialTheme, badge: Materi
             min_height: Val::Px(height),
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
-            padding: UiRect::axes(Val::Px(BADGE_PADDING), Val::Px(0.0)),
+            padding,
             display: if visible {
                 Display::Flex
             } else {
@@ -366,7 +443,7 @@ pub fn spawn_badge(commands: &mut Commands, theme: &MaterialTheme, badge: Materi
     entity.id()
 }
 
-/// Spawn a badge attached to a parent (like an icon button)
+/// Spawn a badge attached to the top-right corner of a parent (like an icon button)
 pub fn spawn_badge_on(
     commands: &mut Commands,
     theme: &MaterialTheme,
@@ -374,100 +451,214 @@ pub fn spawn_badge_on(
     parent: Entity,
 ) -> Entity {
     let entity = spawn_badge(commands, theme, badge);
-    commands.entity(entity).insert(ChildOf(parent));
+    commands.queue(AttachBadge {
+        badge: entity,
+        target: parent,
+    });
     entity
 }
 
+/// Command that attaches an existing badge to the top-right corner of `target`.
+///
+/// Parents the badge to the target and marks it with [`AttachedBadge`]. Does nothing if
+/// either entity no longer exists.
+#[derive(Debug, Clone, Copy)]
+pub struct AttachBadge {
+    /// The badge entity
+    pub badge: Entity,
+    /// The entity to attach to
+    pub target: Entity,
+}
+
+impl Command for AttachBadge {
+    fn apply(self, world: &mut World) {
+        if world.get_entity(self.target).is_err() {
+            return;
+        }
+        let Ok(mut badge) = world.get_entity_mut(self.badge) else {
+            return;
+        };
+        badge.insert((
+            ChildOf(self.target),
+            AttachedBadge {
+                target: self.target,
+            },
+        ));
+    }
+}
+
+/// Margin that centers the leading edge of an attached badge on the target's corner.
+///
+/// Badges grow away from the target as their content gets longer.
+fn attached_badge_margin(badge: &MaterialBadge) -> UiRect {
+    let half = badge.height() / 2.0;
+    UiRect {
+        left: Val::Px(-half),
+        top: Val::Px(-half),
+        ..default()
+    }
+}
+
 // ============================================================================
 // Systems
 // ============================================================================
 
+/// Spawn the text child a badge needs to show `text`
+fn spawn_badge_content(commands: &mut Commands, badge: Entity, text: String, color: Color) {
+    commands.spawn((
+        BadgeContent,
+        Text::new(text),
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(color),
+        ChildOf(badge),
+    ));
+}
+
+/// Apply size, color, and text content to a badge and its text child.
+///
+/// Adds the text child when a dot badge gains content, and removes it when a
+/// badge turns back into a dot.
+fn sync_badge(
+    commands: &mut Commands,
+    theme: &MaterialTheme,
+    entity: Entity,
+    badge: &MaterialBadge,
+    node: &mut Node,
+    bg_color: &mut BackgroundColor,
+    border_radius: &mut BorderRadius,
+    children: Option<&Children>,
+    badge_texts: &mut Query<(&mut Text, &mut TextColor), With<BadgeContent>>,
+) {
+    let width = badge.width();
+    let height = badge.height();
+
+    node.width = Val::Px(width);
+    node.height = Val::Px(height);
+    node.min_width = Val::Px(width);
+    node.min_height = Val::Px(height);
+    node.padding = badge_padding(badge);
+    node.display = if badge.visible {
+        Display::Flex
+    } else {
+        Display::None
+    };
+
+    *bg_color = BackgroundColor(badge.background_color(theme));
+    *border_radius = BorderRadius::all(Val::Px(height / 2.0));
+
+    let content_color = badge.content_color(theme);
+    let text_child = children.and_then(|children| {
+        children
+            .iter()
+            .find(|child| badge_texts.get(*child).is_ok())
+    });
+
+    match (badge.formatted_content(), text_child) {
+        (Some(content), Some(child)) => {
+            if let Ok((mut text, mut color)) = badge_texts.get_mut(child) {
+                if text.0 != content {
+                    text.0 = content;
+                }
+                color.0 = content_color;
+            }
+        }
+        (Some(content), None) => spawn_badge_content(commands, entity, content, content_color),
+        (None, Some(child)) => commands.entity(child).despawn(),
+        (None, None) => {}
+    }
+}
+
 /// System to update badge styles
 fn badge_style_system(
+    mut commands: Commands,
     theme: Option<Res<MaterialTheme>>,
     mut badges: Query<
         (
+            Entity,
             &MaterialBadge,
             &mut Node,
             &mut BackgroundColor,
             &mut BorderRadius,
+            Option<&Children>,
         ),
         Changed<MaterialBadge>,
     >,
-    mut badge_texts: Query<(&ChildOf, &mut Text, &mut TextColor), With<BadgeContent>>,
+    mut badge_texts: Query<(&mut Text, &mut TextColor), With<BadgeContent>>,
 ) {
     let Some(theme) = theme else { return };
 
-    for (badge, mut node, mut bg_color, mut border_radius) in badges.iter_mut() {
-        let width = badge.width();
-        let height = badge.height();
-
-        node.width = Val::Px(width);
-        node.height = Val::Px(height);
-        node.min_width = Val::Px(width);
-        node.min_height = Val::Px(height);
-        node.display = if badge.visible {
-            Display::Flex
-        } else {
-            Display::None
-        };
-
-        *bg_color = BackgroundColor(badge.background_color(&theme));
-        *border_radius = BorderRadius::all(Val::Px(height / 2.0));
-    }
-
-    // Update text content
-    for (parent, mut text, mut color) in badge_texts.iter_mut() {
-        if let Ok((badge, _, _, _)) = badges.get(parent.parent()) {
-            if let Some(content) = &badge.content {
-                **text = content.clone();
-            }
-            color.0 = badge.content_color(&theme);
-        }
+    for (entity, badge, mut node, mut bg_color, mut border_radius, children) in badges.iter_mut() {
+        sync_badge(
+            &mut commands,
+            &theme,
+            entity,
+            badge,
+            &mut node,
+            &mut bg_color,
+            &mut border_radius,
+            children,
+            &mut badge_texts,
+        );
     }
 }
 
 /// Refresh badge visuals when the theme changes.
 fn badge_theme_refresh_system(
+    mut commands: Commands,
     theme: Option<Res<MaterialTheme>>,
     mut badges: Query<(
+        Entity,
         &MaterialBadge,
         &mut Node,
         &mut BackgroundColor,
         &mut BorderRadius,
+        Option<&Children>,
     )>,
-    mut badge_texts: Query<(&ChildOf, &mut Text, &mut TextColor), With<BadgeContent>>,
+    mut badge_texts: Query<(&mut Text, &mut TextColor), With<BadgeContent>>,
 ) {
     let Some(theme) = theme else { return };
     if !theme.is_changed() {
         return;
     }
 
-    for (badge, mut node, mut bg_color, mut border_radius) in badges.iter_mut() {
-        let width = badge.width();
-        let height = badge.height();
-
-        node.width = Val::Px(width);
-        node.height = Val::Px(height);
-        node.min_width = Val::Px(width);
-        node.min_height = Val::Px(height);
-        node.display = if badge.visible {
-            Display::Flex
-        } else {
-            Display::None
-        };
-
-        *bg_color = BackgroundColor(badge.background_color(&theme));
-        *border_radius = BorderRadius::all(Val::Px(height / 2.0));
+    for (entity, badge, mut node, mut bg_color, mut border_radius, children) in badges.iter_mut() {
+        sync_badge(
+            &mut commands,
+            &theme,
+            entity,
+            badge,
+            &mut node,
+            &mut bg_color,
+            &mut border_radius,
+            children,
+            &mut badge_texts,
+        );
     }
+}
 
-    for (parent, mut text, mut color) in badge_texts.iter_mut() {
-        if let Ok(badge) = badges.get(parent.parent()).map(|(b, _, _, _)| b) {
-            if let Some(content) = &badge.content {
-                **text = content.clone();
-            }
-            color.0 = badge.content_color(&theme);
-        }
+/// Pin attached badges to their target's top-right corner.
+///
+/// `left: 100%` is resolved against the target's width during layout, so the badge tracks
+/// the corner when the target resizes.
+fn attached_badge_position_system(
+    mut badges: Query<
+        (&MaterialBadge, &mut Node),
+        (
+            With<AttachedBadge>,
+            Or<(Added<AttachedBadge>, Changed<MaterialBadge>)>,
+        ),
+    >,
+) {
+    for (badge, mut node) in badges.iter_mut() {
+        node.position_type = PositionType::Absolute;
+        node.left = Val::Percent(100.0);
+        node.top = Val::Px(0.0);
+        node.right = Val::Auto;
+        node.bottom = Val::Auto;
+        node.margin = attached_badge_margin(badge);
     }
 }
 
@@ -543,4 +734,51 @@ mod tests {
         assert_eq!(badge.size, BadgeSize::Small);
         assert!(badge.content.is_none());
     }
+
+    #[test]
+    fn test_badge_max_formatting() {
+        let mut badge = MaterialBadge::count(1500);
+        assert_eq!(badge.formatted_content(), Some("999+".to_string()));
+
+        // Changing max directly is applied when formatting.
+        badge.max = 99;
+        assert_eq!(badge.formatted_content(), Some("99+".to_string()));
+
+        let badge = BadgeBuilder::count(42).max(9).badge;
+        assert_eq!(badge.content, Some("9+".to_string()));
+        assert_eq!(badge.count, Some(42));
+    }
+
+    #[test]
+    fn test_badge_auto_size() {
+        let mut badge = MaterialBadge::dot();
+        assert!(badge.is_dot());
+        assert_eq!(badge.resolved_size(), BadgeSize::Small);
+        assert_eq!(badge.height(), BADGE_SIZE_SMALL);
+
+        badge.set_count(3);
+        assert_eq!(badge.resolved_size(), BadgeSize::Large);
+        assert_eq!(badge.height(), BADGE_SIZE_LARGE);
+
+        badge.set_text("");
+        assert!(badge.is_dot());
+        assert_eq!(badge.width(), BADGE_SIZE_SMALL);
+    }
+
+    #[test]
+    fn test_attached_badge_margin() {
+        let dot = attached_badge_margin(&MaterialBadge::dot());
+        assert_eq!(dot.left, Val::Px(-BADGE_SIZE_SMALL / 2.0));
+        assert_eq!(dot.top, Val::Px(-BADGE_SIZE_SMALL / 2.0));
+
+        let count = attached_badge_margin(&MaterialBadge::count(120));
+        assert_eq!(count.left, Val::Px(-BADGE_SIZE_LARGE / 2.0));
+    }
+
+    #[test]
+    fn test_badge_builder_attach_to() {
+        let target = Entity::from_raw_u32(7).unwrap();
+        let builder = BadgeBuilder::count(3).attach_to(target);
+        assert_eq!(builder.target, Some(target));
+    }
 }
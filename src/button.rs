@@ -14,10 +14,15 @@ use bevy::prelude::*;
 use bevy::ui::{BoxShadow, Val};
 
 use crate::{
+    disabled::{sync_disabled_system, DisabledSource},
     elevation::Elevation,
+    motion::{sync_state_layer_system, StateLayer, StateLayerSource},
     ripple::RippleHost,
-    theme::{blend_state_layer, MaterialTheme},
-    tokens::{CornerRadius, Spacing},
+    semantics::{checked_value, sync_semantics_system, Role, Semantic},
+    text_field::MaterialTextField,
+    theme::MaterialTheme,
+    tokens::{CornerRadius, Opacity, Spacing},
+    typography::TypeRole,
 };
 
 /// Plugin for the button component
@@ -36,6 +41,11 @@ impl Plugin for ButtonPlugin {
                 button_label_style_system,
                 button_theme_refresh_system,
                 button_shadow_system,
+                button_shortcut_system,
+                button_shortcut_hint_system,
+                sync_state_layer_system::<MaterialButton>,
+                sync_disabled_system::<MaterialButton>,
+                sync_semantics_system::<MaterialButton>,
             ),
         );
     }
@@ -111,6 +121,8 @@ pub struct MaterialButton {
     pub min_width: Option<f32>,
     /// Custom minimum height
     pub min_height: Option<f32>,
+    /// Whether the button stretches to its parent's width
+    pub full_width: bool,
     /// Custom background color override (for all states)
     pub custom_background_color: Option<Color>,
     /// Custom text color override
@@ -146,6 +158,7 @@ impl MaterialButton {
             corner_radius: None,
             min_width: None,
             min_height: None,
+            full_width: false,
             custom_background_color: None,
             custom_text_color: None,
             stroke_width: 1.0,
@@ -218,6 +231,12 @@ impl MaterialButton {
         self
     }
 
+    /// Stretch the button to its parent's width, with the label centered
+    pub fn full_width(mut self, full_width: bool) -> Self {
+        self.full_width = full_width;
+        self
+    }
+
     /// Set custom background color (overrides theme)
     pub fn custom_background_color(mut self, color: Color) -> Self {
         self.custom_background_color = Some(color);
@@ -268,11 +287,11 @@ impl MaterialButton {
 
     /// Get the background color based on state and theme
     ///
-    /// MD3 uses state layers to indicate hover/pressed states.
-    /// The state layer is a semi-transparent overlay of the "on" color.
+    /// Hover/pressed feedback is drawn by the button's `StateLayer` overlay,
+    /// so this is the resting container color.
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.12);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
         }
 
         // Use custom background color if set
@@ -280,48 +299,18 @@ impl MaterialButton {
             return custom_color;
         }
 
-        let state_opacity = self.state_layer_opacity();
-
         match self.variant {
-            ButtonVariant::Elevated => {
-                // State layer uses primary color on elevated buttons
-                blend_state_layer(theme.surface_container_low, theme.primary, state_opacity)
-            }
-            ButtonVariant::Filled => {
-                // State layer uses on_primary color
-                blend_state_layer(theme.primary, theme.on_primary, state_opacity)
-            }
-            ButtonVariant::FilledTonal => {
-                // State layer uses on_secondary_container color
-                blend_state_layer(
-                    theme.secondary_container,
-                    theme.on_secondary_container,
-                    state_opacity,
-                )
-            }
-            ButtonVariant::Outlined => {
-                // Transparent background with primary state layer
-                if state_opacity > 0.0 {
-                    theme.primary.with_alpha(state_opacity)
-                } else {
-                    Color::NONE
-                }
-            }
-            ButtonVariant::Text => {
-                // Transparent background with primary state layer
-                if state_opacity > 0.0 {
-                    theme.primary.with_alpha(state_opacity)
-                } else {
-                    Color::NONE
-                }
-            }
+            ButtonVariant::Elevated => theme.surface_container_low,
+            ButtonVariant::Filled => theme.primary,
+            ButtonVariant::FilledTonal => theme.secondary_container,
+            ButtonVariant::Outlined | ButtonVariant::Text => Color::NONE,
         }
     }
 
     /// Get the text color based on state and theme
     pub fn text_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         // Use custom text color if set
@@ -341,7 +330,7 @@ impl MaterialButton {
     /// Get the border color based on state and theme
     pub fn border_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.12);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
         }
 
         match self.variant {
@@ -391,6 +380,42 @@ impl MaterialButton {
     }
 }
 
+impl DisabledSource for MaterialButton {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Semantic for MaterialButton {
+    fn semantic_role(&self) -> Role {
+        Role::Button
+    }
+
+    fn semantic_label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        self.checkable.then(|| checked_value(self.checked))
+    }
+}
+
+impl StateLayerSource for MaterialButton {
+    fn state_layer_color(&self, theme: &MaterialTheme) -> Color {
+        match self.variant {
+            ButtonVariant::Filled => theme.on_primary,
+            ButtonVariant::FilledTonal => theme.on_secondary_container,
+            ButtonVariant::Elevated | ButtonVariant::Outlined | ButtonVariant::Text => {
+                theme.primary
+            }
+        }
+    }
+
+    fn state_layer_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event fired when a button is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct ButtonClickEvent {
@@ -398,6 +423,111 @@ pub struct ButtonClickEvent {
     pub entity: Entity,
 }
 
+/// A key plus the modifiers that must be held with it, e.g. Ctrl+S
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    /// The key that triggers the combo
+    pub key: KeyCode,
+    /// Ctrl (or Cmd) must be held
+    pub ctrl: bool,
+    /// Shift must be held
+    pub shift: bool,
+    /// Alt must be held
+    pub alt: bool,
+}
+
+impl KeyCombo {
+    /// A combo for `key` without modifiers
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Require Ctrl (or Cmd)
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Require Shift
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Require Alt
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Whether any modifier is required
+    pub fn has_modifiers(&self) -> bool {
+        self.ctrl || self.shift || self.alt
+    }
+
+    /// Whether the combo was pressed this frame with exactly its modifiers held
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        let ctrl = keys.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ]);
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+
+        keys.just_pressed(self.key) && ctrl == self.ctrl && shift == self.shift && alt == self.alt
+    }
+}
+
+impl From<KeyCode> for KeyCombo {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key)
+    }
+}
+
+impl std::fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+
+        let key = format!("{:?}", self.key);
+        let key = key
+            .strip_prefix("Key")
+            .or_else(|| key.strip_prefix("Digit"))
+            .unwrap_or(&key);
+        f.write_str(key)
+    }
+}
+
+/// Keyboard shortcut that clicks a button
+///
+/// When the combo is pressed, a [`ButtonClickEvent`] is sent for the button as if
+/// it had been clicked. Disabled buttons ignore their shortcut, and shortcuts
+/// without modifiers are ignored while a text field has focus.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonShortcut(pub KeyCombo);
+
+/// Show a button's [`ButtonShortcut`] as a trailing label, e.g. "Save  Ctrl+S"
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ButtonShortcutHint;
+
+/// Trailing text spawned for a [`ButtonShortcutHint`]
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ButtonShortcutLabel;
+
 /// System to handle button interactions
 fn button_interaction_system(
     mut interaction_query: Query<
@@ -429,6 +559,75 @@ fn button_interaction_system(
     }
 }
 
+/// System to click buttons whose shortcut was pressed
+fn button_shortcut_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    buttons: Query<(Entity, &MaterialButton, &ButtonShortcut)>,
+    text_fields: Query<&MaterialTextField>,
+    mut click_events: MessageWriter<ButtonClickEvent>,
+) {
+    let Some(keys) = keys else { return };
+    if keys.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    let typing = text_fields.iter().any(|field| field.focused);
+    for (entity, button, shortcut) in buttons.iter() {
+        if button.disabled || (typing && !shortcut.0.has_modifiers()) {
+            continue;
+        }
+        if shortcut.0.just_pressed(&keys) {
+            click_events.write(ButtonClickEvent { entity });
+        }
+    }
+}
+
+/// System to spawn or update the trailing shortcut label of hinted buttons
+fn button_shortcut_hint_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    buttons: Query<
+        (Entity, &MaterialButton, &ButtonShortcut, Option<&Children>),
+        (
+            With<ButtonShortcutHint>,
+            Or<(Added<ButtonShortcutHint>, Changed<ButtonShortcut>)>,
+        ),
+    >,
+    mut labels: Query<&mut Text, With<ButtonShortcutLabel>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (entity, button, shortcut, children) in buttons.iter() {
+        let hint = shortcut.0.to_string();
+        let existing = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .find(|child| labels.contains(*child));
+
+        if let Some(label) = existing {
+            if let Ok(mut text) = labels.get_mut(label) {
+                text.0 = hint;
+            }
+            continue;
+        }
+
+        commands.entity(entity).with_children(|btn| {
+            btn.spawn((
+                ButtonShortcutLabel,
+                // Tagged as a label so it follows the button's text color.
+                ButtonLabel,
+                Text::new(hint),
+                TextColor(button.text_color(&theme)),
+                TypeRole::LabelLarge.text_bundle(),
+                Node {
+                    margin: UiRect::left(Val::Px(Spacing::MEDIUM)),
+                    ..default()
+                },
+            ));
+        });
+    }
+}
+
 /// System to update button visual styles based on state
 fn button_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -520,13 +719,21 @@ fn button_shadow_system(
 /// ```
 pub struct MaterialButtonBuilder {
     button: MaterialButton,
+    min_width: Option<Val>,
+    height: Option<Val>,
 }
 
 impl MaterialButtonBuilder {
     /// Create a new button builder
     pub fn new(label: impl Into<String>) -> Self {
+        Self::from_button(MaterialButton::new(label))
+    }
+
+    fn from_button(button: MaterialButton) -> Self {
         Self {
-            button: MaterialButton::new(label),
+            button,
+            min_width: None,
+            height: None,
         }
     }
 
@@ -585,29 +792,69 @@ impl MaterialButtonBuilder {
         self
     }
 
-    /// Build the button bundle with native BoxShadow support
-    pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
-        let bg_color = self.button.background_color(theme);
-        let border_color = self.button.border_color(theme);
+    /// Set the minimum width (e.g. to meet a touch target or align a form row)
+    pub fn min_width(mut self, width: Val) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Set a fixed height
+    pub fn height(mut self, height: Val) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Stretch the button to its parent's width, with the label centered
+    pub fn full_width(mut self) -> Self {
+        self.button.full_width = true;
+        self
+    }
+
+    /// Layout node shared by both build variants
+    fn node(&self) -> Node {
         let border_width = if self.button.variant == ButtonVariant::Outlined {
             1.0
         } else {
             0.0
         };
+
+        Node {
+            width: if self.button.full_width {
+                Val::Percent(100.0)
+            } else {
+                Val::Auto
+            },
+            min_width: self
+                .min_width
+                .or(self.button.min_width.map(Val::Px))
+                .unwrap_or(Val::Auto),
+            height: self.height.unwrap_or(Val::Auto),
+            min_height: self.button.min_height.map(Val::Px).unwrap_or(Val::Auto),
+            padding: UiRect::axes(Val::Px(Spacing::EXTRA_LARGE), Val::Px(Spacing::MEDIUM)),
+            border: UiRect::all(Val::Px(border_width)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+
+    /// Build the button bundle with native BoxShadow support
+    pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
+        let bg_color = self.button.background_color(theme);
+        let border_color = self.button.border_color(theme);
         let elevation = self.button.elevation();
         let corner_radius = self.button.effective_corner_radius();
+        let state_layer = StateLayer::new(self.button.state_layer_color(theme));
+        let node = self.node();
+        let semantics = self.button.semantics();
 
         (
             self.button,
+            semantics,
             Button,
             RippleHost::new(),
-            Node {
-                padding: UiRect::axes(Val::Px(Spacing::EXTRA_LARGE), Val::Px(Spacing::MEDIUM)),
-                border: UiRect::all(Val::Px(border_width)),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
+            state_layer,
+            node,
             BackgroundColor(bg_color),
             BorderColor::all(border_color),
             BorderRadius::all(Val::Px(corner_radius)),
@@ -620,24 +867,18 @@ impl MaterialButtonBuilder {
     pub fn build_without_shadow(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.button.background_color(theme);
         let border_color = self.button.border_color(theme);
-        let border_width = if self.button.variant == ButtonVariant::Outlined {
-            1.0
-        } else {
-            0.0
-        };
         let corner_radius = self.button.effective_corner_radius();
+        let state_layer = StateLayer::new(self.button.state_layer_color(theme));
+        let node = self.node();
+        let semantics = self.button.semantics();
 
         (
             self.button,
+            semantics,
             Button,
             RippleHost::new(),
-            Node {
-                padding: UiRect::axes(Val::Px(Spacing::EXTRA_LARGE), Val::Px(Spacing::MEDIUM)),
-                border: UiRect::all(Val::Px(border_width)),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
+            state_layer,
+            node,
             BackgroundColor(bg_color),
             BorderColor::all(border_color),
             BorderRadius::all(Val::Px(corner_radius)),
@@ -669,10 +910,7 @@ pub fn spawn_material_button(
             parent.spawn((
                 Text::new(label_text),
                 TextColor(text_color),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
+                TypeRole::LabelLarge.text_bundle(),
             ));
         })
         .id()
@@ -772,10 +1010,7 @@ impl SpawnButtonChild for ChildSpawnerCommands<'_> {
                 ButtonLabel,
                 Text::new(label_str),
                 TextColor(text_color),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
+                TypeRole::LabelLarge.text_bundle(),
             ));
         });
     }
@@ -803,17 +1038,14 @@ impl SpawnButtonChild for ChildSpawnerCommands<'_> {
     fn spawn_button_with(&mut self, theme: &MaterialTheme, button: MaterialButton) {
         let text_color = button.text_color(theme);
         let label_str = button.label.clone();
-        let builder = MaterialButtonBuilder { button };
+        let builder = MaterialButtonBuilder::from_button(button);
 
         self.spawn(builder.build(theme)).with_children(|btn| {
             btn.spawn((
                 ButtonLabel,
                 Text::new(label_str),
                 TextColor(text_color),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
+                TypeRole::LabelLarge.text_bundle(),
             ));
         });
     }
@@ -1095,4 +1327,90 @@ mod tests {
         let builder = MaterialButtonBuilder::new("Test").disabled(true);
         assert!(builder.button.disabled);
     }
+
+    #[test]
+    fn test_button_builder_sizing() {
+        let node = MaterialButtonBuilder::new("Test").node();
+        assert_eq!(node.width, Val::Auto);
+        assert_eq!(node.min_width, Val::Auto);
+
+        let node = MaterialButtonBuilder::new("Test")
+            .full_width()
+            .min_width(Val::Px(120.0))
+            .height(Val::Px(48.0))
+            .node();
+        assert_eq!(node.width, Val::Percent(100.0));
+        assert_eq!(node.min_width, Val::Px(120.0));
+        assert_eq!(node.height, Val::Px(48.0));
+        assert_eq!(node.justify_content, JustifyContent::Center);
+
+        // Component-level minimums apply when the builder sets none.
+        let node =
+            MaterialButtonBuilder::from_button(MaterialButton::new("Test").min_width(64.0)).node();
+        assert_eq!(node.min_width, Val::Px(64.0));
+    }
+
+    // ============================================================================
+    // Shortcut Tests
+    // ============================================================================
+
+    #[test]
+    fn test_key_combo_display() {
+        assert_eq!(KeyCombo::new(KeyCode::KeyS).ctrl().to_string(), "Ctrl+S");
+        assert_eq!(
+            KeyCombo::new(KeyCode::Digit1).ctrl().shift().to_string(),
+            "Ctrl+Shift+1"
+        );
+        assert_eq!(KeyCombo::new(KeyCode::Delete).to_string(), "Delete");
+    }
+
+    #[test]
+    fn test_key_combo_requires_exact_modifiers() {
+        let combo = KeyCombo::new(KeyCode::KeyS).ctrl();
+        let mut keys = ButtonInput::<KeyCode>::default();
+
+        keys.press(KeyCode::KeyS);
+        assert!(!combo.just_pressed(&keys));
+
+        keys.clear();
+        keys.release(KeyCode::KeyS);
+        keys.press(KeyCode::ControlLeft);
+        keys.press(KeyCode::KeyS);
+        assert!(combo.just_pressed(&keys));
+
+        keys.clear();
+        keys.release(KeyCode::KeyS);
+        keys.press(KeyCode::ShiftLeft);
+        keys.press(KeyCode::KeyS);
+        assert!(!combo.just_pressed(&keys));
+    }
+
+    #[test]
+    fn test_shortcut_skips_disabled_buttons() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_message::<ButtonClickEvent>()
+            .add_systems(Update, button_shortcut_system);
+
+        let shortcut = ButtonShortcut(KeyCode::Enter.into());
+        let enabled = app
+            .world_mut()
+            .spawn((MaterialButton::new("Save"), shortcut))
+            .id();
+        app.world_mut()
+            .spawn((MaterialButton::new("Delete").disabled(true), shortcut));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        app.update();
+
+        let clicked: Vec<Entity> = app
+            .world()
+            .resource::<Messages<ButtonClickEvent>>()
+            .iter_current_update_messages()
+            .map(|ev| ev.entity)
+            .collect();
+        assert_eq!(clicked, vec![enabled]);
+    }
 }
@@ -13,9 +13,13 @@ use bevy::prelude::*;
 use bevy::ui::BoxShadow;
 
 use crate::{
-    elevation::Elevation,
+    elevation::{Elevation, ElevationTintSettings},
+    focus::Focusable,
+    motion::{ease_standard, ReducedMotion, StateLayer},
+    ripple::RippleHost,
     theme::{blend_state_layer, MaterialTheme},
-    tokens::{CornerRadius, Spacing},
+    tokens::{CornerRadius, Duration, Spacing},
+    typography::TypeRole,
 };
 
 /// Plugin for the card component
@@ -29,7 +33,9 @@ impl Plugin for CardPlugin {
         app.add_message::<CardClickEvent>().add_systems(
             Update,
             (
+                card_ripple_setup_system,
                 card_interaction_system,
+                card_focus_system,
                 card_style_system,
                 card_theme_refresh_system,
                 card_shadow_system,
@@ -59,9 +65,13 @@ pub struct MaterialCard {
     pub clickable: bool,
     /// Whether the card is draggable
     pub draggable: bool,
+    /// Whether elevation changes on hover/press are animated (implies clickable)
+    pub interactive: bool,
     /// Interaction states
     pub pressed: bool,
     pub hovered: bool,
+    /// Whether the card has keyboard focus (focus ring visible)
+    pub focused: bool,
 }
 
 impl MaterialCard {
@@ -71,8 +81,10 @@ impl MaterialCard {
             variant: CardVariant::default(),
             clickable: false,
             draggable: false,
+            interactive: false,
             pressed: false,
             hovered: false,
+            focused: false,
         }
     }
 
@@ -94,6 +106,13 @@ impl MaterialCard {
         self
     }
 
+    /// Make the card clickable with animated elevation feedback
+    pub fn interactive(mut self) -> Self {
+        self.clickable = true;
+        self.interactive = true;
+        self
+    }
+
     /// Get the background color with state layer applied
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
         let base = match self.variant {
@@ -111,6 +130,15 @@ impl MaterialCard {
         }
     }
 
+    /// Get the background color with the elevation surface tint applied
+    pub fn tinted_background_color(
+        &self,
+        theme: &MaterialTheme,
+        tint: &ElevationTintSettings,
+    ) -> Color {
+        tint.apply(self.elevation(), self.background_color(theme), theme)
+    }
+
     /// Get the border color
     pub fn border_color(&self, theme: &MaterialTheme) -> Color {
         match self.variant {
@@ -124,16 +152,16 @@ impl MaterialCard {
         match self.variant {
             CardVariant::Elevated => {
                 if self.pressed || self.hovered {
-                    Elevation::Level2
+                    Elevation::level_2()
                 } else {
-                    Elevation::Level1
+                    Elevation::level_1()
                 }
             }
             CardVariant::Filled | CardVariant::Outlined => {
                 if self.clickable && (self.pressed || self.hovered) {
-                    Elevation::Level1
+                    Elevation::level_1()
                 } else {
-                    Elevation::Level0
+                    Elevation::level_0()
                 }
             }
         }
@@ -146,9 +174,11 @@ impl MaterialCard {
         }
 
         if self.pressed {
-            0.12
+            StateLayer::PRESSED_OPACITY
         } else if self.hovered {
-            0.08
+            StateLayer::HOVER_OPACITY
+        } else if self.focused {
+            StateLayer::FOCUS_OPACITY
         } else {
             0.0
         }
@@ -167,6 +197,56 @@ pub struct CardClickEvent {
     pub entity: Entity,
 }
 
+/// Give clickable cards press handling, keyboard focus, and a ripple.
+///
+/// The card builders don't know whether a card is clickable until it is
+/// spawned, so the `Interaction`, `Focusable` and `RippleHost` are added here.
+fn card_ripple_setup_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    cards: Query<
+        (
+            Entity,
+            &MaterialCard,
+            Has<Interaction>,
+            Has<Focusable>,
+            Has<RippleHost>,
+        ),
+        Changed<MaterialCard>,
+    >,
+) {
+    let Some(theme) = theme else { return };
+
+    for (entity, card, has_interaction, has_focusable, has_ripple) in cards.iter() {
+        if !card.clickable {
+            continue;
+        }
+        let mut card_commands = commands.entity(entity);
+        if !has_interaction {
+            card_commands.insert(Interaction::None);
+        }
+        if !has_focusable {
+            let focusable = Focusable::new();
+            let outline = focusable.to_outline(Color::NONE);
+            card_commands.insert((focusable, outline));
+        }
+        if !has_ripple {
+            card_commands.insert(RippleHost::new().with_color(theme.on_surface));
+        }
+    }
+}
+
+/// Show the focus state layer on keyboard-focused cards, as hover does for
+/// the pointer.
+fn card_focus_system(mut cards: Query<(&Focusable, &mut MaterialCard), Changed<Focusable>>) {
+    for (focusable, mut card) in cards.iter_mut() {
+        let focused = card.clickable && focusable.focus_visible;
+        if card.focused != focused {
+            card.focused = focused;
+        }
+    }
+}
+
 /// System to handle card interactions
 fn card_interaction_system(
     mut interaction_query: Query<
@@ -201,40 +281,128 @@ fn card_interaction_system(
 /// System to update card styles
 fn card_style_system(
     theme: Option<Res<MaterialTheme>>,
+    tint: Option<Res<ElevationTintSettings>>,
     mut cards: Query<
         (&MaterialCard, &mut BackgroundColor, &mut BorderColor),
         Changed<MaterialCard>,
     >,
 ) {
     let Some(theme) = theme else { return };
+    let tint = tint.map(|t| *t).unwrap_or_default();
 
     for (card, mut bg_color, mut border_color) in cards.iter_mut() {
-        *bg_color = BackgroundColor(card.background_color(&theme));
+        *bg_color = BackgroundColor(card.tinted_background_color(&theme, &tint));
         *border_color = BorderColor::all(card.border_color(&theme));
     }
 }
 
-/// Refresh card colors when the theme changes.
+/// Refresh card colors when the theme or elevation tint settings change.
 fn card_theme_refresh_system(
     theme: Option<Res<MaterialTheme>>,
+    tint: Option<Res<ElevationTintSettings>>,
     mut cards: Query<(&MaterialCard, &mut BackgroundColor, &mut BorderColor)>,
+    mut slot_texts: Query<(&CardSlot, &mut TextColor)>,
 ) {
     let Some(theme) = theme else { return };
-    if !theme.is_changed() {
+    let tint_changed = tint.as_ref().is_some_and(|t| t.is_changed());
+    if !theme.is_changed() && !tint_changed {
         return;
     }
+    let tint = tint.map(|t| *t).unwrap_or_default();
 
     for (card, mut bg_color, mut border_color) in cards.iter_mut() {
-        *bg_color = BackgroundColor(card.background_color(&theme));
+        *bg_color = BackgroundColor(card.tinted_background_color(&theme, &tint));
         *border_color = BorderColor::all(card.border_color(&theme));
     }
+
+    for (slot, mut text_color) in slot_texts.iter_mut() {
+        text_color.0 = slot.text_color(&theme);
+    }
+}
+
+/// Duration of the elevation change of interactive cards
+const CARD_ELEVATION_DURATION: f32 = Duration::SHORT4;
+
+/// Shadow of an interactive card, eased between elevation levels
+#[derive(Component, Debug, Clone, Copy)]
+struct CardElevationTransition {
+    target: Elevation,
+    /// Shadow opacity, y-offset, blur and spread at the start of the transition
+    start: Vec4,
+    /// Current shadow opacity, y-offset, blur and spread
+    current: Vec4,
+    progress: f32,
+}
+
+impl CardElevationTransition {
+    fn new(elevation: Elevation) -> Self {
+        let params = shadow_params(elevation);
+        Self {
+            target: elevation,
+            start: params,
+            current: params,
+            progress: 1.0,
+        }
+    }
+}
+
+fn shadow_params(elevation: Elevation) -> Vec4 {
+    Vec4::new(
+        elevation.shadow_opacity(),
+        elevation.shadow_offset_y(),
+        elevation.shadow_blur(),
+        elevation.shadow_spread(),
+    )
 }
 
 /// System to update card shadows using Bevy's native BoxShadow
-fn card_shadow_system(mut cards: Query<(&MaterialCard, &mut BoxShadow), Changed<MaterialCard>>) {
-    for (card, mut box_shadow) in cards.iter_mut() {
+///
+/// Interactive cards ease between elevation levels; other cards switch immediately.
+fn card_shadow_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut cards: Query<(
+        Ref<MaterialCard>,
+        &mut BoxShadow,
+        Option<&mut CardElevationTransition>,
+    )>,
+) {
+    let step = if reduced_motion.is_some_and(|r| r.0) {
+        1.0
+    } else {
+        time.delta_secs() / CARD_ELEVATION_DURATION
+    };
+
+    for (card, mut box_shadow, transition) in cards.iter_mut() {
         let elevation = card.elevation();
-        *box_shadow = elevation.to_box_shadow();
+        let Some(mut transition) = transition.filter(|_| card.interactive) else {
+            if card.is_changed() {
+                *box_shadow = elevation.to_box_shadow();
+            }
+            continue;
+        };
+
+        if transition.target != elevation {
+            transition.target = elevation;
+            transition.start = transition.current;
+            transition.progress = 0.0;
+        }
+        if transition.progress >= 1.0 {
+            continue;
+        }
+
+        transition.progress = (transition.progress + step).min(1.0);
+        let t = ease_standard(transition.progress);
+        let current = transition.start.lerp(shadow_params(elevation), t);
+        transition.current = current;
+
+        *box_shadow = BoxShadow::new(
+            Color::srgba(0.0, 0.0, 0.0, current.x),
+            Val::Px(0.0),
+            Val::Px(current.y),
+            Val::Px(current.w),
+            Val::Px(current.z),
+        );
     }
 }
 
@@ -255,6 +423,36 @@ pub struct CardBuilder {
     width: Option<Val>,
     height: Option<Val>,
     padding: f32,
+    media: Option<(Handle<Image>, f32)>,
+    title: Option<String>,
+    subtitle: Option<String>,
+    supporting_text: Option<String>,
+}
+
+/// Structured parts of a card, spawned by [`SpawnCardChild::spawn_card_with`]
+/// in Material card order: media, header, supporting text, then custom children.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardSlot {
+    /// Full-bleed image at the top of the card
+    Media,
+    /// Padded column holding the header, supporting text and custom children
+    Content,
+    /// Header title
+    Title,
+    /// Header subtitle
+    Subtitle,
+    /// Body text
+    SupportingText,
+}
+
+impl CardSlot {
+    /// Text color for text slots
+    pub fn text_color(&self, theme: &MaterialTheme) -> Color {
+        match self {
+            CardSlot::Title => theme.on_surface,
+            _ => theme.on_surface_variant,
+        }
+    }
 }
 
 impl CardBuilder {
@@ -265,6 +463,10 @@ impl CardBuilder {
             width: None,
             height: None,
             padding: Spacing::LARGE,
+            media: None,
+            title: None,
+            subtitle: None,
+            supporting_text: None,
         }
     }
 
@@ -301,6 +503,12 @@ impl CardBuilder {
         self
     }
 
+    /// Make clickable and animate the elevation up on hover/press and back on release
+    pub fn interactive(mut self) -> Self {
+        self.card = self.card.interactive();
+        self
+    }
+
     /// Set width
     pub fn width(mut self, width: Val) -> Self {
         self.width = Some(width);
@@ -319,6 +527,39 @@ impl CardBuilder {
         self
     }
 
+    /// Show an image at the top of the card with the given width/height ratio
+    ///
+    /// The media spans the full card width; the rest of the content stays padded.
+    pub fn media(mut self, image: Handle<Image>, aspect_ratio: f32) -> Self {
+        self.media = Some((image, aspect_ratio.max(f32::EPSILON)));
+        self
+    }
+
+    /// Set the header title
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the header subtitle
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Set the supporting text below the header
+    pub fn supporting_text(mut self, text: impl Into<String>) -> Self {
+        self.supporting_text = Some(text.into());
+        self
+    }
+
+    fn has_slots(&self) -> bool {
+        self.media.is_some()
+            || self.title.is_some()
+            || self.subtitle.is_some()
+            || self.supporting_text.is_some()
+    }
+
     /// Build the card bundle with native BoxShadow
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.card.background_color(theme);
@@ -352,6 +593,7 @@ impl CardBuilder {
             BorderRadius::all(Val::Px(CornerRadius::MEDIUM)),
             // Native Bevy 0.17 shadow support
             elevation.to_box_shadow(),
+            CardElevationTransition::new(elevation),
         )
     }
 
@@ -470,10 +712,193 @@ impl SpawnCardChild for ChildSpawnerCommands<'_> {
     fn spawn_card_with(
         &mut self,
         theme: &MaterialTheme,
-        builder: CardBuilder,
+        mut builder: CardBuilder,
         with_children: impl FnOnce(&mut ChildSpawnerCommands),
     ) {
-        self.spawn(builder.build(theme))
-            .with_children(with_children);
+        if !builder.has_slots() {
+            self.spawn(builder.build(theme))
+                .with_children(with_children);
+            return;
+        }
+
+        // The media is full-bleed, so the padding moves onto the content column.
+        let padding = builder.padding;
+        let media = builder.media.take();
+        let title = builder.title.take();
+        let subtitle = builder.subtitle.take();
+        let supporting_text = builder.supporting_text.take();
+        if media.is_some() {
+            builder.padding = 0.0;
+        }
+
+        self.spawn(builder.build(theme)).with_children(|card| {
+            let content_padding = if let Some((image, aspect_ratio)) = media {
+                card.spawn((
+                    CardSlot::Media,
+                    ImageNode::new(image),
+                    Node {
+                        width: Val::Percent(100.0),
+                        aspect_ratio: Some(aspect_ratio),
+                        ..default()
+                    },
+                    BorderRadius::top(Val::Px(CornerRadius::MEDIUM)),
+                ));
+                padding
+            } else {
+                0.0
+            };
+
+            card.spawn((
+                CardSlot::Content,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(Spacing::SMALL),
+                    padding: UiRect::all(Val::Px(content_padding)),
+                    ..default()
+                },
+            ))
+            .with_children(|content| {
+                for (slot, text, role) in [
+                    (CardSlot::Title, title, TypeRole::TitleMedium),
+                    (CardSlot::Subtitle, subtitle, TypeRole::BodyMedium),
+                    (
+                        CardSlot::SupportingText,
+                        supporting_text,
+                        TypeRole::BodyMedium,
+                    ),
+                ] {
+                    if let Some(text) = text {
+                        content.spawn((
+                            slot,
+                            Text::new(text),
+                            role.text_bundle(),
+                            TextColor(slot.text_color(theme)),
+                        ));
+                    }
+                }
+                with_children(content);
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    fn shadow_blur(app: &App, entity: Entity) -> f32 {
+        match app.world().get::<BoxShadow>(entity).unwrap().0[0].blur_radius {
+            Val::Px(blur) => blur,
+            other => panic!("unexpected blur {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_interactive_card_eases_elevation_on_hover() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, card_shadow_system);
+        let theme = MaterialTheme::default();
+        let card = app
+            .world_mut()
+            .spawn(CardBuilder::new().elevated().interactive().build(&theme))
+            .id();
+        let resting = Elevation::level_1().shadow_blur();
+        let raised = Elevation::level_2().shadow_blur();
+
+        advance(&mut app, 0.0);
+        assert_eq!(shadow_blur(&app, card), resting);
+
+        app.world_mut()
+            .get_mut::<MaterialCard>(card)
+            .unwrap()
+            .hovered = true;
+        advance(&mut app, CARD_ELEVATION_DURATION / 2.0);
+        let halfway = shadow_blur(&app, card);
+        assert!(halfway > resting && halfway < raised, "blur {halfway}");
+
+        advance(&mut app, CARD_ELEVATION_DURATION);
+        assert_eq!(shadow_blur(&app, card), raised);
+
+        // Non-interactive cards switch at once.
+        let plain = app
+            .world_mut()
+            .spawn(CardBuilder::new().elevated().build(&theme))
+            .id();
+        advance(&mut app, 0.0);
+        app.world_mut()
+            .get_mut::<MaterialCard>(plain)
+            .unwrap()
+            .hovered = true;
+        advance(&mut app, 0.0);
+        assert_eq!(shadow_blur(&app, plain), raised);
+    }
+
+    #[test]
+    fn test_card_slots_spawn_in_material_order() {
+        let mut app = App::new();
+        let theme = MaterialTheme::default();
+        let builder = CardBuilder::new()
+            .padding(16.0)
+            .media(Handle::default(), 16.0 / 9.0)
+            .title("Title")
+            .subtitle("Subtitle")
+            .supporting_text("Body");
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                parent.spawn_card_with(&theme, builder, |content| {
+                    content.spawn(Text::new("Custom"));
+                });
+            });
+        app.world_mut().flush();
+
+        let world = app.world_mut();
+        let (card_node, card_children) = world
+            .query_filtered::<(&Node, &Children), With<MaterialCard>>()
+            .single(world)
+            .map(|(node, children)| (node.clone(), children.to_vec()))
+            .unwrap();
+        // Full-bleed media: the card itself is unpadded, the content column is padded.
+        assert_eq!(card_node.padding, UiRect::all(Val::Px(0.0)));
+        let slots: Vec<_> = card_children
+            .iter()
+            .map(|child| *world.get::<CardSlot>(*child).unwrap())
+            .collect();
+        assert_eq!(slots, [CardSlot::Media, CardSlot::Content]);
+
+        let content = card_children[1];
+        assert_eq!(
+            world.get::<Node>(content).unwrap().padding,
+            UiRect::all(Val::Px(16.0))
+        );
+        let texts: Vec<_> = world
+            .get::<Children>(content)
+            .unwrap()
+            .iter()
+            .map(|child| {
+                (
+                    world.get::<CardSlot>(child).copied(),
+                    world.get::<Text>(child).unwrap().0.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                (Some(CardSlot::Title), "Title".to_string()),
+                (Some(CardSlot::Subtitle), "Subtitle".to_string()),
+                (Some(CardSlot::SupportingText), "Body".to_string()),
+                (None, "Custom".to_string()),
+            ]
+        );
     }
 }
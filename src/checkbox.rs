@@ -11,12 +11,15 @@
 use bevy::prelude::*;
 
 use crate::{
+    control_label::spawn_labeled_control,
+    disabled::{sync_disabled_system, DisabledSource},
     icons::{icon_by_name, MaterialIcon, ICON_CHECK, ICON_REMOVE},
     motion::{ease_emphasized_decelerate, StateLayer},
     ripple::RippleHost,
+    semantics::{sync_semantics_system, Role, Semantic},
     telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
-    tokens::{CornerRadius, Duration},
+    tokens::{CornerRadius, Duration, Opacity},
 };
 
 /// Plugin for the checkbox component
@@ -32,6 +35,8 @@ impl Plugin for CheckboxPlugin {
                 checkbox_theme_refresh_system,
                 checkbox_animation_system,
                 checkbox_telemetry_system,
+                sync_disabled_system::<MaterialCheckbox>,
+                sync_semantics_system::<MaterialCheckbox>,
             )
                 .chain(),
         );
@@ -213,10 +218,33 @@ impl MaterialCheckbox {
         self
     }
 
+    /// Whether the checkbox is checked
+    pub fn is_checked(&self) -> bool {
+        self.state.is_checked()
+    }
+
+    /// Check or uncheck the checkbox, animating the change.
+    ///
+    /// Called through `Mut<MaterialCheckbox>`, this marks the checkbox changed
+    /// so its box and check icon are redrawn. No [`CheckboxChangeEvent`] is
+    /// written.
+    pub fn set_checked(&mut self, checked: bool) {
+        self.set_state(if checked {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Unchecked
+        });
+    }
+
+    /// Set the state, including [`CheckboxState::Indeterminate`], animating the change
+    pub fn set_state(&mut self, state: CheckboxState) {
+        self.start_animation(state);
+    }
+
     /// Get the container color (when checked/indeterminate)
     pub fn container_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         if self.error {
@@ -232,7 +260,7 @@ impl MaterialCheckbox {
     /// Get the outline color
     pub fn outline_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         if self.error {
@@ -281,6 +309,35 @@ impl MaterialCheckbox {
     }
 }
 
+impl DisabledSource for MaterialCheckbox {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Semantic for MaterialCheckbox {
+    fn semantic_role(&self) -> Role {
+        Role::Checkbox
+    }
+
+    /// The component has no text of its own; builders label it from the
+    /// control label when one is given
+    fn semantic_label(&self) -> String {
+        String::new()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        Some(
+            match self.state {
+                CheckboxState::Unchecked => "unchecked",
+                CheckboxState::Checked => "checked",
+                CheckboxState::Indeterminate => "mixed",
+            }
+            .to_string(),
+        )
+    }
+}
+
 impl Default for MaterialCheckbox {
     fn default() -> Self {
         Self::new()
@@ -497,6 +554,7 @@ fn checkbox_animation_system(time: Res<Time>, mut checkboxes: Query<&mut Materia
 /// Builder for checkboxes
 pub struct CheckboxBuilder {
     checkbox: MaterialCheckbox,
+    label: Option<String>,
 }
 
 impl CheckboxBuilder {
@@ -504,6 +562,7 @@ impl CheckboxBuilder {
     pub fn new() -> Self {
         Self {
             checkbox: MaterialCheckbox::new(),
+            label: None,
         }
     }
 
@@ -536,6 +595,12 @@ impl CheckboxBuilder {
         self
     }
 
+    /// Add a text label; clicking it toggles the checkbox
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Build the checkbox component (just the component, not visuals)
     pub fn build(self) -> MaterialCheckbox {
         self.checkbox
@@ -548,82 +613,29 @@ impl CheckboxBuilder {
     /// - State layer for hover/press feedback
     /// - Visual checkbox box (18x18)
     /// - Checkmark icon
+    ///
+    /// With a [`label`](Self::label), the checkbox and its text are wrapped in
+    /// a [`ControlLabel`](crate::control_label::ControlLabel) row that acts as
+    /// one touch target, and the row entity is returned.
     pub fn spawn(self, commands: &mut Commands, theme: &MaterialTheme) -> Entity {
-        let checkbox = self.checkbox;
-        let bg_color = checkbox.container_color(theme);
-        let border_color = checkbox.outline_color(theme);
-        let icon_color = checkbox.icon_color(theme);
-        let icon_name = checkbox.state.icon();
-        let state_layer_color = checkbox.state_layer_color(theme);
-        let default_icon_id = icon_by_name(ICON_CHECK)
-            .expect("embedded icon 'check' not found - ensure google-material-design-icons-bin crate is properly configured");
-        let icon_visibility = if icon_name.is_some() {
-            Visibility::Inherited
-        } else {
-            Visibility::Hidden
-        };
-        let icon_id = icon_name.and_then(icon_by_name).unwrap_or(default_icon_id);
-
-        commands
-            .spawn((
-                checkbox,
-                Button,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    height: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|parent| {
-                // State layer (for hover/press effects)
-                parent
-                    .spawn((
-                        CheckboxStateLayer,
-                        StateLayer::new(state_layer_color),
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(40.0),
-                            height: Val::Px(40.0),
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        BorderRadius::all(Val::Px(20.0)),
-                    ))
-                    .with_children(|state_layer_parent| {
-                        // Checkbox box (visual element)
-                        state_layer_parent
-                            .spawn((
-                                CheckboxBox,
-                                Node {
-                                    width: Val::Px(CHECKBOX_SIZE),
-                                    height: Val::Px(CHECKBOX_SIZE),
-                                    border: UiRect::all(Val::Px(CHECKBOX_BORDER_WIDTH)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(bg_color),
-                                BorderColor::all(border_color),
-                                BorderRadius::all(Val::Px(CHECKBOX_CORNER_RADIUS)),
-                            ))
-                            .with_children(|box_parent| {
-                                // Checkmark icon
-                                box_parent.spawn((
-                                    CheckboxIcon,
-                                    MaterialIcon::new(icon_id)
-                                        .with_size(14.0)
-                                        .with_color(icon_color),
-                                    icon_visibility,
-                                ));
-                            });
-                    });
-            })
-            .id()
+        self.spawn_into(commands.spawn_empty(), theme)
+    }
+
+    fn spawn_into(self, mut root: EntityCommands, theme: &MaterialTheme) -> Entity {
+        let Self { checkbox, label } = self;
+        let semantics = checkbox.semantics();
+        match label {
+            Some(label) => {
+                let semantics = semantics.with_label(label.clone());
+                spawn_labeled_control(root, theme, label, |row| {
+                    spawn_checkbox_control(row.spawn(semantics), checkbox, theme)
+                })
+            }
+            None => {
+                root.insert(semantics);
+                spawn_checkbox_control(root, checkbox, theme)
+            }
+        }
     }
 }
 
@@ -633,6 +645,91 @@ impl Default for CheckboxBuilder {
     }
 }
 
+/// Insert the checkbox touch target on `entity` and spawn its visuals.
+fn spawn_checkbox_control(
+    mut entity: EntityCommands,
+    checkbox: MaterialCheckbox,
+    theme: &MaterialTheme,
+) -> Entity {
+    let bg_color = checkbox.container_color(theme);
+    let border_color = checkbox.outline_color(theme);
+    let icon_color = checkbox.icon_color(theme);
+    let icon_name = checkbox.state.icon();
+    let state_layer_color = checkbox.state_layer_color(theme);
+    let default_icon_id = icon_by_name(ICON_CHECK)
+        .expect("embedded icon 'check' not found - ensure google-material-design-icons-bin crate is properly configured");
+    let icon_visibility = if icon_name.is_some() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    let icon_id = icon_name.and_then(icon_by_name).unwrap_or(default_icon_id);
+
+    entity
+        .insert((
+            checkbox,
+            Button,
+            Interaction::None,
+            RippleHost::new(),
+            Node {
+                width: Val::Px(CHECKBOX_TOUCH_TARGET),
+                height: Val::Px(CHECKBOX_TOUCH_TARGET),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Px(CornerRadius::FULL)),
+        ))
+        .with_children(|parent| {
+            // State layer (for hover/press effects)
+            parent
+                .spawn((
+                    CheckboxStateLayer,
+                    StateLayer::new(state_layer_color),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(40.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(20.0)),
+                ))
+                .with_children(|state_layer_parent| {
+                    // Checkbox box (visual element)
+                    state_layer_parent
+                        .spawn((
+                            CheckboxBox,
+                            Node {
+                                width: Val::Px(CHECKBOX_SIZE),
+                                height: Val::Px(CHECKBOX_SIZE),
+                                border: UiRect::all(Val::Px(CHECKBOX_BORDER_WIDTH)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(bg_color),
+                            BorderColor::all(border_color),
+                            BorderRadius::all(Val::Px(CHECKBOX_CORNER_RADIUS)),
+                        ))
+                        .with_children(|box_parent| {
+                            // Checkmark icon
+                            box_parent.spawn((
+                                CheckboxIcon,
+                                MaterialIcon::new(icon_id)
+                                    .with_size(14.0)
+                                    .with_color(icon_color),
+                                icon_visibility,
+                            ));
+                        });
+                });
+        });
+    entity.id()
+}
+
 /// Extension trait to spawn checkboxes easily
 pub trait SpawnCheckbox {
     /// Spawn a checkbox with a label
@@ -651,103 +748,10 @@ impl SpawnCheckbox for Commands<'_, '_> {
         state: CheckboxState,
         label: &str,
     ) -> Entity {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let checkbox = CheckboxBuilder::new().state(state).build();
-        let bg_color = checkbox.container_color(theme);
-        let border_color = checkbox.outline_color(theme);
-        let icon_color = checkbox.icon_color(theme);
-        let icon_name = checkbox.state.icon();
-        let state_layer_color = checkbox.state_layer_color(theme);
-        let default_icon_id = icon_by_name(ICON_CHECK)
-            .expect("embedded icon 'check' not found - ensure google-material-design-icons-bin crate is properly configured");
-        let icon_visibility = if icon_name.is_some() {
-            Visibility::Inherited
-        } else {
-            Visibility::Hidden
-        };
-        let icon_id = icon_name.and_then(icon_by_name).unwrap_or(default_icon_id);
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Checkbox
-            row.spawn((
-                checkbox,
-                Button,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    height: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|parent| {
-                // State layer
-                parent
-                    .spawn((
-                        CheckboxStateLayer,
-                        StateLayer::new(state_layer_color),
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(40.0),
-                            height: Val::Px(40.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        BorderRadius::all(Val::Px(20.0)),
-                    ))
-                    .with_children(|state_layer_parent| {
-                        // Checkbox box
-                        state_layer_parent
-                            .spawn((
-                                CheckboxBox,
-                                Node {
-                                    width: Val::Px(CHECKBOX_SIZE),
-                                    height: Val::Px(CHECKBOX_SIZE),
-                                    border: UiRect::all(Val::Px(CHECKBOX_BORDER_WIDTH)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(bg_color),
-                                BorderColor::all(border_color),
-                                BorderRadius::all(Val::Px(CHECKBOX_CORNER_RADIUS)),
-                            ))
-                            .with_children(|box_parent| {
-                                // Checkmark
-                                box_parent.spawn((
-                                    CheckboxIcon,
-                                    MaterialIcon::new(icon_id)
-                                        .with_size(14.0)
-                                        .with_color(icon_color),
-                                    icon_visibility,
-                                ));
-                            });
-                    });
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        })
-        .id()
+        CheckboxBuilder::new()
+            .state(state)
+            .label(label)
+            .spawn(self, theme)
     }
 }
 
@@ -777,102 +781,11 @@ impl SpawnCheckboxChild for ChildSpawnerCommands<'_> {
         checkbox: MaterialCheckbox,
         label: &str,
     ) {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let bg_color = checkbox.container_color(theme);
-        let border_color = checkbox.outline_color(theme);
-        let icon_color = checkbox.icon_color(theme);
-        let icon_name = checkbox.state.icon();
-        let state_layer_color = checkbox.state_layer_color(theme);
-        let default_icon_id = icon_by_name(ICON_CHECK)
-            .expect("embedded icon 'check' not found - ensure google-material-design-icons-bin crate is properly configured");
-        let icon_visibility = if icon_name.is_some() {
-            Visibility::Inherited
-        } else {
-            Visibility::Hidden
+        let builder = CheckboxBuilder {
+            checkbox,
+            label: Some(label.to_string()),
         };
-        let icon_id = icon_name.and_then(icon_by_name).unwrap_or(default_icon_id);
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Checkbox
-            row.spawn((
-                checkbox,
-                Button,
-                Interaction::None,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    height: Val::Px(CHECKBOX_TOUCH_TARGET),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|parent| {
-                // State layer
-                parent
-                    .spawn((
-                        CheckboxStateLayer,
-                        StateLayer::new(state_layer_color),
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(40.0),
-                            height: Val::Px(40.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        BorderRadius::all(Val::Px(20.0)),
-                    ))
-                    .with_children(|state_layer_parent| {
-                        // Checkbox box
-                        state_layer_parent
-                            .spawn((
-                                CheckboxBox,
-                                Node {
-                                    width: Val::Px(CHECKBOX_SIZE),
-                                    height: Val::Px(CHECKBOX_SIZE),
-                                    border: UiRect::all(Val::Px(CHECKBOX_BORDER_WIDTH)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(bg_color),
-                                BorderColor::all(border_color),
-                                BorderRadius::all(Val::Px(CHECKBOX_CORNER_RADIUS)),
-                            ))
-                            .with_children(|box_parent| {
-                                // Checkmark
-                                box_parent.spawn((
-                                    CheckboxIcon,
-                                    MaterialIcon::new(icon_id)
-                                        .with_size(14.0)
-                                        .with_color(icon_color),
-                                    icon_visibility,
-                                ));
-                            });
-                    });
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        });
+        builder.spawn_into(self.spawn_empty(), theme);
     }
 }
 
@@ -998,6 +911,21 @@ mod tests {
         assert!(!checkbox.error);
     }
 
+    #[test]
+    fn test_checkbox_set_checked() {
+        let mut checkbox = MaterialCheckbox::new();
+        assert!(!checkbox.is_checked());
+
+        checkbox.set_checked(true);
+        assert!(checkbox.is_checked());
+        assert!(checkbox.animating);
+        assert_eq!(checkbox.previous_state, CheckboxState::Unchecked);
+
+        checkbox.set_state(CheckboxState::Indeterminate);
+        assert!(!checkbox.is_checked());
+        assert!(checkbox.state.is_indeterminate());
+    }
+
     #[test]
     fn test_checkbox_builder_chain() {
         let checkbox = MaterialCheckbox::new().checked().disabled(true).error(true);
@@ -1090,6 +1018,46 @@ mod tests {
         assert!(checkbox.error);
     }
 
+    #[test]
+    fn test_checkbox_builder_label_wraps_checkbox() {
+        use crate::control_label::{ControlLabel, ControlLabelText};
+
+        let mut world = World::new();
+        let theme = MaterialTheme::default();
+        let unlabeled = CheckboxBuilder::new().spawn(&mut world.commands(), &theme);
+        let row = CheckboxBuilder::new()
+            .checked()
+            .label("Accept terms")
+            .spawn(&mut world.commands(), &theme);
+        world.flush();
+
+        assert!(world.get::<MaterialCheckbox>(unlabeled).is_some());
+        assert!(world.get::<ControlLabel>(unlabeled).is_none());
+
+        let label = world.get::<ControlLabel>(row).unwrap();
+        let checkbox = world.get::<MaterialCheckbox>(label.control).unwrap();
+        assert!(checkbox.state.is_checked());
+
+        use crate::semantics::{A11ySemantics, Role};
+        assert_eq!(
+            world.get::<A11ySemantics>(label.control),
+            Some(&A11ySemantics::new(Role::Checkbox, "Accept terms").with_value("checked"))
+        );
+        assert_eq!(
+            world
+                .get::<A11ySemantics>(unlabeled)
+                .unwrap()
+                .value
+                .as_deref(),
+            Some("unchecked")
+        );
+
+        let children = world.get::<Children>(row).unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0], label.control);
+        assert!(world.get::<ControlLabelText>(children[1]).is_some());
+    }
+
     // ============================================================================
     // Constants Tests
     // ============================================================================
@@ -10,10 +10,13 @@ use bevy::prelude::*;
 use bevy::ui::BoxShadow;
 
 use crate::{
+    disabled::{sync_disabled_system, DisabledSource},
     elevation::Elevation,
+    focus::adopt_focus_group_system,
+    motion::{sync_state_layer_system, StateLayer, StateLayerSource},
     ripple::RippleHost,
-    theme::{blend_state_layer, MaterialTheme},
-    tokens::Spacing,
+    theme::MaterialTheme,
+    tokens::{Opacity, Spacing},
 };
 
 /// Plugin for the chip component
@@ -26,14 +29,20 @@ impl Plugin for ChipPlugin {
         }
         app.add_message::<ChipClickEvent>()
             .add_message::<ChipDeleteEvent>()
+            .add_message::<ChipGroupChangeEvent>()
             .add_systems(
                 Update,
                 (
                     chip_interaction_system,
+                    chip_group_layout_system,
+                    chip_group_selection_system.after(chip_interaction_system),
                     chip_style_system,
                     chip_content_style_system,
                     chip_theme_refresh_system,
                     chip_shadow_system,
+                    adopt_focus_group_system::<ChipGroup, MaterialChip>,
+                    sync_state_layer_system::<MaterialChip>,
+                    sync_disabled_system::<MaterialChip>,
                 ),
             );
     }
@@ -61,6 +70,15 @@ pub struct ChipDeleteEvent {
     pub value: Option<String>,
 }
 
+/// Event fired when the selection inside a [`ChipGroup`] changes
+#[derive(Event, Clone, Debug, bevy::prelude::Message)]
+pub struct ChipGroupChangeEvent {
+    /// The chip group entity
+    pub entity: Entity,
+    /// Indices (in child order) of the currently selected chips
+    pub selected: Vec<usize>,
+}
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -125,6 +143,8 @@ pub struct MaterialChip {
     pub deletable: bool,
     /// Whether the chip has a leading icon
     pub has_leading_icon: bool,
+    /// Whether the chip has a leading avatar image
+    pub has_avatar: bool,
     /// Elevation style
     pub elevation: ChipElevation,
     /// Interaction states
@@ -143,6 +163,7 @@ impl MaterialChip {
             disabled: false,
             deletable: false,
             has_leading_icon: false,
+            has_avatar: false,
             elevation: ChipElevation::default(),
             pressed: false,
             hovered: false,
@@ -213,40 +234,28 @@ impl MaterialChip {
         self
     }
 
-    /// Get the background color with state layer applied
+    /// Get the background color
+    ///
+    /// Hover/pressed feedback is drawn by the chip's `StateLayer` overlay.
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.12);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
         }
 
-        let base = match (self.variant, self.selected, self.elevation) {
+        match (self.variant, self.selected, self.elevation) {
             // Selected filter chips have secondary container background
             (ChipVariant::Filter, true, _) => theme.secondary_container,
             // Elevated chips have surface container low
             (_, _, ChipElevation::Elevated) => theme.surface_container_low,
             // Flat chips are transparent
             _ => Color::NONE,
-        };
-
-        // Apply state layer
-        let state_opacity = self.state_layer_opacity();
-        let state_color = self.state_layer_color(theme);
-        if state_opacity > 0.0 {
-            if base == Color::NONE {
-                // For transparent backgrounds, just show the state layer
-                state_color.with_alpha(state_opacity)
-            } else {
-                blend_state_layer(base, state_color, state_opacity)
-            }
-        } else {
-            base
         }
     }
 
     /// Get the outline color
     pub fn outline_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.12);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
         }
 
         match (self.variant, self.selected, self.elevation) {
@@ -262,7 +271,7 @@ impl MaterialChip {
     /// Get the label color
     pub fn label_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         match (self.variant, self.selected) {
@@ -274,7 +283,7 @@ impl MaterialChip {
     /// Get the icon color
     pub fn icon_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         match (self.variant, self.selected) {
@@ -290,18 +299,21 @@ impl MaterialChip {
             _ => theme.on_surface_variant,
         }
     }
+}
 
-    /// Get the state layer opacity
-    fn state_layer_opacity(&self) -> f32 {
-        if self.disabled {
-            0.0
-        } else if self.pressed {
-            0.12
-        } else if self.hovered {
-            0.08
-        } else {
-            0.0
-        }
+impl DisabledSource for MaterialChip {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl StateLayerSource for MaterialChip {
+    fn state_layer_color(&self, theme: &MaterialTheme) -> Color {
+        MaterialChip::state_layer_color(self, theme)
+    }
+
+    fn state_layer_disabled(&self) -> bool {
+        self.disabled
     }
 }
 
@@ -315,6 +327,10 @@ impl Default for MaterialChip {
 #[derive(Component)]
 pub struct ChipDeleteButton;
 
+/// Marker for the image node of a chip's leading avatar
+#[derive(Component)]
+pub struct ChipAvatar;
+
 /// Marker for chip leading icon
 #[derive(Component)]
 pub struct ChipLeadingIcon;
@@ -339,6 +355,10 @@ pub const CHIP_PADDING_HORIZONTAL: f32 = 16.0;
 pub const CHIP_ICON_SIZE: f32 = 18.0;
 /// Chip with icon padding (left side)
 pub const CHIP_PADDING_WITH_ICON: f32 = 8.0;
+/// Chip avatar diameter
+pub const CHIP_AVATAR_SIZE: f32 = 24.0;
+/// Chip with avatar padding (left side), centering the avatar vertically
+pub const CHIP_PADDING_WITH_AVATAR: f32 = (CHIP_HEIGHT - CHIP_AVATAR_SIZE) / 2.0;
 
 // ============================================================================
 // Builder
@@ -348,6 +368,7 @@ pub const CHIP_PADDING_WITH_ICON: f32 = 8.0;
 pub struct ChipBuilder {
     chip: MaterialChip,
     leading_icon: Option<String>,
+    avatar: Option<Handle<Image>>,
 }
 
 impl ChipBuilder {
@@ -356,6 +377,7 @@ impl ChipBuilder {
         Self {
             chip: MaterialChip::new(label),
             leading_icon: None,
+            avatar: None,
         }
     }
 
@@ -422,14 +444,26 @@ impl ChipBuilder {
         self
     }
 
+    /// Show a circular avatar image as the leading element (e.g. a contact chip).
+    ///
+    /// Takes the place of the leading icon; input chips keep their delete button.
+    pub fn avatar(mut self, image: Handle<Image>) -> Self {
+        self.avatar = Some(image);
+        self.chip.has_avatar = true;
+        self
+    }
+
     /// Build the chip bundle with native BoxShadow
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.chip.background_color(theme);
         let outline_color = self.chip.outline_color(theme);
         let has_outline = outline_color != Color::NONE;
         let elevation = self.chip.elevation;
+        let state_layer = StateLayer::new(self.chip.state_layer_color(theme));
 
-        let padding_left = if self.chip.has_leading_icon {
+        let padding_left = if self.chip.has_avatar {
+            CHIP_PADDING_WITH_AVATAR
+        } else if self.chip.has_leading_icon {
             CHIP_PADDING_WITH_ICON
         } else {
             CHIP_PADDING_HORIZONTAL
@@ -445,6 +479,7 @@ impl ChipBuilder {
             self.chip,
             Button,
             RippleHost::new(),
+            state_layer,
             Node {
                 height: Val::Px(CHIP_HEIGHT),
                 padding: UiRect {
@@ -468,10 +503,158 @@ impl ChipBuilder {
     }
 }
 
+// ============================================================================
+// Chip Group
+// ============================================================================
+
+/// Selection behavior for a [`ChipGroup`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChipGroupSelection {
+    /// At most one filter chip can be selected at a time
+    Single,
+    /// Any number of filter chips can be selected
+    #[default]
+    Multiple,
+}
+
+/// Container that wraps chips onto multiple lines and coordinates selection
+/// across its direct child filter chips.
+#[derive(Component, Debug, Clone)]
+pub struct ChipGroup {
+    /// Selection behavior
+    pub selection: ChipGroupSelection,
+    /// Gap between chips (both horizontal and vertical) in pixels
+    pub spacing: f32,
+}
+
+impl Default for ChipGroup {
+    fn default() -> Self {
+        Self {
+            selection: ChipGroupSelection::default(),
+            spacing: Spacing::SMALL,
+        }
+    }
+}
+
+impl ChipGroup {
+    /// Create a new multi-select chip group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the group only allows a single selected chip
+    pub fn is_single_select(&self) -> bool {
+        self.selection == ChipGroupSelection::Single
+    }
+
+    /// Compute the selection state of each chip after `clicked` was toggled.
+    ///
+    /// `selected` holds the current selection flags in child order (already
+    /// reflecting the toggle of the clicked chip). In single-select mode every
+    /// other chip is deselected when the clicked chip became selected.
+    pub fn resolve_selection(&self, selected: &[bool], clicked: usize) -> Vec<bool> {
+        let clicked_selected = selected.get(clicked).copied().unwrap_or(false);
+        selected
+            .iter()
+            .enumerate()
+            .map(|(index, &is_selected)| {
+                if self.is_single_select() && clicked_selected && index != clicked {
+                    false
+                } else {
+                    is_selected
+                }
+            })
+            .collect()
+    }
+}
+
+/// Builder for chip groups
+#[derive(Default)]
+pub struct ChipGroupBuilder {
+    group: ChipGroup,
+}
+
+impl ChipGroupBuilder {
+    /// Create a new chip group builder (multi-select by default)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow only a single selected chip
+    pub fn single_select(mut self) -> Self {
+        self.group.selection = ChipGroupSelection::Single;
+        self
+    }
+
+    /// Allow any number of selected chips
+    pub fn multi_select(mut self) -> Self {
+        self.group.selection = ChipGroupSelection::Multiple;
+        self
+    }
+
+    /// Set the gap between chips
+    pub fn spacing(mut self, px: f32) -> Self {
+        self.group.spacing = px;
+        self
+    }
+
+    /// Build the chip group bundle
+    pub fn build(self) -> impl Bundle {
+        let spacing = Val::Px(self.group.spacing);
+        (
+            self.group,
+            Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                align_items: AlignItems::Center,
+                column_gap: spacing,
+                row_gap: spacing,
+                ..default()
+            },
+        )
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Spawn a chip's leading avatar: an image clipped to a circle.
+fn spawn_chip_avatar(parent: &mut ChildSpawnerCommands, image: Handle<Image>, disabled: bool) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(CHIP_AVATAR_SIZE),
+                height: Val::Px(CHIP_AVATAR_SIZE),
+                flex_shrink: 0.0,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BorderRadius::MAX,
+        ))
+        .with_children(|clip| {
+            clip.spawn((
+                ChipAvatar,
+                ImageNode::new(image).with_color(chip_avatar_tint(disabled)),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BorderRadius::MAX,
+            ));
+        });
+}
+
+/// Avatar tint: disabled chips fade the image like their content.
+fn chip_avatar_tint(disabled: bool) -> Color {
+    if disabled {
+        Color::WHITE.with_alpha(Opacity::DISABLED_CONTENT)
+    } else {
+        Color::WHITE
+    }
+}
+
 /// Spawn a chip with its children
 pub fn spawn_chip(commands: &mut Commands, theme: &MaterialTheme, builder: ChipBuilder) -> Entity {
     let label = builder.chip.label.clone();
@@ -482,6 +665,8 @@ pub fn spawn_chip(commands: &mut Commands, theme: &MaterialTheme, builder: ChipB
     let selected = builder.chip.selected;
     let variant = builder.chip.variant;
     let leading_icon = builder.leading_icon.clone();
+    let avatar = builder.avatar.clone();
+    let disabled = builder.chip.disabled;
 
     commands
         .spawn(builder.build(theme))
@@ -497,6 +682,8 @@ pub fn spawn_chip(commands: &mut Commands, theme: &MaterialTheme, builder: ChipB
                     },
                     TextColor(icon_color),
                 ));
+            } else if let Some(image) = avatar {
+                spawn_chip_avatar(parent, image, disabled);
             } else if has_leading {
                 parent.spawn((
                     ChipLeadingIcon,
@@ -604,6 +791,8 @@ impl SpawnChipChild for ChildSpawnerCommands<'_> {
         let selected = builder.chip.selected;
         let variant = builder.chip.variant;
         let leading_icon = builder.leading_icon.clone();
+        let avatar = builder.avatar.clone();
+        let disabled = builder.chip.disabled;
 
         self.spawn(builder.build(theme)).with_children(|parent| {
             // Leading icon (or checkmark for selected filter chips)
@@ -617,6 +806,8 @@ impl SpawnChipChild for ChildSpawnerCommands<'_> {
                     },
                     TextColor(icon_color),
                 ));
+            } else if let Some(image) = avatar {
+                spawn_chip_avatar(parent, image, disabled);
             } else if has_leading {
                 parent.spawn((
                     ChipLeadingIcon,
@@ -756,6 +947,75 @@ fn chip_interaction_system(
     }
 }
 
+/// Keep chip group layout in sync with its configuration
+fn chip_group_layout_system(mut groups: Query<(&ChipGroup, &mut Node), Changed<ChipGroup>>) {
+    for (group, mut node) in groups.iter_mut() {
+        node.flex_wrap = FlexWrap::Wrap;
+        node.column_gap = Val::Px(group.spacing);
+        node.row_gap = Val::Px(group.spacing);
+    }
+}
+
+/// System to coordinate filter chip selection within chip groups
+fn chip_group_selection_system(
+    mut click_events: MessageReader<ChipClickEvent>,
+    parents: Query<&ChildOf, With<MaterialChip>>,
+    groups: Query<(&ChipGroup, &Children)>,
+    mut chips: Query<&mut MaterialChip>,
+    mut change_events: MessageWriter<ChipGroupChangeEvent>,
+) {
+    for event in click_events.read() {
+        let Ok(parent) = parents.get(event.entity) else {
+            continue;
+        };
+        let group_entity = parent.parent();
+        let Ok((group, children)) = groups.get(group_entity) else {
+            continue;
+        };
+
+        let group_chips: Vec<(Entity, bool)> = children
+            .iter()
+            .filter_map(|child| {
+                let chip = chips.get(child).ok()?;
+                Some((child, chip.variant == ChipVariant::Filter && chip.selected))
+            })
+            .collect();
+
+        let Some(clicked) = group_chips
+            .iter()
+            .position(|(entity, _)| *entity == event.entity)
+        else {
+            continue;
+        };
+        let Ok(clicked_chip) = chips.get(event.entity) else {
+            continue;
+        };
+        if clicked_chip.variant != ChipVariant::Filter {
+            continue;
+        }
+
+        let current: Vec<bool> = group_chips.iter().map(|(_, s)| *s).collect();
+        let resolved = group.resolve_selection(&current, clicked);
+
+        for ((entity, was_selected), now_selected) in group_chips.iter().zip(resolved.iter()) {
+            if was_selected != now_selected {
+                if let Ok(mut chip) = chips.get_mut(*entity) {
+                    chip.selected = *now_selected;
+                }
+            }
+        }
+
+        change_events.write(ChipGroupChangeEvent {
+            entity: group_entity,
+            selected: resolved
+                .iter()
+                .enumerate()
+                .filter_map(|(index, selected)| selected.then_some(index))
+                .collect(),
+        });
+    }
+}
+
 /// System to update chip styles
 fn chip_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -786,6 +1046,7 @@ fn chip_content_style_system(
         Query<&mut TextColor, With<ChipLeadingIcon>>,
         Query<&mut TextColor, With<ChipDeleteIcon>>,
     )>,
+    mut avatars: Query<&mut ImageNode, With<ChipAvatar>>,
 ) {
     let Some(theme) = theme else { return };
 
@@ -796,6 +1057,7 @@ fn chip_content_style_system(
 
         let label_color = chip.label_color(&theme);
         let icon_color = chip.icon_color(&theme);
+        let avatar_tint = chip_avatar_tint(chip.disabled);
 
         for child in children.iter() {
             if let Ok(mut color) = colors.p0().get_mut(child) {
@@ -805,12 +1067,15 @@ fn chip_content_style_system(
                 color.0 = icon_color;
             }
 
-            // Delete icon is a grandchild under ChipDeleteButton.
+            // Delete icon and avatar image are grandchildren under their wrappers.
             if let Ok(grandchildren) = children_q.get(child) {
                 for grandchild in grandchildren.iter() {
                     if let Ok(mut color) = colors.p2().get_mut(grandchild) {
                         color.0 = icon_color;
                     }
+                    if let Ok(mut image) = avatars.get_mut(grandchild) {
+                        image.color = avatar_tint;
+                    }
                 }
             }
         }
@@ -922,4 +1187,41 @@ mod tests {
         assert_eq!(builder.chip.value, Some("size-large".to_string()));
         assert!(builder.chip.selected);
     }
+
+    #[test]
+    fn test_chip_builder_avatar() {
+        let builder = ChipBuilder::input("Ada").avatar(Handle::default());
+
+        assert!(builder.chip.has_avatar);
+        assert!(builder.chip.deletable);
+        assert!(builder.avatar.is_some());
+        assert_eq!(CHIP_PADDING_WITH_AVATAR, 4.0);
+    }
+
+    #[test]
+    fn test_chip_group_single_select_deselects_others() {
+        let group = ChipGroup {
+            selection: ChipGroupSelection::Single,
+            ..default()
+        };
+        let resolved = group.resolve_selection(&[true, false, true], 2);
+        assert_eq!(resolved, vec![false, false, true]);
+    }
+
+    #[test]
+    fn test_chip_group_multi_select_keeps_others() {
+        let group = ChipGroup::new();
+        let resolved = group.resolve_selection(&[true, false, true], 2);
+        assert_eq!(resolved, vec![true, false, true]);
+
+        // Deselecting in single mode leaves the rest untouched.
+        let single = ChipGroup {
+            selection: ChipGroupSelection::Single,
+            ..default()
+        };
+        assert_eq!(
+            single.resolve_selection(&[false, false], 0),
+            vec![false, false]
+        );
+    }
 }
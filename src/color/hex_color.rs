@@ -0,0 +1,21 @@
+//! Serde helpers that store a [`Color`] as an sRGB hex string
+//!
+//! Colors are written as `"#RRGGBB"`, or `"#RRGGBBAA"` when not fully opaque,
+//! matching what palette tools export. Any form accepted by [`Srgba::hex`]
+//! is read back.
+
+use bevy::color::{Color, Srgba};
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serialize a color as a hex string
+pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&color.to_srgba().to_hex())
+}
+
+/// Deserialize a color from a hex string
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    Srgba::hex(&hex)
+        .map(Color::Srgba)
+        .map_err(|err| D::Error::custom(format!("invalid color {hex:?}: {err}")))
+}
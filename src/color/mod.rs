@@ -17,7 +17,8 @@
 //! # Example
 //!
 //! ```rust,ignore
-//! use bevy_material_ui::color::{Hct, TonalPalette, MaterialColorScheme};
+//! use bevy::prelude::Color;
+//! use bevy_material_ui::color::{Hct, MaterialColorScheme, SchemeVariant, TonalPalette};
 //!
 //! // Create an HCT color from a hex value
 //! let seed = Hct::from_argb(0xFF6750A4);
@@ -30,12 +31,24 @@
 //!
 //! // Generate a complete color scheme
 //! let scheme = MaterialColorScheme::dark_from_argb(0xFF6750A4);
+//!
+//! // Or pick a scheme variant and contrast level
+//! let vibrant = MaterialColorScheme::from_seed_with(
+//!     Color::srgb(0.4, 0.31, 0.64),
+//!     SchemeVariant::Vibrant,
+//!     true,
+//!     0.0,
+//! );
 //! ```
 
+mod blend;
 mod hct_bevy;
+#[cfg(feature = "serde")]
+pub(crate) mod hex_color;
 mod palette;
 mod scheme;
 
+pub use blend::{blend_cam16, harmonize, HARMONIZE_MAX_ROTATION};
 pub use hct_bevy::Hct;
-pub use palette::TonalPalette;
+pub use palette::{SchemeVariant, TonalPalette};
 pub use scheme::MaterialColorScheme;
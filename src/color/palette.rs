@@ -65,12 +65,36 @@ impl TonalPalette {
 
     /// Get a color at the specified tone (0-100)
     ///
+    /// Whole tones are solved exactly and cached. Fractional tones (e.g. `42.5`)
+    /// are interpolated between the two neighbouring whole tones, so they reuse
+    /// the cache instead of solving HCT again.
+    ///
     /// # Arguments
     /// * `tone` - Lightness level from 0 (black) to 100 (white)
     ///
     /// # Returns
     /// ARGB integer representing the color
-    pub fn tone(&mut self, tone: u8) -> u32 {
+    pub fn tone(&mut self, tone: impl Into<f64>) -> u32 {
+        let tone = tone.into();
+        let tone = if tone.is_nan() {
+            0.0
+        } else {
+            tone.clamp(0.0, 100.0)
+        };
+
+        let lower = tone.floor();
+        let fraction = tone - lower;
+        let low = self.whole_tone(lower as u8);
+        if fraction <= f64::EPSILON {
+            return low;
+        }
+
+        let high = self.whole_tone(lower as u8 + 1);
+        lerp_argb(low, high, fraction)
+    }
+
+    /// Get (and cache) the color at a whole tone
+    fn whole_tone(&mut self, tone: u8) -> u32 {
         let tone = tone.min(100);
 
         if let Some(&cached) = self.cache.get(&tone) {
@@ -83,18 +107,23 @@ impl TonalPalette {
         argb
     }
 
+    /// Number of whole tones currently cached
+    pub fn cached_tones(&self) -> usize {
+        self.cache.len()
+    }
+
     /// Get a Bevy Color at the specified tone
-    pub fn tone_color(&mut self, tone: u8) -> Color {
+    pub fn tone_color(&mut self, tone: impl Into<f64>) -> Color {
         let argb = self.tone(tone);
         argb_to_bevy_color(argb)
     }
 
     /// Get an HCT color at the specified tone
-    pub fn tone_hct(&self, tone: u8) -> Hct {
-        Hct::new(self.hue, self.chroma, tone.min(100) as f64)
+    pub fn tone_hct(&self, tone: impl Into<f64>) -> Hct {
+        Hct::new(self.hue, self.chroma, tone.into().clamp(0.0, 100.0))
     }
 
-    /// Pre-cache all standard tones
+    /// Pre-cache all standard tones (including the key tones 0, 10, ..., 100)
     pub fn cache_standard_tones(&mut self) {
         for &tone in STANDARD_TONES {
             self.tone(tone);
@@ -102,6 +131,31 @@ impl TonalPalette {
     }
 }
 
+/// Dynamic color scheme variant
+///
+/// Each variant derives the core palettes from the seed with different chroma
+/// levels and hue rotations, from fully grey (`Monochrome`) to highly colorful
+/// (`Vibrant`, `Expressive`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemeVariant {
+    /// Calm, seed-hued scheme with a low-chroma secondary (the MD3 default)
+    #[default]
+    TonalSpot,
+    /// Maximum primary chroma with colorful secondary/tertiary palettes
+    Vibrant,
+    /// Playful scheme whose primary hue is rotated away from the seed
+    Expressive,
+    /// Near-grey scheme with a hint of the seed hue
+    Neutral,
+    /// Pure greyscale scheme
+    Monochrome,
+    /// Keeps the seed chroma; tertiary uses the complementary hue
+    Fidelity,
+    /// Keeps the seed chroma; tertiary uses an analogous hue
+    Content,
+}
+
 /// Core tonal palettes for a Material Design 3 color scheme
 #[derive(Debug, Clone)]
 pub struct CorePalette {
@@ -160,6 +214,68 @@ impl CorePalette {
         Self::from_hct(&Hct::from_bevy_color(color))
     }
 
+    /// Create a CorePalette from a seed HCT color for a scheme variant
+    pub fn from_hct_with_variant(seed: &Hct, variant: SchemeVariant) -> Self {
+        let hue = seed.hue();
+        let chroma = seed.chroma();
+        let rotate = |degrees: f64| (hue + degrees).rem_euclid(360.0);
+        let error = TonalPalette::new(25.0, 84.0);
+
+        match variant {
+            SchemeVariant::TonalSpot => Self::from_hct(seed),
+            SchemeVariant::Vibrant => Self {
+                // 200 exceeds any displayable chroma, so tones clamp to the gamut edge.
+                primary: TonalPalette::new(hue, 200.0),
+                secondary: TonalPalette::new(rotate(15.0), 24.0),
+                tertiary: TonalPalette::new(rotate(45.0), 32.0),
+                neutral: TonalPalette::new(hue, 10.0),
+                neutral_variant: TonalPalette::new(hue, 12.0),
+                error,
+            },
+            SchemeVariant::Expressive => Self {
+                primary: TonalPalette::new(rotate(240.0), 40.0),
+                secondary: TonalPalette::new(rotate(15.0), 24.0),
+                tertiary: TonalPalette::new(rotate(90.0), 32.0),
+                neutral: TonalPalette::new(rotate(15.0), 8.0),
+                neutral_variant: TonalPalette::new(rotate(15.0), 12.0),
+                error,
+            },
+            SchemeVariant::Neutral => Self {
+                primary: TonalPalette::new(hue, 12.0),
+                secondary: TonalPalette::new(hue, 8.0),
+                tertiary: TonalPalette::new(rotate(60.0), 16.0),
+                neutral: TonalPalette::new(hue, 2.0),
+                neutral_variant: TonalPalette::new(hue, 2.0),
+                error,
+            },
+            SchemeVariant::Monochrome => Self {
+                primary: TonalPalette::new(hue, 0.0),
+                secondary: TonalPalette::new(hue, 0.0),
+                tertiary: TonalPalette::new(hue, 0.0),
+                neutral: TonalPalette::new(hue, 0.0),
+                neutral_variant: TonalPalette::new(hue, 0.0),
+                error,
+            },
+            SchemeVariant::Fidelity | SchemeVariant::Content => {
+                let tertiary_hue = if variant == SchemeVariant::Fidelity {
+                    rotate(180.0)
+                } else {
+                    rotate(60.0)
+                };
+                let accent_chroma = (chroma - 32.0).max(chroma * 0.5);
+
+                Self {
+                    primary: TonalPalette::new(hue, chroma),
+                    secondary: TonalPalette::new(hue, accent_chroma),
+                    tertiary: TonalPalette::new(tertiary_hue, accent_chroma),
+                    neutral: TonalPalette::new(hue, chroma / 8.0),
+                    neutral_variant: TonalPalette::new(hue, chroma / 8.0 + 4.0),
+                    error,
+                }
+            }
+        }
+    }
+
     /// Pre-cache all standard tones for all palettes
     pub fn cache_all(&mut self) {
         self.primary.cache_standard_tones();
@@ -171,6 +287,16 @@ impl CorePalette {
     }
 }
 
+/// Linearly interpolate two opaque ARGB colors per channel
+fn lerp_argb(a: u32, b: u32, t: f64) -> u32 {
+    let channel = |shift: u32| {
+        let from = ((a >> shift) & 0xFF) as f64;
+        let to = ((b >> shift) & 0xFF) as f64;
+        ((from + (to - from) * t).round() as u32).min(0xFF) << shift
+    };
+    0xFF00_0000 | channel(16) | channel(8) | channel(0)
+}
+
 /// Convert ARGB to Bevy Color
 fn argb_to_bevy_color(argb: u32) -> Color {
     let r = ((argb >> 16) & 0xFF) as f32 / 255.0;
@@ -229,6 +355,26 @@ mod tests {
         assert!(palette.error.hue() < 50.0 || palette.error.hue() > 330.0);
     }
 
+    #[test]
+    fn test_scheme_variant_chroma() {
+        let seed = Hct::from_argb(0xFF6750A4);
+        let expressive = CorePalette::from_hct_with_variant(&seed, SchemeVariant::Expressive);
+        let neutral = CorePalette::from_hct_with_variant(&seed, SchemeVariant::Neutral);
+
+        assert!(expressive.secondary.chroma() > neutral.secondary.chroma());
+        assert!(expressive.tertiary.chroma() > neutral.tertiary.chroma());
+
+        let mono = CorePalette::from_hct_with_variant(&seed, SchemeVariant::Monochrome);
+        assert_eq!(mono.primary.chroma(), 0.0);
+        assert_eq!(mono.neutral_variant.chroma(), 0.0);
+
+        // TonalSpot is the default palette.
+        let tonal_spot = CorePalette::from_hct_with_variant(&seed, SchemeVariant::TonalSpot);
+        let default = CorePalette::from_hct(&seed);
+        assert_eq!(tonal_spot.primary.chroma(), default.primary.chroma());
+        assert_eq!(tonal_spot.tertiary.hue(), default.tertiary.hue());
+    }
+
     #[test]
     fn test_palette_caching() {
         let mut palette = TonalPalette::new(200.0, 40.0);
@@ -242,6 +388,48 @@ mod tests {
         assert_eq!(first, second);
     }
 
+    #[test]
+    fn test_fractional_tones() {
+        let mut palette = TonalPalette::new(270.0, 40.0);
+
+        // Whole tones passed as floats match the exact tone.
+        assert_eq!(palette.tone(40.0), palette.tone(40u8));
+
+        // A fractional tone sits between its neighbours.
+        let low = palette.tone(40) & 0xFF;
+        let mid = palette.tone(40.5) & 0xFF;
+        let high = palette.tone(41) & 0xFF;
+        assert!(low <= mid && mid <= high);
+
+        // Out-of-range tones clamp.
+        assert_eq!(palette.tone(150.0), palette.tone(100));
+        assert_eq!(palette.tone(-5.0), palette.tone(0));
+    }
+
+    #[test]
+    fn test_repeated_tone_lookups_are_cached() {
+        let mut palette = TonalPalette::new(210.0, 36.0);
+        palette.cache_standard_tones();
+        let cached = palette.cached_tones();
+        let first = palette.tone(40);
+
+        for i in 0..10_000 {
+            let tone = [0.0, 10.0, 40.0, 90.0, 100.0][i % 5];
+            palette.tone(tone);
+        }
+
+        // No new HCT solves happened, and the cached value is stable.
+        assert_eq!(palette.cached_tones(), cached);
+        assert_eq!(palette.tone(40), first);
+
+        // A fractional tone only solves its two neighbouring whole tones once.
+        palette.tone(42.5);
+        let after_fraction = palette.cached_tones();
+        assert!(after_fraction <= cached + 2);
+        palette.tone(42.25);
+        assert_eq!(palette.cached_tones(), after_fraction);
+    }
+
     #[test]
     fn test_bevy_color_conversion() {
         let mut palette = TonalPalette::new(120.0, 40.0);
@@ -39,122 +39,169 @@
 //! - `outline` - Prominent borders
 //! - `outline_variant` - Subtle borders
 
-use super::palette::CorePalette;
+use super::palette::{CorePalette, SchemeVariant};
+use super::Hct;
 use bevy::prelude::Color;
 
 /// A complete Material Design 3 color scheme
 ///
 /// Contains all 26 standard color roles derived from a seed color.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaterialColorScheme {
     // Primary
     /// Primary brand color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary: Color,
     /// Content color on primary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary: Color,
     /// Primary container background
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary_container: Color,
     /// Content color on primary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary_container: Color,
 
     // Secondary
     /// Secondary brand color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary: Color,
     /// Content color on secondary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary: Color,
     /// Secondary container background
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary_container: Color,
     /// Content color on secondary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary_container: Color,
 
     // Tertiary
     /// Tertiary accent color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary: Color,
     /// Content color on tertiary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary: Color,
     /// Tertiary container background
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary_container: Color,
     /// Content color on tertiary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary_container: Color,
 
     // Error
     /// Error state color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub error: Color,
     /// Content color on error
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_error: Color,
     /// Error container background
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub error_container: Color,
     /// Content color on error container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_error_container: Color,
 
     // Surface
     /// Main surface background
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface: Color,
     /// Bright surface for emphasis
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_bright: Color,
     /// Dim surface for de-emphasis
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_dim: Color,
     /// Primary content on surface
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_surface: Color,
     /// Secondary content on surface
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_surface_variant: Color,
 
     // Surface Containers
     /// Lowest emphasis container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_lowest: Color,
     /// Low emphasis container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_low: Color,
     /// Default container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container: Color,
     /// High emphasis container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_high: Color,
     /// Highest emphasis container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_highest: Color,
 
     // Outline
     /// Primary outline/border
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub outline: Color,
     /// Subtle outline/border
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub outline_variant: Color,
 
     // Inverse (for contrast)
     /// Inverse surface color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_surface: Color,
     /// Content on inverse surface
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_on_surface: Color,
     /// Inverse primary color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_primary: Color,
 
     // Fixed Accent (constant across themes)
     /// Fixed primary color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary_fixed: Color,
     /// Dimmed fixed primary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary_fixed_dim: Color,
     /// Content on fixed primary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary_fixed: Color,
     /// Variant content on fixed primary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary_fixed_variant: Color,
     /// Fixed secondary color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary_fixed: Color,
     /// Dimmed fixed secondary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary_fixed_dim: Color,
     /// Content on fixed secondary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary_fixed: Color,
     /// Variant content on fixed secondary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary_fixed_variant: Color,
     /// Fixed tertiary color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary_fixed: Color,
     /// Dimmed fixed tertiary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary_fixed_dim: Color,
     /// Content on fixed tertiary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary_fixed: Color,
     /// Variant content on fixed tertiary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary_fixed_variant: Color,
 
     // Utility
     /// Scrim overlay color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub scrim: Color,
     /// Shadow color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub shadow: Color,
 }
 
@@ -194,125 +241,119 @@ impl MaterialColorScheme {
         Self::light_from_palette(&mut palette)
     }
 
+    /// Generate a color scheme for a seed, scheme variant and contrast level
+    ///
+    /// `contrast` ranges from -1.0 (reduced) through 0.0 (standard) to 1.0 (high).
+    pub fn from_seed_with(seed: Color, variant: SchemeVariant, dark: bool, contrast: f64) -> Self {
+        let mut palette = CorePalette::from_hct_with_variant(&Hct::from_bevy_color(seed), variant);
+        palette.cache_all();
+        Self::from_palette(&mut palette, dark, contrast)
+    }
+
     /// Generate a dark color scheme from a CorePalette
     pub fn dark_from_palette(p: &mut CorePalette) -> Self {
-        Self {
-            // Primary - dark theme uses lighter tones for foreground
-            primary: argb_to_color(p.primary.tone(80)),
-            on_primary: argb_to_color(p.primary.tone(20)),
-            primary_container: argb_to_color(p.primary.tone(30)),
-            on_primary_container: argb_to_color(p.primary.tone(90)),
-
-            // Secondary
-            secondary: argb_to_color(p.secondary.tone(80)),
-            on_secondary: argb_to_color(p.secondary.tone(20)),
-            secondary_container: argb_to_color(p.secondary.tone(30)),
-            on_secondary_container: argb_to_color(p.secondary.tone(90)),
-
-            // Tertiary
-            tertiary: argb_to_color(p.tertiary.tone(80)),
-            on_tertiary: argb_to_color(p.tertiary.tone(20)),
-            tertiary_container: argb_to_color(p.tertiary.tone(30)),
-            on_tertiary_container: argb_to_color(p.tertiary.tone(90)),
-
-            // Error
-            error: argb_to_color(p.error.tone(80)),
-            on_error: argb_to_color(p.error.tone(20)),
-            error_container: argb_to_color(p.error.tone(30)),
-            on_error_container: argb_to_color(p.error.tone(90)),
-
-            // Surface - dark theme
-            surface: argb_to_color(p.neutral.tone(6)),
-            surface_bright: argb_to_color(p.neutral.tone(24)),
-            surface_dim: argb_to_color(p.neutral.tone(6)),
-            on_surface: argb_to_color(p.neutral.tone(90)),
-            on_surface_variant: argb_to_color(p.neutral_variant.tone(80)),
-
-            // Surface Containers - dark theme
-            surface_container_lowest: argb_to_color(p.neutral.tone(4)),
-            surface_container_low: argb_to_color(p.neutral.tone(10)),
-            surface_container: argb_to_color(p.neutral.tone(12)),
-            surface_container_high: argb_to_color(p.neutral.tone(17)),
-            surface_container_highest: argb_to_color(p.neutral.tone(22)),
-
-            // Outline
-            outline: argb_to_color(p.neutral_variant.tone(60)),
-            outline_variant: argb_to_color(p.neutral_variant.tone(30)),
-
-            // Inverse
-            inverse_surface: argb_to_color(p.neutral.tone(90)),
-            inverse_on_surface: argb_to_color(p.neutral.tone(20)),
-            inverse_primary: argb_to_color(p.primary.tone(40)),
-
-            // Fixed Accent (same in both themes)
-            primary_fixed: argb_to_color(p.primary.tone(90)),
-            primary_fixed_dim: argb_to_color(p.primary.tone(80)),
-            on_primary_fixed: argb_to_color(p.primary.tone(10)),
-            on_primary_fixed_variant: argb_to_color(p.primary.tone(30)),
-            secondary_fixed: argb_to_color(p.secondary.tone(90)),
-            secondary_fixed_dim: argb_to_color(p.secondary.tone(80)),
-            on_secondary_fixed: argb_to_color(p.secondary.tone(10)),
-            on_secondary_fixed_variant: argb_to_color(p.secondary.tone(30)),
-            tertiary_fixed: argb_to_color(p.tertiary.tone(90)),
-            tertiary_fixed_dim: argb_to_color(p.tertiary.tone(80)),
-            on_tertiary_fixed: argb_to_color(p.tertiary.tone(10)),
-            on_tertiary_fixed_variant: argb_to_color(p.tertiary.tone(30)),
-
-            // Utility
-            scrim: Color::BLACK,
-            shadow: Color::BLACK,
-        }
+        Self::from_palette(p, true, 0.0)
     }
 
     /// Generate a light color scheme from a CorePalette
     pub fn light_from_palette(p: &mut CorePalette) -> Self {
+        Self::from_palette(p, false, 0.0)
+    }
+
+    /// Generate a color scheme from a CorePalette at a contrast level
+    pub fn from_palette(p: &mut CorePalette, dark: bool, contrast: f64) -> Self {
+        let t = |standard: f64, reduced: f64, high: f64| {
+            contrast_tone(standard, reduced, high, contrast)
+        };
+
+        // Accent roles (primary, secondary, tertiary, error).
+        // Dark themes use lighter tones for foreground, light themes darker ones.
+        let (accent, on_accent, container, on_container) = if dark {
+            (
+                t(80.0, 70.0, 90.0),
+                t(20.0, 30.0, 10.0),
+                30,
+                t(90.0, 80.0, 100.0),
+            )
+        } else {
+            (t(40.0, 50.0, 25.0), 100, 90, t(10.0, 25.0, 0.0))
+        };
+
+        // Content on surfaces and outlines
+        let (on_surface, on_surface_variant, outline, outline_variant) = if dark {
+            (
+                t(90.0, 85.0, 100.0),
+                t(80.0, 70.0, 95.0),
+                t(60.0, 50.0, 80.0),
+                t(30.0, 25.0, 50.0),
+            )
+        } else {
+            (
+                t(10.0, 15.0, 0.0),
+                t(30.0, 40.0, 15.0),
+                t(50.0, 60.0, 25.0),
+                t(80.0, 85.0, 55.0),
+            )
+        };
+
+        // Surfaces: (surface, bright, dim, lowest, low, container, high, highest)
+        let surfaces: [u8; 8] = if dark {
+            [6, 24, 6, 4, 10, 12, 17, 22]
+        } else {
+            [98, 98, 87, 100, 96, 94, 92, 90]
+        };
+
+        // Inverse: (surface, on_surface, primary)
+        let (inverse_surface, inverse_on_surface, inverse_primary) =
+            if dark { (90, 20, 40) } else { (20, 95, 80) };
+
         Self {
-            // Primary - light theme uses darker tones for foreground
-            primary: argb_to_color(p.primary.tone(40)),
-            on_primary: argb_to_color(p.primary.tone(100)),
-            primary_container: argb_to_color(p.primary.tone(90)),
-            on_primary_container: argb_to_color(p.primary.tone(10)),
+            // Primary
+            primary: argb_to_color(p.primary.tone(accent)),
+            on_primary: argb_to_color(p.primary.tone(on_accent)),
+            primary_container: argb_to_color(p.primary.tone(container)),
+            on_primary_container: argb_to_color(p.primary.tone(on_container)),
 
             // Secondary
-            secondary: argb_to_color(p.secondary.tone(40)),
-            on_secondary: argb_to_color(p.secondary.tone(100)),
-            secondary_container: argb_to_color(p.secondary.tone(90)),
-            on_secondary_container: argb_to_color(p.secondary.tone(10)),
+            secondary: argb_to_color(p.secondary.tone(accent)),
+            on_secondary: argb_to_color(p.secondary.tone(on_accent)),
+            secondary_container: argb_to_color(p.secondary.tone(container)),
+            on_secondary_container: argb_to_color(p.secondary.tone(on_container)),
 
             // Tertiary
-            tertiary: argb_to_color(p.tertiary.tone(40)),
-            on_tertiary: argb_to_color(p.tertiary.tone(100)),
-            tertiary_container: argb_to_color(p.tertiary.tone(90)),
-            on_tertiary_container: argb_to_color(p.tertiary.tone(10)),
+            tertiary: argb_to_color(p.tertiary.tone(accent)),
+            on_tertiary: argb_to_color(p.tertiary.tone(on_accent)),
+            tertiary_container: argb_to_color(p.tertiary.tone(container)),
+            on_tertiary_container: argb_to_color(p.tertiary.tone(on_container)),
 
             // Error
-            error: argb_to_color(p.error.tone(40)),
-            on_error: argb_to_color(p.error.tone(100)),
-            error_container: argb_to_color(p.error.tone(90)),
-            on_error_container: argb_to_color(p.error.tone(10)),
-
-            // Surface - light theme
-            surface: argb_to_color(p.neutral.tone(98)),
-            surface_bright: argb_to_color(p.neutral.tone(98)),
-            surface_dim: argb_to_color(p.neutral.tone(87)),
-            on_surface: argb_to_color(p.neutral.tone(10)),
-            on_surface_variant: argb_to_color(p.neutral_variant.tone(30)),
-
-            // Surface Containers - light theme
-            surface_container_lowest: argb_to_color(p.neutral.tone(100)),
-            surface_container_low: argb_to_color(p.neutral.tone(96)),
-            surface_container: argb_to_color(p.neutral.tone(94)),
-            surface_container_high: argb_to_color(p.neutral.tone(92)),
-            surface_container_highest: argb_to_color(p.neutral.tone(90)),
+            error: argb_to_color(p.error.tone(accent)),
+            on_error: argb_to_color(p.error.tone(on_accent)),
+            error_container: argb_to_color(p.error.tone(container)),
+            on_error_container: argb_to_color(p.error.tone(on_container)),
+
+            // Surface
+            surface: argb_to_color(p.neutral.tone(surfaces[0])),
+            surface_bright: argb_to_color(p.neutral.tone(surfaces[1])),
+            surface_dim: argb_to_color(p.neutral.tone(surfaces[2])),
+            on_surface: argb_to_color(p.neutral.tone(on_surface)),
+            on_surface_variant: argb_to_color(p.neutral_variant.tone(on_surface_variant)),
+
+            // Surface Containers
+            surface_container_lowest: argb_to_color(p.neutral.tone(surfaces[3])),
+            surface_container_low: argb_to_color(p.neutral.tone(surfaces[4])),
+            surface_container: argb_to_color(p.neutral.tone(surfaces[5])),
+            surface_container_high: argb_to_color(p.neutral.tone(surfaces[6])),
+            surface_container_highest: argb_to_color(p.neutral.tone(surfaces[7])),
 
             // Outline
-            outline: argb_to_color(p.neutral_variant.tone(50)),
-            outline_variant: argb_to_color(p.neutral_variant.tone(80)),
+            outline: argb_to_color(p.neutral_variant.tone(outline)),
+            outline_variant: argb_to_color(p.neutral_variant.tone(outline_variant)),
 
             // Inverse
-            inverse_surface: argb_to_color(p.neutral.tone(20)),
-            inverse_on_surface: argb_to_color(p.neutral.tone(95)),
-            inverse_primary: argb_to_color(p.primary.tone(80)),
+            inverse_surface: argb_to_color(p.neutral.tone(inverse_surface)),
+            inverse_on_surface: argb_to_color(p.neutral.tone(inverse_on_surface)),
+            inverse_primary: argb_to_color(p.primary.tone(inverse_primary)),
 
             // Fixed Accent (same in both themes)
             primary_fixed: argb_to_color(p.primary.tone(90)),
@@ -335,6 +376,17 @@ impl MaterialColorScheme {
     }
 }
 
+/// Tone for a role at a contrast level.
+///
+/// `contrast` moves the `standard` tone linearly toward `reduced` (at -1.0)
+/// or `high` (at 1.0).
+fn contrast_tone(standard: f64, reduced: f64, high: f64, contrast: f64) -> u8 {
+    let contrast = contrast.clamp(-1.0, 1.0);
+    let target = if contrast >= 0.0 { high } else { reduced };
+    let tone = standard + (target - standard) * contrast.abs();
+    tone.round().clamp(0.0, 100.0) as u8
+}
+
 /// Convert ARGB to Bevy Color
 fn argb_to_color(argb: u32) -> Color {
     let r = ((argb >> 16) & 0xFF) as f32 / 255.0;
@@ -419,6 +471,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scheme_variants_and_contrast() {
+        let seed = Color::srgb(0.4, 0.31, 0.64);
+
+        let standard =
+            MaterialColorScheme::from_seed_with(seed, SchemeVariant::TonalSpot, true, 0.0);
+        let default = MaterialColorScheme::dark_from_bevy_color(seed);
+        assert_eq!(standard.primary, default.primary);
+        assert_eq!(standard.on_surface_variant, default.on_surface_variant);
+
+        // Expressive secondary is more saturated than Neutral.
+        fn chroma(c: Color) -> f64 {
+            Hct::from_bevy_color(c).chroma()
+        }
+        let expressive =
+            MaterialColorScheme::from_seed_with(seed, SchemeVariant::Expressive, false, 0.0);
+        let neutral = MaterialColorScheme::from_seed_with(seed, SchemeVariant::Neutral, false, 0.0);
+        assert!(chroma(expressive.secondary_container) > chroma(neutral.secondary_container));
+        assert!(chroma(expressive.tertiary) > chroma(neutral.tertiary));
+
+        // High contrast pushes on-container tones away from their containers.
+        assert_eq!(contrast_tone(10.0, 25.0, 0.0, 1.0), 0);
+        assert_eq!(contrast_tone(10.0, 25.0, 0.0, -1.0), 25);
+        assert_eq!(contrast_tone(80.0, 70.0, 90.0, 0.5), 85);
+        assert_eq!(contrast_tone(80.0, 70.0, 90.0, 5.0), 90);
+    }
+
     #[test]
     fn test_from_bevy_color() {
         let seed = Color::srgb(0.4, 0.31, 0.64);
@@ -430,4 +509,18 @@ mod tests {
         assert!(primary_srgba.green >= 0.0 && primary_srgba.green <= 1.0);
         assert!(primary_srgba.blue >= 0.0 && primary_srgba.blue <= 1.0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scheme_json_round_trip() {
+        let scheme = MaterialColorScheme::light_from_argb(0xFF6750A4);
+        let json = serde_json::to_string(&scheme).unwrap();
+        let loaded: MaterialColorScheme = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+        assert_eq!(loaded.primary, scheme.primary);
+        assert_eq!(
+            loaded.on_tertiary_fixed_variant,
+            scheme.on_tertiary_fixed_variant
+        );
+    }
 }
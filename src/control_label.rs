@@ -0,0 +1,134 @@
+//! Clickable labels for selection controls
+//!
+//! Checkboxes, radio buttons, and switches spawned with a label sit in a row
+//! tagged with [`ControlLabel`]. A press anywhere on that row, including the
+//! label text, is forwarded to the control, so the row is a single touch
+//! target. Presses on the control itself reach it directly, since its `Button`
+//! blocks them from the row underneath.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+
+use crate::disabled::Disabled;
+use crate::theme::MaterialTheme;
+use crate::typography::TypeRole;
+
+/// Minimum height of a labeled control row (the Material touch target)
+pub const CONTROL_LABEL_MIN_HEIGHT: f32 = 48.0;
+
+/// Plugin that forwards label presses to their controls
+pub struct ControlLabelPlugin;
+
+impl Plugin for ControlLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            control_label_press_system.after(UiSystems::Focus),
+        );
+    }
+}
+
+/// Row containing a control and its label; pressing it presses `control`
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Interaction)]
+pub struct ControlLabel {
+    /// The checkbox, radio button, or switch the label belongs to
+    pub control: Entity,
+}
+
+impl ControlLabel {
+    /// Link a label row to `control`
+    pub fn new(control: Entity) -> Self {
+        Self { control }
+    }
+}
+
+/// Marker for the text of a [`ControlLabel`] row
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ControlLabelText;
+
+/// Turn `root` into a [`ControlLabel`] row holding a control and its label.
+///
+/// `spawn_control` spawns the control in the row and returns its entity.
+/// Returns the row entity.
+pub(crate) fn spawn_labeled_control(
+    mut root: EntityCommands,
+    theme: &MaterialTheme,
+    label: String,
+    spawn_control: impl FnOnce(&mut ChildSpawnerCommands) -> Entity,
+) -> Entity {
+    let mut control = Entity::PLACEHOLDER;
+    root.insert(Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(12.0),
+        min_height: Val::Px(CONTROL_LABEL_MIN_HEIGHT),
+        ..default()
+    })
+    .with_children(|row| {
+        control = spawn_control(row);
+
+        row.spawn((
+            ControlLabelText,
+            Text::new(label),
+            TypeRole::BodyMedium.text_bundle(),
+            TextColor(theme.on_surface),
+        ));
+    });
+    root.insert(ControlLabel::new(control));
+    root.id()
+}
+
+/// Press the control when its label row is pressed.
+///
+/// Runs right after Bevy's UI focus pass, so the control's own interaction
+/// system handles the press as if the control had been clicked. Bevy resets
+/// the control to `Interaction::None` on release.
+fn control_label_press_system(
+    labels: Query<(&Interaction, &ControlLabel), Changed<Interaction>>,
+    mut controls: Query<&mut Interaction, (Without<ControlLabel>, Without<Disabled>)>,
+) {
+    for (interaction, label) in labels.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut control) = controls.get_mut(label.control) {
+            control.set_if_neq(Interaction::Pressed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_press_reaches_control() {
+        let mut app = App::new();
+        app.add_systems(Update, control_label_press_system);
+
+        let control = app.world_mut().spawn(Interaction::None).id();
+        let disabled = app.world_mut().spawn((Interaction::None, Disabled)).id();
+        let label = app.world_mut().spawn(ControlLabel::new(control)).id();
+        let disabled_label = app.world_mut().spawn(ControlLabel::new(disabled)).id();
+        app.update();
+        assert_eq!(
+            app.world().get::<Interaction>(control),
+            Some(&Interaction::None)
+        );
+
+        for entity in [label, disabled_label] {
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Pressed;
+        }
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Interaction>(control),
+            Some(&Interaction::Pressed)
+        );
+        assert_eq!(
+            app.world().get::<Interaction>(disabled),
+            Some(&Interaction::None)
+        );
+    }
+}
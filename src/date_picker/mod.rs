@@ -7,12 +7,18 @@ use bevy::picking::Pickable;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
+use crate::disabled::Disabled;
+use crate::elevation::Elevation;
 use crate::i18n::{MaterialI18n, MaterialLanguage, MaterialLanguageOverride};
 use crate::icons::material_icon_names;
 use crate::locale::{
     date_input_pattern_for_locale, DateFieldOrder, DateInputPattern, MaterialLocale,
     MaterialLocaleOverride,
 };
+use crate::overlay::{
+    open_on_shortcut_system, track_overlay_system, DismissOnOutsideClick, OpenableOverlay,
+    OutsideClickEvent, Overlay, OverlayStack, Z_DIALOG,
+};
 use crate::text_field::{
     spawn_text_field_control_with, MaterialTextField, TextFieldBuilder, TextFieldChangeEvent,
     TextFieldFormatter,
@@ -43,13 +49,18 @@ impl Plugin for DatePickerPlugin {
                 Update,
                 (
                     date_picker_localization_system,
+                    date_picker_restore_committed_system,
                     date_picker_visibility_system,
+                    track_overlay_system::<MaterialDatePicker>,
+                    open_on_shortcut_system::<MaterialDatePicker>,
+                    date_picker_outside_click_setup_system,
                     date_picker_keyboard_dismiss_system,
                     date_picker_mode_toggle_system,
                     date_picker_month_nav_system,
                     date_picker_year_selector_toggle_system,
                     date_picker_year_selection_system,
                     date_picker_day_selection_system,
+                    date_picker_range_hover_system,
                     date_picker_text_input_system,
                     date_picker_action_system,
                 ),
@@ -58,7 +69,9 @@ impl Plugin for DatePickerPlugin {
                 Update,
                 (
                     date_picker_rebuild_content_system,
+                    date_picker_day_cell_layers_system,
                     date_picker_render_system,
+                    date_picker_year_highlight_system,
                     date_picker_view_visibility_system,
                     date_picker_theme_system,
                 ),
@@ -212,6 +225,12 @@ pub struct MaterialDatePicker {
     pub dismiss_on_scrim_click: bool,
     /// Dismiss on escape key
     pub dismiss_on_escape: bool,
+    /// Hovered day previewing the end of an incomplete range
+    pub(crate) range_hover: Option<Date>,
+    /// Last submitted selection, restored whenever the picker opens
+    pub(crate) committed: Option<DateSelection>,
+    /// Open state seen by the restore system, to detect the open transition
+    pub(crate) was_open: bool,
 }
 
 impl MaterialDatePicker {
@@ -219,6 +238,37 @@ impl MaterialDatePicker {
     pub fn selection(&self) -> Option<DateSelection> {
         self.selector.selection()
     }
+
+    /// Returns the last submitted selection, which the picker shows when it opens.
+    pub fn committed_selection(&self) -> Option<DateSelection> {
+        self.committed.clone()
+    }
+
+    /// Replace the committed selection (and the current one) programmatically.
+    ///
+    /// Use this before opening the picker to reset what it starts from.
+    pub fn set_selection(&mut self, selection: Option<DateSelection>) {
+        self.committed = selection;
+        self.restore_committed();
+    }
+
+    /// Discard in-progress edits and go back to the committed selection.
+    pub(crate) fn restore_committed(&mut self) {
+        self.selector.clear();
+        match self.committed.clone() {
+            Some(selection) => {
+                let date = match &selection {
+                    DateSelection::Single(date) => *date,
+                    DateSelection::Range { start, .. } => *start,
+                };
+                self.display_month = Month::new(date.year, date.month);
+                self.selector.set_selection(selection);
+            }
+            None => self.display_month = self.constraints.opening,
+        }
+        self.showing_years = false;
+        self.range_hover = None;
+    }
 }
 
 impl std::fmt::Debug for MaterialDatePicker {
@@ -235,10 +285,23 @@ impl std::fmt::Debug for MaterialDatePicker {
             .field("first_day_of_week", &self.first_day_of_week)
             .field("dismiss_on_scrim_click", &self.dismiss_on_scrim_click)
             .field("dismiss_on_escape", &self.dismiss_on_escape)
+            .field("committed", &self.committed)
             .finish()
     }
 }
 
+impl Overlay for MaterialDatePicker {
+    fn overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
+impl OpenableOverlay for MaterialDatePicker {
+    fn open_overlay(&mut self) {
+        self.open = true;
+    }
+}
+
 /// Builder for Material Date Picker
 #[derive(Debug, Clone)]
 pub struct DatePickerBuilder {
@@ -252,7 +315,7 @@ pub struct DatePickerBuilder {
     dismiss_on_scrim_click: bool,
     dismiss_on_escape: bool,
     width: Val,
-    locale_override: Option<String>,
+    locale_override: Option<DateLocale>,
 }
 
 impl Default for DatePickerBuilder {
@@ -344,10 +407,11 @@ impl DatePickerBuilder {
 
     /// Override the locale for this date picker instance.
     ///
-    /// This is applied by attaching a `MaterialLocaleOverride` to the date picker root entity.
-    /// If not set, the picker uses the global `MaterialLocale` resource.
-    pub fn locale(mut self, tag: impl Into<String>) -> Self {
-        self.locale_override = Some(tag.into());
+    /// Accepts a locale tag (built-in month/weekday names) or a custom [`DateLocale`].
+    /// This is applied by attaching a `MaterialLocaleOverride` and the `DateLocale` to the
+    /// date picker root entity. If not set, the picker uses the global `MaterialLocale` resource.
+    pub fn locale(mut self, locale: impl Into<DateLocale>) -> Self {
+        self.locale_override = Some(locale.into());
         self
     }
 
@@ -390,6 +454,9 @@ impl DatePickerBuilder {
             first_day_of_week: self.first_day_of_week,
             dismiss_on_scrim_click: self.dismiss_on_scrim_click,
             dismiss_on_escape: self.dismiss_on_escape,
+            range_hover: None,
+            committed: self.initial_selection.clone(),
+            was_open: false,
         }
     }
 }
@@ -414,12 +481,12 @@ pub struct DatePickerCancelEvent {
 // ============================================================================
 
 #[derive(Component)]
-struct DatePickerScrim {
-    picker: Entity,
-}
+struct DatePickerScrim;
 
 #[derive(Component)]
-struct DatePickerDialog;
+struct DatePickerDialog {
+    picker: Entity,
+}
 
 #[derive(Component)]
 struct DatePickerModeToggle {
@@ -457,6 +524,68 @@ struct DatePickerYearCell {
 struct DatePickerDayCell {
     picker: Entity,
     date: Option<Date>,
+    /// Column within the week row (0..7)
+    column: u8,
+}
+
+/// Range highlight drawn behind a day cell, extended into the gaps between cells
+#[derive(Component)]
+struct DatePickerRangeBand;
+
+/// Circle drawn behind a day's label for selected dates and today
+#[derive(Component)]
+struct DatePickerDayIndicator;
+
+/// How one side of a day cell's range band ends
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RangeBandEdge {
+    /// The range starts/ends on this day: the band stops under the selection circle.
+    Center,
+    /// The range continues on another week row: the band is rounded at the cell edge.
+    Rounded,
+    /// The range continues to the neighbouring cell: the band bridges the gap.
+    Extend,
+}
+
+/// Band edges for `date` in column `column` of a range from `start` to `end`
+///
+/// Returns `None` when the day is outside the range or the range is a single day.
+fn range_band_edges(
+    date: Date,
+    column: u8,
+    start: Date,
+    end: Date,
+) -> Option<(RangeBandEdge, RangeBandEdge)> {
+    if start >= end || date < start || date > end {
+        return None;
+    }
+
+    let last_day = types::days_in_month(date.year, date.month);
+    let left = if date == start {
+        RangeBandEdge::Center
+    } else if column == 0 || date.day == 1 {
+        RangeBandEdge::Rounded
+    } else {
+        RangeBandEdge::Extend
+    };
+    let right = if date == end {
+        RangeBandEdge::Center
+    } else if column == 6 || u32::from(date.day) == last_day {
+        RangeBandEdge::Rounded
+    } else {
+        RangeBandEdge::Extend
+    };
+    Some((left, right))
+}
+
+/// Offset of a band edge from the cell edge
+fn range_band_inset(edge: RangeBandEdge) -> Val {
+    match edge {
+        RangeBandEdge::Center => Val::Percent(50.0),
+        RangeBandEdge::Rounded => Val::Px(0.0),
+        // Half a cell each way covers the column gap; overlapping bands share one color.
+        RangeBandEdge::Extend => Val::Percent(-50.0),
+    }
 }
 
 #[derive(Component)]
@@ -493,10 +622,11 @@ struct DatePickerCalendarView {
     picker: Entity,
 }
 
-#[derive(Component, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Clone, PartialEq, Eq)]
 struct DatePickerCalendarBuiltState {
     month: Month,
     first_day_of_week: Weekday,
+    locale: DateLocale,
 }
 
 #[derive(Component)]
@@ -504,9 +634,9 @@ struct DatePickerYearView {
     picker: Entity,
 }
 
+/// Year range the year grid was built for; the highlighted year is restyled in place
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
 struct DatePickerYearBuiltState {
-    current_year: i32,
     start_year: i32,
     end_year: i32,
 }
@@ -520,6 +650,30 @@ struct DatePickerTextView {
 // Systems
 // ============================================================================
 
+/// Restores the committed selection when a picker opens, so cancelled edits are discarded.
+fn date_picker_restore_committed_system(
+    mut pickers: Query<(Entity, &mut MaterialDatePicker), Changed<MaterialDatePicker>>,
+    mut text_fields: Query<(&DatePickerTextInputValue, &mut MaterialTextField)>,
+) {
+    for (entity, mut picker) in pickers.iter_mut() {
+        if picker.open == picker.was_open {
+            continue;
+        }
+        picker.was_open = picker.open;
+        if !picker.open {
+            continue;
+        }
+
+        picker.restore_committed();
+        for (marker, mut field) in text_fields.iter_mut() {
+            if marker.picker == entity && field.error {
+                field.error = false;
+                field.error_text = None;
+            }
+        }
+    }
+}
+
 fn date_picker_visibility_system(
     pickers: Query<(Entity, &MaterialDatePicker), Changed<MaterialDatePicker>>,
     mut root_nodes: Query<&mut Node, Without<DatePickerDialog>>,
@@ -535,17 +689,27 @@ fn date_picker_visibility_system(
     }
 }
 
+fn date_picker_outside_click_setup_system(
+    mut commands: Commands,
+    surfaces: Query<Entity, Added<DatePickerDialog>>,
+) {
+    // Presses outside the dialog surface land on the scrim.
+    for entity in surfaces.iter() {
+        commands
+            .entity(entity)
+            .insert(DismissOnOutsideClick::new(entity));
+    }
+}
+
 fn date_picker_keyboard_dismiss_system(
-    keys: Res<ButtonInput<KeyCode>>,
+    stack: Option<Res<OverlayStack>>,
     mut pickers: Query<(Entity, &mut MaterialDatePicker)>,
     mut cancel_events: MessageWriter<DatePickerCancelEvent>,
 ) {
-    if !keys.just_pressed(KeyCode::Escape) {
-        return;
-    }
+    let Some(stack) = stack else { return };
 
     for (entity, mut picker) in pickers.iter_mut() {
-        if picker.open && picker.dismiss_on_escape {
+        if picker.open && picker.dismiss_on_escape && stack.escape_pressed_for(entity) {
             picker.open = false;
             cancel_events.write(DatePickerCancelEvent { entity });
         }
@@ -732,6 +896,8 @@ fn date_picker_rebuild_content_system(
     )>,
     children_query: Query<&Children>,
     theme: Res<MaterialTheme>,
+    locale: Res<MaterialLocale>,
+    locale_overrides: DateLocaleOverrides,
     current_date: Option<Res<CurrentDate>>,
 ) {
     let today = current_date.map(|cd| cd.0).unwrap_or_else(Date::today);
@@ -740,6 +906,8 @@ fn date_picker_rebuild_content_system(
             continue;
         }
 
+        let date_locale = resolve_date_locale(picker_entity, &locale, &locale_overrides);
+
         // Rebuild the calendar grid when calendar-related state changes.
         // (This is necessary because the initial UI is generated at spawn-time.)
         for (view_entity, view, children, built_state) in calendar_views.iter_mut() {
@@ -750,6 +918,7 @@ fn date_picker_rebuild_content_system(
             let desired_state = DatePickerCalendarBuiltState {
                 month: picker.display_month,
                 first_day_of_week: picker.first_day_of_week,
+                locale: date_locale.clone(),
             };
 
             let needs_rebuild = match built_state.as_deref() {
@@ -763,10 +932,10 @@ fn date_picker_rebuild_content_system(
 
             match built_state {
                 Some(mut state) => {
-                    *state = desired_state;
+                    *state = desired_state.clone();
                 }
                 None => {
-                    commands.entity(view_entity).insert(desired_state);
+                    commands.entity(view_entity).insert(desired_state.clone());
                 }
             }
 
@@ -788,13 +957,9 @@ fn date_picker_rebuild_content_system(
 
             commands.entity(view_entity).with_children(|calendar| {
                 // Days of week header (rotated based on first day of week)
-                let all_days = ["S", "M", "T", "W", "T", "F", "S"];
                 let first_day_of_week_index =
                     crate::date_picker::types::weekday_index(first_day_of_week) as usize;
-                let mut rotated = Vec::with_capacity(7);
-                for i in 0..7 {
-                    rotated.push(all_days[(first_day_of_week_index + i) % 7]);
-                }
+                let rotated = desired_state.locale.weekday_header(first_day_of_week);
 
                 calendar
                     .spawn(Node {
@@ -845,7 +1010,7 @@ fn date_picker_rebuild_content_system(
                         .with_children(|week| {
                             for day_idx in 0..7 {
                                 let cell_idx = week_idx * 7 + day_idx;
-                                let day_offset = cell_idx - offset as i32;
+                                let day_offset = cell_idx - offset;
 
                                 // Calculate if this cell contains a valid day
                                 if day_offset >= 0 && day_offset < days_in_month as i32 {
@@ -877,6 +1042,7 @@ fn date_picker_rebuild_content_system(
                                             DatePickerDayCell {
                                                 picker: picker_entity,
                                                 date: Some(date),
+                                                column: day_idx as u8,
                                             },
                                             Interaction::None,
                                             Node {
@@ -939,14 +1105,14 @@ fn date_picker_rebuild_content_system(
             });
         }
 
-        // Rebuild the year grid so the highlighted year stays in sync with display_month.
+        // Rebuild the year grid when the year range changes; moving the highlighted
+        // year is handled by `date_picker_year_highlight_system`.
         for (view_entity, view, children, built_state) in year_views.iter_mut() {
             if view.picker != picker_entity {
                 continue;
             }
 
             let desired_state = DatePickerYearBuiltState {
-                current_year: picker.display_month.year,
                 start_year: picker.constraints.start.year,
                 end_year: picker.constraints.end.year,
             };
@@ -1046,21 +1212,22 @@ fn date_picker_rebuild_content_system(
 fn date_picker_action_system(
     mut pickers: Query<&mut MaterialDatePicker>,
     actions: Query<(&Interaction, &DatePickerAction), Changed<Interaction>>,
-    scrim: Query<(&Interaction, &DatePickerScrim), Changed<Interaction>>,
+    mut outside_clicks: MessageReader<OutsideClickEvent>,
+    surfaces: Query<&DatePickerDialog>,
     mut submit_events: MessageWriter<DatePickerSubmitEvent>,
     mut cancel_events: MessageWriter<DatePickerCancelEvent>,
 ) {
-    // Handle scrim clicks
-    for (interaction, scrim) in scrim.iter() {
-        if *interaction != Interaction::Pressed {
+    // Handle presses outside the dialog surface (on the scrim)
+    for ev in outside_clicks.read() {
+        let Ok(surface) = surfaces.get(ev.entity) else {
             continue;
-        }
+        };
 
-        if let Ok(mut picker) = pickers.get_mut(scrim.picker) {
+        if let Ok(mut picker) = pickers.get_mut(surface.picker) {
             if picker.open && picker.dismiss_on_scrim_click {
                 picker.open = false;
                 cancel_events.write(DatePickerCancelEvent {
-                    entity: scrim.picker,
+                    entity: surface.picker,
                 });
             }
         }
@@ -1087,6 +1254,7 @@ fn date_picker_action_system(
                         continue;
                     }
                     picker.open = false;
+                    picker.committed = Some(selection.clone());
                     submit_events.write(DatePickerSubmitEvent {
                         entity: action.picker,
                         selection,
@@ -1102,16 +1270,92 @@ fn date_picker_action_system(
     }
 }
 
+/// Per-picker locale overrides: a tag and/or custom date names
+type DateLocaleOverrides<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Option<&'static MaterialLocaleOverride>,
+        Option<&'static DateLocale>,
+    ),
+>;
+
+fn resolve_date_locale(
+    picker_entity: Entity,
+    locale: &MaterialLocale,
+    overrides: &DateLocaleOverrides,
+) -> DateLocale {
+    match overrides.get(picker_entity) {
+        Ok((_, Some(date_locale))) => date_locale.clone(),
+        Ok((Some(override_locale), None)) => DateLocale::for_tag(&override_locale.tag),
+        _ => DateLocale::for_tag(&locale.tag),
+    }
+}
+
 fn resolve_date_input_pattern(
     picker_entity: Entity,
     locale: &MaterialLocale,
-    overrides: &Query<&MaterialLocaleOverride>,
+    overrides: &DateLocaleOverrides,
 ) -> DateInputPattern {
-    if let Ok(override_locale) = overrides.get(picker_entity) {
-        return date_input_pattern_for_locale(&override_locale.tag);
+    match overrides.get(picker_entity) {
+        Ok((_, Some(date_locale))) => date_locale.pattern,
+        Ok((Some(override_locale), None)) => date_input_pattern_for_locale(&override_locale.tag),
+        _ => date_input_pattern_for_locale(&locale.tag),
+    }
+}
+
+/// Error text shown when a typed date violates the picker's constraints
+const DATE_OUT_OF_RANGE: &str = "Date is outside allowed range";
+
+/// Outcome of validating a date typed into a picker's text field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateInputValidation {
+    /// Still being typed; no error is shown yet
+    Incomplete,
+    /// Complete, but not a real date in the pattern (e.g. 02/30)
+    Invalid,
+    /// A real date that the picker's validator rejects
+    OutOfRange(Date),
+    /// A selectable date
+    Valid(Date),
+}
+
+impl DateInputValidation {
+    /// Validate typed `input` against `pattern` and the picker's `validator`
+    fn of(input: &str, pattern: DateInputPattern, validator: &dyn DateValidator) -> Self {
+        if input.len() < pattern.formatted_len() {
+            return Self::Incomplete;
+        }
+        match try_parse_complete_date(input, pattern) {
+            None => Self::Invalid,
+            Some(date) if !validator.is_valid(date) => Self::OutOfRange(date),
+            Some(date) => Self::Valid(date),
+        }
+    }
+
+    /// The selectable date, if any
+    fn date(self) -> Option<Date> {
+        match self {
+            Self::Valid(date) => Some(date),
+            _ => None,
+        }
+    }
+
+    /// Error text for the field, or `None` when no error should be shown
+    fn error_text(self, pattern: DateInputPattern) -> Option<String> {
+        match self {
+            Self::Incomplete | Self::Valid(_) => None,
+            Self::Invalid => Some(format!("Invalid date. Use {}", pattern.hint())),
+            Self::OutOfRange(_) => Some(DATE_OUT_OF_RANGE.to_string()),
+        }
     }
 
-    date_input_pattern_for_locale(&locale.tag)
+    /// Replace the field's error state with this result's
+    fn apply_to(self, field: &mut MaterialTextField, pattern: DateInputPattern) {
+        let error_text = self.error_text(pattern);
+        field.error = error_text.is_some();
+        field.error_text = error_text;
+    }
 }
 
 fn try_parse_complete_date(input: &str, pattern: DateInputPattern) -> Option<Date> {
@@ -1153,7 +1397,7 @@ fn format_date_for_pattern(date: Date, pattern: DateInputPattern) -> String {
 fn date_picker_text_input_system(
     mut pickers: Query<&mut MaterialDatePicker>,
     locale: Res<MaterialLocale>,
-    locale_overrides: Query<&MaterialLocaleOverride>,
+    locale_overrides: DateLocaleOverrides,
     mut fields: ParamSet<(
         Query<(Entity, &DatePickerTextInputValue, &MaterialTextField)>,
         Query<(Entity, &DatePickerTextInputValue, &mut MaterialTextField)>,
@@ -1179,8 +1423,9 @@ fn date_picker_text_input_system(
 
         let pattern = resolve_date_input_pattern(picker_entity, &locale, &locale_overrides);
 
-        // Note: delimiter insertion + invalid-format errors are handled by the
-        // `TextFieldFormatter::DatePattern(...)` formatter on the field.
+        // Note: delimiter insertion is handled by the
+        // `TextFieldFormatter::DatePattern(...)` formatter on the field; the error
+        // text is recomputed here from `DateInputValidation` on every change.
 
         match picker.mode {
             DatePickerMode::Single => {
@@ -1193,27 +1438,19 @@ fn date_picker_text_input_system(
                     continue;
                 };
 
-                // While incomplete, keep selection cleared (Android "incomplete selection").
-                if field.value.is_empty() || field.value.len() < pattern.formatted_len() {
-                    picker.selector.clear();
-                    continue;
-                }
-
-                let Some(date) = try_parse_complete_date(&field.value, pattern) else {
+                // While incomplete or invalid, keep selection cleared (Android
+                // "incomplete selection"). The error always reflects the current value.
+                let validation = DateInputValidation::of(
+                    &field.value,
+                    pattern,
+                    picker.constraints.validator.as_ref(),
+                );
+                validation.apply_to(&mut field, pattern);
+                let Some(date) = validation.date() else {
                     picker.selector.clear();
                     continue;
                 };
 
-                if !picker.constraints.validator.is_valid(date) {
-                    field.error = true;
-                    field.error_text = Some("Date is outside allowed range".to_string());
-                    picker.selector.clear();
-                    continue;
-                }
-
-                field.error = false;
-                field.error_text = None;
-
                 let selection = DateSelection::Single(date);
                 if picker.selector.selection().as_ref() != Some(&selection) {
                     picker.selector.set_selection(selection);
@@ -1253,60 +1490,30 @@ fn date_picker_text_input_system(
                     continue;
                 };
 
-                let start_complete = start_value.len() >= pattern.formatted_len();
-                let end_complete = end_value.len() >= pattern.formatted_len();
-
-                // While incomplete, clear errors (do not flash errors while typing).
+                // Each field shows its own error; incomplete fields show none
+                // (do not flash errors while typing).
+                let validator = picker.constraints.validator.as_ref();
+                let start = DateInputValidation::of(&start_value, pattern, validator);
+                let end = DateInputValidation::of(&end_value, pattern, validator);
                 {
                     let mut fields_mut = fields.p1();
                     if let Ok((_, _, mut start_field)) = fields_mut.get_mut(start_entity) {
-                        if start_field.value.is_empty() || !start_complete {
-                            start_field.error = false;
-                            start_field.error_text = None;
-                        }
+                        start.apply_to(&mut start_field, pattern);
                     }
                 }
                 {
                     let mut fields_mut = fields.p1();
                     if let Ok((_, _, mut end_field)) = fields_mut.get_mut(end_entity) {
-                        if end_field.value.is_empty() || !end_complete {
-                            end_field.error = false;
-                            end_field.error_text = None;
-                        }
+                        end.apply_to(&mut end_field, pattern);
                     }
                 }
 
                 // Do not allow submission until both are complete and valid.
-                if !(start_complete && end_complete) {
-                    picker.selector.clear();
-                    continue;
-                }
-
-                let start_date = try_parse_complete_date(&start_value, pattern);
-                let end_date = try_parse_complete_date(&end_value, pattern);
-
-                let (Some(start_date), Some(end_date)) = (start_date, end_date) else {
+                let (Some(start_date), Some(end_date)) = (start.date(), end.date()) else {
                     picker.selector.clear();
                     continue;
                 };
 
-                if !picker.constraints.validator.is_valid(start_date)
-                    || !picker.constraints.validator.is_valid(end_date)
-                {
-                    let mut fields_mut = fields.p1();
-                    if let Ok((_, _, mut start_field)) = fields_mut.get_mut(start_entity) {
-                        start_field.error = true;
-                        start_field.error_text = Some("Date is outside allowed range".to_string());
-                    }
-                    let mut fields_mut = fields.p1();
-                    if let Ok((_, _, mut end_field)) = fields_mut.get_mut(end_entity) {
-                        end_field.error = true;
-                        end_field.error_text = Some("Date is outside allowed range".to_string());
-                    }
-                    picker.selector.clear();
-                    continue;
-                }
-
                 if end_date < start_date {
                     let mut fields_mut = fields.p1();
                     if let Ok((_, _, mut start_field)) = fields_mut.get_mut(start_entity) {
@@ -1323,21 +1530,6 @@ fn date_picker_text_input_system(
                     continue;
                 }
 
-                {
-                    let mut fields_mut = fields.p1();
-                    if let Ok((_, _, mut start_field)) = fields_mut.get_mut(start_entity) {
-                        start_field.error = false;
-                        start_field.error_text = None;
-                    }
-                }
-                {
-                    let mut fields_mut = fields.p1();
-                    if let Ok((_, _, mut end_field)) = fields_mut.get_mut(end_entity) {
-                        end_field.error = false;
-                        end_field.error_text = None;
-                    }
-                }
-
                 picker.selector.set_selection(DateSelection::Range {
                     start: start_date,
                     end: Some(end_date),
@@ -1349,12 +1541,91 @@ fn date_picker_text_input_system(
     }
 }
 
+/// Add the range band and selection indicator layers to new day cells
+fn date_picker_day_cell_layers_system(
+    mut commands: Commands,
+    cells: Query<Entity, Added<DatePickerDayCell>>,
+) {
+    for cell in cells.iter() {
+        let band = commands
+            .spawn((
+                DatePickerRangeBand,
+                Pickable::IGNORE,
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.0),
+                    bottom: Val::Px(0.0),
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderRadius::ZERO,
+            ))
+            .id();
+        let indicator = commands
+            .spawn((
+                DatePickerDayIndicator,
+                Pickable::IGNORE,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(CornerRadius::FULL)),
+            ))
+            .id();
+        commands.entity(cell).insert_children(0, &[band, indicator]);
+    }
+}
+
+/// Track the hovered day while a range is missing its end date
+fn date_picker_range_hover_system(
+    mut pickers: Query<&mut MaterialDatePicker>,
+    days: Query<(&Interaction, &DatePickerDayCell), Changed<Interaction>>,
+) {
+    for (interaction, cell) in days.iter() {
+        let Some(date) = cell.date else { continue };
+        let Ok(mut picker) = pickers.get_mut(cell.picker) else {
+            continue;
+        };
+
+        let awaiting_end = picker.mode == DatePickerMode::Range
+            && matches!(
+                picker.selector.selection(),
+                Some(DateSelection::Range { end: None, .. })
+            );
+        let hover = match interaction {
+            Interaction::Hovered if awaiting_end => Some(date),
+            _ if picker.range_hover == Some(date) => None,
+            _ => continue,
+        };
+        if picker.range_hover != hover {
+            picker.range_hover = hover;
+        }
+    }
+}
+
 fn date_picker_render_system(
     mut pickers: ParamSet<(
         Query<(Entity, &MaterialDatePicker), Changed<MaterialDatePicker>>,
         Query<(Entity, &MaterialDatePicker)>,
     )>,
     mut day_cells: Query<(&DatePickerDayCell, &mut BackgroundColor, &Children)>,
+    mut day_layers: Query<
+        (
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderRadius,
+            Has<DatePickerRangeBand>,
+        ),
+        (
+            Or<(With<DatePickerRangeBand>, With<DatePickerDayIndicator>)>,
+            Without<DatePickerDayCell>,
+        ),
+    >,
     mut texts: Query<&mut TextColor>,
     mut text_nodes: Query<(
         &mut Text,
@@ -1374,7 +1645,7 @@ fn date_picker_render_system(
     )>,
     theme: Res<MaterialTheme>,
     locale: Res<MaterialLocale>,
-    locale_overrides: Query<&MaterialLocaleOverride>,
+    locale_overrides: DateLocaleOverrides,
     current_date: Option<Res<CurrentDate>>,
 ) {
     let locale_changed = locale.is_changed();
@@ -1389,6 +1660,16 @@ fn date_picker_render_system(
             let pattern = resolve_date_input_pattern(picker_entity, &locale, &locale_overrides);
             let selection = picker.selector.selection();
 
+            let month_text = resolve_date_locale(picker_entity, &locale, &locale_overrides)
+                .month_year(picker.display_month);
+            for (mut text, _, month_label) in text_nodes.iter_mut() {
+                if month_label.is_some_and(|label| label.picker == picker_entity)
+                    && text.0 != month_text
+                {
+                    text.0 = month_text.clone();
+                }
+            }
+
             let single_text_value = match selection.as_ref() {
                 Some(DateSelection::Single(date)) => format_date_for_pattern(*date, pattern),
                 _ => String::new(),
@@ -1427,6 +1708,9 @@ fn date_picker_render_system(
                 if field.max_length != Some(pattern.formatted_len()) {
                     field.max_length = Some(pattern.formatted_len());
                 }
+                if field.date_pattern != pattern {
+                    field.date_pattern = pattern;
+                }
 
                 if !field.error {
                     field.supporting_text = Some(match marker.kind {
@@ -1467,20 +1751,23 @@ fn date_picker_render_system(
 
         let pattern = resolve_date_input_pattern(picker_entity, &locale, &locale_overrides);
 
+        let date_locale = resolve_date_locale(picker_entity, &locale, &locale_overrides);
+
         let selection = picker.selector.selection();
 
         // Update selection header label
+        let format = |date: Date| format_date_for_pattern(date, pattern);
         let selection_text = match selection.as_ref() {
-            Some(DateSelection::Single(date)) => format!("{}", date),
+            Some(DateSelection::Single(date)) => format(*date),
             Some(DateSelection::Range {
                 start,
                 end: Some(end),
-            }) => format!("{} - {}", start, end),
-            Some(DateSelection::Range { start, end: None }) => format!("{} - ...", start),
+            }) => format!("{} - {}", format(*start), format(*end)),
+            Some(DateSelection::Range { start, end: None }) => format!("{} - ...", format(*start)),
             None => "Select date".to_string(),
         };
         // Update all text nodes tied to this picker
-        let month_text = picker.display_month.display_name();
+        let month_text = date_locale.month_year(picker.display_month);
         let single_text_value = match selection.as_ref() {
             Some(DateSelection::Single(date)) => format_date_for_pattern(*date, pattern),
             _ => String::new(),
@@ -1495,16 +1782,18 @@ fn date_picker_render_system(
             _ => (String::new(), String::new()),
         };
 
+        // Only write labels whose text differs, so a hover or selection change
+        // doesn't re-layout every label of the picker.
         for (mut text, selection_label, month_label) in text_nodes.iter_mut() {
-            if let Some(label) = selection_label {
-                if label.picker == picker_entity {
-                    text.0 = selection_text.clone();
-                }
-            }
-            if let Some(label) = month_label {
-                if label.picker == picker_entity {
-                    text.0 = month_text.clone();
-                }
+            let desired = if selection_label.is_some_and(|label| label.picker == picker_entity) {
+                &selection_text
+            } else if month_label.is_some_and(|label| label.picker == picker_entity) {
+                &month_text
+            } else {
+                continue;
+            };
+            if text.0 != *desired {
+                text.0 = desired.clone();
             }
         }
 
@@ -1534,6 +1823,9 @@ fn date_picker_render_system(
             if field.max_length != Some(pattern.formatted_len()) {
                 field.max_length = Some(pattern.formatted_len());
             }
+            if field.date_pattern != pattern {
+                field.date_pattern = pattern;
+            }
 
             if !field.error {
                 // Only update helper text when not showing an error.
@@ -1583,20 +1875,38 @@ fn date_picker_render_system(
         let desired_year_icon = year_toggle_icon_name;
 
         for (mut icon, mode_marker, year_marker) in toggle_icons.iter_mut() {
-            if let Some(marker) = mode_marker {
-                if marker.picker == picker_entity {
-                    icon.name = desired_mode_icon.to_string();
-                    icon.color = theme.on_surface;
-                }
+            let desired = if mode_marker.is_some_and(|marker| marker.picker == picker_entity) {
+                desired_mode_icon
+            } else if year_marker.is_some_and(|marker| marker.picker == picker_entity) {
+                desired_year_icon
+            } else {
+                continue;
+            };
+            // Changing the icon reloads it, so leave unchanged icons untouched.
+            if icon.name != desired {
+                icon.name = desired.to_string();
             }
-            if let Some(marker) = year_marker {
-                if marker.picker == picker_entity {
-                    icon.name = desired_year_icon.to_string();
-                    icon.color = theme.on_surface;
-                }
+            if icon.color != theme.on_surface {
+                icon.color = theme.on_surface;
             }
         }
 
+        // Committed range, or the range previewed by hovering before the end is picked.
+        let band_range = match &selection {
+            Some(DateSelection::Range {
+                start,
+                end: Some(end),
+            }) => Some((*start, *end)),
+            Some(DateSelection::Range { start, end: None }) => picker.range_hover.map(|hover| {
+                if hover < *start {
+                    (hover, *start)
+                } else {
+                    (*start, hover)
+                }
+            }),
+            _ => None,
+        };
+
         // Update day cell highlighting based on selection
         for (cell, mut bg, children) in day_cells.iter_mut() {
             if cell.picker != picker_entity {
@@ -1614,34 +1924,106 @@ fn date_picker_render_system(
                     None => false,
                 };
 
-                let in_range = match &selection {
-                    Some(DateSelection::Range {
-                        start,
-                        end: Some(end),
-                    }) => date > *start && date < *end,
-                    _ => false,
-                };
+                let band = band_range
+                    .and_then(|(start, end)| range_band_edges(date, cell.column, start, end));
 
                 // Apply colors based on state
-                let (bg_color, text_color) = if !is_valid {
+                let (indicator_color, text_color) = if !is_valid {
                     (Color::NONE, theme.on_surface.with_alpha(0.38))
                 } else if is_selected {
                     (theme.primary, theme.on_primary)
-                } else if in_range {
-                    (theme.primary_container.with_alpha(0.3), theme.on_surface)
+                } else if band.is_some() {
+                    (Color::NONE, theme.on_secondary_container)
                 } else if is_today {
                     (theme.primary_container, theme.on_primary_container)
                 } else {
                     (Color::NONE, theme.on_surface)
                 };
 
-                *bg = BackgroundColor(bg_color);
-
-                // Update text color
+                let mut has_layers = false;
                 for child in children.iter() {
                     if let Ok(mut text_color_comp) = texts.get_mut(child) {
-                        *text_color_comp = TextColor(text_color);
+                        text_color_comp.set_if_neq(TextColor(text_color));
+                    }
+
+                    let Ok((mut node, mut layer_bg, mut radius, is_band)) =
+                        day_layers.get_mut(child)
+                    else {
+                        continue;
+                    };
+                    has_layers = true;
+
+                    if !is_band {
+                        layer_bg.set_if_neq(BackgroundColor(indicator_color));
+                        continue;
                     }
+
+                    match band {
+                        Some((left, right)) => {
+                            let (left_inset, right_inset) =
+                                (range_band_inset(left), range_band_inset(right));
+                            if node.left != left_inset || node.right != right_inset {
+                                node.left = left_inset;
+                                node.right = right_inset;
+                            }
+                            let round = |edge| {
+                                if edge == RangeBandEdge::Rounded {
+                                    Val::Px(CornerRadius::FULL)
+                                } else {
+                                    Val::Px(0.0)
+                                }
+                            };
+                            radius.set_if_neq(BorderRadius {
+                                top_left: round(left),
+                                bottom_left: round(left),
+                                top_right: round(right),
+                                bottom_right: round(right),
+                            });
+                            layer_bg.set_if_neq(BackgroundColor(theme.secondary_container));
+                        }
+                        None => {
+                            layer_bg.set_if_neq(BackgroundColor(Color::NONE));
+                        }
+                    }
+                }
+
+                // Until the layers exist, draw the indicator on the cell itself.
+                bg.set_if_neq(BackgroundColor(if has_layers {
+                    Color::NONE
+                } else {
+                    indicator_color
+                }));
+            }
+        }
+    }
+}
+
+/// Move the year grid highlight to the displayed year without rebuilding the grid
+fn date_picker_year_highlight_system(
+    pickers: Query<(Entity, &MaterialDatePicker), Changed<MaterialDatePicker>>,
+    mut year_cells: Query<(&DatePickerYearCell, &mut BackgroundColor, &Children)>,
+    mut texts: Query<&mut TextColor>,
+    theme: Res<MaterialTheme>,
+) {
+    for (picker_entity, picker) in pickers.iter() {
+        if !picker.open {
+            continue;
+        }
+
+        for (cell, mut bg, children) in year_cells.iter_mut() {
+            if cell.picker != picker_entity {
+                continue;
+            }
+
+            let (bg_color, text_color) = if cell.year == picker.display_month.year {
+                (theme.primary, theme.on_primary)
+            } else {
+                (Color::NONE, theme.on_surface)
+            };
+            bg.set_if_neq(BackgroundColor(bg_color));
+            for child in children.iter() {
+                if let Ok(mut color) = texts.get_mut(child) {
+                    color.set_if_neq(TextColor(text_color));
                 }
             }
         }
@@ -1713,11 +2095,17 @@ fn date_picker_theme_system(
         Option<&DatePickerScrim>,
         Option<&DatePickerDialog>,
     )>,
+    mut pickers: Query<&mut MaterialDatePicker>,
 ) {
     if !theme.is_changed() {
         return;
     }
 
+    // Re-run the render system so day cells, labels and icons pick up the new colors.
+    for mut picker in pickers.iter_mut() {
+        picker.set_changed();
+    }
+
     for (mut bg, scrim, dialog) in backgrounds.iter_mut() {
         if scrim.is_some() {
             *bg = BackgroundColor(theme.scrim.with_alpha(0.32));
@@ -1747,6 +2135,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
         let first_day_of_week = picker.first_day_of_week;
         let showing_years = picker.showing_years;
         let input_mode = picker.input_mode;
+        let date_locale = builder.locale_override.clone().unwrap_or_default();
 
         // Create simplified placeholder UI - full implementation in future update
         let mut root = self.spawn((
@@ -1763,11 +2152,11 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                 display: Display::None,
                 ..default()
             },
-            GlobalZIndex(9999),
+            GlobalZIndex(Z_DIALOG),
         ));
 
-        if let Some(tag) = builder.locale_override.as_deref() {
-            root.insert(MaterialLocaleOverride::new(tag));
+        if let Some(date_locale) = builder.locale_override.clone() {
+            root.insert((MaterialLocaleOverride::new(&date_locale.tag), date_locale));
         }
         let entity = root.id();
 
@@ -1776,10 +2165,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
         root.with_children(|root| {
             // Scrim overlay
             root.spawn((
-                DatePickerScrim { picker: entity },
-                // Required for `Interaction` updates so scrim clicks can be detected.
-                Button,
-                Interaction::None,
+                DatePickerScrim,
                 Node {
                     position_type: PositionType::Absolute,
                     left: Val::Px(0.0),
@@ -1799,8 +2185,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
 
             // Dialog container with calendar
             root.spawn((
-                DatePickerDialog,
-                // Ensure clicks on the dialog surface don't count as scrim clicks.
+                DatePickerDialog { picker: entity },
                 Interaction::None,
                 FocusPolicy::Block,
                 Node {
@@ -1817,7 +2202,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                     is_hoverable: false,
                 },
                 BorderRadius::all(Val::Px(CornerRadius::EXTRA_LARGE)),
-                BoxShadow::default(),
+                Elevation::level_3().to_box_shadow(),
                 ZIndex(1),
             ))
             .with_children(|dialog| {
@@ -1963,7 +2348,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                                 .with_children(|row| {
                                     row.spawn((
                                         DatePickerMonthLabel { picker: entity },
-                                        Text::new(display_month.display_name()),
+                                        Text::new(date_locale.month_year(display_month)),
                                         TextFont {
                                             font_size: 16.0,
                                             ..default()
@@ -2126,7 +2511,6 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                     .spawn((
                         DatePickerYearView { picker: entity },
                         DatePickerYearBuiltState {
-                            current_year: display_month.year,
                             start_year: builder.constraints.start.year,
                             end_year: builder.constraints.end.year,
                         },
@@ -2213,6 +2597,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                         DatePickerCalendarBuiltState {
                             month: display_month,
                             first_day_of_week,
+                            locale: date_locale.clone(),
                         },
                         Node {
                             flex_direction: FlexDirection::Column,
@@ -2230,7 +2615,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                                 ..default()
                             })
                             .with_children(|header| {
-                                for day in ["S", "M", "T", "W", "T", "F", "S"] {
+                                for day in date_locale.weekday_header(first_day_of_week) {
                                     header.spawn((
                                         Text::new(day),
                                         TextFont {
@@ -2314,6 +2699,7 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                                                 DatePickerDayCell {
                                                     picker: entity,
                                                     date: Some(date),
+                                                    column: day_idx as u8,
                                                 },
                                                 Interaction::None,
                                                 Node {
@@ -2327,9 +2713,9 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
                                                 BorderRadius::all(Val::Px(CornerRadius::FULL)),
                                             ));
 
-                                            // Only enable interaction if date is valid
+                                            // Dates outside the constraints can't be picked
                                             if !enabled {
-                                                cell_spawn.insert(Interaction::None);
+                                                cell_spawn.insert(Disabled);
                                             }
 
                                             cell_spawn.with_children(|cell| {
@@ -2408,3 +2794,347 @@ impl SpawnDatePicker for ChildSpawnerCommands<'_> {
         entity
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{apply_set_theme_system, SetTheme};
+
+    fn day_cells(app: &mut App) -> Vec<Entity> {
+        let mut cells = app
+            .world_mut()
+            .query_filtered::<Entity, With<DatePickerDayCell>>();
+        cells.iter(app.world()).collect()
+    }
+
+    fn year_cells(app: &mut App) -> Vec<(Entity, i32, Color)> {
+        let mut cells = app
+            .world_mut()
+            .query::<(Entity, &DatePickerYearCell, &BackgroundColor)>();
+        cells
+            .iter(app.world())
+            .map(|(entity, cell, bg)| (entity, cell.year, bg.0))
+            .collect()
+    }
+
+    fn mdy() -> DateInputPattern {
+        DateInputPattern::new(DateFieldOrder::Mdy, '/')
+    }
+
+    #[test]
+    fn test_set_theme_restyles_the_dialog_in_the_same_frame() {
+        let mut app = App::new();
+        app.insert_resource(MaterialTheme::light())
+            .add_message::<SetTheme>()
+            .add_systems(PreUpdate, apply_set_theme_system)
+            .add_systems(Update, date_picker_theme_system);
+
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| {
+                root.spawn_date_picker(&MaterialTheme::light(), DatePickerBuilder::new());
+            });
+        app.world_mut().flush();
+        app.update();
+
+        let dialog_color = |app: &mut App| {
+            let mut dialogs = app
+                .world_mut()
+                .query_filtered::<&BackgroundColor, With<DatePickerDialog>>();
+            dialogs.single(app.world()).unwrap().0
+        };
+        assert_eq!(
+            dialog_color(&mut app),
+            MaterialTheme::light().surface_container_high
+        );
+
+        app.world_mut().write_message(SetTheme::ToggleMode);
+        app.update();
+        assert_eq!(
+            dialog_color(&mut app),
+            MaterialTheme::dark().surface_container_high
+        );
+    }
+
+    #[test]
+    fn test_date_input_validation_invalid_format() {
+        let validation = DateInputValidation::of("02/30/2024", mdy(), &AllDatesValidator);
+        assert_eq!(validation, DateInputValidation::Invalid);
+        assert_eq!(validation.date(), None);
+        assert!(validation
+            .error_text(mdy())
+            .is_some_and(|text| text.starts_with("Invalid date")));
+
+        assert_eq!(
+            DateInputValidation::of("02/3", mdy(), &AllDatesValidator),
+            DateInputValidation::Incomplete
+        );
+        assert_eq!(DateInputValidation::Incomplete.error_text(mdy()), None);
+    }
+
+    #[test]
+    fn test_date_input_validation_out_of_range() {
+        let blocked = BlockListValidator::new(vec![Date::new(2024, 3, 15)]);
+        let validation = DateInputValidation::of("03/15/2024", mdy(), &blocked);
+        assert_eq!(
+            validation,
+            DateInputValidation::OutOfRange(Date::new(2024, 3, 15))
+        );
+        assert_eq!(validation.date(), None);
+        assert_eq!(
+            validation.error_text(mdy()).as_deref(),
+            Some(DATE_OUT_OF_RANGE)
+        );
+    }
+
+    #[test]
+    fn test_date_input_error_clears_once_corrected() {
+        let blocked = BlockListValidator::new(vec![Date::new(2024, 3, 15)]);
+        let mut field = MaterialTextField::new();
+
+        DateInputValidation::of("03/15/2024", mdy(), &blocked).apply_to(&mut field, mdy());
+        assert!(field.error);
+        assert_eq!(field.error_text.as_deref(), Some(DATE_OUT_OF_RANGE));
+
+        let corrected = DateInputValidation::of("03/16/2024", mdy(), &blocked);
+        assert_eq!(corrected.date(), Some(Date::new(2024, 3, 16)));
+        corrected.apply_to(&mut field, mdy());
+        assert!(!field.error);
+        assert_eq!(field.error_text, None);
+    }
+
+    #[test]
+    fn test_date_locale_monday_first_header() {
+        let locale = DateLocale::for_tag("fr-FR");
+        assert_eq!(locale.month_name(1), "janvier");
+        assert_eq!(
+            locale.weekday_header(Weekday::Mon),
+            ["L", "M", "M", "J", "V", "S", "D"]
+        );
+    }
+
+    #[test]
+    fn test_date_locale_sunday_first_header() {
+        let locale = DateLocale::for_tag("es-ES");
+        assert_eq!(
+            locale.weekday_header(Weekday::Sun),
+            ["D", "L", "M", "X", "J", "V", "S"]
+        );
+    }
+
+    #[test]
+    fn test_date_locale_unknown_tag_falls_back_to_english() {
+        let locale = DateLocale::for_tag("xx-YY");
+        assert_eq!(locale.tag, "xx-YY");
+        assert_eq!(locale.month_names, DateLocale::english().month_names);
+        assert_eq!(
+            locale.weekday_header(Weekday::Sun),
+            ["S", "M", "T", "W", "T", "F", "S"]
+        );
+    }
+
+    #[test]
+    fn test_set_selection_commits_and_shows_its_month() {
+        let mut picker = DatePickerBuilder::new().build_picker();
+        picker.showing_years = true;
+        let selection = DateSelection::Single(Date::new(2025, 7, 4));
+
+        picker.set_selection(Some(selection.clone()));
+
+        assert_eq!(picker.committed_selection(), Some(selection.clone()));
+        assert_eq!(picker.selection(), Some(selection));
+        assert_eq!(picker.display_month, Month::new(2025, 7));
+        assert!(!picker.showing_years);
+    }
+
+    #[test]
+    fn test_cancelled_selection_is_restored_on_reopen() {
+        let mut app = App::new();
+        app.add_systems(Update, date_picker_restore_committed_system);
+
+        let committed = DateSelection::Range {
+            start: Date::new(2024, 5, 2),
+            end: Some(Date::new(2024, 5, 9)),
+        };
+        let picker = DatePickerBuilder::new()
+            .mode(DatePickerMode::Range)
+            .initial_selection(committed.clone())
+            .build_picker();
+        let entity = app.world_mut().spawn(picker).id();
+
+        let set_open = |app: &mut App, open: bool| {
+            app.world_mut()
+                .get_mut::<MaterialDatePicker>(entity)
+                .unwrap()
+                .open = open;
+            app.update();
+        };
+
+        set_open(&mut app, true);
+        {
+            let mut picker = app
+                .world_mut()
+                .get_mut::<MaterialDatePicker>(entity)
+                .unwrap();
+            picker.selector.clear();
+            picker
+                .selector
+                .set_selection(DateSelection::Single(Date::new(2024, 8, 20)));
+            picker.display_month = Month::new(2024, 8);
+            picker.range_hover = Some(Date::new(2024, 8, 25));
+        }
+        // Cancelling closes the picker without committing the edit.
+        set_open(&mut app, false);
+        set_open(&mut app, true);
+
+        let picker = app.world().get::<MaterialDatePicker>(entity).unwrap();
+        assert_eq!(picker.selection(), Some(committed.clone()));
+        assert_eq!(picker.committed_selection(), Some(committed));
+        assert_eq!(picker.display_month, Month::new(2024, 5));
+        assert_eq!(picker.range_hover, None);
+    }
+
+    #[test]
+    fn test_range_band_covers_cells_between_start_and_end() {
+        let mut app = App::new();
+        app.init_resource::<MaterialTheme>()
+            .init_resource::<MaterialLocale>()
+            .insert_resource(CurrentDate(Date::new(2024, 3, 1)))
+            .add_systems(
+                Update,
+                (
+                    date_picker_rebuild_content_system,
+                    date_picker_day_cell_layers_system,
+                    date_picker_render_system,
+                )
+                    .chain(),
+            );
+        let theme = MaterialTheme::default();
+
+        let builder = DatePickerBuilder::new()
+            .mode(DatePickerMode::Range)
+            .constraints(
+                CalendarConstraints::new()
+                    .with_bounds(Month::new(2020, 1), Month::new(2030, 12))
+                    .with_opening(Month::new(2024, 3)),
+            );
+        let mut picker = Entity::PLACEHOLDER;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| picker = root.spawn_date_picker(&theme, builder));
+        app.world_mut().flush();
+        {
+            let mut date_picker = app
+                .world_mut()
+                .get_mut::<MaterialDatePicker>(picker)
+                .unwrap();
+            date_picker.open = true;
+            date_picker.set_selection(Some(DateSelection::Range {
+                start: Date::new(2024, 3, 5),
+                end: Some(Date::new(2024, 3, 12)),
+            }));
+        }
+        app.update();
+        // Touch the picker so the render pass sees the freshly spawned layers.
+        app.world_mut()
+            .get_mut::<MaterialDatePicker>(picker)
+            .unwrap()
+            .set_changed();
+        app.update();
+
+        let mut cells = app.world_mut().query::<(&DatePickerDayCell, &Children)>();
+        let mut bands = app
+            .world_mut()
+            .query_filtered::<&BackgroundColor, With<DatePickerRangeBand>>();
+        let mut checked = 0;
+        for (cell, children) in cells.iter(app.world()) {
+            let Some(date) = cell.date else {
+                continue;
+            };
+            let band = children
+                .iter()
+                .find_map(|child| bands.get(app.world(), child).ok())
+                .expect("day cell has a range band layer");
+            let expected = if (5..=12).contains(&date.day) {
+                theme.secondary_container
+            } else {
+                Color::NONE
+            };
+            assert_eq!(band.0, expected, "day {}", date.day);
+            checked += 1;
+        }
+        assert_eq!(checked, 31);
+    }
+
+    #[test]
+    fn test_selection_and_year_changes_restyle_cells_in_place() {
+        let mut app = App::new();
+        app.init_resource::<MaterialTheme>()
+            .init_resource::<MaterialLocale>()
+            .insert_resource(CurrentDate(Date::new(2024, 3, 1)))
+            .add_systems(
+                Update,
+                (
+                    date_picker_rebuild_content_system,
+                    date_picker_render_system,
+                    date_picker_year_highlight_system,
+                ),
+            );
+        let theme = MaterialTheme::default();
+
+        let builder = DatePickerBuilder::new().constraints(
+            CalendarConstraints::new()
+                .with_bounds(Month::new(2020, 1), Month::new(2030, 12))
+                .with_opening(Month::new(2024, 3)),
+        );
+        let mut picker = Entity::PLACEHOLDER;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| picker = root.spawn_date_picker(&theme, builder));
+        app.world_mut().flush();
+        app.world_mut()
+            .get_mut::<MaterialDatePicker>(picker)
+            .unwrap()
+            .open = true;
+        app.update();
+
+        let days = day_cells(&mut app);
+        let years = year_cells(&mut app);
+        assert!(!days.is_empty());
+
+        // Selecting a day restyles the existing cells.
+        app.world_mut()
+            .get_mut::<MaterialDatePicker>(picker)
+            .unwrap()
+            .selector
+            .set_selection(DateSelection::Single(Date::new(2024, 3, 15)));
+        app.update();
+        assert_eq!(day_cells(&mut app), days);
+
+        // Moving to another year keeps the year grid and moves its highlight.
+        app.world_mut()
+            .get_mut::<MaterialDatePicker>(picker)
+            .unwrap()
+            .display_month = Month::new(2026, 3);
+        app.update();
+        let restyled = year_cells(&mut app);
+        assert_eq!(
+            restyled.iter().map(|(e, ..)| *e).collect::<Vec<_>>(),
+            years.iter().map(|(e, ..)| *e).collect::<Vec<_>>()
+        );
+        for (_, year, color) in restyled {
+            let expected = if year == 2026 {
+                theme.primary
+            } else {
+                Color::NONE
+            };
+            assert_eq!(color, expected, "year {year}");
+        }
+
+        // The month change itself rebuilds the calendar grid.
+        assert!(day_cells(&mut app).iter().all(|cell| !days.contains(cell)));
+    }
+}
@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use std::fmt;
 
+use crate::locale::{date_input_pattern_for_locale, DateFieldOrder, DateInputPattern};
+
 /// Day of week
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekday {
@@ -59,6 +61,200 @@ impl Weekday {
     }
 }
 
+/// Date names and input order for a locale
+///
+/// Attached to a date picker root to localize month names, weekday headers and the
+/// text input pattern. Pickers without one resolve it from `MaterialLocale`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct DateLocale {
+    /// BCP-47-ish locale tag (e.g. `"fr-FR"`)
+    pub tag: String,
+    /// Month names, January first
+    pub month_names: [String; 12],
+    /// Narrow weekday names used in the calendar header, Sunday first
+    pub weekday_abbreviations: [String; 7],
+    /// Numeric date input pattern (field order and separator)
+    pub pattern: DateInputPattern,
+}
+
+impl DateLocale {
+    /// Built-in names for `tag`, falling back to English names
+    ///
+    /// The input pattern always follows [`date_input_pattern_for_locale`].
+    pub fn for_tag(tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        let language = tag
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        let (months, weekdays): ([&str; 12], [&str; 7]) = match language.as_str() {
+            "fr" => (
+                [
+                    "janvier",
+                    "février",
+                    "mars",
+                    "avril",
+                    "mai",
+                    "juin",
+                    "juillet",
+                    "août",
+                    "septembre",
+                    "octobre",
+                    "novembre",
+                    "décembre",
+                ],
+                ["D", "L", "M", "M", "J", "V", "S"],
+            ),
+            "de" => (
+                [
+                    "Januar",
+                    "Februar",
+                    "März",
+                    "April",
+                    "Mai",
+                    "Juni",
+                    "Juli",
+                    "August",
+                    "September",
+                    "Oktober",
+                    "November",
+                    "Dezember",
+                ],
+                ["S", "M", "D", "M", "D", "F", "S"],
+            ),
+            "es" => (
+                [
+                    "enero",
+                    "febrero",
+                    "marzo",
+                    "abril",
+                    "mayo",
+                    "junio",
+                    "julio",
+                    "agosto",
+                    "septiembre",
+                    "octubre",
+                    "noviembre",
+                    "diciembre",
+                ],
+                ["D", "L", "M", "X", "J", "V", "S"],
+            ),
+            "pt" => (
+                [
+                    "janeiro",
+                    "fevereiro",
+                    "março",
+                    "abril",
+                    "maio",
+                    "junho",
+                    "julho",
+                    "agosto",
+                    "setembro",
+                    "outubro",
+                    "novembro",
+                    "dezembro",
+                ],
+                ["D", "S", "T", "Q", "Q", "S", "S"],
+            ),
+            "it" => (
+                [
+                    "gennaio",
+                    "febbraio",
+                    "marzo",
+                    "aprile",
+                    "maggio",
+                    "giugno",
+                    "luglio",
+                    "agosto",
+                    "settembre",
+                    "ottobre",
+                    "novembre",
+                    "dicembre",
+                ],
+                ["D", "L", "M", "M", "G", "V", "S"],
+            ),
+            _ => (
+                std::array::from_fn(|i| month_name(i as u8 + 1)),
+                Weekday::all_starting_from(Weekday::Sun).map(Weekday::short_name),
+            ),
+        };
+
+        Self {
+            pattern: date_input_pattern_for_locale(&tag),
+            tag,
+            month_names: months.map(String::from),
+            weekday_abbreviations: weekdays.map(String::from),
+        }
+    }
+
+    /// US English
+    pub fn english() -> Self {
+        Self::for_tag("en-US")
+    }
+
+    /// Replace the month names (January first)
+    pub fn with_month_names(mut self, names: [&str; 12]) -> Self {
+        self.month_names = names.map(String::from);
+        self
+    }
+
+    /// Replace the weekday header names (Sunday first)
+    pub fn with_weekday_abbreviations(mut self, names: [&str; 7]) -> Self {
+        self.weekday_abbreviations = names.map(String::from);
+        self
+    }
+
+    /// Set the text input field order
+    pub fn with_order(mut self, order: DateFieldOrder) -> Self {
+        self.pattern.order = order;
+        self
+    }
+
+    /// Name of `month` (1-12)
+    pub fn month_name(&self, month: u8) -> &str {
+        self.month_names
+            .get(month.saturating_sub(1) as usize)
+            .map(String::as_str)
+            .unwrap_or("???")
+    }
+
+    /// Header name of `weekday`
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        &self.weekday_abbreviations[weekday_index(weekday) as usize]
+    }
+
+    /// Weekday header names starting at `first_day_of_week`
+    pub fn weekday_header(&self, first_day_of_week: Weekday) -> [&str; 7] {
+        Weekday::all_starting_from(first_day_of_week).map(|day| self.weekday_abbreviation(day))
+    }
+
+    /// Month and year label, e.g. `"janvier 2025"`
+    pub fn month_year(&self, month: Month) -> String {
+        format!("{} {}", self.month_name(month.month), month.year)
+    }
+}
+
+impl Default for DateLocale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl From<&str> for DateLocale {
+    fn from(tag: &str) -> Self {
+        Self::for_tag(tag)
+    }
+}
+
+impl From<String> for DateLocale {
+    fn from(tag: String) -> Self {
+        Self::for_tag(tag)
+    }
+}
+
 /// A calendar date
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
@@ -0,0 +1,478 @@
+//! Material Design 3 Date-Time Picker flow
+//!
+//! Chains a [`MaterialDatePicker`] and a [`MaterialTimePicker`]: the user picks a date, then a
+//! time, and a single [`DateTimePickerSubmitEvent`] is emitted with both values. Cancelling
+//! either stage cancels the whole flow.
+
+use bevy::picking::Pickable;
+use bevy::prelude::*;
+
+use crate::date_picker::{
+    Date, DatePickerBuilder, DatePickerCancelEvent, DatePickerMode, DatePickerSubmitEvent,
+    DateSelection, MaterialDatePicker, SpawnDatePicker,
+};
+use crate::theme::MaterialTheme;
+use crate::time_picker::{
+    MaterialTimePicker, SpawnTimePicker, TimePickerBuilder, TimePickerCancelEvent,
+    TimePickerSubmitEvent,
+};
+
+/// Plugin for the combined date-time picker flow.
+pub struct DateTimePickerPlugin;
+
+impl Plugin for DateTimePickerPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
+            app.add_plugins(crate::MaterialUiCorePlugin);
+        }
+        if !app.is_plugin_added::<crate::date_picker::DatePickerPlugin>() {
+            app.add_plugins(crate::date_picker::DatePickerPlugin);
+        }
+        if !app.is_plugin_added::<crate::time_picker::TimePickerPlugin>() {
+            app.add_plugins(crate::time_picker::TimePickerPlugin);
+        }
+        app.add_message::<DateTimePickerSubmitEvent>()
+            .add_message::<DateTimePickerCancelEvent>()
+            .add_systems(
+                Update,
+                (
+                    datetime_picker_open_system,
+                    datetime_picker_date_stage_system,
+                    datetime_picker_time_stage_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A date combined with a time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub date: Date,
+    /// Hour (0-23)
+    pub hour: u8,
+    /// Minute (0-59)
+    pub minute: u8,
+}
+
+impl DateTime {
+    pub fn new(date: Date, hour: u8, minute: u8) -> Self {
+        Self { date, hour, minute }
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:02}:{:02}", self.date, self.hour, self.minute)
+    }
+}
+
+/// Stage of a date-time picker flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateTimePickerStage {
+    /// Not running
+    #[default]
+    Idle,
+    /// Waiting for the date
+    Date,
+    /// Date chosen, waiting for the time
+    Time,
+}
+
+/// Combined date-time picker
+///
+/// Set `open` to `true` to start the flow; it is reset to `false` when the flow submits or
+/// cancels. Setting it to `false` while running closes both pickers.
+#[derive(Component, Debug, Clone)]
+pub struct MaterialDateTimePicker {
+    /// Whether the flow is running
+    pub open: bool,
+    stage: DateTimePickerStage,
+    date_picker: Entity,
+    time_picker: Entity,
+    date: Option<Date>,
+}
+
+impl MaterialDateTimePicker {
+    /// Current stage of the flow
+    pub fn stage(&self) -> DateTimePickerStage {
+        self.stage
+    }
+
+    /// The underlying date picker entity
+    pub fn date_picker(&self) -> Entity {
+        self.date_picker
+    }
+
+    /// The underlying time picker entity
+    pub fn time_picker(&self) -> Entity {
+        self.time_picker
+    }
+
+    /// Date chosen in the first stage, if any
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+}
+
+/// Builder for the combined date-time picker
+#[derive(Default)]
+pub struct DateTimePickerBuilder {
+    date: DatePickerBuilder,
+    time: TimePickerBuilder,
+}
+
+impl DateTimePickerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure the date stage (always single-date)
+    pub fn date_picker(mut self, builder: DatePickerBuilder) -> Self {
+        self.date = builder;
+        self
+    }
+
+    /// Configure the time stage
+    pub fn time_picker(mut self, builder: TimePickerBuilder) -> Self {
+        self.time = builder;
+        self
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[derive(Event, Message)]
+pub struct DateTimePickerSubmitEvent {
+    pub entity: Entity,
+    pub value: DateTime,
+}
+
+#[derive(Event, Message)]
+pub struct DateTimePickerCancelEvent {
+    pub entity: Entity,
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn datetime_picker_open_system(
+    mut flows: Query<&mut MaterialDateTimePicker, Changed<MaterialDateTimePicker>>,
+    mut date_pickers: Query<&mut MaterialDatePicker>,
+    mut time_pickers: Query<&mut MaterialTimePicker>,
+) {
+    for mut flow in flows.iter_mut() {
+        match (flow.open, flow.stage) {
+            (true, DateTimePickerStage::Idle) => {
+                flow.stage = DateTimePickerStage::Date;
+                flow.date = None;
+                if let Ok(mut picker) = date_pickers.get_mut(flow.date_picker) {
+                    picker.open = true;
+                }
+            }
+            (false, DateTimePickerStage::Date | DateTimePickerStage::Time) => {
+                flow.stage = DateTimePickerStage::Idle;
+                if let Ok(mut picker) = date_pickers.get_mut(flow.date_picker) {
+                    picker.open = false;
+                }
+                if let Ok(mut picker) = time_pickers.get_mut(flow.time_picker) {
+                    picker.open = false;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn datetime_picker_date_stage_system(
+    mut submits: MessageReader<DatePickerSubmitEvent>,
+    mut cancels: MessageReader<DatePickerCancelEvent>,
+    mut flows: Query<(Entity, &mut MaterialDateTimePicker)>,
+    mut time_pickers: Query<&mut MaterialTimePicker>,
+    mut cancel_events: MessageWriter<DateTimePickerCancelEvent>,
+) {
+    for ev in submits.read() {
+        for (_, mut flow) in flows.iter_mut() {
+            if flow.date_picker != ev.entity || flow.stage != DateTimePickerStage::Date {
+                continue;
+            }
+            let DateSelection::Single(date) = ev.selection else {
+                continue;
+            };
+
+            flow.date = Some(date);
+            flow.stage = DateTimePickerStage::Time;
+            if let Ok(mut picker) = time_pickers.get_mut(flow.time_picker) {
+                picker.open = true;
+            }
+        }
+    }
+
+    for ev in cancels.read() {
+        for (entity, mut flow) in flows.iter_mut() {
+            if flow.date_picker != ev.entity || flow.stage != DateTimePickerStage::Date {
+                continue;
+            }
+            flow.open = false;
+            flow.stage = DateTimePickerStage::Idle;
+            cancel_events.write(DateTimePickerCancelEvent { entity });
+        }
+    }
+}
+
+fn datetime_picker_time_stage_system(
+    mut submits: MessageReader<TimePickerSubmitEvent>,
+    mut cancels: MessageReader<TimePickerCancelEvent>,
+    mut flows: Query<(Entity, &mut MaterialDateTimePicker)>,
+    mut submit_events: MessageWriter<DateTimePickerSubmitEvent>,
+    mut cancel_events: MessageWriter<DateTimePickerCancelEvent>,
+) {
+    for ev in submits.read() {
+        for (entity, mut flow) in flows.iter_mut() {
+            if flow.time_picker != ev.entity || flow.stage != DateTimePickerStage::Time {
+                continue;
+            }
+            let Some(date) = flow.date else {
+                continue;
+            };
+
+            flow.open = false;
+            flow.stage = DateTimePickerStage::Idle;
+            submit_events.write(DateTimePickerSubmitEvent {
+                entity,
+                value: DateTime::new(date, ev.hour, ev.minute),
+            });
+        }
+    }
+
+    for ev in cancels.read() {
+        for (entity, mut flow) in flows.iter_mut() {
+            if flow.time_picker != ev.entity || flow.stage != DateTimePickerStage::Time {
+                continue;
+            }
+            flow.open = false;
+            flow.stage = DateTimePickerStage::Idle;
+            cancel_events.write(DateTimePickerCancelEvent { entity });
+        }
+    }
+}
+
+// ============================================================================
+// Spawn Trait
+// ============================================================================
+
+pub trait SpawnDateTimePicker {
+    fn spawn_datetime_picker(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: DateTimePickerBuilder,
+    ) -> Entity;
+}
+
+impl SpawnDateTimePicker for ChildSpawnerCommands<'_> {
+    fn spawn_datetime_picker(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: DateTimePickerBuilder,
+    ) -> Entity {
+        let mut date_picker = Entity::PLACEHOLDER;
+        let mut time_picker = Entity::PLACEHOLDER;
+
+        // Full-size, non-blocking container so both picker overlays cover the same area.
+        let mut root = self.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+        ));
+        root.with_children(|flow| {
+            date_picker = flow.spawn_date_picker(theme, builder.date.mode(DatePickerMode::Single));
+            time_picker = flow.spawn_time_picker(theme, builder.time);
+        });
+        root.insert(MaterialDateTimePicker {
+            open: false,
+            stage: DateTimePickerStage::Idle,
+            date_picker,
+            time_picker,
+            date: None,
+        });
+        root.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_message::<DatePickerSubmitEvent>()
+            .add_message::<DatePickerCancelEvent>()
+            .add_message::<TimePickerSubmitEvent>()
+            .add_message::<TimePickerCancelEvent>()
+            .add_message::<DateTimePickerSubmitEvent>()
+            .add_message::<DateTimePickerCancelEvent>()
+            .add_systems(
+                Update,
+                (
+                    datetime_picker_open_system,
+                    datetime_picker_date_stage_system,
+                    datetime_picker_time_stage_system,
+                )
+                    .chain(),
+            );
+        let theme = MaterialTheme::default();
+
+        let mut flow = Entity::PLACEHOLDER;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| {
+                flow = root.spawn_datetime_picker(&theme, DateTimePickerBuilder::new())
+            });
+        app.world_mut().flush();
+        (app, flow)
+    }
+
+    fn start(app: &mut App, flow: Entity) -> MaterialDateTimePicker {
+        app.world_mut()
+            .get_mut::<MaterialDateTimePicker>(flow)
+            .unwrap()
+            .open = true;
+        app.update();
+        picker(app, flow)
+    }
+
+    fn picker(app: &App, flow: Entity) -> MaterialDateTimePicker {
+        app.world()
+            .get::<MaterialDateTimePicker>(flow)
+            .unwrap()
+            .clone()
+    }
+
+    fn cancelled(app: &App) -> Vec<Entity> {
+        app.world()
+            .resource::<Messages<DateTimePickerCancelEvent>>()
+            .iter_current_update_messages()
+            .map(|ev| ev.entity)
+            .collect()
+    }
+
+    #[test]
+    fn test_date_then_time_submits_once() {
+        let (mut app, flow) = flow_app();
+        let state = start(&mut app, flow);
+        assert_eq!(state.stage(), DateTimePickerStage::Date);
+        assert!(
+            app.world()
+                .get::<MaterialDatePicker>(state.date_picker())
+                .unwrap()
+                .open
+        );
+
+        let date = Date::new(2025, 3, 7);
+        app.world_mut().write_message(DatePickerSubmitEvent {
+            entity: state.date_picker(),
+            selection: DateSelection::Single(date),
+        });
+        app.update();
+        let state = picker(&app, flow);
+        assert_eq!(state.stage(), DateTimePickerStage::Time);
+        assert_eq!(state.date(), Some(date));
+        assert!(
+            app.world()
+                .get::<MaterialTimePicker>(state.time_picker())
+                .unwrap()
+                .open
+        );
+
+        app.world_mut().write_message(TimePickerSubmitEvent {
+            entity: state.time_picker(),
+            hour: 9,
+            minute: 5,
+        });
+        app.update();
+        let submitted: Vec<_> = app
+            .world()
+            .resource::<Messages<DateTimePickerSubmitEvent>>()
+            .iter_current_update_messages()
+            .map(|ev| (ev.entity, ev.value))
+            .collect();
+        assert_eq!(submitted, vec![(flow, DateTime::new(date, 9, 5))]);
+
+        let state = picker(&app, flow);
+        assert!(!state.open);
+        assert_eq!(state.stage(), DateTimePickerStage::Idle);
+    }
+
+    #[test]
+    fn test_cancelling_the_date_stage_cancels_the_flow() {
+        let (mut app, flow) = flow_app();
+        let state = start(&mut app, flow);
+
+        app.world_mut().write_message(DatePickerCancelEvent {
+            entity: state.date_picker(),
+        });
+        app.update();
+        assert_eq!(cancelled(&app), vec![flow]);
+
+        let state = picker(&app, flow);
+        assert!(!state.open);
+        assert_eq!(state.stage(), DateTimePickerStage::Idle);
+
+        // The flow starts over from the date stage.
+        let state = start(&mut app, flow);
+        assert_eq!(state.stage(), DateTimePickerStage::Date);
+        assert_eq!(state.date(), None);
+    }
+
+    #[test]
+    fn test_cancelling_the_time_stage_cancels_the_flow() {
+        let (mut app, flow) = flow_app();
+        let state = start(&mut app, flow);
+
+        app.world_mut().write_message(DatePickerSubmitEvent {
+            entity: state.date_picker(),
+            selection: DateSelection::Single(Date::new(2025, 3, 7)),
+        });
+        app.update();
+        app.world_mut().write_message(TimePickerCancelEvent {
+            entity: state.time_picker(),
+        });
+        app.update();
+        assert_eq!(cancelled(&app), vec![flow]);
+        assert!(app
+            .world()
+            .resource::<Messages<DateTimePickerSubmitEvent>>()
+            .is_empty());
+
+        let state = picker(&app, flow);
+        assert!(!state.open);
+        assert_eq!(state.stage(), DateTimePickerStage::Idle);
+
+        let state = start(&mut app, flow);
+        assert_eq!(state.stage(), DateTimePickerStage::Date);
+        assert_eq!(state.date(), None);
+    }
+
+    #[test]
+    fn test_datetime_display() {
+        let value = DateTime::new(Date::new(2025, 3, 7), 9, 5);
+        assert_eq!(value.to_string(), "2025-03-07 09:05");
+    }
+
+    #[test]
+    fn test_datetime_ordering() {
+        let date = Date::new(2025, 3, 7);
+        assert!(DateTime::new(date, 9, 5) < DateTime::new(date, 10, 0));
+        assert!(DateTime::new(date, 23, 59) < DateTime::new(Date::new(2025, 3, 8), 0, 0));
+    }
+}
@@ -8,10 +8,17 @@
 use bevy::picking::Pickable;
 use bevy::prelude::*;
 use bevy::ui::BoxShadow;
+use bevy::window::PrimaryWindow;
 
 use crate::{
-    elevation::Elevation,
+    divider::DIVIDER_THICKNESS,
+    elevation::{Elevation, ElevationTintSettings},
     i18n::LocalizedText,
+    overlay::{
+        open_on_shortcut_system, track_overlay_system, OpenableOverlay, Overlay, OverlayStack,
+        Z_DIALOG, Z_SCRIM,
+    },
+    scroll::{ScrollContainer, ScrollContainerBuilder, ScrollPlugin},
     telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
@@ -25,6 +32,9 @@ impl Plugin for DialogPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
+        if !app.is_plugin_added::<ScrollPlugin>() {
+            app.add_plugins(ScrollPlugin);
+        }
         app.add_message::<DialogOpenEvent>()
             .add_message::<DialogCloseEvent>()
             .add_message::<DialogConfirmEvent>()
@@ -32,12 +42,22 @@ impl Plugin for DialogPlugin {
                 Update,
                 (
                     dialog_visibility_system,
+                    track_overlay_system::<MaterialDialog>,
+                    open_on_shortcut_system::<MaterialDialog>,
+                    dialog_keyboard_dismiss_system,
                     dialog_scrim_visibility_system,
                     dialog_pickable_system,
                     dialog_scrim_pickable_system,
                     dialog_shadow_system,
+                    dialog_surface_tint_system,
                     dialog_telemetry_system,
                     dialog_scrim_telemetry_system,
+                    (
+                        dialog_layout_system,
+                        dialog_divider_spawn_system,
+                        dialog_max_height_system,
+                        dialog_divider_system,
+                    ),
                 ),
             );
     }
@@ -279,7 +299,7 @@ impl MaterialDialog {
 
     /// Get the elevation
     pub fn elevation(&self) -> Elevation {
-        Elevation::Level3
+        Elevation::level_3()
     }
 }
 
@@ -289,6 +309,18 @@ impl Default for MaterialDialog {
     }
 }
 
+impl Overlay for MaterialDialog {
+    fn overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
+impl OpenableOverlay for MaterialDialog {
+    fn open_overlay(&mut self) {
+        self.open = true;
+    }
+}
+
 /// Event to open a dialog
 #[derive(Event, bevy::prelude::Message)]
 pub struct DialogOpenEvent {
@@ -312,6 +344,8 @@ pub struct DialogConfirmEvent {
 /// Dialog dimensions
 pub const DIALOG_MIN_WIDTH: f32 = 280.0;
 pub const DIALOG_MAX_WIDTH: f32 = 560.0;
+/// Space kept between a dialog and the top and bottom edges of the window
+pub const DIALOG_WINDOW_MARGIN: f32 = 48.0;
 
 /// System to handle dialog visibility
 fn dialog_visibility_system(
@@ -326,6 +360,156 @@ fn dialog_visibility_system(
     }
 }
 
+/// Cap basic dialogs to the window height minus [`DIALOG_WINDOW_MARGIN`].
+///
+/// The content area shrinks to fit and scrolls, keeping the headline and
+/// actions on screen.
+fn dialog_max_height_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut dialogs: Query<(&MaterialDialog, &mut Node)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let scale = ui_scale.map_or(1.0, |s| s.0).max(f32::EPSILON);
+    let available = (window.height() / scale - 2.0 * DIALOG_WINDOW_MARGIN).max(0.0);
+
+    for (dialog, mut node) in dialogs.iter_mut() {
+        let max_height = if dialog.dialog_type == DialogType::FullScreen {
+            Val::Auto
+        } else {
+            Val::Px(available)
+        };
+        if node.max_height != max_height {
+            node.max_height = max_height;
+        }
+    }
+}
+
+/// Make dialog content scrollable and pin the headline and actions.
+///
+/// Content spawned by hand gets the same treatment as content spawned through
+/// [`SpawnDialogChild`].
+fn dialog_layout_system(
+    mut commands: Commands,
+    mut contents: Query<
+        (Entity, &mut Node),
+        (
+            With<DialogContent>,
+            Added<DialogContent>,
+            Without<ScrollContainer>,
+        ),
+    >,
+    mut pinned: Query<
+        &mut Node,
+        (
+            Or<(Added<DialogHeadline>, Added<DialogActions>)>,
+            Without<DialogContent>,
+        ),
+    >,
+) {
+    for (entity, mut node) in contents.iter_mut() {
+        node.min_height = Val::Px(0.0);
+        node.flex_shrink = 1.0;
+        // Bevy's scroll system expects both axes to be `Scroll`.
+        node.overflow = Overflow::scroll();
+        commands.entity(entity).insert((
+            ScrollContainerBuilder::new().vertical().build(),
+            ScrollPosition::default(),
+        ));
+    }
+    for mut node in pinned.iter_mut() {
+        node.flex_shrink = 0.0;
+    }
+}
+
+/// Insert a [`DialogDivider`] above the actions of each dialog.
+fn dialog_divider_spawn_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    actions: Query<(Entity, &ChildOf), Added<DialogActions>>,
+    dialogs: Query<&Children, With<MaterialDialog>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (entity, child_of) in actions.iter() {
+        let dialog = child_of.parent();
+        let Ok(children) = dialogs.get(dialog) else {
+            continue;
+        };
+        let Some(index) = children.iter().position(|child| child == entity) else {
+            continue;
+        };
+
+        let divider = commands
+            .spawn((
+                DialogDivider,
+                Node {
+                    height: Val::Px(DIVIDER_THICKNESS),
+                    flex_shrink: 0.0,
+                    // Span the full dialog width, across its padding.
+                    margin: UiRect::horizontal(Val::Px(-Spacing::EXTRA_LARGE)),
+                    ..default()
+                },
+                BackgroundColor(theme.outline_variant),
+                Visibility::Hidden,
+            ))
+            .id();
+        commands.entity(dialog).insert_children(index, &[divider]);
+    }
+}
+
+/// Show the actions divider while the dialog content overflows.
+fn dialog_divider_system(
+    theme: Option<Res<MaterialTheme>>,
+    dialogs: Query<&Children, With<MaterialDialog>>,
+    contents: Query<&ScrollContainer, With<DialogContent>>,
+    mut dividers: Query<(&mut Visibility, &mut BackgroundColor), With<DialogDivider>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for children in dialogs.iter() {
+        let scrollable = children
+            .iter()
+            .any(|child| contents.get(child).is_ok_and(|c| c.needs_scroll_y()));
+        let visibility = if scrollable {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        for child in children.iter() {
+            let Ok((mut divider_visibility, mut color)) = dividers.get_mut(child) else {
+                continue;
+            };
+            divider_visibility.set_if_neq(visibility);
+            if theme.is_changed() {
+                color.set_if_neq(BackgroundColor(theme.outline_variant));
+            }
+        }
+    }
+}
+
+/// Close the frontmost dialog when Escape is pressed.
+fn dialog_keyboard_dismiss_system(
+    stack: Option<Res<OverlayStack>>,
+    mut dialogs: Query<(Entity, &mut MaterialDialog)>,
+    mut close_events: MessageWriter<DialogCloseEvent>,
+) {
+    let Some(stack) = stack else { return };
+
+    for (entity, mut dialog) in dialogs.iter_mut() {
+        if dialog.open && dialog.dismiss_on_escape && stack.escape_pressed_for(entity) {
+            dialog.open = false;
+            close_events.write(DialogCloseEvent {
+                entity,
+                dismissed: true,
+            });
+        }
+    }
+}
+
 /// System to update dialog shadows using native BoxShadow
 fn dialog_shadow_system(
     mut dialogs: Query<(&MaterialDialog, &mut BoxShadow), Changed<MaterialDialog>>,
@@ -335,8 +519,27 @@ fn dialog_shadow_system(
         if dialog.open {
             *shadow = dialog.elevation().to_box_shadow();
         } else {
-            *shadow = BoxShadow::default();
+            *shadow = Elevation::level_0().to_box_shadow();
+        }
+    }
+}
+
+/// Apply the elevation surface tint to dialog backgrounds.
+fn dialog_surface_tint_system(
+    theme: Option<Res<MaterialTheme>>,
+    tint: Option<Res<ElevationTintSettings>>,
+    mut dialogs: Query<(Ref<MaterialDialog>, &mut BackgroundColor)>,
+) {
+    let Some(theme) = theme else { return };
+    let refresh_all = theme.is_changed() || tint.as_ref().is_some_and(|t| t.is_changed());
+    let tint = tint.map(|t| *t).unwrap_or_default();
+
+    for (dialog, mut bg_color) in dialogs.iter_mut() {
+        if !refresh_all && !dialog.is_added() {
+            continue;
         }
+        let surface = dialog.surface_color(&theme);
+        *bg_color = BackgroundColor(tint.apply(dialog.elevation(), surface, &theme));
     }
 }
 
@@ -489,7 +692,7 @@ impl DialogBuilder {
                 CornerRadius::EXTRA_LARGE
             })),
             // Native Bevy 0.17 shadow support (starts hidden since dialog is closed)
-            BoxShadow::default(),
+            Elevation::level_0().to_box_shadow(),
             // Ensure modal dialogs block pointer interactions behind the dialog surface.
             if modal {
                 Pickable {
@@ -502,6 +705,7 @@ impl DialogBuilder {
                     is_hoverable: false,
                 }
             },
+            GlobalZIndex(Z_DIALOG),
         )
     }
 }
@@ -524,7 +728,9 @@ pub struct DialogScrimFor(pub Entity);
 #[derive(Component)]
 pub struct DialogHeadline;
 
-/// Marker for dialog content area
+/// Marker for dialog content area.
+///
+/// The content scrolls when it doesn't fit in the dialog.
 #[derive(Component)]
 pub struct DialogContent;
 
@@ -532,6 +738,10 @@ pub struct DialogContent;
 #[derive(Component)]
 pub struct DialogActions;
 
+/// Marker for the divider shown above [`DialogActions`] while the content scrolls
+#[derive(Component)]
+pub struct DialogDivider;
+
 /// Helper to create a dialog scrim
 pub fn create_dialog_scrim(theme: &MaterialTheme) -> impl Bundle {
     (
@@ -552,6 +762,7 @@ pub fn create_dialog_scrim(theme: &MaterialTheme) -> impl Bundle {
             should_block_lower: true,
             is_hoverable: false,
         },
+        GlobalZIndex(Z_SCRIM),
     )
 }
 
@@ -587,7 +798,7 @@ pub fn create_dialog_scrim_for(
         } else {
             Pickable::IGNORE
         },
-        GlobalZIndex(1000),
+        GlobalZIndex(Z_SCRIM),
     )
 }
 
@@ -701,3 +912,99 @@ impl SpawnDialogChild for ChildSpawnerCommands<'_> {
         self.spawn(create_dialog_scrim(theme));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_content_scrolls_between_pinned_headline_and_actions() {
+        let mut app = App::new();
+        app.insert_resource(MaterialTheme::default()).add_systems(
+            Update,
+            (
+                dialog_layout_system,
+                dialog_divider_spawn_system,
+                dialog_divider_system,
+            )
+                .chain(),
+        );
+
+        let dialog = app.world_mut().spawn(MaterialDialog::new()).id();
+        let headline = app
+            .world_mut()
+            .spawn((DialogHeadline, Node::default(), ChildOf(dialog)))
+            .id();
+        let content = app
+            .world_mut()
+            .spawn((DialogContent, Node::default(), ChildOf(dialog)))
+            .id();
+        let actions = app
+            .world_mut()
+            .spawn((DialogActions, Node::default(), ChildOf(dialog)))
+            .id();
+        app.update();
+
+        let node = app.world().get::<Node>(content).unwrap();
+        assert_eq!(node.overflow, Overflow::scroll());
+        assert_eq!(node.flex_shrink, 1.0);
+        assert!(app.world().get::<ScrollContainer>(content).is_some());
+        for pinned in [headline, actions] {
+            assert_eq!(app.world().get::<Node>(pinned).unwrap().flex_shrink, 0.0);
+        }
+
+        // The divider sits right above the actions and stays hidden while
+        // everything fits.
+        let children = app.world().get::<Children>(dialog).unwrap().to_vec();
+        assert_eq!(children.len(), 4);
+        let divider = children[2];
+        assert!(app.world().get::<DialogDivider>(divider).is_some());
+        assert_eq!(children[3], actions);
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(divider),
+            Some(&Visibility::Hidden)
+        );
+
+        app.world_mut()
+            .get_mut::<ScrollContainer>(content)
+            .unwrap()
+            .max_offset
+            .y = 120.0;
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(divider),
+            Some(&Visibility::Inherited)
+        );
+    }
+
+    #[test]
+    fn test_dialog_height_is_capped_to_the_window() {
+        let mut app = App::new();
+        app.add_systems(Update, dialog_max_height_system);
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+
+        let basic = app
+            .world_mut()
+            .spawn((MaterialDialog::new(), Node::default()))
+            .id();
+        let full_screen = app
+            .world_mut()
+            .spawn((
+                MaterialDialog::new().with_type(DialogType::FullScreen),
+                Node::default(),
+            ))
+            .id();
+        app.update();
+
+        let window_height = Window::default().height();
+        assert_eq!(
+            app.world().get::<Node>(basic).unwrap().max_height,
+            Val::Px(window_height - 2.0 * DIALOG_WINDOW_MARGIN)
+        );
+        assert_eq!(
+            app.world().get::<Node>(full_screen).unwrap().max_height,
+            Val::Auto
+        );
+    }
+}
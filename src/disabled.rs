@@ -0,0 +1,150 @@
+//! Shared disabled state for interactive components
+//!
+//! Components keep their own `disabled` flag for styling. Registering
+//! [`sync_disabled_system`] for a component mirrors that flag into the
+//! [`Disabled`] marker, and entities carrying the marker:
+//!
+//! - never report `Interaction::Hovered` or `Interaction::Pressed`, so click,
+//!   hover, and state layer systems don't react to them
+//! - don't spawn ripples
+//! - are skipped by Tab traversal
+//!
+//! The marker can also be inserted directly on plain `Button` entities.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+
+use crate::focus::Focusable;
+
+/// Plugin that enforces the [`Disabled`] marker
+pub struct DisabledPlugin;
+
+impl Plugin for DisabledPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            (suppress_disabled_interaction_system, disabled_focus_system).after(UiSystems::Focus),
+        );
+    }
+}
+
+/// Marks an entity as disabled: it ignores pointer interaction and ripples
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Disabled;
+
+/// Components with a disabled flag that should drive the [`Disabled`] marker
+pub trait DisabledSource: Component {
+    /// Whether the component is currently disabled
+    fn is_disabled(&self) -> bool;
+}
+
+/// Insert or remove [`Disabled`] to match a component's disabled flag.
+///
+/// Register once per [`DisabledSource`] component type.
+pub fn sync_disabled_system<T: DisabledSource>(
+    mut commands: Commands,
+    sources: Query<(Entity, &T, Has<Disabled>), Changed<T>>,
+) {
+    for (entity, source, has_marker) in sources.iter() {
+        match (source.is_disabled(), has_marker) {
+            (true, false) => {
+                commands.entity(entity).insert(Disabled);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<Disabled>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Hold disabled entities at `Interaction::None`.
+///
+/// Runs right after Bevy's UI focus pass, so `Update` systems never observe
+/// a hover or press on a disabled entity.
+fn suppress_disabled_interaction_system(
+    mut interactions: Query<
+        &mut Interaction,
+        (With<Disabled>, Or<(Changed<Interaction>, Added<Disabled>)>),
+    >,
+) {
+    for mut interaction in interactions.iter_mut() {
+        if *interaction != Interaction::None {
+            *interaction = Interaction::None;
+        }
+    }
+}
+
+/// Keep disabled entities out of Tab traversal and drop their focus.
+fn disabled_focus_system(
+    mut added: Query<&mut Focusable, Added<Disabled>>,
+    mut removed: RemovedComponents<Disabled>,
+    mut focusables: Query<&mut Focusable, Without<Disabled>>,
+) {
+    for mut focusable in added.iter_mut() {
+        focusable.disabled = true;
+        focusable.focused = false;
+        focusable.focus_visible = false;
+    }
+    for entity in removed.read() {
+        if let Ok(mut focusable) = focusables.get_mut(entity) {
+            focusable.disabled = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Toggle {
+        disabled: bool,
+    }
+
+    impl DisabledSource for Toggle {
+        fn is_disabled(&self) -> bool {
+            self.disabled
+        }
+    }
+
+    #[test]
+    fn test_marker_follows_source() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_disabled_system::<Toggle>);
+
+        let entity = app.world_mut().spawn(Toggle { disabled: true }).id();
+        app.update();
+        assert!(app.world().entity(entity).contains::<Disabled>());
+
+        app.world_mut().get_mut::<Toggle>(entity).unwrap().disabled = false;
+        app.update();
+        assert!(!app.world().entity(entity).contains::<Disabled>());
+    }
+
+    #[test]
+    fn test_disabled_interaction_is_suppressed() {
+        let mut app = App::new();
+        app.add_systems(Update, suppress_disabled_interaction_system);
+
+        let disabled = app.world_mut().spawn((Interaction::Pressed, Disabled)).id();
+        let enabled = app.world_mut().spawn(Interaction::Pressed).id();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Interaction>(disabled),
+            Some(&Interaction::None)
+        );
+        assert_eq!(
+            app.world().get::<Interaction>(enabled),
+            Some(&Interaction::Pressed)
+        );
+
+        *app.world_mut().get_mut::<Interaction>(disabled).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(
+            app.world().get::<Interaction>(disabled),
+            Some(&Interaction::None)
+        );
+    }
+}
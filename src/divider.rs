@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 
 use crate::theme::MaterialTheme;
+use crate::tokens::Spacing;
 
 /// Divider variants
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -26,6 +27,9 @@ pub struct MaterialDivider {
     pub variant: DividerVariant,
     /// Whether the divider is vertical
     pub vertical: bool,
+    /// Whether `vertical` follows the parent's flex direction: vertical in
+    /// rows, horizontal in columns
+    pub auto_orientation: bool,
 }
 
 impl MaterialDivider {
@@ -34,6 +38,7 @@ impl MaterialDivider {
         Self {
             variant: DividerVariant::default(),
             vertical: false,
+            auto_orientation: false,
         }
     }
 
@@ -42,6 +47,7 @@ impl MaterialDivider {
         Self {
             variant: DividerVariant::default(),
             vertical: true,
+            auto_orientation: false,
         }
     }
 
@@ -65,6 +71,39 @@ impl MaterialDivider {
     pub fn color(&self, theme: &MaterialTheme) -> Color {
         theme.outline_variant
     }
+
+    fn margin(&self) -> UiRect {
+        let is_vertical = self.vertical;
+        match self.variant {
+            DividerVariant::FullWidth => UiRect::ZERO,
+            DividerVariant::Inset => {
+                if is_vertical {
+                    UiRect::top(Val::Px(DIVIDER_INSET))
+                } else {
+                    UiRect::left(Val::Px(DIVIDER_INSET))
+                }
+            }
+            DividerVariant::MiddleInset => {
+                if is_vertical {
+                    UiRect::vertical(Val::Px(DIVIDER_INSET))
+                } else {
+                    UiRect::horizontal(Val::Px(DIVIDER_INSET))
+                }
+            }
+        }
+    }
+
+    /// Size and margin of an unlabeled divider line
+    fn apply_line_layout(&self, node: &mut Node) {
+        let (width, height) = if self.vertical {
+            (Val::Px(DIVIDER_THICKNESS), Val::Percent(100.0))
+        } else {
+            (Val::Percent(100.0), Val::Px(DIVIDER_THICKNESS))
+        };
+        node.width = width;
+        node.height = height;
+        node.margin = self.margin();
+    }
 }
 
 impl Default for MaterialDivider {
@@ -77,10 +116,17 @@ impl Default for MaterialDivider {
 pub const DIVIDER_THICKNESS: f32 = 1.0;
 /// Inset margin
 pub const DIVIDER_INSET: f32 = 16.0;
+/// Font size of a divider label
+pub const DIVIDER_LABEL_FONT_SIZE: f32 = 12.0;
+
+/// Marker for the text of a labeled divider
+#[derive(Component)]
+pub struct DividerLabel;
 
 /// Builder for dividers
 pub struct DividerBuilder {
     divider: MaterialDivider,
+    label: Option<String>,
 }
 
 impl DividerBuilder {
@@ -88,6 +134,7 @@ impl DividerBuilder {
     pub fn new() -> Self {
         Self {
             divider: MaterialDivider::new(),
+            label: None,
         }
     }
 
@@ -95,9 +142,19 @@ impl DividerBuilder {
     pub fn vertical() -> Self {
         Self {
             divider: MaterialDivider::vertical(),
+            label: None,
         }
     }
 
+    /// Center a label (e.g. "OR") between two divider lines
+    ///
+    /// Labeled dividers have children, so spawn them with
+    /// [`SpawnDividerChild::spawn_divider_with`]; [`DividerBuilder::build`] ignores the label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Set variant
     pub fn variant(mut self, variant: DividerVariant) -> Self {
         self.divider.variant = variant;
@@ -119,48 +176,22 @@ impl DividerBuilder {
         self.variant(DividerVariant::MiddleInset)
     }
 
+    /// Pick the orientation from the parent's flex direction: vertical in a
+    /// row, horizontal in a column. It follows later direction changes too.
+    ///
+    /// Applies to unlabeled dividers; labeled ones keep the builder's orientation.
+    pub fn auto(mut self) -> Self {
+        self.divider.auto_orientation = true;
+        self
+    }
+
     /// Build the divider bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let color = self.divider.color(theme);
-        let is_vertical = self.divider.vertical;
-        let variant = self.divider.variant;
+        let mut node = Node::default();
+        self.divider.apply_line_layout(&mut node);
 
-        let margin = match variant {
-            DividerVariant::FullWidth => UiRect::ZERO,
-            DividerVariant::Inset => {
-                if is_vertical {
-                    UiRect::top(Val::Px(DIVIDER_INSET))
-                } else {
-                    UiRect::left(Val::Px(DIVIDER_INSET))
-                }
-            }
-            DividerVariant::MiddleInset => {
-                if is_vertical {
-                    UiRect::vertical(Val::Px(DIVIDER_INSET))
-                } else {
-                    UiRect::horizontal(Val::Px(DIVIDER_INSET))
-                }
-            }
-        };
-
-        (
-            self.divider,
-            Node {
-                width: if is_vertical {
-                    Val::Px(DIVIDER_THICKNESS)
-                } else {
-                    Val::Percent(100.0)
-                },
-                height: if is_vertical {
-                    Val::Percent(100.0)
-                } else {
-                    Val::Px(DIVIDER_THICKNESS)
-                },
-                margin,
-                ..default()
-            },
-            BackgroundColor(color),
-        )
+        (self.divider, node, BackgroundColor(color))
     }
 }
 
@@ -170,6 +201,39 @@ impl Default for DividerBuilder {
     }
 }
 
+/// Orient [`DividerBuilder::auto`] dividers along their parent's cross axis.
+///
+/// Runs when the divider is spawned and whenever the parent's `Node` changes.
+pub(crate) fn divider_auto_orientation_system(
+    mut dividers: Query<(&mut MaterialDivider, &mut Node, &ChildOf)>,
+    parents: Query<Ref<Node>, Without<MaterialDivider>>,
+) {
+    for (mut divider, mut node, child_of) in dividers.iter_mut() {
+        if !divider.auto_orientation {
+            continue;
+        }
+        let Ok(parent) = parents.get(child_of.parent()) else {
+            continue;
+        };
+        if !parent.is_changed() && !divider.is_changed() {
+            continue;
+        }
+
+        let vertical = matches!(
+            parent.flex_direction,
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        if divider.vertical != vertical {
+            divider.vertical = vertical;
+        }
+        let mut line = node.clone();
+        divider.apply_line_layout(&mut line);
+        if *node != line {
+            *node = line;
+        }
+    }
+}
+
 /// Helper function to create a simple horizontal divider
 pub fn horizontal_divider(theme: &MaterialTheme) -> impl Bundle {
     DividerBuilder::new().build(theme)
@@ -217,7 +281,154 @@ impl SpawnDividerChild for ChildSpawnerCommands<'_> {
         self.spawn(DividerBuilder::new().inset().build(theme));
     }
 
-    fn spawn_divider_with(&mut self, theme: &MaterialTheme, builder: DividerBuilder) {
-        self.spawn(builder.build(theme));
+    fn spawn_divider_with(&mut self, theme: &MaterialTheme, mut builder: DividerBuilder) {
+        let Some(label) = builder.label.clone() else {
+            self.spawn(builder.build(theme));
+            return;
+        };
+        builder.divider.auto_orientation = false;
+
+        let line_color = builder.divider.color(theme);
+        let is_vertical = builder.divider.vertical;
+        let line = Node {
+            flex_grow: 1.0,
+            width: if is_vertical {
+                Val::Px(DIVIDER_THICKNESS)
+            } else {
+                Val::Auto
+            },
+            height: if is_vertical {
+                Val::Auto
+            } else {
+                Val::Px(DIVIDER_THICKNESS)
+            },
+            ..default()
+        };
+
+        self.spawn((
+            Node {
+                width: if is_vertical {
+                    Val::Auto
+                } else {
+                    Val::Percent(100.0)
+                },
+                height: if is_vertical {
+                    Val::Percent(100.0)
+                } else {
+                    Val::Auto
+                },
+                flex_direction: if is_vertical {
+                    FlexDirection::Column
+                } else {
+                    FlexDirection::Row
+                },
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::LARGE),
+                row_gap: Val::Px(Spacing::SMALL),
+                margin: builder.divider.margin(),
+                ..default()
+            },
+            builder.divider,
+        ))
+        .with_children(|divider| {
+            divider.spawn((line.clone(), BackgroundColor(line_color)));
+            divider.spawn((
+                DividerLabel,
+                Text::new(label),
+                TextFont {
+                    font_size: DIVIDER_LABEL_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(theme.on_surface_variant),
+            ));
+            divider.spawn((line, BackgroundColor(line_color)));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_divider_follows_parent_direction() {
+        let mut app = App::new();
+        app.add_systems(Update, divider_auto_orientation_system);
+        let theme = MaterialTheme::default();
+
+        let row = app
+            .world_mut()
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                ..default()
+            })
+            .id();
+        let divider = app
+            .world_mut()
+            .spawn((
+                DividerBuilder::new().inset().auto().build(&theme),
+                ChildOf(row),
+            ))
+            .id();
+        app.update();
+
+        assert!(
+            app.world()
+                .get::<MaterialDivider>(divider)
+                .unwrap()
+                .vertical
+        );
+        let node = app.world().get::<Node>(divider).unwrap();
+        assert_eq!(node.width, Val::Px(DIVIDER_THICKNESS));
+        assert_eq!(node.height, Val::Percent(100.0));
+        assert_eq!(node.margin, UiRect::top(Val::Px(DIVIDER_INSET)));
+
+        app.world_mut().get_mut::<Node>(row).unwrap().flex_direction = FlexDirection::Column;
+        app.update();
+
+        assert!(
+            !app.world()
+                .get::<MaterialDivider>(divider)
+                .unwrap()
+                .vertical
+        );
+        let node = app.world().get::<Node>(divider).unwrap();
+        assert_eq!(node.width, Val::Percent(100.0));
+        assert_eq!(node.height, Val::Px(DIVIDER_THICKNESS));
+    }
+
+    #[test]
+    fn test_labeled_divider_centers_label_between_lines() {
+        let mut app = App::new();
+        let theme = MaterialTheme::default();
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                parent.spawn_divider_with(&theme, DividerBuilder::new().inset().with_label("OR"));
+            });
+        app.world_mut().flush();
+
+        let (line_color, node, children) = app
+            .world_mut()
+            .query::<(&MaterialDivider, &Node, &Children)>()
+            .single(app.world())
+            .map(|(divider, node, children)| {
+                (divider.color(&theme), node.clone(), children.to_vec())
+            })
+            .unwrap();
+        assert_eq!(node.flex_direction, FlexDirection::Row);
+        assert_eq!(node.margin.left, Val::Px(DIVIDER_INSET));
+        assert_eq!(children.len(), 3);
+
+        for line in [children[0], children[2]] {
+            assert_eq!(
+                app.world().get::<BackgroundColor>(line).unwrap().0,
+                line_color
+            );
+        }
+        let label = children[1];
+        assert!(app.world().get::<DividerLabel>(label).is_some());
+        assert_eq!(app.world().get::<Text>(label).unwrap().0, "OR");
     }
 }
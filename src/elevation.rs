@@ -5,10 +5,25 @@
 //!
 //! This module now leverages Bevy 0.17's native `BoxShadow` component for rendering
 //! Material Design elevation shadows.
+//!
+//! Elevated surfaces are also tinted towards the theme's surface tint color, see
+//! [`ElevationTint`] and [`ElevationTintSettings`].
 
 use bevy::prelude::*;
 use bevy::ui::{BoxShadow, ShadowStyle, Val};
 
+use crate::theme::{blend_state_layer, MaterialTheme};
+
+/// Plugin for elevation surface tinting
+pub struct ElevationPlugin;
+
+impl Plugin for ElevationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ElevationTintSettings>()
+            .add_systems(Update, elevation_tint_system);
+    }
+}
+
 /// Elevation levels in Material Design 3
 #[derive(Debug, Clone, Copy, PartialEq, Default, Component)]
 pub enum Elevation {
@@ -28,6 +43,36 @@ pub enum Elevation {
 }
 
 impl Elevation {
+    /// Level 0 (0dp): flat surfaces
+    pub const fn level_0() -> Self {
+        Elevation::Level0
+    }
+
+    /// Level 1 (1dp): elevated cards, buttons, and chips
+    pub const fn level_1() -> Self {
+        Elevation::Level1
+    }
+
+    /// Level 2 (3dp): menus and hovered cards
+    pub const fn level_2() -> Self {
+        Elevation::Level2
+    }
+
+    /// Level 3 (6dp): dialogs, FABs, and snackbars
+    pub const fn level_3() -> Self {
+        Elevation::Level3
+    }
+
+    /// Level 4 (8dp): hovered FABs
+    pub const fn level_4() -> Self {
+        Elevation::Level4
+    }
+
+    /// Level 5 (12dp): the highest level
+    pub const fn level_5() -> Self {
+        Elevation::Level5
+    }
+
     /// Get the elevation value in dp
     pub fn dp(&self) -> f32 {
         match self {
@@ -65,13 +110,41 @@ impl Elevation {
     }
 
     /// Get the shadow blur radius for this elevation level
+    ///
+    /// Offsets, blur, and spread follow the MD3 ambient shadow for each level.
     pub fn shadow_blur(&self) -> f32 {
-        self.dp() * 2.0
+        match self {
+            Elevation::Level0 => 0.0,
+            Elevation::Level1 => 3.0,
+            Elevation::Level2 => 6.0,
+            Elevation::Level3 => 8.0,
+            Elevation::Level4 => 10.0,
+            Elevation::Level5 => 12.0,
+        }
     }
 
     /// Get the shadow y-offset for this elevation level
     pub fn shadow_offset_y(&self) -> f32 {
-        self.dp() * 0.5
+        match self {
+            Elevation::Level0 => 0.0,
+            Elevation::Level1 => 1.0,
+            Elevation::Level2 => 2.0,
+            Elevation::Level3 => 4.0,
+            Elevation::Level4 => 6.0,
+            Elevation::Level5 => 8.0,
+        }
+    }
+
+    /// Get the shadow spread radius for this elevation level
+    pub fn shadow_spread(&self) -> f32 {
+        match self {
+            Elevation::Level0 => 0.0,
+            Elevation::Level1 => 1.0,
+            Elevation::Level2 => 2.0,
+            Elevation::Level3 => 3.0,
+            Elevation::Level4 => 4.0,
+            Elevation::Level5 => 6.0,
+        }
     }
 
     /// Move to the next higher elevation level
@@ -110,11 +183,21 @@ impl Elevation {
             Color::srgba(0.0, 0.0, 0.0, self.shadow_opacity()),
             Val::Px(0.0),
             Val::Px(self.shadow_offset_y()),
-            Val::Px(0.0),
+            Val::Px(self.shadow_spread()),
             Val::Px(self.shadow_blur()),
         )
     }
 
+    /// Blend `base` towards `tint` by this level's tonal overlay opacity
+    pub fn tint(&self, base: Color, tint: Color) -> Color {
+        blend_state_layer(base, tint, self.tonal_overlay_opacity())
+    }
+
+    /// The theme surface tinted for this elevation level
+    pub fn tinted_surface(&self, theme: &MaterialTheme) -> Color {
+        self.tint(theme.surface, theme.surface_tint())
+    }
+
     /// Create a `ShadowStyle` for this elevation level
     ///
     /// Useful when you need more control over the shadow styling.
@@ -123,7 +206,7 @@ impl Elevation {
             color: Color::srgba(0.0, 0.0, 0.0, self.shadow_opacity()),
             x_offset: Val::Px(0.0),
             y_offset: Val::Px(self.shadow_offset_y()),
-            spread_radius: Val::Px(0.0),
+            spread_radius: Val::Px(self.shadow_spread()),
             blur_radius: Val::Px(self.shadow_blur()),
         }
     }
@@ -157,7 +240,120 @@ impl ElevationShadow {
             offset_x: 0.0,
             offset_y: elevation.shadow_offset_y(),
             blur: elevation.shadow_blur(),
-            spread: 0.0,
+            spread: elevation.shadow_spread(),
+        }
+    }
+}
+
+/// Global toggle for MD3 elevation surface tinting.
+///
+/// Disable this to keep only drop shadows; surfaces then use their untinted color.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ElevationTintSettings {
+    /// Whether elevated surfaces are tinted
+    pub enabled: bool,
+}
+
+impl Default for ElevationTintSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl ElevationTintSettings {
+    /// Apply the surface tint for `elevation` on top of `base`, if enabled
+    pub fn apply(&self, elevation: Elevation, base: Color, theme: &MaterialTheme) -> Color {
+        if self.enabled {
+            elevation.tint(base, theme.surface_tint())
+        } else {
+            base
+        }
+    }
+}
+
+/// Tint a surface's `BackgroundColor` from its [`Elevation`].
+///
+/// The entity's background becomes `theme.surface` blended towards the surface
+/// tint color, so higher levels look more tinted. Cards and dialogs apply the
+/// same tint to their own surface colors without needing this marker.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ElevationTint;
+
+fn elevation_tint_system(
+    theme: Option<Res<MaterialTheme>>,
+    settings: Option<Res<ElevationTintSettings>>,
+    mut surfaces: Query<(Ref<Elevation>, Ref<ElevationTint>, &mut BackgroundColor)>,
+) {
+    let Some(theme) = theme else { return };
+    let settings_changed = settings.as_ref().is_some_and(|s| s.is_changed());
+    let settings = settings.map(|s| *s).unwrap_or_default();
+    let refresh_all = theme.is_changed() || settings_changed;
+
+    for (elevation, tint, mut bg_color) in surfaces.iter_mut() {
+        if !refresh_all && !elevation.is_changed() && !tint.is_added() {
+            continue;
         }
+        *bg_color = BackgroundColor(settings.apply(*elevation, theme.surface, &theme));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_elevation_is_more_tinted() {
+        let surface = Color::WHITE;
+        let tint = Color::srgb(0.4, 0.3, 0.6);
+
+        let distance = |elevation: Elevation| {
+            let c = elevation.tint(surface, tint).to_srgba();
+            (1.0 - c.red) + (1.0 - c.green) + (1.0 - c.blue)
+        };
+
+        assert_eq!(Elevation::Level0.tint(surface, tint), surface);
+        assert!(distance(Elevation::Level1) < distance(Elevation::Level3));
+        assert!(distance(Elevation::Level3) < distance(Elevation::Level5));
+    }
+
+    #[test]
+    fn test_level_presets_match_md3() {
+        // (preset, dp, y offset, blur, spread)
+        let expected = [
+            (Elevation::level_0(), 0.0, 0.0, 0.0, 0.0),
+            (Elevation::level_1(), 1.0, 1.0, 3.0, 1.0),
+            (Elevation::level_2(), 3.0, 2.0, 6.0, 2.0),
+            (Elevation::level_3(), 6.0, 4.0, 8.0, 3.0),
+            (Elevation::level_4(), 8.0, 6.0, 10.0, 4.0),
+            (Elevation::level_5(), 12.0, 8.0, 12.0, 6.0),
+        ];
+
+        for (elevation, dp, y, blur, spread) in expected {
+            assert_eq!(elevation.dp(), dp);
+
+            let shadow = elevation.to_box_shadow();
+            if elevation == Elevation::Level0 {
+                assert!(shadow.is_empty());
+                continue;
+            }
+            assert_eq!(shadow.len(), 1);
+            let style = &shadow[0];
+            assert_eq!(style.x_offset, Val::Px(0.0));
+            assert_eq!(style.y_offset, Val::Px(y));
+            assert_eq!(style.blur_radius, Val::Px(blur));
+            assert_eq!(style.spread_radius, Val::Px(spread));
+            assert_eq!(style.color.alpha(), elevation.shadow_opacity());
+        }
+    }
+
+    #[test]
+    fn test_tint_settings_opt_out() {
+        let theme = MaterialTheme::default();
+        let base = theme.surface;
+        let disabled = ElevationTintSettings { enabled: false };
+        assert_eq!(disabled.apply(Elevation::Level5, base, &theme), base);
+
+        let enabled = ElevationTintSettings::default();
+        assert_ne!(enabled.apply(Elevation::Level5, base, &theme), base);
     }
 }
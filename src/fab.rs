@@ -14,6 +14,7 @@ use crate::{
     elevation::Elevation,
     icons::IconStyle,
     ripple::RippleHost,
+    semantics::{sync_semantics_system, Role, Semantic},
     theme::{blend_state_layer, MaterialTheme},
     tokens::{CornerRadius, Spacing},
 };
@@ -31,6 +32,7 @@ impl Plugin for FabPlugin {
                 fab_content_style_system,
                 fab_theme_refresh_system,
                 fab_shadow_system,
+                sync_semantics_system::<MaterialFab>,
             ),
         );
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
@@ -215,6 +217,21 @@ impl MaterialFab {
     }
 }
 
+impl Semantic for MaterialFab {
+    fn semantic_role(&self) -> Role {
+        Role::Button
+    }
+
+    /// The extended label, or the icon name for icon-only FABs
+    fn semantic_label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.icon.clone())
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        None
+    }
+}
+
 /// Event when FAB is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct FabClickEvent {
@@ -328,7 +345,7 @@ fn fab_shadow_system(mut fabs: Query<(&MaterialFab, &mut BoxShadow), Changed<Mat
 
 /// Builder for FABs
 pub struct FabBuilder {
-    fab: MaterialFab,
+    pub(crate) fab: MaterialFab,
 }
 
 impl FabBuilder {
@@ -395,9 +412,11 @@ impl FabBuilder {
         let corner_radius = self.fab.size.corner_radius();
         let is_extended = self.fab.is_extended();
         let elevation = self.fab.elevation();
+        let semantics = self.fab.semantics();
 
         (
             self.fab,
+            semantics,
             Button,
             RippleHost::new(),
             Node {
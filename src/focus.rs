@@ -5,19 +5,78 @@
 //!
 //! This module now leverages Bevy 0.17's native `Outline` component for rendering
 //! focus rings, providing better performance and simpler implementation.
+//!
+//! Rings follow "focus-visible" semantics: they only show when focus was moved
+//! with the keyboard, not when an element was focused by a pointer press. The
+//! last input modality is tracked in the [`FocusModality`] resource.
+//!
+//! Rings sit [`FocusIndicator::OFFSET`] outside the element and follow its
+//! `BorderRadius`, so a circular icon button gets a circular ring and a card a
+//! rounded one. Native outlines get this from Bevy; legacy [`FocusRing`]
+//! children copy the target's resolved corners.
 
+use std::collections::{HashMap, HashSet};
+
+use bevy::input::touch::Touches;
 use bevy::prelude::*;
-use bevy::ui::Outline;
+use bevy::ui::{Outline, ResolvedBorderRadius, UiGlobalTransform};
+
+use crate::tokens::FocusIndicator;
 
 /// Plugin for the focus ring system
 pub struct FocusPlugin;
 
 impl Plugin for FocusPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (update_focus_outline_system, update_focus_ring_system),
-        );
+        app.init_resource::<FocusModality>()
+            .add_message::<FocusGained>()
+            .add_message::<FocusLost>()
+            .add_systems(
+                Update,
+                (
+                    track_input_modality_system,
+                    focus_traversal_system,
+                    focus_group_navigation_system,
+                    pointer_focus_system,
+                    focus_state_system,
+                    focus_group_active_system,
+                    update_focus_outline_system,
+                    update_focus_ring_system,
+                    focus_ring_shape_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// The kind of input that last drove the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputModality {
+    /// Mouse or touch input
+    #[default]
+    Pointer,
+    /// Keyboard navigation (Tab or arrow keys)
+    Keyboard,
+}
+
+/// Tracks the last input modality to decide whether focus rings are visible.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct FocusModality {
+    /// The most recent input modality
+    pub last: InputModality,
+    /// Show focus rings regardless of modality (useful for tests and screenshots)
+    pub force_visible: bool,
+}
+
+impl FocusModality {
+    /// Whether a focused element should currently show its focus ring
+    pub fn focus_visible(&self) -> bool {
+        self.force_visible || self.last == InputModality::Keyboard
+    }
+
+    /// Force focus rings to show regardless of modality
+    pub fn set_force_visible(&mut self, force: bool) {
+        self.force_visible = force;
     }
 }
 
@@ -39,6 +98,13 @@ pub struct Focusable {
     pub ring_width: f32,
     /// Whether to use Bevy's native Outline component (recommended)
     pub use_native_outline: bool,
+    /// Explicit tab order.
+    ///
+    /// Positive values are visited first in ascending order, then `None`/`0` in
+    /// hierarchy order. Negative values remove the element from Tab traversal.
+    pub tab_index: Option<i32>,
+    /// Disabled elements are skipped by Tab traversal
+    pub disabled: bool,
 }
 
 impl Focusable {
@@ -48,9 +114,11 @@ impl Focusable {
             focused: false,
             focus_visible: false,
             ring_color: None,
-            ring_offset: 2.0,
-            ring_width: 3.0,
+            ring_offset: FocusIndicator::OFFSET,
+            ring_width: FocusIndicator::THICKNESS,
             use_native_outline: true,
+            tab_index: None,
+            disabled: false,
         }
     }
 
@@ -80,6 +148,23 @@ impl Focusable {
         self
     }
 
+    /// Set the tab order index
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Set whether the element is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether Tab traversal can land on this element
+    pub fn is_tabbable(&self) -> bool {
+        !self.disabled && self.tab_index.is_none_or(|index| index >= 0)
+    }
+
     /// Convert to a Bevy `Outline` component
     ///
     /// This leverages Bevy 0.17's native outline rendering.
@@ -102,7 +187,7 @@ pub struct FocusRing {
 }
 
 /// Event when an element gains focus
-#[derive(Event, bevy::prelude::Message)]
+#[derive(Event, Clone, bevy::prelude::Message)]
 pub struct FocusGained {
     /// The focused entity
     pub entity: Entity,
@@ -111,12 +196,448 @@ pub struct FocusGained {
 }
 
 /// Event when an element loses focus
-#[derive(Event, bevy::prelude::Message)]
+#[derive(Event, Clone, bevy::prelude::Message)]
 pub struct FocusLost {
     /// The entity that lost focus
     pub entity: Entity,
 }
 
+const KEYBOARD_NAVIGATION_KEYS: [KeyCode; 5] = [
+    KeyCode::Tab,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+];
+
+/// Record whether the keyboard or a pointer was used most recently.
+fn track_input_modality_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    touches: Option<Res<Touches>>,
+    mut modality: ResMut<FocusModality>,
+) {
+    let keyboard = keys.is_some_and(|keys| keys.any_just_pressed(KEYBOARD_NAVIGATION_KEYS));
+    let pointer = mouse.is_some_and(|mouse| mouse.get_just_pressed().next().is_some())
+        || touches.is_some_and(|touches| touches.any_just_pressed());
+
+    let next = if keyboard {
+        InputModality::Keyboard
+    } else if pointer {
+        InputModality::Pointer
+    } else {
+        return;
+    };
+
+    if modality.last != next {
+        modality.last = next;
+    }
+}
+
+/// Treats a subtree as a single Tab stop with arrow-key roving focus inside.
+///
+/// Tab enters the group on its active member (the last focused one, or the
+/// first member) and leaves it on the next Tab. Arrow keys move focus between
+/// the group's `Focusable` descendants, wrapping around.
+///
+/// Adopted by `RadioGroup`, `MaterialTabs`, `ChipGroup`, `MaterialList`, and
+/// `MaterialMenu`, whose radios, tabs, chips, and items become `Focusable`
+/// members automatically.
+#[derive(Component, Debug, Clone, Default)]
+pub struct FocusGroup {
+    /// Which arrow keys move focus within the group
+    pub orientation: FocusGroupOrientation,
+    /// Member that receives focus when Tab enters the group
+    pub active: Option<Entity>,
+}
+
+/// Arrow keys handled by a [`FocusGroup`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusGroupOrientation {
+    /// Left/Right only
+    Horizontal,
+    /// Up/Down only
+    Vertical,
+    /// Both Left/Right and Up/Down
+    #[default]
+    Both,
+}
+
+impl FocusGroup {
+    /// Create a focus group handling all arrow keys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a focus group handling Left/Right only
+    pub fn horizontal() -> Self {
+        Self {
+            orientation: FocusGroupOrientation::Horizontal,
+            ..default()
+        }
+    }
+
+    /// Create a focus group handling Up/Down only
+    pub fn vertical() -> Self {
+        Self {
+            orientation: FocusGroupOrientation::Vertical,
+            ..default()
+        }
+    }
+
+    /// Movement step for an arrow key, if this group handles it
+    pub fn arrow_step(&self, key: KeyCode) -> Option<isize> {
+        let horizontal = self.orientation != FocusGroupOrientation::Vertical;
+        let vertical = self.orientation != FocusGroupOrientation::Horizontal;
+        match key {
+            KeyCode::ArrowLeft if horizontal => Some(-1),
+            KeyCode::ArrowRight if horizontal => Some(1),
+            KeyCode::ArrowUp if vertical => Some(-1),
+            KeyCode::ArrowDown if vertical => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// A candidate for Tab traversal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TabStop {
+    /// The focusable entity
+    pub entity: Entity,
+    /// Copied from [`Focusable::tab_index`]
+    pub tab_index: Option<i32>,
+    /// Position in hierarchy (document) order
+    pub order: usize,
+    /// Nearest enclosing [`FocusGroup`]
+    pub group: Option<Entity>,
+}
+
+/// Sort tab stops: positive `tab_index` first (ascending), then hierarchy order.
+pub fn sort_tab_stops(stops: &mut [TabStop]) {
+    stops.sort_by_key(|stop| match stop.tab_index {
+        Some(index) if index > 0 => (0, index, stop.order),
+        _ => (1, 0, stop.order),
+    });
+}
+
+/// Collapse each focus group into a single stop.
+///
+/// The group keeps its focused member if any, otherwise its `active` member,
+/// otherwise its first member. The stop takes the position of the group's
+/// first member. `stops` must already be sorted.
+pub fn collapse_focus_groups(
+    stops: Vec<TabStop>,
+    focused: Option<Entity>,
+    active: impl Fn(Entity) -> Option<Entity>,
+) -> Vec<TabStop> {
+    let mut collapsed: Vec<TabStop> = Vec::with_capacity(stops.len());
+    for stop in &stops {
+        let Some(group) = stop.group else {
+            collapsed.push(*stop);
+            continue;
+        };
+        if collapsed.iter().any(|s| s.group == Some(group)) {
+            continue;
+        }
+
+        let members = || stops.iter().filter(move |s| s.group == Some(group));
+        let preferred = members()
+            .find(|s| Some(s.entity) == focused)
+            .or_else(|| active(group).and_then(|a| members().find(|s| s.entity == a)))
+            .unwrap_or(stop);
+        collapsed.push(TabStop {
+            entity: preferred.entity,
+            ..*stop
+        });
+    }
+    collapsed
+}
+
+/// The next stop after `current`, wrapping around. Without a current stop the
+/// first (or last, when moving backwards) stop is returned.
+pub fn next_tab_stop(stops: &[TabStop], current: Option<Entity>, backward: bool) -> Option<Entity> {
+    if stops.is_empty() {
+        return None;
+    }
+
+    let len = stops.len();
+    let position = current.and_then(|current| stops.iter().position(|s| s.entity == current));
+    let next = match (position, backward) {
+        (Some(i), false) => (i + 1) % len,
+        (Some(i), true) => (i + len - 1) % len,
+        (None, false) => 0,
+        (None, true) => len - 1,
+    };
+    Some(stops[next].entity)
+}
+
+/// The highlighted index `delta` items away from `current` in a list of
+/// `count` items, wrapping around. Without a current highlight the first (or
+/// last, when moving backwards) item is returned.
+pub fn wrap_highlight(current: Option<usize>, delta: i32, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let count = count as i32;
+    let next = match current {
+        Some(index) => (index as i32 + delta).rem_euclid(count),
+        None if delta >= 0 => 0,
+        None => count - 1,
+    };
+    Some(next as usize)
+}
+
+/// Assign hierarchy (depth-first) order to every UI node.
+///
+/// Root nodes are ordered spatially: top to bottom, then left to right.
+fn hierarchy_order(
+    roots: &Query<(Entity, &UiGlobalTransform), (With<Node>, Without<ChildOf>)>,
+    children: &Query<&Children>,
+) -> HashMap<Entity, usize> {
+    let mut sorted_roots: Vec<(Entity, Vec2)> = roots
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation))
+        .collect();
+    sorted_roots.sort_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+    let mut order = HashMap::new();
+    let mut stack: Vec<Entity> = sorted_roots.into_iter().rev().map(|(e, _)| e).collect();
+    while let Some(entity) = stack.pop() {
+        order.insert(entity, order.len());
+        if let Ok(kids) = children.get(entity) {
+            stack.extend(kids.iter().rev());
+        }
+    }
+    order
+}
+
+/// Nearest ancestor (excluding `entity` itself) carrying a [`FocusGroup`].
+fn enclosing_focus_group(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    groups: &Query<&mut FocusGroup>,
+) -> Option<Entity> {
+    parents
+        .iter_ancestors(entity)
+        .find(|ancestor| groups.contains(*ancestor))
+}
+
+/// Collect tabbable, visible focusables in sorted Tab order.
+fn collect_tab_stops(
+    roots: &Query<(Entity, &UiGlobalTransform), (With<Node>, Without<ChildOf>)>,
+    children: &Query<&Children>,
+    parents: &Query<&ChildOf>,
+    groups: &Query<&mut FocusGroup>,
+    focusables: &Query<(Entity, &mut Focusable, Option<&InheritedVisibility>)>,
+) -> Vec<TabStop> {
+    let order = hierarchy_order(roots, children);
+    let mut stops: Vec<TabStop> = focusables
+        .iter()
+        .filter(|(_, focusable, visibility)| {
+            focusable.is_tabbable() && visibility.is_none_or(|v| v.get())
+        })
+        .map(|(entity, focusable, _)| TabStop {
+            entity,
+            tab_index: focusable.tab_index,
+            order: order.get(&entity).copied().unwrap_or(usize::MAX),
+            group: enclosing_focus_group(entity, parents, groups),
+        })
+        .collect();
+    sort_tab_stops(&mut stops);
+    stops
+}
+
+fn set_focus(
+    focusables: &mut Query<(Entity, &mut Focusable, Option<&InheritedVisibility>)>,
+    target: Entity,
+) {
+    for (entity, mut focusable, _) in focusables.iter_mut() {
+        let focused = entity == target;
+        if focusable.focused != focused {
+            focusable.focused = focused;
+        }
+    }
+}
+
+/// Move focus with Tab / Shift+Tab across all tabbable `Focusable` entities.
+///
+/// Each [`FocusGroup`] counts as a single stop.
+pub fn focus_traversal_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    roots: Query<(Entity, &UiGlobalTransform), (With<Node>, Without<ChildOf>)>,
+    children: Query<&Children>,
+    parents: Query<&ChildOf>,
+    groups: Query<&mut FocusGroup>,
+    mut focusables: Query<(Entity, &mut Focusable, Option<&InheritedVisibility>)>,
+) {
+    let Some(keys) = keys else { return };
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    let backward = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let current = focusables
+        .iter()
+        .find_map(|(entity, focusable, _)| focusable.focused.then_some(entity));
+    let stops = collect_tab_stops(&roots, &children, &parents, &groups, &focusables);
+    let stops = collapse_focus_groups(stops, current, |group| {
+        groups.get(group).ok().and_then(|g| g.active)
+    });
+
+    if let Some(next) = next_tab_stop(&stops, current, backward) {
+        set_focus(&mut focusables, next);
+    }
+}
+
+/// Move focus between members of the focused element's [`FocusGroup`] with arrow keys.
+fn focus_group_navigation_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    roots: Query<(Entity, &UiGlobalTransform), (With<Node>, Without<ChildOf>)>,
+    children: Query<&Children>,
+    parents: Query<&ChildOf>,
+    groups: Query<&mut FocusGroup>,
+    mut focusables: Query<(Entity, &mut Focusable, Option<&InheritedVisibility>)>,
+) {
+    let Some(keys) = keys else { return };
+    let Some(key) = keys
+        .get_just_pressed()
+        .find(|key| KEYBOARD_NAVIGATION_KEYS[1..].contains(*key))
+        .copied()
+    else {
+        return;
+    };
+
+    let Some(current) = focusables
+        .iter()
+        .find_map(|(entity, focusable, _)| focusable.focused.then_some(entity))
+    else {
+        return;
+    };
+    let Some(group_entity) = enclosing_focus_group(current, &parents, &groups) else {
+        return;
+    };
+    let Some(step) = groups
+        .get(group_entity)
+        .ok()
+        .and_then(|g| g.arrow_step(key))
+    else {
+        return;
+    };
+
+    let members: Vec<Entity> = collect_tab_stops(&roots, &children, &parents, &groups, &focusables)
+        .into_iter()
+        .filter(|stop| stop.group == Some(group_entity))
+        .map(|stop| stop.entity)
+        .collect();
+    let Some(position) = members.iter().position(|&e| e == current) else {
+        return;
+    };
+
+    let len = members.len() as isize;
+    let next = members[(position as isize + step).rem_euclid(len) as usize];
+    set_focus(&mut focusables, next);
+}
+
+/// Remember the last focused member of each [`FocusGroup`].
+fn focus_group_active_system(
+    parents: Query<&ChildOf>,
+    mut groups: Query<&mut FocusGroup>,
+    focusables: Query<(Entity, &Focusable), Changed<Focusable>>,
+) {
+    for (entity, focusable) in focusables.iter() {
+        if !focusable.focused {
+            continue;
+        }
+        let Some(group_entity) = enclosing_focus_group(entity, &parents, &groups) else {
+            continue;
+        };
+        if let Ok(mut group) = groups.get_mut(group_entity) {
+            if group.active != Some(entity) {
+                group.active = Some(entity);
+            }
+        }
+    }
+}
+
+/// Make a component a [`FocusGroup`] and its items `Focusable` members.
+///
+/// Used by component plugins (radio groups, tabs, chip groups, lists, menus) to adopt
+/// roving focus.
+pub(crate) fn adopt_focus_group_system<G: Component, I: Component>(
+    mut commands: Commands,
+    groups: Query<Entity, (Added<G>, Without<FocusGroup>)>,
+    items: Query<Entity, (Added<I>, Without<Focusable>)>,
+) {
+    for entity in groups.iter() {
+        commands.entity(entity).insert(FocusGroup::new());
+    }
+    for entity in items.iter() {
+        let focusable = Focusable::new();
+        let outline = focusable.to_outline(Color::NONE);
+        commands.entity(entity).insert((focusable, outline));
+    }
+}
+
+/// Move focus to a focusable element when it is pressed.
+fn pointer_focus_system(
+    interactions: Query<(Entity, &Interaction), (Changed<Interaction>, With<Focusable>)>,
+    mut focusables: Query<(Entity, &mut Focusable)>,
+) {
+    let Some(pressed) = interactions
+        .iter()
+        .find_map(|(entity, interaction)| (*interaction == Interaction::Pressed).then_some(entity))
+    else {
+        return;
+    };
+
+    for (entity, mut focusable) in focusables.iter_mut() {
+        let focused = entity == pressed;
+        if focusable.focused != focused {
+            focusable.focused = focused;
+        }
+    }
+}
+
+/// Fire focus events and derive `focus_visible` from the input modality.
+///
+/// Events fire for every focus change; only the ring is suppressed for
+/// pointer-driven focus.
+fn focus_state_system(
+    modality: Res<FocusModality>,
+    mut focused: Local<HashSet<Entity>>,
+    mut removed: RemovedComponents<Focusable>,
+    mut focusables: Query<(Entity, &mut Focusable)>,
+    mut gained: MessageWriter<FocusGained>,
+    mut lost: MessageWriter<FocusLost>,
+) {
+    for entity in removed.read() {
+        focused.remove(&entity);
+    }
+
+    let from_keyboard = modality.last == InputModality::Keyboard;
+    let ring_visible = modality.focus_visible();
+
+    for (entity, mut focusable) in focusables.iter_mut() {
+        let was_focused = focused.contains(&entity);
+        if focusable.focused && !was_focused {
+            focused.insert(entity);
+            gained.write(FocusGained {
+                entity,
+                from_keyboard,
+            });
+        } else if !focusable.focused && was_focused {
+            focused.remove(&entity);
+            lost.write(FocusLost { entity });
+        }
+
+        let visible = focusable.focused && ring_visible;
+        if focusable.focus_visible != visible {
+            focusable.focus_visible = visible;
+        }
+    }
+}
+
 /// System to update focus using Bevy's native Outline component
 ///
 /// This is the recommended approach for Bevy 0.17+ as it leverages
@@ -161,10 +682,62 @@ fn update_focus_ring_system(
     }
 }
 
+/// Corner radii of a ring drawn `offset` outside a node, `width` thick.
+///
+/// Rounded corners grow by the ring's distance from the node so the ring stays
+/// concentric; square corners stay square. Matches Bevy's native outlines.
+pub fn focus_ring_radius(target: ResolvedBorderRadius, offset: f32, width: f32) -> BorderRadius {
+    let outset = |radius: f32| {
+        if radius > 0.0 {
+            Val::Px(radius + offset + width)
+        } else {
+            Val::Px(0.0)
+        }
+    };
+    BorderRadius {
+        top_left: outset(target.top_left),
+        top_right: outset(target.top_right),
+        bottom_left: outset(target.bottom_left),
+        bottom_right: outset(target.bottom_right),
+    }
+}
+
+/// Match legacy focus ring corners to their target's resolved corner radius.
+fn focus_ring_shape_system(
+    targets: Query<(&Focusable, &ComputedNode, &Children), Changed<ComputedNode>>,
+    mut focus_rings: Query<&mut BorderRadius, With<FocusRing>>,
+) {
+    for (focusable, computed, children) in targets.iter() {
+        if focusable.use_native_outline {
+            continue;
+        }
+
+        // `ComputedNode` radii are physical pixels; `BorderRadius` is logical.
+        let scale = computed.inverse_scale_factor();
+        let resolved = computed.border_radius();
+        let logical = ResolvedBorderRadius {
+            top_left: resolved.top_left * scale,
+            top_right: resolved.top_right * scale,
+            bottom_left: resolved.bottom_left * scale,
+            bottom_right: resolved.bottom_right * scale,
+        };
+        let radius = focus_ring_radius(logical, focusable.ring_offset, focusable.ring_width);
+
+        for child in children.iter() {
+            if let Ok(mut ring) = focus_rings.get_mut(child) {
+                ring.set_if_neq(radius);
+            }
+        }
+    }
+}
+
 /// Create a focus ring node bundle (legacy approach)
 ///
 /// **Note**: Consider using `Focusable::to_outline()` with Bevy's native `Outline`
 /// component for better performance. This function is retained for backwards compatibility.
+///
+/// The ring starts with small rounded corners and takes the target's shape once
+/// the target has been laid out.
 pub fn create_focus_ring(target: Entity, color: Color, offset: f32, width: f32) -> impl Bundle {
     (
         FocusRing { target },
@@ -187,7 +760,176 @@ pub fn create_focus_ring(target: Entity, color: Color, offset: f32, width: f32)
 /// Create a native outline bundle for focus rings (recommended for Bevy 0.17+)
 ///
 /// This uses Bevy's built-in `Outline` component which is more performant
-/// than the legacy child entity approach.
+/// than the legacy child entity approach. The outline follows the node's
+/// `BorderRadius`; [`FocusIndicator`] holds the MD3 offset and thickness.
 pub fn create_native_focus_outline(color: Color, offset: f32, width: f32) -> Outline {
     Outline::new(Val::Px(width), Val::Px(offset), color)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_highlight() {
+        assert_eq!(wrap_highlight(None, 1, 3), Some(0));
+        assert_eq!(wrap_highlight(None, -1, 3), Some(2));
+        assert_eq!(wrap_highlight(Some(2), 1, 3), Some(0));
+        assert_eq!(wrap_highlight(Some(0), -1, 3), Some(2));
+        assert_eq!(wrap_highlight(Some(1), 1, 0), None);
+    }
+
+    #[test]
+    fn test_focus_ring_radius_follows_target_shape() {
+        let offset = FocusIndicator::OFFSET;
+        let width = FocusIndicator::THICKNESS;
+
+        // A 40px circular icon button gets a circular ring around it.
+        let circle = focus_ring_radius(
+            ResolvedBorderRadius {
+                top_left: 20.0,
+                top_right: 20.0,
+                bottom_left: 20.0,
+                bottom_right: 20.0,
+            },
+            offset,
+            width,
+        );
+        assert_eq!(circle, BorderRadius::all(Val::Px(25.0)));
+
+        // Square corners stay square; rounded ones grow by the ring's distance.
+        let mixed = focus_ring_radius(
+            ResolvedBorderRadius {
+                top_left: 12.0,
+                top_right: 12.0,
+                bottom_left: 0.0,
+                bottom_right: 0.0,
+            },
+            offset,
+            width,
+        );
+        assert_eq!(mixed.top_left, Val::Px(17.0));
+        assert_eq!(mixed.bottom_right, Val::Px(0.0));
+    }
+
+    #[test]
+    fn test_focus_visible_follows_modality() {
+        let mut modality = FocusModality::default();
+        assert!(!modality.focus_visible());
+
+        modality.last = InputModality::Keyboard;
+        assert!(modality.focus_visible());
+
+        modality.last = InputModality::Pointer;
+        modality.set_force_visible(true);
+        assert!(modality.focus_visible());
+    }
+
+    #[test]
+    fn test_pointer_focus_hides_ring_but_fires_events() {
+        let mut app = App::new();
+        app.add_plugins(FocusPlugin);
+
+        let entity = app.world_mut().spawn(Focusable::new()).id();
+        app.world_mut()
+            .get_mut::<Focusable>(entity)
+            .unwrap()
+            .focused = true;
+        app.update();
+
+        assert!(!app.world().get::<Focusable>(entity).unwrap().focus_visible);
+        let gained = app.world().resource::<Messages<FocusGained>>();
+        assert_eq!(gained.len(), 1);
+
+        app.world_mut()
+            .resource_mut::<FocusModality>()
+            .set_force_visible(true);
+        app.update();
+        assert!(app.world().get::<Focusable>(entity).unwrap().focus_visible);
+    }
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    fn stop(index: u32, tab_index: Option<i32>, order: usize) -> TabStop {
+        TabStop {
+            entity: entity(index),
+            tab_index,
+            order,
+            group: None,
+        }
+    }
+
+    #[test]
+    fn test_tab_order_and_wrap() {
+        let mut stops = vec![
+            stop(1, None, 0),
+            stop(2, Some(2), 5),
+            stop(3, Some(1), 9),
+            stop(4, Some(0), 3),
+        ];
+        sort_tab_stops(&mut stops);
+        assert_eq!(stops[0].tab_index, Some(1));
+        assert_eq!(stops[1].tab_index, Some(2));
+        assert_eq!(stops[2].order, 0);
+        assert_eq!(stops[3].order, 3);
+
+        let last = stops[3].entity;
+        let first = stops[0].entity;
+        assert_eq!(next_tab_stop(&stops, Some(last), false), Some(first));
+        assert_eq!(next_tab_stop(&stops, Some(first), true), Some(last));
+        assert_eq!(next_tab_stop(&stops, None, false), Some(first));
+    }
+
+    #[test]
+    fn test_disabled_and_negative_index_not_tabbable() {
+        assert!(Focusable::new().is_tabbable());
+        assert!(!Focusable::new().disabled(true).is_tabbable());
+        assert!(!Focusable::new().with_tab_index(-1).is_tabbable());
+    }
+
+    #[test]
+    fn test_focus_group_is_single_stop() {
+        let group = entity(100);
+        let in_group = |index, order| TabStop {
+            group: Some(group),
+            ..stop(index, None, order)
+        };
+        let stops = vec![
+            stop(1, None, 0),
+            in_group(2, 1),
+            in_group(3, 2),
+            stop(4, None, 3),
+        ];
+
+        let collapsed = collapse_focus_groups(stops.clone(), None, |_| None);
+        let order: Vec<Entity> = collapsed.iter().map(|s| s.entity).collect();
+        assert_eq!(order, vec![entity(1), entity(2), entity(4)]);
+
+        // The active member represents the group
+        let collapsed = collapse_focus_groups(stops.clone(), None, |_| Some(entity(3)));
+        assert_eq!(collapsed[1].entity, entity(3));
+        assert_eq!(
+            next_tab_stop(&collapsed, Some(entity(1)), false),
+            Some(entity(3))
+        );
+        assert_eq!(
+            next_tab_stop(&collapsed, Some(entity(3)), false),
+            Some(entity(4))
+        );
+
+        // A focused member keeps its place
+        let collapsed = collapse_focus_groups(stops, Some(entity(3)), |_| None);
+        assert_eq!(collapsed[1].entity, entity(3));
+    }
+
+    #[test]
+    fn test_focus_group_arrow_step() {
+        let group = FocusGroup::horizontal();
+        assert_eq!(group.arrow_step(KeyCode::ArrowRight), Some(1));
+        assert_eq!(group.arrow_step(KeyCode::ArrowLeft), Some(-1));
+        assert_eq!(group.arrow_step(KeyCode::ArrowDown), None);
+        assert_eq!(FocusGroup::new().arrow_step(KeyCode::ArrowUp), Some(-1));
+    }
+}
@@ -6,10 +6,13 @@
 use bevy::prelude::*;
 
 use crate::{
+    disabled::{sync_disabled_system, DisabledSource},
     icons::MaterialIcon,
+    motion::AnimatedIcon,
     ripple::RippleHost,
+    semantics::{checked_value, sync_semantics_system, Role, Semantic},
     theme::{blend_state_layer, MaterialTheme},
-    tokens::CornerRadius,
+    tokens::{CornerRadius, Opacity},
 };
 
 /// Plugin for the icon button component
@@ -17,15 +20,19 @@ pub struct IconButtonPlugin;
 
 impl Plugin for IconButtonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<IconButtonClickEvent>().add_systems(
-            Update,
-            (
-                icon_button_interaction_system,
-                icon_button_style_system,
-                icon_button_content_style_system,
-                icon_button_theme_refresh_system,
-            ),
-        );
+        app.add_message::<IconButtonClickEvent>()
+            .add_message::<IconButtonToggleEvent>()
+            .add_systems(
+                Update,
+                (
+                    icon_button_interaction_system,
+                    icon_button_style_system,
+                    icon_button_content_style_system,
+                    icon_button_theme_refresh_system,
+                    sync_disabled_system::<MaterialIconButton>,
+                    sync_semantics_system::<MaterialIconButton>,
+                ),
+            );
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
@@ -59,6 +66,8 @@ pub struct MaterialIconButton {
     pub toggle: bool,
     /// Icon identifier
     pub icon: String,
+    /// Icon shown while selected (toggle buttons); `icon` is used when unset
+    pub selected_icon: Option<String>,
     /// Whether this button is pressed
     pub pressed: bool,
     /// Whether this button is hovered
@@ -74,6 +83,7 @@ impl MaterialIconButton {
             selected: false,
             toggle: false,
             icon: icon.into(),
+            selected_icon: None,
             pressed: false,
             hovered: false,
         }
@@ -103,13 +113,28 @@ impl MaterialIconButton {
         self
     }
 
+    /// Enable toggle behavior, swapping to `selected_icon` while selected
+    pub fn with_selected_icon(mut self, selected_icon: impl Into<String>) -> Self {
+        self.toggle = true;
+        self.selected_icon = Some(selected_icon.into());
+        self
+    }
+
+    /// The icon for the current selected state
+    pub fn current_icon(&self) -> &str {
+        match &self.selected_icon {
+            Some(selected_icon) if self.toggle && self.selected => selected_icon,
+            _ => &self.icon,
+        }
+    }
+
     /// Get the background color with state layer applied
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
             return match self.variant {
                 IconButtonVariant::Standard => Color::NONE,
                 IconButtonVariant::Filled | IconButtonVariant::FilledTonal => {
-                    theme.on_surface.with_alpha(0.12)
+                    theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER)
                 }
                 IconButtonVariant::Outlined => Color::NONE,
             };
@@ -170,7 +195,7 @@ impl MaterialIconButton {
     /// Get the icon color
     pub fn icon_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         match self.variant {
@@ -212,7 +237,7 @@ impl MaterialIconButton {
         }
 
         if self.disabled {
-            theme.on_surface.with_alpha(0.12)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER)
         } else if self.selected {
             Color::NONE
         } else {
@@ -221,6 +246,27 @@ impl MaterialIconButton {
     }
 }
 
+impl DisabledSource for MaterialIconButton {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Semantic for MaterialIconButton {
+    fn semantic_role(&self) -> Role {
+        Role::Button
+    }
+
+    /// Icon buttons have no text, so the icon name is the default label
+    fn semantic_label(&self) -> String {
+        self.icon.clone()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        self.toggle.then(|| checked_value(self.selected))
+    }
+}
+
 /// Event fired when an icon button is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct IconButtonClickEvent {
@@ -230,6 +276,15 @@ pub struct IconButtonClickEvent {
     pub selected: bool,
 }
 
+/// Event fired when a toggle icon button changes its selected state
+#[derive(Event, bevy::prelude::Message)]
+pub struct IconButtonToggleEvent {
+    /// The button entity
+    pub entity: Entity,
+    /// The new selected state
+    pub selected: bool,
+}
+
 /// System to handle icon button interactions
 fn icon_button_interaction_system(
     mut interaction_query: Query<
@@ -237,6 +292,7 @@ fn icon_button_interaction_system(
         (Changed<Interaction>, With<MaterialIconButton>),
     >,
     mut click_events: MessageWriter<IconButtonClickEvent>,
+    mut toggle_events: MessageWriter<IconButtonToggleEvent>,
 ) {
     for (entity, interaction, mut button) in interaction_query.iter_mut() {
         if button.disabled {
@@ -250,6 +306,10 @@ fn icon_button_interaction_system(
 
                 if button.toggle {
                     button.selected = !button.selected;
+                    toggle_events.write(IconButtonToggleEvent {
+                        entity,
+                        selected: button.selected,
+                    });
                 }
 
                 click_events.write(IconButtonClickEvent {
@@ -291,6 +351,7 @@ fn icon_button_content_style_system(
     buttons: Query<(Entity, &MaterialIconButton), Changed<MaterialIconButton>>,
     children_q: Query<&Children>,
     mut icons: Query<&mut MaterialIcon>,
+    mut animated_icons: Query<&mut AnimatedIcon>,
 ) {
     let Some(theme) = theme else { return };
 
@@ -299,9 +360,20 @@ fn icon_button_content_style_system(
             continue;
         };
         let icon_color = button.icon_color(&theme);
+        let icon_id = MaterialIcon::from_name(button.current_icon()).map(|icon| icon.id);
         for child in children.iter() {
             if let Ok(mut icon) = icons.get_mut(child) {
                 icon.color = icon_color;
+                if let Some(id) = icon_id {
+                    icon.id = id;
+                }
+            }
+            // Toggle buttons drive animated icons from their selected state.
+            if let Ok(mut animated) = animated_icons.get_mut(child) {
+                animated.set_color(icon_color);
+                if button.toggle {
+                    animated.set_forward(button.selected);
+                }
             }
         }
     }
@@ -318,6 +390,7 @@ fn icon_button_theme_refresh_system(
     )>,
     children_q: Query<&Children>,
     mut icons: Query<&mut MaterialIcon>,
+    mut animated_icons: Query<&mut AnimatedIcon>,
 ) {
     let Some(theme) = theme else { return };
     if !theme.is_changed() {
@@ -336,6 +409,9 @@ fn icon_button_theme_refresh_system(
             if let Ok(mut icon) = icons.get_mut(child) {
                 icon.color = icon_color;
             }
+            if let Ok(mut animated) = animated_icons.get_mut(child) {
+                animated.set_color(icon_color);
+            }
         }
     }
 }
@@ -390,12 +466,21 @@ impl IconButtonBuilder {
         self
     }
 
-    /// Enable toggle mode
-    pub fn toggle(mut self) -> Self {
+    /// Enable toggle mode, keeping the same icon in both states
+    pub fn toggleable(mut self) -> Self {
         self.button.toggle = true;
         self
     }
 
+    /// Toggle between the builder icon and `selected_icon` (e.g. outlined and filled favorite)
+    pub fn toggle(mut self, selected_icon: impl Into<String>, initial: bool) -> Self {
+        self.button = self
+            .button
+            .with_selected_icon(selected_icon)
+            .selected(initial);
+        self
+    }
+
     /// Set selected state
     pub fn selected(mut self, selected: bool) -> Self {
         self.button.selected = selected;
@@ -411,11 +496,13 @@ impl IconButtonBuilder {
         } else {
             0.0
         };
+        let semantics = self.button.semantics();
 
         (
             self.button,
+            semantics,
             Button,
-            RippleHost::new(),
+            RippleHost::new().unbounded(true),
             Node {
                 width: Val::Px(ICON_BUTTON_SIZE),
                 height: Val::Px(ICON_BUTTON_SIZE),
@@ -500,7 +587,7 @@ impl SpawnIconButtonChild for ChildSpawnerCommands<'_> {
 
     fn spawn_icon_button_with(&mut self, theme: &MaterialTheme, button: MaterialIconButton) {
         let icon_color = button.icon_color(theme);
-        let icon_name = button.icon.clone();
+        let icon_name = button.current_icon().to_string();
         let builder = IconButtonBuilder { button };
 
         self.spawn(builder.build(theme)).with_children(|btn| {
@@ -510,3 +597,74 @@ impl SpawnIconButtonChild for ChildSpawnerCommands<'_> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_swaps_to_selected_icon() {
+        let mut app = App::new();
+        app.init_resource::<MaterialTheme>()
+            .add_message::<IconButtonClickEvent>()
+            .add_message::<IconButtonToggleEvent>()
+            .add_systems(
+                Update,
+                (
+                    icon_button_interaction_system,
+                    icon_button_content_style_system,
+                )
+                    .chain(),
+            );
+        let theme = MaterialTheme::default();
+        let button = IconButtonBuilder::new("favorite_border")
+            .toggle("favorite", false)
+            .button;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| parent.spawn_icon_button_with(&theme, button));
+        app.world_mut().flush();
+
+        let (entity, children) = app
+            .world_mut()
+            .query::<(Entity, &Children, &MaterialIconButton)>()
+            .single(app.world())
+            .map(|(entity, children, _)| (entity, children.to_vec()))
+            .unwrap();
+        let icon_id = |app: &App| {
+            children
+                .iter()
+                .find_map(|child| app.world().get::<MaterialIcon>(*child))
+                .unwrap()
+                .id
+        };
+        let outlined = MaterialIcon::from_name("favorite_border").unwrap().id;
+        let filled = MaterialIcon::from_name("favorite").unwrap().id;
+        assert_eq!(icon_id(&app), outlined);
+
+        let press = |app: &mut App| {
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Pressed;
+            app.update();
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::None;
+            app.update();
+        };
+        press(&mut app);
+        assert!(
+            app.world()
+                .get::<MaterialIconButton>(entity)
+                .unwrap()
+                .selected
+        );
+        assert_eq!(icon_id(&app), filled);
+
+        press(&mut app);
+        assert!(
+            !app.world()
+                .get::<MaterialIconButton>(entity)
+                .unwrap()
+                .selected
+        );
+        assert_eq!(icon_id(&app), outlined);
+    }
+}
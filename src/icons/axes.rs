@@ -0,0 +1,307 @@
+//! Icon style axes
+//!
+//! Material Symbols expose Fill, Weight, Grade and Optical Size as variable
+//! font axes. The embedded icons are 48px alpha bitmaps of the baseline
+//! Material Icons set instead, so the axes are applied to those bitmaps:
+//! - Fill picks the filled sibling of `*_outline` / `*_border` icons.
+//! - Weight, Grade and Optical Size grow or shrink the glyph's strokes.
+
+use super::material_icons::{self, IconId};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Icon weight (stroke thickness)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconWeight {
+    /// Weight 100 - Thinnest
+    Thin,
+    /// Weight 200
+    ExtraLight,
+    /// Weight 300
+    Light,
+    /// Weight 400 - Default, the embedded glyph as-is
+    #[default]
+    Regular,
+    /// Weight 500
+    Medium,
+    /// Weight 600
+    SemiBold,
+    /// Weight 700 - Thickest
+    Bold,
+}
+
+impl IconWeight {
+    /// Get the numeric weight value (100-700)
+    pub fn value(&self) -> u16 {
+        match self {
+            IconWeight::Thin => 100,
+            IconWeight::ExtraLight => 200,
+            IconWeight::Light => 300,
+            IconWeight::Regular => 400,
+            IconWeight::Medium => 500,
+            IconWeight::SemiBold => 600,
+            IconWeight::Bold => 700,
+        }
+    }
+}
+
+/// Icon grade (fine adjustment of weight)
+///
+/// Negative grades are lighter, positive grades are heavier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconGrade {
+    /// Grade -25 - Reduced emphasis
+    Low,
+    /// Grade 0 - Default emphasis
+    #[default]
+    Normal,
+    /// Grade 200 - High emphasis
+    High,
+}
+
+impl IconGrade {
+    /// Get the numeric grade value (-25 to 200)
+    pub fn value(&self) -> i16 {
+        match self {
+            IconGrade::Low => -25,
+            IconGrade::Normal => 0,
+            IconGrade::High => 200,
+        }
+    }
+}
+
+/// Optical size the glyph is tuned for
+///
+/// Larger optical sizes use thinner strokes, smaller ones heavier strokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconOpticalSize {
+    /// 20dp
+    Small,
+    /// 24dp - Default
+    #[default]
+    Default,
+    /// 40dp
+    Large,
+    /// 48dp
+    ExtraLarge,
+}
+
+impl IconOpticalSize {
+    /// Get the numeric optical size value (20, 24, 40, 48)
+    pub fn value(&self) -> u8 {
+        match self {
+            IconOpticalSize::Small => 20,
+            IconOpticalSize::Default => 24,
+            IconOpticalSize::Large => 40,
+            IconOpticalSize::ExtraLarge => 48,
+        }
+    }
+}
+
+/// Fill, weight, grade and optical size of a rendered icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IconAxes {
+    /// Use the filled glyph when the icon has one
+    pub fill: bool,
+    /// Stroke weight
+    pub weight: IconWeight,
+    /// Emphasis grade
+    pub grade: IconGrade,
+    /// Optical size
+    pub optical_size: IconOpticalSize,
+}
+
+impl IconAxes {
+    /// How far strokes grow (positive) or shrink (negative), in source pixels.
+    ///
+    /// Weight moves strokes by up to 2px at 100/700, grade by 0.5px per 100,
+    /// and optical size thins strokes by up to 1px at 48dp.
+    pub fn stroke_offset(&self) -> f32 {
+        let weight = (self.weight.value() as f32 - 400.0) / 150.0;
+        let grade = self.grade.value() as f32 / 200.0;
+        let optical = (24.0 - self.optical_size.value() as f32) / 24.0;
+        weight + grade + optical
+    }
+
+    /// The glyph to render for `id` under these axes
+    pub fn glyph(&self, id: IconId) -> IconId {
+        if self.fill {
+            filled_variant(id)
+        } else {
+            id
+        }
+    }
+
+    /// Alpha bytes of `id` with these axes applied
+    pub(crate) fn render_alpha(&self, id: IconId) -> Vec<u8> {
+        let glyph = self.glyph(id);
+        apply_stroke_offset(
+            glyph.alpha(),
+            glyph.width as usize,
+            glyph.height as usize,
+            self.stroke_offset(),
+        )
+    }
+}
+
+/// The filled sibling of an `*_outline` / `*_border` icon, or `id` itself
+pub fn filled_variant(id: IconId) -> IconId {
+    let Some(name) = icon_name(id) else {
+        return id;
+    };
+
+    ["_outline", "_border"]
+        .iter()
+        .filter_map(|suffix| name.strip_suffix(suffix))
+        .find_map(material_icons::by_name)
+        .unwrap_or(id)
+}
+
+/// Folder name of an embedded icon
+fn icon_name(id: IconId) -> Option<&'static str> {
+    static NAMES: OnceLock<HashMap<IconId, &'static str>> = OnceLock::new();
+    NAMES
+        .get_or_init(|| {
+            let mut names = HashMap::with_capacity(material_icons::ALL.len());
+            for (path, icon) in material_icons::ALL {
+                let name = path.rsplit('/').next().unwrap_or(path);
+                names.entry(*icon).or_insert(name);
+            }
+            names
+        })
+        .get(&id)
+        .copied()
+}
+
+/// Grow (positive `offset`) or shrink (negative) an alpha mask's strokes.
+///
+/// Each whole pixel of offset is one 3x3 dilation or erosion pass; the
+/// fractional remainder blends a final pass with the previous result.
+fn apply_stroke_offset(alpha: &[u8], width: usize, height: usize, offset: f32) -> Vec<u8> {
+    let mut mask = alpha.to_vec();
+    let grow = offset > 0.0;
+    let mut remaining = offset.abs();
+
+    while remaining > f32::EPSILON {
+        let amount = remaining.min(1.0);
+        let extreme = morphology_pass(&mask, width, height, grow);
+        for (value, target) in mask.iter_mut().zip(extreme) {
+            let blended = *value as f32 + (target as f32 - *value as f32) * amount;
+            *value = blended.round() as u8;
+        }
+        remaining -= amount;
+    }
+
+    mask
+}
+
+/// One 3x3 max (dilate) or min (erode) pass; pixels outside the mask are empty
+fn morphology_pass(mask: &[u8], width: usize, height: usize, dilate: bool) -> Vec<u8> {
+    let mut out = vec![0; mask.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let mut extreme = mask[y * width + x];
+            for dy in -1i32..=1 {
+                for dx in -1i32..=1 {
+                    let nx = x as i32 + dx;
+                    let ny = y as i32 + dy;
+                    let value = if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                        0
+                    } else {
+                        mask[ny as usize * width + nx as usize]
+                    };
+                    extreme = if dilate {
+                        extreme.max(value)
+                    } else {
+                        extreme.min(value)
+                    };
+                }
+            }
+            out[y * width + x] = extreme;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coverage(alpha: &[u8]) -> u32 {
+        alpha.iter().map(|&a| a as u32).sum()
+    }
+
+    #[test]
+    fn test_icon_weight_values() {
+        assert_eq!(IconWeight::Thin.value(), 100);
+        assert_eq!(IconWeight::Regular.value(), 400);
+        assert_eq!(IconWeight::Bold.value(), 700);
+    }
+
+    #[test]
+    fn test_icon_grade_values() {
+        assert_eq!(IconGrade::Low.value(), -25);
+        assert_eq!(IconGrade::Normal.value(), 0);
+        assert_eq!(IconGrade::High.value(), 200);
+    }
+
+    #[test]
+    fn test_icon_optical_size_values() {
+        assert_eq!(IconOpticalSize::Small.value(), 20);
+        assert_eq!(IconOpticalSize::Default.value(), 24);
+        assert_eq!(IconOpticalSize::Large.value(), 40);
+        assert_eq!(IconOpticalSize::ExtraLarge.value(), 48);
+    }
+
+    #[test]
+    fn test_default_axes_render_the_embedded_glyph() {
+        let id = material_icons::by_name("home").unwrap();
+        assert_eq!(IconAxes::default().stroke_offset(), 0.0);
+        assert_eq!(IconAxes::default().render_alpha(id), id.alpha());
+    }
+
+    #[test]
+    fn test_fill_picks_the_filled_sibling() {
+        let border = material_icons::by_name("favorite_border").unwrap();
+        let filled = material_icons::by_name("favorite").unwrap();
+        let axes = IconAxes {
+            fill: true,
+            ..Default::default()
+        };
+
+        assert_eq!(axes.glyph(border), filled);
+        assert_eq!(IconAxes::default().glyph(border), border);
+        // Icons without an outlined sibling are left alone.
+        let home = material_icons::by_name("home").unwrap();
+        assert_eq!(axes.glyph(home), home);
+    }
+
+    #[test]
+    fn test_weight_grade_and_optical_size_change_stroke_coverage() {
+        let id = material_icons::by_name("settings").unwrap();
+        let regular = coverage(&IconAxes::default().render_alpha(id));
+        let render = |axes: IconAxes| coverage(&axes.render_alpha(id));
+
+        let bold = IconAxes {
+            weight: IconWeight::Bold,
+            ..Default::default()
+        };
+        let thin = IconAxes {
+            weight: IconWeight::Thin,
+            ..Default::default()
+        };
+        let high_grade = IconAxes {
+            grade: IconGrade::High,
+            ..Default::default()
+        };
+        let large_optical = IconAxes {
+            optical_size: IconOpticalSize::ExtraLarge,
+            ..Default::default()
+        };
+
+        assert!(render(bold) > regular);
+        assert!(render(thin) < regular);
+        assert!(render(high_grade) > regular);
+        assert!(render(large_optical) < regular);
+    }
+}
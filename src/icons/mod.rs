@@ -10,8 +10,12 @@ use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use bevy::ui::widget::ImageNode;
 use std::collections::HashMap;
 
-fn icon_pixels_rgba8(id: material_icons::IconId) -> Vec<u8> {
-    let alpha = id.alpha();
+mod axes;
+
+pub use axes::{filled_variant, IconAxes, IconGrade, IconOpticalSize, IconWeight};
+
+fn icon_pixels_rgba8(id: material_icons::IconId, axes: IconAxes) -> Vec<u8> {
+    let alpha = axes.render_alpha(id);
     let mut rgba = Vec::with_capacity(alpha.len() * 4);
     for a in alpha.iter().copied() {
         rgba.extend_from_slice(&[255, 255, 255, a]);
@@ -80,10 +84,21 @@ pub mod material_icon_names {
 /// color updates separately from the icon itself. In the embedded icon system,
 /// the source-of-truth is `MaterialIcon { size, color }`, but we keep this
 /// lightweight shim to avoid rewriting every widget at once.
+///
+/// The fill/weight/grade/optical-size fields are applied to the embedded
+/// bitmap as described in [`IconAxes`]; changing the style re-renders the icon.
 #[derive(Component, Clone, Copy, Debug)]
 pub struct IconStyle {
     pub size: f32,
     pub color: Color,
+    /// Use the filled glyph when the icon has one
+    pub filled: bool,
+    /// Stroke weight
+    pub weight: IconWeight,
+    /// Emphasis grade
+    pub grade: IconGrade,
+    /// Optical size
+    pub optical_size: IconOpticalSize,
 }
 
 impl Default for IconStyle {
@@ -91,17 +106,53 @@ impl Default for IconStyle {
         Self {
             size: 24.0,
             color: Color::WHITE,
+            filled: false,
+            weight: IconWeight::default(),
+            grade: IconGrade::default(),
+            optical_size: IconOpticalSize::default(),
         }
     }
 }
 
 impl IconStyle {
+    /// Outlined style: the icon is rendered as named.
     pub fn outlined() -> Self {
         Self::default()
     }
 
+    /// Filled style: `*_outline` / `*_border` icons render their filled sibling.
     pub fn filled() -> Self {
-        Self::default()
+        Self::default().with_fill(true)
+    }
+
+    pub fn with_fill(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    pub fn with_weight(mut self, weight: IconWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_grade(mut self, grade: IconGrade) -> Self {
+        self.grade = grade;
+        self
+    }
+
+    pub fn with_optical_size(mut self, optical_size: IconOpticalSize) -> Self {
+        self.optical_size = optical_size;
+        self
+    }
+
+    /// The axes this style renders with
+    pub fn axes(&self) -> IconAxes {
+        IconAxes {
+            fill: self.filled,
+            weight: self.weight,
+            grade: self.grade,
+            optical_size: self.optical_size,
+        }
     }
 
     pub fn with_size(mut self, size: f32) -> Self {
@@ -193,7 +244,7 @@ pub mod svg {
 }
 
 #[derive(Resource, Default)]
-struct MaterialIconImageCache(HashMap<material_icons::IconId, Handle<Image>>);
+struct MaterialIconImageCache(HashMap<(material_icons::IconId, IconAxes), Handle<Image>>);
 
 /// Icon component for rendering an embedded icon via `ImageNode`.
 #[derive(Component, Clone, Copy, Debug)]
@@ -201,6 +252,8 @@ pub struct MaterialIcon {
     pub id: material_icons::IconId,
     pub size: f32,
     pub color: Color,
+    /// Fill/weight/grade/optical-size applied to the glyph
+    pub axes: IconAxes,
 }
 
 impl MaterialIcon {
@@ -209,6 +262,7 @@ impl MaterialIcon {
             id,
             size: 20.0,
             color: Color::WHITE,
+            axes: IconAxes::default(),
         }
     }
 
@@ -216,6 +270,18 @@ impl MaterialIcon {
         material_icons::by_name(name).map(Self::new)
     }
 
+    /// Look up an icon by name, logging a warning when the name is unknown.
+    ///
+    /// Prefer this over [`MaterialIcon::from_name`] for user-facing names so
+    /// typos show up in the log instead of as a silently missing icon.
+    pub fn named(name: &str) -> Option<Self> {
+        let icon = Self::from_name(name);
+        if icon.is_none() {
+            warn!("Unknown Material icon name: {name:?}");
+        }
+        icon
+    }
+
     pub fn with_size(mut self, size: f32) -> Self {
         self.size = size;
         self
@@ -225,6 +291,27 @@ impl MaterialIcon {
         self.color = color;
         self
     }
+
+    pub fn with_axes(mut self, axes: IconAxes) -> Self {
+        self.axes = axes;
+        self
+    }
+}
+
+/// Spawn an icon child by name with the given style.
+///
+/// Icons are embedded images, so no font or codepoint is involved. Unknown
+/// names log a warning and spawn nothing.
+pub fn spawn_icon_by_name(
+    parent: &mut ChildSpawnerCommands,
+    name: &str,
+    style: IconStyle,
+) -> Option<Entity> {
+    let icon = MaterialIcon::named(name)?
+        .with_size(style.size)
+        .with_color(style.color)
+        .with_axes(style.axes());
+    Some(parent.spawn((icon, style)).id())
 }
 
 /// Plugin that enables `MaterialIcon` rendering.
@@ -251,9 +338,39 @@ fn icon_style_sync_system(
     for (mut icon, style) in icons.iter_mut() {
         icon.size = style.size;
         icon.color = style.color;
+        icon.axes = style.axes();
     }
 }
 
+/// Get (and cache) the image for an icon's glyph and axes
+fn icon_image(
+    images: &mut Assets<Image>,
+    cache: &mut MaterialIconImageCache,
+    icon: &MaterialIcon,
+) -> Handle<Image> {
+    let key = (icon.id, icon.axes);
+    if let Some(handle) = cache.0.get(&key) {
+        return handle.clone();
+    }
+
+    let glyph = icon.axes.glyph(icon.id);
+    let extent = Extent3d {
+        width: glyph.width as u32,
+        height: glyph.height as u32,
+        depth_or_array_layers: 1,
+    };
+    let image = Image::new(
+        extent,
+        TextureDimension::D2,
+        icon_pixels_rgba8(icon.id, icon.axes),
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    let handle = images.add(image);
+    cache.0.insert(key, handle.clone());
+    handle
+}
+
 fn material_icon_system(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
@@ -269,25 +386,7 @@ fn material_icon_system(
     >,
 ) {
     for (entity, icon, image_node, node) in icons.iter_mut() {
-        let handle = if let Some(handle) = cache.0.get(&icon.id) {
-            handle.clone()
-        } else {
-            let extent = Extent3d {
-                width: icon.id.width as u32,
-                height: icon.id.height as u32,
-                depth_or_array_layers: 1,
-            };
-            let image = Image::new(
-                extent,
-                TextureDimension::D2,
-                icon_pixels_rgba8(icon.id),
-                TextureFormat::Rgba8UnormSrgb,
-                RenderAssetUsages::default(),
-            );
-            let handle = images.add(image);
-            cache.0.insert(icon.id, handle.clone());
-            handle
-        };
+        let handle = icon_image(&mut images, &mut cache, icon);
 
         if let Some(mut image_node) = image_node {
             image_node.image = handle;
@@ -326,25 +425,7 @@ fn material_icon_repair_system(
     >,
 ) {
     for (entity, icon, image_node, node) in icons.iter_mut() {
-        let handle = if let Some(handle) = cache.0.get(&icon.id) {
-            handle.clone()
-        } else {
-            let extent = Extent3d {
-                width: icon.id.width as u32,
-                height: icon.id.height as u32,
-                depth_or_array_layers: 1,
-            };
-            let image = Image::new(
-                extent,
-                TextureDimension::D2,
-                icon_pixels_rgba8(icon.id),
-                TextureFormat::Rgba8UnormSrgb,
-                RenderAssetUsages::default(),
-            );
-            let handle = images.add(image);
-            cache.0.insert(icon.id, handle.clone());
-            handle
-        };
+        let handle = icon_image(&mut images, &mut cache, icon);
 
         if let Some(mut image_node) = image_node {
             image_node.image = handle;
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 
-use crate::{adaptive::WindowSizeClass, theme::MaterialTheme};
+use crate::{
+    adaptive::{WindowSizeClass, WindowSizeClassChanged},
+    theme::MaterialTheme,
+};
 
 use super::{
     spawn_bottom_navigation_scaffold, spawn_navigation_rail_scaffold,
@@ -8,14 +11,93 @@ use super::{
     PermanentDrawerScaffold, ScaffoldEntities,
 };
 
+/// Plugin that keeps spawned navigation suite scaffolds in sync with the window size class.
+pub struct NavigationSuitePlugin;
+
+impl Plugin for NavigationSuitePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::adaptive::WindowSizeClassPlugin>() {
+            app.add_plugins(crate::adaptive::WindowSizeClassPlugin);
+        }
+        app.add_message::<NavigationSuiteLayoutChanged>()
+            .add_systems(Update, navigation_suite_layout_system);
+    }
+}
+
 /// Material 3 **navigation suite scaffold**.
 ///
 /// Selects an appropriate navigation pattern based on Material window size classes.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct AdaptiveNavigationScaffold {
     pub bottom: BottomNavigationScaffold,
     pub rail: NavigationRailScaffold,
     pub drawer: PermanentDrawerScaffold,
+    /// Re-layout the spawned scaffold when the window size class changes.
+    ///
+    /// Disable for scaffolds that preview a fixed size class.
+    pub follow_window_size_class: bool,
+}
+
+impl Default for AdaptiveNavigationScaffold {
+    fn default() -> Self {
+        Self {
+            bottom: BottomNavigationScaffold::default(),
+            rail: NavigationRailScaffold::default(),
+            drawer: PermanentDrawerScaffold::default(),
+            follow_window_size_class: true,
+        }
+    }
+}
+
+/// Navigation presentation used by a navigation suite scaffold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationSuiteLayout {
+    /// Navigation bar below the content
+    NavigationBar,
+    /// Navigation rail beside the content
+    NavigationRail,
+    /// Permanent navigation drawer beside the content
+    NavigationDrawer,
+}
+
+impl NavigationSuiteLayout {
+    /// Pick the presentation for a window size class.
+    pub fn for_size_class(size_class: &WindowSizeClass) -> Self {
+        if size_class.use_expanded_drawer() {
+            Self::NavigationDrawer
+        } else if size_class.use_nav_rail() {
+            Self::NavigationRail
+        } else {
+            Self::NavigationBar
+        }
+    }
+}
+
+/// Marker on the root of a spawned navigation suite scaffold.
+///
+/// The navigation and content slots are restyled in place when the layout changes, so
+/// their children (and any selected destination) survive the swap.
+#[derive(Component, Debug, Clone)]
+pub struct NavigationSuite {
+    pub config: AdaptiveNavigationScaffold,
+    pub navigation: Entity,
+    pub content: Entity,
+    layout: NavigationSuiteLayout,
+}
+
+impl NavigationSuite {
+    /// Current navigation presentation
+    pub fn layout(&self) -> NavigationSuiteLayout {
+        self.layout
+    }
+}
+
+/// Fired after a navigation suite scaffold switched presentation.
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct NavigationSuiteLayoutChanged {
+    pub scaffold: Entity,
+    pub old: NavigationSuiteLayout,
+    pub new: NavigationSuiteLayout,
 }
 
 /// Spawn an adaptive navigation scaffold.
@@ -32,25 +114,180 @@ pub fn spawn_adaptive_navigation_scaffold(
     nav_children: impl FnOnce(&mut ChildSpawnerCommands),
     content_children: impl FnOnce(&mut ChildSpawnerCommands),
 ) -> ScaffoldEntities {
-    if size_class.use_expanded_drawer() {
-        spawn_permanent_drawer_scaffold(
+    let layout = NavigationSuiteLayout::for_size_class(size_class);
+    let entities = match layout {
+        NavigationSuiteLayout::NavigationDrawer => spawn_permanent_drawer_scaffold(
             parent,
             theme,
             &config.drawer,
             nav_children,
             content_children,
-        )
-    } else if size_class.use_nav_rail() {
-        spawn_navigation_rail_scaffold(parent, theme, &config.rail, nav_children, content_children)
-    } else {
+        ),
+        NavigationSuiteLayout::NavigationRail => spawn_navigation_rail_scaffold(
+            parent,
+            theme,
+            &config.rail,
+            nav_children,
+            content_children,
+        ),
         // Bottom navigation places the nav slot after the content.
-        spawn_bottom_navigation_scaffold(
+        NavigationSuiteLayout::NavigationBar => spawn_bottom_navigation_scaffold(
             parent,
             theme,
             &config.bottom,
             content_children,
             nav_children,
-        )
+        ),
+    };
+
+    parent
+        .commands()
+        .entity(entities.root)
+        .insert(NavigationSuite {
+            config: config.clone(),
+            navigation: entities.navigation,
+            content: entities.content,
+            layout,
+        });
+
+    entities
+}
+
+/// Slot nodes for a navigation suite layout: `(root, navigation, content)`.
+///
+/// Mirrors the nodes spawned by the bar, rail and drawer scaffolds.
+fn navigation_suite_nodes(
+    layout: NavigationSuiteLayout,
+    config: &AdaptiveNavigationScaffold,
+) -> (Node, Node, Node) {
+    match layout {
+        NavigationSuiteLayout::NavigationBar => {
+            let bottom = &config.bottom;
+            (
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(bottom.root_padding_px)),
+                    ..default()
+                },
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(bottom.bottom_bar_height_px),
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(bottom.bottom_bar_padding_px)),
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                Node {
+                    flex_grow: 1.0,
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Stretch,
+                    min_height: Val::Px(0.0),
+                    padding: UiRect::all(Val::Px(bottom.content_padding_px)),
+                    overflow: Overflow::clip_y(),
+                    ..default()
+                },
+            )
+        }
+        NavigationSuiteLayout::NavigationRail | NavigationSuiteLayout::NavigationDrawer => {
+            let (width, root_padding, root_gap, nav_padding, content_padding) =
+                if layout == NavigationSuiteLayout::NavigationRail {
+                    let rail = &config.rail;
+                    (
+                        rail.rail_width_px,
+                        rail.root_padding_px,
+                        rail.root_gap_px,
+                        rail.rail_padding_px,
+                        rail.content_padding_px,
+                    )
+                } else {
+                    let drawer = &config.drawer;
+                    (
+                        drawer.navigation_width_px,
+                        drawer.root_padding_px,
+                        drawer.root_gap_px,
+                        drawer.navigation_padding_px,
+                        drawer.content_padding_px,
+                    )
+                };
+            (
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    padding: UiRect::all(Val::Px(root_padding)),
+                    column_gap: Val::Px(root_gap),
+                    ..default()
+                },
+                Node {
+                    width: Val::Px(width),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(nav_padding)),
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                Node {
+                    flex_grow: 1.0,
+                    height: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(content_padding)),
+                    overflow: Overflow::clip_y(),
+                    ..default()
+                },
+            )
+        }
+    }
+}
+
+/// Swaps the presentation of navigation suite scaffolds when the window size class changes.
+fn navigation_suite_layout_system(
+    mut commands: Commands,
+    mut size_class_changes: MessageReader<WindowSizeClassChanged>,
+    size_class: Res<WindowSizeClass>,
+    mut suites: Query<(Entity, &mut NavigationSuite)>,
+    mut nodes: Query<&mut Node>,
+    mut layout_changes: MessageWriter<NavigationSuiteLayoutChanged>,
+) {
+    if size_class_changes.read().count() == 0 {
+        return;
+    }
+
+    let layout = NavigationSuiteLayout::for_size_class(&size_class);
+    for (root, mut suite) in suites.iter_mut() {
+        if !suite.config.follow_window_size_class || suite.layout == layout {
+            continue;
+        }
+
+        let (root_node, nav_node, content_node) = navigation_suite_nodes(layout, &suite.config);
+        for (entity, new_node) in [
+            (root, root_node),
+            (suite.navigation, nav_node),
+            (suite.content, content_node),
+        ] {
+            if let Ok(mut node) = nodes.get_mut(entity) {
+                // Keep app-controlled visibility.
+                let display = node.display;
+                *node = new_node;
+                node.display = display;
+            }
+        }
+
+        // Bars sit below the content; rails and drawers lead it.
+        let leading = match layout {
+            NavigationSuiteLayout::NavigationBar => suite.content,
+            _ => suite.navigation,
+        };
+        commands.entity(root).insert_children(0, &[leading]);
+
+        layout_changes.write(NavigationSuiteLayoutChanged {
+            scaffold: root,
+            old: suite.layout,
+            new: layout,
+        });
+        suite.layout = layout;
     }
 }
 
@@ -75,3 +312,66 @@ pub fn spawn_navigation_suite_scaffold(
         content_children,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_class_change_swaps_presentation_in_place() {
+        let mut app = App::new();
+        app.insert_resource(WindowSizeClass::new(400.0, 800.0))
+            .add_message::<WindowSizeClassChanged>()
+            .add_message::<NavigationSuiteLayoutChanged>()
+            .add_systems(Update, navigation_suite_layout_system);
+
+        let theme = MaterialTheme::default();
+        let compact = WindowSizeClass::new(400.0, 800.0);
+        let mut entities = None;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                entities = Some(spawn_adaptive_navigation_scaffold(
+                    parent,
+                    &theme,
+                    &compact,
+                    &AdaptiveNavigationScaffold::default(),
+                    |_| {},
+                    |_| {},
+                ));
+            });
+        app.update();
+        let entities = entities.unwrap();
+        let suite = app.world().get::<NavigationSuite>(entities.root).unwrap();
+        assert_eq!(suite.layout(), NavigationSuiteLayout::NavigationBar);
+
+        let medium = WindowSizeClass::new(700.0, 800.0);
+        app.world_mut().write_message(WindowSizeClassChanged {
+            old_width: compact.width,
+            new_width: medium.width,
+            old_height: compact.height,
+            new_height: medium.height,
+        });
+        app.insert_resource(medium);
+        app.update();
+
+        let world = app.world();
+        let suite = world.get::<NavigationSuite>(entities.root).unwrap();
+        assert_eq!(suite.layout(), NavigationSuiteLayout::NavigationRail);
+        // The same slot entities are reused, with the rail leading the content.
+        let children = world.get::<Children>(entities.root).unwrap();
+        assert_eq!(
+            children.iter().collect::<Vec<_>>(),
+            vec![entities.navigation, entities.content]
+        );
+        let root = world.get::<Node>(entities.root).unwrap();
+        assert_eq!(root.flex_direction, FlexDirection::Row);
+
+        let changes = world.resource::<Messages<NavigationSuiteLayoutChanged>>();
+        let change = changes.iter_current_update_messages().last().unwrap();
+        assert_eq!(change.scaffold, entities.root);
+        assert_eq!(change.old, NavigationSuiteLayout::NavigationBar);
+        assert_eq!(change.new, NavigationSuiteLayout::NavigationRail);
+    }
+}
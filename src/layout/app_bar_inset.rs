@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ui::ComputedNode;
 
 /// Minimal coordinator helper for layouts with a top app bar. It offsets content by the app bar
 /// height and optionally allows collapsing via a provided factor.
@@ -35,3 +36,175 @@ pub fn apply_app_bar_inset(content: &mut EntityCommands, config: AppBarOffsetCon
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t.clamp(0.0, 1.0)
 }
+
+/// Plugin that keeps [`ScaffoldContentInset`] padding in sync with measured bar heights.
+pub struct ScaffoldInsetPlugin;
+
+impl Plugin for ScaffoldInsetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SafeAreaInsets>()
+            .add_systems(Update, scaffold_content_inset_system);
+    }
+}
+
+/// Platform safe-area insets (notches, system bars) in logical pixels.
+///
+/// Defaults to zero; set it on platforms that report cut-outs.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub right: f32,
+}
+
+/// Insets applied to a content pane, in logical pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ContentInsets {
+    pub top: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub right: f32,
+}
+
+/// Pads a scaffold content pane by the measured heights of the bars drawn over it.
+///
+/// Insert on [`ScaffoldEntities::content`](super::ScaffoldEntities) and list the app bars
+/// that overlay the content. The padding follows the bars' laid-out heights (e.g. while a
+/// large app bar collapses) plus [`SafeAreaInsets`], on top of the pane's own padding.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ScaffoldContentInset {
+    pub top_bars: Vec<Entity>,
+    pub bottom_bars: Vec<Entity>,
+    base_padding: Option<UiRect>,
+    insets: Option<ContentInsets>,
+}
+
+impl ScaffoldContentInset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bar that overlays the top of the content.
+    pub fn with_top_bar(mut self, bar: Entity) -> Self {
+        self.top_bars.push(bar);
+        self
+    }
+
+    /// Add a bar that overlays the bottom of the content.
+    pub fn with_bottom_bar(mut self, bar: Entity) -> Self {
+        self.bottom_bars.push(bar);
+        self
+    }
+
+    /// The insets currently applied (zero until the bars have been laid out).
+    pub fn insets(&self) -> ContentInsets {
+        self.insets.unwrap_or_default()
+    }
+}
+
+fn measured_height(bars: &[Entity], computed: &Query<&ComputedNode>) -> f32 {
+    bars.iter()
+        .filter_map(|bar| computed.get(*bar).ok())
+        .map(|node| node.size().y * node.inverse_scale_factor())
+        .sum()
+}
+
+/// Adds `px` to a pixel value; non-pixel values are replaced.
+fn add_px(val: Val, px: f32) -> Val {
+    match val {
+        Val::Px(base) => Val::Px(base + px),
+        _ => Val::Px(px),
+    }
+}
+
+fn scaffold_content_inset_system(
+    safe_area: Res<SafeAreaInsets>,
+    computed: Query<&ComputedNode>,
+    mut panes: Query<(&mut ScaffoldContentInset, &mut Node)>,
+) {
+    for (mut inset, mut node) in panes.iter_mut() {
+        let insets = ContentInsets {
+            top: measured_height(&inset.top_bars, &computed) + safe_area.top,
+            bottom: measured_height(&inset.bottom_bars, &computed) + safe_area.bottom,
+            left: safe_area.left,
+            right: safe_area.right,
+        };
+        if inset.insets == Some(insets) {
+            continue;
+        }
+
+        let base = *inset.base_padding.get_or_insert(node.padding);
+        node.padding = UiRect {
+            left: add_px(base.left, insets.left),
+            right: add_px(base.right, insets.right),
+            top: add_px(base.top, insets.top),
+            bottom: add_px(base.bottom, insets.bottom),
+        };
+        inset.insets = Some(insets);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(app: &mut App, physical_height: f32) -> Entity {
+        app.world_mut()
+            .spawn(ComputedNode {
+                size: Vec2::new(800.0, physical_height),
+                inverse_scale_factor: 0.5,
+                ..ComputedNode::DEFAULT
+            })
+            .id()
+    }
+
+    #[test]
+    fn test_content_padding_follows_bar_heights_and_safe_area() {
+        let mut app = App::new();
+        app.insert_resource(SafeAreaInsets {
+            top: 24.0,
+            ..default()
+        })
+        .add_systems(Update, scaffold_content_inset_system);
+
+        let top = bar(&mut app, 128.0);
+        let bottom = bar(&mut app, 160.0);
+        let pane = app
+            .world_mut()
+            .spawn((
+                Node {
+                    padding: UiRect::all(Val::Px(16.0)),
+                    ..default()
+                },
+                ScaffoldContentInset::new()
+                    .with_top_bar(top)
+                    .with_bottom_bar(bottom),
+            ))
+            .id();
+        app.update();
+
+        let padding = app.world().get::<Node>(pane).unwrap().padding;
+        assert_eq!(padding.top, Val::Px(16.0 + 64.0 + 24.0));
+        assert_eq!(padding.bottom, Val::Px(16.0 + 80.0));
+        assert_eq!(padding.left, Val::Px(16.0));
+        assert_eq!(
+            app.world()
+                .get::<ScaffoldContentInset>(pane)
+                .unwrap()
+                .insets(),
+            ContentInsets {
+                top: 88.0,
+                bottom: 80.0,
+                left: 0.0,
+                right: 0.0,
+            }
+        );
+
+        // A collapsing bar shrinks the padding from the original base, not cumulatively.
+        app.world_mut().get_mut::<ComputedNode>(top).unwrap().size.y = 64.0;
+        app.update();
+        let padding = app.world().get::<Node>(pane).unwrap().padding;
+        assert_eq!(padding.top, Val::Px(16.0 + 32.0 + 24.0));
+    }
+}
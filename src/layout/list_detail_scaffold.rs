@@ -1,9 +1,29 @@
 use bevy::prelude::*;
 
-use crate::theme::MaterialTheme;
+use crate::{
+    adaptive::{WindowSizeClass, WindowWidthClass},
+    icons::{icon_by_name, IconStyle, MaterialIcon, ICON_ARROW_BACK},
+    theme::MaterialTheme,
+    tokens::CornerRadius,
+};
 
 use super::{PaneEntities, PaneTestIds};
 
+/// Plugin that adapts spawned list-detail scaffolds to the window width class.
+pub struct ListDetailPlugin;
+
+impl Plugin for ListDetailPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::adaptive::WindowSizeClassPlugin>() {
+            app.add_plugins(crate::adaptive::WindowSizeClassPlugin);
+        }
+        app.add_message::<ListDetailPaneChanged>().add_systems(
+            Update,
+            (list_detail_back_button_system, list_detail_layout_system).chain(),
+        );
+    }
+}
+
 /// Configuration for a list-detail scaffold (two-pane canonical layout).
 #[derive(Debug, Clone)]
 pub struct ListDetailScaffold {
@@ -12,6 +32,8 @@ pub struct ListDetailScaffold {
     pub gutter_px: f32,
     pub padding_px: f32,
     pub test_ids: PaneTestIds,
+    /// Collapse to a single pane on `Compact` widths, pushing the detail over the list.
+    pub adaptive: bool,
 }
 
 impl Default for ListDetailScaffold {
@@ -22,11 +44,89 @@ impl Default for ListDetailScaffold {
             gutter_px: 24.0,
             padding_px: 16.0,
             test_ids: PaneTestIds::default_two_pane(),
+            adaptive: true,
+        }
+    }
+}
+
+/// Pane(s) currently shown by a list-detail scaffold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListDetailPane {
+    /// List and detail side by side
+    Both,
+    /// Single pane showing the list
+    List,
+    /// Single pane showing the detail
+    Detail,
+}
+
+/// State of a spawned list-detail scaffold, stored on its root.
+///
+/// Call [`ListDetail::select`] when a list item is chosen and [`ListDetail::back`] to
+/// return to the list. On `Compact` widths only one pane is shown at a time.
+#[derive(Component, Debug, Clone)]
+pub struct ListDetail {
+    pub primary: Entity,
+    pub secondary: Entity,
+    config: ListDetailScaffold,
+    back_button: Entity,
+    selected: Option<Entity>,
+    single_pane: bool,
+    visible: Option<ListDetailPane>,
+}
+
+impl ListDetail {
+    /// Show the detail for `item` (pushes the detail pane in single-pane mode).
+    pub fn select(&mut self, item: Entity) {
+        self.selected = Some(item);
+    }
+
+    /// Clear the selected detail, returning to the list.
+    ///
+    /// Returns `false` when there was nothing to go back from.
+    pub fn back(&mut self) -> bool {
+        self.selected.take().is_some()
+    }
+
+    /// Item whose detail is selected, if any
+    pub fn selected(&self) -> Option<Entity> {
+        self.selected
+    }
+
+    /// Whether the scaffold currently shows one pane at a time
+    pub fn is_single_pane(&self) -> bool {
+        self.single_pane
+    }
+
+    /// Pane(s) that should be visible for the current width and selection
+    pub fn visible_pane(&self) -> ListDetailPane {
+        if !self.single_pane {
+            ListDetailPane::Both
+        } else if self.selected.is_some() {
+            ListDetailPane::Detail
+        } else {
+            ListDetailPane::List
         }
     }
 }
 
+/// Back affordance shown at the top of the detail pane in single-pane mode.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ListDetailBackButton {
+    pub scaffold: Entity,
+}
+
+/// Fired when the visible pane(s) of a list-detail scaffold change.
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct ListDetailPaneChanged {
+    pub scaffold: Entity,
+    pub pane: ListDetailPane,
+}
+
 /// Spawn a list-detail scaffold with two panes.
+///
+/// The root gets a [`ListDetail`] component; with `config.adaptive` the scaffold
+/// collapses to a single pane on `Compact` widths.
 pub fn spawn_list_detail_scaffold(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
@@ -36,52 +136,96 @@ pub fn spawn_list_detail_scaffold(
 ) -> PaneEntities {
     let mut primary = Entity::PLACEHOLDER;
     let mut secondary = Entity::PLACEHOLDER;
+    let mut back_button = Entity::PLACEHOLDER;
+
+    let mut root = parent.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(config.gutter_px),
+            padding: UiRect::all(Val::Px(config.padding_px)),
+            ..default()
+        },
+        BackgroundColor(theme.surface.with_alpha(0.0)),
+    ));
+    let root_id = root.id();
+
+    root.with_children(|root| {
+        primary = root
+            .spawn((
+                Node {
+                    flex_grow: 1.0,
+                    min_width: Val::Px(config.primary_min_width_px),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::clip_y(),
+                    ..default()
+                },
+                BackgroundColor(theme.surface_container_low),
+                config.test_ids.primary.clone(),
+            ))
+            .with_children(primary_children)
+            .id();
 
-    parent
-        .spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                flex_direction: FlexDirection::Row,
-                column_gap: Val::Px(config.gutter_px),
-                padding: UiRect::all(Val::Px(config.padding_px)),
-                ..default()
-            },
-            BackgroundColor(theme.surface.with_alpha(0.0)),
-        ))
-        .with_children(|root| {
-            primary = root
-                .spawn((
-                    Node {
-                        flex_grow: 1.0,
-                        min_width: Val::Px(config.primary_min_width_px),
-                        height: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Column,
-                        overflow: Overflow::clip_y(),
-                        ..default()
-                    },
-                    BackgroundColor(theme.surface_container_low),
-                    config.test_ids.primary.clone(),
-                ))
-                .with_children(primary_children)
-                .id();
-
-            secondary = root
-                .spawn((
-                    Node {
-                        flex_grow: 2.0,
-                        min_width: Val::Px(config.secondary_min_width_px),
-                        height: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Column,
-                        overflow: Overflow::clip_y(),
-                        ..default()
-                    },
-                    BackgroundColor(theme.surface),
-                    config.test_ids.secondary.clone(),
-                ))
-                .with_children(secondary_children)
-                .id();
-        });
+        secondary = root
+            .spawn((
+                Node {
+                    flex_grow: 2.0,
+                    min_width: Val::Px(config.secondary_min_width_px),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::clip_y(),
+                    ..default()
+                },
+                BackgroundColor(theme.surface),
+                config.test_ids.secondary.clone(),
+            ))
+            .with_children(|detail| {
+                // Only visible while the detail covers the list.
+                back_button = detail
+                    .spawn((
+                        ListDetailBackButton { scaffold: root_id },
+                        Button,
+                        Interaction::None,
+                        Node {
+                            display: Display::None,
+                            width: Val::Px(40.0),
+                            height: Val::Px(40.0),
+                            flex_shrink: 0.0,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                    ))
+                    .with_children(|btn| {
+                        if let Some(id) = icon_by_name(ICON_ARROW_BACK) {
+                            btn.spawn((
+                                MaterialIcon::new(id),
+                                IconStyle::outlined()
+                                    .with_color(theme.on_surface)
+                                    .with_size(24.0),
+                            ));
+                        }
+                    })
+                    .id();
+
+                secondary_children(detail);
+            })
+            .id();
+    });
+
+    root.insert(ListDetail {
+        primary,
+        secondary,
+        config: config.clone(),
+        back_button,
+        selected: None,
+        single_pane: false,
+        visible: None,
+    });
 
     PaneEntities {
         primary,
@@ -89,3 +233,153 @@ pub fn spawn_list_detail_scaffold(
         supporting: None,
     }
 }
+
+fn list_detail_back_button_system(
+    buttons: Query<(&Interaction, &ListDetailBackButton), Changed<Interaction>>,
+    mut scaffolds: Query<&mut ListDetail>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut scaffold) = scaffolds.get_mut(button.scaffold) {
+            scaffold.back();
+        }
+    }
+}
+
+/// Shows/hides panes for the current width class and selection.
+fn list_detail_layout_system(
+    size_class: Res<WindowSizeClass>,
+    mut scaffolds: Query<(Entity, &mut ListDetail)>,
+    mut nodes: Query<&mut Node>,
+    mut pane_changes: MessageWriter<ListDetailPaneChanged>,
+) {
+    for (entity, mut scaffold) in scaffolds.iter_mut() {
+        if !size_class.is_changed() && !scaffold.is_changed() {
+            continue;
+        }
+
+        let single_pane = scaffold.config.adaptive && size_class.width == WindowWidthClass::Compact;
+        if scaffold.single_pane != single_pane {
+            scaffold.single_pane = single_pane;
+        }
+
+        let pane = scaffold.visible_pane();
+        if scaffold.visible == Some(pane) {
+            continue;
+        }
+
+        let (show_primary, show_secondary) = match pane {
+            ListDetailPane::Both => (true, true),
+            ListDetailPane::List => (true, false),
+            ListDetailPane::Detail => (false, true),
+        };
+        // A single pane fills the width, so the two-pane minimums no longer apply.
+        let min_width = |px: f32| {
+            if single_pane {
+                Val::Px(0.0)
+            } else {
+                Val::Px(px)
+            }
+        };
+
+        if let Ok(mut node) = nodes.get_mut(scaffold.primary) {
+            node.display = if show_primary {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            node.min_width = min_width(scaffold.config.primary_min_width_px);
+        }
+        if let Ok(mut node) = nodes.get_mut(scaffold.secondary) {
+            node.display = if show_secondary {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            node.min_width = min_width(scaffold.config.secondary_min_width_px);
+        }
+        if let Ok(mut node) = nodes.get_mut(scaffold.back_button) {
+            node.display = if pane == ListDetailPane::Detail {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        // The first layout pass is not a change.
+        if scaffold.visible.is_some() {
+            pane_changes.write(ListDetailPaneChanged {
+                scaffold: entity,
+                pane,
+            });
+        }
+        scaffold.visible = Some(pane);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(app: &App, entity: Entity) -> Display {
+        app.world().get::<Node>(entity).unwrap().display
+    }
+
+    #[test]
+    fn test_compact_width_shows_one_pane_at_a_time() {
+        let mut app = App::new();
+        app.insert_resource(WindowSizeClass::new(400.0, 800.0))
+            .add_message::<ListDetailPaneChanged>()
+            .add_systems(Update, list_detail_layout_system);
+
+        let theme = MaterialTheme::default();
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_list_detail_scaffold(
+                    parent,
+                    &theme,
+                    &ListDetailScaffold::default(),
+                    |_| {},
+                    |_| {},
+                );
+            });
+        app.update();
+
+        let (root, scaffold) = app
+            .world_mut()
+            .query::<(Entity, &ListDetail)>()
+            .single(app.world())
+            .map(|(entity, scaffold)| (entity, scaffold.clone()))
+            .unwrap();
+        assert!(scaffold.is_single_pane());
+        assert_eq!(scaffold.visible_pane(), ListDetailPane::List);
+        assert_eq!(display(&app, scaffold.primary), Display::Flex);
+        assert_eq!(display(&app, scaffold.secondary), Display::None);
+
+        // Selecting an item pushes the detail over the list, with a back button.
+        let item = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .get_mut::<ListDetail>(root)
+            .unwrap()
+            .select(item);
+        app.update();
+        assert_eq!(display(&app, scaffold.primary), Display::None);
+        assert_eq!(display(&app, scaffold.secondary), Display::Flex);
+        assert_eq!(display(&app, scaffold.back_button), Display::Flex);
+        let changes = app.world().resource::<Messages<ListDetailPaneChanged>>();
+        let change = changes.iter_current_update_messages().last().unwrap();
+        assert_eq!(change.scaffold, root);
+        assert_eq!(change.pane, ListDetailPane::Detail);
+
+        // Widening the window shows both panes again.
+        app.insert_resource(WindowSizeClass::new(1000.0, 800.0));
+        app.update();
+        assert_eq!(display(&app, scaffold.primary), Display::Flex);
+        assert_eq!(display(&app, scaffold.secondary), Display::Flex);
+        assert_eq!(display(&app, scaffold.back_button), Display::None);
+    }
+}
@@ -5,6 +5,7 @@
 //! Bevy UI's flexbox model.
 
 mod adaptive_navigation_scaffold;
+mod app_bar_inset;
 mod bottom_navigation_scaffold;
 mod list_detail_scaffold;
 mod modal_drawer_scaffold;
@@ -15,6 +16,11 @@ mod supporting_panes_scaffold;
 
 pub use permanent_drawer_scaffold::{spawn_permanent_drawer_scaffold, PermanentDrawerScaffold};
 
+pub use app_bar_inset::{
+    apply_app_bar_inset, AppBarOffsetConfig, ContentInsets, SafeAreaInsets, ScaffoldContentInset,
+    ScaffoldInsetPlugin,
+};
+
 pub use scaffold_types::{PaneEntities, PaneTestIds, ScaffoldEntities, ScaffoldTestIds};
 
 pub use bottom_navigation_scaffold::{
@@ -22,15 +28,22 @@ pub use bottom_navigation_scaffold::{
     NavigationBarScaffold,
 };
 
-pub use navigation_rail_scaffold::{spawn_navigation_rail_scaffold, NavigationRailScaffold};
+pub use navigation_rail_scaffold::{
+    spawn_navigation_rail_scaffold, NavigationRail, NavigationRailDestination, NavigationRailLabel,
+    NavigationRailMenuButton, NavigationRailPlugin, NavigationRailScaffold,
+};
 
 pub use modal_drawer_scaffold::{spawn_modal_drawer_scaffold, ModalDrawerScaffold};
 
-pub use list_detail_scaffold::{spawn_list_detail_scaffold, ListDetailScaffold};
+pub use list_detail_scaffold::{
+    spawn_list_detail_scaffold, ListDetail, ListDetailBackButton, ListDetailPane,
+    ListDetailPaneChanged, ListDetailPlugin, ListDetailScaffold,
+};
 
 pub use supporting_panes_scaffold::{spawn_supporting_panes_scaffold, SupportingPanesScaffold};
 
 pub use adaptive_navigation_scaffold::{
     spawn_adaptive_navigation_scaffold, spawn_navigation_suite_scaffold,
-    AdaptiveNavigationScaffold, NavigationSuiteScaffold,
+    AdaptiveNavigationScaffold, NavigationSuite, NavigationSuiteLayout,
+    NavigationSuiteLayoutChanged, NavigationSuitePlugin, NavigationSuiteScaffold,
 };
@@ -1,13 +1,42 @@
 use bevy::prelude::*;
 
-use crate::theme::MaterialTheme;
+use crate::{
+    icons::{icon_by_name, IconStyle, MaterialIcon, ICON_MENU},
+    motion::{ease_emphasized, ReducedMotion},
+    theme::MaterialTheme,
+    tokens::{CornerRadius, Duration},
+};
 
 use super::{ScaffoldEntities, ScaffoldTestIds};
 
+/// Plugin for expandable navigation rails.
+pub struct NavigationRailPlugin;
+
+impl Plugin for NavigationRailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                navigation_rail_menu_button_system,
+                navigation_rail_width_system,
+                navigation_rail_label_system,
+                navigation_rail_destination_system,
+            )
+                .chain(),
+        );
+    }
+}
+
 /// Configuration for a navigation-rail scaffold (left rail + content).
 #[derive(Debug, Clone)]
 pub struct NavigationRailScaffold {
     pub rail_width_px: f32,
+    /// Rail width while expanded (icon + label destinations)
+    pub expanded_width_px: f32,
+    /// Start in the expanded state
+    pub expanded: bool,
+    /// Show a menu button at the top of the rail that toggles expansion
+    pub menu_toggle: bool,
     pub root_padding_px: f32,
     pub root_gap_px: f32,
     pub rail_padding_px: f32,
@@ -19,6 +48,9 @@ impl Default for NavigationRailScaffold {
     fn default() -> Self {
         Self {
             rail_width_px: 80.0,
+            expanded_width_px: 220.0,
+            expanded: false,
+            menu_toggle: false,
             root_padding_px: 0.0,
             root_gap_px: 0.0,
             rail_padding_px: 8.0,
@@ -28,10 +60,104 @@ impl Default for NavigationRailScaffold {
     }
 }
 
+/// Expansion state of a spawned navigation rail, stored on the navigation node.
+///
+/// Width changes are animated; the content pane reflows because it fills the
+/// remaining space.
+#[derive(Component, Debug, Clone)]
+pub struct NavigationRail {
+    expanded: bool,
+    collapsed_width: f32,
+    expanded_width: f32,
+    start_width: f32,
+    width: f32,
+    elapsed: f32,
+}
+
+impl NavigationRail {
+    fn new(collapsed_width: f32, expanded_width: f32, expanded: bool) -> Self {
+        let width = if expanded {
+            expanded_width
+        } else {
+            collapsed_width
+        };
+        Self {
+            expanded,
+            collapsed_width,
+            expanded_width,
+            start_width: width,
+            width,
+            elapsed: Duration::MEDIUM4,
+        }
+    }
+
+    /// Whether the rail is (or is animating towards) expanded
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Expand or collapse the rail
+    pub fn set_expanded(&mut self, expanded: bool) {
+        if self.expanded != expanded {
+            self.expanded = expanded;
+            self.start_width = self.width;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Flip between expanded and collapsed
+    pub fn toggle(&mut self) {
+        let expanded = !self.expanded;
+        self.set_expanded(expanded);
+    }
+
+    /// Current (animated) width in logical pixels
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Whether the width transition is still running
+    pub fn is_animating(&self) -> bool {
+        self.elapsed < Duration::MEDIUM4
+    }
+
+    fn target_width(&self) -> f32 {
+        if self.expanded {
+            self.expanded_width
+        } else {
+            self.collapsed_width
+        }
+    }
+
+    fn update(&mut self, dt: f32) -> f32 {
+        self.elapsed = (self.elapsed + dt).min(Duration::MEDIUM4);
+        let t = ease_emphasized(self.elapsed / Duration::MEDIUM4);
+        self.width = self.start_width + (self.target_width() - self.start_width) * t;
+        self.width
+    }
+}
+
+/// Menu button that toggles its rail between collapsed and expanded.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NavigationRailMenuButton {
+    pub rail: Entity,
+}
+
+/// Marker for a destination label; only shown while its rail is expanded.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NavigationRailLabel;
+
+/// A rail destination. Selected destinations get the active indicator in both states.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NavigationRailDestination {
+    pub selected: bool,
+}
+
 /// Spawn a navigation-rail scaffold.
 ///
 /// This is similar to a permanent drawer scaffold but uses a narrower navigation
-/// surface intended for icons.
+/// surface intended for icons. The navigation node carries a [`NavigationRail`] that
+/// can expand it to show [`NavigationRailLabel`]s.
 pub fn spawn_navigation_rail_scaffold(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
@@ -41,6 +167,11 @@ pub fn spawn_navigation_rail_scaffold(
 ) -> ScaffoldEntities {
     let mut navigation = Entity::PLACEHOLDER;
     let mut content = Entity::PLACEHOLDER;
+    let rail = NavigationRail::new(
+        config.rail_width_px,
+        config.expanded_width_px,
+        config.expanded,
+    );
 
     let root = parent
         .spawn((
@@ -56,21 +187,52 @@ pub fn spawn_navigation_rail_scaffold(
             config.test_ids.root.clone(),
         ))
         .with_children(|root| {
-            navigation = root
-                .spawn((
-                    Node {
-                        width: Val::Px(config.rail_width_px),
-                        height: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Column,
-                        padding: UiRect::all(Val::Px(config.rail_padding_px)),
-                        row_gap: Val::Px(8.0),
-                        ..default()
-                    },
-                    BackgroundColor(theme.surface_container_low),
-                    config.test_ids.navigation.clone(),
-                ))
-                .with_children(nav_children)
-                .id();
+            let mut nav = root.spawn((
+                Node {
+                    width: Val::Px(rail.width()),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(config.rail_padding_px)),
+                    row_gap: Val::Px(8.0),
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                BackgroundColor(theme.surface_container_low),
+                config.test_ids.navigation.clone(),
+                rail,
+            ));
+            navigation = nav.id();
+            nav.with_children(|nav| {
+                if config.menu_toggle {
+                    nav.spawn((
+                        NavigationRailMenuButton { rail: navigation },
+                        Button,
+                        Interaction::None,
+                        Node {
+                            width: Val::Px(56.0),
+                            height: Val::Px(56.0),
+                            flex_shrink: 0.0,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                    ))
+                    .with_children(|btn| {
+                        if let Some(id) = icon_by_name(ICON_MENU) {
+                            btn.spawn((
+                                MaterialIcon::new(id),
+                                IconStyle::outlined()
+                                    .with_color(theme.on_surface_variant)
+                                    .with_size(24.0),
+                            ));
+                        }
+                    });
+                }
+
+                nav_children(nav);
+            });
 
             content = root
                 .spawn((
@@ -95,3 +257,173 @@ pub fn spawn_navigation_rail_scaffold(
         content,
     }
 }
+
+fn navigation_rail_menu_button_system(
+    buttons: Query<(&Interaction, &NavigationRailMenuButton), Changed<Interaction>>,
+    mut rails: Query<&mut NavigationRail>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut rail) = rails.get_mut(button.rail) {
+            rail.toggle();
+        }
+    }
+}
+
+fn navigation_rail_width_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut rails: Query<(&mut NavigationRail, &mut Node)>,
+) {
+    let dt = if reduced_motion.is_some_and(|r| r.0) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+    for (mut rail, mut node) in rails.iter_mut() {
+        // Only drive the width while animating so other layout code can own it otherwise.
+        if !rail.is_animating() {
+            continue;
+        }
+        let width = rail.update(dt);
+        node.width = Val::Px(width);
+    }
+}
+
+/// Labels appear once the rail has finished expanding and hide as soon as it collapses.
+fn navigation_rail_label_system(
+    rails: Query<&NavigationRail>,
+    mut labels: Query<(Entity, &mut Node), With<NavigationRailLabel>>,
+    parents: Query<&ChildOf>,
+) {
+    for (label, mut node) in labels.iter_mut() {
+        let Some(rail) = parents
+            .iter_ancestors(label)
+            .find_map(|ancestor| rails.get(ancestor).ok())
+        else {
+            continue;
+        };
+
+        let display = if rail.is_expanded() && !rail.is_animating() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// Lays destinations out for the rail state and draws the active indicator.
+fn navigation_rail_destination_system(
+    theme: Option<Res<MaterialTheme>>,
+    rails: Query<Ref<NavigationRail>>,
+    mut destinations: Query<(
+        Entity,
+        Ref<NavigationRailDestination>,
+        &mut Node,
+        &mut BackgroundColor,
+    )>,
+    parents: Query<&ChildOf>,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+
+    for (entity, destination, mut node, mut bg) in destinations.iter_mut() {
+        let Some(rail) = parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| rails.get(ancestor).ok())
+        else {
+            continue;
+        };
+        if !destination.is_changed() && !rail.is_changed() && !theme.is_changed() {
+            continue;
+        }
+
+        // Collapsed: stacked, centered icon. Expanded: icon and label in a full-width row.
+        let (direction, justify, width) = if rail.is_expanded() {
+            (
+                FlexDirection::Row,
+                JustifyContent::Start,
+                Val::Percent(100.0),
+            )
+        } else {
+            (FlexDirection::Column, JustifyContent::Center, Val::Px(56.0))
+        };
+        node.flex_direction = direction;
+        node.justify_content = justify;
+        node.width = width;
+        node.column_gap = Val::Px(12.0);
+
+        *bg = BackgroundColor(if destination.selected {
+            theme.secondary_container
+        } else {
+            Color::NONE
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rail_width_animates_between_collapsed_and_expanded() {
+        let mut rail = NavigationRail::new(80.0, 220.0, false);
+        assert_eq!(rail.width(), 80.0);
+        assert!(!rail.is_animating());
+
+        rail.toggle();
+        assert!(rail.is_expanded());
+        assert!(rail.is_animating());
+
+        let halfway = rail.update(Duration::MEDIUM4 / 2.0);
+        assert!(halfway > 80.0 && halfway < 220.0, "width {halfway}");
+
+        assert_eq!(rail.update(Duration::MEDIUM4), 220.0);
+        assert!(!rail.is_animating());
+
+        // Collapsing mid-way starts from the current width.
+        rail.set_expanded(false);
+        let collapsing = rail.update(Duration::MEDIUM4 / 4.0);
+        assert!(collapsing < 220.0 && collapsing > 80.0);
+        assert_eq!(rail.update(Duration::MEDIUM4), 80.0);
+    }
+
+    #[test]
+    fn test_labels_show_only_once_fully_expanded() {
+        let mut app = App::new();
+        app.add_systems(Update, navigation_rail_label_system);
+
+        let rail = app
+            .world_mut()
+            .spawn((Node::default(), NavigationRail::new(80.0, 220.0, false)))
+            .id();
+        let label = app
+            .world_mut()
+            .spawn((Node::default(), NavigationRailLabel, ChildOf(rail)))
+            .id();
+        let label_display = |app: &App| app.world().get::<Node>(label).unwrap().display;
+
+        app.update();
+        assert_eq!(label_display(&app), Display::None);
+
+        app.world_mut()
+            .get_mut::<NavigationRail>(rail)
+            .unwrap()
+            .set_expanded(true);
+        app.update();
+        assert_eq!(label_display(&app), Display::None);
+
+        app.world_mut()
+            .get_mut::<NavigationRail>(rail)
+            .unwrap()
+            .update(Duration::MEDIUM4);
+        app.update();
+        assert_eq!(label_display(&app), Display::Flex);
+    }
+}
@@ -23,7 +23,7 @@
 //! fn main() {
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
-//!         .add_plugins(MaterialUiPlugin)
+//!         .add_plugins(MaterialUiPlugin::default())
 //!         .add_systems(Startup, setup)
 //!         .run();
 //! }
@@ -58,6 +58,10 @@ use bevy::prelude::*;
 /// Theme and color system based on Material Design 3
 pub mod theme;
 
+/// Live theme reloading from a `.mui_theme` asset file
+#[cfg(feature = "theme_hot_reload")]
+pub mod theme_hot_reload;
+
 /// Locale configuration and locale-driven defaults
 pub mod locale;
 
@@ -82,9 +86,27 @@ pub mod elevation;
 /// Focus ring component for accessibility
 pub mod focus;
 
+/// Overlay stack for coordinated Escape dismissal and keyboard shortcuts
+pub mod overlay;
+
+/// Shared disabled state for interactive components
+pub mod disabled;
+
+/// Clickable labels for checkboxes, radio buttons, and switches
+pub mod control_label;
+
+/// Ellipsis and marquee handling for toolbar and app bar titles
+pub mod title_overflow;
+
+/// Overflow menus for toolbar and app bar actions
+pub mod action_overflow;
+
 /// Ripple effect component for touch feedback
 pub mod ripple;
 
+/// Accessibility role, label, and value metadata for interactive components
+pub mod semantics;
+
 /// Telemetry and test automation support
 pub mod telemetry;
 
@@ -122,6 +144,9 @@ pub mod slider;
 /// Text field components (filled, outlined)
 pub mod text_field;
 
+/// Autocomplete text field with a suggestion menu
+pub mod autocomplete;
+
 /// Progress indicators (linear and circular)
 pub mod progress;
 
@@ -134,6 +159,9 @@ pub mod date_picker;
 /// Time picker component (Material Design 3)
 pub mod time_picker;
 
+/// Combined date and time picker flow
+pub mod datetime_picker;
+
 /// List and list item components
 pub mod list;
 
@@ -158,6 +186,9 @@ pub mod layout;
 /// Motion and animation utilities
 pub mod motion;
 
+/// Animation components and spring-driven property animation
+pub mod animation;
+
 /// Snackbar component for brief messages
 pub mod snackbar;
 
@@ -199,32 +230,57 @@ pub mod prelude {
     pub use bevy::ui::{BoxShadow, Outline, ShadowStyle};
 
     // Core
-    pub use crate::elevation::{Elevation, ElevationShadow};
+    pub use crate::action_overflow::{ActionOverflow, ActionOverflowPlugin};
+    pub use crate::control_label::{ControlLabel, ControlLabelPlugin, ControlLabelText};
+    pub use crate::disabled::{sync_disabled_system, Disabled, DisabledPlugin, DisabledSource};
+    pub use crate::elevation::{
+        Elevation, ElevationPlugin, ElevationShadow, ElevationTint, ElevationTintSettings,
+    };
     pub use crate::focus::{
-        create_native_focus_outline, FocusGained, FocusLost, FocusPlugin, FocusRing, Focusable,
+        create_native_focus_outline, FocusGained, FocusGroup, FocusGroupOrientation, FocusLost,
+        FocusModality, FocusPlugin, FocusRing, Focusable, InputModality,
     };
     pub use crate::i18n::{
         LocalizedText, MaterialI18n, MaterialI18nPlugin, MaterialLanguage,
         MaterialLanguageOverride, MaterialTranslations,
     };
-    pub use crate::ripple::{Ripple, RippleHost, RipplePlugin, SpawnRipple};
+    pub use crate::overlay::{
+        open_on_shortcut_system, track_overlay_system, DismissOnOutsideClick, OpenOnShortcut,
+        OpenableOverlay, OutsideClickEvent, Overlay, OverlayPlugin, OverlayReflowSystems,
+        OverlayStack, Z_APP_BAR_CONTROLS, Z_CONTAINER_TRANSFORM, Z_DIALOG, Z_MENU, Z_SCRIM,
+        Z_SNACKBAR, Z_TOOLTIP,
+    };
+    pub use crate::ripple::{
+        Ripple, RippleHost, RipplePlugin, RipplePool, RippleSettings, RippleSurface, SpawnRipple,
+    };
+    pub use crate::semantics::{sync_semantics_system, A11ySemantics, Role, Semantic};
     pub use crate::telemetry::{
-        test_id_if_enabled, ElementBounds, InsertTestId, TelemetryConfig, TelemetryPlugin, TestId,
+        find_by_test_id, test_id_if_enabled, ElementBounds, InsertTestId, TelemetryConfig,
+        TelemetryEventKind, TelemetryEvents, TelemetryPlugin, TelemetryRecord, TestId, TestIdIndex,
     };
-    pub use crate::theme::{ColorScheme, MaterialTheme};
-    pub use crate::tokens::{CornerRadius, Duration, Easing, Spacing};
-    pub use crate::typography::Typography;
+    pub use crate::theme::{ColorScheme, MaterialTheme, SetTheme, ThemeMode};
+    #[cfg(feature = "theme_hot_reload")]
+    pub use crate::theme_hot_reload::{HotReloadTheme, ThemeAsset, ThemeHotReloadPlugin};
+    pub use crate::title_overflow::{OverflowTitle, TitleOverflow, TitleOverflowPlugin};
+    pub use crate::tokens::{CornerRadius, Duration, Easing, FocusIndicator, Opacity, Spacing};
+    pub use crate::typography::{TypeRole, TypeStyle, TypeStyleOverride, Typography};
 
     // Color System
-    pub use crate::color::{Hct, MaterialColorScheme, TonalPalette};
+    pub use crate::color::{
+        blend_cam16, harmonize, Hct, MaterialColorScheme, SchemeVariant, TonalPalette,
+    };
 
     // Icons
-    pub use crate::icons::{material_icons, MaterialIcon, MaterialIconsPlugin};
+    pub use crate::icons::{
+        material_icons, spawn_icon_by_name, IconAxes, IconGrade, IconOpticalSize, IconStyle,
+        IconWeight, MaterialIcon, MaterialIconsPlugin,
+    };
 
     // Button
     pub use crate::button::{
         material_button_bundle, spawn_material_button, ButtonClickEvent, ButtonLabel, ButtonPlugin,
-        ButtonVariant, MaterialButton, MaterialButtonBuilder, SpawnButtonChild,
+        ButtonShortcut, ButtonShortcutHint, ButtonShortcutLabel, ButtonVariant, KeyCombo,
+        MaterialButton, MaterialButtonBuilder, SpawnButtonChild,
     };
 
     // Button Group
@@ -234,8 +290,8 @@ pub mod prelude {
 
     // Icon Button
     pub use crate::icon_button::{
-        IconButtonBuilder, IconButtonClickEvent, IconButtonPlugin, IconButtonVariant,
-        MaterialIconButton, SpawnIconButtonChild, ICON_BUTTON_SIZE, ICON_SIZE,
+        IconButtonBuilder, IconButtonClickEvent, IconButtonPlugin, IconButtonToggleEvent,
+        IconButtonVariant, MaterialIconButton, SpawnIconButtonChild, ICON_BUTTON_SIZE, ICON_SIZE,
     };
 
     // FAB
@@ -246,7 +302,8 @@ pub mod prelude {
 
     // Card
     pub use crate::card::{
-        CardBuilder, CardClickEvent, CardPlugin, CardVariant, MaterialCard, SpawnCardChild,
+        CardBuilder, CardClickEvent, CardPlugin, CardSlot, CardVariant, MaterialCard,
+        SpawnCardChild,
     };
 
     // Checkbox
@@ -274,39 +331,48 @@ pub mod prelude {
     // Slider
     pub use crate::slider::{
         MaterialSlider, SliderActiveTrack, SliderBuilder, SliderChangeEvent, SliderHandle,
-        SliderLabel, SliderPlugin, SliderTraceSettings, SliderTrack, SpawnSliderChild,
-        SLIDER_HANDLE_SIZE, SLIDER_HANDLE_SIZE_PRESSED, SLIDER_LABEL_HEIGHT, SLIDER_TICK_SIZE,
-        SLIDER_TRACK_HEIGHT, SLIDER_TRACK_HEIGHT_ACTIVE,
+        SliderLabel, SliderLabelText, SliderPlugin, SliderTickLabel, SliderTraceSettings,
+        SliderTrack, SpawnSliderChild, SLIDER_HANDLE_SIZE, SLIDER_HANDLE_SIZE_PRESSED,
+        SLIDER_LABEL_HEIGHT, SLIDER_TICK_LABEL_HEIGHT, SLIDER_TICK_SIZE, SLIDER_TRACK_HEIGHT,
+        SLIDER_TRACK_HEIGHT_ACTIVE,
     };
 
     // Text Field
     pub use crate::text_field::{
         MaterialTextField, SpawnTextFieldChild, TextFieldBuilder, TextFieldChangeEvent,
         TextFieldFormatter, TextFieldInput, TextFieldLabel, TextFieldPlugin, TextFieldSubmitEvent,
-        TextFieldSupportingText, TextFieldVariant, TEXT_FIELD_HEIGHT, TEXT_FIELD_MIN_WIDTH,
+        TextFieldSuffix, TextFieldSuffixFor, TextFieldSupportingText, TextFieldVariant,
+        TEXT_FIELD_HEIGHT, TEXT_FIELD_MIN_WIDTH,
+    };
+
+    // Autocomplete
+    pub use crate::autocomplete::{
+        filter_suggestions, Autocomplete, AutocompletePlugin, AutocompleteSelected,
+        SuggestionProvider, AUTOCOMPLETE_MAX_SUGGESTIONS,
     };
 
     // Progress
     pub use crate::progress::{
         CircularProgressBuilder, LinearProgressBuilder, MaterialCircularProgress,
         MaterialLinearProgress, ProgressIndicator, ProgressMode, ProgressPlugin, ProgressTrack,
-        ProgressVariant, SpawnProgressChild, CIRCULAR_PROGRESS_SIZE, CIRCULAR_PROGRESS_TRACK_WIDTH,
-        LINEAR_PROGRESS_HEIGHT,
+        ProgressTrackSegment, ProgressVariant, SpawnProgressChild, CIRCULAR_PROGRESS_SIZE,
+        CIRCULAR_PROGRESS_TRACK_WIDTH, LINEAR_PROGRESS_HEIGHT, PROGRESS_TRACK_GAP,
     };
 
     // Dialog
     pub use crate::dialog::{
         create_dialog_scrim, create_dialog_scrim_for, DialogActions, DialogBuilder,
-        DialogCloseEvent, DialogConfirmEvent, DialogContent, DialogHeadline, DialogOpenEvent,
-        DialogPlugin, DialogScrim, DialogType, MaterialDialog, SpawnDialogChild, DIALOG_MAX_WIDTH,
-        DIALOG_MIN_WIDTH,
+        DialogCloseEvent, DialogConfirmEvent, DialogContent, DialogDivider, DialogHeadline,
+        DialogOpenEvent, DialogPlugin, DialogScrim, DialogType, MaterialDialog, SpawnDialogChild,
+        DIALOG_MAX_WIDTH, DIALOG_MIN_WIDTH, DIALOG_WINDOW_MARGIN,
     };
 
     // Date Picker
     pub use crate::date_picker::{
-        CalendarConstraints, Date, DateInputMode, DatePickerBuilder, DatePickerCancelEvent,
-        DatePickerMode, DatePickerSubmitEvent, DateSelection, DateSelector, DateValidator,
-        MaterialDatePicker, Month, RangeDateSelector, SingleDateSelector, SpawnDatePicker, Weekday,
+        CalendarConstraints, Date, DateInputMode, DateLocale, DatePickerBuilder,
+        DatePickerCancelEvent, DatePickerMode, DatePickerSubmitEvent, DateSelection, DateSelector,
+        DateValidator, MaterialDatePicker, Month, RangeDateSelector, SingleDateSelector,
+        SpawnDatePicker, Weekday,
     };
 
     // Time Picker
@@ -315,46 +381,60 @@ pub mod prelude {
         TimePickerBuilder, TimePickerCancelEvent, TimePickerSubmitEvent,
     };
 
+    // Date-Time Picker
+    pub use crate::datetime_picker::{
+        DateTime, DateTimePickerBuilder, DateTimePickerCancelEvent, DateTimePickerStage,
+        DateTimePickerSubmitEvent, MaterialDateTimePicker, SpawnDateTimePicker,
+    };
+
     // List
     pub use crate::list::{
-        create_list_divider, ListBuilder, ListDivider, ListItemBody, ListItemBuilder,
-        ListItemClickEvent, ListItemHeadline, ListItemLeading, ListItemSupportingText,
-        ListItemTrailing, ListItemVariant, ListPlugin, ListSelectionMode, MaterialList,
-        MaterialListItem, ScrollableList, SpawnListChild,
+        create_list_divider, create_list_section_header, reorder_target_index,
+        sticky_header_offsets, ListBuilder, ListDivider, ListItemActionEvent, ListItemBody,
+        ListItemBuilder, ListItemClickEvent, ListItemDragHandle, ListItemHeadline, ListItemLeading,
+        ListItemReflow, ListItemShortcut, ListItemSource, ListItemSupportingText,
+        ListItemSwipeAction, ListItemSwipeActions, ListItemSwipeBackground, ListItemSwipeSide,
+        ListItemTrailing, ListItemVariant, ListPlugin, ListReorderEvent, ListSectionHeader,
+        ListSectionHeaderText, ListSelectionChanged, ListSelectionMode, MaterialList,
+        MaterialListItem, ScrollableList, SpawnListChild, VirtualizedList, VirtualizedListBuilder,
+        VirtualizedListContent, VirtualizedListRow,
     };
 
     // Menu
     pub use crate::menu::{
-        create_menu_divider, MaterialMenu, MaterialMenuItem, MenuAnchor, MenuBuilder,
-        MenuCloseEvent, MenuDivider, MenuItemBuilder, MenuItemSelectEvent, MenuOpenEvent,
-        MenuPlugin, SpawnMenuChild, MENU_ITEM_HEIGHT, MENU_MAX_WIDTH, MENU_MIN_WIDTH,
+        create_menu_divider, resolve_menu_placement, MaterialMenu, MaterialMenuItem, MenuAnchor,
+        MenuBuilder, MenuCloseEvent, MenuDivider, MenuItemBuilder, MenuItemSelectEvent,
+        MenuItemShortcut, MenuItemToggle, MenuItemToggleIcon, MenuOpenEvent, MenuPlugin,
+        MenuSubmenuOpenEvent, SpawnMenuChild, MENU_ITEM_HEIGHT, MENU_MAX_WIDTH, MENU_MIN_WIDTH,
+        MENU_SUBMENU_DELAY,
     };
 
     // Tabs
     pub use crate::tabs::{
-        create_tab_indicator, MaterialTab, MaterialTabs, SpawnTabsChild, TabBuilder,
-        TabChangeEvent, TabContent, TabIndicator, TabLabelText, TabVariant, TabsBuilder,
-        TabsPlugin, TAB_HEIGHT_PRIMARY, TAB_HEIGHT_PRIMARY_ICON_ONLY, TAB_HEIGHT_SECONDARY,
-        TAB_INDICATOR_HEIGHT,
+        create_tab_indicator, MaterialTab, MaterialTabs, SpawnTabsChild, TabBadgedLabel,
+        TabBuilder, TabChangeEvent, TabContent, TabContentFade, TabIndicator, TabLabelText,
+        TabVariant, TabsBuilder, TabsPlugin, TAB_CONTENT_FADE_DURATION, TAB_HEIGHT_PRIMARY,
+        TAB_HEIGHT_PRIMARY_ICON_ONLY, TAB_HEIGHT_SECONDARY, TAB_INDICATOR_HEIGHT,
     };
 
     // Divider
     pub use crate::divider::{
-        horizontal_divider, inset_divider, vertical_divider, DividerBuilder, DividerVariant,
-        MaterialDivider, SpawnDividerChild, DIVIDER_INSET, DIVIDER_THICKNESS,
+        horizontal_divider, inset_divider, vertical_divider, DividerBuilder, DividerLabel,
+        DividerVariant, MaterialDivider, SpawnDividerChild, DIVIDER_INSET, DIVIDER_THICKNESS,
     };
 
     // Select
     pub use crate::select::{
-        MaterialSelect, SelectBuilder, SelectChangeEvent, SelectContainer, SelectDisplayText,
-        SelectDropdown, SelectOption, SelectOptionItem, SelectPlugin, SelectTrigger, SelectVariant,
-        SpawnSelectChild, SELECT_HEIGHT, SELECT_OPTION_HEIGHT,
+        resolve_select_dropdown_placement, MaterialSelect, SelectBuilder, SelectChangeEvent,
+        SelectContainer, SelectDisplayText, SelectDropdown, SelectDropdownDirection, SelectOption,
+        SelectOptionItem, SelectPlugin, SelectTrigger, SelectVariant, SpawnSelectChild,
+        SELECT_DROPDOWN_EDGE_MARGIN, SELECT_DROPDOWN_GAP, SELECT_HEIGHT, SELECT_OPTION_HEIGHT,
     };
 
     // Adaptive Layout
     pub use crate::adaptive::{
-        WindowHeightClass, WindowSizeClass, WindowSizeClassChanged, WindowSizeClassPlugin,
-        WindowWidthClass,
+        AdaptiveGrid, WindowHeightClass, WindowSizeClass, WindowSizeClassChanged,
+        WindowSizeClassPlugin, WindowWidthClass,
     };
 
     // Layout
@@ -365,14 +445,16 @@ pub mod prelude {
     // Search
     pub use crate::search::{
         MaterialSearchBar, SearchBarAction, SearchBarBuilder, SearchBarClickEvent,
-        SearchBarNavigation, SearchPlugin, SearchQueryEvent, SpawnSearchBarChild,
-        SEARCH_BAR_HEIGHT,
+        SearchBarNavigation, SearchPlugin, SearchQueryEvent, SearchSuggestion,
+        SearchSuggestionSelected, SearchSuggestions, SpawnSearchBarChild, UpdateSearchSuggestions,
+        SEARCH_BAR_HEIGHT, SEARCH_SUGGESTIONS_MAX_HEIGHT,
     };
 
     // Toolbar
     pub use crate::toolbar::{
         MaterialToolbar, SpawnToolbarChild, ToolbarAction, ToolbarActionEvent, ToolbarBuilder,
-        ToolbarNavigationEvent, ToolbarPlugin, TOOLBAR_HEIGHT, TOOLBAR_ICON_SIZE,
+        ToolbarNavigationEvent, ToolbarOverflow, ToolbarPlugin, TOOLBAR_HEIGHT, TOOLBAR_ICON_SIZE,
+        TOOLBAR_TITLE_MIN_WIDTH,
     };
 
     // Loading Indicator
@@ -381,56 +463,72 @@ pub mod prelude {
         ShapeMorphMaterial, SpawnLoadingIndicatorChild, LOADING_INDICATOR_SIZE,
     };
 
+    // Animation
+    pub use crate::animation::{
+        animate_spring, Animatable, AnimationBinding, AnimationComplete, AnimationPlugin,
+        AnimationRepeat, AnimationTargets, ContainerTransform, ContainerTransformComplete,
+        PropertySprings, RevealDirection, SpringProperty, SpringTarget, StaggeredReveal,
+        TriggerStaggeredReveal,
+    };
+
     // Motion
     pub use crate::motion::{
         ease_emphasized, ease_emphasized_accelerate, ease_emphasized_decelerate, ease_standard,
-        ease_standard_accelerate, ease_standard_decelerate, AnimatedValue, MotionPlugin,
-        SpringConfig, StateLayer,
+        ease_standard_accelerate, ease_standard_decelerate, AnimatedIcon, AnimatedIconLayer,
+        AnimatedValue, MotionPlugin, ReducedMotion, SpringConfig, StateLayer, StateLayerOverlay,
+        StateLayerSource,
     };
 
     // Snackbar
     pub use crate::snackbar::{
         spawn_snackbar, DismissSnackbar, ShowSnackbar, Snackbar, SnackbarActionEvent,
-        SnackbarAnimationState, SnackbarBuilder, SnackbarHostBuilder, SnackbarPlugin,
-        SnackbarPosition, SnackbarQueue, SpawnSnackbarChild, SNACKBAR_MAX_WIDTH,
+        SnackbarAnimationState, SnackbarBuilder, SnackbarHostBuilder, SnackbarInset,
+        SnackbarPlugin, SnackbarPosition, SnackbarPriority, SnackbarQueue, SpawnSnackbarChild,
+        SNACKBAR_MAX_WIDTH,
     };
 
     // Chip
     pub use crate::chip::{
-        ChipBuilder, ChipClickEvent, ChipDeleteButton, ChipDeleteEvent, ChipLabel, ChipLeadingIcon,
+        ChipBuilder, ChipClickEvent, ChipDeleteButton, ChipDeleteEvent, ChipGroup,
+        ChipGroupBuilder, ChipGroupChangeEvent, ChipGroupSelection, ChipLabel, ChipLeadingIcon,
         ChipPlugin, ChipVariant, MaterialChip, SpawnChipChild, CHIP_HEIGHT,
     };
 
     // App Bar
     pub use crate::app_bar::{
-        AppBarPlugin, BottomAppBar, BottomAppBarBuilder, SpawnAppBarChild, TopAppBar,
-        TopAppBarBuilder, TopAppBarVariant, BOTTOM_APP_BAR_HEIGHT, TOP_APP_BAR_HEIGHT_LARGE,
-        TOP_APP_BAR_HEIGHT_MEDIUM, TOP_APP_BAR_HEIGHT_SMALL,
+        AppBarAction, AppBarActionEvent, AppBarCollapsedTitle, AppBarLargeTitle,
+        AppBarNavigationEvent, AppBarOverflow, AppBarPlugin, AppBarScrollBehavior, BottomAppBar,
+        BottomAppBarBuilder, BottomAppBarEntities, BottomAppBarFabPosition, SpawnAppBarChild,
+        TopAppBar, TopAppBarBuilder, TopAppBarVariant, APP_BAR_TITLE_MIN_WIDTH,
+        BOTTOM_APP_BAR_HEIGHT, TOP_APP_BAR_HEIGHT_LARGE, TOP_APP_BAR_HEIGHT_MEDIUM,
+        TOP_APP_BAR_HEIGHT_SMALL,
     };
 
     // Badge
     pub use crate::badge::{
-        BadgeBuilder, BadgeContent, BadgePlugin, MaterialBadge, SpawnBadgeChild, BADGE_SIZE_LARGE,
-        BADGE_SIZE_SMALL,
+        spawn_badge, spawn_badge_on, AttachBadge, AttachedBadge, BadgeBuilder, BadgeContent,
+        BadgePlugin, MaterialBadge, SpawnBadgeChild, BADGE_SIZE_LARGE, BADGE_SIZE_SMALL,
     };
 
     // Tooltip
     pub use crate::tooltip::{
-        spawn_rich_tooltip, spawn_tooltip, RichTooltip, SpawnTooltipChild, Tooltip,
-        TooltipAnimationState, TooltipPlugin, TooltipPosition, TooltipText, TooltipTrigger,
-        TooltipTriggerBuilder, TooltipVariant, TOOLTIP_DELAY_DEFAULT, TOOLTIP_DELAY_SHORT,
+        resolve_tooltip_placement, spawn_rich_tooltip, spawn_tooltip, RichTooltip,
+        RichTooltipAction, RichTooltipActionButton, RichTooltipActionEvent, SpawnTooltipChild,
+        Tooltip, TooltipAnimationState, TooltipConfig, TooltipHint, TooltipPlugin, TooltipPosition,
+        TooltipText, TooltipTrigger, TooltipTriggerBuilder, TooltipVariant,
+        RICH_TOOLTIP_DISMISS_ACTION, TOOLTIP_DELAY_DEFAULT, TOOLTIP_DELAY_SHORT,
         TOOLTIP_HEIGHT_PLAIN, TOOLTIP_MAX_WIDTH, TOOLTIP_OFFSET,
     };
 
     // Scroll Container
     pub use crate::scroll::{
         spawn_scrollbars, ScrollContainer, ScrollContainerBuilder, ScrollContent, ScrollDirection,
-        ScrollPlugin, ScrollbarThumbHorizontal, ScrollbarThumbVertical, ScrollbarTrackHorizontal,
-        ScrollbarTrackVertical,
+        ScrollMomentum, ScrollPlugin, ScrollbarFade, ScrollbarStyle, ScrollbarThumbHorizontal,
+        ScrollbarThumbVertical, ScrollbarTrackHorizontal, ScrollbarTrackVertical,
     };
 
-    // Main plugin
-    pub use crate::MaterialUiPlugin;
+    // Main plugins
+    pub use crate::{MaterialUiCorePlugin, MaterialUiPlugin};
 }
 
 // ============================================================================
@@ -452,15 +550,38 @@ pub mod prelude {
 ///
 /// App::new()
 ///     .add_plugins(DefaultPlugins)
-///     .add_plugins(MaterialUiPlugin)
+///     .add_plugins(MaterialUiPlugin::default())
 ///     .run();
 /// ```
-pub struct MaterialUiPlugin;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaterialUiPlugin {
+    /// Start with [`motion::ReducedMotion`] enabled
+    pub reduced_motion: bool,
+}
+
+impl MaterialUiPlugin {
+    /// Make animations jump to their final state for motion-sensitive users.
+    ///
+    /// This only sets the initial value; the [`motion::ReducedMotion`] resource can be
+    /// changed at runtime.
+    pub fn with_reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+}
 
 impl Plugin for MaterialUiPlugin {
     fn build(&self, app: &mut App) {
-        // Core systems (theme, icons, focus, ripple).
-        app.add_plugins(MaterialUiCorePlugin);
+        // Core systems (theme, icons, focus, ripple, motion).
+        if !app.is_plugin_added::<MaterialUiCorePlugin>() {
+            app.add_plugins(MaterialUiCorePlugin);
+        }
+        if self.reduced_motion {
+            app.insert_resource(motion::ReducedMotion(true));
+        }
+
+        // Dialogs build on scrolling, so add it first.
+        app.add_plugins(scroll::ScrollPlugin);
 
         // Component plugins
         app.add_plugins((
@@ -486,15 +607,15 @@ impl Plugin for MaterialUiPlugin {
 
         // New component plugins
         app.add_plugins((
-            motion::MotionPlugin,
             snackbar::SnackbarPlugin,
             chip::ChipPlugin,
             app_bar::AppBarPlugin,
             badge::BadgePlugin,
             tooltip::TooltipPlugin,
-            scroll::ScrollPlugin,
             date_picker::DatePickerPlugin,
             time_picker::TimePickerPlugin,
+            datetime_picker::DateTimePickerPlugin,
+            autocomplete::AutocompletePlugin,
             search::SearchPlugin,
             toolbar::ToolbarPlugin,
             loading_indicator::LoadingIndicatorPlugin,
@@ -502,21 +623,54 @@ impl Plugin for MaterialUiPlugin {
 
         // Adaptive layout
         app.add_plugins(adaptive::WindowSizeClassPlugin);
+        app.add_plugins((
+            layout::NavigationSuitePlugin,
+            layout::ListDetailPlugin,
+            layout::NavigationRailPlugin,
+            layout::ScaffoldInsetPlugin,
+        ));
     }
 }
 
 /// Core plugin that provides the shared foundations required by most components.
 ///
-/// This is intended to be used as an internal dependency for component plugins
-/// so downstream users can do either of:
-/// - `app.add_plugins(MaterialUiPlugin)` (everything)
+/// It sets up the theme, typography, locale, i18n, focus, overlays, disabled
+/// state, elevation, ripples, icons, and motion. Every component plugin adds it
+/// if missing, so downstream users can do any of:
+/// - `app.add_plugins(MaterialUiPlugin::default())` (everything)
 /// - `app.add_plugins(ButtonPlugin)` (single component; core dependencies auto-added)
+/// - add core plus only the component plugins the app uses
+///
+/// Plugins that build on other components (autocomplete, the date-time picker)
+/// add those plugins too. Add such dependencies before the plugin that needs
+/// them, or leave them out and let it add them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_material_ui::prelude::*;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins((MaterialUiCorePlugin, TextFieldPlugin, DialogPlugin))
+///     .run();
+/// ```
 pub struct MaterialUiCorePlugin;
 
 impl Plugin for MaterialUiCorePlugin {
     fn build(&self, app: &mut App) {
         // Theme is a resource; initializing it is idempotent.
         app.init_resource::<theme::MaterialTheme>();
+        app.add_message::<theme::SetTheme>()
+            .add_systems(PreUpdate, theme::apply_set_theme_system);
+
+        // Typography is a resource; text tagged with a `TypeRole` follows it.
+        app.init_resource::<typography::Typography>()
+            .add_systems(Update, typography::typography_text_system);
+
+        // Dividers built with `DividerBuilder::auto` follow their parent's direction.
+        app.add_systems(Update, divider::divider_auto_orientation_system);
 
         // Locale is a resource; initializing it is idempotent.
         app.init_resource::<locale::MaterialLocale>();
@@ -530,12 +684,33 @@ impl Plugin for MaterialUiCorePlugin {
         if !app.is_plugin_added::<focus::FocusPlugin>() {
             app.add_plugins(focus::FocusPlugin);
         }
+        if !app.is_plugin_added::<overlay::OverlayPlugin>() {
+            app.add_plugins(overlay::OverlayPlugin);
+        }
+        if !app.is_plugin_added::<disabled::DisabledPlugin>() {
+            app.add_plugins(disabled::DisabledPlugin);
+        }
+        if !app.is_plugin_added::<control_label::ControlLabelPlugin>() {
+            app.add_plugins(control_label::ControlLabelPlugin);
+        }
+        if !app.is_plugin_added::<title_overflow::TitleOverflowPlugin>() {
+            app.add_plugins(title_overflow::TitleOverflowPlugin);
+        }
+        if !app.is_plugin_added::<elevation::ElevationPlugin>() {
+            app.add_plugins(elevation::ElevationPlugin);
+        }
         if !app.is_plugin_added::<ripple::RipplePlugin>() {
             app.add_plugins(ripple::RipplePlugin);
         }
         if !app.is_plugin_added::<icons::MaterialIconsPlugin>() {
             app.add_plugins(icons::MaterialIconsPlugin);
         }
+        if !app.is_plugin_added::<motion::MotionPlugin>() {
+            app.add_plugins(motion::MotionPlugin);
+        }
+        if !app.is_plugin_added::<animation::AnimationPlugin>() {
+            app.add_plugins(animation::AnimationPlugin);
+        }
     }
 }
 
@@ -545,6 +720,6 @@ pub struct MaterialUiPlugins;
 
 impl PluginGroup for MaterialUiPlugins {
     fn build(self) -> bevy::app::PluginGroupBuilder {
-        bevy::app::PluginGroupBuilder::start::<Self>().add(MaterialUiPlugin)
+        bevy::app::PluginGroupBuilder::start::<Self>().add(MaterialUiPlugin::default())
     }
 }
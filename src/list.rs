@@ -4,14 +4,24 @@
 //! Reference: <https://m3.material.io/components/lists/overview>
 
 use bevy::prelude::*;
-use bevy::ui::ScrollPosition;
+use bevy::ui::{ScrollPosition, UiGlobalTransform};
+use bevy::window::PrimaryWindow;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
 
+use crate::motion::ease_standard;
 use crate::{
+    disabled::{sync_disabled_system, DisabledSource},
+    elevation::Elevation,
+    focus::adopt_focus_group_system,
     icons::{icon_by_name, IconStyle, MaterialIcon},
+    motion::{sync_state_layer_system, StateLayer, StateLayerSource},
     ripple::RippleHost,
-    scroll::ScrollContainerBuilder,
-    theme::{blend_state_layer, MaterialTheme},
-    tokens::Spacing,
+    scroll::{ScrollContainerBuilder, ScrollContent},
+    theme::MaterialTheme,
+    tokens::{Duration, Opacity, Spacing},
+    typography::TypeRole,
 };
 
 /// Maximum depth to traverse when searching for ancestor entities.
@@ -35,15 +45,37 @@ impl Plugin for ListPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
-        app.add_message::<ListItemClickEvent>().add_systems(
-            Update,
-            (
-                list_item_interaction_system,
-                list_selection_system,
-                list_item_style_system,
-                list_item_text_style_system,
-            ),
-        );
+        app.add_message::<ListItemClickEvent>()
+            .add_message::<ListSelectionChanged>()
+            .add_message::<ListReorderEvent>()
+            .add_message::<ListItemActionEvent>()
+            .add_systems(
+                Update,
+                (
+                    list_item_interaction_system,
+                    list_selection_system,
+                    list_item_style_system,
+                    list_item_text_style_system,
+                    sync_state_layer_system::<MaterialListItem>,
+                    sync_disabled_system::<MaterialListItem>,
+                    adopt_focus_group_system::<MaterialList, MaterialListItem>,
+                    (virtualized_list_setup_system, virtualized_list_system).chain(),
+                    list_section_header_sticky_system,
+                    (
+                        list_reorder_start_system,
+                        list_reorder_drag_system,
+                        list_item_reflow_system,
+                    )
+                        .chain(),
+                    list_section_header_theme_system,
+                    (
+                        list_item_swipe_setup_system,
+                        list_item_swipe_drag_system,
+                        list_item_swipe_visual_system,
+                    )
+                        .chain(),
+                ),
+            );
     }
 }
 
@@ -55,8 +87,8 @@ pub enum ListSelectionMode {
     None,
     /// Exactly one item is selected at a time.
     Single,
-    /// Multiple items may be selected.
-    Multi,
+    /// Clicking toggles an item; Shift+click selects a contiguous range.
+    Multiple,
 }
 
 /// List item variants based on content
@@ -86,6 +118,14 @@ impl ListItemVariant {
 #[derive(Component, Default)]
 pub struct MaterialList {
     pub selection_mode: ListSelectionMode,
+    /// Selected items, in selection order
+    pub selected: Vec<Entity>,
+    /// Item that anchors Shift+click range selection
+    anchor: Option<Entity>,
+    /// Whether items can be reordered with their drag handle
+    pub reorderable: bool,
+    /// Active reorder drag, if any
+    reorder_drag: Option<ListReorderDrag>,
 }
 
 impl MaterialList {
@@ -93,9 +133,87 @@ impl MaterialList {
     pub fn new() -> Self {
         Self {
             selection_mode: ListSelectionMode::None,
+            selected: Vec::new(),
+            anchor: None,
+            reorderable: false,
+            reorder_drag: None,
+        }
+    }
+
+    /// Allow reordering items by dragging their handle
+    pub fn with_reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Whether an item is currently being dragged
+    pub fn is_reordering(&self) -> bool {
+        self.reorder_drag.is_some()
+    }
+
+    /// Whether `item` is selected
+    pub fn is_selected(&self, item: Entity) -> bool {
+        self.selected.contains(&item)
+    }
+
+    /// Clear the selection
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Reconcile the stored selection with the items currently marked selected
+    ///
+    /// Keeps the existing selection order and appends newly selected items.
+    pub fn sync_selection(&mut self, selected_items: &[Entity]) {
+        if self.selected.len() == selected_items.len()
+            && self.selected.iter().all(|e| selected_items.contains(e))
+        {
+            return;
+        }
+        self.selected.retain(|e| selected_items.contains(e));
+        for item in selected_items {
+            if !self.selected.contains(item) {
+                self.selected.push(*item);
+            }
+        }
+        if self.anchor.is_some_and(|a| !self.selected.contains(&a)) {
+            self.anchor = self.selected.last().copied();
         }
     }
 
+    /// Apply a click on `item` given the list's items in display order
+    ///
+    /// Returns `true` if the selection changed.
+    pub fn click(&mut self, item: Entity, order: &[Entity], extend_range: bool) -> bool {
+        let previous = self.selected.clone();
+        match self.selection_mode {
+            ListSelectionMode::None => return false,
+            ListSelectionMode::Single => {
+                self.selected = vec![item];
+                self.anchor = Some(item);
+            }
+            ListSelectionMode::Multiple => {
+                let range = self
+                    .anchor
+                    .filter(|_| extend_range)
+                    .and_then(|anchor| selection_range(order, anchor, item));
+                match range {
+                    Some(range) => self.selected = range,
+                    None => {
+                        if let Some(pos) = self.selected.iter().position(|e| *e == item) {
+                            self.selected.remove(pos);
+                        } else {
+                            self.selected.push(item);
+                        }
+                        self.anchor = Some(item);
+                    }
+                }
+            }
+        }
+        self.selected != previous
+    }
+
     pub fn with_selection_mode(mut self, mode: ListSelectionMode) -> Self {
         self.selection_mode = mode;
         self
@@ -103,7 +221,7 @@ impl MaterialList {
 }
 
 /// Material list item component
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct MaterialListItem {
     /// Item variant
     pub variant: ListItemVariant,
@@ -117,6 +235,8 @@ pub struct MaterialListItem {
     pub supporting_text: Option<String>,
     /// Trailing supporting text
     pub trailing_text: Option<String>,
+    /// Trailing keyboard shortcut hint, shown before the trailing content
+    pub shortcut: Option<String>,
     /// Leading icon
     pub leading_icon: Option<String>,
     /// Trailing icon
@@ -125,6 +245,8 @@ pub struct MaterialListItem {
     pub leading_avatar: Option<String>,
     /// Leading video thumbnail URL
     pub leading_video: Option<String>,
+    /// Show a trailing drag handle for reordering
+    pub drag_handle: bool,
     /// Interaction states
     pub pressed: bool,
     pub hovered: bool,
@@ -140,10 +262,12 @@ impl MaterialListItem {
             headline: headline.into(),
             supporting_text: None,
             trailing_text: None,
+            shortcut: None,
             leading_icon: None,
             trailing_icon: None,
             leading_avatar: None,
             leading_video: None,
+            drag_handle: false,
             pressed: false,
             hovered: false,
         }
@@ -167,6 +291,12 @@ impl MaterialListItem {
         self
     }
 
+    /// Set a keyboard shortcut hint (e.g. "Ctrl+C")
+    pub fn shortcut(mut self, text: impl Into<String>) -> Self {
+        self.shortcut = Some(text.into());
+        self
+    }
+
     /// Set leading icon
     pub fn leading_icon(mut self, icon: impl Into<String>) -> Self {
         self.leading_icon = Some(icon.into());
@@ -197,32 +327,21 @@ impl MaterialListItem {
         self
     }
 
-    /// Get the background color with state layer applied
+    /// Get the background color
+    ///
+    /// Hover/pressed feedback is drawn by the item's `StateLayer` overlay.
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
-        let base = if self.selected {
+        if self.selected {
             theme.secondary_container
         } else {
             Color::NONE
-        };
-
-        // Apply state layer
-        let state_opacity = self.state_layer_opacity();
-        if state_opacity > 0.0 {
-            let state_color = theme.on_surface;
-            if base == Color::NONE {
-                state_color.with_alpha(state_opacity)
-            } else {
-                blend_state_layer(base, state_color, state_opacity)
-            }
-        } else {
-            base
         }
     }
 
     /// Get the headline color
     pub fn headline_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            theme.on_surface.with_alpha(0.38)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
         } else {
             theme.on_surface
         }
@@ -231,7 +350,7 @@ impl MaterialListItem {
     /// Get the supporting text color
     pub fn supporting_text_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            theme.on_surface.with_alpha(0.38)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
         } else {
             theme.on_surface_variant
         }
@@ -240,7 +359,7 @@ impl MaterialListItem {
     /// Get the icon color
     pub fn icon_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            theme.on_surface.with_alpha(0.38)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
         } else {
             theme.on_surface_variant
         }
@@ -260,26 +379,376 @@ impl MaterialListItem {
     }
 }
 
+impl DisabledSource for MaterialListItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl StateLayerSource for MaterialListItem {
+    fn state_layer_color(&self, theme: &MaterialTheme) -> Color {
+        theme.on_surface
+    }
+
+    fn state_layer_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when list item is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct ListItemClickEvent {
     pub entity: Entity,
 }
 
+/// Event when a swipe action on a list item is committed
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct ListItemActionEvent {
+    /// The list item that was swiped
+    pub item: Entity,
+    /// ID of the committed [`ListItemSwipeAction`]
+    pub action_id: String,
+}
+
+/// Default fraction of the item width that must be swiped to commit an action
+pub const LIST_ITEM_SWIPE_THRESHOLD: f32 = 0.4;
+
+/// Rate at which a released item springs back to rest (per second)
+const LIST_ITEM_SWIPE_SPRING: f32 = 18.0;
+
+/// An action revealed by swiping a list item
+#[derive(Debug, Clone)]
+pub struct ListItemSwipeAction {
+    /// Identifier reported in [`ListItemActionEvent`]
+    pub id: String,
+    /// Optional icon name shown on the revealed background
+    pub icon: Option<String>,
+    /// Background color revealed behind the item
+    pub color: Color,
+    /// Icon color
+    pub content_color: Color,
+}
+
+impl ListItemSwipeAction {
+    /// Create a new swipe action
+    pub fn new(id: impl Into<String>, color: Color) -> Self {
+        Self {
+            id: id.into(),
+            icon: None,
+            color,
+            content_color: Color::WHITE,
+        }
+    }
+
+    /// Set the icon
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the icon color
+    pub fn content_color(mut self, color: Color) -> Self {
+        self.content_color = color;
+        self
+    }
+}
+
+/// Swipe-to-reveal actions for a [`MaterialListItem`]
+///
+/// Dragging the item right reveals the leading action, dragging it left reveals the
+/// trailing action. Releasing past `threshold` emits a [`ListItemActionEvent`]; the item
+/// springs back to rest either way.
+#[derive(Component, Debug, Clone)]
+pub struct ListItemSwipeActions {
+    /// Action revealed when swiping towards the end (right)
+    pub leading: Option<ListItemSwipeAction>,
+    /// Action revealed when swiping towards the start (left)
+    pub trailing: Option<ListItemSwipeAction>,
+    /// Fraction of the item width that must be swiped to commit
+    pub threshold: f32,
+    /// Current horizontal offset in logical pixels
+    pub offset: f32,
+    /// Whether the item is being dragged
+    pub dragging: bool,
+    /// Cursor x minus offset at drag start
+    drag_origin: f32,
+}
+
+impl Default for ListItemSwipeActions {
+    fn default() -> Self {
+        Self {
+            leading: None,
+            trailing: None,
+            threshold: LIST_ITEM_SWIPE_THRESHOLD,
+            offset: 0.0,
+            dragging: false,
+            drag_origin: 0.0,
+        }
+    }
+}
+
+impl ListItemSwipeActions {
+    /// Create swipe actions with no actions configured
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the action revealed when swiping right
+    pub fn leading(mut self, action: ListItemSwipeAction) -> Self {
+        self.leading = Some(action);
+        self
+    }
+
+    /// Set the action revealed when swiping left
+    pub fn trailing(mut self, action: ListItemSwipeAction) -> Self {
+        self.trailing = Some(action);
+        self
+    }
+
+    /// Set the commit threshold as a fraction of the item width
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Clamp an offset to the directions that have an action and to the item width
+    pub fn clamp_offset(&self, offset: f32, width: f32) -> f32 {
+        let min = if self.trailing.is_some() { -width } else { 0.0 };
+        let max = if self.leading.is_some() { width } else { 0.0 };
+        offset.clamp(min, max)
+    }
+
+    /// The action that would be committed if released at the current offset
+    pub fn committed_action(&self, width: f32) -> Option<&ListItemSwipeAction> {
+        if width <= 0.0 || self.offset.abs() < self.threshold * width {
+            return None;
+        }
+        if self.offset > 0.0 {
+            self.leading.as_ref()
+        } else {
+            self.trailing.as_ref()
+        }
+    }
+}
+
+/// Which side of a swiped item a background belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListItemSwipeSide {
+    /// Revealed when swiping right
+    Leading,
+    /// Revealed when swiping left
+    Trailing,
+}
+
+/// Colored background revealed behind a swiped list item
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ListItemSwipeBackground {
+    pub side: ListItemSwipeSide,
+}
+
+/// Spawn the revealed action backgrounds for newly added swipe actions
+fn list_item_swipe_setup_system(
+    mut commands: Commands,
+    items: Query<(Entity, &ListItemSwipeActions), Added<ListItemSwipeActions>>,
+) {
+    for (entity, swipe) in items.iter() {
+        for (side, action) in [
+            (ListItemSwipeSide::Leading, swipe.leading.as_ref()),
+            (ListItemSwipeSide::Trailing, swipe.trailing.as_ref()),
+        ] {
+            let Some(action) = action else { continue };
+
+            let justify_content = match side {
+                ListItemSwipeSide::Leading => JustifyContent::FlexStart,
+                ListItemSwipeSide::Trailing => JustifyContent::FlexEnd,
+            };
+            let background = commands
+                .spawn((
+                    ListItemSwipeBackground { side },
+                    Pickable::IGNORE,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(0.0),
+                        bottom: Val::Px(0.0),
+                        width: Val::Px(0.0),
+                        padding: UiRect::horizontal(Val::Px(Spacing::LARGE)),
+                        justify_content,
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(action.color),
+                ))
+                .id();
+
+            if let Some(icon_id) = action.icon.as_deref().and_then(resolve_icon_id) {
+                commands.entity(background).with_child((
+                    MaterialIcon::new(icon_id),
+                    IconStyle::outlined()
+                        .with_color(action.content_color)
+                        .with_size(24.0),
+                ));
+            }
+
+            commands.entity(entity).add_child(background);
+        }
+    }
+}
+
+/// Drag swipeable list items horizontally and commit actions on release
+fn list_item_swipe_drag_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut items: Query<(
+        Entity,
+        &Interaction,
+        &MaterialListItem,
+        &mut ListItemSwipeActions,
+        &ComputedNode,
+    )>,
+    mut action_events: MessageWriter<ListItemActionEvent>,
+) {
+    let cursor_x = windows
+        .single()
+        .ok()
+        .and_then(|w| w.cursor_position())
+        .map(|c| c.x);
+    let dt = time.delta_secs();
+
+    for (entity, interaction, item, mut swipe, computed) in items.iter_mut() {
+        let width = computed.size().x * computed.inverse_scale_factor();
+
+        if !swipe.dragging
+            && !item.disabled
+            && *interaction == Interaction::Pressed
+            && mouse.just_pressed(MouseButton::Left)
+        {
+            if let Some(x) = cursor_x {
+                swipe.dragging = true;
+                swipe.drag_origin = x - swipe.offset;
+            }
+        }
+
+        if swipe.dragging {
+            if mouse.pressed(MouseButton::Left) {
+                if let Some(x) = cursor_x {
+                    let offset = swipe.clamp_offset(x - swipe.drag_origin, width);
+                    if swipe.offset != offset {
+                        swipe.offset = offset;
+                    }
+                }
+                continue;
+            }
+
+            swipe.dragging = false;
+            if let Some(action) = swipe.committed_action(width) {
+                action_events.write(ListItemActionEvent {
+                    item: entity,
+                    action_id: action.id.clone(),
+                });
+            }
+        }
+
+        // Spring back to rest
+        if swipe.offset != 0.0 {
+            let t = 1.0 - (-LIST_ITEM_SWIPE_SPRING * dt).exp();
+            let offset = swipe.offset * (1.0 - t);
+            swipe.offset = if offset.abs() < 0.5 { 0.0 } else { offset };
+        }
+    }
+}
+
+/// Apply the swipe offset to the item and size the revealed backgrounds
+fn list_item_swipe_visual_system(
+    mut items: Query<
+        (&ListItemSwipeActions, &Children, &mut Node),
+        (
+            Changed<ListItemSwipeActions>,
+            Without<ListItemSwipeBackground>,
+        ),
+    >,
+    mut backgrounds: Query<(&ListItemSwipeBackground, &mut Node)>,
+) {
+    for (swipe, children, mut node) in items.iter_mut() {
+        node.left = Val::Px(swipe.offset);
+
+        for child in children.iter() {
+            let Ok((background, mut bg_node)) = backgrounds.get_mut(child) else {
+                continue;
+            };
+
+            // Backgrounds sit just outside the item, filling the space it vacated.
+            match background.side {
+                ListItemSwipeSide::Leading => {
+                    bg_node.right = Val::Percent(100.0);
+                    bg_node.width = Val::Px(swipe.offset.max(0.0));
+                }
+                ListItemSwipeSide::Trailing => {
+                    bg_node.left = Val::Percent(100.0);
+                    bg_node.width = Val::Px((-swipe.offset).max(0.0));
+                }
+            }
+        }
+    }
+}
+
+/// Event when a list's selection changes
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct ListSelectionChanged {
+    /// The list whose selection changed
+    pub list: Entity,
+    /// Selected items, in selection order
+    pub selected: Vec<Entity>,
+}
+
+/// Items between `anchor` and `item` (inclusive) in display order
+fn selection_range(order: &[Entity], anchor: Entity, item: Entity) -> Option<Vec<Entity>> {
+    let a = order.iter().position(|e| *e == anchor)?;
+    let b = order.iter().position(|e| *e == item)?;
+    let (start, end) = if a <= b { (a, b) } else { (b, a) };
+    Some(order[start..=end].to_vec())
+}
+
+/// List items under `list` in display (depth-first, child) order
+fn list_items_in_order(
+    list: Entity,
+    children_query: &Query<&Children>,
+    is_item: impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    let mut order = Vec::new();
+    let mut stack = vec![list];
+    while let Some(node) = stack.pop() {
+        if let Ok(children) = children_query.get(node) {
+            for child in children.iter().rev() {
+                stack.push(child);
+            }
+        }
+        if node != list && is_item(node) {
+            order.push(node);
+        }
+    }
+    order
+}
+
 fn list_selection_system(
     mut click_events: MessageReader<ListItemClickEvent>,
+    mut selection_events: MessageWriter<ListSelectionChanged>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
     parents: Query<&ChildOf>,
-    lists: Query<&MaterialList>,
+    mut lists: Query<&mut MaterialList>,
     children_query: Query<&Children>,
     mut items: Query<&mut MaterialListItem>,
 ) {
+    let shift = keys.is_some_and(|k| k.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]));
+
     for event in click_events.read() {
         // Find the nearest ancestor that is a MaterialList.
         let mut current = Some(event.entity);
         let mut list_entity = None;
         for _ in 0..MAX_ANCESTOR_DEPTH {
             let Some(e) = current else { break };
-            if lists.get(e).is_ok() {
+            if lists.contains(e) {
                 list_entity = Some(e);
                 break;
             }
@@ -289,38 +758,37 @@ fn list_selection_system(
         let Some(list_entity) = list_entity else {
             continue;
         };
-        let Ok(list) = lists.get(list_entity) else {
+        let Ok(mut list) = lists.get_mut(list_entity) else {
             continue;
         };
 
-        match list.selection_mode {
-            ListSelectionMode::None => {}
-            ListSelectionMode::Multi => {
-                if let Ok(mut clicked) = items.get_mut(event.entity) {
-                    clicked.selected = !clicked.selected;
-                }
-            }
-            ListSelectionMode::Single => {
-                // Select the clicked item and clear any other selected items in this list.
-                // Traverse the list subtree to support wrappers (e.g., scroll content).
-                let mut stack: Vec<Entity> = vec![list_entity];
-                while let Some(node) = stack.pop() {
-                    if let Ok(children) = children_query.get(node) {
-                        for child in children.iter() {
-                            if let Ok(mut item) = items.get_mut(child) {
-                                item.selected = child == event.entity;
-                            }
-                            stack.push(child);
-                        }
-                    }
-                }
+        let order = list_items_in_order(list_entity, &children_query, |e| items.contains(e));
+
+        // Honor selection changes made directly on items (e.g. `ListItemBuilder::selected`).
+        let item_selected: Vec<Entity> = order
+            .iter()
+            .copied()
+            .filter(|e| items.get(*e).is_ok_and(|item| item.selected))
+            .collect();
+        list.sync_selection(&item_selected);
+
+        if !list.click(event.entity, &order, shift) {
+            continue;
+        }
 
-                // If the clicked entity isn't under the list (unexpected), still force it selected.
-                if let Ok(mut clicked) = items.get_mut(event.entity) {
-                    clicked.selected = true;
+        for entity in order {
+            if let Ok(mut item) = items.get_mut(entity) {
+                let selected = list.is_selected(entity);
+                if item.selected != selected {
+                    item.selected = selected;
                 }
             }
         }
+
+        selection_events.write(ListSelectionChanged {
+            list: list_entity,
+            selected: list.selected.clone(),
+        });
     }
 }
 
@@ -358,12 +826,15 @@ fn list_item_interaction_system(
 /// System to update list item styles
 fn list_item_style_system(
     theme: Option<Res<MaterialTheme>>,
-    mut items: Query<(&MaterialListItem, &mut BackgroundColor), Changed<MaterialListItem>>,
+    mut items: Query<(Ref<MaterialListItem>, &mut BackgroundColor)>,
 ) {
     let Some(theme) = theme else { return };
+    let theme_changed = theme.is_changed();
 
     for (item, mut bg_color) in items.iter_mut() {
-        *bg_color = BackgroundColor(item.background_color(&theme));
+        if theme_changed || item.is_changed() {
+            *bg_color = BackgroundColor(item.background_color(&theme));
+        }
     }
 }
 
@@ -416,6 +887,8 @@ pub struct ListBuilder {
     show_scrollbar: bool,
     /// Selection behavior
     selection_mode: ListSelectionMode,
+    /// Whether items can be reordered
+    reorderable: bool,
 }
 
 impl ListBuilder {
@@ -425,9 +898,16 @@ impl ListBuilder {
             max_height: None,
             show_scrollbar: true,
             selection_mode: ListSelectionMode::None,
+            reorderable: false,
         }
     }
 
+    /// Allow reordering items by dragging their handle
+    pub fn reorderable(mut self) -> Self {
+        self.reorderable = true;
+        self
+    }
+
     /// Set list selection behavior.
     pub fn selection_mode(mut self, mode: ListSelectionMode) -> Self {
         self.selection_mode = mode;
@@ -459,10 +939,22 @@ impl ListBuilder {
         self
     }
 
+    /// Spawn only the visible rows, recycling them while scrolling
+    ///
+    /// All rows must share the same `item_height`.
+    pub fn virtualized(self, item_height: f32) -> VirtualizedListBuilder {
+        VirtualizedListBuilder {
+            list: self,
+            virtualized: VirtualizedList::new(item_height),
+        }
+    }
+
     /// Build the list bundle (non-scrollable)
     pub fn build(self) -> impl Bundle {
         (
-            MaterialList::new().with_selection_mode(self.selection_mode),
+            MaterialList::new()
+                .with_selection_mode(self.selection_mode)
+                .with_reorderable(self.reorderable),
             Node {
                 flex_direction: FlexDirection::Column,
                 width: Val::Percent(100.0),
@@ -477,7 +969,9 @@ impl ListBuilder {
     pub fn build_scrollable(self) -> impl Bundle {
         let height = self.max_height.map(Val::Px).unwrap_or(Val::Auto);
         (
-            MaterialList::new().with_selection_mode(self.selection_mode),
+            MaterialList::new()
+                .with_selection_mode(self.selection_mode)
+                .with_reorderable(self.reorderable),
             ScrollableList,
             ScrollContainerBuilder::new()
                 .vertical()
@@ -506,6 +1000,143 @@ impl ListBuilder {
 #[derive(Component)]
 pub struct ScrollableList;
 
+/// Data source for virtualized lists: builds the item at a given index
+pub type ListItemSource = Arc<dyn Fn(usize) -> ListItemBuilder + Send + Sync>;
+
+/// Default number of extra rows kept spawned above and below the viewport
+pub const VIRTUALIZED_LIST_OVERSCAN: usize = 3;
+
+/// Virtualized list state
+///
+/// Only the rows intersecting the viewport (plus `overscan` rows on each side) are spawned.
+/// Rows that scroll out of view are recycled for newly visible indices, so item state such as
+/// `selected` should live in the data source rather than on the spawned entities.
+#[derive(Component, Clone)]
+pub struct VirtualizedList {
+    /// Fixed height of every row in logical pixels
+    pub item_height: f32,
+    /// Total number of items in the data source
+    pub item_count: usize,
+    /// Extra rows spawned above and below the viewport
+    pub overscan: usize,
+    source: Option<ListItemSource>,
+    content: Option<Entity>,
+    rows: HashMap<usize, Entity>,
+    spare: Vec<Entity>,
+    range: Range<usize>,
+    dirty: bool,
+}
+
+impl VirtualizedList {
+    /// Create a virtualized list with a fixed row height
+    pub fn new(item_height: f32) -> Self {
+        Self {
+            item_height: item_height.max(1.0),
+            item_count: 0,
+            overscan: VIRTUALIZED_LIST_OVERSCAN,
+            source: None,
+            content: None,
+            rows: HashMap::new(),
+            spare: Vec::new(),
+            range: 0..0,
+            dirty: true,
+        }
+    }
+
+    /// Set the data source
+    pub fn with_source(
+        mut self,
+        item_count: usize,
+        source: impl Fn(usize) -> ListItemBuilder + Send + Sync + 'static,
+    ) -> Self {
+        self.item_count = item_count;
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// Set the number of overscan rows
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Change the item count and rebuild visible rows
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.dirty = true;
+    }
+
+    /// Rebuild visible rows from the data source on the next update
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Currently spawned index range
+    pub fn spawned_range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Row entity currently bound to `index`, if spawned
+    pub fn row(&self, index: usize) -> Option<Entity> {
+        self.rows.get(&index).copied()
+    }
+
+    /// Total content height in logical pixels
+    pub fn content_height(&self) -> f32 {
+        self.item_count as f32 * self.item_height
+    }
+
+    /// Index range to spawn for a scroll offset and viewport height (logical pixels)
+    pub fn visible_range(&self, scroll_y: f32, viewport_height: f32) -> Range<usize> {
+        let first = (scroll_y.max(0.0) / self.item_height).floor() as usize;
+        let last =
+            ((scroll_y.max(0.0) + viewport_height.max(0.0)) / self.item_height).ceil() as usize;
+        let start = first.saturating_sub(self.overscan).min(self.item_count);
+        let end = (last + self.overscan).min(self.item_count);
+        start..end
+    }
+}
+
+/// Container holding the rows of a [`VirtualizedList`], sized to the full content height
+#[derive(Component)]
+pub struct VirtualizedListContent;
+
+/// Row spawned by a [`VirtualizedList`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualizedListRow {
+    /// Data-source index this row currently shows
+    pub index: usize,
+}
+
+/// Builder for virtualized lists, created by [`ListBuilder::virtualized`]
+pub struct VirtualizedListBuilder {
+    list: ListBuilder,
+    virtualized: VirtualizedList,
+}
+
+impl VirtualizedListBuilder {
+    /// Set the data source used to build each row
+    pub fn item_source(
+        mut self,
+        item_count: usize,
+        source: impl Fn(usize) -> ListItemBuilder + Send + Sync + 'static,
+    ) -> Self {
+        self.virtualized = self.virtualized.with_source(item_count, source);
+        self
+    }
+
+    /// Set the number of overscan rows
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.virtualized.overscan = overscan;
+        self
+    }
+
+    /// Build the scrollable, virtualized list bundle
+    pub fn build(self) -> impl Bundle {
+        (self.list.build_scrollable(), self.virtualized)
+    }
+}
+
 impl Default for ListBuilder {
     fn default() -> Self {
         Self::new()
@@ -558,6 +1189,12 @@ impl ListItemBuilder {
         self
     }
 
+    /// Set a keyboard shortcut hint (e.g. "Ctrl+C")
+    pub fn shortcut(mut self, text: impl Into<String>) -> Self {
+        self.item.shortcut = Some(text.into());
+        self
+    }
+
     /// Set leading icon
     pub fn leading_icon(mut self, icon: impl Into<String>) -> Self {
         self.item.leading_icon = Some(icon.into());
@@ -582,6 +1219,12 @@ impl ListItemBuilder {
         self
     }
 
+    /// Show a trailing drag handle (requires a reorderable list)
+    pub fn drag_handle(mut self, drag_handle: bool) -> Self {
+        self.item.drag_handle = drag_handle;
+        self
+    }
+
     /// Set selected
     pub fn selected(mut self, selected: bool) -> Self {
         self.item.selected = selected;
@@ -597,6 +1240,7 @@ impl ListItemBuilder {
             self.item,
             Button,
             RippleHost::new(),
+            StateLayer::new(theme.on_surface),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Px(height),
@@ -627,6 +1271,10 @@ pub struct ListItemHeadline;
 #[derive(Component)]
 pub struct ListItemSupportingText;
 
+/// Marker for the keyboard shortcut hint of a list item
+#[derive(Component)]
+pub struct ListItemShortcut;
+
 /// Marker for trailing content area
 #[derive(Component)]
 pub struct ListItemTrailing;
@@ -653,6 +1301,445 @@ pub fn create_list_divider(theme: &MaterialTheme, inset: bool) -> impl Bundle {
     )
 }
 
+/// Drag handle that reorders its list item within a reorderable [`MaterialList`]
+#[derive(Component)]
+pub struct ListItemDragHandle;
+
+/// Event when an item of a reorderable list is dropped at a new index
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct ListReorderEvent {
+    /// The list that was reordered
+    pub list: Entity,
+    /// Original index of the dragged item
+    pub from: usize,
+    /// Index the item was dropped at
+    pub to: usize,
+}
+
+/// Distance from the list edge (logical pixels) where dragging auto-scrolls
+const LIST_REORDER_EDGE: f32 = 48.0;
+
+/// Maximum auto-scroll speed while dragging (logical pixels per second)
+const LIST_REORDER_SCROLL_SPEED: f32 = 600.0;
+
+/// In-progress reorder drag
+#[derive(Debug, Clone)]
+struct ListReorderDrag {
+    item: Entity,
+    from: usize,
+    to: usize,
+    order: Vec<Entity>,
+    tops: Vec<f32>,
+    heights: Vec<f32>,
+    start_cursor_y: f32,
+    start_scroll_y: f32,
+}
+
+/// Animated vertical offset applied to items making room for a dragged item
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ListItemReflow {
+    start: f32,
+    target: f32,
+    current: f32,
+    elapsed: f32,
+}
+
+impl ListItemReflow {
+    /// Animate towards a new offset
+    pub fn set_target(&mut self, target: f32) {
+        if (self.target - target).abs() > f32::EPSILON {
+            self.start = self.current;
+            self.target = target;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Advance the animation and return the current offset
+    pub fn update(&mut self, dt: f32) -> f32 {
+        self.elapsed = (self.elapsed + dt).min(Duration::MEDIUM2);
+        let t = ease_standard(self.elapsed / Duration::MEDIUM2);
+        self.current = self.start + (self.target - self.start) * t;
+        self.current
+    }
+}
+
+/// Index a dragged item would be dropped at
+///
+/// `centers` are the natural item centers in display order; the result counts how many other
+/// items sit above `dragged_center`.
+pub fn reorder_target_index(centers: &[f32], from: usize, dragged_center: f32) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|&(i, &center)| i != from && center < dragged_center)
+        .count()
+}
+
+/// Offset for the item at `index` while the item at `from` hovers over `to`
+fn reflow_offset(index: usize, from: usize, to: usize, dragged_height: f32) -> f32 {
+    if from < to && index > from && index <= to {
+        -dragged_height
+    } else if to < from && index >= to && index < from {
+        dragged_height
+    } else {
+        0.0
+    }
+}
+
+/// Start a reorder drag when a drag handle is pressed
+fn list_reorder_start_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    handles: Query<(Entity, &Interaction), (Changed<Interaction>, With<ListItemDragHandle>)>,
+    parents: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    mut lists: Query<(&mut MaterialList, Option<&ScrollPosition>)>,
+    items: Query<&MaterialListItem>,
+    geometry: Query<(&ComputedNode, &UiGlobalTransform)>,
+) {
+    let Some(theme) = theme else { return };
+    let Some(cursor) = windows.single().ok().and_then(|w| w.cursor_position()) else {
+        return;
+    };
+
+    for (handle, interaction) in handles.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some(item) = parents
+            .iter_ancestors(handle)
+            .take(MAX_ANCESTOR_DEPTH)
+            .find(|e| items.contains(*e))
+        else {
+            continue;
+        };
+        let Some(list_entity) = parents
+            .iter_ancestors(item)
+            .take(MAX_ANCESTOR_DEPTH)
+            .find(|e| lists.contains(*e))
+        else {
+            continue;
+        };
+        let Ok((mut list, scroll)) = lists.get_mut(list_entity) else {
+            continue;
+        };
+        if !list.reorderable || list.reorder_drag.is_some() {
+            continue;
+        }
+        if items.get(item).is_ok_and(|i| i.disabled) {
+            continue;
+        }
+
+        let order = list_items_in_order(list_entity, &children_query, |e| items.contains(e));
+        let Some(from) = order.iter().position(|e| *e == item) else {
+            continue;
+        };
+
+        let (tops, heights): (Vec<f32>, Vec<f32>) = order
+            .iter()
+            .map(|e| {
+                geometry
+                    .get(*e)
+                    .map(|(node, transform)| {
+                        let scale = node.inverse_scale_factor();
+                        let height = node.size().y * scale;
+                        (transform.translation.y * scale - height / 2.0, height)
+                    })
+                    .unwrap_or((0.0, 0.0))
+            })
+            .unzip();
+
+        // Lift the dragged item above its siblings.
+        commands.entity(item).insert((
+            ZIndex(10),
+            Elevation::Level3.to_box_shadow(),
+            BackgroundColor(Elevation::Level3.tinted_surface(&theme)),
+        ));
+        for other in order.iter().filter(|e| **e != item) {
+            commands.entity(*other).insert(ListItemReflow::default());
+        }
+
+        list.reorder_drag = Some(ListReorderDrag {
+            item,
+            from,
+            to: from,
+            order,
+            tops,
+            heights,
+            start_cursor_y: cursor.y,
+            start_scroll_y: scroll.map(|s| s.y).unwrap_or(0.0),
+        });
+    }
+}
+
+/// Follow the cursor, reflow siblings, auto-scroll and drop dragged list items
+fn list_reorder_drag_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut lists: Query<(
+        Entity,
+        &mut MaterialList,
+        Option<&mut ScrollPosition>,
+        &ComputedNode,
+        &UiGlobalTransform,
+    )>,
+    items: Query<&MaterialListItem>,
+    mut item_nodes: Query<&mut Node, With<MaterialListItem>>,
+    mut reflows: Query<&mut ListItemReflow>,
+    parents: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    mut reorder_events: MessageWriter<ListReorderEvent>,
+) {
+    let Some(theme) = theme else { return };
+    let cursor_y = windows
+        .single()
+        .ok()
+        .and_then(|w| w.cursor_position())
+        .map(|c| c.y);
+
+    for (list_entity, mut list, mut scroll, computed, transform) in lists.iter_mut() {
+        if list.reorder_drag.is_none() {
+            continue;
+        }
+        let Some(mut drag) = list.reorder_drag.take() else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            // Drop: restore the lifted item and move it to its new index.
+            let background = items
+                .get(drag.item)
+                .map(|i| i.background_color(&theme))
+                .unwrap_or(Color::NONE);
+            commands
+                .entity(drag.item)
+                .remove::<(ZIndex, BoxShadow)>()
+                .insert(BackgroundColor(background));
+            if let Ok(mut node) = item_nodes.get_mut(drag.item) {
+                node.top = Val::Auto;
+            }
+            for other in drag.order.iter().filter(|e| **e != drag.item) {
+                commands.entity(*other).remove::<ListItemReflow>();
+                if let Ok(mut node) = item_nodes.get_mut(*other) {
+                    node.top = Val::Auto;
+                }
+            }
+
+            if drag.from != drag.to {
+                let target = drag.order[drag.to];
+                let parent = parents.get(drag.item).map(|p| p.parent());
+                if let Ok(parent) = parent {
+                    let index = children_query
+                        .get(parent)
+                        .ok()
+                        .and_then(|c| c.iter().position(|e| e == target));
+                    if let Some(index) = index {
+                        commands.entity(parent).insert_children(index, &[drag.item]);
+                    }
+                }
+                reorder_events.write(ListReorderEvent {
+                    list: list_entity,
+                    from: drag.from,
+                    to: drag.to,
+                });
+            }
+            continue;
+        }
+
+        let Some(cursor_y) = cursor_y else {
+            list.reorder_drag = Some(drag);
+            continue;
+        };
+
+        // Auto-scroll when the cursor nears the list edges.
+        if let Some(scroll) = scroll.as_mut() {
+            let scale = computed.inverse_scale_factor();
+            let height = computed.size().y * scale;
+            let top = transform.translation.y * scale - height / 2.0;
+            let bottom = top + height;
+            let speed = if cursor_y < top + LIST_REORDER_EDGE {
+                -((top + LIST_REORDER_EDGE - cursor_y) / LIST_REORDER_EDGE).min(1.0)
+            } else if cursor_y > bottom - LIST_REORDER_EDGE {
+                ((cursor_y - (bottom - LIST_REORDER_EDGE)) / LIST_REORDER_EDGE).min(1.0)
+            } else {
+                0.0
+            };
+            if speed != 0.0 {
+                scroll.y =
+                    (scroll.y + speed * LIST_REORDER_SCROLL_SPEED * time.delta_secs()).max(0.0);
+            }
+        }
+        let scroll_y = scroll.map(|s| s.y).unwrap_or(drag.start_scroll_y);
+
+        let delta = (cursor_y - drag.start_cursor_y) + (scroll_y - drag.start_scroll_y);
+        if let Ok(mut node) = item_nodes.get_mut(drag.item) {
+            node.top = Val::Px(delta);
+        }
+
+        let centers: Vec<f32> = drag
+            .tops
+            .iter()
+            .zip(&drag.heights)
+            .map(|(top, height)| top + height / 2.0)
+            .collect();
+        drag.to = reorder_target_index(&centers, drag.from, centers[drag.from] + delta);
+
+        let dragged_height = drag.heights[drag.from];
+        for (index, entity) in drag.order.iter().enumerate() {
+            if let Ok(mut reflow) = reflows.get_mut(*entity) {
+                reflow.set_target(reflow_offset(index, drag.from, drag.to, dragged_height));
+            }
+        }
+
+        list.reorder_drag = Some(drag);
+    }
+}
+
+/// Animate items making room for a dragged item
+fn list_item_reflow_system(time: Res<Time>, mut items: Query<(&mut ListItemReflow, &mut Node)>) {
+    for (mut reflow, mut node) in items.iter_mut() {
+        if reflow.elapsed >= Duration::MEDIUM2 && reflow.current == reflow.target {
+            continue;
+        }
+        node.top = Val::Px(reflow.update(time.delta_secs()));
+    }
+}
+
+/// Section header that sticks to the top of its scroll viewport
+///
+/// While its section is visible the header stays pinned at the top; the next header pushes it
+/// up as it arrives. Headers must be direct content of a `ScrollContainer` list.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ListSectionHeader {
+    /// Offset currently applied to keep the header pinned (logical pixels)
+    pub offset: f32,
+}
+
+/// Label text of a [`ListSectionHeader`]
+#[derive(Component)]
+pub struct ListSectionHeaderText;
+
+/// Height of a list section header
+pub const LIST_SECTION_HEADER_HEIGHT: f32 = 48.0;
+
+/// Create a list section header
+pub fn create_list_section_header(theme: &MaterialTheme) -> impl Bundle {
+    (
+        ListSectionHeader::default(),
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(LIST_SECTION_HEADER_HEIGHT),
+            flex_shrink: 0.0,
+            padding: UiRect::horizontal(Val::Px(Spacing::LARGE)),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        // Draw above the items scrolling underneath.
+        ZIndex(1),
+        BackgroundColor(theme.surface),
+    )
+}
+
+/// Compute pinned offsets for section headers
+///
+/// `headers` holds each header's unpinned top relative to the viewport top and its height,
+/// sorted by position. Returns the offset to apply to each header.
+pub fn sticky_header_offsets(headers: &[(f32, f32)]) -> Vec<f32> {
+    headers
+        .iter()
+        .enumerate()
+        .map(|(i, &(top, height))| {
+            let limit = headers
+                .get(i + 1)
+                .map(|&(next_top, _)| next_top - height)
+                .unwrap_or(f32::MAX);
+            let pinned = top.max(limit.min(0.0));
+            pinned - top
+        })
+        .collect()
+}
+
+/// Pin section headers to the top of their scroll viewport
+fn list_section_header_sticky_system(
+    mut headers: Query<(
+        Entity,
+        &mut ListSectionHeader,
+        &mut Node,
+        &ComputedNode,
+        &UiGlobalTransform,
+    )>,
+    parents: Query<&ChildOf>,
+    viewports: Query<(&ComputedNode, &UiGlobalTransform), With<ScrollContent>>,
+) {
+    let mut groups: HashMap<Entity, Vec<(Entity, f32, f32)>> = HashMap::new();
+
+    for (entity, header, _, computed, transform) in headers.iter() {
+        let Some(viewport) = parents
+            .iter_ancestors(entity)
+            .take(MAX_ANCESTOR_DEPTH)
+            .find(|e| viewports.contains(*e))
+        else {
+            continue;
+        };
+        let Ok((viewport_node, viewport_transform)) = viewports.get(viewport) else {
+            continue;
+        };
+
+        // Transforms and sizes are physical; convert to logical and remove the pin offset
+        // applied last frame to recover the header's natural position.
+        let scale = computed.inverse_scale_factor();
+        let size = computed.size() * scale;
+        let header_top = transform.translation.y * scale - size.y / 2.0;
+        let viewport_top =
+            viewport_transform.translation.y * scale - viewport_node.size().y * scale / 2.0;
+        let top = header_top - viewport_top - header.offset;
+
+        groups
+            .entry(viewport)
+            .or_default()
+            .push((entity, top, size.y));
+    }
+
+    for mut group in groups.into_values() {
+        group.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let positions: Vec<(f32, f32)> = group.iter().map(|&(_, top, h)| (top, h)).collect();
+
+        for ((entity, _, _), offset) in group.iter().zip(sticky_header_offsets(&positions)) {
+            let Ok((_, mut header, mut node, _, _)) = headers.get_mut(*entity) else {
+                continue;
+            };
+            if (header.offset - offset).abs() > 0.01 {
+                header.offset = offset;
+                node.top = Val::Px(offset);
+            }
+        }
+    }
+}
+
+/// Refresh section header colors when the theme changes
+fn list_section_header_theme_system(
+    theme: Option<Res<MaterialTheme>>,
+    mut headers: Query<&mut BackgroundColor, With<ListSectionHeader>>,
+    mut texts: Query<&mut TextColor, With<ListSectionHeaderText>>,
+) {
+    let Some(theme) = theme else { return };
+    if !theme.is_changed() {
+        return;
+    }
+
+    for mut bg in headers.iter_mut() {
+        bg.0 = theme.surface;
+    }
+    for mut color in texts.iter_mut() {
+        color.0 = theme.primary;
+    }
+}
+
 // ============================================================================
 // Spawn Traits for ChildSpawnerCommands
 // ============================================================================
@@ -687,6 +1774,9 @@ pub trait SpawnListChild {
 
     /// Spawn a list divider
     fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool);
+
+    /// Spawn a sticky section header
+    fn spawn_list_section_header(&mut self, theme: &MaterialTheme, label: impl Into<String>);
 }
 
 impl SpawnListChild for ChildSpawnerCommands<'_> {
@@ -729,10 +1819,7 @@ impl SpawnListChild for ChildSpawnerCommands<'_> {
                 body.spawn((
                     ListItemHeadline,
                     Text::new(&headline_str),
-                    TextFont {
-                        font_size: 16.0,
-                        ..default()
-                    },
+                    TypeRole::BodyLarge.text_bundle(),
                     TextColor(headline_color),
                 ));
 
@@ -741,10 +1828,7 @@ impl SpawnListChild for ChildSpawnerCommands<'_> {
                     body.spawn((
                         ListItemSupportingText,
                         Text::new(supporting),
-                        TextFont {
-                            font_size: 14.0,
-                            ..default()
-                        },
+                        TypeRole::BodyMedium.text_bundle(),
                         TextColor(supporting_color),
                     ));
                 }
@@ -753,109 +1837,424 @@ impl SpawnListChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_list_item_with(&mut self, theme: &MaterialTheme, builder: ListItemBuilder) {
-        let headline = builder.item.headline.clone();
-        let supporting_text = builder.item.supporting_text.clone();
-        let trailing_text = builder.item.trailing_text.clone();
-        let leading_icon = builder.item.leading_icon.clone();
-        let trailing_icon = builder.item.trailing_icon.clone();
+        let data = builder.item.clone();
+        self.spawn(builder.build(theme))
+            .with_children(|item| spawn_list_item_content(item, theme, &data));
+    }
 
-        let headline_color = builder.item.headline_color(theme);
-        let supporting_color = builder.item.supporting_text_color(theme);
-        let icon_color = builder.item.icon_color(theme);
+    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool) {
+        self.spawn(create_list_divider(theme, inset));
+    }
 
-        self.spawn(builder.build(theme)).with_children(|item| {
-            // Leading content
-            if let Some(icon_str) = leading_icon.as_deref() {
-                if let Some(icon_id) = resolve_icon_id(icon_str) {
-                    item.spawn((
-                        ListItemLeading,
-                        Node {
-                            width: Val::Px(56.0),
-                            height: Val::Px(56.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                    ))
-                    .with_children(|leading| {
-                        leading.spawn((
-                            MaterialIcon::new(icon_id),
-                            IconStyle::outlined().with_color(icon_color).with_size(24.0),
-                        ));
-                    });
-                }
+    fn spawn_list_section_header(&mut self, theme: &MaterialTheme, label: impl Into<String>) {
+        self.spawn(create_list_section_header(theme))
+            .with_children(|header| {
+                header.spawn((
+                    ListSectionHeaderText,
+                    Text::new(label),
+                    TypeRole::TitleSmall.text_bundle(),
+                    TextColor(theme.primary),
+                ));
+            });
+    }
+}
+
+/// Spawn the row container for newly added virtualized lists
+fn virtualized_list_setup_system(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut VirtualizedList), Added<VirtualizedList>>,
+) {
+    for (entity, mut list) in lists.iter_mut() {
+        let content = commands
+            .spawn((
+                VirtualizedListContent,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(list.content_height()),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                ChildOf(entity),
+            ))
+            .id();
+        list.content = Some(content);
+        list.dirty = true;
+    }
+}
+
+/// Node for a virtualized row positioned at `index`
+fn virtualized_row_node(index: usize, item_height: f32) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(index as f32 * item_height),
+        left: Val::Px(0.0),
+        width: Val::Percent(100.0),
+        height: Val::Px(item_height),
+        padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::SMALL)),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(Spacing::LARGE),
+        ..default()
+    }
+}
+
+/// Spawn, recycle and position the visible rows of virtualized lists
+fn virtualized_list_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut lists: Query<(&mut VirtualizedList, Option<&ScrollPosition>, &ComputedNode)>,
+    mut content_nodes: Query<&mut Node, With<VirtualizedListContent>>,
+    children_query: Query<&Children>,
+    item_content: Query<
+        (),
+        Or<(
+            With<ListItemLeading>,
+            With<ListItemBody>,
+            With<ListItemShortcut>,
+            With<ListItemTrailing>,
+        )>,
+    >,
+) {
+    let Some(theme) = theme else { return };
+
+    for (mut list, scroll, computed) in lists.iter_mut() {
+        let Some(content) = list.content else {
+            continue;
+        };
+        let Some(source) = list.source.clone() else {
+            continue;
+        };
+
+        // Before the first layout pass, assume a viewport of a few rows.
+        let viewport = computed.size().y * computed.inverse_scale_factor();
+        let viewport = if viewport > 0.0 {
+            viewport
+        } else {
+            list.item_height * 8.0
+        };
+        let scroll_y = scroll.map(|s| s.y).unwrap_or(0.0);
+        let range = list.visible_range(scroll_y, viewport);
+
+        if !list.dirty && range == list.range {
+            continue;
+        }
+
+        if let Ok(mut node) = content_nodes.get_mut(content) {
+            let height = Val::Px(list.content_height());
+            if node.height != height {
+                node.height = height;
+            }
+        }
+
+        // Release rows that left the range (or every row when refreshing).
+        let dirty = list.dirty;
+        let mut rows = std::mem::take(&mut list.rows);
+        let mut spare = std::mem::take(&mut list.spare);
+        rows.retain(|index, row| {
+            let keep = !dirty && range.contains(index);
+            if !keep {
+                spare.push(*row);
             }
+            keep
+        });
+
+        for index in range.clone() {
+            if rows.contains_key(&index) {
+                continue;
+            }
+
+            let builder = source(index);
+            let data = builder.item.clone();
+            let node = virtualized_row_node(index, list.item_height);
 
-            // Body
+            let row = match spare.pop() {
+                Some(row) => {
+                    // Recycle: drop the old item content but keep overlays (state layer, ripple).
+                    if let Ok(children) = children_query.get(row) {
+                        for child in children.iter().filter(|c| item_content.contains(*c)) {
+                            commands.entity(child).despawn();
+                        }
+                    }
+                    commands.entity(row).insert((
+                        BackgroundColor(data.background_color(&theme)),
+                        data.clone(),
+                        VirtualizedListRow { index },
+                        node,
+                    ));
+                    row
+                }
+                None => commands
+                    .spawn((
+                        builder.build(&theme),
+                        VirtualizedListRow { index },
+                        ChildOf(content),
+                    ))
+                    .insert(node)
+                    .id(),
+            };
+
+            commands
+                .entity(row)
+                .with_children(|item| spawn_list_item_content(item, &theme, &data));
+            rows.insert(index, row);
+        }
+
+        for row in &spare {
+            commands.entity(*row).insert(Node {
+                display: Display::None,
+                ..default()
+            });
+        }
+
+        list.rows = rows;
+        list.spare = spare;
+        list.range = range;
+        list.dirty = false;
+    }
+}
+
+/// Spawn the leading, body and trailing content of a list item
+fn spawn_list_item_content(
+    item: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    data: &MaterialListItem,
+) {
+    let headline = data.headline.clone();
+    let supporting_text = data.supporting_text.clone();
+    let trailing_text = data.trailing_text.clone();
+    let shortcut = data.shortcut.clone();
+    let leading_icon = data.leading_icon.clone();
+    let trailing_icon = data.trailing_icon.clone();
+
+    let headline_color = data.headline_color(theme);
+    let supporting_color = data.supporting_text_color(theme);
+    let icon_color = data.icon_color(theme);
+
+    // Leading content
+    if let Some(icon_str) = leading_icon.as_deref() {
+        if let Some(icon_id) = resolve_icon_id(icon_str) {
             item.spawn((
-                ListItemBody,
+                ListItemLeading,
                 Node {
-                    flex_direction: FlexDirection::Column,
-                    flex_grow: 1.0,
+                    width: Val::Px(56.0),
+                    height: Val::Px(56.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
                     ..default()
                 },
             ))
-            .with_children(|body| {
-                body.spawn((
-                    ListItemHeadline,
-                    Text::new(&headline),
-                    TextFont {
-                        font_size: 16.0,
-                        ..default()
-                    },
-                    TextColor(headline_color),
+            .with_children(|leading| {
+                leading.spawn((
+                    MaterialIcon::new(icon_id),
+                    IconStyle::outlined().with_color(icon_color).with_size(24.0),
                 ));
+            });
+        }
+    }
 
-                if let Some(ref supporting) = supporting_text {
-                    body.spawn((
-                        ListItemSupportingText,
-                        Text::new(supporting),
-                        TextFont {
-                            font_size: 14.0,
-                            ..default()
-                        },
-                        TextColor(supporting_color),
+    // Body
+    item.spawn((
+        ListItemBody,
+        Node {
+            flex_direction: FlexDirection::Column,
+            flex_grow: 1.0,
+            min_width: Val::Px(0.0),
+            ..default()
+        },
+    ))
+    .with_children(|body| {
+        body.spawn((
+            ListItemHeadline,
+            Text::new(&headline),
+            TypeRole::BodyLarge.text_bundle(),
+            TextColor(headline_color),
+        ));
+
+        if let Some(ref supporting) = supporting_text {
+            body.spawn((
+                ListItemSupportingText,
+                Text::new(supporting),
+                TypeRole::BodyMedium.text_bundle(),
+                TextColor(supporting_color),
+            ));
+        }
+    });
+
+    // Shortcut hint, right-aligned ahead of any trailing icons or controls
+    if let Some(ref text) = shortcut {
+        item.spawn((
+            ListItemShortcut,
+            Text::new(text),
+            TypeRole::LabelLarge.text_bundle(),
+            TextColor(supporting_color),
+            Node {
+                flex_shrink: 0.0,
+                ..default()
+            },
+        ));
+    }
+
+    // Trailing content
+    if trailing_text.is_some() || trailing_icon.is_some() || data.drag_handle {
+        item.spawn((
+            ListItemTrailing,
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(Spacing::MEDIUM),
+                flex_shrink: 0.0,
+                ..default()
+            },
+        ))
+        .with_children(|trailing| {
+            if let Some(ref text) = trailing_text {
+                trailing.spawn((
+                    Text::new(text),
+                    TypeRole::BodyMedium.text_bundle(),
+                    TextColor(supporting_color),
+                ));
+            }
+
+            if let Some(icon_str) = trailing_icon.as_deref() {
+                if let Some(icon_id) = resolve_icon_id(icon_str) {
+                    trailing.spawn((
+                        MaterialIcon::new(icon_id),
+                        IconStyle::outlined().with_color(icon_color).with_size(24.0),
                     ));
                 }
-            });
-
-            // Trailing content
-            if trailing_text.is_some() || trailing_icon.is_some() {
-                item.spawn((
-                    ListItemTrailing,
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        align_items: AlignItems::Center,
-                        column_gap: Val::Px(Spacing::MEDIUM),
-                        ..default()
-                    },
-                ))
-                .with_children(|trailing| {
-                    if let Some(ref text) = trailing_text {
-                        trailing.spawn((
-                            Text::new(text),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(supporting_color),
-                        ));
-                    }
+            }
 
-                    if let Some(icon_str) = trailing_icon.as_deref() {
-                        if let Some(icon_id) = resolve_icon_id(icon_str) {
-                            trailing.spawn((
+            if data.drag_handle {
+                trailing
+                    .spawn((
+                        ListItemDragHandle,
+                        Button,
+                        Node {
+                            width: Val::Px(24.0),
+                            height: Val::Px(24.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                    ))
+                    .with_children(|handle| {
+                        if let Some(icon_id) = resolve_icon_id("drag_handle") {
+                            handle.spawn((
                                 MaterialIcon::new(icon_id),
                                 IconStyle::outlined().with_color(icon_color).with_size(24.0),
                             ));
                         }
-                    }
-                });
+                    });
             }
         });
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swipe() -> ListItemSwipeActions {
+        ListItemSwipeActions::new()
+            .leading(ListItemSwipeAction::new("archive", Color::BLACK))
+            .trailing(ListItemSwipeAction::new("delete", Color::WHITE))
+    }
 
-    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool) {
-        self.spawn(create_list_divider(theme, inset));
+    #[test]
+    fn test_swipe_clamps_to_available_actions() {
+        let only_trailing =
+            ListItemSwipeActions::new().trailing(ListItemSwipeAction::new("delete", Color::BLACK));
+        assert_eq!(only_trailing.clamp_offset(50.0, 200.0), 0.0);
+        assert_eq!(only_trailing.clamp_offset(-500.0, 200.0), -200.0);
+    }
+
+    #[test]
+    fn test_swipe_commits_past_threshold() {
+        let mut swipe = swipe();
+        swipe.offset = 60.0;
+        assert!(swipe.committed_action(200.0).is_none());
+
+        swipe.offset = 100.0;
+        assert_eq!(swipe.committed_action(200.0).unwrap().id, "archive");
+
+        swipe.offset = -100.0;
+        assert_eq!(swipe.committed_action(200.0).unwrap().id, "delete");
+    }
+
+    #[test]
+    fn test_virtualized_visible_range() {
+        let list = VirtualizedList::new(56.0)
+            .with_source(1000, |i| ListItemBuilder::new(format!("Item {i}")))
+            .with_overscan(2);
+
+        assert_eq!(list.visible_range(0.0, 560.0), 0..12);
+        assert_eq!(list.visible_range(5600.0, 560.0), 98..112);
+        assert_eq!(list.visible_range(56_000.0, 560.0), 998..1000);
+        assert_eq!(list.content_height(), 56_000.0);
+    }
+
+    #[test]
+    fn test_multiple_selection_toggle_and_range() {
+        let order: Vec<Entity> = (1..=5).map(|i| Entity::from_raw_u32(i).unwrap()).collect();
+        let mut list = MaterialList::new().with_selection_mode(ListSelectionMode::Multiple);
+
+        assert!(list.click(order[1], &order, false));
+        assert!(list.click(order[3], &order, true));
+        assert_eq!(list.selected, order[1..=3].to_vec());
+
+        assert!(list.click(order[2], &order, false));
+        assert_eq!(list.selected, vec![order[1], order[3]]);
+    }
+
+    #[test]
+    fn test_single_selection_replaces() {
+        let order: Vec<Entity> = (1..=3).map(|i| Entity::from_raw_u32(i).unwrap()).collect();
+        let mut list = MaterialList::new().with_selection_mode(ListSelectionMode::Single);
+
+        list.click(order[0], &order, false);
+        assert!(list.click(order[2], &order, true));
+        assert_eq!(list.selected, vec![order[2]]);
+        assert!(!list.click(order[2], &order, false));
+    }
+
+    #[test]
+    fn test_sticky_header_offsets() {
+        // Not scrolled: nothing pinned.
+        assert_eq!(
+            sticky_header_offsets(&[(0.0, 48.0), (200.0, 48.0)]),
+            vec![0.0, 0.0]
+        );
+
+        // First header scrolled away: pinned at the top.
+        assert_eq!(
+            sticky_header_offsets(&[(-100.0, 48.0), (100.0, 48.0)]),
+            vec![100.0, 0.0]
+        );
+
+        // Next header pushes the pinned one up.
+        assert_eq!(
+            sticky_header_offsets(&[(-100.0, 48.0), (20.0, 48.0)]),
+            vec![72.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_reorder_target_index() {
+        let centers = [28.0, 84.0, 140.0, 196.0];
+        assert_eq!(reorder_target_index(&centers, 0, 28.0), 0);
+        assert_eq!(reorder_target_index(&centers, 0, 150.0), 2);
+        assert_eq!(reorder_target_index(&centers, 3, 10.0), 0);
+    }
+
+    #[test]
+    fn test_reflow_offsets_make_room() {
+        // Dragging index 0 down to 2 shifts items 1 and 2 up.
+        assert_eq!(reflow_offset(1, 0, 2, 56.0), -56.0);
+        assert_eq!(reflow_offset(2, 0, 2, 56.0), -56.0);
+        assert_eq!(reflow_offset(3, 0, 2, 56.0), 0.0);
+
+        // Dragging index 3 up to 1 shifts items 1 and 2 down.
+        assert_eq!(reflow_offset(1, 3, 1, 56.0), 56.0);
+        assert_eq!(reflow_offset(0, 3, 1, 56.0), 0.0);
     }
 }
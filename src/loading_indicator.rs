@@ -21,6 +21,9 @@ pub struct ShapeMorphMaterial {
     pub morph_t: f32,
     #[uniform(0)]
     pub rotation: f32,
+    /// Fraction of the shape drawn, swept clockwise from the top (1.0 draws all of it)
+    #[uniform(0)]
+    pub fill: f32,
     #[uniform(0)]
     pub color: LinearRgba,
 }
@@ -38,6 +41,7 @@ impl Default for ShapeMorphMaterial {
             shape_to: 1,
             morph_t: 0.0,
             rotation: 0.0,
+            fill: 1.0,
             color: LinearRgba::WHITE,
         }
     }
@@ -85,7 +89,9 @@ pub struct MaterialLoadingIndicator {
     pub contained: bool,
     /// Whether the indicator cycles through multiple colors
     pub multi_color: bool,
-    /// Current morph fraction (0.0-7.0, cycles through shapes)
+    /// Shapes the indicator morphs through, in order (at least two)
+    pub shapes: Vec<LoadingShape>,
+    /// Current morph fraction (0.0 to `shapes.len()`, cycles through shapes)
     pub morph_fraction: f32,
     /// Current rotation in degrees
     pub rotation: f32,
@@ -93,6 +99,10 @@ pub struct MaterialLoadingIndicator {
     pub speed: f32,
     /// Current color index for multi-color mode
     pub color_index: usize,
+    /// Whether progress is driven by `value` instead of animating continuously
+    pub determinate: bool,
+    /// Progress (0.0-1.0) shown in determinate mode
+    pub value: f32,
 }
 
 impl MaterialLoadingIndicator {
@@ -102,10 +112,13 @@ impl MaterialLoadingIndicator {
             fill_parent: false,
             contained: false,
             multi_color: false,
+            shapes: LoadingShape::MD3_SEQUENCE.to_vec(),
             morph_fraction: 0.0,
             rotation: 0.0,
             speed: 1.0,
             color_index: 0,
+            determinate: false,
+            value: 0.0,
         }
     }
 
@@ -128,6 +141,55 @@ impl MaterialLoadingIndicator {
         self.speed = speed.max(0.1);
         self
     }
+
+    /// Show known progress instead of animating indefinitely
+    pub fn determinate(mut self, value: f32) -> Self {
+        self.determinate = true;
+        self.set_value(value);
+        self
+    }
+
+    /// Update the progress (clamped to 0.0-1.0) shown in determinate mode
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value.clamp(0.0, 1.0);
+    }
+
+    /// Fraction of the shape that should be drawn
+    pub fn fill(&self) -> f32 {
+        if self.determinate {
+            self.value
+        } else {
+            1.0
+        }
+    }
+
+    /// Set the morph sequence; ignored (with a warning) if it has fewer than two shapes.
+    pub fn with_shapes(mut self, shapes: impl Into<Vec<LoadingShape>>) -> Self {
+        let shapes = shapes.into();
+        if shapes.len() < 2 {
+            warn!(
+                "Loading indicator needs at least two shapes to morph, got {}",
+                shapes.len()
+            );
+            return self;
+        }
+        self.shapes = shapes;
+        self.morph_fraction = 0.0;
+        self
+    }
+
+    /// Shapes being morphed between (from, to) and the morph progress between them
+    pub fn current_morph(&self) -> (LoadingShape, LoadingShape, f32) {
+        let count = self.shapes.len().max(1);
+        let index = self.morph_fraction.floor() as usize;
+        let shape = |i: usize| {
+            self.shapes
+                .get(i % count)
+                .copied()
+                .unwrap_or(LoadingShape::SoftBurst)
+        };
+        (shape(index), shape(index + 1), self.morph_fraction.fract())
+    }
 }
 
 impl Default for MaterialLoadingIndicator {
@@ -151,22 +213,51 @@ pub const CONSTANT_ROTATION_PER_SHAPE: f32 = 50.0;
 /// Extra rotation per shape in degrees (spring-based)
 pub const EXTRA_ROTATION_PER_SHAPE: f32 = 90.0;
 
-/// Number of shapes in the morph sequence
+/// Rotation in degrees applied over the full range of a determinate indicator
+pub const DETERMINATE_ROTATION: f32 = 180.0;
+
+/// Number of shapes the morph shader can draw
 pub const SHAPE_COUNT: usize = 7;
 
 /// Shape types for morphing sequence
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// The discriminant is the shape id understood by the morph shader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LoadingShape {
-    SoftBurst, // 0
-    Cookie9,   // 1
-    Pentagon,  // 2
-    Pill,      // 3
-    Sunny,     // 4
-    Cookie4,   // 5
-    Oval,      // 6
+    SoftBurst = 0,
+    Cookie9 = 1,
+    Pentagon = 2,
+    Pill = 3,
+    Sunny = 4,
+    Cookie4 = 5,
+    Oval = 6,
 }
 
 impl LoadingShape {
+    /// The MD3 loading indicator sequence (the default)
+    pub const MD3_SEQUENCE: [LoadingShape; SHAPE_COUNT] = [
+        LoadingShape::SoftBurst,
+        LoadingShape::Cookie9,
+        LoadingShape::Pentagon,
+        LoadingShape::Pill,
+        LoadingShape::Sunny,
+        LoadingShape::Cookie4,
+        LoadingShape::Oval,
+    ];
+
+    /// Rounded-only subset of the MD3 shapes, without corners or points
+    pub const MD3_SOFT_SEQUENCE: [LoadingShape; 4] = [
+        LoadingShape::SoftBurst,
+        LoadingShape::Cookie9,
+        LoadingShape::Cookie4,
+        LoadingShape::Oval,
+    ];
+
+    /// Shape id passed to the morph shader
+    pub fn shader_id(self) -> u32 {
+        self as u32
+    }
+
     pub fn from_index(index: usize) -> Self {
         match index % SHAPE_COUNT {
             0 => LoadingShape::SoftBurst,
@@ -203,12 +294,24 @@ fn loading_indicator_morph_animation_system(
     mut indicators: Query<&mut MaterialLoadingIndicator>,
 ) {
     for mut indicator in indicators.iter_mut() {
-        // Advance morph fraction (0.0 to SHAPE_COUNT)
+        // Determinate indicators morph from the first shape to the second as progress grows.
+        if indicator.determinate {
+            let value = indicator.value;
+            if indicator.morph_fraction != value {
+                indicator.morph_fraction = value;
+                indicator.rotation = DETERMINATE_ROTATION * value;
+            }
+            continue;
+        }
+
+        let shape_count = indicator.shapes.len().max(1) as f32;
+
+        // Advance morph fraction (0.0 to the sequence length)
         indicator.morph_fraction += time.delta_secs() * indicator.speed / DURATION_PER_SHAPE;
 
         // Wrap around after completing all shapes
-        if indicator.morph_fraction >= SHAPE_COUNT as f32 {
-            indicator.morph_fraction -= SHAPE_COUNT as f32;
+        if indicator.morph_fraction >= shape_count {
+            indicator.morph_fraction %= shape_count;
             indicator.color_index = (indicator.color_index + 1) % 4; // Cycle through 4 colors
         }
     }
@@ -220,6 +323,10 @@ fn loading_indicator_rotation_animation_system(
     mut indicators: Query<&mut MaterialLoadingIndicator>,
 ) {
     for mut indicator in indicators.iter_mut() {
+        if indicator.determinate {
+            continue;
+        }
+
         let morph_factor_base = indicator.morph_fraction.floor();
         let morph_factor_per_shape = indicator.morph_fraction - morph_factor_base;
 
@@ -260,13 +367,13 @@ fn loading_indicator_material_update_system(
             if let Ok(material_node) = material_query.get(child) {
                 if let Some(material) = materials.get_mut(&material_node.0) {
                     // Calculate current and next shapes
-                    let shape_index = indicator.morph_fraction.floor() as u32;
-                    let morph_t = indicator.morph_fraction.fract();
+                    let (from, to, morph_t) = indicator.current_morph();
 
-                    material.shape_from = shape_index % 7;
-                    material.shape_to = (shape_index + 1) % 7;
+                    material.shape_from = from.shader_id();
+                    material.shape_to = to.shader_id();
                     material.morph_t = morph_t;
                     material.rotation = indicator.rotation.to_radians();
+                    material.fill = indicator.fill();
                     material.color = LinearRgba::from(color);
                 }
             }
@@ -355,6 +462,20 @@ impl LoadingIndicatorBuilder {
         self
     }
 
+    /// Show known progress (0.0-1.0); update it with [`MaterialLoadingIndicator::set_value`].
+    pub fn determinate(mut self, value: f32) -> Self {
+        self.indicator = self.indicator.determinate(value);
+        self
+    }
+
+    /// Shapes to morph through, e.g. [`LoadingShape::MD3_SOFT_SEQUENCE`].
+    ///
+    /// Sequences with fewer than two shapes are rejected and the default is kept.
+    pub fn shapes(mut self, shapes: impl Into<Vec<LoadingShape>>) -> Self {
+        self.indicator = self.indicator.with_shapes(shapes);
+        self
+    }
+
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let size = self.indicator.size;
         let bg = if self.indicator.contained {
@@ -430,11 +551,14 @@ impl<'a> SpawnLoadingIndicatorChild<'a> for ChildSpawnerCommands<'a> {
         let container_size = builder.indicator.size;
 
         // Create material handle
+        let (from, to, _) = builder.indicator.current_morph();
+        let fill = builder.indicator.fill();
         let material_handle = materials.add(ShapeMorphMaterial {
-            shape_from: 0,
-            shape_to: 1,
+            shape_from: from.shader_id(),
+            shape_to: to.shader_id(),
             morph_t: 0.0,
             rotation: 0.0,
+            fill,
             color: LinearRgba::from(color),
         });
 
@@ -471,3 +595,86 @@ impl<'a> SpawnLoadingIndicatorChild<'a> for ChildSpawnerCommands<'a> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    #[test]
+    fn test_custom_shape_sequence_wraps_at_its_length() {
+        let indicator =
+            MaterialLoadingIndicator::new().with_shapes(LoadingShape::MD3_SOFT_SEQUENCE);
+        assert_eq!(
+            indicator.current_morph(),
+            (LoadingShape::SoftBurst, LoadingShape::Cookie9, 0.0)
+        );
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, loading_indicator_morph_animation_system);
+        let entity = app.world_mut().spawn(indicator).id();
+
+        // Three and a half shapes in: morphing from the last shape back to the first.
+        advance(&mut app, DURATION_PER_SHAPE * 3.5);
+        let indicator = app.world().get::<MaterialLoadingIndicator>(entity).unwrap();
+        let (from, to, t) = indicator.current_morph();
+        assert_eq!((from, to), (LoadingShape::Oval, LoadingShape::SoftBurst));
+        assert!((t - 0.5).abs() < 1e-3);
+
+        advance(&mut app, DURATION_PER_SHAPE);
+        let indicator = app.world().get::<MaterialLoadingIndicator>(entity).unwrap();
+        assert!((indicator.morph_fraction - 0.5).abs() < 1e-3);
+        assert_eq!(indicator.color_index, 1);
+    }
+
+    #[test]
+    fn test_sequences_shorter_than_two_shapes_are_rejected() {
+        let indicator = MaterialLoadingIndicator::new().with_shapes([LoadingShape::Pill]);
+        assert_eq!(indicator.shapes, LoadingShape::MD3_SEQUENCE.to_vec());
+    }
+
+    #[test]
+    fn test_determinate_progress_drives_fill_and_morph() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (
+                loading_indicator_morph_animation_system,
+                loading_indicator_rotation_animation_system,
+            ),
+        );
+        let entity = app
+            .world_mut()
+            .spawn(MaterialLoadingIndicator::new().determinate(1.4))
+            .id();
+
+        // Out-of-range progress is clamped.
+        let indicator = app.world().get::<MaterialLoadingIndicator>(entity).unwrap();
+        assert_eq!(indicator.fill(), 1.0);
+
+        app.world_mut()
+            .get_mut::<MaterialLoadingIndicator>(entity)
+            .unwrap()
+            .set_value(0.25);
+        // Time passing does not animate a determinate indicator.
+        advance(&mut app, 1.0);
+        let indicator = app.world().get::<MaterialLoadingIndicator>(entity).unwrap();
+        assert_eq!(indicator.fill(), 0.25);
+        assert_eq!(indicator.morph_fraction, 0.25);
+        assert_eq!(indicator.rotation, DETERMINATE_ROTATION * 0.25);
+        let (from, to, t) = indicator.current_morph();
+        assert_eq!(
+            (from, to, t),
+            (LoadingShape::SoftBurst, LoadingShape::Cookie9, 0.25)
+        );
+
+        assert_eq!(MaterialLoadingIndicator::new().fill(), 1.0);
+    }
+}
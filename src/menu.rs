@@ -6,16 +6,25 @@
 //! Reference: <https://m3.material.io/components/menus/overview>
 
 use bevy::prelude::*;
-use bevy::ui::BoxShadow;
+use bevy::ui::{BoxShadow, UiGlobalTransform};
+use bevy::window::PrimaryWindow;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
+    disabled::{sync_disabled_system, DisabledSource},
     elevation::Elevation,
+    focus::adopt_focus_group_system,
+    icons::{IconStyle, MaterialIcon},
+    motion::{sync_state_layer_system, StateLayer, StateLayerSource},
+    overlay::{
+        track_overlay_system, DismissOnOutsideClick, OutsideClickEvent, Overlay,
+        OverlayReflowSystems, OverlayStack, Z_MENU,
+    },
     ripple::RippleHost,
-    telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
+    telemetry::{ElementBounds, InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
-    tokens::{CornerRadius, Spacing},
+    tokens::{CornerRadius, Opacity, Spacing},
 };
 
 /// Plugin for the menu component
@@ -26,18 +35,35 @@ impl Plugin for MenuPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
-        app.add_message::<MenuOpenEvent>()
+        app.init_resource::<AnnouncedMenus>()
+            .add_message::<MenuOpenEvent>()
             .add_message::<MenuCloseEvent>()
             .add_message::<MenuItemSelectEvent>()
+            .add_message::<MenuSubmenuOpenEvent>()
             .add_systems(
                 Update,
                 (
                     menu_visibility_system,
+                    track_overlay_system::<MaterialMenu>,
+                    menu_keyboard_dismiss_system,
+                    menu_outside_click_setup_system,
+                    menu_outside_click_system,
                     menu_shadow_system,
                     menu_item_interaction_system,
+                    menu_item_select_system.after(menu_item_interaction_system),
+                    menu_submenu_hover_system.after(menu_item_interaction_system),
                     menu_item_style_system,
+                    menu_item_toggle_icon_system,
                     menu_telemetry_system,
+                    menu_position_system.after(menu_visibility_system),
+                    sync_state_layer_system::<MaterialMenuItem>,
+                    sync_disabled_system::<MaterialMenuItem>,
+                    adopt_focus_group_system::<MaterialMenu, MaterialMenuItem>,
                 ),
+            )
+            .add_systems(
+                PostUpdate,
+                menu_position_system.in_set(OverlayReflowSystems),
             );
     }
 }
@@ -124,8 +150,15 @@ pub struct MaterialMenu {
     pub open: bool,
     /// Anchor corner for positioning
     pub anchor: MenuAnchor,
+    /// Trigger the menu is positioned against while open
+    pub anchor_to: Option<Entity>,
     /// Whether clicking outside closes the menu
     pub close_on_click_outside: bool,
+    /// Whether selecting a plain item closes the menu; checkable and radio
+    /// items always keep it open
+    pub close_on_select: bool,
+    /// Hover time before a submenu item asks for its submenu (in seconds)
+    pub submenu_delay: f32,
 }
 
 impl MaterialMenu {
@@ -134,7 +167,10 @@ impl MaterialMenu {
         Self {
             open: false,
             anchor: MenuAnchor::default(),
+            anchor_to: None,
             close_on_click_outside: true,
+            close_on_select: true,
+            submenu_delay: MENU_SUBMENU_DELAY,
         }
     }
 
@@ -144,6 +180,13 @@ impl MaterialMenu {
         self
     }
 
+    /// Position the menu at `corner` of `trigger` while open
+    pub fn anchor_to(mut self, trigger: Entity, corner: MenuAnchor) -> Self {
+        self.anchor_to = Some(trigger);
+        self.anchor = corner;
+        self
+    }
+
     /// Start open
     pub fn open(mut self) -> Self {
         self.open = true;
@@ -156,6 +199,18 @@ impl MaterialMenu {
         self
     }
 
+    /// Keep open when an item is selected
+    pub fn no_close_on_select(mut self) -> Self {
+        self.close_on_select = false;
+        self
+    }
+
+    /// Set the hover time before a submenu item opens its submenu (in seconds)
+    pub fn submenu_delay(mut self, delay: f32) -> Self {
+        self.submenu_delay = delay;
+        self
+    }
+
     /// Get the surface color
     pub fn surface_color(&self, theme: &MaterialTheme) -> Color {
         theme.surface_container
@@ -163,7 +218,7 @@ impl MaterialMenu {
 
     /// Get the elevation
     pub fn elevation(&self) -> Elevation {
-        Elevation::Level2
+        Elevation::level_2()
     }
 }
 
@@ -173,7 +228,16 @@ impl Default for MaterialMenu {
     }
 }
 
+impl Overlay for MaterialMenu {
+    fn overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
 /// Menu anchor position
+///
+/// For menus anchored to a trigger, the vertical half picks the side the menu
+/// opens on and the horizontal half picks which edges line up.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum MenuAnchor {
     /// Top-left corner
@@ -187,6 +251,38 @@ pub enum MenuAnchor {
     BottomRight,
 }
 
+impl MenuAnchor {
+    fn from_sides(below: bool, start: bool) -> Self {
+        match (below, start) {
+            (true, true) => Self::BottomLeft,
+            (true, false) => Self::BottomRight,
+            (false, true) => Self::TopLeft,
+            (false, false) => Self::TopRight,
+        }
+    }
+
+    /// Whether the menu opens below its trigger
+    pub fn is_bottom(self) -> bool {
+        matches!(self, Self::BottomLeft | Self::BottomRight)
+    }
+
+    /// Whether the menu's left edge lines up with its trigger's
+    pub fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// Leading check state control of a menu item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuItemToggle {
+    /// Independent on/off option, shown with a leading checkbox
+    Checkbox,
+    /// One of a group of exclusive options, shown with a leading radio button.
+    ///
+    /// Checking it unchecks the other items of the same group in its menu.
+    Radio(String),
+}
+
 /// Material menu item
 #[derive(Component)]
 pub struct MaterialMenuItem {
@@ -204,6 +300,10 @@ pub struct MaterialMenuItem {
     pub disabled: bool,
     /// Whether the item is selected/checked
     pub selected: bool,
+    /// Leading checkbox or radio button, if the item is checkable
+    pub toggle: Option<MenuItemToggle>,
+    /// Check state shown by `toggle`
+    pub checked: bool,
     /// Interaction states
     pub pressed: bool,
     pub hovered: bool,
@@ -220,6 +320,8 @@ impl MaterialMenuItem {
             has_submenu: false,
             disabled: false,
             selected: false,
+            toggle: None,
+            checked: false,
             pressed: false,
             hovered: false,
         }
@@ -262,10 +364,50 @@ impl MaterialMenuItem {
         self
     }
 
+    /// Show a leading checkbox in the given state; selecting toggles it
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.toggle = Some(MenuItemToggle::Checkbox);
+        self.checked = checked;
+        self
+    }
+
+    /// Show a leading radio button; selecting checks it and unchecks the
+    /// other items of `group` in the same menu
+    pub fn radio(mut self, group: impl Into<String>) -> Self {
+        self.toggle = Some(MenuItemToggle::Radio(group.into()));
+        self
+    }
+
+    /// Set the check state of a checkable or radio item
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Icon name of the leading checkbox or radio button
+    pub fn toggle_icon_name(&self) -> Option<&'static str> {
+        let name = match (self.toggle.as_ref()?, self.checked) {
+            (MenuItemToggle::Checkbox, true) => "check_box",
+            (MenuItemToggle::Checkbox, false) => "check_box_outline_blank",
+            (MenuItemToggle::Radio(_), true) => "radio_button_checked",
+            (MenuItemToggle::Radio(_), false) => "radio_button_unchecked",
+        };
+        Some(name)
+    }
+
+    /// Get the color of the leading checkbox or radio button
+    pub fn toggle_icon_color(&self, theme: &MaterialTheme) -> Color {
+        if self.checked && !self.disabled {
+            theme.primary
+        } else {
+            self.icon_color(theme)
+        }
+    }
+
     /// Get the text color
     pub fn text_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            theme.on_surface.with_alpha(0.38)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
         } else {
             theme.on_surface
         }
@@ -274,7 +416,7 @@ impl MaterialMenuItem {
     /// Get the icon color
     pub fn icon_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            theme.on_surface.with_alpha(0.38)
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
         } else {
             theme.on_surface_variant
         }
@@ -290,10 +432,30 @@ impl MaterialMenuItem {
     }
 }
 
-/// Event to open a menu
+impl DisabledSource for MaterialMenuItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl StateLayerSource for MaterialMenuItem {
+    fn state_layer_color(&self, theme: &MaterialTheme) -> Color {
+        theme.on_surface
+    }
+
+    fn state_layer_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+/// Event when a menu has opened and been laid out
 #[derive(Event, bevy::prelude::Message)]
 pub struct MenuOpenEvent {
     pub entity: Entity,
+    /// Top-left corner of the menu in logical window pixels
+    pub position: Vec2,
+    /// Corner actually used, after flipping to stay on screen
+    pub anchor: MenuAnchor,
 }
 
 /// Event when menu is closed
@@ -307,6 +469,18 @@ pub struct MenuCloseEvent {
 pub struct MenuItemSelectEvent {
     pub menu_entity: Entity,
     pub item_entity: Entity,
+    /// New check state of a checkable or radio item (`None` for plain items)
+    pub checked: Option<bool>,
+}
+
+/// Event when a submenu item has been hovered for its menu's
+/// [`submenu_delay`](MaterialMenu::submenu_delay)
+///
+/// Sent once per hover; open the submenu in response.
+#[derive(Event, bevy::prelude::Message)]
+pub struct MenuSubmenuOpenEvent {
+    pub menu_entity: Entity,
+    pub item_entity: Entity,
 }
 
 /// Menu dimensions
@@ -314,14 +488,242 @@ pub const MENU_MIN_WIDTH: f32 = 112.0;
 pub const MENU_MAX_WIDTH: f32 = 280.0;
 pub const MENU_ITEM_HEIGHT: f32 = 48.0;
 
+/// Default hover time before a submenu opens (in seconds)
+pub const MENU_SUBMENU_DELAY: f32 = 0.2;
+
+/// Compute the top-left corner of a menu attached at `corner` of `trigger`.
+///
+/// All values are logical pixels with the origin at the top-left of `viewport`.
+/// The menu opens on the other vertical side, or aligns the other horizontal
+/// edge, when the preferred one would overflow and the other fits. It is then
+/// clamped to stay on screen.
+///
+/// Returns the corner actually used and the menu's top-left corner.
+pub fn resolve_menu_placement(
+    corner: MenuAnchor,
+    trigger: &ElementBounds,
+    menu_size: Vec2,
+    viewport: Vec2,
+) -> (MenuAnchor, Vec2) {
+    let below = trigger.y + trigger.height;
+    let above = trigger.y - menu_size.y;
+    let start = trigger.x;
+    let end = trigger.x + trigger.width - menu_size.x;
+
+    let fits_below = below + menu_size.y <= viewport.y;
+    let fits_above = above >= 0.0;
+    let opens_below = if corner.is_bottom() {
+        fits_below || !fits_above
+    } else {
+        !fits_above && fits_below
+    };
+
+    let fits_start = start + menu_size.x <= viewport.x;
+    let fits_end = end >= 0.0;
+    let aligns_start = if corner.is_left() {
+        fits_start || !fits_end
+    } else {
+        !fits_end && fits_start
+    };
+
+    let clamp_axis = |value: f32, size: f32, limit: f32| -> f32 {
+        if size >= limit {
+            0.0
+        } else {
+            value.clamp(0.0, limit - size)
+        }
+    };
+    let origin = Vec2::new(
+        clamp_axis(
+            if aligns_start { start } else { end },
+            menu_size.x,
+            viewport.x,
+        ),
+        clamp_axis(
+            if opens_below { below } else { above },
+            menu_size.y,
+            viewport.y,
+        ),
+    );
+
+    (MenuAnchor::from_sides(opens_below, aligns_start), origin)
+}
+
 /// System to handle menu visibility
-fn menu_visibility_system(mut menus: Query<(&MaterialMenu, &mut Node), Changed<MaterialMenu>>) {
-    for (menu, mut node) in menus.iter_mut() {
+fn menu_visibility_system(
+    mut menus: Query<(&MaterialMenu, &mut Node, &mut Visibility), Changed<MaterialMenu>>,
+) {
+    for (menu, mut node, mut visibility) in menus.iter_mut() {
         node.display = if menu.open {
             Display::Flex
         } else {
             Display::None
         };
+        // Hidden items of a closed menu are skipped by keyboard focus traversal.
+        visibility.set_if_neq(if menu.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+/// Close the frontmost menu when Escape is pressed.
+fn menu_keyboard_dismiss_system(
+    stack: Option<Res<OverlayStack>>,
+    mut menus: Query<(Entity, &mut MaterialMenu)>,
+    mut close_events: MessageWriter<MenuCloseEvent>,
+) {
+    let Some(stack) = stack else { return };
+
+    for (entity, mut menu) in menus.iter_mut() {
+        if menu.open && stack.escape_pressed_for(entity) {
+            menu.open = false;
+            close_events.write(MenuCloseEvent { entity });
+        }
+    }
+}
+
+/// Anchor the outside-click surface of new menus to their trigger or parent.
+///
+/// Menus are usually spawned next to or inside their trigger, so presses on the
+/// parent don't count as outside. Menus with [`MaterialMenu::anchor_to`] use
+/// that trigger instead. Insert a [`DismissOnOutsideClick`] yourself to pick a
+/// different owner.
+fn menu_outside_click_setup_system(
+    mut commands: Commands,
+    menus: Query<
+        (Entity, &MaterialMenu, Option<&ChildOf>),
+        (Added<MaterialMenu>, Without<DismissOnOutsideClick>),
+    >,
+) {
+    for (entity, menu, parent) in menus.iter() {
+        let owner = menu
+            .anchor_to
+            .unwrap_or_else(|| parent.map_or(entity, ChildOf::parent));
+        commands
+            .entity(entity)
+            .insert(DismissOnOutsideClick::new(owner));
+    }
+}
+
+/// Close menus when a press lands outside them.
+fn menu_outside_click_system(
+    mut clicks: MessageReader<OutsideClickEvent>,
+    mut menus: Query<&mut MaterialMenu>,
+    mut close_events: MessageWriter<MenuCloseEvent>,
+) {
+    for ev in clicks.read() {
+        let Ok(mut menu) = menus.get_mut(ev.entity) else {
+            continue;
+        };
+        if menu.open && menu.close_on_click_outside {
+            menu.open = false;
+            close_events.write(MenuCloseEvent { entity: ev.entity });
+        }
+    }
+}
+
+/// Open menus that have been announced with a [`MenuOpenEvent`]
+#[derive(Resource, Default)]
+struct AnnouncedMenus(HashSet<Entity>);
+
+/// Place anchored menus next to their trigger and announce opened menus.
+///
+/// Until a menu has been laid out, its size is estimated from its children.
+/// [`MenuOpenEvent`] is sent once the menu has a size, so its position is final.
+fn menu_position_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut menus: Query<(
+        Entity,
+        &MaterialMenu,
+        &mut Node,
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&ChildOf>,
+        Option<&Children>,
+    )>,
+    nodes: Query<(&UiGlobalTransform, &ComputedNode)>,
+    mut announced: ResMut<AnnouncedMenus>,
+    mut open_events: MessageWriter<MenuOpenEvent>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let viewport = Vec2::new(window.width(), window.height());
+    // UiGlobalTransform and ComputedNode are in physical pixels.
+    let top_left = |transform: &UiGlobalTransform, computed: &ComputedNode| {
+        (transform.translation - computed.size() / 2.0) * computed.inverse_scale_factor
+    };
+
+    announced
+        .0
+        .retain(|entity| menus.get(*entity).is_ok_and(|(_, menu, ..)| menu.open));
+
+    for (entity, menu, mut node, computed, transform, parent, children) in menus.iter_mut() {
+        if !menu.open {
+            continue;
+        }
+        let laid_out = computed.size().x > 0.0 && computed.size().y > 0.0;
+
+        let Some((trigger_transform, trigger_computed)) =
+            menu.anchor_to.and_then(|trigger| nodes.get(trigger).ok())
+        else {
+            if laid_out && announced.0.insert(entity) {
+                open_events.write(MenuOpenEvent {
+                    entity,
+                    position: top_left(transform, computed),
+                    anchor: menu.anchor,
+                });
+            }
+            continue;
+        };
+        if trigger_computed.is_empty() {
+            continue;
+        }
+
+        let scale = trigger_computed.inverse_scale_factor;
+        let trigger_top_left = top_left(trigger_transform, trigger_computed);
+        let trigger_size = trigger_computed.size() * scale;
+        let trigger = ElementBounds::new(
+            "menu_trigger",
+            trigger_top_left.x,
+            trigger_top_left.y,
+            trigger_size.x,
+            trigger_size.y,
+        );
+        let menu_size = if laid_out {
+            computed.size() * scale
+        } else {
+            let items = children.map_or(0, |c| c.len());
+            Vec2::new(
+                MENU_MIN_WIDTH,
+                items as f32 * MENU_ITEM_HEIGHT + 2.0 * Spacing::SMALL,
+            )
+        };
+
+        let (anchor, position) = resolve_menu_placement(menu.anchor, &trigger, menu_size, viewport);
+
+        // Absolute offsets are relative to the menu's parent.
+        let origin = parent
+            .and_then(|p| nodes.get(p.parent()).ok())
+            .map_or(Vec2::ZERO, |(t, c)| top_left(t, c));
+        let left = Val::Px(position.x - origin.x);
+        let top = Val::Px(position.y - origin.y);
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+            node.right = Val::Auto;
+            node.bottom = Val::Auto;
+        }
+
+        if laid_out && announced.0.insert(entity) {
+            open_events.write(MenuOpenEvent {
+                entity,
+                position,
+                anchor,
+            });
+        }
     }
 }
 
@@ -332,7 +734,7 @@ fn menu_shadow_system(mut menus: Query<(&MaterialMenu, &mut BoxShadow), Changed<
         if menu.open {
             *shadow = menu.elevation().to_box_shadow();
         } else {
-            *shadow = BoxShadow::default();
+            *shadow = Elevation::level_0().to_box_shadow();
         }
     }
 }
@@ -357,11 +759,21 @@ fn menu_item_interaction_system(
                 item.hovered = false;
 
                 if !item.has_submenu {
+                    let checked = match item.toggle {
+                        Some(MenuItemToggle::Checkbox) => Some(!item.checked),
+                        Some(MenuItemToggle::Radio(_)) => Some(true),
+                        None => None,
+                    };
+                    if let Some(checked) = checked {
+                        item.checked = checked;
+                    }
+
                     // Find the menu ancestor
                     if let Ok(menu_entity) = menus.get(parent.parent()) {
                         select_events.write(MenuItemSelectEvent {
                             menu_entity,
                             item_entity: entity,
+                            checked,
                         });
                     }
                 }
@@ -378,6 +790,102 @@ fn menu_item_interaction_system(
     }
 }
 
+/// Send [`MenuSubmenuOpenEvent`] once a submenu item has been hovered for the
+/// menu's submenu delay.
+fn menu_submenu_hover_system(
+    time: Res<Time>,
+    menus: Query<&MaterialMenu>,
+    items: Query<(Entity, &MaterialMenuItem, &ChildOf)>,
+    // Hover time per hovered submenu item; `None` once its event was sent
+    mut timers: Local<HashMap<Entity, Option<f32>>>,
+    mut open_events: MessageWriter<MenuSubmenuOpenEvent>,
+) {
+    let mut hovered = HashSet::new();
+    for (entity, item, parent) in items.iter() {
+        if !item.has_submenu || item.disabled || !item.hovered {
+            continue;
+        }
+        let Ok(menu) = menus.get(parent.parent()) else {
+            continue;
+        };
+        hovered.insert(entity);
+
+        let timer = timers.entry(entity).or_insert(Some(0.0));
+        let Some(elapsed) = timer.as_mut() else {
+            continue;
+        };
+        *elapsed += time.delta_secs();
+        if *elapsed >= menu.submenu_delay {
+            *timer = None;
+            open_events.write(MenuSubmenuOpenEvent {
+                menu_entity: parent.parent(),
+                item_entity: entity,
+            });
+        }
+    }
+    timers.retain(|entity, _| hovered.contains(entity));
+}
+
+/// Close menus when a plain item is selected, and uncheck the rest of a
+/// radio group when one of its items is checked.
+fn menu_item_select_system(
+    mut selections: MessageReader<MenuItemSelectEvent>,
+    mut menus: Query<(&mut MaterialMenu, &Children)>,
+    mut items: Query<&mut MaterialMenuItem>,
+) {
+    for ev in selections.read() {
+        let Ok((mut menu, children)) = menus.get_mut(ev.menu_entity) else {
+            continue;
+        };
+        let group = match items.get(ev.item_entity).map(|item| &item.toggle) {
+            Ok(Some(MenuItemToggle::Radio(group))) => group.clone(),
+            Ok(Some(MenuItemToggle::Checkbox)) => continue,
+            _ => {
+                if menu.close_on_select && menu.open {
+                    menu.open = false;
+                }
+                continue;
+            }
+        };
+
+        for child in children.iter().filter(|child| *child != ev.item_entity) {
+            if let Ok(mut item) = items.get_mut(child) {
+                let same_group =
+                    matches!(&item.toggle, Some(MenuItemToggle::Radio(g)) if *g == group);
+                if same_group && item.checked {
+                    item.checked = false;
+                }
+            }
+        }
+    }
+}
+
+/// Marker for the leading checkbox or radio button of a menu item
+#[derive(Component)]
+pub struct MenuItemToggleIcon;
+
+/// Keep the leading checkbox or radio button in sync with its item's state
+fn menu_item_toggle_icon_system(
+    theme: Option<Res<MaterialTheme>>,
+    items: Query<(&MaterialMenuItem, &Children), Changed<MaterialMenuItem>>,
+    mut icons: Query<(&mut MaterialIcon, &mut IconStyle), With<MenuItemToggleIcon>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (item, children) in items.iter() {
+        let Some(toggle_icon) = item.toggle_icon_name().and_then(MaterialIcon::from_name) else {
+            continue;
+        };
+        let color = item.toggle_icon_color(&theme);
+        for child in children.iter() {
+            if let Ok((mut icon, mut style)) = icons.get_mut(child) {
+                icon.id = toggle_icon.id;
+                style.color = color;
+            }
+        }
+    }
+}
+
 /// System to update menu item styles
 fn menu_item_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -409,6 +917,13 @@ impl MenuBuilder {
         self
     }
 
+    /// Position the menu at `corner` of `trigger` while open, flipping to
+    /// stay on screen
+    pub fn anchor_to(mut self, trigger: Entity, corner: MenuAnchor) -> Self {
+        self.menu = self.menu.anchor_to(trigger, corner);
+        self
+    }
+
     /// Start open
     pub fn open(mut self) -> Self {
         self.menu.open = true;
@@ -421,6 +936,20 @@ impl MenuBuilder {
         self
     }
 
+    /// Keep open when an item is selected
+    pub fn no_close_on_select(mut self) -> Self {
+        self.menu.close_on_select = false;
+        self
+    }
+
+    /// Set the hover time before a submenu item opens its submenu (in
+    /// seconds). Shorter suits mouse input; longer avoids opening submenus
+    /// while the pointer passes over items.
+    pub fn submenu_delay(mut self, delay: f32) -> Self {
+        self.menu.submenu_delay = delay;
+        self
+    }
+
     /// Build the menu bundle with native BoxShadow
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.menu.surface_color(theme);
@@ -439,7 +968,9 @@ impl MenuBuilder {
             BackgroundColor(bg_color),
             BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
             // Native Bevy 0.17 shadow support (starts hidden since menu is closed)
-            BoxShadow::default(),
+            Elevation::level_0().to_box_shadow(),
+            // Above dialogs, so menus opened from a dialog aren't covered by it
+            GlobalZIndex(Z_MENU),
         )
     }
 }
@@ -500,14 +1031,36 @@ impl MenuItemBuilder {
         self
     }
 
+    /// Show a leading checkbox in the given state; selecting toggles it
+    /// without closing the menu
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.item = self.item.checkable(checked);
+        self
+    }
+
+    /// Show a leading radio button in `group`; selecting checks it, unchecks
+    /// the rest of the group, and keeps the menu open
+    pub fn radio(mut self, group: impl Into<String>) -> Self {
+        self.item = self.item.radio(group);
+        self
+    }
+
+    /// Set the initial check state of a checkable or radio item
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.item.checked = checked;
+        self
+    }
+
     /// Build the menu item bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.item.background_color(theme);
+        let state_layer = StateLayer::new(self.item.state_layer_color(theme));
 
         (
             self.item,
             Button,
             RippleHost::new(),
+            state_layer,
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Px(MENU_ITEM_HEIGHT),
@@ -521,6 +1074,105 @@ impl MenuItemBuilder {
     }
 }
 
+/// Marker for the trailing shortcut text of a menu item
+#[derive(Component)]
+pub struct MenuItemShortcut;
+
+/// Children of a menu item row: checkbox or radio button, leading icon,
+/// label, shortcut, trailing icon
+struct MenuItemContent {
+    label: String,
+    toggle_icon: Option<(MaterialIcon, Color)>,
+    leading_icon: Option<MaterialIcon>,
+    trailing_icon: Option<MaterialIcon>,
+    shortcut: Option<String>,
+    label_color: Color,
+    icon_color: Color,
+}
+
+impl MenuItemContent {
+    fn from(item: &MaterialMenuItem, theme: &MaterialTheme) -> Self {
+        Self {
+            label: item.label.clone(),
+            toggle_icon: item
+                .toggle_icon_name()
+                .and_then(MaterialIcon::from_name)
+                .map(|icon| (icon, item.toggle_icon_color(theme))),
+            leading_icon: item
+                .leading_icon
+                .as_deref()
+                .and_then(MaterialIcon::from_name),
+            trailing_icon: item
+                .trailing_icon
+                .as_deref()
+                .and_then(MaterialIcon::from_name),
+            shortcut: item.trailing_text.clone(),
+            label_color: item.text_color(theme),
+            icon_color: item.icon_color(theme),
+        }
+    }
+
+    fn spawn(self, item: &mut ChildSpawnerCommands) {
+        if let Some((icon, color)) = self.toggle_icon {
+            item.spawn((
+                MenuItemToggleIcon,
+                icon,
+                IconStyle::outlined().with_color(color).with_size(24.0),
+            ));
+        }
+
+        if let Some(icon) = self.leading_icon {
+            item.spawn((
+                icon,
+                IconStyle::outlined()
+                    .with_color(self.icon_color)
+                    .with_size(24.0),
+            ));
+        }
+
+        // The label takes the free space so the shortcut and trailing icon
+        // stay right-aligned.
+        item.spawn((
+            Text::new(self.label),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(self.label_color),
+            Node {
+                flex_grow: 1.0,
+                min_width: Val::Px(0.0),
+                ..default()
+            },
+        ));
+
+        if let Some(shortcut) = self.shortcut {
+            item.spawn((
+                MenuItemShortcut,
+                Text::new(shortcut),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(self.icon_color),
+                Node {
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+            ));
+        }
+
+        if let Some(icon) = self.trailing_icon {
+            item.spawn((
+                icon,
+                IconStyle::outlined()
+                    .with_color(self.icon_color)
+                    .with_size(24.0),
+            ));
+        }
+    }
+}
+
 /// Marker for menu divider
 #[derive(Component)]
 pub struct MenuDivider;
@@ -573,39 +1225,182 @@ impl SpawnMenuChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_menu_item(&mut self, theme: &MaterialTheme, label: impl Into<String>) {
-        let label_str = label.into();
-        let label_color = theme.on_surface;
-
-        self.spawn(MenuItemBuilder::new(&label_str).build(theme))
-            .with_children(|item| {
-                item.spawn((
-                    Text::new(&label_str),
-                    TextFont {
-                        font_size: 14.0,
-                        ..default()
-                    },
-                    TextColor(label_color),
-                ));
-            });
+        self.spawn_menu_item_with(theme, MenuItemBuilder::new(label));
     }
 
     fn spawn_menu_item_with(&mut self, theme: &MaterialTheme, builder: MenuItemBuilder) {
-        let label_str = builder.item.label.clone();
-        let label_color = theme.on_surface;
+        let content = MenuItemContent::from(&builder.item, theme);
 
-        self.spawn(builder.build(theme)).with_children(|item| {
-            item.spawn((
-                Text::new(&label_str),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        });
+        self.spawn(builder.build(theme))
+            .with_children(|item| content.spawn(item));
     }
 
     fn spawn_menu_divider(&mut self, theme: &MaterialTheme) {
         self.spawn(create_menu_divider(theme));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_keeps_menu_open_for_toggles() {
+        let mut app = App::new();
+        app.add_message::<MenuItemSelectEvent>()
+            .add_systems(Update, menu_item_select_system);
+
+        let menu = app.world_mut().spawn(MaterialMenu::new().open()).id();
+        let spawn_item = |app: &mut App, item: MaterialMenuItem| {
+            app.world_mut().spawn((item, ChildOf(menu))).id()
+        };
+        let by_name = spawn_item(
+            &mut app,
+            MaterialMenuItem::new("Name").radio("sort").checked(true),
+        );
+        let by_date = spawn_item(&mut app, MaterialMenuItem::new("Date").radio("sort"));
+        let grid = spawn_item(
+            &mut app,
+            MaterialMenuItem::new("Grid").radio("view").checked(true),
+        );
+        let plain = spawn_item(&mut app, MaterialMenuItem::new("Refresh"));
+
+        // The interaction system checks the pressed radio before sending the event.
+        app.world_mut()
+            .get_mut::<MaterialMenuItem>(by_date)
+            .unwrap()
+            .checked = true;
+        app.world_mut().write_message(MenuItemSelectEvent {
+            menu_entity: menu,
+            item_entity: by_date,
+            checked: Some(true),
+        });
+        app.update();
+
+        let checked =
+            |app: &App, item: Entity| app.world().get::<MaterialMenuItem>(item).unwrap().checked;
+        assert!(!checked(&app, by_name));
+        assert!(checked(&app, by_date));
+        assert!(checked(&app, grid), "other groups are untouched");
+        assert!(app.world().get::<MaterialMenu>(menu).unwrap().open);
+
+        app.world_mut().write_message(MenuItemSelectEvent {
+            menu_entity: menu,
+            item_entity: plain,
+            checked: None,
+        });
+        app.update();
+        assert!(!app.world().get::<MaterialMenu>(menu).unwrap().open);
+    }
+
+    #[test]
+    fn test_menu_items_are_focusable_only_while_open() {
+        use crate::focus::{FocusGroup, Focusable};
+
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            (
+                adopt_focus_group_system::<MaterialMenu, MaterialMenuItem>,
+                menu_visibility_system,
+            ),
+        );
+        let menu = app
+            .world_mut()
+            .spawn((MaterialMenu::new(), Node::default()))
+            .id();
+        let item = app
+            .world_mut()
+            .spawn((MaterialMenuItem::new("Copy"), ChildOf(menu)))
+            .id();
+        app.update();
+
+        assert!(app.world().get::<FocusGroup>(menu).is_some());
+        assert!(app.world().get::<Focusable>(item).is_some());
+        assert_eq!(
+            app.world().get::<Visibility>(menu),
+            Some(&Visibility::Hidden)
+        );
+
+        app.world_mut().get_mut::<MaterialMenu>(menu).unwrap().open = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(menu),
+            Some(&Visibility::Inherited)
+        );
+    }
+
+    #[test]
+    fn test_submenu_opens_after_hover_delay() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<MenuSubmenuOpenEvent>()
+            .add_systems(Update, menu_submenu_hover_system);
+
+        let menu = app
+            .world_mut()
+            .spawn(MaterialMenu::new().open().submenu_delay(0.3))
+            .id();
+        let item = app
+            .world_mut()
+            .spawn((MaterialMenuItem::new("Share").submenu(), ChildOf(menu)))
+            .id();
+        app.world_mut()
+            .get_mut::<MaterialMenuItem>(item)
+            .unwrap()
+            .hovered = true;
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(200));
+            app.update();
+            app.world()
+                .resource::<Messages<MenuSubmenuOpenEvent>>()
+                .iter_current_update_messages()
+                .map(|ev| (ev.menu_entity, ev.item_entity))
+                .collect::<Vec<_>>()
+        };
+        assert!(step(&mut app).is_empty());
+        assert_eq!(step(&mut app), vec![(menu, item)]);
+        assert!(step(&mut app).is_empty(), "sent once per hover");
+    }
+
+    #[test]
+    fn test_shortcut_sits_between_label_and_trailing_icon() {
+        let mut app = App::new();
+        let theme = MaterialTheme::default();
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|menu| {
+                menu.spawn_menu_item_with(
+                    &theme,
+                    MenuItemBuilder::new("Copy")
+                        .leading_icon("content_copy")
+                        .trailing_icon("more_vert")
+                        .shortcut("Ctrl+C"),
+                );
+            });
+        app.world_mut().flush();
+
+        let children = app
+            .world_mut()
+            .query_filtered::<&Children, With<MaterialMenuItem>>()
+            .single(app.world())
+            .unwrap()
+            .to_vec();
+        assert_eq!(children.len(), 4);
+
+        let world = app.world();
+        assert!(world.get::<MaterialIcon>(children[0]).is_some());
+        assert_eq!(world.get::<Text>(children[1]).unwrap().0, "Copy");
+        assert!(world.get::<MenuItemShortcut>(children[2]).is_some());
+        assert_eq!(world.get::<Text>(children[2]).unwrap().0, "Ctrl+C");
+        assert_eq!(
+            world.get::<TextColor>(children[2]).unwrap().0,
+            theme.on_surface_variant
+        );
+        assert!(world.get::<MaterialIcon>(children[3]).is_some());
+    }
+}
@@ -7,6 +7,11 @@
 
 use bevy::prelude::*;
 
+use bevy::picking::Pickable;
+
+use crate::focus::Focusable;
+use crate::icons::MaterialIcon;
+use crate::theme::MaterialTheme;
 use crate::tokens::{Duration, Easing};
 
 /// Plugin for motion and animation systems
@@ -14,10 +19,25 @@ pub struct MotionPlugin;
 
 impl Plugin for MotionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, animate_state_layers);
+        app.init_resource::<ReducedMotion>();
+        app.add_systems(
+            Update,
+            (
+                animate_state_layers,
+                state_layer_system,
+                (animated_icon_setup_system, animated_icon_system).chain(),
+            ),
+        );
     }
 }
 
+/// Accessibility preference for motion-sensitive users.
+///
+/// When `true`, component animations (snackbar slides, tooltip fades, ripples, springs,
+/// transitions) jump straight to their final state instead of animating.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReducedMotion(pub bool);
+
 // ============================================================================
 // Easing Functions
 // ============================================================================
@@ -155,6 +175,16 @@ impl SpringConfig {
         }
     }
 
+    /// Create a critically damped spring: the fastest settle without overshoot
+    pub fn critically_damped(stiffness: f32) -> Self {
+        let mass = 1.0;
+        Self {
+            stiffness,
+            damping: 2.0 * (stiffness * mass).sqrt(),
+            mass,
+        }
+    }
+
     /// Create a smooth spring (critically damped)
     pub fn smooth() -> Self {
         Self {
@@ -271,6 +301,8 @@ pub struct StateLayer {
     pub animating: bool,
     /// Base color for the state layer
     pub color: Color,
+    /// Disabled hosts show no state layer
+    pub disabled: bool,
 }
 
 impl Default for StateLayer {
@@ -281,6 +313,7 @@ impl Default for StateLayer {
             timer: Timer::from_seconds(Duration::SHORT3, TimerMode::Once),
             animating: false,
             color: Color::WHITE,
+            disabled: false,
         }
     }
 }
@@ -365,10 +398,126 @@ impl StateLayer {
     pub const DRAGGED_OPACITY: f32 = 0.16;
 }
 
-/// System to animate state layers
+/// Interactive components whose [`StateLayer`] color follows their own state.
+pub trait StateLayerSource: Component {
+    /// Color of the state layer overlay (the component's "on" color)
+    fn state_layer_color(&self, theme: &MaterialTheme) -> Color;
+
+    /// Whether the component is disabled
+    fn state_layer_disabled(&self) -> bool {
+        false
+    }
+}
+
+/// Marker for the overlay node a [`StateLayer`] host draws its state layer on
+#[derive(Component)]
+pub struct StateLayerOverlay;
+
+/// Target state layer opacity for an interaction state
+pub fn state_layer_target(interaction: Interaction, focus_visible: bool) -> f32 {
+    match interaction {
+        Interaction::Pressed => StateLayer::PRESSED_OPACITY,
+        Interaction::Hovered => StateLayer::HOVER_OPACITY,
+        Interaction::None if focus_visible => StateLayer::FOCUS_OPACITY,
+        Interaction::None => 0.0,
+    }
+}
+
+/// Keep a component's [`StateLayer`] color and disabled state in sync.
+///
+/// Register once per [`StateLayerSource`] component type.
+pub fn sync_state_layer_system<T: StateLayerSource>(
+    theme: Option<Res<MaterialTheme>>,
+    mut layers: Query<(Ref<T>, &mut StateLayer)>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (source, mut layer) in layers.iter_mut() {
+        if !theme.is_changed() && !source.is_changed() {
+            continue;
+        }
+        layer.color = source.state_layer_color(&theme);
+        layer.disabled = source.state_layer_disabled();
+    }
+}
+
+/// Overlay the MD3 state layer on interactive entities.
+///
+/// Entities with both `StateLayer` and `Interaction` get an overlay child sized
+/// to the host; hover, focus-visible, and press drive its opacity.
+fn state_layer_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut hosts: Query<
+        (
+            Entity,
+            &Interaction,
+            &mut StateLayer,
+            Option<&Focusable>,
+            Option<&BorderRadius>,
+            Option<&Children>,
+        ),
+        Without<StateLayerOverlay>,
+    >,
+    mut overlays: Query<(&mut BackgroundColor, &mut BorderRadius), With<StateLayerOverlay>>,
+) {
+    for (entity, interaction, mut layer, focusable, radius, children) in hosts.iter_mut() {
+        let focus_visible = focusable.is_some_and(|f| f.focus_visible);
+        let target = if layer.disabled {
+            0.0
+        } else {
+            state_layer_target(*interaction, focus_visible)
+        };
+        if (layer.target_opacity - target).abs() > 0.001 {
+            layer.set_target(target);
+        }
+        if layer.animating {
+            layer.update(time.delta_secs());
+        }
+
+        let radius = radius.copied().unwrap_or_default();
+        let overlay = children.and_then(|c| c.iter().find(|child| overlays.contains(*child)));
+        match overlay {
+            Some(overlay) => {
+                let Ok((mut bg, mut overlay_radius)) = overlays.get_mut(overlay) else {
+                    continue;
+                };
+                let color = layer.current_color();
+                if bg.0 != color {
+                    bg.0 = color;
+                }
+                if *overlay_radius != radius {
+                    *overlay_radius = radius;
+                }
+            }
+            None => {
+                let overlay = commands
+                    .spawn((
+                        StateLayerOverlay,
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            top: Val::Px(0.0),
+                            right: Val::Px(0.0),
+                            bottom: Val::Px(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(layer.current_color()),
+                        radius,
+                        Pickable::IGNORE,
+                    ))
+                    .id();
+                // First child so the layer sits below the content.
+                commands.entity(entity).insert_children(0, &[overlay]);
+            }
+        }
+    }
+}
+
+/// System to animate standalone state layers (without an `Interaction` host)
 fn animate_state_layers(
     time: Res<Time>,
-    mut state_layers: Query<(&mut StateLayer, Option<&mut BackgroundColor>)>,
+    mut state_layers: Query<(&mut StateLayer, Option<&mut BackgroundColor>), Without<Interaction>>,
 ) {
     for (mut layer, bg_color) in state_layers.iter_mut() {
         layer.update(time.delta_secs());
@@ -462,6 +611,195 @@ impl AnimatedValue {
     }
 }
 
+// ============================================================================
+// Animated Icon
+// ============================================================================
+
+/// Crossfades between two icons, e.g. play/pause or menu/close.
+///
+/// Both icons are spawned as overlaid children of this entity. `progress` runs
+/// from 0.0 (showing `from`) to 1.0 (showing `to`) and is eased with
+/// [`ease_standard`] before being applied to each layer's alpha.
+///
+/// When spawned as a child of a toggleable `MaterialIconButton`, the button's
+/// selected state drives the direction and its icon color is applied to both icons.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AnimatedIcon {
+    /// Icon shown at progress 0.0
+    pub from: MaterialIcon,
+    /// Icon shown at progress 1.0
+    pub to: MaterialIcon,
+    /// Linear animation progress (0.0 to 1.0)
+    pub progress: f32,
+    /// Duration of a full transition in seconds
+    pub duration: f32,
+    /// Whether the animation is heading towards `to`
+    pub forward: bool,
+}
+
+/// Which side of an [`AnimatedIcon`] a child layer renders.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimatedIconLayer {
+    From,
+    To,
+}
+
+impl AnimatedIcon {
+    /// Create an animated icon resting on `from`
+    pub fn new(from: MaterialIcon, to: MaterialIcon) -> Self {
+        Self {
+            from,
+            to,
+            progress: 0.0,
+            duration: Duration::SHORT4,
+            forward: false,
+        }
+    }
+
+    /// Set the transition duration
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the color of both icons
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    /// Set the size of both icons
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.from.size = size;
+        self.to.size = size;
+        self
+    }
+
+    /// Set the color of both icons
+    pub fn set_color(&mut self, color: Color) {
+        self.from.color = color;
+        self.to.color = color;
+    }
+
+    /// Flip the animation direction
+    pub fn toggle(&mut self) {
+        self.forward = !self.forward;
+    }
+
+    /// Animate towards `to` when `forward` is true, otherwise towards `from`
+    pub fn set_forward(&mut self, forward: bool) {
+        self.forward = forward;
+    }
+
+    /// Progress value the animation is heading towards
+    pub fn target(&self) -> f32 {
+        if self.forward {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether the animation has not yet reached its target
+    pub fn is_animating(&self) -> bool {
+        (self.progress - self.target()).abs() > f32::EPSILON
+    }
+
+    /// Advance progress towards the target
+    pub fn update(&mut self, dt: f32) {
+        let step = if self.duration > 0.0 {
+            dt / self.duration
+        } else {
+            1.0
+        };
+        self.progress = if self.forward {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+    }
+
+    /// Alpha multipliers for the `from` and `to` layers
+    pub fn layer_alphas(&self) -> (f32, f32) {
+        // The easing curve is solved numerically; keep the resting states exact.
+        let eased = match self.progress {
+            p if p <= 0.0 => 0.0,
+            p if p >= 1.0 => 1.0,
+            p => ease_standard(p),
+        };
+        (1.0 - eased, eased)
+    }
+
+    fn layer_icon(&self, layer: AnimatedIconLayer) -> MaterialIcon {
+        let (from_alpha, to_alpha) = self.layer_alphas();
+        let (icon, alpha) = match layer {
+            AnimatedIconLayer::From => (self.from, from_alpha),
+            AnimatedIconLayer::To => (self.to, to_alpha),
+        };
+        let base_alpha = icon.color.alpha();
+        icon.with_color(icon.color.with_alpha(base_alpha * alpha))
+    }
+}
+
+fn animated_icon_setup_system(
+    mut commands: Commands,
+    icons: Query<(Entity, &AnimatedIcon, Has<Node>), Added<AnimatedIcon>>,
+) {
+    for (entity, icon, has_node) in icons.iter() {
+        let size = icon.from.size.max(icon.to.size);
+        if !has_node {
+            commands.entity(entity).insert(Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..default()
+            });
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for layer in [AnimatedIconLayer::From, AnimatedIconLayer::To] {
+                parent.spawn((
+                    layer,
+                    icon.layer_icon(layer),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        top: Val::Px(0.0),
+                        ..default()
+                    },
+                ));
+            }
+        });
+    }
+}
+
+fn animated_icon_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut icons: Query<(&mut AnimatedIcon, &Children)>,
+    mut layers: Query<(&AnimatedIconLayer, &mut MaterialIcon)>,
+) {
+    let dt = if reduced_motion.is_some_and(|r| r.0) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+
+    for (mut icon, children) in icons.iter_mut() {
+        if icon.is_animating() {
+            icon.update(dt);
+        }
+        if !icon.is_changed() {
+            continue;
+        }
+
+        for child in children.iter() {
+            if let Ok((layer, mut material_icon)) = layers.get_mut(child) {
+                *material_icon = icon.layer_icon(*layer);
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -513,6 +851,16 @@ mod tests {
         assert!((spring.value - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_critically_damped_spring_does_not_overshoot() {
+        let mut spring = SpringAnimation::new(0.0, 1.0, SpringConfig::critically_damped(300.0));
+        for _ in 0..240 {
+            spring.update(1.0 / 240.0);
+            assert!(spring.value <= 1.0 + 1e-3);
+        }
+        assert!((spring.value - 1.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_state_layer_opacity() {
         let mut layer = StateLayer::default();
@@ -540,4 +888,39 @@ mod tests {
         assert!(value.complete);
         assert!((value.current - 100.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_animated_icon_toggle_crossfades() {
+        let play = MaterialIcon::from_name("play_arrow").unwrap();
+        let pause = MaterialIcon::from_name("pause").unwrap();
+        let mut icon = AnimatedIcon::new(play, pause).with_duration(0.2);
+
+        assert_eq!(icon.layer_alphas(), (1.0, 0.0));
+        assert!(!icon.is_animating());
+
+        icon.toggle();
+        assert!(icon.is_animating());
+        icon.update(0.1);
+        let (from_alpha, to_alpha) = icon.layer_alphas();
+        assert!(from_alpha > 0.0 && to_alpha > 0.0);
+        assert!((from_alpha + to_alpha - 1.0).abs() < 0.001);
+
+        icon.update(0.2);
+        assert!(!icon.is_animating());
+        assert!((icon.layer_alphas().1 - 1.0).abs() < 0.001);
+
+        // Reversing mid-way heads back towards `from`
+        icon.toggle();
+        icon.update(0.05);
+        assert!(icon.progress < 1.0);
+        assert!(icon.is_animating());
+    }
+
+    #[test]
+    fn test_state_layer_targets() {
+        assert_eq!(state_layer_target(Interaction::Hovered, false), 0.08);
+        assert_eq!(state_layer_target(Interaction::Pressed, false), 0.12);
+        assert_eq!(state_layer_target(Interaction::None, true), 0.12);
+        assert_eq!(state_layer_target(Interaction::None, false), 0.0);
+    }
 }
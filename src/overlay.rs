@@ -0,0 +1,565 @@
+//! Shared dismissal for overlays
+//!
+//! Dialogs, menus, select dropdowns, pickers, and persistent tooltips register
+//! with the [`OverlayStack`] while they are open. When Escape is pressed, only
+//! the frontmost overlay is dismissed, so nested overlays close one at a time.
+//!
+//! Surfaces tagged with [`DismissOnOutsideClick`] get an [`OutsideClickEvent`]
+//! when a press lands outside them, so components don't each hit-test clicks.
+//!
+//! The other way round, dialogs and pickers tagged with [`OpenOnShortcut`]
+//! open when their [`KeyCombo`] is pressed, for fully keyboard-driven flows.
+//!
+//! Overlay surfaces are stacked with the `Z_*` [`GlobalZIndex`] layers, from
+//! back to front:
+//!
+//! | Layer | Used by |
+//! |-------|---------|
+//! | [`Z_SCRIM`] | Dialog scrims |
+//! | [`Z_DIALOG`] | Dialogs, date and time pickers |
+//! | [`Z_MENU`] | Menus, select dropdowns, search suggestions, autocomplete |
+//! | [`Z_SNACKBAR`] | Snackbars |
+//! | [`Z_TOOLTIP`] | Tooltips |
+//! | [`Z_CONTAINER_TRANSFORM`] | Surfaces morphing in a container transform |
+//!
+//! Menus sit above dialogs, so a menu opened from inside a dialog renders on top of it.
+//!
+//! Surfaces anchored to a trigger (menus, tooltips, select dropdowns) place
+//! themselves from the previous frame's layout. When the window is resized
+//! their position systems run again in [`OverlayReflowSystems`], right after
+//! layout, so an open menu is moved next to its trigger and clamped back
+//! on-screen instead of drifting or clipping.
+
+use bevy::ecs::component::Mutable;
+use bevy::ecs::entity::Entities;
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy::ui::{UiGlobalTransform, UiSystems};
+use bevy::window::{PrimaryWindow, RequestRedraw, WindowResized};
+
+use crate::button::KeyCombo;
+use crate::text_field::MaterialTextField;
+
+/// [`GlobalZIndex`] of dialog scrims
+pub const Z_SCRIM: i32 = 1000;
+
+/// [`GlobalZIndex`] of top app bar buttons that stay clickable above scrims
+pub const Z_APP_BAR_CONTROLS: i32 = 1002;
+
+/// [`GlobalZIndex`] of dialogs and picker dialogs
+pub const Z_DIALOG: i32 = 1010;
+
+/// [`GlobalZIndex`] of menus and dropdowns
+pub const Z_MENU: i32 = 1100;
+
+/// [`GlobalZIndex`] of snackbars
+pub const Z_SNACKBAR: i32 = 1200;
+
+/// [`GlobalZIndex`] of tooltips
+pub const Z_TOOLTIP: i32 = 1300;
+
+/// [`GlobalZIndex`] of a surface while it morphs in a container transform
+pub const Z_CONTAINER_TRANSFORM: i32 = 1400;
+
+/// Plugin that maintains the [`OverlayStack`]
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OverlayStack>()
+            .add_message::<OutsideClickEvent>()
+            .add_message::<WindowResized>()
+            .add_message::<RequestRedraw>()
+            .configure_sets(
+                PostUpdate,
+                OverlayReflowSystems
+                    .after(UiSystems::Layout)
+                    .run_if(on_message::<WindowResized>),
+            )
+            .add_systems(
+                PreUpdate,
+                (overlay_escape_system, outside_click_system).after(InputSystems),
+            )
+            .add_systems(
+                PostUpdate,
+                request_reflow_redraw_system.in_set(OverlayReflowSystems),
+            );
+    }
+}
+
+/// Position systems of trigger-anchored overlays, re-run after layout on frames
+/// where the window was resized.
+///
+/// Components add their position system here in addition to `Update`. The new
+/// positions are laid out on the next frame, which is requested explicitly so
+/// apps in reactive update mode don't wait for further input.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OverlayReflowSystems;
+
+/// Open overlays, ordered from back to front.
+///
+/// Components keep their entry in sync through [`track_overlay_system`] and
+/// consult [`OverlayStack::escape_pressed_for`] in their keyboard-dismiss systems.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct OverlayStack {
+    entries: Vec<Entity>,
+    escape_target: Option<Entity>,
+}
+
+impl OverlayStack {
+    /// Register `entity` as the frontmost overlay (moving it there if already open)
+    pub fn push(&mut self, entity: Entity) {
+        self.remove(entity);
+        self.entries.push(entity);
+    }
+
+    /// Unregister `entity`
+    pub fn remove(&mut self, entity: Entity) {
+        self.entries.retain(|e| *e != entity);
+    }
+
+    /// Register or unregister `entity` depending on whether it is open
+    pub fn sync(&mut self, entity: Entity, open: bool) {
+        if !open {
+            self.remove(entity);
+        } else if !self.contains(entity) {
+            self.entries.push(entity);
+        }
+    }
+
+    /// The frontmost open overlay
+    pub fn top(&self) -> Option<Entity> {
+        self.entries.last().copied()
+    }
+
+    /// Whether `entity` is the frontmost open overlay
+    pub fn is_top(&self, entity: Entity) -> bool {
+        self.top() == Some(entity)
+    }
+
+    /// Whether `entity` is registered as open
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entries.contains(&entity)
+    }
+
+    /// Number of open overlays
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no overlay is open
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether Escape was pressed this frame while `entity` was frontmost.
+    ///
+    /// At most one overlay sees `true` per key press, even if it closes and
+    /// the next overlay becomes frontmost within the same frame.
+    pub fn escape_pressed_for(&self, entity: Entity) -> bool {
+        self.escape_target == Some(entity)
+    }
+}
+
+/// Components that behave as dismissible overlays
+pub trait Overlay: Component {
+    /// Whether the overlay is currently open
+    fn overlay_open(&self) -> bool;
+}
+
+/// Overlays that can be opened without a trigger, e.g. by [`OpenOnShortcut`]
+pub trait OpenableOverlay: Overlay {
+    /// Open the overlay
+    fn open_overlay(&mut self);
+}
+
+/// Keep the [`OverlayStack`] in sync with a component's open state.
+///
+/// Register once per [`Overlay`] component type.
+pub fn track_overlay_system<T: Overlay>(
+    stack: Option<ResMut<OverlayStack>>,
+    overlays: Query<(Entity, &T), Changed<T>>,
+    mut removed: RemovedComponents<T>,
+) {
+    let Some(mut stack) = stack else { return };
+
+    for entity in removed.read() {
+        stack.remove(entity);
+    }
+    for (entity, overlay) in overlays.iter() {
+        stack.sync(entity, overlay.overlay_open());
+    }
+}
+
+/// Request one more frame so repositioned overlays get laid out.
+fn request_reflow_redraw_system(mut redraw: MessageWriter<RequestRedraw>) {
+    redraw.write(RequestRedraw);
+}
+
+/// Dismiss a shown overlay surface when a press lands outside it.
+///
+/// Presses on the surface, its `owner` (usually the trigger that opened it),
+/// or any of their descendants count as inside. Hidden or collapsed surfaces
+/// are ignored.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DismissOnOutsideClick {
+    /// Entity anchoring the surface; presses on it never dismiss
+    pub owner: Entity,
+}
+
+impl DismissOnOutsideClick {
+    /// Dismiss when a press lands outside the surface and `owner`
+    pub fn new(owner: Entity) -> Self {
+        Self { owner }
+    }
+}
+
+/// Open the overlay on this entity when its [`KeyCombo`] is pressed.
+///
+/// Works on dialogs, date pickers, and time pickers. Pressing the combo while
+/// the overlay is already open does nothing; Escape still closes it. Like
+/// [`ButtonShortcut`](crate::button::ButtonShortcut)s, combos without
+/// modifiers are ignored while a text field has focus.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenOnShortcut(pub KeyCombo);
+
+/// Open [`OpenableOverlay`]s whose [`OpenOnShortcut`] combo was just pressed.
+///
+/// Register once per [`OpenableOverlay`] component type.
+pub fn open_on_shortcut_system<T: OpenableOverlay + Component<Mutability = Mutable>>(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlays: Query<(&OpenOnShortcut, &mut T)>,
+    text_fields: Query<&MaterialTextField>,
+) {
+    let Some(keys) = keys else { return };
+    if keys.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    let typing = text_fields.iter().any(|field| field.focused);
+    for (shortcut, mut overlay) in overlays.iter_mut() {
+        if overlay.overlay_open() || (typing && !shortcut.0.has_modifiers()) {
+            continue;
+        }
+        if shortcut.0.just_pressed(&keys) {
+            overlay.open_overlay();
+        }
+    }
+}
+
+/// Sent when a press lands outside a [`DismissOnOutsideClick`] surface
+#[derive(Event, Clone, Copy, Debug, bevy::prelude::Message)]
+pub struct OutsideClickEvent {
+    /// The surface that was clicked outside of
+    pub entity: Entity,
+    /// The surface's owner
+    pub owner: Entity,
+}
+
+/// Drop despawned overlays and pick the one Escape dismisses this frame.
+fn overlay_escape_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    entities: &Entities,
+    mut stack: ResMut<OverlayStack>,
+) {
+    if stack.entries.iter().any(|e| !entities.contains(*e)) {
+        stack.entries.retain(|e| entities.contains(*e));
+    }
+
+    let escape = keys.is_some_and(|keys| keys.just_pressed(KeyCode::Escape));
+    let target = if escape { stack.top() } else { None };
+    if stack.escape_target != target {
+        stack.escape_target = target;
+    }
+}
+
+/// Hit-test left presses against every shown [`DismissOnOutsideClick`] surface.
+fn outside_click_system(
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    surfaces: Query<(Entity, &DismissOnOutsideClick)>,
+    nodes: Query<(
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&InheritedVisibility>,
+    )>,
+    children: Query<&Children>,
+    mut events: MessageWriter<OutsideClickEvent>,
+) {
+    if !mouse.is_some_and(|mouse| mouse.just_pressed(MouseButton::Left)) {
+        return;
+    }
+    // Both the cursor and `UiGlobalTransform` are in physical pixels.
+    let Some(cursor) = windows
+        .single()
+        .ok()
+        .and_then(Window::physical_cursor_position)
+    else {
+        return;
+    };
+
+    let shown = |entity: Entity| {
+        nodes.get(entity).is_ok_and(|(computed, _, visibility)| {
+            !computed.is_empty() && visibility.is_none_or(|v| v.get())
+        })
+    };
+    let hit = |entity: Entity| {
+        nodes
+            .get(entity)
+            .is_ok_and(|(computed, transform, visibility)| {
+                visibility.is_none_or(|v| v.get()) && computed.contains_point(*transform, cursor)
+            })
+    };
+
+    for (entity, dismiss) in surfaces.iter() {
+        if !shown(entity) {
+            continue;
+        }
+
+        let inside = [entity, dismiss.owner]
+            .into_iter()
+            .flat_map(|root| std::iter::once(root).chain(children.iter_descendants(root)))
+            .any(hit);
+        if !inside {
+            events.write(OutsideClickEvent {
+                entity,
+                owner: dismiss.owner,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_stack_order() {
+        let mut world = World::new();
+        let dialog = world.spawn_empty().id();
+        let menu = world.spawn_empty().id();
+
+        let mut stack = OverlayStack::default();
+        assert!(stack.is_empty());
+
+        stack.push(dialog);
+        stack.sync(menu, true);
+        assert_eq!(stack.top(), Some(menu));
+        assert_eq!(stack.len(), 2);
+
+        // Syncing an already open overlay keeps its place.
+        stack.sync(dialog, true);
+        assert!(stack.is_top(menu));
+
+        // Pushing brings it to the front.
+        stack.push(dialog);
+        assert!(stack.is_top(dialog));
+
+        stack.sync(dialog, false);
+        assert!(stack.is_top(menu));
+        assert!(!stack.contains(dialog));
+    }
+
+    #[derive(Resource, Default)]
+    struct Reflows(usize);
+
+    #[derive(Component, Default)]
+    struct Sheet {
+        open: bool,
+        opened: usize,
+    }
+
+    impl Overlay for Sheet {
+        fn overlay_open(&self) -> bool {
+            self.open
+        }
+    }
+
+    impl OpenableOverlay for Sheet {
+        fn open_overlay(&mut self) {
+            self.open = true;
+            self.opened += 1;
+        }
+    }
+
+    #[test]
+    fn test_shortcut_opens_closed_overlay() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, open_on_shortcut_system::<Sheet>);
+        let sheet = app
+            .world_mut()
+            .spawn((
+                Sheet::default(),
+                OpenOnShortcut(KeyCombo::new(KeyCode::KeyO).ctrl()),
+            ))
+            .id();
+        let press = |app: &mut App, keys: &[KeyCode]| {
+            let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            input.reset_all();
+            for key in keys {
+                input.press(*key);
+            }
+            app.update();
+        };
+
+        // Missing or extra modifiers don't match.
+        press(&mut app, &[KeyCode::KeyO]);
+        press(
+            &mut app,
+            &[KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyO],
+        );
+        assert!(!app.world().get::<Sheet>(sheet).unwrap().open);
+
+        press(&mut app, &[KeyCode::ControlRight, KeyCode::KeyO]);
+        assert!(app.world().get::<Sheet>(sheet).unwrap().open);
+
+        // Already open: pressing again is a no-op.
+        press(&mut app, &[KeyCode::ControlLeft, KeyCode::KeyO]);
+        assert_eq!(app.world().get::<Sheet>(sheet).unwrap().opened, 1);
+    }
+
+    #[test]
+    fn test_reflow_runs_only_after_resize() {
+        let mut app = App::new();
+        app.add_plugins(OverlayPlugin)
+            .init_resource::<Reflows>()
+            .add_systems(
+                PostUpdate,
+                (|mut reflows: ResMut<Reflows>| reflows.0 += 1).in_set(OverlayReflowSystems),
+            );
+        let redraws = |app: &App| {
+            app.world()
+                .resource::<Messages<RequestRedraw>>()
+                .iter_current_update_messages()
+                .count()
+        };
+
+        app.update();
+        assert_eq!(app.world().resource::<Reflows>().0, 0);
+        assert_eq!(redraws(&app), 0);
+
+        let window = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(WindowResized {
+            window,
+            width: 640.0,
+            height: 480.0,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Reflows>().0, 1);
+        assert_eq!(redraws(&app), 1);
+
+        app.update();
+        assert_eq!(app.world().resource::<Reflows>().0, 1);
+    }
+
+    #[test]
+    fn test_escape_dismisses_only_frontmost() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<OverlayStack>()
+            .add_systems(Update, overlay_escape_system);
+
+        let dialog = app.world_mut().spawn_empty().id();
+        let menu = app.world_mut().spawn_empty().id();
+        {
+            let mut stack = app.world_mut().resource_mut::<OverlayStack>();
+            stack.push(dialog);
+            stack.push(menu);
+        }
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+
+        let stack = app.world().resource::<OverlayStack>();
+        assert!(stack.escape_pressed_for(menu));
+        assert!(!stack.escape_pressed_for(dialog));
+
+        // The menu closes; the dialog stays put until the next press.
+        app.world_mut().resource_mut::<OverlayStack>().remove(menu);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+        assert!(!app
+            .world()
+            .resource::<OverlayStack>()
+            .escape_pressed_for(dialog));
+    }
+
+    #[test]
+    fn test_outside_click_ignores_surface_and_owner() {
+        use bevy::math::Affine2;
+
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .add_message::<OutsideClickEvent>()
+            .add_systems(Update, outside_click_system);
+
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let node = |center: Vec2| {
+            (
+                ComputedNode {
+                    size: Vec2::splat(100.0),
+                    ..default()
+                },
+                UiGlobalTransform::from(Affine2::from_translation(center)),
+            )
+        };
+        let trigger = app.world_mut().spawn(node(Vec2::new(50.0, 50.0))).id();
+        let menu = app
+            .world_mut()
+            .spawn((
+                node(Vec2::new(50.0, 150.0)),
+                DismissOnOutsideClick::new(trigger),
+            ))
+            .id();
+
+        let press_at = |app: &mut App, position: Vec2| {
+            app.world_mut()
+                .resource_mut::<Messages<OutsideClickEvent>>()
+                .clear();
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .set_cursor_position(Some(position));
+            let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+            mouse.release(MouseButton::Left);
+            mouse.clear();
+            mouse.press(MouseButton::Left);
+            app.update();
+            app.world()
+                .resource::<Messages<OutsideClickEvent>>()
+                .iter_current_update_messages()
+                .map(|ev| (ev.entity, ev.owner))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            press_at(&mut app, Vec2::new(500.0, 500.0)),
+            vec![(menu, trigger)]
+        );
+        // Presses on the trigger or the menu itself don't dismiss.
+        assert!(press_at(&mut app, Vec2::new(50.0, 50.0)).is_empty());
+        assert!(press_at(&mut app, Vec2::new(50.0, 150.0)).is_empty());
+    }
+
+    #[test]
+    fn test_despawned_overlays_are_dropped() {
+        let mut app = App::new();
+        app.init_resource::<OverlayStack>()
+            .add_systems(Update, overlay_escape_system);
+
+        let menu = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<OverlayStack>().push(menu);
+        app.world_mut().despawn(menu);
+        app.update();
+
+        assert!(app.world().resource::<OverlayStack>().is_empty());
+    }
+}
@@ -105,6 +105,10 @@ pub struct MaterialLinearProgress {
     pub mode: ProgressMode,
     /// Whether the indicator uses a 4-color approach
     pub four_color: bool,
+    /// Gap in pixels between the active indicator and the track (0 for a continuous track)
+    pub track_gap: f32,
+    /// Whether the indicator and track use fully rounded caps
+    pub rounded_caps: bool,
     /// Animation state for indeterminate mode
     pub animation_progress: f32,
 }
@@ -116,6 +120,8 @@ impl MaterialLinearProgress {
             progress: 0.0,
             mode: ProgressMode::default(),
             four_color: false,
+            track_gap: 0.0,
+            rounded_caps: false,
             animation_progress: 0.0,
         }
     }
@@ -138,11 +144,62 @@ impl MaterialLinearProgress {
         self
     }
 
+    /// Separate the indicator from the track by `gap` pixels
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.track_gap = gap.max(0.0);
+        self
+    }
+
+    /// Use fully rounded caps on the indicator and track
+    pub fn with_rounded_caps(mut self, rounded: bool) -> Self {
+        self.rounded_caps = rounded;
+        self
+    }
+
+    /// Span covered by the active indicator, as start and end percentages of the track
+    pub fn indicator_span(&self) -> (f32, f32) {
+        match self.mode {
+            ProgressMode::Determinate => (0.0, self.progress.clamp(0.0, 1.0) * 100.0),
+            ProgressMode::Indeterminate => {
+                let t = self.animation_progress.clamp(0.0, 1.0);
+                // Travel from -segment_width to 100%.
+                let start = t * (100.0 + INDETERMINATE_SEGMENT_WIDTH) - INDETERMINATE_SEGMENT_WIDTH;
+                (
+                    start.clamp(0.0, 100.0),
+                    (start + INDETERMINATE_SEGMENT_WIDTH).clamp(0.0, 100.0),
+                )
+            }
+        }
+    }
+
+    /// Whether the track is drawn as separate segments around the indicator
+    pub fn has_track_gap(&self) -> bool {
+        self.track_gap > 0.0
+    }
+
+    /// Corner radius shared by the indicator and track
+    pub fn corner_radius(&self) -> BorderRadius {
+        if self.rounded_caps {
+            BorderRadius::MAX
+        } else {
+            BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL))
+        }
+    }
+
     /// Get the track color
     pub fn track_color(&self, theme: &MaterialTheme) -> Color {
         theme.surface_container_highest
     }
 
+    /// Background of the bar itself; transparent when the track is drawn as segments
+    fn container_color(&self, theme: &MaterialTheme) -> Color {
+        if self.has_track_gap() {
+            Color::NONE
+        } else {
+            self.track_color(theme)
+        }
+    }
+
     /// Get the indicator color
     pub fn indicator_color(&self, theme: &MaterialTheme) -> Color {
         theme.primary
@@ -166,6 +223,10 @@ pub struct MaterialCircularProgress {
     pub four_color: bool,
     /// Size of the indicator
     pub size: f32,
+    /// Gap in pixels between the active arc and the track arc (0 for a continuous track)
+    pub track_gap: f32,
+    /// Whether the arcs use rounded stroke caps
+    pub rounded_caps: bool,
     /// Animation state for indeterminate mode
     pub animation_progress: f32,
     /// Rotation angle for animation
@@ -180,6 +241,8 @@ impl MaterialCircularProgress {
             mode: ProgressMode::default(),
             four_color: false,
             size: CIRCULAR_PROGRESS_SIZE,
+            track_gap: 0.0,
+            rounded_caps: false,
             animation_progress: 0.0,
             rotation: 0.0,
         }
@@ -209,6 +272,30 @@ impl MaterialCircularProgress {
         self
     }
 
+    /// Separate the active arc from the track arc by `gap` pixels
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.track_gap = gap.max(0.0);
+        self
+    }
+
+    /// Use rounded stroke caps on the arcs
+    pub fn with_rounded_caps(mut self, rounded: bool) -> Self {
+        self.rounded_caps = rounded;
+        self
+    }
+
+    /// Angle in radians left empty at each end of the active arc.
+    ///
+    /// The gap is measured along the centerline of the stroke.
+    pub fn gap_angle(&self) -> f32 {
+        let radius = (self.size - CIRCULAR_PROGRESS_TRACK_WIDTH) / 2.0;
+        if radius <= 0.0 {
+            0.0
+        } else {
+            self.track_gap / radius
+        }
+    }
+
     /// Get the track color
     pub fn track_color(&self, _theme: &MaterialTheme) -> Color {
         Color::NONE // Circular doesn't have visible track by default
@@ -230,6 +317,11 @@ impl Default for MaterialCircularProgress {
 pub const LINEAR_PROGRESS_HEIGHT: f32 = 4.0;
 pub const CIRCULAR_PROGRESS_SIZE: f32 = 48.0;
 pub const CIRCULAR_PROGRESS_TRACK_WIDTH: f32 = 4.0;
+/// Gap between the indicator and the track in the MD3 2024 style
+pub const PROGRESS_TRACK_GAP: f32 = 4.0;
+
+/// Indeterminate segment width (percent of track width).
+const INDETERMINATE_SEGMENT_WIDTH: f32 = 30.0;
 
 /// System to animate linear progress indicators
 fn linear_progress_animation_system(
@@ -271,40 +363,68 @@ fn circular_progress_animation_system(
 fn progress_style_system(
     theme: Option<Res<MaterialTheme>>,
     mut linear_progress: Query<
-        (&MaterialLinearProgress, &mut BackgroundColor),
+        (
+            &MaterialLinearProgress,
+            &mut BackgroundColor,
+            Option<&mut BorderRadius>,
+        ),
         Changed<MaterialLinearProgress>,
     >,
 ) {
     let Some(theme) = theme else { return };
 
-    for (progress, mut bg_color) in linear_progress.iter_mut() {
-        *bg_color = BackgroundColor(progress.track_color(&theme));
+    for (progress, mut bg_color, radius) in linear_progress.iter_mut() {
+        *bg_color = BackgroundColor(progress.container_color(&theme));
+        if let Some(mut radius) = radius {
+            let target = progress.corner_radius();
+            if *radius != target {
+                *radius = target;
+            }
+        }
     }
 }
 
-/// Update the indicator (fill) element for linear progress bars.
+/// Update the indicator (fill) and gapped track segments for linear progress bars.
 fn linear_progress_indicator_system(
     theme: Option<Res<MaterialTheme>>,
     progress_bars: Query<(Entity, &MaterialLinearProgress)>,
     mut indicators: Query<
-        (&LinearProgressIndicatorFor, &mut Node, &mut BackgroundColor),
-        With<ProgressIndicator>,
+        (
+            &LinearProgressIndicatorFor,
+            &mut Node,
+            &mut BackgroundColor,
+            Option<&mut BorderRadius>,
+        ),
+        (With<ProgressIndicator>, Without<ProgressTrackSegment>),
+    >,
+    mut segments: Query<
+        (
+            &ChildOf,
+            &ProgressTrackSegment,
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderRadius,
+        ),
+        Without<ProgressIndicator>,
     >,
 ) {
     let Some(theme) = theme else { return };
 
-    // Indeterminate segment width (percent of track width).
-    const INDETERMINATE_SEGMENT_WIDTH: f32 = 30.0;
-
     for (bar_entity, progress) in progress_bars.iter() {
         let indicator_color = progress.indicator_color(&theme);
+        let radius = progress.corner_radius();
 
-        for (owner, mut node, mut bg) in indicators.iter_mut() {
+        for (owner, mut node, mut bg, indicator_radius) in indicators.iter_mut() {
             if owner.0 != bar_entity {
                 continue;
             }
 
             bg.0 = indicator_color;
+            if let Some(mut indicator_radius) = indicator_radius {
+                if *indicator_radius != radius {
+                    *indicator_radius = radius;
+                }
+            }
 
             match progress.mode {
                 ProgressMode::Determinate => {
@@ -322,6 +442,49 @@ fn linear_progress_indicator_system(
             }
         }
     }
+
+    for (parent, segment, mut node, mut bg, mut segment_radius) in segments.iter_mut() {
+        let Ok((_, progress)) = progress_bars.get(parent.parent()) else {
+            continue;
+        };
+
+        bg.0 = progress.track_color(&theme);
+        let radius = progress.corner_radius();
+        if *segment_radius != radius {
+            *segment_radius = radius;
+        }
+
+        let (start, end) = progress.indicator_span();
+        let gap = if end > start { progress.track_gap } else { 0.0 };
+        let (visible, left, right, margin) = match segment {
+            ProgressTrackSegment::Leading => (
+                start > 0.0,
+                Val::Px(0.0),
+                Val::Percent(100.0 - start),
+                UiRect::right(Val::Px(gap)),
+            ),
+            ProgressTrackSegment::Trailing => (
+                end < 100.0,
+                Val::Percent(end),
+                Val::Px(0.0),
+                UiRect::left(Val::Px(gap)),
+            ),
+        };
+        let display = if visible && progress.has_track_gap() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        if node.display != display {
+            node.display = display;
+        }
+        if node.left != left || node.right != right || node.margin != margin {
+            node.left = left;
+            node.right = right;
+            node.margin = margin;
+        }
+    }
 }
 
 /// Links an indicator entity to its owning linear progress entity.
@@ -337,12 +500,40 @@ fn ensure_linear_progress_indicator_system(
     theme: Option<Res<MaterialTheme>>,
     progress_bars: Query<(Entity, &MaterialLinearProgress, Option<&Children>)>,
     indicator_nodes: Query<(), With<ProgressIndicator>>,
+    segment_nodes: Query<(), With<ProgressTrackSegment>>,
 ) {
     let Some(theme) = theme else { return };
 
     for (entity, progress, children) in progress_bars.iter() {
         let has_indicator = children
             .is_some_and(|children| children.iter().any(|child| indicator_nodes.contains(child)));
+        let has_segments = children
+            .is_some_and(|children| children.iter().any(|child| segment_nodes.contains(child)));
+
+        if progress.has_track_gap() && !has_segments {
+            let track_color = progress.track_color(&theme);
+            let radius = progress.corner_radius();
+            commands.entity(entity).with_children(|container| {
+                for segment in [
+                    ProgressTrackSegment::Leading,
+                    ProgressTrackSegment::Trailing,
+                ] {
+                    container.spawn((
+                        ProgressTrack,
+                        segment,
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(0.0),
+                            bottom: Val::Px(0.0),
+                            display: Display::None,
+                            ..default()
+                        },
+                        BackgroundColor(track_color),
+                        radius,
+                    ));
+                }
+            });
+        }
 
         if has_indicator {
             continue;
@@ -358,7 +549,7 @@ fn ensure_linear_progress_indicator_system(
             width: Val::Percent(progress.progress.clamp(0.0, 1.0) * 100.0),
             ..default()
         };
-        let indicator_radius = BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL));
+        let indicator_radius = progress.corner_radius();
 
         commands.entity(entity).with_children(|container| {
             container.spawn((
@@ -390,7 +581,7 @@ fn progress_theme_refresh_system(
     }
 
     for (progress, children, mut track_bg) in progress_bars.iter_mut() {
-        track_bg.0 = progress.track_color(&theme);
+        track_bg.0 = progress.container_color(&theme);
         let indicator_color = progress.indicator_color(&theme);
 
         for child in children.iter() {
@@ -448,9 +639,25 @@ impl LinearProgressBuilder {
         self
     }
 
+    /// Separate the indicator from the track by `gap` pixels (MD3 2024 style).
+    ///
+    /// The track is then drawn as the remaining segments on either side of
+    /// the indicator. A gap of 0 keeps the continuous track.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.progress.track_gap = gap.max(0.0);
+        self
+    }
+
+    /// Use fully rounded caps on the indicator and track
+    pub fn rounded_caps(mut self, rounded: bool) -> Self {
+        self.progress.rounded_caps = rounded;
+        self
+    }
+
     /// Build the bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
-        let bg_color = self.progress.track_color(theme);
+        let bg_color = self.progress.container_color(theme);
+        let radius = self.progress.corner_radius();
 
         let node = Node {
             width: self.width,
@@ -462,12 +669,7 @@ impl LinearProgressBuilder {
             ..default()
         };
 
-        (
-            self.progress,
-            node,
-            BackgroundColor(bg_color),
-            BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
-        )
+        (self.progress, node, BackgroundColor(bg_color), radius)
     }
 }
 
@@ -514,6 +716,18 @@ impl CircularProgressBuilder {
         self
     }
 
+    /// Separate the active arc from the track arc by `gap` pixels (MD3 2024 style)
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.progress.track_gap = gap.max(0.0);
+        self
+    }
+
+    /// Use rounded stroke caps on the arcs
+    pub fn rounded_caps(mut self, rounded: bool) -> Self {
+        self.progress.rounded_caps = rounded;
+        self
+    }
+
     /// Build the bundle
     pub fn build(self, _theme: &MaterialTheme) -> impl Bundle {
         let size = self.progress.size;
@@ -539,6 +753,15 @@ impl Default for CircularProgressBuilder {
 #[derive(Component)]
 pub struct ProgressTrack;
 
+/// Which side of the indicator a gapped track segment covers
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressTrackSegment {
+    /// Track before the indicator (indeterminate mode only)
+    Leading,
+    /// Track after the indicator
+    Trailing,
+}
+
 /// Marker for progress indicator bar
 #[derive(Component)]
 pub struct ProgressIndicator;
@@ -607,6 +830,7 @@ impl SpawnProgressChild for ChildSpawnerCommands<'_> {
     ) {
         let progress_value = builder.progress.progress;
         let indicator_color = builder.progress.indicator_color(theme);
+        let indicator_radius = builder.progress.corner_radius();
 
         let mut bar = self.spawn(builder.build(theme));
         let bar_entity = bar.id();
@@ -628,7 +852,7 @@ impl SpawnProgressChild for ChildSpawnerCommands<'_> {
                 LinearProgressIndicatorFor(bar_entity),
                 indicator_node,
                 BackgroundColor(indicator_color),
-                BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
+                indicator_radius,
             ));
         });
     }
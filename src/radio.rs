@@ -15,11 +15,15 @@
 use bevy::prelude::*;
 
 use crate::{
+    control_label::spawn_labeled_control,
+    disabled::{sync_disabled_system, DisabledSource},
+    focus::adopt_focus_group_system,
     motion::StateLayer,
     ripple::RippleHost,
+    semantics::{checked_value, sync_semantics_system, Role, Semantic},
     telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
-    tokens::CornerRadius,
+    tokens::{CornerRadius, Opacity},
 };
 
 /// Marker component for the radio outer circle
@@ -47,6 +51,9 @@ impl Plugin for RadioPlugin {
                 radio_style_system,
                 radio_theme_refresh_system,
                 radio_telemetry_system,
+                adopt_focus_group_system::<RadioGroup, MaterialRadio>,
+                sync_disabled_system::<MaterialRadio>,
+                sync_semantics_system::<MaterialRadio>,
             ),
         );
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
@@ -160,7 +167,7 @@ impl MaterialRadio {
     /// Get the outer circle color
     pub fn outer_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         if self.selected {
@@ -173,7 +180,7 @@ impl MaterialRadio {
     /// Get the inner dot color
     pub fn inner_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.38);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
         }
 
         theme.primary
@@ -189,6 +196,28 @@ impl MaterialRadio {
     }
 }
 
+impl DisabledSource for MaterialRadio {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Semantic for MaterialRadio {
+    fn semantic_role(&self) -> Role {
+        Role::RadioButton
+    }
+
+    /// The component has no text of its own; builders label it from the
+    /// control label when one is given
+    fn semantic_label(&self) -> String {
+        String::new()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        Some(checked_value(self.selected))
+    }
+}
+
 impl Default for MaterialRadio {
     fn default() -> Self {
         Self::new()
@@ -426,6 +455,7 @@ fn radio_theme_refresh_system(
 /// Builder for radio buttons
 pub struct RadioBuilder {
     radio: MaterialRadio,
+    label: Option<String>,
 }
 
 impl RadioBuilder {
@@ -433,6 +463,7 @@ impl RadioBuilder {
     pub fn new() -> Self {
         Self {
             radio: MaterialRadio::new(),
+            label: None,
         }
     }
 
@@ -454,12 +485,20 @@ impl RadioBuilder {
         self
     }
 
+    /// Add a text label; clicking it selects the radio button
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Build the radio bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let _border_color = self.radio.outer_color(theme);
+        let semantics = self.radio.semantics();
 
         (
             self.radio,
+            semantics,
             Button,
             RippleHost::new(),
             Node {
@@ -473,6 +512,32 @@ impl RadioBuilder {
             BorderRadius::all(Val::Px(CornerRadius::FULL)),
         )
     }
+
+    /// Spawn a complete radio button with visuals
+    ///
+    /// With a [`label`](Self::label), the radio button and its text are wrapped
+    /// in a [`ControlLabel`](crate::control_label::ControlLabel) row that acts
+    /// as one touch target, and the row entity is returned.
+    pub fn spawn(self, commands: &mut Commands, theme: &MaterialTheme) -> Entity {
+        self.spawn_into(commands.spawn_empty(), theme)
+    }
+
+    fn spawn_into(self, mut root: EntityCommands, theme: &MaterialTheme) -> Entity {
+        let Self { radio, label } = self;
+        let semantics = radio.semantics();
+        match label {
+            Some(label) => {
+                let semantics = semantics.with_label(label.clone());
+                spawn_labeled_control(root, theme, label, |row| {
+                    spawn_radio_control(row.spawn(semantics), radio, theme)
+                })
+            }
+            None => {
+                root.insert(semantics);
+                spawn_radio_control(root, radio, theme)
+            }
+        }
+    }
 }
 
 impl Default for RadioBuilder {
@@ -481,6 +546,88 @@ impl Default for RadioBuilder {
     }
 }
 
+/// Insert the radio touch target on `entity` and spawn its visuals.
+fn spawn_radio_control(
+    mut entity: EntityCommands,
+    radio: MaterialRadio,
+    theme: &MaterialTheme,
+) -> Entity {
+    let border_color = radio.outer_color(theme);
+    let inner_color = if radio.selected {
+        theme.primary
+    } else {
+        Color::NONE
+    };
+    let state_layer_color = radio.state_layer_color(theme);
+
+    entity
+        .insert((
+            radio,
+            Button,
+            Interaction::None,
+            RippleHost::new(),
+            Node {
+                width: Val::Px(RADIO_TOUCH_TARGET),
+                height: Val::Px(RADIO_TOUCH_TARGET),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Px(CornerRadius::FULL)),
+        ))
+        .with_children(|touch| {
+            // State layer
+            touch
+                .spawn((
+                    RadioStateLayer,
+                    StateLayer::new(state_layer_color),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(40.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(20.0)),
+                ))
+                .with_children(|state_layer| {
+                    // Outer circle
+                    state_layer
+                        .spawn((
+                            RadioOuter,
+                            Node {
+                                width: Val::Px(RADIO_SIZE),
+                                height: Val::Px(RADIO_SIZE),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                            BorderColor::all(border_color),
+                            BorderRadius::all(Val::Px(RADIO_SIZE / 2.0)),
+                        ))
+                        .with_children(|outer| {
+                            // Inner dot
+                            outer.spawn((
+                                RadioInner,
+                                Node {
+                                    width: Val::Px(RADIO_DOT_SIZE),
+                                    height: Val::Px(RADIO_DOT_SIZE),
+                                    ..default()
+                                },
+                                BackgroundColor(inner_color),
+                                BorderRadius::all(Val::Px(RADIO_DOT_SIZE / 2.0)),
+                            ));
+                        });
+                });
+        });
+    entity.id()
+}
+
 /// Extension trait to spawn radio buttons with full visual hierarchy
 pub trait SpawnRadio {
     /// Spawn a radio button with a label
@@ -519,101 +666,7 @@ impl SpawnRadio for Commands<'_, '_> {
         builder: RadioBuilder,
         label: &str,
     ) -> Entity {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let is_selected = builder.radio.selected;
-        let border_color = builder.radio.outer_color(theme);
-        let inner_color = if is_selected {
-            theme.primary
-        } else {
-            Color::NONE
-        };
-        let state_layer_color = builder.radio.state_layer_color(theme);
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Radio touch target
-            row.spawn((
-                builder.radio,
-                Button,
-                Interaction::None,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(RADIO_TOUCH_TARGET),
-                    height: Val::Px(RADIO_TOUCH_TARGET),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|touch| {
-                // State layer
-                touch
-                    .spawn((
-                        RadioStateLayer,
-                        StateLayer::new(state_layer_color),
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(40.0),
-                            height: Val::Px(40.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        BorderRadius::all(Val::Px(20.0)),
-                    ))
-                    .with_children(|state_layer| {
-                        // Outer circle
-                        state_layer
-                            .spawn((
-                                RadioOuter,
-                                Node {
-                                    width: Val::Px(RADIO_SIZE),
-                                    height: Val::Px(RADIO_SIZE),
-                                    border: UiRect::all(Val::Px(2.0)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                BorderColor::all(border_color),
-                                BorderRadius::all(Val::Px(RADIO_SIZE / 2.0)),
-                            ))
-                            .with_children(|outer| {
-                                // Inner dot
-                                outer.spawn((
-                                    RadioInner,
-                                    Node {
-                                        width: Val::Px(RADIO_DOT_SIZE),
-                                        height: Val::Px(RADIO_DOT_SIZE),
-                                        ..default()
-                                    },
-                                    BackgroundColor(inner_color),
-                                    BorderRadius::all(Val::Px(RADIO_DOT_SIZE / 2.0)),
-                                ));
-                            });
-                    });
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        })
-        .id()
+        builder.label(label).spawn(self, theme)
     }
 }
 
@@ -645,99 +698,6 @@ impl SpawnRadioChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_radio_with(&mut self, theme: &MaterialTheme, builder: RadioBuilder, label: &str) {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let is_selected = builder.radio.selected;
-        let border_color = builder.radio.outer_color(theme);
-        let inner_color = if is_selected {
-            theme.primary
-        } else {
-            Color::NONE
-        };
-        let state_layer_color = builder.radio.state_layer_color(theme);
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Radio touch target
-            row.spawn((
-                builder.radio,
-                Button,
-                Interaction::None,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(RADIO_TOUCH_TARGET),
-                    height: Val::Px(RADIO_TOUCH_TARGET),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|touch| {
-                // State layer
-                touch
-                    .spawn((
-                        RadioStateLayer,
-                        StateLayer::new(state_layer_color),
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(40.0),
-                            height: Val::Px(40.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        BorderRadius::all(Val::Px(20.0)),
-                    ))
-                    .with_children(|state_layer| {
-                        // Outer circle
-                        state_layer
-                            .spawn((
-                                RadioOuter,
-                                Node {
-                                    width: Val::Px(RADIO_SIZE),
-                                    height: Val::Px(RADIO_SIZE),
-                                    border: UiRect::all(Val::Px(2.0)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                BorderColor::all(border_color),
-                                BorderRadius::all(Val::Px(RADIO_SIZE / 2.0)),
-                            ))
-                            .with_children(|outer| {
-                                // Inner dot
-                                outer.spawn((
-                                    RadioInner,
-                                    Node {
-                                        width: Val::Px(RADIO_DOT_SIZE),
-                                        height: Val::Px(RADIO_DOT_SIZE),
-                                        ..default()
-                                    },
-                                    BackgroundColor(inner_color),
-                                    BorderRadius::all(Val::Px(RADIO_DOT_SIZE / 2.0)),
-                                ));
-                            });
-                    });
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        });
+        builder.label(label).spawn_into(self.spawn_empty(), theme);
     }
 }
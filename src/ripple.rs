@@ -2,9 +2,18 @@
 //!
 //! The ripple provides visual feedback when users interact with components.
 //! Reference: <https://m3.material.io/foundations/interaction/states/overview>
+//!
+//! Finished ripples are parked in the [`RipplePool`] and reused by later
+//! presses, and each host shows at most [`RippleSettings::max_per_host`]
+//! ripples at once, so rapid clicking on long lists doesn't pile up entities.
 
+use bevy::picking::Pickable;
 use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+use bevy::window::PrimaryWindow;
 
+use crate::disabled::Disabled;
+use crate::motion::{ease_standard, ReducedMotion, StateLayer};
 use crate::tokens::Duration;
 
 /// Plugin for the ripple effect system
@@ -13,14 +22,92 @@ pub struct RipplePlugin;
 impl Plugin for RipplePlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<SpawnRipple>()
-            .add_systems(Update, (spawn_ripple_system, animate_ripple_system));
+            .init_resource::<RippleSettings>()
+            .init_resource::<RipplePool>()
+            .add_systems(
+                Update,
+                (
+                    ripple_press_system,
+                    spawn_ripple_system,
+                    ripple_release_system,
+                    animate_ripple_system,
+                    paint_ripple_surface_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Peak opacity of a ripple
+const RIPPLE_OPACITY: f32 = 0.12;
+
+/// Limits on ripple entities
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RippleSettings {
+    /// Most ripples a single host shows at once; pressing again recycles the oldest
+    pub max_per_host: usize,
+    /// Most finished ripple entities kept for reuse; extras are despawned
+    pub pool_size: usize,
+}
+
+impl Default for RippleSettings {
+    fn default() -> Self {
+        Self {
+            max_per_host: 3,
+            pool_size: 32,
+        }
+    }
+}
+
+/// Finished ripple entities, detached and hidden, waiting to be reused
+#[derive(Resource, Debug, Default)]
+pub struct RipplePool {
+    entities: Vec<Entity>,
+}
+
+impl RipplePool {
+    /// Number of pooled ripple entities
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Whether the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Park a finished ripple, or despawn it if the pool is full
+    fn recycle(&mut self, commands: &mut Commands, entity: Entity, capacity: usize) {
+        if self.entities.len() >= capacity {
+            commands.entity(entity).despawn();
+            return;
+        }
+        commands
+            .entity(entity)
+            .remove::<(Ripple, ChildOf, BackgroundColor, BorderRadius)>()
+            .insert(Node {
+                display: Display::None,
+                ..default()
+            });
+        self.entities.push(entity);
+    }
+
+    /// Take a pooled entity that still exists
+    fn take(&mut self, commands: &mut Commands) -> Option<Entity> {
+        std::iter::from_fn(|| self.entities.pop())
+            .find(|entity| commands.get_entity(*entity).is_ok())
     }
 }
 
 /// Component that enables ripple effects on an entity
+///
+/// Pressing the host spawns a ripple that grows from the press point and fades
+/// out once the pointer is released. Bounded ripples are clipped to the host,
+/// including its rounded corners; unbounded ripples grow from the host's center
+/// past its bounds (e.g. icon buttons).
 #[derive(Component, Default)]
 pub struct RippleHost {
-    /// Color of the ripple effect
+    /// Color of the ripple effect (defaults to the host's [`StateLayer`] color)
     pub color: Option<Color>,
     /// Whether ripple is unbounded (extends beyond container)
     pub unbounded: bool,
@@ -38,9 +125,9 @@ impl RippleHost {
         self
     }
 
-    /// Make the ripple unbounded
-    pub fn unbounded(mut self) -> Self {
-        self.unbounded = true;
+    /// Set whether the ripple is unbounded
+    pub fn unbounded(mut self, unbounded: bool) -> Self {
+        self.unbounded = unbounded;
         self
     }
 }
@@ -54,6 +141,13 @@ pub struct SpawnRipple {
     pub position: Vec2,
 }
 
+/// Container for bounded ripples, spawned as a child of the host
+///
+/// Its ripples are painted as gradient layers of its background so they follow
+/// the host's `BorderRadius`; UI clipping alone is rectangular.
+#[derive(Component)]
+pub struct RippleSurface;
+
 /// Component for active ripple animations
 #[derive(Component)]
 pub struct Ripple {
@@ -61,72 +155,277 @@ pub struct Ripple {
     pub scale: f32,
     /// Current opacity of the ripple
     pub opacity: f32,
-    /// Animation timer
+    /// Expansion timer
     pub timer: Timer,
+    /// Fade-out timer, running once `fading_out` is set
+    pub fade_timer: Timer,
     /// Whether the ripple is in the fade-out phase
     pub fading_out: bool,
+    /// Whether the pointer that spawned the ripple was released
+    pub released: bool,
     /// Maximum radius of the ripple
     pub max_radius: f32,
     /// Center position of the ripple
     pub center: Vec2,
     /// Color of the ripple
     pub color: Color,
+    /// Host the ripple belongs to
+    pub host: Option<Entity>,
 }
 
 impl Ripple {
     /// Create a new ripple
+    ///
+    /// The ripple fades out as soon as it has fully expanded; use
+    /// [`Ripple::held`] to keep it visible until the pointer is released.
     pub fn new(center: Vec2, max_radius: f32, color: Color) -> Self {
         Self {
             scale: 0.0,
-            opacity: 0.12,
+            opacity: RIPPLE_OPACITY,
             timer: Timer::from_seconds(Duration::MEDIUM4, TimerMode::Once),
+            fade_timer: Timer::from_seconds(Duration::SHORT4, TimerMode::Once),
             fading_out: false,
+            released: true,
             max_radius,
             center,
             color,
+            host: None,
         }
     }
 
+    /// Keep the ripple visible until [`Ripple::release`] is called
+    pub fn held(mut self, host: Entity) -> Self {
+        self.released = false;
+        self.host = Some(host);
+        self
+    }
+
+    /// Mark the pointer as released; the ripple fades out
+    pub fn release(&mut self) {
+        self.released = true;
+        self.start_fade_out();
+    }
+
     /// Start the fade-out phase
     pub fn start_fade_out(&mut self) {
-        self.fading_out = true;
-        self.timer = Timer::from_seconds(Duration::SHORT4, TimerMode::Once);
+        if !self.fading_out {
+            self.fading_out = true;
+            self.fade_timer.reset();
+        }
+    }
+
+    /// Advance the ripple animation
+    ///
+    /// Expansion keeps running while fading so quick taps still spread.
+    pub fn update(&mut self, delta: std::time::Duration) {
+        self.timer.tick(delta);
+        self.scale = ease_out(self.timer.fraction());
+
+        if !self.fading_out && self.released && self.timer.is_finished() {
+            self.start_fade_out();
+        }
+
+        if self.fading_out {
+            self.fade_timer.tick(delta);
+            self.opacity = RIPPLE_OPACITY * (1.0 - ease_standard(self.fade_timer.fraction()));
+        }
     }
 
     /// Check if the ripple animation is complete
     pub fn is_complete(&self) -> bool {
-        self.fading_out && self.timer.is_finished()
+        self.fading_out && self.fade_timer.is_finished()
+    }
+
+    /// How far along the ripple is; older ripples have larger values
+    fn age(&self) -> f32 {
+        self.timer.elapsed_secs() + self.fade_timer.elapsed_secs()
+    }
+}
+
+/// Spawn a ripple at the pointer when a host is pressed.
+fn ripple_press_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    hosts: Query<
+        (Entity, &Interaction, &ComputedNode, &UiGlobalTransform),
+        (Changed<Interaction>, With<RippleHost>),
+    >,
+    mut spawn: MessageWriter<SpawnRipple>,
+) {
+    let cursor = windows.single().ok().and_then(|w| w.cursor_position());
+
+    for (entity, interaction, computed, transform) in hosts.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // `UiGlobalTransform` / `ComputedNode` are physical pixels; the cursor is logical.
+        let scale = computed.inverse_scale_factor();
+        let size = computed.size() * scale;
+        let top_left = transform.translation * scale - size / 2.0;
+        let position = cursor
+            .map(|cursor| (cursor - top_left).clamp(Vec2::ZERO, size))
+            .unwrap_or(size / 2.0);
+
+        spawn.write(SpawnRipple {
+            host: entity,
+            position,
+        });
     }
 }
 
 /// System to spawn ripple effects
+///
+/// Reuses the host's oldest ripple once it has [`RippleSettings::max_per_host`],
+/// then pooled entities, and only spawns new ones when the pool is empty.
 fn spawn_ripple_system(
     mut commands: Commands,
     mut events: MessageReader<SpawnRipple>,
-    hosts: Query<(&RippleHost, &ComputedNode, &GlobalTransform)>,
+    settings: Option<Res<RippleSettings>>,
+    mut pool: ResMut<RipplePool>,
+    hosts: Query<(
+        &RippleHost,
+        &ComputedNode,
+        Option<&Interaction>,
+        Option<&StateLayer>,
+        Option<&BorderRadius>,
+        Option<&Children>,
+        Has<Disabled>,
+    )>,
+    surfaces: Query<(), With<RippleSurface>>,
+    containers: Query<&Children>,
+    ripples: Query<&Ripple>,
 ) {
+    let settings = settings.map(|s| *s).unwrap_or_default();
+
     for event in events.read() {
-        if let Ok((host, computed_node, _transform)) = hosts.get(event.host) {
-            let size = computed_node.size();
-            let max_radius = (size.x.powi(2) + size.y.powi(2)).sqrt();
-
-            let color = host.color.unwrap_or(Color::srgba(1.0, 1.0, 1.0, 0.12));
-
-            commands.entity(event.host).with_children(|parent| {
-                parent.spawn((
-                    Ripple::new(event.position, max_radius, color),
-                    Node {
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(event.position.x),
-                        top: Val::Px(event.position.y),
-                        width: Val::Px(0.0),
-                        height: Val::Px(0.0),
-                        ..default()
-                    },
-                    BackgroundColor(color),
-                    BorderRadius::all(Val::Percent(50.0)),
-                ));
-            });
+        let Ok((host, computed_node, interaction, state_layer, radius, children, disabled)) =
+            hosts.get(event.host)
+        else {
+            continue;
+        };
+        if disabled || state_layer.is_some_and(|layer| layer.disabled) {
+            continue;
+        }
+
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let color = host
+            .color
+            .or(state_layer.map(|layer| layer.color))
+            .unwrap_or(Color::WHITE);
+
+        // Unbounded ripples are centered on the host and only cover its circle;
+        // bounded ripples must reach the farthest corner from the press point.
+        let (center, max_radius) = if host.unbounded {
+            (size / 2.0, size.max_element() / 2.0)
+        } else {
+            let farthest = Vec2::new(
+                event.position.x.max(size.x - event.position.x),
+                event.position.y.max(size.y - event.position.y),
+            );
+            (event.position, farthest.length())
+        };
+
+        let mut ripple = Ripple::new(center, max_radius, color);
+        if interaction == Some(&Interaction::Pressed) {
+            ripple = ripple.held(event.host);
+        }
+        let ripple_node = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(center.x),
+            top: Val::Px(center.y),
+            width: Val::Px(0.0),
+            height: Val::Px(0.0),
+            ..default()
+        };
+
+        let surface =
+            children.and_then(|children| children.iter().find(|child| surfaces.contains(*child)));
+        let container = if host.unbounded {
+            Some(event.host)
+        } else {
+            surface
+        };
+
+        // Recycle the oldest ripple once the host is at its cap.
+        let active: Vec<(Entity, f32)> = container
+            .and_then(|container| containers.get(container).ok())
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| ripples.get(child).ok().map(|r| (child, r.age())))
+            .collect();
+        let oldest = if active.len() >= settings.max_per_host.max(1) {
+            active
+                .iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(entity, _)| *entity)
+        } else {
+            None
+        };
+        let entity = oldest
+            .or_else(|| pool.take(&mut commands))
+            .unwrap_or_else(|| commands.spawn_empty().id());
+
+        if host.unbounded {
+            commands.entity(entity).insert((
+                ripple_node,
+                BackgroundColor(color.with_alpha(ripple.opacity)),
+                BorderRadius::all(Val::Percent(50.0)),
+                Pickable::IGNORE,
+                ripple,
+                ChildOf(event.host),
+            ));
+            continue;
+        }
+
+        // Bounded ripples are drawn by their surface; see `paint_ripple_surface_system`.
+        commands
+            .entity(entity)
+            .insert((ripple_node, Pickable::IGNORE, ripple));
+
+        match surface {
+            Some(surface) => {
+                commands.entity(entity).insert(ChildOf(surface));
+            }
+            None => {
+                commands.entity(event.host).with_children(|parent| {
+                    parent
+                        .spawn((
+                            RippleSurface,
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(0.0),
+                                top: Val::Px(0.0),
+                                right: Val::Px(0.0),
+                                bottom: Val::Px(0.0),
+                                overflow: Overflow::clip(),
+                                ..default()
+                            },
+                            radius.copied().unwrap_or_default(),
+                            BackgroundGradient::default(),
+                            Pickable::IGNORE,
+                        ))
+                        .add_child(entity);
+                });
+            }
+        }
+    }
+}
+
+/// Fade out held ripples once their host is no longer pressed.
+fn ripple_release_system(
+    hosts: Query<&Interaction, With<RippleHost>>,
+    mut ripples: Query<&mut Ripple>,
+) {
+    for mut ripple in ripples.iter_mut() {
+        if ripple.released {
+            continue;
+        }
+        let pressed = ripple
+            .host
+            .and_then(|host| hosts.get(host).ok())
+            .is_some_and(|interaction| *interaction == Interaction::Pressed);
+        if !pressed {
+            ripple.release();
         }
     }
 }
@@ -135,21 +434,22 @@ fn spawn_ripple_system(
 fn animate_ripple_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut ripples: Query<(Entity, &mut Ripple, &mut Node, &mut BackgroundColor)>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    settings: Option<Res<RippleSettings>>,
+    mut pool: ResMut<RipplePool>,
+    mut ripples: Query<(Entity, &mut Ripple, &mut Node, Option<&mut BackgroundColor>)>,
 ) {
-    for (entity, mut ripple, mut node, mut bg_color) in ripples.iter_mut() {
-        ripple.timer.tick(time.delta());
-
-        let progress = ripple.timer.fraction();
+    let pool_size = settings.map(|s| *s).unwrap_or_default().pool_size;
 
-        if ripple.fading_out {
-            // Fade out phase - reduce opacity
-            ripple.opacity = 0.12 * (1.0 - ease_out(progress));
-        } else {
-            // Expand phase - grow the ripple
-            ripple.scale = ease_out(progress);
+    for (entity, mut ripple, mut node, bg_color) in ripples.iter_mut() {
+        // Reduced motion drops the expanding ripple; state layers still show press feedback.
+        if reduced_motion.as_ref().is_some_and(|r| r.0) {
+            pool.recycle(&mut commands, entity, pool_size);
+            continue;
         }
 
+        ripple.update(time.delta());
+
         // Update visual properties
         let current_radius = ripple.max_radius * ripple.scale;
         let diameter = current_radius * 2.0;
@@ -159,21 +459,166 @@ fn animate_ripple_system(
         node.left = Val::Px(ripple.center.x - current_radius);
         node.top = Val::Px(ripple.center.y - current_radius);
 
-        *bg_color = BackgroundColor(ripple.color.with_alpha(ripple.opacity));
-
-        // Check if expansion is complete, start fade out
-        if !ripple.fading_out && ripple.timer.is_finished() {
-            ripple.start_fade_out();
+        if let Some(mut bg_color) = bg_color {
+            *bg_color = BackgroundColor(ripple.color.with_alpha(ripple.opacity));
         }
 
-        // Remove completed ripples
+        // Park completed ripples for reuse
         if ripple.is_complete() {
-            commands.entity(entity).despawn();
+            pool.recycle(&mut commands, entity, pool_size);
+        }
+    }
+}
+
+/// Paint bounded ripples onto their surface and keep its corners matching the host.
+fn paint_ripple_surface_system(
+    mut surfaces: Query<
+        (
+            &ChildOf,
+            Option<&Children>,
+            &mut BorderRadius,
+            &mut BackgroundGradient,
+        ),
+        With<RippleSurface>,
+    >,
+    hosts: Query<&BorderRadius, Without<RippleSurface>>,
+    ripples: Query<&Ripple>,
+) {
+    for (parent, children, mut radius, mut gradient) in surfaces.iter_mut() {
+        if let Ok(host_radius) = hosts.get(parent.parent()) {
+            if *radius != *host_radius {
+                *radius = *host_radius;
+            }
+        }
+
+        let layers: Vec<Gradient> = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| ripples.get(child).ok())
+            .map(ripple_gradient)
+            .collect();
+        if gradient.0 != layers {
+            gradient.0 = layers;
         }
     }
 }
 
+/// A hard-edged circle gradient drawing `ripple` at its current size
+fn ripple_gradient(ripple: &Ripple) -> Gradient {
+    let radius = ripple.max_radius * ripple.scale;
+    let color = ripple.color.with_alpha(ripple.opacity);
+
+    RadialGradient::new(
+        UiPosition::TOP_LEFT.at_px(ripple.center.x, ripple.center.y),
+        RadialGradientShape::Circle(Val::Px(radius)),
+        vec![
+            ColorStop::px(color, 0.0),
+            ColorStop::px(color, radius),
+            ColorStop::px(Color::NONE, radius),
+        ],
+    )
+    .into()
+}
+
 /// Ease out cubic function for smooth deceleration
 fn ease_out(t: f32) -> f32 {
     1.0 - (1.0 - t).powi(3)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_ripple_waits_for_release() {
+        let host = Entity::from_raw_u32(1).unwrap();
+        let mut ripple = Ripple::new(Vec2::ZERO, 10.0, Color::WHITE).held(host);
+
+        ripple.update(std::time::Duration::from_secs(1));
+        assert_eq!(ripple.scale, 1.0);
+        assert!(!ripple.fading_out);
+
+        ripple.release();
+        ripple.update(std::time::Duration::from_millis(100));
+        assert!(ripple.opacity < RIPPLE_OPACITY && ripple.opacity > 0.0);
+        assert!(!ripple.is_complete());
+
+        ripple.update(std::time::Duration::from_millis(150));
+        assert!(ripple.is_complete());
+    }
+
+    #[test]
+    fn test_ripple_gradient_tracks_scale() {
+        let mut ripple = Ripple::new(Vec2::new(4.0, 6.0), 20.0, Color::BLACK);
+        ripple.scale = 0.5;
+
+        let Gradient::Radial(gradient) = ripple_gradient(&ripple) else {
+            panic!("expected a radial gradient");
+        };
+        assert_eq!(gradient.position, UiPosition::TOP_LEFT.at_px(4.0, 6.0));
+        assert_eq!(gradient.shape, RadialGradientShape::Circle(Val::Px(10.0)));
+        assert_eq!(gradient.stops.last().unwrap().color, Color::NONE);
+    }
+
+    #[test]
+    fn test_ripples_are_capped_and_pooled() {
+        let mut app = App::new();
+        app.add_message::<SpawnRipple>()
+            .init_resource::<Time>()
+            .init_resource::<RipplePool>()
+            .insert_resource(RippleSettings {
+                max_per_host: 2,
+                pool_size: 8,
+            })
+            .add_systems(Update, (spawn_ripple_system, animate_ripple_system).chain());
+
+        let host = app
+            .world_mut()
+            .spawn((RippleHost::new().unbounded(true), ComputedNode::default()))
+            .id();
+        let ripples = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<Entity, With<Ripple>>()
+                .iter(app.world())
+                .collect::<Vec<_>>()
+        };
+
+        for _ in 0..5 {
+            app.world_mut().write_message(SpawnRipple {
+                host,
+                position: Vec2::ZERO,
+            });
+            app.update();
+        }
+        let active = ripples(&mut app);
+        assert_eq!(active.len(), 2);
+
+        // Reduced motion drops the ripples into the pool instead of despawning them.
+        app.insert_resource(ReducedMotion(true));
+        app.update();
+        assert!(ripples(&mut app).is_empty());
+        assert_eq!(app.world().resource::<RipplePool>().len(), 2);
+
+        app.insert_resource(ReducedMotion(false));
+        app.world_mut().write_message(SpawnRipple {
+            host,
+            position: Vec2::ZERO,
+        });
+        app.update();
+        let reused = ripples(&mut app);
+        assert_eq!(reused.len(), 1);
+        assert!(active.contains(&reused[0]));
+        assert_eq!(app.world().resource::<RipplePool>().len(), 1);
+        assert_eq!(
+            app.world().get::<ChildOf>(reused[0]).map(ChildOf::parent),
+            Some(host)
+        );
+    }
+
+    #[test]
+    fn test_unheld_ripple_fades_after_expansion() {
+        let mut ripple = Ripple::new(Vec2::ZERO, 10.0, Color::WHITE);
+        ripple.update(std::time::Duration::from_secs(1));
+        assert!(ripple.fading_out);
+    }
+}
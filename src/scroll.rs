@@ -24,6 +24,7 @@
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
 
 /// Maximum depth to traverse when searching for ancestor entities.
 /// This prevents infinite loops in case of circular references or pathological entity hierarchies.
@@ -31,9 +32,11 @@ const MAX_ANCESTOR_DEPTH: usize = 32;
 use bevy::ecs::system::Command;
 use bevy::picking::Pickable;
 use bevy::ui::UiSystems;
+use bevy::window::PrimaryWindow;
 
 use std::collections::HashSet;
 
+use crate::motion::ReducedMotion;
 use crate::telemetry::{InsertTestIdIfExists, TestId};
 use crate::theme::MaterialTheme;
 
@@ -119,12 +122,18 @@ impl Plugin for ScrollPlugin {
             Update,
             (
                 assign_scrollbar_test_ids_system,
+                ensure_scroll_momentum_system,
+                ensure_scrollbar_fade_system,
                 sync_scroll_state_system,
                 sync_scroll_content_padding_system,
                 mouse_wheel_scroll_system,
+                smooth_scroll_system,
                 scrollbar_thumb_drag_system,
+                scrollbar_track_click_system,
                 sync_scroll_position_to_content_system,
+                scrollbar_fade_system,
                 update_scrollbars,
+                scrollbar_style_system,
             )
                 .chain(),
         );
@@ -202,14 +211,14 @@ fn ensure_scrollbars_system(
         for track in existing_tracks_v {
             if !wants_v {
                 commands.entity(track).insert(Visibility::Hidden);
-            } else if container.always_show_scrollbars {
+            } else if container.always_show_scrollbars && !container.auto_hide {
                 commands.entity(track).insert(Visibility::Inherited);
             }
         }
         for track in existing_tracks_h {
             if !wants_h {
                 commands.entity(track).insert(Visibility::Hidden);
-            } else if container.always_show_scrollbars {
+            } else if container.always_show_scrollbars && !container.auto_hide {
                 commands.entity(track).insert(Visibility::Inherited);
             }
         }
@@ -508,7 +517,10 @@ pub struct ScrollContainer {
     pub container_size: Vec2,
     /// Scroll sensitivity (pixels per scroll unit)
     pub sensitivity: f32,
-    /// Whether smooth scrolling is enabled
+    /// Whether smooth scrolling is enabled.
+    ///
+    /// When true, wheel input eases `ScrollPosition` towards a target offset and
+    /// fast flicks carry momentum (see [`ScrollMomentum`]). Default: false.
     pub smooth: bool,
     /// Smooth scrolling speed (0.0-1.0, higher = faster)
     pub smooth_speed: f32,
@@ -524,6 +536,12 @@ pub struct ScrollContainer {
     pub always_show_scrollbars: bool,
     /// Scrollbar width
     pub scrollbar_width: f32,
+    /// Scrollbar colors
+    pub scrollbar_style: ScrollbarStyle,
+    /// Fade scrollbars in while scrolling or hovered and out after inactivity.
+    ///
+    /// See [`ScrollbarFade`]. Default: false.
+    pub auto_hide: bool,
 }
 
 impl Default for ScrollContainer {
@@ -536,13 +554,15 @@ impl Default for ScrollContainer {
             content_size: Vec2::ZERO,
             container_size: Vec2::ZERO,
             sensitivity: 40.0,
-            smooth: true,
+            smooth: false,
             smooth_speed: 0.2,
             dragging: false,
             last_drag_pos: None,
             show_scrollbars: true,
             always_show_scrollbars: false,
             scrollbar_width: 8.0,
+            scrollbar_style: ScrollbarStyle::default(),
+            auto_hide: false,
         }
     }
 }
@@ -605,6 +625,27 @@ impl ScrollContainer {
         self
     }
 
+    /// Set scrollbar colors
+    pub fn with_scrollbar_style(mut self, style: ScrollbarStyle) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Fade scrollbars out after inactivity
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Current scrollbar opacity, accounting for auto-hide
+    fn scrollbar_opacity(&self, fade: Option<&ScrollbarFade>) -> f32 {
+        if self.auto_hide {
+            fade.map_or(0.0, |fade| fade.opacity)
+        } else {
+            1.0
+        }
+    }
+
     /// Scroll by a delta amount
     pub fn scroll_by(&mut self, delta: Vec2) {
         match self.direction {
@@ -677,6 +718,95 @@ impl ScrollContainer {
     }
 }
 
+/// Scrollbar colors for a [`ScrollContainer`]
+///
+/// Unset colors follow the theme: an `outline_variant` track and an
+/// `on_surface_variant` thumb.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScrollbarStyle {
+    /// Track color override
+    pub track: Option<Color>,
+    /// Thumb color override
+    pub thumb: Option<Color>,
+}
+
+impl ScrollbarStyle {
+    /// Theme-colored scrollbars
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the track color
+    pub fn track(mut self, color: Color) -> Self {
+        self.track = Some(color);
+        self
+    }
+
+    /// Override the thumb color
+    pub fn thumb(mut self, color: Color) -> Self {
+        self.thumb = Some(color);
+        self
+    }
+
+    /// Resolved track color
+    pub fn track_color(&self, theme: &MaterialTheme) -> Color {
+        self.track.unwrap_or(theme.outline_variant)
+    }
+
+    /// Resolved thumb color
+    pub fn thumb_color(&self, theme: &MaterialTheme) -> Color {
+        self.thumb.unwrap_or(theme.on_surface_variant)
+    }
+}
+
+/// Auto-hide state for the scrollbars of a [`ScrollContainer`] with `auto_hide` enabled.
+///
+/// Inserted automatically for auto-hide containers. Scrollbars fade in while the
+/// container is scrolled, dragged, or hovered, and fade out once it has been
+/// idle for `delay` seconds.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ScrollbarFade {
+    /// Current scrollbar opacity (0.0 to 1.0)
+    pub opacity: f32,
+    /// Seconds of inactivity before the scrollbars fade out
+    pub delay: f32,
+    /// Seconds since the container was last scrolled or hovered
+    pub idle: f32,
+    last_offset: Vec2,
+}
+
+impl Default for ScrollbarFade {
+    fn default() -> Self {
+        Self {
+            opacity: 0.0,
+            delay: SCROLLBAR_AUTO_HIDE_DELAY,
+            idle: SCROLLBAR_AUTO_HIDE_DELAY,
+            last_offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl ScrollbarFade {
+    /// Advance the fade by `dt` seconds.
+    ///
+    /// `active` wakes the scrollbars; `fade_step` is the opacity change per
+    /// call (1.0 snaps straight to the target).
+    pub fn step(&mut self, active: bool, dt: f32, fade_step: f32) {
+        if active {
+            self.idle = 0.0;
+        } else {
+            self.idle += dt;
+        }
+
+        let target = if self.idle < self.delay { 1.0 } else { 0.0 };
+        self.opacity = if target > self.opacity {
+            (self.opacity + fade_step).min(target)
+        } else {
+            (self.opacity - fade_step).max(target)
+        };
+    }
+}
+
 /// Marker component for scroll content (the inner scrollable element)
 #[derive(Component, Default)]
 pub struct ScrollContent;
@@ -708,9 +838,115 @@ pub struct ScrollbarDragging {
     pub drag_start_offset: f32,
 }
 
+/// Smooth scrolling state for a [`ScrollContainer`] with `smooth` enabled.
+///
+/// Inserted automatically for smooth containers; insert it yourself to tune the
+/// momentum `friction` or `impulse`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ScrollMomentum {
+    /// Offset the scroll position is easing towards (logical px)
+    pub target: Vec2,
+    /// Momentum velocity applied to the target (logical px per second)
+    pub velocity: Vec2,
+    /// Exponential velocity decay rate (per second)
+    pub friction: f32,
+    /// Velocity gained per pixel of wheel delta (per second)
+    pub impulse: f32,
+    /// Whether an animation is in progress
+    pub active: bool,
+}
+
+impl Default for ScrollMomentum {
+    fn default() -> Self {
+        Self {
+            target: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            friction: 6.0,
+            impulse: 4.0,
+            active: false,
+        }
+    }
+}
+
+impl ScrollMomentum {
+    /// Apply a wheel delta, moving the target and building up momentum.
+    ///
+    /// Deltas in the same direction as the current velocity accumulate (a fast
+    /// flick); a reversal cancels the momentum on that axis.
+    pub fn push(&mut self, current: Vec2, delta: Vec2, max_offset: Vec2) {
+        if !self.active {
+            self.target = current;
+            self.velocity = Vec2::ZERO;
+        }
+        self.active = true;
+
+        self.target = (self.target + delta).clamp(Vec2::ZERO, max_offset.max(Vec2::ZERO));
+
+        for (velocity, delta) in [
+            (&mut self.velocity.x, delta.x),
+            (&mut self.velocity.y, delta.y),
+        ] {
+            if delta == 0.0 {
+                continue;
+            }
+            if velocity.signum() != delta.signum() {
+                *velocity = 0.0;
+            }
+            *velocity += delta * self.impulse;
+        }
+    }
+
+    /// Advance the animation by `dt` seconds and return the new scroll position.
+    ///
+    /// `smooth_speed` is the fraction of the remaining distance covered per
+    /// 60 Hz frame. The target never leaves `0..=max_offset`.
+    pub fn step(&mut self, current: Vec2, max_offset: Vec2, smooth_speed: f32, dt: f32) -> Vec2 {
+        if !self.active {
+            return current;
+        }
+
+        let max_offset = max_offset.max(Vec2::ZERO);
+
+        // Momentum carries the target further, decaying over time.
+        self.target += self.velocity * dt;
+        self.velocity *= (-self.friction * dt).exp();
+        if self.velocity.length() < MOMENTUM_MIN_VELOCITY {
+            self.velocity = Vec2::ZERO;
+        }
+
+        // Clamp at the content bounds and kill momentum that pushes past them.
+        let clamped = self.target.clamp(Vec2::ZERO, max_offset);
+        if clamped.x != self.target.x {
+            self.velocity.x = 0.0;
+        }
+        if clamped.y != self.target.y {
+            self.velocity.y = 0.0;
+        }
+        self.target = clamped;
+
+        // Frame-rate independent exponential ease towards the target.
+        let speed = smooth_speed.clamp(0.0, 1.0);
+        let t = 1.0 - (1.0 - speed).powf(dt * 60.0);
+        let mut next = current + (self.target - current) * t;
+
+        if (self.target - next).length() < SMOOTH_SNAP_DISTANCE && self.velocity == Vec2::ZERO {
+            next = self.target;
+            self.active = false;
+        }
+
+        next
+    }
+}
+
 /// Line height for scroll calculations
 const LINE_HEIGHT: f32 = 21.0;
 
+/// Distance (in logical px) at which smooth scrolling snaps to its target.
+const SMOOTH_SNAP_DISTANCE: f32 = 0.5;
+
+/// Velocity (in logical px/s) below which scroll momentum stops.
+const MOMENTUM_MIN_VELOCITY: f32 = 5.0;
+
 /// Minimum overflow (in logical px) required before we consider a scrollbar necessary.
 ///
 /// During window resize/layout, computed sizes can fluctuate by sub-pixel amounts.
@@ -718,6 +954,15 @@ const LINE_HEIGHT: f32 = 21.0;
 /// causing scrollbars to briefly appear and then disappear.
 const OVERFLOW_EPSILON: f32 = 1.0;
 
+/// Minimum scrollbar thumb length (in logical px).
+const MIN_THUMB_LENGTH: f32 = 30.0;
+
+/// Seconds of inactivity before auto-hide scrollbars fade out.
+const SCROLLBAR_AUTO_HIDE_DELAY: f32 = 1.5;
+
+/// Duration (in seconds) of the auto-hide scrollbar fade.
+const SCROLLBAR_FADE_DURATION: f32 = 0.15;
+
 /// Thickness (in logical px) of the visual scrollbars spawned by `spawn_scrollbars`.
 /// Also used to reserve space in `ScrollContent` so scrollbars do not overlap content.
 const SCROLLBAR_THICKNESS: f32 = 10.0;
@@ -730,7 +975,14 @@ fn mouse_wheel_scroll_system(
     hover_map: Res<HoverMap>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     parents: Query<&ChildOf>,
-    mut scrollable_query: Query<(&mut ScrollPosition, &ScrollContainer), With<ScrollContainer>>,
+    mut scrollable_query: Query<
+        (
+            &mut ScrollPosition,
+            &ScrollContainer,
+            Option<&mut ScrollMomentum>,
+        ),
+        With<ScrollContainer>,
+    >,
 ) {
     for mouse_wheel in mouse_wheel_reader.read() {
         // Calculate scroll delta.
@@ -777,11 +1029,34 @@ fn mouse_wheel_scroll_system(
                     continue;
                 }
 
-                if let Ok((mut scroll_position, container)) =
+                if let Ok((mut scroll_position, container, momentum)) =
                     scrollable_query.get_mut(container_entity)
                 {
                     let max_offset = container.max_offset;
 
+                    // Smooth containers animate towards a target instead of jumping.
+                    if let (true, Some(mut momentum)) = (container.smooth, momentum) {
+                        let mut applied = Vec2::ZERO;
+                        if matches!(
+                            container.direction,
+                            ScrollDirection::Vertical | ScrollDirection::Both
+                        ) && max_offset.y > 0.0
+                        {
+                            applied.y = delta.y;
+                        }
+                        if matches!(
+                            container.direction,
+                            ScrollDirection::Horizontal | ScrollDirection::Both
+                        ) && max_offset.x > 0.0
+                        {
+                            applied.x = delta.x;
+                        }
+                        if applied != Vec2::ZERO {
+                            momentum.push(**scroll_position, applied, max_offset);
+                        }
+                        continue;
+                    }
+
                     // Handle vertical scroll
                     if matches!(
                         container.direction,
@@ -825,6 +1100,65 @@ fn mouse_wheel_scroll_system(
     }
 }
 
+/// Insert [`ScrollMomentum`] on smooth containers that don't have one yet
+fn ensure_scroll_momentum_system(
+    mut commands: Commands,
+    containers: Query<(Entity, &ScrollContainer), Without<ScrollMomentum>>,
+) {
+    for (entity, container) in containers.iter() {
+        if container.smooth {
+            commands.entity(entity).insert(ScrollMomentum::default());
+        }
+    }
+}
+
+/// Insert [`ScrollbarFade`] on auto-hide containers that don't have one yet
+fn ensure_scrollbar_fade_system(
+    mut commands: Commands,
+    containers: Query<(Entity, &ScrollContainer), Without<ScrollbarFade>>,
+) {
+    for (entity, container) in containers.iter() {
+        if container.auto_hide {
+            commands.entity(entity).insert(ScrollbarFade::default());
+        }
+    }
+}
+
+/// System to ease smooth containers towards their scroll target
+fn smooth_scroll_system(
+    time: Res<Time>,
+    mut containers: Query<(
+        &mut ScrollContainer,
+        &mut ScrollPosition,
+        &mut ScrollMomentum,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (mut container, mut scroll_pos, mut momentum) in containers.iter_mut() {
+        if !container.smooth || !momentum.active {
+            continue;
+        }
+
+        // A scrollbar drag takes over; drop any in-flight animation.
+        if container.dragging {
+            momentum.active = false;
+            momentum.velocity = Vec2::ZERO;
+            continue;
+        }
+
+        let next = momentum.step(
+            **scroll_pos,
+            container.max_offset,
+            container.smooth_speed,
+            dt,
+        );
+        if next != **scroll_pos {
+            **scroll_pos = next;
+        }
+        container.target_offset = momentum.target;
+    }
+}
+
 /// System to sync ScrollContainer state with Bevy's native ScrollPosition
 /// This reads the ScrollPosition (managed by Bevy's scroll system) and updates our ScrollContainer
 fn sync_scroll_state_system(
@@ -924,7 +1258,7 @@ fn scrollbar_thumb_drag_system(
     >,
     track_v: Query<(&ComputedNode, &ChildOf), With<ScrollbarTrackVertical>>,
     track_h: Query<(&ComputedNode, &ChildOf), With<ScrollbarTrackHorizontal>>,
-    mut containers: Query<(&ScrollContainer, &mut ScrollPosition, &ComputedNode)>,
+    mut containers: Query<(&mut ScrollContainer, &mut ScrollPosition, &ComputedNode)>,
 ) {
     let Ok(window) = windows.single() else { return };
     let cursor_pos = window.cursor_position();
@@ -942,10 +1276,13 @@ fn scrollbar_thumb_drag_system(
                 // track_parent is the thumb's ChildOf (points to track)
                 // scroll_parent is the track's ChildOf (points to scroll container)
                 if let Ok((track_node, scroll_parent)) = track_v.get(track_parent.0) {
-                    if let Ok((container, scroll_pos, computed)) = containers.get(scroll_parent.0) {
+                    if let Ok((mut container, scroll_pos, computed)) =
+                        containers.get_mut(scroll_parent.0)
+                    {
                         drag_state.is_dragging = true;
                         drag_state.drag_start_pos = Some(pos);
                         drag_state.drag_start_offset = scroll_pos.y;
+                        container.dragging = true;
 
                         let inv = computed.inverse_scale_factor();
                         let phys_scale = if inv > 0.0 { 1.0 / inv } else { 0.0 };
@@ -971,9 +1308,14 @@ fn scrollbar_thumb_drag_system(
         }
 
         // Stop dragging on release
-        if mouse_button.just_released(MouseButton::Left) {
+        if mouse_button.just_released(MouseButton::Left) && drag_state.is_dragging {
             drag_state.is_dragging = false;
             drag_state.drag_start_pos = None;
+            if let Ok((_, scroll_parent)) = track_v.get(track_parent.0) {
+                if let Ok((mut container, ..)) = containers.get_mut(scroll_parent.0) {
+                    container.dragging = false;
+                }
+            }
 
             if let Some(pos) = cursor_pos {
                 bevy::log::info!("SBAR DragVEnd: cursor_log=({:.1},{:.1})", pos.x, pos.y);
@@ -996,8 +1338,11 @@ fn scrollbar_thumb_drag_system(
 
                         // Clamp thumb size to the actual track height (important when both scrollbars are visible
                         // and the vertical track is shortened to avoid the bottom-right corner overlap).
-                        let thumb_size =
-                            container.vertical_thumb_size().max(30.0).min(track_height);
+                        let thumb_size = scrollbar_thumb_length(
+                            track_height,
+                            container.container_size.y,
+                            container.content_size.y,
+                        );
                         let thumb_travel = track_height - thumb_size;
 
                         let max_offset_y = container.max_offset.y;
@@ -1008,9 +1353,13 @@ fn scrollbar_thumb_drag_system(
                             let drag_delta_y = current_pos.y - start_pos.y;
 
                             // Convert thumb movement to scroll
-                            let scroll_delta = (drag_delta_y / thumb_travel) * max_offset_y;
-                            let new_y = (drag_state.drag_start_offset + scroll_delta)
-                                .clamp(0.0, max_offset_y);
+                            let new_y = scrollbar_drag_offset(
+                                drag_state.drag_start_offset,
+                                drag_delta_y,
+                                track_height,
+                                thumb_size,
+                                max_offset_y,
+                            );
 
                             let phys_scale = if inv > 0.0 { 1.0 / inv } else { 0.0 };
                             let start_phys = start_pos * phys_scale;
@@ -1054,10 +1403,13 @@ fn scrollbar_thumb_drag_system(
             if let Some(pos) = cursor_pos {
                 // Find the container through the track's parent
                 if let Ok((_track_node, scroll_parent)) = track_h.get(track_parent.0) {
-                    if let Ok((container, scroll_pos, computed)) = containers.get(scroll_parent.0) {
+                    if let Ok((mut container, scroll_pos, computed)) =
+                        containers.get_mut(scroll_parent.0)
+                    {
                         drag_state.is_dragging = true;
                         drag_state.drag_start_pos = Some(pos);
                         drag_state.drag_start_offset = scroll_pos.x;
+                        container.dragging = true;
 
                         let inv = computed.inverse_scale_factor();
                         let phys_scale = if inv > 0.0 { 1.0 / inv } else { 0.0 };
@@ -1083,9 +1435,14 @@ fn scrollbar_thumb_drag_system(
         }
 
         // Stop dragging on release
-        if mouse_button.just_released(MouseButton::Left) {
+        if mouse_button.just_released(MouseButton::Left) && drag_state.is_dragging {
             drag_state.is_dragging = false;
             drag_state.drag_start_pos = None;
+            if let Ok((_, scroll_parent)) = track_h.get(track_parent.0) {
+                if let Ok((mut container, ..)) = containers.get_mut(scroll_parent.0) {
+                    container.dragging = false;
+                }
+            }
 
             if let Some(pos) = cursor_pos {
                 bevy::log::info!("SBAR DragHEnd: cursor_log=({:.1},{:.1})", pos.x, pos.y);
@@ -1104,8 +1461,11 @@ fn scrollbar_thumb_drag_system(
                         // Use logical pixels consistently.
                         let inv = computed.inverse_scale_factor();
                         let track_width = track_node.size().x * inv;
-                        let thumb_size =
-                            container.horizontal_thumb_size().max(30.0).min(track_width);
+                        let thumb_size = scrollbar_thumb_length(
+                            track_width,
+                            container.container_size.x,
+                            container.content_size.x,
+                        );
                         let thumb_travel = track_width - thumb_size;
 
                         let max_offset_x = container.max_offset.x;
@@ -1114,9 +1474,13 @@ fn scrollbar_thumb_drag_system(
                             let drag_delta_x = current_pos.x - start_pos.x;
 
                             // Convert thumb movement to scroll
-                            let scroll_delta = (drag_delta_x / thumb_travel) * max_offset_x;
-                            let new_x = (drag_state.drag_start_offset + scroll_delta)
-                                .clamp(0.0, max_offset_x);
+                            let new_x = scrollbar_drag_offset(
+                                drag_state.drag_start_offset,
+                                drag_delta_x,
+                                track_width,
+                                thumb_size,
+                                max_offset_x,
+                            );
 
                             let phys_scale = if inv > 0.0 { 1.0 / inv } else { 0.0 };
                             let start_phys = start_pos * phys_scale;
@@ -1154,9 +1518,192 @@ fn scrollbar_thumb_drag_system(
     }
 }
 
+/// Thumb length for a track of `track_len` showing `visible` of `content` pixels.
+fn scrollbar_thumb_length(track_len: f32, visible: f32, content: f32) -> f32 {
+    if track_len <= 0.0 {
+        return 0.0;
+    }
+    if visible <= 0.0 || content <= visible {
+        return track_len;
+    }
+    (track_len * (visible / content))
+        .max(MIN_THUMB_LENGTH)
+        .min(track_len)
+}
+
+/// Scroll offset for a thumb dragged `drag_delta` pixels from where it was grabbed.
+fn scrollbar_drag_offset(
+    start_offset: f32,
+    drag_delta: f32,
+    track_len: f32,
+    thumb_len: f32,
+    max_offset: f32,
+) -> f32 {
+    let thumb_travel = track_len - thumb_len;
+    if max_offset <= 0.0 || thumb_travel <= 0.0 {
+        return start_offset.clamp(0.0, max_offset.max(0.0));
+    }
+    (start_offset + (drag_delta / thumb_travel) * max_offset).clamp(0.0, max_offset)
+}
+
+/// Scroll offset after clicking the track at `click`, paging one viewport toward it.
+///
+/// `click`, `thumb_start` and `thumb_len` are measured along the same axis.
+/// Clicks on the thumb itself leave the offset unchanged.
+fn scrollbar_page_offset(
+    current: f32,
+    page: f32,
+    max_offset: f32,
+    click: f32,
+    thumb_start: f32,
+    thumb_len: f32,
+) -> f32 {
+    let max_offset = max_offset.max(0.0);
+    if click < thumb_start {
+        (current - page).clamp(0.0, max_offset)
+    } else if click > thumb_start + thumb_len {
+        (current + page).clamp(0.0, max_offset)
+    } else {
+        current.clamp(0.0, max_offset)
+    }
+}
+
+/// System to page scroll containers when their scrollbar track is clicked
+fn scrollbar_track_click_system(
+    windows: Query<&Window>,
+    tracks_v: Query<
+        (&Interaction, &ChildOf, &Children),
+        (Changed<Interaction>, With<ScrollbarTrackVertical>),
+    >,
+    tracks_h: Query<
+        (&Interaction, &ChildOf, &Children),
+        (Changed<Interaction>, With<ScrollbarTrackHorizontal>),
+    >,
+    thumbs: Query<
+        (&ComputedNode, &UiGlobalTransform),
+        Or<(With<ScrollbarThumbVertical>, With<ScrollbarThumbHorizontal>)>,
+    >,
+    mut containers: Query<(
+        &ScrollContainer,
+        &mut ScrollPosition,
+        Option<&mut ScrollMomentum>,
+    )>,
+) {
+    let Ok(window) = windows.single() else { return };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    let pressed_v = tracks_v
+        .iter()
+        .filter(|(interaction, ..)| **interaction == Interaction::Pressed)
+        .map(|(_, parent, children)| (parent.parent(), children, true));
+    let pressed_h = tracks_h
+        .iter()
+        .filter(|(interaction, ..)| **interaction == Interaction::Pressed)
+        .map(|(_, parent, children)| (parent.parent(), children, false));
+
+    for (container_entity, children, vertical) in pressed_v.chain(pressed_h) {
+        let Some((thumb_node, thumb_transform)) =
+            children.iter().find_map(|child| thumbs.get(child).ok())
+        else {
+            continue;
+        };
+        let Ok((container, mut scroll_pos, momentum)) = containers.get_mut(container_entity) else {
+            continue;
+        };
+
+        // Cursor is logical; UiGlobalTransform/ComputedNode are physical.
+        let inv = thumb_node.inverse_scale_factor();
+        let thumb_size = thumb_node.size() * inv;
+        let thumb_start = thumb_transform.translation * inv - thumb_size / 2.0;
+
+        let mut target = **scroll_pos;
+        if vertical {
+            target.y = scrollbar_page_offset(
+                scroll_pos.y,
+                container.container_size.y,
+                container.max_offset.y,
+                cursor.y,
+                thumb_start.y,
+                thumb_size.y,
+            );
+        } else {
+            target.x = scrollbar_page_offset(
+                scroll_pos.x,
+                container.container_size.x,
+                container.max_offset.x,
+                cursor.x,
+                thumb_start.x,
+                thumb_size.x,
+            );
+        }
+
+        if target == **scroll_pos {
+            continue;
+        }
+
+        match (container.smooth, momentum) {
+            (true, Some(mut momentum)) => {
+                momentum.target = target;
+                momentum.velocity = Vec2::ZERO;
+                momentum.active = true;
+            }
+            _ => **scroll_pos = target,
+        }
+    }
+}
+
+/// Wake auto-hide scrollbars on scroll, drag, or hover and fade them out when idle
+fn scrollbar_fade_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut containers: Query<(
+        &ScrollContainer,
+        &ScrollPosition,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &mut ScrollbarFade,
+    )>,
+) {
+    let dt = time.delta_secs();
+    let fade_step = if reduced_motion.is_some_and(|r| r.0) {
+        1.0
+    } else {
+        dt / SCROLLBAR_FADE_DURATION
+    };
+    let cursor = windows
+        .single()
+        .ok()
+        .and_then(Window::physical_cursor_position);
+
+    for (container, scroll_pos, computed, transform, mut fade) in containers.iter_mut() {
+        if !container.auto_hide {
+            continue;
+        }
+
+        let scrolled = fade.last_offset != **scroll_pos;
+        let hovered = cursor.is_some_and(|cursor| computed.contains_point(*transform, cursor));
+        let active = scrolled || hovered || container.dragging;
+
+        let mut next = fade.clone();
+        next.last_offset = **scroll_pos;
+        next.step(active, dt, fade_step);
+        if *fade != next {
+            *fade = next;
+        }
+    }
+}
+
 /// System to update scrollbar visuals
 fn update_scrollbars(
-    containers: Query<(&ScrollContainer, &ScrollPosition, &Children)>,
+    containers: Query<(
+        &ScrollContainer,
+        &ScrollPosition,
+        &Children,
+        Option<&ScrollbarFade>,
+    )>,
     mut queries: ParamSet<(
         Query<&ComputedNode, With<ScrollbarTrackVertical>>,
         Query<&ComputedNode, With<ScrollbarTrackHorizontal>>,
@@ -1191,12 +1738,16 @@ fn update_scrollbars(
         Query<&Children>,
     )>,
 ) {
-    for (container, scroll_pos, children) in containers.iter() {
+    for (container, scroll_pos, children, fade) in containers.iter() {
+        // Fully faded auto-hide scrollbars are hidden so they don't catch clicks.
+        let faded_out = container.scrollbar_opacity(fade) <= 0.0;
+
         // Find scrollbar elements in children
         for child in children.iter() {
             // Check for vertical track
             if let Ok(mut vis) = queries.p4().get_mut(child) {
                 *vis = if container.show_scrollbars
+                    && !faded_out
                     && matches!(
                         container.direction,
                         ScrollDirection::Vertical | ScrollDirection::Both
@@ -1212,6 +1763,7 @@ fn update_scrollbars(
             // Check for horizontal track
             if let Ok(mut vis) = queries.p5().get_mut(child) {
                 *vis = if container.show_scrollbars
+                    && !faded_out
                     && matches!(
                         container.direction,
                         ScrollDirection::Horizontal | ScrollDirection::Both
@@ -1240,8 +1792,11 @@ fn update_scrollbars(
                         .map(|t| t.size().y * t.inverse_scale_factor());
                     if let Some(track_height) = track_height {
                         if let Ok(mut node) = queries.p2().get_mut(track_child) {
-                            let thumb_size =
-                                container.vertical_thumb_size().max(30.0).min(track_height);
+                            let thumb_size = scrollbar_thumb_length(
+                                track_height,
+                                container.container_size.y,
+                                container.content_size.y,
+                            );
                             // scroll_ratio: 0 = top, 1 = bottom
                             let scroll_ratio = if container.max_offset.y > 0.0 {
                                 (scroll_pos.y / container.max_offset.y).clamp(0.0, 1.0)
@@ -1281,8 +1836,11 @@ fn update_scrollbars(
                         .map(|t| t.size().x * t.inverse_scale_factor());
                     if let Some(track_width) = track_width {
                         if let Ok(mut node) = queries.p3().get_mut(track_child) {
-                            let thumb_size =
-                                container.horizontal_thumb_size().max(30.0).min(track_width);
+                            let thumb_size = scrollbar_thumb_length(
+                                track_width,
+                                container.container_size.x,
+                                container.content_size.x,
+                            );
                             let scroll_ratio = if container.max_offset.x > 0.0 {
                                 (scroll_pos.x / container.max_offset.x).clamp(0.0, 1.0)
                             } else {
@@ -1317,6 +1875,51 @@ fn update_scrollbars(
     }
 }
 
+/// Apply the container's [`ScrollbarStyle`] and auto-hide opacity to its scrollbars
+fn scrollbar_style_system(
+    theme: Option<Res<MaterialTheme>>,
+    containers: Query<(&ScrollContainer, Option<&ScrollbarFade>, &Children)>,
+    tracks: Query<
+        Option<&Children>,
+        Or<(With<ScrollbarTrackVertical>, With<ScrollbarTrackHorizontal>)>,
+    >,
+    thumbs: Query<(), Or<(With<ScrollbarThumbVertical>, With<ScrollbarThumbHorizontal>)>>,
+    mut colors: Query<&mut BackgroundColor>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (container, fade, children) in containers.iter() {
+        let opacity = container.scrollbar_opacity(fade);
+        let style = container.scrollbar_style;
+        let track_color = style.track_color(&theme);
+        let track_color = track_color.with_alpha(track_color.alpha() * opacity);
+        let thumb_color = style.thumb_color(&theme);
+        let thumb_color = thumb_color.with_alpha(thumb_color.alpha() * opacity);
+
+        for track in children.iter() {
+            let Ok(track_children) = tracks.get(track) else {
+                continue;
+            };
+            if let Ok(mut color) = colors.get_mut(track) {
+                if color.0 != track_color {
+                    color.0 = track_color;
+                }
+            }
+
+            for thumb in track_children.into_iter().flat_map(|c| c.iter()) {
+                if !thumbs.contains(thumb) {
+                    continue;
+                }
+                if let Ok(mut color) = colors.get_mut(thumb) {
+                    if color.0 != thumb_color {
+                        color.0 = thumb_color;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Keep `ScrollContent` padding in sync with scrollbar visibility.
 ///
 /// Our scrollbars are overlay UI, so we reserve space on the right/bottom of the
@@ -1379,12 +1982,15 @@ fn spawn_scrollbar_vertical(
     reserve_bottom_corner: bool,
 ) {
     let scrollbar_width = SCROLLBAR_THICKNESS;
-    let track_color = theme.surface_container_highest.with_alpha(0.5);
-    let thumb_color = theme.primary.with_alpha(0.7);
+    let style = ScrollbarStyle::default();
+    let track_color = style.track_color(theme);
+    let thumb_color = style.thumb_color(theme);
 
     commands
         .spawn((
             ScrollbarTrackVertical,
+            Button,
+            Interaction::None,
             Visibility::Hidden,
             Node {
                 position_type: PositionType::Absolute,
@@ -1426,12 +2032,15 @@ fn spawn_scrollbar_horizontal(
     reserve_right_corner: bool,
 ) {
     let scrollbar_width = SCROLLBAR_THICKNESS;
-    let track_color = theme.surface_container_highest.with_alpha(0.5);
-    let thumb_color = theme.primary.with_alpha(0.7);
+    let style = ScrollbarStyle::default();
+    let track_color = style.track_color(theme);
+    let thumb_color = style.thumb_color(theme);
 
     commands
         .spawn((
             ScrollbarTrackHorizontal,
+            Button,
+            Interaction::None,
             Visibility::Hidden,
             Node {
                 position_type: PositionType::Absolute,
@@ -1475,6 +2084,8 @@ pub struct ScrollContainerBuilder {
     smooth_speed: f32,
     show_scrollbars: bool,
     always_show_scrollbars: bool,
+    scrollbar_style: ScrollbarStyle,
+    auto_hide: bool,
 }
 
 impl Default for ScrollContainerBuilder {
@@ -1482,10 +2093,12 @@ impl Default for ScrollContainerBuilder {
         Self {
             direction: ScrollDirection::Vertical,
             sensitivity: 40.0,
-            smooth: true,
+            smooth: false,
             smooth_speed: 0.2,
             show_scrollbars: true,
             always_show_scrollbars: false,
+            scrollbar_style: ScrollbarStyle::default(),
+            auto_hide: false,
         }
     }
 }
@@ -1531,6 +2144,18 @@ impl ScrollContainerBuilder {
         self
     }
 
+    /// Set scrollbar colors
+    pub fn scrollbar_style(mut self, style: ScrollbarStyle) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Fade scrollbars in on scroll/hover and out after inactivity.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
     pub fn build(self) -> ScrollContainer {
         ScrollContainer {
             direction: self.direction,
@@ -1539,6 +2164,8 @@ impl ScrollContainerBuilder {
             smooth_speed: self.smooth_speed,
             show_scrollbars: self.show_scrollbars,
             always_show_scrollbars: self.always_show_scrollbars,
+            scrollbar_style: self.scrollbar_style,
+            auto_hide: self.auto_hide,
             ..default()
         }
     }
@@ -1553,7 +2180,7 @@ mod tests {
         let container = ScrollContainer::default();
         assert!(matches!(container.direction, ScrollDirection::Vertical));
         assert_eq!(container.offset, Vec2::ZERO);
-        assert!(container.smooth);
+        assert!(!container.smooth);
         assert!(container.show_scrollbars);
         assert!(!container.always_show_scrollbars);
     }
@@ -1588,6 +2215,134 @@ mod tests {
         assert!(container.always_show_scrollbars);
     }
 
+    #[test]
+    fn test_scrollbar_style_follows_theme() {
+        let theme = MaterialTheme::default();
+        let style = ScrollbarStyle::new();
+        assert_eq!(style.track_color(&theme), theme.outline_variant);
+        assert_eq!(style.thumb_color(&theme), theme.on_surface_variant);
+
+        let style = style.thumb(Color::WHITE);
+        assert_eq!(style.thumb_color(&theme), Color::WHITE);
+        assert_eq!(style.track_color(&theme), theme.outline_variant);
+
+        let container = ScrollContainerBuilder::new()
+            .scrollbar_style(style)
+            .auto_hide(true)
+            .build();
+        assert_eq!(container.scrollbar_style, style);
+        assert!(container.auto_hide);
+        assert_eq!(container.scrollbar_opacity(None), 0.0);
+    }
+
+    #[test]
+    fn test_scrollbar_fade_in_and_out() {
+        let mut fade = ScrollbarFade::default();
+        assert_eq!(fade.opacity, 0.0);
+
+        // Activity fades the scrollbars in.
+        fade.step(true, 0.1, 0.5);
+        fade.step(false, 0.1, 0.5);
+        assert_eq!(fade.opacity, 1.0);
+
+        // They stay until the container has been idle for `delay` seconds.
+        fade.step(false, fade.delay, 0.5);
+        assert_eq!(fade.opacity, 0.5);
+        fade.step(false, 0.1, 0.5);
+        assert_eq!(fade.opacity, 0.0);
+    }
+
+    #[test]
+    fn test_scroll_momentum_eases_to_target() {
+        let mut momentum = ScrollMomentum {
+            impulse: 0.0,
+            ..default()
+        };
+        let max = Vec2::new(0.0, 500.0);
+        momentum.push(Vec2::ZERO, Vec2::new(0.0, 100.0), max);
+        assert_eq!(momentum.target, Vec2::new(0.0, 100.0));
+
+        let mut pos = Vec2::ZERO;
+        pos = momentum.step(pos, max, 0.2, 1.0 / 60.0);
+        assert!(pos.y > 0.0 && pos.y < 100.0);
+
+        for _ in 0..600 {
+            pos = momentum.step(pos, max, 0.2, 1.0 / 60.0);
+        }
+        assert_eq!(pos, Vec2::new(0.0, 100.0));
+        assert!(!momentum.active);
+    }
+
+    #[test]
+    fn test_scroll_momentum_flick_is_clamped() {
+        let mut momentum = ScrollMomentum::default();
+        let max = Vec2::new(0.0, 300.0);
+        for _ in 0..5 {
+            momentum.push(Vec2::ZERO, Vec2::new(0.0, 60.0), max);
+        }
+        assert_eq!(momentum.target.y, 300.0);
+        assert!(momentum.velocity.y > 0.0);
+
+        let mut pos = Vec2::ZERO;
+        for _ in 0..600 {
+            pos = momentum.step(pos, max, 0.2, 1.0 / 60.0);
+            assert!(pos.y <= max.y);
+        }
+        assert_eq!(pos.y, 300.0);
+
+        // Reversing direction mid-flick cancels the built-up momentum.
+        let mut momentum = ScrollMomentum::default();
+        let max = Vec2::new(0.0, 1000.0);
+        momentum.push(Vec2::ZERO, Vec2::new(0.0, 60.0), max);
+        momentum.push(Vec2::ZERO, Vec2::new(0.0, 60.0), max);
+        let pos = momentum.step(Vec2::ZERO, max, 0.2, 1.0 / 60.0);
+        assert!(momentum.active);
+        assert!(momentum.velocity.y > 0.0);
+
+        momentum.push(pos, Vec2::new(0.0, -20.0), max);
+        assert_eq!(momentum.velocity.y, -20.0 * momentum.impulse);
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_length_and_drag() {
+        // A quarter of the content is visible: the thumb covers a quarter of the track.
+        assert_eq!(scrollbar_thumb_length(200.0, 100.0, 400.0), 50.0);
+        assert_eq!(
+            scrollbar_thumb_length(200.0, 10.0, 10_000.0),
+            MIN_THUMB_LENGTH
+        );
+        assert_eq!(scrollbar_thumb_length(200.0, 400.0, 400.0), 200.0);
+
+        // Dragging the thumb across its full travel scrolls across the full range.
+        assert_eq!(scrollbar_drag_offset(0.0, 150.0, 200.0, 50.0, 300.0), 300.0);
+        assert_eq!(scrollbar_drag_offset(0.0, 75.0, 200.0, 50.0, 300.0), 150.0);
+        assert_eq!(
+            scrollbar_drag_offset(150.0, -500.0, 200.0, 50.0, 300.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_scrollbar_track_click_pages_toward_click() {
+        // Thumb spans 50..100 on the track.
+        assert_eq!(
+            scrollbar_page_offset(150.0, 100.0, 300.0, 180.0, 50.0, 50.0),
+            250.0
+        );
+        assert_eq!(
+            scrollbar_page_offset(150.0, 100.0, 300.0, 10.0, 50.0, 50.0),
+            50.0
+        );
+        assert_eq!(
+            scrollbar_page_offset(250.0, 100.0, 300.0, 180.0, 50.0, 50.0),
+            300.0
+        );
+        assert_eq!(
+            scrollbar_page_offset(150.0, 100.0, 300.0, 75.0, 50.0, 50.0),
+            150.0
+        );
+    }
+
     #[test]
     fn test_thumb_calculations() {
         let mut container = ScrollContainer::vertical();
@@ -6,8 +6,11 @@
 use bevy::prelude::*;
 
 use crate::{
+    focus::wrap_highlight,
     i18n::{MaterialI18n, MaterialLanguage, MaterialLanguageOverride},
     icons::{IconStyle, MaterialIcon},
+    list::{ListItemBuilder, MaterialListItem, SpawnListChild},
+    overlay::{track_overlay_system, Overlay, OverlayStack, Z_MENU},
     ripple::RippleHost,
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
@@ -23,12 +26,20 @@ impl Plugin for SearchPlugin {
         }
         app.add_message::<SearchBarClickEvent>()
             .add_message::<SearchQueryEvent>()
+            .add_message::<UpdateSearchSuggestions>()
+            .add_message::<SearchSuggestionSelected>()
             .add_systems(
                 Update,
                 (
                     search_bar_interaction_system,
                     search_bar_localization_system,
                     search_bar_display_text_system,
+                    search_suggestions_update_system,
+                    search_suggestions_click_system,
+                    search_suggestions_keyboard_system,
+                    search_bar_blur_system,
+                    track_overlay_system::<MaterialSearchBar>,
+                    search_suggestions_sync_system,
                 )
                     .chain(),
             );
@@ -52,6 +63,22 @@ pub struct SearchQueryEvent {
     pub query: String,
 }
 
+/// Replace the suggestions shown below a search bar.
+///
+/// Send an empty `items` list to clear the dropdown.
+#[derive(Event, bevy::prelude::Message, Clone)]
+pub struct UpdateSearchSuggestions {
+    pub bar: Entity,
+    pub items: Vec<SearchSuggestion>,
+}
+
+/// Event fired when a suggestion is chosen by click or Enter
+#[derive(Event, bevy::prelude::Message, Clone)]
+pub struct SearchSuggestionSelected {
+    pub bar: Entity,
+    pub index: usize,
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -67,6 +94,8 @@ pub struct MaterialSearchBar {
     pub navigation_icon: Option<MaterialIcon>,
     /// Whether to show the trailing action icon
     pub show_action: bool,
+    /// Whether the bar has focus (set on click, cleared on blur/Escape)
+    pub focused: bool,
 }
 
 impl MaterialSearchBar {
@@ -76,6 +105,7 @@ impl MaterialSearchBar {
             text: String::new(),
             navigation_icon: None,
             show_action: true,
+            focused: false,
         }
     }
 
@@ -90,6 +120,12 @@ impl MaterialSearchBar {
     }
 }
 
+impl Overlay for MaterialSearchBar {
+    fn overlay_open(&self) -> bool {
+        self.focused
+    }
+}
+
 /// Marker for search bar navigation button
 #[derive(Component)]
 pub struct SearchBarNavigation;
@@ -124,12 +160,97 @@ pub struct SearchBarDisplayText;
 #[derive(Component)]
 pub struct SearchBarDisplayTextFor(pub Entity);
 
+/// Suggestions dropdown anchored below a search bar.
+///
+/// Its `MaterialListItem` children are the suggestions. Populate it with
+/// [`UpdateSearchSuggestions`] or by spawning list items under it directly.
+/// The dropdown is shown while the bar is focused and has at least one item.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SearchSuggestions {
+    /// The search bar this dropdown belongs to
+    pub bar: Entity,
+    /// Keyboard-highlighted suggestion
+    pub highlighted: Option<usize>,
+}
+
+impl SearchSuggestions {
+    pub fn new(bar: Entity) -> Self {
+        Self {
+            bar,
+            highlighted: None,
+        }
+    }
+
+    /// Move the highlight by `delta` items, wrapping around `count` items.
+    pub fn move_highlight(&mut self, delta: i32, count: usize) {
+        self.highlighted = wrap_highlight(self.highlighted, delta, count);
+    }
+}
+
+/// A single search suggestion row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchSuggestion {
+    /// Primary text of the row
+    pub headline: String,
+    /// Optional second line shown under the headline
+    pub supporting_text: Option<String>,
+    /// Optional Material icon name shown before the headline
+    pub leading_icon: Option<String>,
+}
+
+impl SearchSuggestion {
+    pub fn new(headline: impl Into<String>) -> Self {
+        Self {
+            headline: headline.into(),
+            supporting_text: None,
+            leading_icon: None,
+        }
+    }
+
+    pub fn supporting_text(mut self, text: impl Into<String>) -> Self {
+        self.supporting_text = Some(text.into());
+        self
+    }
+
+    pub fn leading_icon(mut self, icon: impl Into<String>) -> Self {
+        self.leading_icon = Some(icon.into());
+        self
+    }
+
+    /// List item builder used to render this suggestion.
+    pub fn list_item(&self) -> ListItemBuilder {
+        let mut builder = ListItemBuilder::new(self.headline.clone());
+        if let Some(text) = &self.supporting_text {
+            builder = builder.two_line().supporting_text(text.clone());
+        }
+        if let Some(icon) = &self.leading_icon {
+            builder = builder.leading_icon(icon.clone());
+        }
+        builder
+    }
+}
+
+impl From<&str> for SearchSuggestion {
+    fn from(headline: &str) -> Self {
+        Self::new(headline)
+    }
+}
+
+impl From<String> for SearchSuggestion {
+    fn from(headline: String) -> Self {
+        Self::new(headline)
+    }
+}
+
 // ============================================================================
 // Constants
 // ============================================================================
 
 pub const SEARCH_BAR_HEIGHT: f32 = 56.0;
 
+/// Maximum height of the suggestions dropdown before it clips.
+pub const SEARCH_SUGGESTIONS_MAX_HEIGHT: f32 = 320.0;
+
 // ============================================================================
 // Builder
 // ============================================================================
@@ -300,6 +421,26 @@ impl SpawnSearchBarChild for ChildSpawnerCommands<'_> {
                     ));
                 }
             });
+
+            // Suggestions dropdown (hidden until focused with results)
+            bar.spawn((
+                SearchSuggestions::new(bar_entity),
+                Visibility::Hidden,
+                GlobalZIndex(Z_MENU),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(SEARCH_BAR_HEIGHT + Spacing::EXTRA_SMALL),
+                    left: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    max_height: Val::Px(SEARCH_SUGGESTIONS_MAX_HEIGHT),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::vertical(Val::Px(Spacing::SMALL)),
+                    overflow: Overflow::clip_y(),
+                    ..default()
+                },
+                BackgroundColor(theme.surface_container_high),
+                BorderRadius::all(Val::Px(CornerRadius::LARGE)),
+            ));
         });
     }
 }
@@ -413,12 +554,239 @@ fn search_bar_display_text_system(
 // ============================================================================
 
 fn search_bar_interaction_system(
-    search_bars: Query<(&Interaction, Entity), (Changed<Interaction>, With<MaterialSearchBar>)>,
+    mut search_bars: Query<(&Interaction, Entity, &mut MaterialSearchBar), Changed<Interaction>>,
     mut click_events: MessageWriter<SearchBarClickEvent>,
 ) {
-    for (interaction, entity) in search_bars.iter() {
+    for (interaction, entity, mut bar) in search_bars.iter_mut() {
         if *interaction == Interaction::Pressed {
+            bar.focused = true;
             click_events.write(SearchBarClickEvent { search_bar: entity });
         }
     }
 }
+
+/// Collect the suggestion list items under a dropdown, in display order.
+fn suggestion_items(
+    children: Option<&Children>,
+    items: &Query<(), With<MaterialListItem>>,
+) -> Vec<Entity> {
+    children
+        .map(|children| {
+            children
+                .iter()
+                .filter(|child| items.get(*child).is_ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Replace dropdown rows from [`UpdateSearchSuggestions`] messages.
+fn search_suggestions_update_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut updates: MessageReader<UpdateSearchSuggestions>,
+    mut dropdowns: Query<(Entity, &mut SearchSuggestions, Option<&Children>)>,
+) {
+    let Some(theme) = theme else { return };
+
+    for update in updates.read() {
+        for (entity, mut suggestions, children) in dropdowns.iter_mut() {
+            if suggestions.bar != update.bar {
+                continue;
+            }
+
+            if let Some(children) = children {
+                for child in children.iter() {
+                    commands.entity(child).despawn();
+                }
+            }
+
+            suggestions.highlighted = None;
+
+            let items = update.items.clone();
+            commands.entity(entity).with_children(|dropdown| {
+                for item in &items {
+                    dropdown.spawn_list_item_with(&theme, item.list_item());
+                }
+            });
+        }
+    }
+}
+
+/// Emit [`SearchSuggestionSelected`] when a suggestion row is clicked.
+fn search_suggestions_click_system(
+    rows: Query<(Entity, &Interaction, &ChildOf), (Changed<Interaction>, With<MaterialListItem>)>,
+    dropdowns: Query<(&SearchSuggestions, &Children)>,
+    mut bars: Query<&mut MaterialSearchBar>,
+    mut selected: MessageWriter<SearchSuggestionSelected>,
+    items: Query<(), With<MaterialListItem>>,
+) {
+    for (row, interaction, parent) in rows.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((suggestions, children)) = dropdowns.get(parent.parent()) else {
+            continue;
+        };
+        let Some(index) = suggestion_items(Some(children), &items)
+            .iter()
+            .position(|e| *e == row)
+        else {
+            continue;
+        };
+
+        if let Ok(mut bar) = bars.get_mut(suggestions.bar) {
+            bar.focused = false;
+        }
+        selected.write(SearchSuggestionSelected {
+            bar: suggestions.bar,
+            index,
+        });
+    }
+}
+
+/// Arrow keys move the highlight, Enter selects it and Escape dismisses.
+fn search_suggestions_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    stack: Option<Res<OverlayStack>>,
+    mut bars: Query<(Entity, &mut MaterialSearchBar)>,
+    mut dropdowns: Query<(&mut SearchSuggestions, Option<&Children>)>,
+    items: Query<(), With<MaterialListItem>>,
+    mut selected: MessageWriter<SearchSuggestionSelected>,
+) {
+    // Escape only blurs the bar when nothing is open above it.
+    if let Some(stack) = stack {
+        for (entity, mut bar) in bars.iter_mut() {
+            if bar.focused && stack.escape_pressed_for(entity) {
+                bar.focused = false;
+            }
+        }
+    }
+
+    for (mut suggestions, children) in dropdowns.iter_mut() {
+        let Ok((_, mut bar)) = bars.get_mut(suggestions.bar) else {
+            continue;
+        };
+        if !bar.focused {
+            continue;
+        }
+
+        let count = suggestion_items(children, &items).len();
+        if keys.just_pressed(KeyCode::ArrowDown) {
+            suggestions.move_highlight(1, count);
+        }
+        if keys.just_pressed(KeyCode::ArrowUp) {
+            suggestions.move_highlight(-1, count);
+        }
+
+        if keys.just_pressed(KeyCode::Enter) {
+            if let Some(index) = suggestions.highlighted.filter(|i| *i < count) {
+                bar.focused = false;
+                selected.write(SearchSuggestionSelected {
+                    bar: suggestions.bar,
+                    index,
+                });
+            }
+        }
+    }
+}
+
+/// Clear focus when the user clicks outside a search bar and its suggestions.
+fn search_bar_blur_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut bars: Query<(Entity, &Interaction, &mut MaterialSearchBar)>,
+    dropdowns: Query<(&SearchSuggestions, Option<&Children>)>,
+    interactions: Query<&Interaction>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (entity, interaction, mut bar) in bars.iter_mut() {
+        if !bar.focused || *interaction != Interaction::None {
+            continue;
+        }
+
+        let over_suggestions = dropdowns
+            .iter()
+            .filter(|(suggestions, _)| suggestions.bar == entity)
+            .filter_map(|(_, children)| children)
+            .flat_map(|children| children.iter())
+            .any(|child| {
+                interactions
+                    .get(child)
+                    .is_ok_and(|i| *i != Interaction::None)
+            });
+
+        if !over_suggestions {
+            bar.focused = false;
+        }
+    }
+}
+
+/// Show the dropdown while its bar is focused with results, and mirror the highlight.
+fn search_suggestions_sync_system(
+    bars: Query<&MaterialSearchBar>,
+    mut dropdowns: Query<(&mut SearchSuggestions, &mut Visibility, Option<&Children>)>,
+    mut items: Query<&mut MaterialListItem>,
+) {
+    for (mut suggestions, mut visibility, children) in dropdowns.iter_mut() {
+        let focused = bars.get(suggestions.bar).is_ok_and(|bar| bar.focused);
+
+        let rows: Vec<Entity> = children
+            .map(|children| {
+                children
+                    .iter()
+                    .filter(|child| items.get(*child).is_ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if !focused && suggestions.highlighted.is_some() {
+            suggestions.highlighted = None;
+        }
+
+        let next = if focused && !rows.is_empty() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != next {
+            *visibility = next;
+        }
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let Ok(mut item) = items.get_mut(row) else {
+                continue;
+            };
+            let highlighted = suggestions.highlighted == Some(index);
+            if item.selected != highlighted {
+                item.selected = highlighted;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestion_highlight_wraps() {
+        let mut suggestions = SearchSuggestions::new(Entity::PLACEHOLDER);
+
+        suggestions.move_highlight(1, 3);
+        assert_eq!(suggestions.highlighted, Some(0));
+        suggestions.move_highlight(-1, 3);
+        assert_eq!(suggestions.highlighted, Some(2));
+        suggestions.move_highlight(1, 3);
+        assert_eq!(suggestions.highlighted, Some(0));
+
+        suggestions.highlighted = None;
+        suggestions.move_highlight(-1, 3);
+        assert_eq!(suggestions.highlighted, Some(2));
+
+        suggestions.move_highlight(1, 0);
+        assert_eq!(suggestions.highlighted, None);
+    }
+}
@@ -3,12 +3,19 @@
 //! Select menus display a list of choices on a temporary surface and allow users to select one.
 //! Reference: <https://m3.material.io/components/menus/overview>
 
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::ui::{RelativeCursorPosition, ScrollPosition, UiGlobalTransform};
+use bevy::window::PrimaryWindow;
 
 use crate::{
     i18n::{MaterialI18n, MaterialLanguage, MaterialLanguageOverride},
     icons::{icon_by_name, MaterialIcon, ICON_EXPAND_MORE},
-    telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
+    overlay::{
+        track_overlay_system, DismissOnOutsideClick, OutsideClickEvent, Overlay,
+        OverlayReflowSystems, OverlayStack, Z_MENU,
+    },
+    telemetry::{ElementBounds, InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
 };
@@ -21,20 +28,30 @@ impl Plugin for SelectPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
-        app.add_message::<SelectChangeEvent>().add_systems(
-            Update,
-            (
-                select_interaction_system,
-                select_style_system,
-                select_content_style_system,
-                select_theme_refresh_system,
-                select_localization_system,
-                select_dropdown_rebuild_options_system,
-                select_dropdown_sync_system,
-                select_option_interaction_system,
-                select_telemetry_system,
-            ),
-        );
+        app.add_message::<SelectChangeEvent>()
+            .add_systems(
+                Update,
+                (
+                    select_interaction_system,
+                    track_overlay_system::<MaterialSelect>,
+                    select_keyboard_dismiss_system,
+                    select_outside_click_system,
+                    select_style_system,
+                    select_content_style_system,
+                    select_theme_refresh_system,
+                    select_localization_system,
+                    select_dropdown_rebuild_options_system,
+                    select_dropdown_sync_system,
+                    select_dropdown_position_system,
+                    select_dropdown_scroll_system,
+                    select_option_interaction_system,
+                    select_telemetry_system,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                select_dropdown_position_system.in_set(OverlayReflowSystems),
+            );
     }
 }
 
@@ -468,6 +485,12 @@ impl MaterialSelect {
     }
 }
 
+impl Overlay for MaterialSelect {
+    fn overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
 /// A select option
 #[derive(Debug, Clone)]
 pub struct SelectOption {
@@ -532,6 +555,49 @@ pub struct SelectChangeEvent {
 /// Select dimensions
 pub const SELECT_HEIGHT: f32 = 56.0;
 pub const SELECT_OPTION_HEIGHT: f32 = 48.0;
+/// Gap between the select field and its dropdown
+pub const SELECT_DROPDOWN_GAP: f32 = 4.0;
+/// Minimum space kept between the dropdown and the window edge
+pub const SELECT_DROPDOWN_EDGE_MARGIN: f32 = 8.0;
+/// Vertical padding inside the dropdown
+const SELECT_DROPDOWN_PADDING: f32 = 8.0;
+
+/// Direction in which a select dropdown opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectDropdownDirection {
+    /// Below the select field
+    #[default]
+    Down,
+    /// Above the select field
+    Up,
+}
+
+/// Decide where a dropdown of `content_height` opens next to `field`.
+///
+/// All values are logical pixels with the origin at the top-left of `viewport`. The dropdown
+/// opens downward when it fits, or when there is at least as much room below as above;
+/// otherwise it opens upward. Returns the direction and the maximum dropdown height, which is
+/// clamped to the available space (but never below one option row) so long lists scroll.
+pub fn resolve_select_dropdown_placement(
+    field: &ElementBounds,
+    content_height: f32,
+    viewport: Vec2,
+) -> (SelectDropdownDirection, f32) {
+    let below =
+        viewport.y - (field.y + field.height) - SELECT_DROPDOWN_GAP - SELECT_DROPDOWN_EDGE_MARGIN;
+    let above = field.y - SELECT_DROPDOWN_GAP - SELECT_DROPDOWN_EDGE_MARGIN;
+
+    let (direction, space) = if content_height <= below || below >= above {
+        (SelectDropdownDirection::Down, below)
+    } else {
+        (SelectDropdownDirection::Up, above)
+    };
+
+    let max_height = content_height
+        .min(space)
+        .max(SELECT_OPTION_HEIGHT.min(content_height));
+    (direction, max_height)
+}
 
 /// System to handle select interactions
 fn select_interaction_system(
@@ -560,6 +626,38 @@ fn select_interaction_system(
     }
 }
 
+/// Close the dropdown of the frontmost select when Escape is pressed.
+fn select_keyboard_dismiss_system(
+    stack: Option<Res<OverlayStack>>,
+    mut selects: Query<(Entity, &mut MaterialSelect)>,
+) {
+    let Some(stack) = stack else { return };
+
+    for (entity, mut select) in selects.iter_mut() {
+        if select.open && stack.escape_pressed_for(entity) {
+            select.open = false;
+        }
+    }
+}
+
+/// Close the dropdown when a press lands outside it and its select field.
+fn select_outside_click_system(
+    mut clicks: MessageReader<OutsideClickEvent>,
+    dropdowns: Query<(), With<SelectDropdown>>,
+    mut selects: Query<&mut MaterialSelect>,
+) {
+    for ev in clicks.read() {
+        if dropdowns.get(ev.entity).is_err() {
+            continue;
+        }
+        if let Ok(mut select) = selects.get_mut(ev.owner) {
+            if select.open {
+                select.open = false;
+            }
+        }
+    }
+}
+
 /// System to update select styles
 fn select_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -1113,6 +1211,102 @@ fn select_dropdown_sync_system(
     }
 }
 
+/// Open dropdowns upward when there is not enough room below the field, and clamp their
+/// height to the space available.
+fn select_dropdown_position_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    selects: Query<(
+        &MaterialSelect,
+        &UiGlobalTransform,
+        &ComputedNode,
+        &Children,
+    )>,
+    mut dropdowns: Query<(&mut Node, &mut ScrollPosition), With<SelectDropdown>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let viewport = Vec2::new(window.width(), window.height());
+
+    for (select, transform, computed, children) in selects.iter() {
+        if !select.open {
+            continue;
+        }
+
+        // UiGlobalTransform and ComputedNode are in physical pixels.
+        let scale = computed.inverse_scale_factor;
+        let size = computed.size() * scale;
+        if size.x <= 0.0 || size.y <= 0.0 {
+            continue;
+        }
+        let top_left = (transform.translation - computed.size() / 2.0) * scale;
+        let field = ElementBounds::new("select_field", top_left.x, top_left.y, size.x, size.y);
+
+        let content_height =
+            select.options.len() as f32 * SELECT_OPTION_HEIGHT + 2.0 * SELECT_DROPDOWN_PADDING;
+        let (direction, max_height) =
+            resolve_select_dropdown_placement(&field, content_height, viewport);
+
+        let offset = Val::Px(size.y + SELECT_DROPDOWN_GAP);
+        let (top, bottom) = match direction {
+            SelectDropdownDirection::Down => (offset, Val::Auto),
+            SelectDropdownDirection::Up => (Val::Auto, offset),
+        };
+
+        for child in children.iter() {
+            let Ok((mut node, mut scroll)) = dropdowns.get_mut(child) else {
+                continue;
+            };
+            if node.top != top || node.bottom != bottom || node.max_height != Val::Px(max_height) {
+                node.top = top;
+                node.bottom = bottom;
+                node.max_height = Val::Px(max_height);
+            }
+
+            // Keep the scroll offset valid when the dropdown grows.
+            let max_scroll = (content_height - max_height).max(0.0);
+            if scroll.y > max_scroll {
+                scroll.y = max_scroll;
+            }
+        }
+    }
+}
+
+/// Scroll a height-clamped dropdown with the mouse wheel while the cursor is over it.
+fn select_dropdown_scroll_system(
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    mut dropdowns: Query<
+        (
+            &RelativeCursorPosition,
+            &InheritedVisibility,
+            &ComputedNode,
+            &Children,
+            &mut ScrollPosition,
+        ),
+        With<SelectDropdown>,
+    >,
+) {
+    for ev in mouse_wheel.read() {
+        // Wheel down should move further down the list.
+        let delta = match ev.unit {
+            MouseScrollUnit::Line => -ev.y * SELECT_OPTION_HEIGHT,
+            MouseScrollUnit::Pixel => -ev.y,
+        };
+
+        for (cursor, visibility, computed, children, mut scroll) in dropdowns.iter_mut() {
+            if !visibility.get() || !cursor.cursor_over() {
+                continue;
+            }
+
+            let content_height =
+                children.len() as f32 * SELECT_OPTION_HEIGHT + 2.0 * SELECT_DROPDOWN_PADDING;
+            let visible_height = computed.size().y * computed.inverse_scale_factor;
+            let max_scroll = (content_height - visible_height).max(0.0);
+            scroll.y = (scroll.y + delta).clamp(0.0, max_scroll);
+        }
+    }
+}
+
 /// Handle clicks on option items.
 fn select_option_interaction_system(
     mut interactions: Query<(&Interaction, &SelectOptionItem, &SelectOwner), Changed<Interaction>>,
@@ -1249,21 +1443,23 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
             select
                 .spawn((
                     SelectDropdown,
+                    DismissOnOutsideClick::new(select_entity),
                     Visibility::Hidden,
-                    // Ensure the dropdown renders above later siblings (e.g. code blocks).
-                    // NOTE: Dialog scrims in this project use `GlobalZIndex(1000)`.
-                    // If the dropdown is promoted to a root node by `GlobalZIndex`, it must
-                    // be above modal overlays, otherwise it will render "behind" dialogs.
-                    GlobalZIndex(1100),
+                    // Above later siblings (e.g. code blocks) and any dialog it sits in.
+                    GlobalZIndex(Z_MENU),
                     Node {
                         position_type: PositionType::Absolute,
-                        top: Val::Px(SELECT_HEIGHT + 4.0),
+                        // Flipped upward by `select_dropdown_position_system` when needed.
+                        top: Val::Px(SELECT_HEIGHT + SELECT_DROPDOWN_GAP),
                         left: Val::Px(0.0),
                         width: Val::Percent(100.0),
                         flex_direction: FlexDirection::Column,
-                        padding: UiRect::vertical(Val::Px(8.0)),
+                        padding: UiRect::vertical(Val::Px(SELECT_DROPDOWN_PADDING)),
+                        overflow: Overflow::scroll_y(),
                         ..default()
                     },
+                    ScrollPosition::default(),
+                    RelativeCursorPosition::default(),
                     BackgroundColor(theme.surface_container),
                     BorderRadius::all(Val::Px(8.0)),
                 ))
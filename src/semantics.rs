@@ -0,0 +1,193 @@
+//! Accessibility semantics for interactive components
+//!
+//! Interactive components carry an [`A11ySemantics`] describing what they are
+//! ([`Role`]), their accessible name, and their current value (e.g. a
+//! checkbox's checked state or a slider's value). There is no screen-reader
+//! bridge yet; the metadata gives automation and telemetry one place to read
+//! what a control is and what state it is in.
+//!
+//! Builders attach the semantics when they spawn a component. Components
+//! spawned by hand get them on the next frame, and the value follows the
+//! component's state from then on.
+
+use bevy::prelude::*;
+
+/// Semantic role of an interactive element, following ARIA role names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Push button (buttons, icon buttons, FABs)
+    Button,
+    /// Checkbox (value: `checked`, `unchecked` or `mixed`)
+    Checkbox,
+    /// On/off switch (value: `on` or `off`)
+    Switch,
+    /// Radio button (value: `checked` or `unchecked`)
+    RadioButton,
+    /// Slider (value: the formatted slider value)
+    Slider,
+    /// Editable text field (value: the current text)
+    TextField,
+    /// Tab in a tab bar (value: `selected` or `unselected`)
+    Tab,
+}
+
+impl Role {
+    /// Stable string name used in structured output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Button => "button",
+            Self::Checkbox => "checkbox",
+            Self::Switch => "switch",
+            Self::RadioButton => "radio",
+            Self::Slider => "slider",
+            Self::TextField => "textbox",
+            Self::Tab => "tab",
+        }
+    }
+}
+
+/// Role, accessible name, and current value of an interactive element
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct A11ySemantics {
+    /// What kind of control this is
+    pub role: Role,
+    /// Accessible name (button text, field label, control label, ...)
+    pub label: String,
+    /// Current value, for controls that have one
+    pub value: Option<String>,
+}
+
+impl A11ySemantics {
+    /// Create semantics with a role and label and no value
+    pub fn new(role: Role, label: impl Into<String>) -> Self {
+        Self {
+            role,
+            label: label.into(),
+            value: None,
+        }
+    }
+
+    /// Set the accessible name
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the current value
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Structured JSON representation, for automation tooling
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "role": self.role.as_str(),
+            "label": self.label,
+            "value": self.value,
+        })
+    }
+}
+
+/// A component that describes itself with [`A11ySemantics`]
+pub trait Semantic: Component {
+    /// Role of the component
+    fn semantic_role(&self) -> Role;
+
+    /// Accessible name derived from the component (may be empty)
+    fn semantic_label(&self) -> String;
+
+    /// Current value derived from the component's state
+    fn semantic_value(&self) -> Option<String>;
+
+    /// Full semantics for the component's current state
+    fn semantics(&self) -> A11ySemantics {
+        A11ySemantics {
+            role: self.semantic_role(),
+            label: self.semantic_label(),
+            value: self.semantic_value(),
+        }
+    }
+}
+
+/// `"checked"` or `"unchecked"`
+pub(crate) fn checked_value(checked: bool) -> String {
+    if checked { "checked" } else { "unchecked" }.to_string()
+}
+
+/// Attach semantics to components that lack them and keep `value` in sync.
+///
+/// The label is only set when the semantics are first attached, so a label
+/// given by a builder or by the app is never overwritten.
+pub fn sync_semantics_system<T: Semantic>(
+    mut commands: Commands,
+    mut components: Query<(Entity, &T, Option<&mut A11ySemantics>), Changed<T>>,
+) {
+    for (entity, component, semantics) in components.iter_mut() {
+        match semantics {
+            Some(mut semantics) => {
+                let value = component.semantic_value();
+                if semantics.value != value {
+                    semantics.value = value;
+                }
+            }
+            None => {
+                commands.entity(entity).try_insert(component.semantics());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Toggle(bool);
+
+    impl Semantic for Toggle {
+        fn semantic_role(&self) -> Role {
+            Role::Switch
+        }
+
+        fn semantic_label(&self) -> String {
+            "Wi-Fi".to_string()
+        }
+
+        fn semantic_value(&self) -> Option<String> {
+            Some(if self.0 { "on" } else { "off" }.to_string())
+        }
+    }
+
+    #[test]
+    fn test_semantics_attached_and_value_synced() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_semantics_system::<Toggle>);
+
+        let plain = app.world_mut().spawn(Toggle(false)).id();
+        let labeled = app
+            .world_mut()
+            .spawn((
+                Toggle(false),
+                A11ySemantics::new(Role::Switch, "Airplane mode"),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<A11ySemantics>(plain),
+            Some(&A11ySemantics::new(Role::Switch, "Wi-Fi").with_value("off"))
+        );
+
+        app.world_mut().get_mut::<Toggle>(labeled).unwrap().0 = true;
+        app.update();
+
+        let semantics = app.world().get::<A11ySemantics>(labeled).unwrap();
+        assert_eq!(semantics.label, "Airplane mode");
+        assert_eq!(semantics.value.as_deref(), Some("on"));
+        assert_eq!(
+            semantics.to_json(),
+            serde_json::json!({"role": "switch", "label": "Airplane mode", "value": "on"})
+        );
+    }
+}
@@ -8,7 +8,10 @@ use bevy::ui::UiGlobalTransform;
 
 use std::collections::HashMap;
 
+use crate::semantics::{sync_semantics_system, Role, Semantic};
 use crate::theme::MaterialTheme;
+use crate::tokens::Opacity;
+use crate::typography::TypeRole;
 
 /// Slider orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -38,6 +41,9 @@ pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
+            app.add_plugins(crate::MaterialUiCorePlugin);
+        }
         app.init_resource::<SliderTraceSettings>()
             .init_resource::<SliderTraceState>();
         app.add_message::<SliderChangeEvent>().add_systems(
@@ -46,9 +52,11 @@ impl Plugin for SliderPlugin {
                 slider_interaction_system,
                 slider_visual_update_system.after(slider_interaction_system),
                 slider_theme_refresh_system.after(slider_visual_update_system),
+                slider_label_update_system.after(slider_interaction_system),
                 // Position handle/active track using actual track geometry so callers don't
                 // need to perfectly superimpose the slider root and its rail/track.
                 slider_geometry_update_system.after(slider_theme_refresh_system),
+                sync_semantics_system::<MaterialSlider>,
             ),
         );
     }
@@ -114,6 +122,9 @@ struct SliderParts {
     active_track: Entity,
     handle: Entity,
     ticks: Vec<Entity>,
+    tick_labels: Vec<Entity>,
+    value_label: Option<Entity>,
+    value_label_text: Option<Entity>,
 }
 
 #[derive(Component, Clone, Copy)]
@@ -172,6 +183,8 @@ pub struct MaterialSlider {
     pub thumb_ripple_radius: f32,
     /// Custom value label formatter
     pub value_formatter: Option<fn(f32) -> String>,
+    /// Labels spaced evenly beneath the track, from `min` to `max`
+    pub tick_labels: Vec<String>,
     /// Slider orientation
     pub orientation: SliderOrientation,
     /// Direction values increase along the track
@@ -213,6 +226,7 @@ impl MaterialSlider {
             thumb_elevation: 1.0,
             thumb_ripple_radius: SLIDER_HANDLE_SIZE * 1.5,
             value_formatter: None,
+            tick_labels: Vec::new(),
             orientation: SliderOrientation::Horizontal,
             direction: SliderDirection::StartToEnd,
             dragging: false,
@@ -314,12 +328,39 @@ impl MaterialSlider {
         self
     }
 
+    /// Show text beneath the track, spaced evenly from `min` to `max`.
+    ///
+    /// For discrete sliders, pass one label per tick mark.
+    pub fn tick_labels(mut self, labels: Vec<String>) -> Self {
+        self.tick_labels = labels;
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
+    /// Normalized position [0..1] along the track of tick label `index`
+    pub fn tick_label_position(&self, index: usize) -> f32 {
+        let count = self.tick_labels.len();
+        let normalized = if count > 1 {
+            index as f32 / (count - 1) as f32
+        } else {
+            0.0
+        };
+        match self.direction {
+            SliderDirection::StartToEnd => normalized,
+            SliderDirection::EndToStart => 1.0 - normalized,
+        }
+    }
+
+    /// Whether the value label is currently shown above the thumb
+    pub fn shows_value_label(&self) -> bool {
+        self.show_label && self.dragging
+    }
+
     /// Get the formatted value string for the label
     pub fn formatted_value(&self) -> String {
         if let Some(formatter) = self.value_formatter {
@@ -360,15 +401,26 @@ impl MaterialSlider {
         }
     }
 
+    /// Get the current value
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value, snapped to `step` and clamped to `min..=max`.
+    ///
+    /// Called through `Mut<MaterialSlider>`, this marks the slider changed so
+    /// the thumb, track, and label are redrawn.
+    pub fn set_value(&mut self, value: f32) {
+        let value = match self.step {
+            Some(step) if step > 0.0 => (value / step).round() * step,
+            _ => value,
+        };
+        self.value = value.clamp(self.min, self.max);
+    }
+
     /// Set value from normalized (0.0 to 1.0)
     pub fn set_from_normalized(&mut self, normalized: f32) {
-        let raw_value = self.min + normalized * (self.max - self.min);
-        self.value = if let Some(step) = self.step {
-            (raw_value / step).round() * step
-        } else {
-            raw_value
-        };
-        self.value = self.value.clamp(self.min, self.max);
+        self.set_value(self.min + normalized * (self.max - self.min));
     }
 
     /// Get the active track color
@@ -425,6 +477,15 @@ impl MaterialSlider {
     pub fn label_text_color(&self, theme: &MaterialTheme) -> Color {
         theme.on_primary
     }
+
+    /// Get the tick label text color
+    pub fn tick_label_color(&self, theme: &MaterialTheme) -> Color {
+        if self.disabled {
+            theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT)
+        } else {
+            theme.on_surface_variant
+        }
+    }
 }
 
 impl Default for MaterialSlider {
@@ -433,6 +494,21 @@ impl Default for MaterialSlider {
     }
 }
 
+impl Semantic for MaterialSlider {
+    fn semantic_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn semantic_label(&self) -> String {
+        String::new()
+    }
+
+    /// The value as shown in the value label
+    fn semantic_value(&self) -> Option<String> {
+        Some(self.formatted_value())
+    }
+}
+
 /// Event when slider value changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct SliderChangeEvent {
@@ -447,6 +523,10 @@ pub const SLIDER_HANDLE_SIZE: f32 = 20.0;
 pub const SLIDER_HANDLE_SIZE_PRESSED: f32 = 24.0;
 pub const SLIDER_TICK_SIZE: f32 = 4.0;
 pub const SLIDER_LABEL_HEIGHT: f32 = 28.0;
+pub const SLIDER_TICK_LABEL_HEIGHT: f32 = 16.0;
+
+/// Space between the thumb and the value label or tick labels
+const SLIDER_LABEL_GAP: f32 = 4.0;
 
 /// System to handle slider interactions
 fn slider_interaction_system(
@@ -680,6 +760,56 @@ fn slider_theme_refresh_system(
     }
 }
 
+/// Keep the value label and tick labels in sync with the slider and theme.
+fn slider_label_update_system(
+    theme: Option<Res<MaterialTheme>>,
+    sliders: Query<(Ref<MaterialSlider>, &SliderParts)>,
+    mut texts: Query<&mut Text>,
+    mut text_colors: Query<&mut TextColor>,
+    mut bg_colors: Query<&mut BackgroundColor>,
+    mut visibilities: Query<&mut Visibility>,
+) {
+    let Some(theme) = theme else { return };
+    let theme_changed = theme.is_changed();
+
+    for (slider, parts) in sliders.iter() {
+        if !theme_changed && !slider.is_changed() {
+            continue;
+        }
+
+        if let Some(label) = parts.value_label {
+            if let Ok(mut visibility) = visibilities.get_mut(label) {
+                visibility.set_if_neq(if slider.shows_value_label() {
+                    Visibility::Visible
+                } else {
+                    Visibility::Hidden
+                });
+            }
+            if let Ok(mut bg) = bg_colors.get_mut(label) {
+                *bg = BackgroundColor(slider.label_background_color(&theme));
+            }
+        }
+        if let Some(label_text) = parts.value_label_text {
+            let value = slider.formatted_value();
+            if let Ok(mut text) = texts.get_mut(label_text) {
+                if text.0 != value {
+                    text.0 = value;
+                }
+            }
+            if let Ok(mut color) = text_colors.get_mut(label_text) {
+                *color = TextColor(slider.label_text_color(&theme));
+            }
+        }
+
+        let tick_label_color = slider.tick_label_color(&theme);
+        for &tick_label in &parts.tick_labels {
+            if let Ok(mut color) = text_colors.get_mut(tick_label) {
+                *color = TextColor(tick_label_color);
+            }
+        }
+    }
+}
+
 fn update_slider_visuals(
     theme: &MaterialTheme,
     slider: &MaterialSlider,
@@ -813,6 +943,7 @@ fn slider_geometry_update_system(
     >,
     computed: Query<(&ComputedNode, &UiGlobalTransform)>,
     ticks: Query<&SliderTick>,
+    tick_labels: Query<&SliderTickLabel>,
     mut nodes: Query<&mut Node>,
     time: Res<Time>,
     trace: Res<SliderTraceSettings>,
@@ -883,6 +1014,15 @@ fn slider_geometry_update_system(
 
         let position_percent = slider.position_percent().clamp(0.0, 1.0);
 
+        // Labels are sized by their text, so center them using last frame's layout.
+        let size_of = |entity: Entity| {
+            computed
+                .get(entity)
+                .map(|(node, _)| node.size())
+                .unwrap_or(Vec2::ZERO)
+        };
+        let label_gap_physical = SLIDER_LABEL_GAP * physical_per_logical;
+
         match slider.orientation {
             SliderOrientation::Horizontal => {
                 // Usable range is track, inset by thumb radius, and clamped to slider bounds.
@@ -961,6 +1101,44 @@ fn slider_geometry_update_system(
                         tick_node.margin = UiRect::all(Val::Px(0.0));
                     }
                 }
+
+                // Tick labels: centered beneath their position, clear of the thumb
+                let tick_label_top_physical =
+                    track_center.y - slider_top + handle_radius_physical + label_gap_physical;
+                for &label_entity in &parts.tick_labels {
+                    let Ok(label) = tick_labels.get(label_entity) else {
+                        continue;
+                    };
+                    let x = track_left + track_size.x * label.position.clamp(0.0, 1.0);
+                    let width = size_of(label_entity).x;
+                    if let Ok(mut label_node) = nodes.get_mut(label_entity) {
+                        label_node.position_type = PositionType::Absolute;
+                        label_node.left =
+                            Val::Px((x - slider_left - width / 2.0) * logical_per_physical);
+                        label_node.top = Val::Px(tick_label_top_physical * logical_per_physical);
+                        label_node.margin = UiRect::all(Val::Px(0.0));
+                    }
+                }
+
+                // Value label: centered above the thumb
+                if let Some(label_entity) = parts.value_label {
+                    let size = size_of(label_entity);
+                    if let Ok(mut label_node) = nodes.get_mut(label_entity) {
+                        label_node.position_type = PositionType::Absolute;
+                        label_node.left = Val::Px(
+                            (thumb_center_x - slider_left - size.x / 2.0) * logical_per_physical,
+                        );
+                        label_node.top = Val::Px(
+                            (thumb_center_y
+                                - slider_top
+                                - handle_radius_physical
+                                - label_gap_physical
+                                - size.y)
+                                * logical_per_physical,
+                        );
+                        label_node.margin = UiRect::all(Val::Px(0.0));
+                    }
+                }
             }
             SliderOrientation::Vertical => {
                 let usable_top =
@@ -1043,6 +1221,44 @@ fn slider_geometry_update_system(
                         tick_node.margin = UiRect::all(Val::Px(0.0));
                     }
                 }
+
+                // Tick labels: beside the track, clear of the thumb
+                let tick_label_left_physical =
+                    track_center.x - slider_left + handle_radius_physical + label_gap_physical;
+                for &label_entity in &parts.tick_labels {
+                    let Ok(label) = tick_labels.get(label_entity) else {
+                        continue;
+                    };
+                    let y = track_top + track_size.y * label.position.clamp(0.0, 1.0);
+                    let height = size_of(label_entity).y;
+                    if let Ok(mut label_node) = nodes.get_mut(label_entity) {
+                        label_node.position_type = PositionType::Absolute;
+                        label_node.left = Val::Px(tick_label_left_physical * logical_per_physical);
+                        label_node.top =
+                            Val::Px((y - slider_top - height / 2.0) * logical_per_physical);
+                        label_node.margin = UiRect::all(Val::Px(0.0));
+                    }
+                }
+
+                // Value label: beside the thumb, opposite the tick labels
+                if let Some(label_entity) = parts.value_label {
+                    let size = size_of(label_entity);
+                    if let Ok(mut label_node) = nodes.get_mut(label_entity) {
+                        label_node.position_type = PositionType::Absolute;
+                        label_node.left = Val::Px(
+                            (thumb_center_x
+                                - slider_left
+                                - handle_radius_physical
+                                - label_gap_physical
+                                - size.x)
+                                * logical_per_physical,
+                        );
+                        label_node.top = Val::Px(
+                            (thumb_center_y - slider_top - size.y / 2.0) * logical_per_physical,
+                        );
+                        label_node.margin = UiRect::all(Val::Px(0.0));
+                    }
+                }
             }
         }
 
@@ -1109,6 +1325,23 @@ pub fn spawn_slider_control_with<E: Bundle>(
     let active_tick_color = slider.active_tick_color(theme);
     let inactive_tick_color = slider.inactive_tick_color(theme);
 
+    let tick_labels: Vec<(f32, String)> = slider
+        .tick_labels
+        .iter()
+        .enumerate()
+        .map(|(index, text)| (slider.tick_label_position(index), text.clone()))
+        .collect();
+    let tick_label_color = slider.tick_label_color(theme);
+    let show_label = slider.show_label;
+    let formatted_value = slider.formatted_value();
+    let label_bg_color = slider.label_background_color(theme);
+    let label_text_color = slider.label_text_color(theme);
+    let label_visibility = if slider.shows_value_label() {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+
     let mut slider_ec = parent.spawn((
         slider,
         Button,
@@ -1134,6 +1367,8 @@ pub fn spawn_slider_control_with<E: Bundle>(
     let mut parts_active_track: Option<Entity> = None;
     let mut parts_handle: Option<Entity> = None;
     let mut parts_ticks: Vec<Entity> = Vec::new();
+    let mut parts_tick_labels: Vec<Entity> = Vec::new();
+    let mut parts_value_label: Option<(Entity, Entity)> = None;
 
     slider_ec.with_children(|slider_area| {
         // Track
@@ -1307,6 +1542,71 @@ pub fn spawn_slider_control_with<E: Bundle>(
                 }
             }
         }
+
+        // Tick labels (placed precisely in `slider_geometry_update_system`)
+        for (position, text) in tick_labels {
+            let label_node = match orientation {
+                SliderOrientation::Horizontal => Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(position * 100.0),
+                    top: Val::Px(SLIDER_HANDLE_SIZE + 8.0),
+                    ..default()
+                },
+                SliderOrientation::Vertical => Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(position * 100.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(SLIDER_HANDLE_SIZE)),
+                    ..default()
+                },
+            };
+            let label_entity = slider_area
+                .spawn((
+                    SliderTickLabel { position },
+                    label_node,
+                    Text::new(text),
+                    TypeRole::LabelSmall.text_bundle(),
+                    TextColor(tick_label_color),
+                ))
+                .id();
+            parts_tick_labels.push(label_entity);
+        }
+
+        // Value label, shown next to the thumb while dragging
+        if show_label {
+            let mut label_text = Entity::PLACEHOLDER;
+            let label_entity = slider_area
+                .spawn((
+                    SliderLabel {
+                        track: track_entity,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        min_width: Val::Px(SLIDER_LABEL_HEIGHT),
+                        height: Val::Px(SLIDER_LABEL_HEIGHT),
+                        padding: UiRect::horizontal(Val::Px(8.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(label_bg_color),
+                    BorderRadius::all(Val::Px(SLIDER_LABEL_HEIGHT / 2.0)),
+                    ZIndex(1),
+                    label_visibility,
+                ))
+                .with_children(|bubble| {
+                    label_text = bubble
+                        .spawn((
+                            SliderLabelText,
+                            Text::new(formatted_value),
+                            TypeRole::LabelMedium.text_bundle(),
+                            TextColor(label_text_color),
+                        ))
+                        .id();
+                })
+                .id();
+            parts_value_label = Some((label_entity, label_text));
+        }
     });
 
     if let (Some(track), Some(active_track), Some(handle)) =
@@ -1317,6 +1617,9 @@ pub fn spawn_slider_control_with<E: Bundle>(
             active_track,
             handle,
             ticks: parts_ticks,
+            tick_labels: parts_tick_labels,
+            value_label: parts_value_label.map(|(label, _)| label),
+            value_label_text: parts_value_label.map(|(_, text)| text),
         });
     }
 
@@ -1363,6 +1666,18 @@ impl SliderBuilder {
         self
     }
 
+    /// Format the value label, e.g. `|v| format!("{v:.0}%")`
+    pub fn value_formatter(mut self, formatter: fn(f32) -> String) -> Self {
+        self.slider.value_formatter = Some(formatter);
+        self
+    }
+
+    /// Show text beneath the track, spaced evenly from min to max
+    pub fn tick_labels(mut self, labels: Vec<String>) -> Self {
+        self.slider.tick_labels = labels;
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.slider.disabled = disabled;
@@ -1378,9 +1693,11 @@ impl SliderBuilder {
     /// Build the slider bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let _bg_color = self.slider.inactive_track_color(theme);
+        let semantics = self.slider.semantics();
 
         (
             self.slider,
+            semantics,
             Button,
             Node {
                 width: self.width,
@@ -1427,6 +1744,17 @@ pub struct SliderLabel {
     pub track: Entity,
 }
 
+/// Marker component for the text inside the slider value label
+#[derive(Component)]
+pub struct SliderLabelText;
+
+/// Text beneath a tick mark, from [`MaterialSlider::tick_labels`]
+#[derive(Component)]
+pub struct SliderTickLabel {
+    /// Normalized position [0..1] along the track
+    pub position: f32,
+}
+
 /// Extension trait to spawn sliders with full visual hierarchy
 pub trait SpawnSliderChild {
     /// Spawn a continuous slider with a label
@@ -1496,6 +1824,13 @@ impl SpawnSliderChild for ChildSpawnerCommands<'_> {
         label: Option<&str>,
     ) {
         let label_color = theme.on_surface;
+        let tick_label_room = if !slider.tick_labels.is_empty()
+            && slider.orientation == SliderOrientation::Horizontal
+        {
+            SLIDER_TICK_LABEL_HEIGHT
+        } else {
+            0.0
+        };
         // Container row with optional label
         self.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1525,6 +1860,8 @@ impl SpawnSliderChild for ChildSpawnerCommands<'_> {
                 width: Val::Percent(100.0),
                 min_width: Val::Px(0.0),
                 height: Val::Px(SLIDER_HANDLE_SIZE + 8.0),
+                // Tick labels hang below the slider; keep room for them.
+                margin: UiRect::bottom(Val::Px(tick_label_room)),
                 ..default()
             })
             .with_children(|slot| {
@@ -1709,6 +2046,22 @@ mod tests {
         assert_eq!(slider.formatted_value(), "75%");
     }
 
+    #[test]
+    fn test_slider_tick_label_positions() {
+        let labels = vec!["Low".to_string(), "Mid".to_string(), "High".to_string()];
+        let slider = MaterialSlider::new(0.0, 10.0).tick_labels(labels.clone());
+        assert_eq!(slider.tick_label_position(0), 0.0);
+        assert_eq!(slider.tick_label_position(1), 0.5);
+        assert_eq!(slider.tick_label_position(2), 1.0);
+
+        // Reversed sliders put the first label (min) at the far end.
+        let slider = MaterialSlider::new(0.0, 10.0)
+            .reversed()
+            .tick_labels(labels);
+        assert_eq!(slider.tick_label_position(0), 1.0);
+        assert_eq!(slider.tick_label_position(2), 0.0);
+    }
+
     #[test]
     fn test_slider_normalized_value() {
         let slider = MaterialSlider::new(0.0, 100.0).with_value(50.0);
@@ -1741,6 +2094,20 @@ mod tests {
         assert_eq!(slider.value, 60.0);
     }
 
+    #[test]
+    fn test_slider_set_value_snaps_and_clamps() {
+        let mut slider = MaterialSlider::new(0.0, 100.0).with_step(10.0);
+        slider.set_value(42.0);
+        assert_eq!(slider.value(), 40.0);
+
+        slider.set_value(250.0);
+        assert_eq!(slider.value(), 100.0);
+
+        let mut continuous = MaterialSlider::new(0.0, 1.0);
+        continuous.set_value(0.25);
+        assert_eq!(continuous.value(), 0.25);
+    }
+
     #[test]
     fn test_slider_should_show_ticks_always() {
         let slider = MaterialSlider::new(0.0, 100.0)
@@ -1844,4 +2211,19 @@ mod tests {
         assert!(builder.slider.show_label);
         assert!(!builder.slider.disabled);
     }
+
+    #[test]
+    fn test_slider_builder_labels_and_formatter() {
+        let builder = SliderBuilder::new(0.0, 10.0)
+            .step(5.0)
+            .value(5.0)
+            .label()
+            .value_formatter(|v| format!("${v:.0}"))
+            .tick_labels(vec!["$0".into(), "$5".into(), "$10".into()]);
+
+        assert_eq!(builder.slider.formatted_value(), "$5");
+        assert_eq!(builder.slider.tick_labels.len(), 3);
+        // The value label only shows while dragging.
+        assert!(!builder.slider.shows_value_label());
+    }
 }
@@ -8,11 +8,13 @@
 
 use bevy::picking::Pickable;
 use bevy::prelude::*;
+use bevy::ui::{UiTransform, Val2};
 
 use crate::{
     elevation::Elevation,
     icons::{icon_by_name, IconStyle, MaterialIcon, ICON_CLOSE},
-    motion::{ease_standard_accelerate, ease_standard_decelerate},
+    motion::{ease_standard_accelerate, ease_standard_decelerate, ReducedMotion},
+    overlay::Z_SNACKBAR,
     theme::MaterialTheme,
     tokens::{CornerRadius, Duration, Spacing},
 };
@@ -32,13 +34,22 @@ impl Plugin for SnackbarPlugin {
             .add_systems(
                 Update,
                 (
+                    // Despawning the outgoing snackbar in the same frame the next one is
+                    // spawned keeps the host layout from jumping between them.
+                    snackbar_cleanup_system,
                     snackbar_queue_system,
+                    snackbar_host_layout_system,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
                     snackbar_animation_system,
                     snackbar_timeout_system,
                     snackbar_action_system,
                     snackbar_close_system,
                     snackbar_close_button_style_system,
-                    snackbar_cleanup_system,
                 ),
             );
     }
@@ -66,6 +77,66 @@ pub enum SnackbarPosition {
     TopRight,
 }
 
+impl SnackbarPosition {
+    /// Bottom start corner (bottom left in left-to-right layouts)
+    pub const BOTTOM_START: Self = Self::BottomLeft;
+    /// Bottom end corner (bottom right in left-to-right layouts)
+    pub const BOTTOM_END: Self = Self::BottomRight;
+
+    /// Whether the snackbar is anchored to the bottom edge
+    pub fn is_bottom(&self) -> bool {
+        matches!(
+            self,
+            Self::BottomCenter | Self::BottomLeft | Self::BottomRight
+        )
+    }
+
+    /// Host layout for this position.
+    ///
+    /// `inset` is added to the margin on the anchored edge, so a snackbar can sit above a
+    /// docked FAB or bottom app bar (or below a top app bar).
+    fn host_layout(&self, inset: f32) -> (JustifyContent, AlignItems, UiRect) {
+        // For Column flex direction:
+        // - justify_content controls vertical (main axis) - FlexEnd = bottom, FlexStart = top
+        // - align_items controls horizontal (cross axis) - Center = centered, FlexStart = left, FlexEnd = right
+        let justify = if self.is_bottom() {
+            JustifyContent::FlexEnd
+        } else {
+            JustifyContent::FlexStart
+        };
+        let align = match self {
+            Self::BottomCenter | Self::TopCenter => AlignItems::Center,
+            Self::BottomLeft | Self::TopLeft => AlignItems::FlexStart,
+            Self::BottomRight | Self::TopRight => AlignItems::FlexEnd,
+        };
+
+        let edge = Val::Px(SNACKBAR_MARGIN_BOTTOM + inset);
+        let side = Val::Px(SNACKBAR_MARGIN_BOTTOM);
+        let padding = if self.is_bottom() {
+            UiRect::new(side, side, Val::Auto, edge)
+        } else {
+            UiRect::new(side, side, edge, Val::Auto)
+        };
+
+        (justify, align, padding)
+    }
+}
+
+/// Queue priority of a snackbar.
+///
+/// Higher priorities are shown first, and a queued snackbar of higher priority
+/// than the one on screen dismisses it early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum SnackbarPriority {
+    /// Background information that can wait
+    Low,
+    /// Regular feedback
+    #[default]
+    Normal,
+    /// Errors and other messages that preempt regular feedback
+    High,
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -83,6 +154,14 @@ pub struct ShowSnackbar {
     pub dismissible: bool,
     /// Position on screen
     pub position: SnackbarPosition,
+    /// Optional leading icon name
+    pub icon: Option<String>,
+    /// Whether the message may wrap onto a second line
+    pub multiline: bool,
+    /// Queue priority
+    pub priority: SnackbarPriority,
+    /// Key identifying repeats of the same message; see [`SnackbarQueue`]
+    pub dedup_key: Option<String>,
 }
 
 impl ShowSnackbar {
@@ -94,6 +173,10 @@ impl ShowSnackbar {
             duration: None,
             dismissible: true,
             position: SnackbarPosition::default(),
+            icon: None,
+            multiline: false,
+            priority: SnackbarPriority::default(),
+            dedup_key: None,
         }
     }
 
@@ -105,6 +188,10 @@ impl ShowSnackbar {
             duration: None,
             dismissible: true,
             position: SnackbarPosition::default(),
+            icon: None,
+            multiline: false,
+            priority: SnackbarPriority::default(),
+            dedup_key: None,
         }
     }
 
@@ -120,6 +207,30 @@ impl ShowSnackbar {
         self
     }
 
+    /// Show a leading icon
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Allow the message to wrap onto a second line
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Set the queue priority
+    pub fn priority(mut self, priority: SnackbarPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Suppress this snackbar while another with the same key is shown or queued
+    pub fn dedup_key(mut self, key: impl Into<String>) -> Self {
+        self.dedup_key = Some(key.into());
+        self
+    }
+
     /// Set position
     pub fn position(mut self, position: SnackbarPosition) -> Self {
         self.position = position;
@@ -136,6 +247,16 @@ impl ShowSnackbar {
         self.position(SnackbarPosition::BottomRight)
     }
 
+    /// Position at the bottom start corner
+    pub fn bottom_start(self) -> Self {
+        self.position(SnackbarPosition::BOTTOM_START)
+    }
+
+    /// Position at the bottom end corner
+    pub fn bottom_end(self) -> Self {
+        self.position(SnackbarPosition::BOTTOM_END)
+    }
+
     /// Position at top center
     pub fn top_center(self) -> Self {
         self.position(SnackbarPosition::TopCenter)
@@ -169,7 +290,12 @@ pub struct SnackbarActionEvent {
 // Resources
 // ============================================================================
 
-/// Queue of pending snackbars
+/// Queue of pending snackbars.
+///
+/// Snackbars are ordered by [`SnackbarPriority`], first-in first-out within a
+/// priority. A [`ShowSnackbar`] whose `dedup_key` matches the snackbar on
+/// screen or one already queued is dropped, so frequent events don't stack up
+/// repeats of the same message.
 #[derive(Resource, Default)]
 pub struct SnackbarQueue {
     /// Queued snackbars waiting to be shown
@@ -178,6 +304,31 @@ pub struct SnackbarQueue {
     pub active: Option<Entity>,
 }
 
+impl SnackbarQueue {
+    /// Queue a snackbar behind every queued snackbar of equal or higher priority.
+    ///
+    /// Returns `false` without queuing it if a queued snackbar has the same
+    /// `dedup_key`.
+    pub fn push(&mut self, event: ShowSnackbar) -> bool {
+        if event.dedup_key.is_some()
+            && self
+                .queue
+                .iter()
+                .any(|queued| queued.dedup_key == event.dedup_key)
+        {
+            return false;
+        }
+
+        let index = self
+            .queue
+            .iter()
+            .position(|queued| queued.priority < event.priority)
+            .unwrap_or(self.queue.len());
+        self.queue.insert(index, event);
+        true
+    }
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -195,6 +346,14 @@ pub struct Snackbar {
     pub dismissible: bool,
     /// Position on screen
     pub position: SnackbarPosition,
+    /// Optional leading icon name
+    pub icon: Option<String>,
+    /// Whether the message may wrap onto a second line
+    pub multiline: bool,
+    /// Queue priority
+    pub priority: SnackbarPriority,
+    /// Key identifying repeats of the same message
+    pub dedup_key: Option<String>,
     /// Current animation state
     pub animation_state: SnackbarAnimationState,
     /// Time remaining before auto-dismiss
@@ -235,6 +394,10 @@ impl Snackbar {
             duration: event.duration.unwrap_or(Self::DEFAULT_DURATION),
             dismissible: event.dismissible,
             position: event.position,
+            icon: event.icon.clone(),
+            multiline: event.multiline,
+            priority: event.priority,
+            dedup_key: event.dedup_key.clone(),
             animation_state: SnackbarAnimationState::Entering,
             time_remaining: event.duration.unwrap_or(Self::DEFAULT_DURATION),
             animation_progress: 0.0,
@@ -253,6 +416,23 @@ impl Snackbar {
     pub fn is_dismissed(&self) -> bool {
         self.animation_state == SnackbarAnimationState::Dismissed
     }
+
+    /// Whether the snackbar is entering or visible, i.e. not on its way out
+    pub fn is_showing(&self) -> bool {
+        matches!(
+            self.animation_state,
+            SnackbarAnimationState::Entering | SnackbarAnimationState::Visible
+        )
+    }
+
+    /// Minimum container height for the line count
+    pub fn min_height(&self) -> f32 {
+        if self.multiline {
+            SNACKBAR_HEIGHT_DOUBLE
+        } else {
+            SNACKBAR_HEIGHT_SINGLE
+        }
+    }
 }
 
 /// Marker for snackbar action button
@@ -263,6 +443,10 @@ pub struct SnackbarAction;
 #[derive(Component)]
 pub struct SnackbarMessage;
 
+/// Marker for snackbar leading icon
+#[derive(Component)]
+pub struct SnackbarIcon;
+
 /// Marker for snackbar close button
 #[derive(Component)]
 pub struct SnackbarCloseButton;
@@ -275,6 +459,13 @@ pub struct SnackbarHost;
 #[derive(Component, Clone, Copy)]
 pub struct SnackbarHostPosition(pub SnackbarPosition);
 
+/// Extra space (in logical pixels) between the snackbar and the edge it is anchored to.
+///
+/// Set this to the height of a docked FAB or bottom app bar so snackbars rise above it.
+/// Changing it re-lays out the host.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SnackbarInset(pub f32);
+
 // ============================================================================
 // Dimensions
 // ============================================================================
@@ -289,6 +480,10 @@ pub const SNACKBAR_HEIGHT_SINGLE: f32 = 48.0;
 pub const SNACKBAR_HEIGHT_DOUBLE: f32 = 68.0;
 /// Bottom margin from screen edge
 pub const SNACKBAR_MARGIN_BOTTOM: f32 = 16.0;
+/// Line height of the snackbar message
+const SNACKBAR_LINE_HEIGHT: f32 = 20.0;
+/// Maximum number of message lines for multi-line snackbars
+const SNACKBAR_MAX_LINES: f32 = 2.0;
 
 // ============================================================================
 // Builder
@@ -306,78 +501,24 @@ impl SnackbarHostBuilder {
 
     /// Build the snackbar host with a specific default position
     pub fn build_with_position(position: SnackbarPosition) -> impl Bundle {
-        // For Column flex direction:
-        // - justify_content controls vertical (main axis) - FlexEnd = bottom, FlexStart = top
-        // - align_items controls horizontal (cross axis) - Center = centered, FlexStart = left, FlexEnd = right
-        let (justify, align, flex_direction, padding) = match position {
-            SnackbarPosition::BottomCenter => (
-                JustifyContent::FlexEnd, // Bottom
-                AlignItems::Center,      // Horizontally centered
-                FlexDirection::Column,
-                UiRect::bottom(Val::Px(SNACKBAR_MARGIN_BOTTOM)),
-            ),
-            SnackbarPosition::BottomLeft => (
-                JustifyContent::FlexEnd, // Bottom
-                AlignItems::FlexStart,   // Left
-                FlexDirection::Column,
-                UiRect::new(
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Auto,
-                    Val::Auto,
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                ),
-            ),
-            SnackbarPosition::BottomRight => (
-                JustifyContent::FlexEnd, // Bottom
-                AlignItems::FlexEnd,     // Right
-                FlexDirection::Column,
-                UiRect::new(
-                    Val::Auto,
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Auto,
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                ),
-            ),
-            SnackbarPosition::TopCenter => (
-                JustifyContent::FlexStart, // Top
-                AlignItems::Center,        // Horizontally centered
-                FlexDirection::Column,
-                UiRect::top(Val::Px(SNACKBAR_MARGIN_BOTTOM)),
-            ),
-            SnackbarPosition::TopLeft => (
-                JustifyContent::FlexStart, // Top
-                AlignItems::FlexStart,     // Left
-                FlexDirection::Column,
-                UiRect::new(
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Auto,
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Auto,
-                ),
-            ),
-            SnackbarPosition::TopRight => (
-                JustifyContent::FlexStart, // Top
-                AlignItems::FlexEnd,       // Right
-                FlexDirection::Column,
-                UiRect::new(
-                    Val::Auto,
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                    Val::Auto,
-                ),
-            ),
-        };
+        Self::build_with_inset(position, 0.0)
+    }
+
+    /// Build the snackbar host with a default position and an inset from the anchored edge
+    pub fn build_with_inset(position: SnackbarPosition, inset: f32) -> impl Bundle {
+        let (justify, align, padding) = position.host_layout(inset);
 
         (
             SnackbarHost,
             SnackbarHostPosition(position),
+            SnackbarInset(inset),
             Node {
                 position_type: PositionType::Absolute,
                 top: Val::Px(0.0),
                 bottom: Val::Px(0.0),
                 left: Val::Px(0.0),
                 right: Val::Px(0.0),
-                flex_direction,
+                flex_direction: FlexDirection::Column,
                 justify_content: justify,
                 align_items: align,
                 padding,
@@ -385,7 +526,7 @@ impl SnackbarHostBuilder {
             },
             // Make it not block mouse events on the overlay itself
             Pickable::IGNORE,
-            GlobalZIndex(999),
+            GlobalZIndex(Z_SNACKBAR),
         )
     }
 }
@@ -405,6 +546,10 @@ impl SnackbarBuilder {
                 duration: Snackbar::DEFAULT_DURATION,
                 dismissible: true,
                 position: SnackbarPosition::default(),
+                icon: None,
+                multiline: false,
+                priority: SnackbarPriority::default(),
+                dedup_key: None,
                 animation_state: SnackbarAnimationState::Entering,
                 time_remaining: Snackbar::DEFAULT_DURATION,
                 animation_progress: 0.0,
@@ -440,23 +585,38 @@ impl SnackbarBuilder {
         self.duration(Snackbar::INDEFINITE)
     }
 
+    /// Show a leading icon
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.snackbar.icon = Some(icon.into());
+        self
+    }
+
+    /// Allow the message to wrap onto a second line; the snackbar grows to fit
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.snackbar.multiline = multiline;
+        self
+    }
+
+    /// Set the queue priority
+    pub fn priority(mut self, priority: SnackbarPriority) -> Self {
+        self.snackbar.priority = priority;
+        self
+    }
+
+    /// Set the key identifying repeats of this message
+    pub fn dedup_key(mut self, key: impl Into<String>) -> Self {
+        self.snackbar.dedup_key = Some(key.into());
+        self
+    }
+
     /// Build the snackbar bundle with native BoxShadow
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = theme.inverse_surface;
+        let node = snackbar_node(&self.snackbar);
 
         (
             self.snackbar,
-            Node {
-                min_width: Val::Px(SNACKBAR_MIN_WIDTH),
-                max_width: Val::Px(SNACKBAR_MAX_WIDTH),
-                min_height: Val::Px(SNACKBAR_HEIGHT_SINGLE),
-                padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                column_gap: Val::Px(Spacing::SMALL),
-                ..default()
-            },
+            node,
             BackgroundColor(bg_color),
             BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
             // Native Bevy 0.17 shadow support (MD3 snackbars are Level 3 elevation)
@@ -511,90 +671,154 @@ impl SpawnSnackbarChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_snackbar_with(&mut self, theme: &MaterialTheme, builder: SnackbarBuilder) {
-        let message_text = builder.snackbar.message.clone();
-        let action_text = builder.snackbar.action.clone();
-        let message_color = theme.inverse_on_surface;
-        let action_color = theme.inverse_primary;
-        let close_color = theme.inverse_on_surface;
-
-        self.spawn(builder.build(theme)).with_children(|snackbar| {
-            // Message
-            snackbar.spawn((
-                SnackbarMessage,
-                Text::new(&message_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(message_color),
-                Node {
-                    flex_grow: 1.0,
-                    ..default()
-                },
+        let content = SnackbarContent::new(&builder.snackbar, theme);
+
+        self.spawn(builder.build(theme))
+            .with_children(|snackbar| content.spawn(snackbar));
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Container layout for a snackbar
+fn snackbar_node(snackbar: &Snackbar) -> Node {
+    Node {
+        min_width: Val::Px(SNACKBAR_MIN_WIDTH),
+        max_width: Val::Px(SNACKBAR_MAX_WIDTH),
+        min_height: Val::Px(snackbar.min_height()),
+        padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        // Keeps the icon, action and close button centered on two-line messages
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(Spacing::SMALL),
+        ..default()
+    }
+}
+
+/// Children of a snackbar: leading icon, message, action and close button
+struct SnackbarContent {
+    icon: Option<MaterialIcon>,
+    message: String,
+    action: Option<String>,
+    multiline: bool,
+    message_color: Color,
+    action_color: Color,
+}
+
+impl SnackbarContent {
+    fn new(snackbar: &Snackbar, theme: &MaterialTheme) -> Self {
+        Self {
+            icon: snackbar.icon.as_deref().and_then(MaterialIcon::from_name),
+            message: snackbar.message.clone(),
+            action: snackbar.action.clone(),
+            multiline: snackbar.multiline,
+            message_color: theme.inverse_on_surface,
+            action_color: theme.inverse_primary,
+        }
+    }
+
+    fn spawn(self, parent: &mut ChildSpawnerCommands) {
+        // Leading icon (if present)
+        if let Some(icon) = self.icon {
+            parent.spawn((
+                SnackbarIcon,
+                icon,
+                IconStyle::outlined()
+                    .with_color(self.message_color)
+                    .with_size(24.0),
             ));
+        }
 
-            // Action button (if present)
-            if let Some(ref action) = action_text {
-                snackbar
-                    .spawn((
-                        SnackbarAction,
-                        Button,
-                        Node {
-                            padding: UiRect::axes(
-                                Val::Px(Spacing::SMALL),
-                                Val::Px(Spacing::EXTRA_SMALL),
-                            ),
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                    ))
-                    .with_children(|btn| {
-                        btn.spawn((
-                            Text::new(action),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(action_color),
-                        ));
-                    });
+        // Message text; multi-line messages wrap and are clipped after two lines.
+        let message_node = if self.multiline {
+            Node {
+                flex_grow: 1.0,
+                flex_shrink: 1.0,
+                min_width: Val::Px(0.0),
+                max_height: Val::Px(SNACKBAR_LINE_HEIGHT * SNACKBAR_MAX_LINES),
+                overflow: Overflow::clip(),
+                ..default()
             }
+        } else {
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            }
+        };
+        parent.spawn((
+            SnackbarMessage,
+            Text::new(self.message),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(self.message_color),
+            message_node,
+        ));
 
-            // Close button (X icon)
-            snackbar
+        // Action button (if provided)
+        if let Some(action) = self.action {
+            parent
                 .spawn((
-                    SnackbarCloseButton,
+                    SnackbarAction,
                     Button,
-                    Interaction::None,
                     Node {
-                        width: Val::Px(32.0),
-                        height: Val::Px(32.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        margin: UiRect::left(Val::Px(Spacing::SMALL)),
+                        padding: UiRect::axes(
+                            Val::Px(Spacing::SMALL),
+                            Val::Px(Spacing::EXTRA_SMALL),
+                        ),
+                        flex_shrink: 0.0,
                         ..default()
                     },
                     BackgroundColor(Color::NONE),
-                    BorderRadius::all(Val::Px(CornerRadius::FULL)),
                 ))
                 .with_children(|btn| {
                     btn.spawn((
-                        MaterialIcon::new(
-                            icon_by_name(ICON_CLOSE).expect("embedded icon 'close' not found"),
-                        ),
-                        IconStyle::outlined()
-                            .with_color(close_color)
-                            .with_size(24.0),
+                        Text::new(action),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(self.action_color),
                     ));
                 });
-        });
+        }
+
+        // Close button (X icon) - always shown for easy dismissal
+        let close_color = self.message_color;
+        parent
+            .spawn((
+                SnackbarCloseButton,
+                Button,
+                Interaction::None,
+                Node {
+                    width: Val::Px(32.0),
+                    height: Val::Px(32.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::left(Val::Px(Spacing::SMALL)),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(CornerRadius::FULL)),
+            ))
+            .with_children(move |btn| {
+                btn.spawn((
+                    MaterialIcon::new(
+                        icon_by_name(ICON_CLOSE).expect("embedded icon 'close' not found"),
+                    ),
+                    IconStyle::outlined()
+                        .with_color(close_color)
+                        .with_size(24.0),
+                ));
+            });
     }
 }
 
-// ============================================================================
-// Helper Functions
-// ============================================================================
-
 /// Spawn a snackbar entity as a child of the host
 pub fn spawn_snackbar(
     commands: &mut Commands,
@@ -603,102 +827,20 @@ pub fn spawn_snackbar(
     host: Entity,
 ) -> Entity {
     let snackbar = Snackbar::from_event(event);
-    let message = snackbar.message.clone();
-    let action = snackbar.action.clone();
+    let content = SnackbarContent::new(&snackbar, theme);
+    let node = snackbar_node(&snackbar);
 
     let snackbar_entity = commands
         .spawn((
             snackbar,
-            Node {
-                min_width: Val::Px(SNACKBAR_MIN_WIDTH),
-                max_width: Val::Px(SNACKBAR_MAX_WIDTH),
-                min_height: Val::Px(SNACKBAR_HEIGHT_SINGLE),
-                padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                column_gap: Val::Px(Spacing::SMALL),
-                ..default()
-            },
-            Transform::default(), // Required for animation system
+            node,
             BackgroundColor(theme.inverse_surface),
             BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
             // Native Bevy 0.17 shadow support
             Elevation::Level3.to_box_shadow(),
-            GlobalZIndex(1000), // Ensure snackbar is on top
+            GlobalZIndex(Z_SNACKBAR),
         ))
-        .with_children(|parent| {
-            // Message text
-            parent.spawn((
-                SnackbarMessage,
-                Text::new(&message),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(theme.inverse_on_surface),
-                Node {
-                    flex_grow: 1.0,
-                    ..default()
-                },
-            ));
-
-            // Action button (if provided)
-            if let Some(action_text) = &action {
-                parent
-                    .spawn((
-                        SnackbarAction,
-                        Button,
-                        Node {
-                            padding: UiRect::axes(
-                                Val::Px(Spacing::SMALL),
-                                Val::Px(Spacing::EXTRA_SMALL),
-                            ),
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                    ))
-                    .with_children(|btn| {
-                        btn.spawn((
-                            Text::new(action_text),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(theme.inverse_primary),
-                        ));
-                    });
-            }
-
-            // Close button (X icon) - always shown for easy dismissal
-            let inverse_on_surface = theme.inverse_on_surface;
-            parent
-                .spawn((
-                    SnackbarCloseButton,
-                    Button,
-                    Interaction::None,
-                    Node {
-                        width: Val::Px(32.0),
-                        height: Val::Px(32.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        margin: UiRect::left(Val::Px(Spacing::SMALL)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::NONE),
-                    BorderRadius::all(Val::Px(CornerRadius::FULL)),
-                ))
-                .with_children(move |btn| {
-                    btn.spawn((
-                        MaterialIcon::new(
-                            icon_by_name(ICON_CLOSE).expect("embedded icon 'close' not found"),
-                        ),
-                        IconStyle::outlined()
-                            .with_color(inverse_on_surface)
-                            .with_size(24.0),
-                    ));
-                });
-        })
+        .with_children(|parent| content.spawn(parent))
         .id();
 
     // Make the snackbar a child of the host for proper z-ordering
@@ -736,8 +878,8 @@ fn snackbar_queue_system(
     mut events: MessageReader<ShowSnackbar>,
     theme: Option<Res<MaterialTheme>>,
     mut queue: ResMut<SnackbarQueue>,
-    mut hosts: Query<(Entity, &mut Node, &mut SnackbarHostPosition), With<SnackbarHost>>,
-    snackbars: Query<&Snackbar>,
+    mut hosts: Query<(Entity, &mut SnackbarHostPosition), With<SnackbarHost>>,
+    mut snackbars: Query<&mut Snackbar>,
 ) {
     let Some(theme) = theme else { return };
 
@@ -749,9 +891,30 @@ fn snackbar_queue_system(
         }
     }
 
-    // Add new events to the queue
+    // Add new events to the queue, dropping repeats of the snackbar on screen
+    let active = queue
+        .active
+        .and_then(|entity| snackbars.get(entity).ok())
+        .filter(|snackbar| snackbar.is_showing())
+        .map(|snackbar| (snackbar.priority, snackbar.dedup_key.clone()));
     for event in events.read() {
-        queue.queue.push(event.clone());
+        let repeats_active = active
+            .as_ref()
+            .is_some_and(|(_, key)| key.is_some() && *key == event.dedup_key);
+        if !repeats_active {
+            queue.push(event.clone());
+        }
+    }
+
+    // A higher-priority snackbar preempts the one on screen
+    if let (Some(entity), Some((priority, _)), Some(next)) =
+        (queue.active, active, queue.queue.first())
+    {
+        if next.priority > priority {
+            if let Ok(mut snackbar) = snackbars.get_mut(entity) {
+                snackbar.dismiss();
+            }
+        }
     }
 
     // Check if we can show a snackbar
@@ -766,75 +929,10 @@ fn snackbar_queue_system(
     // Show next snackbar if queue has items and we can show
     if can_show && !queue.queue.is_empty() {
         if let Some(event) = queue.queue.first().cloned() {
-            if let Some((host, mut host_node, mut host_pos)) = hosts.iter_mut().next() {
-                // Update host layout if position changed
+            if let Some((host, mut host_pos)) = hosts.iter_mut().next() {
+                // The host layout follows in `snackbar_host_layout_system`.
                 if host_pos.0 != event.position {
                     host_pos.0 = event.position;
-                    // For Column flex direction:
-                    // - justify_content controls vertical (main axis) - FlexEnd = bottom, FlexStart = top
-                    // - align_items controls horizontal (cross axis) - Center = centered, FlexStart = left, FlexEnd = right
-                    let (justify, align, flex_direction, padding) = match event.position {
-                        SnackbarPosition::BottomCenter => (
-                            JustifyContent::FlexEnd, // Bottom
-                            AlignItems::Center,      // Horizontally centered
-                            FlexDirection::Column,
-                            UiRect::bottom(Val::Px(SNACKBAR_MARGIN_BOTTOM)),
-                        ),
-                        SnackbarPosition::BottomLeft => (
-                            JustifyContent::FlexEnd, // Bottom
-                            AlignItems::FlexStart,   // Left
-                            FlexDirection::Column,
-                            UiRect::new(
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Auto,
-                                Val::Auto,
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                            ),
-                        ),
-                        SnackbarPosition::BottomRight => (
-                            JustifyContent::FlexEnd, // Bottom
-                            AlignItems::FlexEnd,     // Right
-                            FlexDirection::Column,
-                            UiRect::new(
-                                Val::Auto,
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Auto,
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                            ),
-                        ),
-                        SnackbarPosition::TopCenter => (
-                            JustifyContent::FlexStart, // Top
-                            AlignItems::Center,        // Horizontally centered
-                            FlexDirection::Column,
-                            UiRect::top(Val::Px(SNACKBAR_MARGIN_BOTTOM)),
-                        ),
-                        SnackbarPosition::TopLeft => (
-                            JustifyContent::FlexStart, // Top
-                            AlignItems::FlexStart,     // Left
-                            FlexDirection::Column,
-                            UiRect::new(
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Auto,
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Auto,
-                            ),
-                        ),
-                        SnackbarPosition::TopRight => (
-                            JustifyContent::FlexStart, // Top
-                            AlignItems::FlexEnd,       // Right
-                            FlexDirection::Column,
-                            UiRect::new(
-                                Val::Auto,
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Px(SNACKBAR_MARGIN_BOTTOM),
-                                Val::Auto,
-                            ),
-                        ),
-                    };
-                    host_node.justify_content = justify;
-                    host_node.align_items = align;
-                    host_node.flex_direction = flex_direction;
-                    host_node.padding = padding;
                 }
 
                 let entity = spawn_snackbar(&mut commands, &theme, &event, host);
@@ -845,48 +943,79 @@ fn snackbar_queue_system(
     }
 }
 
-/// System to animate snackbars using transform for slide animation
+/// System to lay out snackbar hosts for their current position and inset
+fn snackbar_host_layout_system(
+    mut hosts: Query<
+        (&SnackbarHostPosition, Option<&SnackbarInset>, &mut Node),
+        (
+            With<SnackbarHost>,
+            Or<(Changed<SnackbarHostPosition>, Changed<SnackbarInset>)>,
+        ),
+    >,
+) {
+    for (position, inset, mut node) in hosts.iter_mut() {
+        let (justify, align, padding) = position.0.host_layout(inset.map_or(0.0, |i| i.0));
+        node.flex_direction = FlexDirection::Column;
+        node.justify_content = justify;
+        node.align_items = align;
+        node.padding = padding;
+    }
+}
+
+/// Distance a snackbar slides in from (and out to) its anchored edge.
+///
+/// Positive values move down in UI coordinates.
+fn snackbar_slide_offset(snackbar: &Snackbar, inset: f32, progress: f32) -> f32 {
+    let distance = (1.0 - progress) * (snackbar.min_height() + SNACKBAR_MARGIN_BOTTOM + inset);
+    if snackbar.position.is_bottom() {
+        distance
+    } else {
+        -distance
+    }
+}
+
+/// System to animate snackbars sliding in from and out to their anchored edge
 fn snackbar_animation_system(
     time: Res<Time>,
-    mut snackbars: Query<(&mut Snackbar, &mut Transform)>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut snackbars: Query<(&mut Snackbar, &mut UiTransform, Option<&ChildOf>)>,
+    insets: Query<&SnackbarInset>,
 ) {
-    for (mut snackbar, mut transform) in snackbars.iter_mut() {
-        let dt = time.delta_secs();
+    // Reduced motion completes each slide in a single step.
+    let step = if reduced_motion.is_some_and(|r| r.0) {
+        1.0
+    } else {
+        time.delta_secs() / Duration::MEDIUM2
+    };
 
-        match snackbar.animation_state {
+    for (mut snackbar, mut transform, parent) in snackbars.iter_mut() {
+        let inset = parent
+            .and_then(|p| insets.get(p.parent()).ok())
+            .map_or(0.0, |i| i.0);
+
+        let progress = match snackbar.animation_state {
             SnackbarAnimationState::Entering => {
-                snackbar.animation_progress += dt / Duration::MEDIUM2;
+                snackbar.animation_progress += step;
                 if snackbar.animation_progress >= 1.0 {
                     snackbar.animation_progress = 1.0;
                     snackbar.animation_state = SnackbarAnimationState::Visible;
                 }
-
-                // Slide animation using transform
-                let progress = ease_standard_decelerate(snackbar.animation_progress);
-                let offset = (1.0 - progress) * (SNACKBAR_HEIGHT_SINGLE + SNACKBAR_MARGIN_BOTTOM);
-                // Positive Y moves down in UI coordinates, so we use positive offset for bottom snackbars
-                transform.translation.y = -offset;
-            }
-            SnackbarAnimationState::Visible => {
-                // Ensure fully visible
-                transform.translation.y = 0.0;
+                ease_standard_decelerate(snackbar.animation_progress)
             }
+            SnackbarAnimationState::Visible => 1.0,
             SnackbarAnimationState::Exiting => {
-                snackbar.animation_progress -= dt / Duration::MEDIUM2;
+                snackbar.animation_progress -= step;
                 if snackbar.animation_progress <= 0.0 {
                     snackbar.animation_progress = 0.0;
                     snackbar.animation_state = SnackbarAnimationState::Dismissed;
                 }
-
-                // Slide animation using transform
-                let progress = ease_standard_accelerate(snackbar.animation_progress);
-                let offset = (1.0 - progress) * (SNACKBAR_HEIGHT_SINGLE + SNACKBAR_MARGIN_BOTTOM);
-                transform.translation.y = -offset;
+                ease_standard_accelerate(snackbar.animation_progress)
             }
-            SnackbarAnimationState::Dismissed => {
-                // Will be cleaned up
-            }
-        }
+            // Will be cleaned up
+            SnackbarAnimationState::Dismissed => continue,
+        };
+
+        transform.translation = Val2::px(0.0, snackbar_slide_offset(&snackbar, inset, progress));
     }
 }
 
@@ -1034,6 +1163,52 @@ mod tests {
         assert!(!event.dismissible);
     }
 
+    #[test]
+    fn test_snackbar_queue_orders_by_priority() {
+        let mut queue = SnackbarQueue::default();
+        assert!(queue.push(ShowSnackbar::message("first")));
+        assert!(queue.push(ShowSnackbar::message("low").priority(SnackbarPriority::Low)));
+        assert!(queue.push(ShowSnackbar::message("second").dedup_key("sync")));
+        assert!(queue.push(ShowSnackbar::message("error").priority(SnackbarPriority::High)));
+        assert!(!queue.push(ShowSnackbar::message("second again").dedup_key("sync")));
+
+        let order: Vec<_> = queue.queue.iter().map(|s| s.message.as_str()).collect();
+        assert_eq!(order, ["error", "first", "second", "low"]);
+    }
+
+    #[test]
+    fn test_snackbar_queue_dedup_and_preempt() {
+        let mut app = App::new();
+        app.add_message::<ShowSnackbar>()
+            .insert_resource(MaterialTheme::default())
+            .init_resource::<SnackbarQueue>()
+            .add_systems(Update, snackbar_queue_system);
+        app.world_mut().spawn(SnackbarHostBuilder::build());
+
+        app.world_mut()
+            .write_message(ShowSnackbar::message("Syncing").dedup_key("sync"));
+        app.update();
+        let active = app.world().resource::<SnackbarQueue>().active.unwrap();
+
+        // A repeat of the snackbar on screen is dropped.
+        app.world_mut()
+            .write_message(ShowSnackbar::message("Syncing").dedup_key("sync"));
+        app.update();
+        assert!(app.world().resource::<SnackbarQueue>().queue.is_empty());
+
+        // An error waits in the queue and sends the info snackbar on its way out.
+        app.world_mut()
+            .write_message(ShowSnackbar::message("Sync failed").priority(SnackbarPriority::High));
+        app.update();
+        let queue = app.world().resource::<SnackbarQueue>();
+        assert_eq!(queue.active, Some(active));
+        assert_eq!(queue.queue.len(), 1);
+        assert_eq!(
+            app.world().get::<Snackbar>(active).unwrap().animation_state,
+            SnackbarAnimationState::Exiting
+        );
+    }
+
     #[test]
     fn test_snackbar_close_button_marker() {
         // Verify SnackbarCloseButton can be created as a marker component
@@ -1052,4 +1227,48 @@ mod tests {
         snackbar.dismiss();
         assert_eq!(snackbar.animation_state, SnackbarAnimationState::Exiting);
     }
+
+    #[test]
+    fn test_multiline_snackbar_grows() {
+        let single = Snackbar::from_event(&ShowSnackbar::message("Saved"));
+        assert!(!single.multiline);
+        assert_eq!(single.min_height(), SNACKBAR_HEIGHT_SINGLE);
+
+        let double = Snackbar::from_event(
+            &ShowSnackbar::with_action("Connection lost. Changes will sync later.", "Retry")
+                .icon("cloud_off")
+                .multiline(true),
+        );
+        assert_eq!(double.icon.as_deref(), Some("cloud_off"));
+        assert_eq!(double.min_height(), SNACKBAR_HEIGHT_DOUBLE);
+
+        // Two-line snackbars also slide further so they start fully off-screen.
+        assert!(
+            snackbar_slide_offset(&double, 0.0, 0.0) > snackbar_slide_offset(&single, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_snackbar_host_layout_inset() {
+        let (justify, align, padding) = SnackbarPosition::BOTTOM_END.host_layout(56.0);
+        assert_eq!(justify, JustifyContent::FlexEnd);
+        assert_eq!(align, AlignItems::FlexEnd);
+        assert_eq!(padding.bottom, Val::Px(SNACKBAR_MARGIN_BOTTOM + 56.0));
+
+        let (justify, _, padding) = SnackbarPosition::TopCenter.host_layout(56.0);
+        assert_eq!(justify, JustifyContent::FlexStart);
+        assert_eq!(padding.top, Val::Px(SNACKBAR_MARGIN_BOTTOM + 56.0));
+    }
+
+    #[test]
+    fn test_snackbar_slide_direction() {
+        // Bottom snackbars slide up from below, top snackbars slide down from above.
+        let at = |position| Snackbar::from_event(&ShowSnackbar::message("Test").position(position));
+        assert!(snackbar_slide_offset(&at(SnackbarPosition::BottomCenter), 0.0, 0.0) > 0.0);
+        assert!(snackbar_slide_offset(&at(SnackbarPosition::TopCenter), 0.0, 0.0) < 0.0);
+        assert_eq!(
+            snackbar_slide_offset(&at(SnackbarPosition::BottomLeft), 80.0, 1.0),
+            0.0
+        );
+    }
 }
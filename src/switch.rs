@@ -14,7 +14,14 @@
 
 use bevy::prelude::*;
 
-use crate::{ripple::RippleHost, theme::MaterialTheme, tokens::CornerRadius};
+use crate::{
+    control_label::spawn_labeled_control,
+    disabled::{sync_disabled_system, DisabledSource},
+    ripple::RippleHost,
+    semantics::{sync_semantics_system, Role, Semantic},
+    theme::MaterialTheme,
+    tokens::{CornerRadius, Opacity},
+};
 
 /// Marker component for switch state layer
 #[derive(Component)]
@@ -31,6 +38,8 @@ impl Plugin for SwitchPlugin {
                 switch_interaction_system,
                 switch_style_system,
                 switch_theme_refresh_system,
+                sync_disabled_system::<MaterialSwitch>,
+                sync_semantics_system::<MaterialSwitch>,
             ),
         );
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
@@ -81,6 +90,20 @@ impl MaterialSwitch {
         self
     }
 
+    /// Whether the switch is on (selected)
+    pub fn is_on(&self) -> bool {
+        self.selected
+    }
+
+    /// Turn the switch on or off.
+    ///
+    /// Called through `Mut<MaterialSwitch>`, this marks the switch changed so
+    /// its track and handle are restyled. No [`SwitchChangeEvent`] is written.
+    pub fn set_on(&mut self, on: bool) {
+        self.selected = on;
+        self.animation_progress = if on { 1.0 } else { 0.0 };
+    }
+
     /// Enable icons in the switch
     pub fn with_icon(mut self) -> Self {
         self.with_icon = true;
@@ -91,9 +114,11 @@ impl MaterialSwitch {
     pub fn track_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
             if self.selected {
-                return theme.on_surface.with_alpha(0.12);
+                return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
             } else {
-                return theme.surface_container_highest.with_alpha(0.12);
+                return theme
+                    .surface_container_highest
+                    .with_alpha(Opacity::DISABLED_CONTAINER);
             }
         }
 
@@ -107,7 +132,7 @@ impl MaterialSwitch {
     /// Get the track outline color
     pub fn track_outline_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
-            return theme.on_surface.with_alpha(0.12);
+            return theme.on_surface.with_alpha(Opacity::DISABLED_CONTAINER);
         }
 
         if self.selected {
@@ -123,7 +148,7 @@ impl MaterialSwitch {
             if self.selected {
                 return theme.surface;
             } else {
-                return theme.on_surface.with_alpha(0.38);
+                return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
             }
         }
 
@@ -140,9 +165,11 @@ impl MaterialSwitch {
     pub fn icon_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
             if self.selected {
-                return theme.on_surface.with_alpha(0.38);
+                return theme.on_surface.with_alpha(Opacity::DISABLED_CONTENT);
             } else {
-                return theme.surface_container_highest.with_alpha(0.38);
+                return theme
+                    .surface_container_highest
+                    .with_alpha(Opacity::DISABLED_CONTENT);
             }
         }
 
@@ -170,6 +197,28 @@ impl MaterialSwitch {
     }
 }
 
+impl DisabledSource for MaterialSwitch {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Semantic for MaterialSwitch {
+    fn semantic_role(&self) -> Role {
+        Role::Switch
+    }
+
+    /// The component has no text of its own; builders label it from the
+    /// control label when one is given
+    fn semantic_label(&self) -> String {
+        String::new()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        Some(if self.selected { "on" } else { "off" }.to_string())
+    }
+}
+
 impl Default for MaterialSwitch {
     fn default() -> Self {
         Self::new()
@@ -323,6 +372,7 @@ fn switch_theme_refresh_system(
 /// Builder for switches
 pub struct SwitchBuilder {
     switch: MaterialSwitch,
+    label: Option<String>,
 }
 
 impl SwitchBuilder {
@@ -330,6 +380,7 @@ impl SwitchBuilder {
     pub fn new() -> Self {
         Self {
             switch: MaterialSwitch::new(),
+            label: None,
         }
     }
 
@@ -352,14 +403,22 @@ impl SwitchBuilder {
         self
     }
 
+    /// Add a text label; clicking it toggles the switch
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Build the switch bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.switch.track_color(theme);
         let border_color = self.switch.track_outline_color(theme);
         let has_border = !self.switch.selected;
+        let semantics = self.switch.semantics();
 
         (
             self.switch,
+            semantics,
             Button,
             RippleHost::new(),
             Node {
@@ -376,6 +435,32 @@ impl SwitchBuilder {
             BorderRadius::all(Val::Px(CornerRadius::FULL)),
         )
     }
+
+    /// Spawn a complete switch with visuals
+    ///
+    /// With a [`label`](Self::label), the switch and its text are wrapped in a
+    /// [`ControlLabel`](crate::control_label::ControlLabel) row that acts as
+    /// one touch target, and the row entity is returned.
+    pub fn spawn(self, commands: &mut Commands, theme: &MaterialTheme) -> Entity {
+        self.spawn_into(commands.spawn_empty(), theme)
+    }
+
+    fn spawn_into(self, mut root: EntityCommands, theme: &MaterialTheme) -> Entity {
+        let Self { switch, label } = self;
+        let semantics = switch.semantics();
+        match label {
+            Some(label) => {
+                let semantics = semantics.with_label(label.clone());
+                spawn_labeled_control(root, theme, label, |row| {
+                    spawn_switch_control(row.spawn(semantics), switch, theme)
+                })
+            }
+            None => {
+                root.insert(semantics);
+                spawn_switch_control(root, switch, theme)
+            }
+        }
+    }
 }
 
 impl Default for SwitchBuilder {
@@ -388,6 +473,58 @@ impl Default for SwitchBuilder {
 #[derive(Component)]
 pub struct SwitchHandle;
 
+/// Insert the switch track on `entity` and spawn its handle.
+fn spawn_switch_control(
+    mut entity: EntityCommands,
+    switch: MaterialSwitch,
+    theme: &MaterialTheme,
+) -> Entity {
+    let bg_color = switch.track_color(theme);
+    let border_color = switch.track_outline_color(theme);
+    let handle_color = switch.handle_color(theme);
+    let handle_size = switch.handle_size();
+    let has_border = !switch.selected;
+    let justify = if switch.selected {
+        JustifyContent::FlexEnd
+    } else {
+        JustifyContent::FlexStart
+    };
+
+    entity
+        .insert((
+            switch,
+            Button,
+            Interaction::None,
+            RippleHost::new(),
+            Node {
+                width: Val::Px(SWITCH_TRACK_WIDTH),
+                height: Val::Px(SWITCH_TRACK_HEIGHT),
+                justify_content: justify,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(2.0)),
+                border: UiRect::all(Val::Px(if has_border { 2.0 } else { 0.0 })),
+                ..default()
+            },
+            BackgroundColor(bg_color),
+            BorderColor::all(border_color),
+            BorderRadius::all(Val::Px(CornerRadius::FULL)),
+        ))
+        .with_children(|track| {
+            // Handle (thumb)
+            track.spawn((
+                SwitchHandle,
+                Node {
+                    width: Val::Px(handle_size),
+                    height: Val::Px(handle_size),
+                    ..default()
+                },
+                BackgroundColor(handle_color),
+                BorderRadius::all(Val::Px(handle_size / 2.0)),
+            ));
+        });
+    entity.id()
+}
+
 /// Extension trait to spawn switches with full visual hierarchy
 pub trait SpawnSwitch {
     /// Spawn a switch with a label
@@ -414,71 +551,7 @@ impl SpawnSwitch for Commands<'_, '_> {
         builder: SwitchBuilder,
         label: &str,
     ) -> Entity {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let switch = builder.switch;
-        let bg_color = switch.track_color(theme);
-        let border_color = switch.track_outline_color(theme);
-        let handle_color = switch.handle_color(theme);
-        let handle_size = switch.handle_size();
-        let has_border = !switch.selected;
-        let justify = if switch.selected {
-            JustifyContent::FlexEnd
-        } else {
-            JustifyContent::FlexStart
-        };
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Switch track (the main touch target)
-            row.spawn((
-                switch,
-                Button,
-                Interaction::None,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(SWITCH_TRACK_WIDTH),
-                    height: Val::Px(SWITCH_TRACK_HEIGHT),
-                    justify_content: justify,
-                    align_items: AlignItems::Center,
-                    padding: UiRect::horizontal(Val::Px(2.0)),
-                    border: UiRect::all(Val::Px(if has_border { 2.0 } else { 0.0 })),
-                    ..default()
-                },
-                BackgroundColor(bg_color),
-                BorderColor::all(border_color),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|track| {
-                // Handle (thumb)
-                track.spawn((
-                    SwitchHandle,
-                    Node {
-                        width: Val::Px(handle_size),
-                        height: Val::Px(handle_size),
-                        ..default()
-                    },
-                    BackgroundColor(handle_color),
-                    BorderRadius::all(Val::Px(handle_size / 2.0)),
-                ));
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        })
-        .id()
+        builder.label(label).spawn(self, theme)
     }
 }
 
@@ -498,70 +571,7 @@ impl SpawnSwitchChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_switch_with(&mut self, theme: &MaterialTheme, builder: SwitchBuilder, label: &str) {
-        let label_color = theme.on_surface;
-        let label_text = label.to_string();
-        let switch = builder.switch;
-        let bg_color = switch.track_color(theme);
-        let border_color = switch.track_outline_color(theme);
-        let handle_color = switch.handle_color(theme);
-        let handle_size = switch.handle_size();
-        let has_border = !switch.selected;
-        let justify = if switch.selected {
-            JustifyContent::FlexEnd
-        } else {
-            JustifyContent::FlexStart
-        };
-
-        self.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(12.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Switch track
-            row.spawn((
-                switch,
-                Button,
-                Interaction::None,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(SWITCH_TRACK_WIDTH),
-                    height: Val::Px(SWITCH_TRACK_HEIGHT),
-                    justify_content: justify,
-                    align_items: AlignItems::Center,
-                    padding: UiRect::horizontal(Val::Px(2.0)),
-                    border: UiRect::all(Val::Px(if has_border { 2.0 } else { 0.0 })),
-                    ..default()
-                },
-                BackgroundColor(bg_color),
-                BorderColor::all(border_color),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|track| {
-                // Handle (thumb)
-                track.spawn((
-                    SwitchHandle,
-                    Node {
-                        width: Val::Px(handle_size),
-                        height: Val::Px(handle_size),
-                        ..default()
-                    },
-                    BackgroundColor(handle_color),
-                    BorderRadius::all(Val::Px(handle_size / 2.0)),
-                ));
-            });
-
-            // Label
-            row.spawn((
-                Text::new(label_text),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(label_color),
-            ));
-        });
+        builder.label(label).spawn_into(self.spawn_empty(), theme);
     }
 }
 
@@ -614,6 +624,20 @@ mod tests {
         assert!(!switch.disabled);
     }
 
+    #[test]
+    fn test_switch_set_on() {
+        let mut switch = MaterialSwitch::new();
+        assert!(!switch.is_on());
+
+        switch.set_on(true);
+        assert!(switch.is_on());
+        assert_eq!(switch.animation_progress, 1.0);
+
+        switch.set_on(false);
+        assert!(!switch.is_on());
+        assert_eq!(switch.animation_progress, 0.0);
+    }
+
     #[test]
     fn test_switch_with_icon() {
         let switch = MaterialSwitch::new().with_icon();
@@ -717,6 +741,25 @@ mod tests {
         assert!(builder.switch.with_icon);
     }
 
+    #[test]
+    fn test_switch_builder_label_wraps_switch() {
+        use crate::control_label::ControlLabel;
+
+        let mut world = World::new();
+        let theme = MaterialTheme::default();
+        let row = SwitchBuilder::new()
+            .label("Wi-Fi")
+            .spawn(&mut world.commands(), &theme);
+        world.flush();
+
+        let label = world.get::<ControlLabel>(row).unwrap();
+        assert!(world.get::<MaterialSwitch>(label.control).is_some());
+        assert_eq!(
+            world.get::<ChildOf>(label.control).map(ChildOf::parent),
+            Some(row)
+        );
+    }
+
     // ============================================================================
     // Constants Tests
     // ============================================================================
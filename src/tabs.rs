@@ -3,13 +3,18 @@
 //! Tabs organize content across different screens, data sets, and other interactions.
 //! Reference: <https://m3.material.io/components/tabs/overview>
 
+use bevy::picking::Pickable;
 use bevy::prelude::*;
 
 use crate::{
+    badge::{spawn_badge_on, MaterialBadge},
+    focus::adopt_focus_group_system,
+    motion::{ease_standard, ReducedMotion},
     ripple::RippleHost,
+    semantics::{sync_semantics_system, Role, Semantic},
     telemetry::{InsertTestIdIfExists, TelemetryConfig, TestId},
     theme::MaterialTheme,
-    tokens::Spacing,
+    tokens::{Duration, Spacing},
 };
 
 /// Plugin for the tabs component
@@ -26,6 +31,12 @@ impl Plugin for TabsPlugin {
             .add_systems(Update, sync_tabs_selection_system)
             .add_systems(Update, tab_label_and_indicator_system)
             .add_systems(Update, tab_content_visibility_system)
+            .add_systems(Update, tab_content_fade_system)
+            .add_systems(
+                Update,
+                adopt_focus_group_system::<MaterialTabs, MaterialTab>,
+            )
+            .add_systems(Update, sync_semantics_system::<MaterialTab>)
             .add_systems(
                 Update,
                 tabs_telemetry_system.after(tab_label_and_indicator_system),
@@ -41,6 +52,7 @@ fn tabs_telemetry_system(
     label_query: Query<(), With<TabLabelText>>,
     indicator_query: Query<(), With<TabIndicator>>,
     content_query: Query<(Entity, &TabContent), Without<TestId>>,
+    badge_labels: Query<&Children, With<TabBadgedLabel>>,
 ) {
     let Some(telemetry) = telemetry else {
         return;
@@ -67,11 +79,13 @@ fn tabs_telemetry_system(
             });
 
             for tab_child in tab_children.iter() {
-                if label_query.get(tab_child).is_ok() {
-                    commands.queue(InsertTestIdIfExists {
-                        entity: tab_child,
-                        id: format!("{tabs_id}/tab/{}/label", tab.index),
-                    });
+                for label in tab_label_entities(tab_child, &badge_labels) {
+                    if label_query.get(label).is_ok() {
+                        commands.queue(InsertTestIdIfExists {
+                            entity: label,
+                            id: format!("{tabs_id}/tab/{}/label", tab.index),
+                        });
+                    }
                 }
                 if indicator_query.get(tab_child).is_ok() {
                     commands.queue(InsertTestIdIfExists {
@@ -112,6 +126,8 @@ pub struct MaterialTabs {
     pub variant: TabVariant,
     /// Currently selected tab index
     pub selected: usize,
+    /// Whether a newly selected content panel fades in
+    pub crossfade: bool,
 }
 
 impl MaterialTabs {
@@ -120,6 +136,7 @@ impl MaterialTabs {
         Self {
             variant: TabVariant::default(),
             selected: 0,
+            crossfade: false,
         }
     }
 
@@ -134,6 +151,12 @@ impl MaterialTabs {
         self.selected = index;
         self
     }
+
+    /// Fade content panels in when their tab is selected
+    pub fn crossfade(mut self, crossfade: bool) -> Self {
+        self.crossfade = crossfade;
+        self
+    }
 }
 
 impl Default for MaterialTabs {
@@ -217,6 +240,27 @@ impl MaterialTab {
     }
 }
 
+impl Semantic for MaterialTab {
+    fn semantic_role(&self) -> Role {
+        Role::Tab
+    }
+
+    fn semantic_label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn semantic_value(&self) -> Option<String> {
+        Some(
+            if self.selected {
+                "selected"
+            } else {
+                "unselected"
+            }
+            .to_string(),
+        )
+    }
+}
+
 /// Event when tab selection changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct TabChangeEvent {
@@ -233,11 +277,31 @@ pub const TAB_HEIGHT_PRIMARY: f32 = 64.0;
 pub const TAB_HEIGHT_PRIMARY_ICON_ONLY: f32 = 48.0;
 pub const TAB_HEIGHT_SECONDARY: f32 = 48.0;
 pub const TAB_INDICATOR_HEIGHT: f32 = 3.0;
+/// Duration of the content panel fade-in (seconds)
+pub const TAB_CONTENT_FADE_DURATION: f32 = Duration::MEDIUM1;
 
 /// Marker for tab label text, so the tabs systems can reliably update the label color.
 #[derive(Component)]
 pub struct TabLabelText;
 
+/// Wrapper around a tab's [`TabLabelText`] that carries the tab's badge.
+///
+/// Text nodes can't have laid-out children, so a badged label sits in this
+/// wrapper and the badge is attached to the wrapper's top-right corner.
+#[derive(Component)]
+pub struct TabBadgedLabel;
+
+/// `child` of a tab, or the children of a [`TabBadgedLabel`] wrapper
+fn tab_label_entities(
+    child: Entity,
+    badge_labels: &Query<&Children, With<TabBadgedLabel>>,
+) -> Vec<Entity> {
+    match badge_labels.get(child) {
+        Ok(wrapped) => wrapped.iter().collect(),
+        Err(_) => vec![child],
+    }
+}
+
 /// System to handle tab interactions
 fn tab_interaction_system(
     mut tab_queries: ParamSet<(
@@ -357,6 +421,7 @@ fn tab_label_and_indicator_system(
     mut tab_query: Query<(Entity, &MaterialTab, &Children, &ChildOf), Changed<MaterialTab>>,
     mut label_query: Query<&mut TextColor, With<TabLabelText>>,
     indicator_query: Query<(), With<TabIndicator>>,
+    badge_labels: Query<&Children, With<TabBadgedLabel>>,
 ) {
     let Some(theme) = theme else { return };
 
@@ -368,8 +433,10 @@ fn tab_label_and_indicator_system(
 
         let mut has_indicator = false;
         for child in children.iter() {
-            if let Ok(mut tc) = label_query.get_mut(child) {
-                tc.0 = label_color;
+            for label in tab_label_entities(child, &badge_labels) {
+                if let Ok(mut tc) = label_query.get_mut(label) {
+                    tc.0 = label_color;
+                }
             }
 
             if indicator_query.get(child).is_ok() {
@@ -393,23 +460,61 @@ fn tab_label_and_indicator_system(
 ///
 /// This system looks for `TabContent` components that are siblings of `MaterialTab` components
 /// within a `MaterialTabs` container, or content panels that are children of the tabs container.
+/// With [`MaterialTabs::crossfade`], a panel that becomes visible fades in.
 fn tab_content_visibility_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    reduced_motion: Option<Res<ReducedMotion>>,
     tabs_query: Query<(Entity, &MaterialTabs)>,
-    mut content_query: Query<(&TabContent, &mut Visibility, Option<&mut Node>)>,
+    mut content_query: Query<(Entity, &TabContent, &mut Visibility, Option<&mut Node>)>,
+    backgrounds: Query<&BackgroundColor>,
+    parents: Query<&ChildOf>,
 ) {
+    let fade = reduced_motion.is_none_or(|r| !r.0);
+
     for (tabs_entity, tabs) in tabs_query.iter() {
-        for (content, mut visibility, node) in content_query.iter_mut() {
+        for (panel, content, mut visibility, node) in content_query.iter_mut() {
             if content.tabs_entity != tabs_entity {
                 continue;
             }
 
             let is_selected = content.index == tabs.selected;
+            let was_hidden = *visibility == Visibility::Hidden;
             *visibility = if is_selected {
                 Visibility::Inherited
             } else {
                 Visibility::Hidden
             };
 
+            if is_selected && was_hidden && tabs.crossfade && fade {
+                // Fade from whatever the panel is drawn on.
+                let color = std::iter::once(panel)
+                    .chain(parents.iter_ancestors(panel))
+                    .filter_map(|e| backgrounds.get(e).ok())
+                    .map(|bg| bg.0)
+                    .find(|c| c.alpha() > 0.0)
+                    .or_else(|| theme.as_ref().map(|t| t.surface))
+                    .unwrap_or(Color::NONE);
+                commands.spawn((
+                    TabContentFade {
+                        elapsed: 0.0,
+                        color,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        top: Val::Px(0.0),
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    ZIndex(i32::MAX),
+                    Pickable::IGNORE,
+                    ChildOf(panel),
+                ));
+            }
+
             // `Visibility::Hidden` still participates in layout in Bevy UI.
             // Toggle `Display` so only the selected panel affects layout.
             if let Some(mut node) = node {
@@ -423,6 +528,33 @@ fn tab_content_visibility_system(
     }
 }
 
+/// Cover drawn over a newly selected [`TabContent`] panel while it fades in
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TabContentFade {
+    /// Seconds since the fade started
+    pub elapsed: f32,
+    /// Color the panel fades in from
+    pub color: Color,
+}
+
+/// Fade out content panel covers, then despawn them.
+fn tab_content_fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut covers: Query<(Entity, &mut TabContentFade, &mut BackgroundColor)>,
+) {
+    for (entity, mut fade, mut bg_color) in covers.iter_mut() {
+        fade.elapsed += time.delta_secs();
+        let t = (fade.elapsed / TAB_CONTENT_FADE_DURATION).min(1.0);
+        if t >= 1.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = fade.color.alpha() * (1.0 - ease_standard(t));
+        bg_color.0 = fade.color.with_alpha(alpha);
+    }
+}
+
 /// Builder for tabs container
 pub struct TabsBuilder {
     tabs: MaterialTabs,
@@ -458,6 +590,12 @@ impl TabsBuilder {
         self
     }
 
+    /// Fade content panels in when their tab is selected
+    pub fn crossfade(mut self, crossfade: bool) -> Self {
+        self.tabs.crossfade = crossfade;
+        self
+    }
+
     /// Build the tabs bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let height = match self.tabs.variant {
@@ -490,6 +628,7 @@ impl Default for TabsBuilder {
 pub struct TabBuilder {
     tab: MaterialTab,
     variant: TabVariant,
+    badge: Option<MaterialBadge>,
 }
 
 impl TabBuilder {
@@ -498,6 +637,7 @@ impl TabBuilder {
         Self {
             tab: MaterialTab::new(index, label),
             variant: TabVariant::Primary,
+            badge: None,
         }
     }
 
@@ -525,12 +665,23 @@ impl TabBuilder {
         self
     }
 
+    /// Show a badge at the top-right of the tab label (e.g. an unread count).
+    ///
+    /// Counts above the badge's `max` display as "99+" and the like; see
+    /// [`MaterialBadge::with_max`].
+    pub fn badge(mut self, badge: MaterialBadge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+
     /// Build the tab bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let _content_color = self.tab.content_color(theme, self.variant);
+        let semantics = self.tab.semantics();
 
         (
             self.tab,
+            semantics,
             Button,
             RippleHost::new(),
             Node {
@@ -607,6 +758,19 @@ pub trait SpawnTabsChild {
 
     /// Spawn a tab with full builder control
     fn spawn_tab_with(&mut self, theme: &MaterialTheme, builder: TabBuilder);
+
+    /// Spawn a tab bar above one [`TabContent`] panel per tab.
+    ///
+    /// `with_panel` fills the panel for each tab index. The selected and
+    /// variant settings of `builder` apply to every tab. Returns the tabs
+    /// container entity.
+    fn spawn_tabs_with_content(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: TabsBuilder,
+        tabs: impl IntoIterator<Item = TabBuilder>,
+        with_panel: impl FnMut(usize, &mut ChildSpawnerCommands),
+    ) -> Entity;
 }
 
 impl SpawnTabsChild for ChildSpawnerCommands<'_> {
@@ -642,14 +806,15 @@ impl SpawnTabsChild for ChildSpawnerCommands<'_> {
         });
     }
 
-    fn spawn_tab_with(&mut self, theme: &MaterialTheme, builder: TabBuilder) {
+    fn spawn_tab_with(&mut self, theme: &MaterialTheme, mut builder: TabBuilder) {
         let label_str = builder.tab.label.clone();
         let selected = builder.tab.selected;
         let variant = builder.variant;
         let content_color = builder.tab.content_color(theme, variant);
+        let badge = builder.badge.take();
 
         self.spawn(builder.build(theme)).with_children(|tab| {
-            tab.spawn((
+            let label = (
                 TabLabelText,
                 Text::new(&label_str),
                 TextFont {
@@ -657,11 +822,236 @@ impl SpawnTabsChild for ChildSpawnerCommands<'_> {
                     ..default()
                 },
                 TextColor(content_color),
-            ));
+            );
+
+            match badge {
+                Some(badge) => {
+                    // The badge is absolutely positioned, so the tab keeps its
+                    // height and the indicator stays aligned to the tab.
+                    let wrapper = tab
+                        .spawn((TabBadgedLabel, Node::default()))
+                        .with_children(|wrapper| {
+                            wrapper.spawn(label);
+                        })
+                        .id();
+                    spawn_badge_on(&mut tab.commands(), theme, badge, wrapper);
+                }
+                None => {
+                    tab.spawn(label);
+                }
+            }
 
             if selected {
                 tab.spawn(create_tab_indicator(theme, variant));
             }
         });
     }
+
+    fn spawn_tabs_with_content(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: TabsBuilder,
+        tabs: impl IntoIterator<Item = TabBuilder>,
+        mut with_panel: impl FnMut(usize, &mut ChildSpawnerCommands),
+    ) -> Entity {
+        let selected = builder.tabs.selected;
+        let variant = builder.tabs.variant;
+        let tabs: Vec<TabBuilder> = tabs.into_iter().collect();
+        let mut tabs_entity = Entity::PLACEHOLDER;
+
+        self.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_grow: 1.0,
+            flex_direction: FlexDirection::Column,
+            ..default()
+        })
+        .with_children(|root| {
+            let indices: Vec<usize> = tabs.iter().map(|tab| tab.tab.index).collect();
+
+            tabs_entity = root
+                .spawn(builder.build(theme))
+                .with_children(|bar| {
+                    for tab in tabs {
+                        let is_selected = tab.tab.index == selected;
+                        bar.spawn_tab_with(theme, tab.variant(variant).selected(is_selected));
+                    }
+                })
+                .id();
+
+            root.spawn(Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|panels| {
+                for index in indices {
+                    let is_selected = index == selected;
+                    panels
+                        .spawn((
+                            TabContent::new(index, tabs_entity),
+                            Node {
+                                display: if is_selected {
+                                    Display::Flex
+                                } else {
+                                    Display::None
+                                },
+                                width: Val::Percent(100.0),
+                                flex_grow: 1.0,
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            if is_selected {
+                                Visibility::Inherited
+                            } else {
+                                Visibility::Hidden
+                            },
+                        ))
+                        .with_children(|panel| with_panel(index, panel));
+                }
+            });
+        });
+
+        tabs_entity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::badge::AttachedBadge;
+
+    #[test]
+    fn test_badged_tab_label_keeps_color_updates() {
+        let theme = MaterialTheme::default();
+        let mut app = App::new();
+        app.insert_resource(theme.clone())
+            .add_systems(Update, tab_label_and_indicator_system);
+
+        {
+            let world = app.world_mut();
+            let mut commands = world.commands();
+            commands.spawn(MaterialTabs::new()).with_children(|bar| {
+                bar.spawn_tab_with(
+                    &theme,
+                    TabBuilder::new(1, "Inbox")
+                        .selected(false)
+                        .badge(MaterialBadge::count(150).with_max(99)),
+                );
+            });
+            world.flush();
+        }
+        app.update();
+
+        let world = app.world_mut();
+        let (badge_entity, badge, attached) = world
+            .query::<(Entity, &MaterialBadge, &AttachedBadge)>()
+            .single(world)
+            .unwrap();
+        assert_eq!(badge.formatted_content(), Some("99+".to_string()));
+        let wrapper = attached.target;
+        assert!(world.get::<TabBadgedLabel>(wrapper).is_some());
+        let label = world
+            .get::<Children>(wrapper)
+            .unwrap()
+            .iter()
+            .find(|child| world.get::<TabLabelText>(*child).is_some())
+            .unwrap();
+        assert_ne!(label, badge_entity);
+
+        let (tab, mut material_tab) = world
+            .query::<(Entity, &mut MaterialTab)>()
+            .single_mut(world)
+            .unwrap();
+        material_tab.selected = true;
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<TextColor>(label).unwrap().0,
+            theme.primary,
+            "selected label color reaches the label inside the badge wrapper"
+        );
+        assert!(world
+            .get::<Children>(tab)
+            .unwrap()
+            .iter()
+            .any(|child| world.get::<TabIndicator>(child).is_some()));
+    }
+
+    #[test]
+    fn test_tabs_with_content_show_the_selected_panel_and_fade_it_in() {
+        let theme = MaterialTheme::default();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(theme.clone())
+            .add_systems(
+                Update,
+                (tab_content_visibility_system, tab_content_fade_system).chain(),
+            );
+
+        let mut tabs = Entity::PLACEHOLDER;
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| {
+                tabs = root.spawn_tabs_with_content(
+                    &theme,
+                    TabsBuilder::new().crossfade(true),
+                    [TabBuilder::new(0, "Photos"), TabBuilder::new(1, "Albums")],
+                    |index, panel| {
+                        panel.spawn(Text::new(format!("Panel {index}")));
+                    },
+                );
+            });
+        app.world_mut().flush();
+        app.update();
+
+        let panel = |app: &mut App, index: usize| {
+            app.world_mut()
+                .query::<(Entity, &TabContent)>()
+                .iter(app.world())
+                .find_map(|(entity, content)| (content.index == index).then_some(entity))
+                .unwrap()
+        };
+        let display = |app: &App, entity: Entity| app.world().get::<Node>(entity).unwrap().display;
+        let (photos, albums) = (panel(&mut app, 0), panel(&mut app, 1));
+        assert_eq!(
+            app.world().get::<TabContent>(albums).unwrap().tabs_entity,
+            tabs
+        );
+        assert_eq!(display(&app, photos), Display::Flex);
+        assert_eq!(display(&app, albums), Display::None);
+
+        app.world_mut()
+            .get_mut::<MaterialTabs>(tabs)
+            .unwrap()
+            .selected = 1;
+        app.update();
+        assert_eq!(display(&app, photos), Display::None);
+        assert_eq!(display(&app, albums), Display::Flex);
+
+        let mut covers = app
+            .world_mut()
+            .query_filtered::<(Entity, &ChildOf), With<TabContentFade>>();
+        let (cover, parent) = covers.single(app.world()).unwrap();
+        assert_eq!(parent.parent(), albums);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(
+                TAB_CONTENT_FADE_DURATION / 2.0,
+            ));
+        app.update();
+        let alpha = app.world().get::<BackgroundColor>(cover).unwrap().0.alpha();
+        assert!(alpha > 0.0 && alpha < 1.0, "half-faded cover, got {alpha}");
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(
+                TAB_CONTENT_FADE_DURATION,
+            ));
+        app.update();
+        assert!(app.world().get_entity(cover).is_err());
+    }
 }
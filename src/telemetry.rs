@@ -7,6 +7,9 @@
 //! - The `BEVY_TELEMETRY` environment variable at runtime
 //! - The `TelemetryConfig` resource
 //!
+//! When enabled, `TelemetryPlugin` also records component interactions into the
+//! `TelemetryEvents` ring buffer, which tests can drain and assert on.
+//!
 //! # Example
 //! ```ignore
 //! // Enable telemetry programmatically
@@ -17,6 +20,9 @@
 //! ```
 
 use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// A safe deferred command that inserts a `TestId` only if the entity still exists.
 ///
@@ -47,7 +53,25 @@ impl Plugin for TelemetryPlugin {
     fn build(&self, app: &mut App) {
         // Initialize telemetry config from environment
         let enabled = std::env::var("BEVY_TELEMETRY").is_ok();
-        app.insert_resource(TelemetryConfig { enabled });
+        app.insert_resource(TelemetryConfig { enabled })
+            .init_resource::<TelemetryEvents>()
+            .init_resource::<TestIdIndex>()
+            .add_systems(
+                Update,
+                (
+                    test_id_index_system,
+                    record_interaction_telemetry_system::<crate::button::MaterialButton>,
+                    record_interaction_telemetry_system::<crate::icon_button::MaterialIconButton>,
+                    record_interaction_telemetry_system::<crate::fab::MaterialFab>,
+                    record_interaction_telemetry_system::<crate::chip::MaterialChip>,
+                    record_interaction_telemetry_system::<crate::checkbox::MaterialCheckbox>,
+                    record_interaction_telemetry_system::<crate::switch::MaterialSwitch>,
+                    record_interaction_telemetry_system::<crate::radio::MaterialRadio>,
+                    record_interaction_telemetry_system::<crate::tabs::MaterialTab>,
+                    record_interaction_telemetry_system::<crate::list::MaterialListItem>,
+                    record_interaction_telemetry_system::<crate::menu::MaterialMenuItem>,
+                ),
+            );
 
         if enabled {
             info!("📊 Telemetry enabled");
@@ -79,7 +103,7 @@ impl TelemetryConfig {
 /// This component allows test automation tools to find UI elements by a stable identifier.
 /// When telemetry is disabled, this component can still be added but won't be written
 /// to the telemetry output.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TestId(pub String);
 
 impl TestId {
@@ -94,6 +118,12 @@ impl TestId {
     }
 }
 
+impl Borrow<str> for TestId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 /// Extension trait to conditionally add TestId based on telemetry config
 pub trait WithTestId {
     /// Add a TestId component if telemetry is enabled, otherwise a no-op marker
@@ -156,6 +186,299 @@ impl ElementBounds {
     }
 }
 
+/// Index from [`TestId`] to the entity carrying it
+///
+/// Maintained by `TelemetryPlugin` while telemetry is enabled.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TestIdIndex {
+    entities: HashMap<TestId, Entity>,
+}
+
+impl TestIdIndex {
+    /// Entity registered under `id`
+    pub fn get(&self, id: &str) -> Option<Entity> {
+        self.entities.get(id).copied()
+    }
+
+    /// Number of indexed test IDs
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Iterate over all indexed `(TestId, Entity)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&TestId, Entity)> {
+        self.entities.iter().map(|(id, entity)| (id, *entity))
+    }
+
+    /// Register `entity` under `id`, dropping any previous ID of that entity
+    pub fn insert(&mut self, id: TestId, entity: Entity) {
+        self.remove_entity(entity);
+        self.entities.insert(id, entity);
+    }
+
+    /// Remove every entry pointing at `entity`
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.entities.retain(|_, e| *e != entity);
+    }
+}
+
+/// Keep [`TestIdIndex`] in sync with `TestId` components
+pub fn test_id_index_system(
+    config: Option<Res<TelemetryConfig>>,
+    index: Option<ResMut<TestIdIndex>>,
+    all: Query<(Entity, &TestId)>,
+    changed: Query<(Entity, &TestId), Changed<TestId>>,
+    mut removed: RemovedComponents<TestId>,
+) {
+    let Some(config) = config else {
+        return;
+    };
+    let Some(mut index) = index else {
+        return;
+    };
+    if !config.enabled {
+        removed.clear();
+        return;
+    }
+
+    for entity in removed.read() {
+        index.remove_entity(entity);
+    }
+
+    // Rebuild from scratch when telemetry was just turned on.
+    if config.is_changed() {
+        index.entities.clear();
+        for (entity, id) in all.iter() {
+            index.insert(id.clone(), entity);
+        }
+        return;
+    }
+
+    for (entity, id) in changed.iter() {
+        index.insert(id.clone(), entity);
+    }
+}
+
+/// Look up an element by test ID and compute its logical-pixel bounds
+///
+/// Returns `None` if the ID is not indexed or the entity has not been laid out yet.
+/// The returned bounds carry the nearest ancestor's test ID as `parent`.
+pub fn find_by_test_id(world: &World, id: &str) -> Option<(Entity, ElementBounds)> {
+    let entity = world.get_resource::<TestIdIndex>()?.get(id)?;
+    if world.get::<TestId>(entity)?.id() != id {
+        return None;
+    }
+
+    let computed = world.get::<ComputedNode>(entity)?;
+    let transform = world.get::<UiGlobalTransform>(entity)?;
+
+    // UiGlobalTransform is the node center; both values are in physical pixels.
+    let scale = computed.inverse_scale_factor();
+    let size = computed.size() * scale;
+    let top_left = transform.translation * scale - size / 2.0;
+
+    let mut bounds = ElementBounds::new(id, top_left.x, top_left.y, size.x, size.y);
+
+    let mut current = world.get::<ChildOf>(entity).map(|c| c.parent());
+    while let Some(ancestor) = current {
+        if let Some(parent_id) = world.get::<TestId>(ancestor) {
+            bounds = bounds.with_parent(parent_id.id());
+            break;
+        }
+        current = world.get::<ChildOf>(ancestor).map(|c| c.parent());
+    }
+
+    Some((entity, bounds))
+}
+
+/// Default number of records kept by [`TelemetryEvents`]
+pub const DEFAULT_TELEMETRY_CAPACITY: usize = 1024;
+
+/// Kind of interaction captured in a [`TelemetryRecord`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TelemetryEventKind {
+    /// Pointer entered the element
+    Hovered,
+    /// Pointer left the element
+    Unhovered,
+    /// Element was pressed
+    Pressed,
+    /// Element was pressed and released while still hovered
+    Clicked,
+    /// Application-defined event
+    Custom(String),
+}
+
+impl TelemetryEventKind {
+    /// Stable string name used in structured output
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Hovered => "hovered",
+            Self::Unhovered => "unhovered",
+            Self::Pressed => "pressed",
+            Self::Clicked => "clicked",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+/// A single timestamped telemetry record
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryRecord {
+    /// Seconds since app startup when the record was captured
+    pub timestamp: f64,
+    /// Entity the record refers to
+    pub entity: Entity,
+    /// Short component type name (e.g. `MaterialButton`)
+    pub component: String,
+    /// Test ID of the entity, if it has one
+    pub test_id: Option<String>,
+    /// What happened
+    pub kind: TelemetryEventKind,
+}
+
+impl TelemetryRecord {
+    /// Structured JSON representation of this record
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "timestamp": self.timestamp,
+            "entity": self.entity.to_bits(),
+            "component": self.component,
+            "test_id": self.test_id,
+            "kind": self.kind.as_str(),
+        })
+    }
+}
+
+/// Ring buffer of telemetry records
+///
+/// Once `capacity` records are stored, the oldest record is dropped for each new one.
+/// Automated tests can [`drain`](Self::drain) the buffer after driving the UI and assert
+/// on what happened.
+#[derive(Resource, Debug, Clone)]
+pub struct TelemetryEvents {
+    records: VecDeque<TelemetryRecord>,
+    capacity: usize,
+}
+
+impl Default for TelemetryEvents {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_TELEMETRY_CAPACITY)
+    }
+}
+
+impl TelemetryEvents {
+    /// Create an empty buffer holding at most `capacity` records
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            records: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Maximum number of records kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of records currently stored
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Push a record, evicting the oldest one when full
+    pub fn push(&mut self, record: TelemetryRecord) {
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// Push a record if telemetry is enabled
+    pub fn record(&mut self, config: &TelemetryConfig, record: TelemetryRecord) {
+        if config.enabled {
+            self.push(record);
+        }
+    }
+
+    /// Iterate over stored records, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &TelemetryRecord> {
+        self.records.iter()
+    }
+
+    /// Remove and return all stored records, oldest first
+    pub fn drain(&mut self) -> Vec<TelemetryRecord> {
+        self.records.drain(..).collect()
+    }
+
+    /// Discard all stored records
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Structured JSON array of all stored records
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.records.iter().map(TelemetryRecord::to_json).collect())
+    }
+}
+
+/// Short type name of `T` without its module path
+fn component_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Record interaction changes on components of type `T` into [`TelemetryEvents`]
+pub fn record_interaction_telemetry_system<T: Component>(
+    time: Res<Time>,
+    config: Option<Res<TelemetryConfig>>,
+    events: Option<ResMut<TelemetryEvents>>,
+    mut pressed: Local<HashSet<Entity>>,
+    changed: Query<(Entity, &Interaction, Option<&TestId>), (Changed<Interaction>, With<T>)>,
+) {
+    let Some(config) = config else {
+        return;
+    };
+    let Some(mut events) = events else {
+        return;
+    };
+    if !config.enabled {
+        return;
+    }
+
+    for (entity, interaction, test_id) in changed.iter() {
+        let was_pressed = pressed.remove(&entity);
+        let kind = match interaction {
+            Interaction::Pressed => {
+                pressed.insert(entity);
+                TelemetryEventKind::Pressed
+            }
+            Interaction::Hovered if was_pressed => TelemetryEventKind::Clicked,
+            Interaction::Hovered => TelemetryEventKind::Hovered,
+            Interaction::None => TelemetryEventKind::Unhovered,
+        };
+
+        events.push(TelemetryRecord {
+            timestamp: time.elapsed_secs_f64(),
+            entity,
+            component: component_name::<T>().to_string(),
+            test_id: test_id.map(|id| id.0.clone()),
+            kind,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +538,88 @@ mod tests {
         let bounds = ElementBounds::new("child", 0.0, 0.0, 50.0, 50.0).with_parent("parent");
         assert_eq!(bounds.parent, Some("parent".to_string()));
     }
+
+    fn record(kind: TelemetryEventKind) -> TelemetryRecord {
+        TelemetryRecord {
+            timestamp: 0.0,
+            entity: Entity::from_raw_u32(1).unwrap(),
+            component: "MaterialButton".to_string(),
+            test_id: Some("ok_button".to_string()),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_telemetry_events_ring_buffer() {
+        let mut events = TelemetryEvents::with_capacity(2);
+        events.push(record(TelemetryEventKind::Hovered));
+        events.push(record(TelemetryEventKind::Pressed));
+        events.push(record(TelemetryEventKind::Clicked));
+
+        assert_eq!(events.len(), 2);
+        let drained = events.drain();
+        assert_eq!(drained[0].kind, TelemetryEventKind::Pressed);
+        assert_eq!(drained[1].kind, TelemetryEventKind::Clicked);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_telemetry_events_gated_by_config() {
+        let mut events = TelemetryEvents::default();
+        events.record(
+            &TelemetryConfig::disabled(),
+            record(TelemetryEventKind::Clicked),
+        );
+        assert!(events.is_empty());
+
+        events.record(
+            &TelemetryConfig::enabled(),
+            record(TelemetryEventKind::Clicked),
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.to_json()[0]["kind"], "clicked");
+    }
+
+    #[test]
+    fn test_component_name_strips_path() {
+        assert_eq!(
+            component_name::<crate::button::MaterialButton>(),
+            "MaterialButton"
+        );
+    }
+
+    #[test]
+    fn test_test_id_index_replaces_entity_entry() {
+        let entity = Entity::from_raw_u32(3).unwrap();
+        let mut index = TestIdIndex::default();
+        index.insert(TestId::new("old"), entity);
+        index.insert(TestId::new("submit-button"), entity);
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.get("old"), None);
+        assert_eq!(index.get("submit-button"), Some(entity));
+    }
+
+    #[test]
+    fn test_find_by_test_id() {
+        let mut world = World::new();
+        let parent = world.spawn(TestId::new("form")).id();
+        let child = world
+            .spawn((
+                TestId::new("submit-button"),
+                Node::default(),
+                ChildOf(parent),
+            ))
+            .id();
+
+        let mut index = TestIdIndex::default();
+        index.insert(TestId::new("form"), parent);
+        index.insert(TestId::new("submit-button"), child);
+        world.insert_resource(index);
+
+        let (entity, bounds) = find_by_test_id(&world, "submit-button").unwrap();
+        assert_eq!(entity, child);
+        assert_eq!(bounds.parent.as_deref(), Some("form"));
+        assert!(find_by_test_id(&world, "missing").is_none());
+    }
 }
@@ -10,8 +10,10 @@ use crate::{
     icons::{icon_by_name, IconStyle, MaterialIcon, ICON_CLOSE},
     locale::{DateFieldOrder, DateInputPattern},
     ripple::RippleHost,
+    semantics::{sync_semantics_system, Role, Semantic},
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
+    typography::TypeRole,
 };
 
 #[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
@@ -62,6 +64,7 @@ impl Plugin for TextFieldPlugin {
             .add_systems(
                 Update,
                 (
+                    text_field_theme_refresh_system,
                     text_field_focus_system,
                     text_field_end_icon_click_system,
                     text_field_input_system,
@@ -76,7 +79,8 @@ impl Plugin for TextFieldPlugin {
                     text_field_style_system,
                 )
                     .chain(),
-            );
+            )
+            .add_systems(Update, sync_semantics_system::<MaterialTextField>);
     }
 }
 
@@ -275,8 +279,15 @@ pub enum InputType {
     Url,
     /// Multi-line text input
     Multiline,
+    /// Date input, masked by the field's `date_pattern` (e.g. `MM/DD/YYYY`)
+    Date,
+    /// 24-hour time input, masked as `HH:MM`
+    Time,
 }
 
+/// Digit slot in an input mask
+const INPUT_MASK_DIGIT: char = '#';
+
 /// Material text field component
 ///
 /// Matches properties from the reference `TextInputLayout`:
@@ -295,6 +306,8 @@ pub struct MaterialTextField {
     pub variant: TextFieldVariant,
     /// Current text value
     pub value: String,
+    /// Caret position as a character index into `value` (`None` = end of the text)
+    pub caret: Option<usize>,
     /// Placeholder/hint text (shown when empty and unfocused)
     pub placeholder: String,
     /// Label text (floats above when focused/has content)
@@ -340,6 +353,8 @@ pub struct MaterialTextField {
     pub box_corner_radius: Option<f32>,
     /// Input type (affects keyboard and visibility)
     pub input_type: InputType,
+    /// Date layout used by [`InputType::Date`]
+    pub date_pattern: DateInputPattern,
 }
 
 impl MaterialTextField {
@@ -348,6 +363,7 @@ impl MaterialTextField {
         Self {
             variant: TextFieldVariant::default(),
             value: String::new(),
+            caret: None,
             placeholder: String::new(),
             label: None,
             supporting_text: None,
@@ -370,6 +386,7 @@ impl MaterialTextField {
             box_stroke_width_focused: 2.0,
             box_corner_radius: None,
             input_type: InputType::default(),
+            date_pattern: DateInputPattern::new(DateFieldOrder::Mdy, '/'),
         }
     }
 
@@ -501,6 +518,179 @@ impl MaterialTextField {
         self
     }
 
+    /// Set the date layout used by [`InputType::Date`]
+    pub fn date_pattern(mut self, pattern: DateInputPattern) -> Self {
+        self.date_pattern = pattern;
+        self
+    }
+
+    /// Mask applied while typing, with `#` marking digit slots.
+    ///
+    /// Only date and time inputs are masked.
+    pub fn input_mask(&self) -> Option<String> {
+        match self.input_type {
+            InputType::Date => Some(
+                self.date_pattern
+                    .hint()
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphabetic() {
+                            INPUT_MASK_DIGIT
+                        } else {
+                            c
+                        }
+                    })
+                    .collect(),
+            ),
+            InputType::Time => Some("##:##".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Human-readable format of a masked input (e.g. `MM/DD/YYYY`)
+    pub fn input_mask_hint(&self) -> Option<String> {
+        match self.input_type {
+            InputType::Date => Some(self.date_pattern.hint()),
+            InputType::Time => Some("HH:MM".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Whether the value fills the whole input mask
+    pub fn is_input_complete(&self) -> bool {
+        self.input_mask()
+            .is_none_or(|mask| self.value.chars().count() >= mask.chars().count())
+    }
+
+    /// Whether a complete date or time value names a real date or time.
+    ///
+    /// Always `true` for unmasked input types.
+    pub fn is_input_valid(&self) -> bool {
+        match self.input_type {
+            InputType::Date => is_valid_complete_date_by_pattern(&self.value, self.date_pattern),
+            InputType::Time => self
+                .value
+                .split_once(':')
+                .and_then(|(h, m)| Some((h.parse::<u8>().ok()?, m.parse::<u8>().ok()?)))
+                .is_some_and(|(h, m)| self.value.len() == 5 && h < 24 && m < 60),
+            _ => true,
+        }
+    }
+
+    /// Caret position as a character index, clamped to the value
+    pub fn caret_index(&self) -> usize {
+        let len = self.value.chars().count();
+        self.caret.map_or(len, |caret| caret.min(len))
+    }
+
+    /// Move the caret to a character index.
+    ///
+    /// Masked inputs are edited at the end only, so their caret stays there.
+    pub fn set_caret(&mut self, index: usize) {
+        let caret =
+            (index < self.value.chars().count() && self.input_mask().is_none()).then_some(index);
+        if self.caret != caret {
+            self.caret = caret;
+        }
+    }
+
+    /// Byte offset of the caret in `value`
+    fn caret_byte_offset(&self) -> usize {
+        self.value
+            .char_indices()
+            .nth(self.caret_index())
+            .map_or(self.value.len(), |(offset, _)| offset)
+    }
+
+    /// Insert a typed character at the caret, applying the input type's restrictions.
+    ///
+    /// Masked inputs accept digits only, append them, and insert separators
+    /// automatically. Returns whether the value changed.
+    pub fn push_input_char(&mut self, ch: char) -> bool {
+        if !is_allowed_input_char(self, ch) {
+            return false;
+        }
+
+        let Some(mask) = self.input_mask() else {
+            if self
+                .max_length
+                .is_some_and(|max| self.value.chars().count() >= max)
+            {
+                return false;
+            }
+            let caret = self.caret_index();
+            let offset = self.caret_byte_offset();
+            self.value.insert(offset, ch);
+            self.set_caret(caret + 1);
+            return true;
+        };
+
+        let mask: Vec<char> = mask.chars().collect();
+        let mut pos = self.value.chars().count();
+        if mask[pos] != INPUT_MASK_DIGIT && ch.is_ascii_digit() {
+            // Typing a digit where a separator goes inserts the separator first.
+            self.value.push(mask[pos]);
+            pos += 1;
+        }
+        self.value.push(ch);
+        pos += 1;
+        if let Some(&separator) = mask.get(pos).filter(|c| **c != INPUT_MASK_DIGIT) {
+            self.value.push(separator);
+        }
+        true
+    }
+
+    /// Delete the character before the caret, along with a separator it
+    /// leaves dangling in masked inputs. Returns whether the value changed.
+    pub fn pop_input_char(&mut self) -> bool {
+        if self.input_mask().is_some() {
+            if self.value.pop().is_none() {
+                return false;
+            }
+            while self.value.ends_with(|c: char| !c.is_ascii_digit()) {
+                self.value.pop();
+            }
+            return true;
+        }
+
+        let caret = self.caret_index();
+        if caret == 0 {
+            return false;
+        }
+        self.set_caret(caret - 1);
+        let offset = self.caret_byte_offset();
+        self.value.remove(offset);
+        true
+    }
+
+    /// Insert pasted text at the caret.
+    ///
+    /// Characters the input type doesn't allow are skipped, newlines become
+    /// spaces outside multiline fields, and insertion stops once `max_length`
+    /// is reached. Returns whether the value changed.
+    pub fn paste_text(&mut self, text: &str) -> bool {
+        let multiline = self.input_type == InputType::Multiline;
+        let mut changed = false;
+
+        for ch in text.chars() {
+            let ch = match ch {
+                '\r' if multiline => continue,
+                '\n' if multiline => '\n',
+                '\n' | '\r' => ' ',
+                ch if ch.is_control() => continue,
+                ch => ch,
+            };
+            if self
+                .max_length
+                .is_some_and(|max| self.value.chars().count() >= max)
+            {
+                break;
+            }
+            changed |= self.push_input_char(ch);
+        }
+        changed
+    }
+
     /// Get current character count for counter display
     pub fn character_count(&self) -> usize {
         self.value.chars().count()
@@ -664,6 +854,24 @@ impl Default for MaterialTextField {
     }
 }
 
+impl Semantic for MaterialTextField {
+    fn semantic_role(&self) -> Role {
+        Role::TextField
+    }
+
+    /// The floating label, falling back to the placeholder
+    fn semantic_label(&self) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| self.placeholder.clone())
+    }
+
+    /// The current text; password fields never expose their contents
+    fn semantic_value(&self) -> Option<String> {
+        (!matches!(self.input_type, InputType::Password)).then(|| self.value.clone())
+    }
+}
+
 /// Event when text field value changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct TextFieldChangeEvent {
@@ -740,14 +948,41 @@ fn text_field_formatter_system(
         };
 
         match *formatter {
-            TextFieldFormatter::None => {}
+            TextFieldFormatter::None => {
+                let Some(hint) = field.input_mask_hint() else {
+                    continue;
+                };
+
+                // Don't validate until the mask is filled.
+                if field.value.is_empty() || !field.is_input_complete() {
+                    if state.format_error {
+                        field.error = false;
+                        field.error_text = None;
+                        state.format_error = false;
+                    }
+                    continue;
+                }
+
+                if !field.is_input_valid() {
+                    field.error = true;
+                    field.error_text = Some(format!("Invalid format. Use {hint}"));
+                    state.format_error = true;
+                } else if state.format_error {
+                    field.error = false;
+                    field.error_text = None;
+                    state.format_error = false;
+                }
+            }
             TextFieldFormatter::DateMmDdYyyy => {
                 let pattern = DateInputPattern::new(DateFieldOrder::Mdy, '/');
 
-                // Normalize (auto-insert delimiters).
-                let normalized = normalize_date_by_pattern(&field.value, pattern);
-                if field.value != normalized {
-                    field.value = normalized;
+                // Normalize (auto-insert delimiters). Masked date inputs are
+                // already formatted as they are typed.
+                if field.input_type != InputType::Date {
+                    let normalized = normalize_date_by_pattern(&field.value, pattern);
+                    if field.value != normalized {
+                        field.value = normalized;
+                    }
                 }
                 field.has_content = !field.value.is_empty();
 
@@ -773,10 +1008,13 @@ fn text_field_formatter_system(
                 }
             }
             TextFieldFormatter::DatePattern(pattern) => {
-                // Normalize (auto-insert delimiters).
-                let normalized = normalize_date_by_pattern(&field.value, pattern);
-                if field.value != normalized {
-                    field.value = normalized;
+                // Normalize (auto-insert delimiters). Masked date inputs are
+                // already formatted as they are typed.
+                if field.input_type != InputType::Date {
+                    let normalized = normalize_date_by_pattern(&field.value, pattern);
+                    if field.value != normalized {
+                        field.value = normalized;
+                    }
                 }
                 field.has_content = !field.value.is_empty();
 
@@ -817,8 +1055,19 @@ fn text_field_focus_system(
         Query<(Entity, &Interaction), (Changed<Interaction>, With<MaterialTextField>)>,
         Query<(Entity, &mut MaterialTextField), With<MaterialTextField>>,
     )>,
+    other_presses: Query<
+        (Entity, &Interaction),
+        (Changed<Interaction>, Without<MaterialTextField>),
+    >,
+    child_of: Query<&ChildOf>,
+    suffixes: Query<(), With<TextFieldSuffix>>,
 ) {
-    let mut activated_this_frame = false;
+    // Presses inside a suffix (e.g. a unit selector) keep the current focus.
+    let mut activated_this_frame = other_presses.iter().any(|(entity, interaction)| {
+        *interaction == Interaction::Pressed
+            && std::iter::successors(Some(entity), |e| child_of.get(*e).ok().map(ChildOf::parent))
+                .any(|e| suffixes.contains(e))
+    });
 
     // Determine which field was pressed this frame.
     for (entity, interaction) in fields.p0().iter_mut() {
@@ -914,8 +1163,7 @@ fn text_field_input_system(
 
     let mut changed = false;
 
-    // Clipboard shortcuts (desktop): Ctrl/Cmd + C/X/V.
-    // Current text field input is append-only (caret at end), so paste appends.
+    // Clipboard shortcuts (desktop): Ctrl/Cmd + C/X/V. Paste inserts at the caret.
     let modifier_down = keys.pressed(KeyCode::ControlLeft)
         || keys.pressed(KeyCode::ControlRight)
         || keys.pressed(KeyCode::SuperLeft)
@@ -932,6 +1180,7 @@ fn text_field_input_system(
             clipboard.set_text(field.value.clone());
             if !field.value.is_empty() {
                 field.value.clear();
+                field.caret = None;
                 changed = true;
             }
         }
@@ -939,41 +1188,29 @@ fn text_field_input_system(
         // Paste
         if keys.just_pressed(KeyCode::KeyV) {
             if let Some(text) = clipboard.get_text() {
-                for mut ch in text.chars() {
-                    // Normalize newlines for single-line inputs.
-                    if ch == '\n' || ch == '\r' {
-                        if field.input_type == InputType::Multiline {
-                            // keep
-                        } else {
-                            ch = ' ';
-                        }
-                    }
-
-                    if ch.is_control() {
-                        continue;
-                    }
-
-                    if !is_allowed_input_char(&field, ch) {
-                        continue;
-                    }
-
-                    if let Some(max) = field.max_length {
-                        if field.value.chars().count() >= max {
-                            break;
-                        }
-                    }
-
-                    field.value.push(ch);
-                    changed = true;
-                }
+                changed |= field.paste_text(&text);
             }
         }
     }
 
     // Backspace
-    if keys.just_pressed(KeyCode::Backspace) && !field.value.is_empty() {
-        field.value.pop();
-        changed = true;
+    if keys.just_pressed(KeyCode::Backspace) {
+        changed |= field.pop_input_char();
+    }
+
+    // Caret movement
+    let caret = field.caret_index();
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        field.set_caret(caret.saturating_sub(1));
+    }
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        field.set_caret(caret + 1);
+    }
+    if keys.just_pressed(KeyCode::Home) {
+        field.set_caret(0);
+    }
+    if keys.just_pressed(KeyCode::End) {
+        field.caret = None;
     }
 
     // Text entry
@@ -998,18 +1235,7 @@ fn text_field_input_system(
                 continue;
             }
 
-            if !is_allowed_input_char(&field, ch) {
-                continue;
-            }
-
-            if let Some(max) = field.max_length {
-                if field.value.chars().count() >= max {
-                    break;
-                }
-            }
-
-            field.value.push(ch);
-            changed = true;
+            changed |= field.push_input_char(ch);
         }
     }
 
@@ -1025,11 +1251,7 @@ fn text_field_input_system(
     // Submit / newline
     if keys.just_pressed(KeyCode::Enter) {
         if field.input_type == InputType::Multiline {
-            if field
-                .max_length
-                .is_none_or(|max| field.value.chars().count() < max)
-            {
-                field.value.push('\n');
+            if field.push_input_char('\n') {
                 field.has_content = !field.value.is_empty();
                 change_events.write(TextFieldChangeEvent {
                     entity,
@@ -1053,7 +1275,10 @@ fn is_allowed_input_char(field: &MaterialTextField, ch: char) -> bool {
             }
 
             // Allow a leading sign character.
-            if (ch == '-' || ch == '+') && field.value.is_empty() {
+            if (ch == '-' || ch == '+')
+                && field.caret_index() == 0
+                && !field.value.starts_with(['-', '+'])
+            {
                 return true;
             }
 
@@ -1063,11 +1288,36 @@ fn is_allowed_input_char(field: &MaterialTextField, ch: char) -> bool {
             // Keep this permissive for typical phone number formats.
             ch.is_ascii_digit() || matches!(ch, ' ' | '+' | '-' | '(' | ')')
         }
+        InputType::Date | InputType::Time => {
+            // Digits, or the separator due at the caret, while the mask has room.
+            let Some(slot) = field
+                .input_mask()
+                .and_then(|mask| mask.chars().nth(field.value.chars().count()))
+            else {
+                return false;
+            };
+            ch.is_ascii_digit() || (slot != INPUT_MASK_DIGIT && ch == slot)
+        }
         // For other input types we currently accept any non-control characters.
         _ => true,
     }
 }
 
+/// Mark all text fields changed when the theme changes so the style systems re-run.
+fn text_field_theme_refresh_system(
+    theme: Option<Res<MaterialTheme>>,
+    mut fields: Query<&mut MaterialTextField>,
+) {
+    let Some(theme) = theme else { return };
+    if !theme.is_changed() {
+        return;
+    }
+
+    for mut field in fields.iter_mut() {
+        field.set_changed();
+    }
+}
+
 /// Blink the caret for focused text fields.
 fn text_field_caret_blink_system(
     time: Res<Time>,
@@ -1111,8 +1361,8 @@ fn text_field_display_system(
             field.placeholder.as_str()
         };
 
-        // Inline caret: render it as part of the input text so it appears right after the
-        // last glyph instead of being pushed to the far right by flex layout.
+        // Inline caret: render it as part of the input text at the caret position instead
+        // of letting flex layout push it to the far right.
         let (display, color) = if field.value.is_empty() {
             if field.is_label_floating() {
                 // Label is floating (focused or has content). If empty, show just the caret.
@@ -1139,8 +1389,14 @@ fn text_field_display_system(
             };
 
             if field.focused {
+                let (before, after) = shown_value
+                    .char_indices()
+                    .nth(field.caret_index())
+                    .map_or((shown_value.as_str(), ""), |(offset, _)| {
+                        shown_value.split_at(offset)
+                    });
                 (
-                    format!("{}{}", shown_value, caret),
+                    format!("{}{}{}", before, caret, after),
                     field.input_color(&theme),
                 )
             } else {
@@ -1279,12 +1535,16 @@ fn text_field_style_system(
     }
 }
 
+/// Spawns custom content into a text field slot
+type TextFieldSlotContent = Box<dyn FnOnce(&mut ChildSpawnerCommands)>;
+
 /// Builder for text fields
 pub struct TextFieldBuilder {
     text_field: MaterialTextField,
     width: Val,
     formatter: TextFieldFormatter,
     localization: TextFieldLocalization,
+    suffix: Option<TextFieldSlotContent>,
 }
 
 impl TextFieldBuilder {
@@ -1295,6 +1555,7 @@ impl TextFieldBuilder {
             width: Val::Px(TEXT_FIELD_MIN_WIDTH),
             formatter: TextFieldFormatter::None,
             localization: TextFieldLocalization::default(),
+            suffix: None,
         }
     }
 
@@ -1310,10 +1571,11 @@ impl TextFieldBuilder {
     }
 
     /// Convenience: set up a date field with a specific input pattern.
-    pub fn date_pattern(self, pattern: DateInputPattern) -> Self {
+    pub fn date_pattern(mut self, pattern: DateInputPattern) -> Self {
+        self.text_field.date_pattern = pattern;
         self.formatter(TextFieldFormatter::DatePattern(pattern))
+            .input_type(InputType::Date)
             .placeholder(pattern.hint())
-            .input_type(InputType::Number)
             .max_length(pattern.formatted_len())
     }
 
@@ -1399,6 +1661,12 @@ impl TextFieldBuilder {
         {
             self.text_field.end_icon_mode = EndIconMode::PasswordToggle;
         }
+        // Show the expected format for masked fields
+        if let Some(hint) = self.text_field.input_mask_hint() {
+            if self.text_field.placeholder.is_empty() {
+                self.text_field.placeholder = hint;
+            }
+        }
         self
     }
 
@@ -1450,14 +1718,29 @@ impl TextFieldBuilder {
         self
     }
 
+    /// Spawn custom content (e.g. a unit or currency selector) into the suffix
+    /// area, between the input and the trailing icon.
+    ///
+    /// The input shrinks to make room for the suffix. Pressing inside the
+    /// suffix doesn't move keyboard focus away from the field.
+    ///
+    /// Used by the spawn helpers ([`SpawnTextFieldChild::spawn_text_field_with`],
+    /// [`spawn_text_field_control`]); [`build`](Self::build) ignores it.
+    pub fn suffix(mut self, spawn: impl FnOnce(&mut ChildSpawnerCommands) + 'static) -> Self {
+        self.suffix = Some(Box::new(spawn));
+        self
+    }
+
     /// Build the text field bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         let bg_color = self.text_field.container_color(theme);
         let border_color = self.text_field.indicator_color(theme);
         let is_outlined = self.text_field.variant == TextFieldVariant::Outlined;
+        let semantics = self.text_field.semantics();
 
         (
             self.text_field,
+            semantics,
             self.formatter,
             TextFieldFormatState::default(),
             self.localization,
@@ -1529,6 +1812,14 @@ pub struct TextFieldLeadingIcon;
 #[derive(Component)]
 pub struct TextFieldLeadingIconFor(pub Entity);
 
+/// Marker for the suffix area holding [`TextFieldBuilder::suffix`] content.
+#[derive(Component)]
+pub struct TextFieldSuffix;
+
+/// Links a suffix area entity to its owning text field entity.
+#[derive(Component)]
+pub struct TextFieldSuffixFor(pub Entity);
+
 /// Marker for the end icon button (trailing icon).
 #[derive(Component)]
 pub struct TextFieldEndIconButton;
@@ -1614,7 +1905,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
         );
     }
 
-    fn spawn_text_field_with(&mut self, theme: &MaterialTheme, builder: TextFieldBuilder) {
+    fn spawn_text_field_with(&mut self, theme: &MaterialTheme, mut builder: TextFieldBuilder) {
         let label_text = builder.text_field.label.clone();
         let value_text = builder.text_field.value.clone();
         let placeholder_text = builder.text_field.placeholder.clone();
@@ -1644,6 +1935,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
 
         let should_spawn_supporting =
             !supporting_display.is_empty() || builder.localization.needs_supporting_entity();
+        let suffix = builder.suffix.take();
 
         // Wrapper so supporting/error text can appear below the 56px field.
         self.spawn(Node {
@@ -1713,10 +2005,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
                                 TextFieldLabel,
                                 TextFieldLabelFor(field_entity),
                                 Text::new(label.as_str()),
-                                TextFont {
-                                    font_size: 12.0,
-                                    ..default()
-                                },
+                                TypeRole::BodySmall.text_bundle(),
                                 TextColor(label_color),
                                 Node {
                                     display: if initial_is_label_floating {
@@ -1772,10 +2061,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
                                     TextFieldPlaceholder,
                                     TextFieldPlaceholderFor(field_entity),
                                     Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(placeholder_color),
                                     Node {
                                         position_type: PositionType::Absolute,
@@ -1793,15 +2079,28 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
                                     TextFieldInput,
                                     TextFieldInputFor(field_entity),
                                     Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(initial_color),
                                 ));
                             });
                     });
 
+                // Custom suffix content (kept at its own width; the input shrinks)
+                if let Some(spawn_suffix) = suffix {
+                    container
+                        .spawn((
+                            TextFieldSuffix,
+                            TextFieldSuffixFor(field_entity),
+                            Node {
+                                flex_shrink: 0.0,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::left(Val::Px(Spacing::SMALL)),
+                                ..default()
+                            },
+                        ))
+                        .with_children(spawn_suffix);
+                }
+
                 // End icon (trailing icon)
                 let end_icon_visible = end_icon_text.as_deref().and_then(resolve_icon_id).is_some();
                 container
@@ -1846,10 +2145,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
                     TextFieldSupportingText,
                     TextFieldSupportingFor(field_entity),
                     Text::new(supporting_display),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
+                    TypeRole::BodySmall.text_bundle(),
                     TextColor(supporting_color),
                     Node {
                         margin: UiRect::left(Val::Px(Spacing::LARGE)),
@@ -1871,7 +2167,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
 pub fn spawn_text_field_control(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
-    builder: TextFieldBuilder,
+    mut builder: TextFieldBuilder,
 ) -> Entity {
     // Duplicate the SpawnTextFieldChild implementation, but return the field entity.
     let label_text: Option<String> = builder.text_field.label.clone();
@@ -1903,6 +2199,7 @@ pub fn spawn_text_field_control(
 
     let should_spawn_supporting =
         !supporting_display.is_empty() || builder.localization.needs_supporting_entity();
+    let suffix = builder.suffix.take();
 
     let mut spawned_field: Option<Entity> = None;
     parent
@@ -1974,10 +2271,7 @@ pub fn spawn_text_field_control(
                                 TextFieldLabel,
                                 TextFieldLabelFor(field_entity),
                                 Text::new(label.as_str()),
-                                TextFont {
-                                    font_size: 12.0,
-                                    ..default()
-                                },
+                                TypeRole::BodySmall.text_bundle(),
                                 TextColor(label_color),
                                 Node {
                                     display: if initial_is_label_floating {
@@ -2033,10 +2327,7 @@ pub fn spawn_text_field_control(
                                     TextFieldPlaceholder,
                                     TextFieldPlaceholderFor(field_entity),
                                     Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(placeholder_color),
                                     Node {
                                         position_type: PositionType::Absolute,
@@ -2054,15 +2345,28 @@ pub fn spawn_text_field_control(
                                     TextFieldInput,
                                     TextFieldInputFor(field_entity),
                                     Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(initial_color),
                                 ));
                             });
                     });
 
+                // Custom suffix content (kept at its own width; the input shrinks)
+                if let Some(spawn_suffix) = suffix {
+                    container
+                        .spawn((
+                            TextFieldSuffix,
+                            TextFieldSuffixFor(field_entity),
+                            Node {
+                                flex_shrink: 0.0,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::left(Val::Px(Spacing::SMALL)),
+                                ..default()
+                            },
+                        ))
+                        .with_children(spawn_suffix);
+                }
+
                 // End icon (trailing icon)
                 let end_icon_visible = end_icon_text.as_deref().and_then(resolve_icon_id).is_some();
                 container
@@ -2107,10 +2411,7 @@ pub fn spawn_text_field_control(
                     TextFieldSupportingText,
                     TextFieldSupportingFor(field_entity),
                     Text::new(supporting_display),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
+                    TypeRole::BodySmall.text_bundle(),
                     TextColor(supporting_color),
                     Node {
                         margin: UiRect::left(Val::Px(Spacing::LARGE)),
@@ -2128,7 +2429,7 @@ pub fn spawn_text_field_control(
 pub fn spawn_text_field_control_with<M: Component>(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
-    builder: TextFieldBuilder,
+    mut builder: TextFieldBuilder,
     marker: M,
 ) -> Entity {
     // Copy the control spawn logic so we can insert the marker at spawn-time.
@@ -2161,6 +2462,7 @@ pub fn spawn_text_field_control_with<M: Component>(
 
     let should_spawn_supporting =
         !supporting_display.is_empty() || builder.localization.needs_supporting_entity();
+    let suffix = builder.suffix.take();
 
     let mut spawned_field: Option<Entity> = None;
     parent
@@ -2233,10 +2535,7 @@ pub fn spawn_text_field_control_with<M: Component>(
                                 TextFieldLabel,
                                 TextFieldLabelFor(field_entity),
                                 Text::new(label.as_str()),
-                                TextFont {
-                                    font_size: 12.0,
-                                    ..default()
-                                },
+                                TypeRole::BodySmall.text_bundle(),
                                 TextColor(label_color),
                                 Node {
                                     display: if initial_is_label_floating {
@@ -2292,10 +2591,7 @@ pub fn spawn_text_field_control_with<M: Component>(
                                     TextFieldPlaceholder,
                                     TextFieldPlaceholderFor(field_entity),
                                     Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(placeholder_color),
                                     Node {
                                         position_type: PositionType::Absolute,
@@ -2313,15 +2609,28 @@ pub fn spawn_text_field_control_with<M: Component>(
                                     TextFieldInput,
                                     TextFieldInputFor(field_entity),
                                     Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
+                                    TypeRole::BodyLarge.text_bundle(),
                                     TextColor(initial_color),
                                 ));
                             });
                     });
 
+                // Custom suffix content (kept at its own width; the input shrinks)
+                if let Some(spawn_suffix) = suffix {
+                    container
+                        .spawn((
+                            TextFieldSuffix,
+                            TextFieldSuffixFor(field_entity),
+                            Node {
+                                flex_shrink: 0.0,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::left(Val::Px(Spacing::SMALL)),
+                                ..default()
+                            },
+                        ))
+                        .with_children(spawn_suffix);
+                }
+
                 // End icon (trailing icon)
                 let end_icon_visible = end_icon_text.as_deref().and_then(resolve_icon_id).is_some();
                 container
@@ -2366,10 +2675,7 @@ pub fn spawn_text_field_control_with<M: Component>(
                     TextFieldSupportingText,
                     TextFieldSupportingFor(field_entity),
                     Text::new(supporting_display),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
+                    TypeRole::BodySmall.text_bundle(),
                     TextColor(supporting_color),
                     Node {
                         margin: UiRect::left(Val::Px(Spacing::LARGE)),
@@ -2403,15 +2709,13 @@ fn text_field_end_icon_click_system(
             EndIconMode::PasswordToggle => {
                 field.toggle_password_visibility();
             }
-            EndIconMode::ClearText => {
-                if !field.value.is_empty() {
-                    field.value.clear();
-                    field.has_content = false;
-                    click_events.write(TextFieldChangeEvent {
-                        entity: *field_entity,
-                        value: field.value.clone(),
-                    });
-                }
+            EndIconMode::ClearText if !field.value.is_empty() => {
+                field.value.clear();
+                field.has_content = false;
+                click_events.write(TextFieldChangeEvent {
+                    entity: *field_entity,
+                    value: field.value.clone(),
+                });
             }
             _ => {}
         }
@@ -2496,3 +2800,84 @@ fn text_field_icon_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{apply_set_theme_system, SetTheme, ThemeMode};
+
+    #[test]
+    fn test_set_theme_restyles_fields_in_the_same_frame() {
+        let mut app = App::new();
+        app.insert_resource(MaterialTheme::light())
+            .add_message::<SetTheme>()
+            .add_systems(PreUpdate, apply_set_theme_system)
+            .add_systems(
+                Update,
+                (text_field_theme_refresh_system, text_field_style_system).chain(),
+            );
+
+        let light = MaterialTheme::light();
+        let field = app
+            .world_mut()
+            .spawn(TextFieldBuilder::new().filled().build(&light))
+            .id();
+        app.update();
+
+        let seed = Color::srgb_u8(0x1B, 0x6E, 0x4A);
+        app.world_mut().write_message(SetTheme::Seed(seed));
+        app.update();
+
+        let seeded = MaterialTheme::from_seed(seed, ThemeMode::Light);
+        let text_field = app.world().get::<MaterialTextField>(field).unwrap();
+        assert_eq!(
+            app.world().get::<BackgroundColor>(field).unwrap().0,
+            text_field.container_color(&seeded)
+        );
+        assert_eq!(
+            *app.world().get::<BorderColor>(field).unwrap(),
+            BorderColor::all(text_field.indicator_color(&seeded))
+        );
+        assert_ne!(
+            text_field.indicator_color(&seeded),
+            text_field.indicator_color(&light)
+        );
+    }
+
+    #[test]
+    fn test_suffix_press_keeps_field_focus() {
+        let mut app = App::new();
+        app.add_message::<bevy::input::keyboard::KeyboardInput>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ActiveTextField>()
+            .add_systems(Update, text_field_focus_system);
+
+        let field = app
+            .world_mut()
+            .spawn((MaterialTextField::new(), Interaction::None))
+            .id();
+        let suffix = app
+            .world_mut()
+            .spawn((TextFieldSuffix, TextFieldSuffixFor(field), ChildOf(field)))
+            .id();
+        let unit_button = app
+            .world_mut()
+            .spawn((Button, Interaction::None, ChildOf(suffix)))
+            .id();
+        let elsewhere = app.world_mut().spawn((Button, Interaction::None)).id();
+        app.world_mut().resource_mut::<ActiveTextField>().0 = Some(field);
+        app.update();
+
+        let press = |app: &mut App, target: Entity| {
+            let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+            mouse.reset_all();
+            mouse.press(MouseButton::Left);
+            *app.world_mut().get_mut::<Interaction>(target).unwrap() = Interaction::Pressed;
+            app.update();
+            app.world().resource::<ActiveTextField>().0
+        };
+        assert_eq!(press(&mut app, unit_button), Some(field));
+        assert!(app.world().get::<MaterialTextField>(field).unwrap().focused);
+        assert_eq!(press(&mut app, elsewhere), None);
+    }
+}
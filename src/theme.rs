@@ -5,10 +5,14 @@
 
 use bevy::prelude::*;
 
-use crate::color::MaterialColorScheme;
+use crate::color::{harmonize, MaterialColorScheme, SchemeVariant};
+
+/// Seed color of the built-in light/dark themes (Material You purple, #6750A4)
+pub const DEFAULT_SEED_COLOR: Color = Color::srgb(0.404, 0.314, 0.643);
 
 /// Theme mode (light or dark)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThemeMode {
     /// Light theme
     Light,
@@ -19,6 +23,7 @@ pub enum ThemeMode {
 
 /// Color scheme variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorScheme {
     /// Default Material You purple/violet scheme
     #[default]
@@ -27,6 +32,22 @@ pub enum ColorScheme {
     Custom,
 }
 
+/// Message to swap the active [`MaterialTheme`] at runtime.
+///
+/// Component theme systems key off `theme.is_changed()`, so every spawned
+/// component re-styles on the frame the message is applied.
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub enum SetTheme {
+    /// Replace the whole theme
+    Theme(Box<MaterialTheme>),
+    /// Switch to light or dark, keeping seed, variant and contrast
+    Mode(ThemeMode),
+    /// Toggle between light and dark
+    ToggleMode,
+    /// Regenerate from a new seed color, keeping mode, variant and contrast
+    Seed(Color),
+}
+
 /// Material Design 3 Theme Resource
 ///
 /// Contains all color tokens for the Material Design 3 color system.
@@ -50,88 +71,132 @@ pub enum ColorScheme {
 /// }
 /// ```
 #[derive(Resource, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaterialTheme {
     /// Current theme mode
     pub mode: ThemeMode,
+    /// Seed color the color roles are generated from
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
+    pub seed: Color,
+    /// Dynamic color scheme variant
+    pub variant: SchemeVariant,
+    /// Contrast level from -1.0 (reduced) through 0.0 (standard) to 1.0 (high).
+    ///
+    /// Use 0.5 for MD3's "medium" contrast. Change it with [`MaterialTheme::set_contrast`]
+    /// so the color roles are regenerated.
+    pub contrast: f32,
 
     // Primary colors
     /// Primary brand color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary: Color,
     /// Color for content on primary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary: Color,
     /// Primary container color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub primary_container: Color,
     /// Color for content on primary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_primary_container: Color,
 
     // Secondary colors
     /// Secondary brand color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary: Color,
     /// Color for content on secondary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary: Color,
     /// Secondary container color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub secondary_container: Color,
     /// Color for content on secondary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_secondary_container: Color,
 
     // Tertiary colors
     /// Tertiary accent color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary: Color,
     /// Color for content on tertiary
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary: Color,
     /// Tertiary container color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub tertiary_container: Color,
     /// Color for content on tertiary container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_tertiary_container: Color,
 
     // Error colors
     /// Error state color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub error: Color,
     /// Color for content on error
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_error: Color,
     /// Error container color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub error_container: Color,
     /// Color for content on error container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_error_container: Color,
 
     // Surface colors
     /// Base surface color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface: Color,
     /// Color for content on surface
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_surface: Color,
     /// Variant of on_surface for less emphasis
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub on_surface_variant: Color,
     /// Lowest surface container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_lowest: Color,
     /// Low surface container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_low: Color,
     /// Default surface container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container: Color,
     /// High surface container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_high: Color,
     /// Highest surface container
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub surface_container_highest: Color,
 
     // Other colors
     /// Outline color for borders
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub outline: Color,
     /// Variant outline for subtle borders
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub outline_variant: Color,
     /// Inverse surface for contrast
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_surface: Color,
     /// Content on inverse surface
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_on_surface: Color,
     /// Inverse primary for contrast
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub inverse_primary: Color,
     /// Scrim overlay color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub scrim: Color,
     /// Shadow color
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub shadow: Color,
 
     // Custom game-specific colors
     /// Color for selected/active states
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub selected: Color,
     /// Color for unselected/inactive states
+    #[cfg_attr(feature = "serde", serde(with = "crate::color::hex_color"))]
     pub unselected: Color,
 }
 
@@ -144,13 +209,29 @@ impl Default for MaterialTheme {
 impl MaterialTheme {
     /// Create a theme from a seed color using MD3 scheme generation.
     pub fn from_seed(seed: Color, mode: ThemeMode) -> Self {
-        let scheme = match mode {
-            ThemeMode::Dark => MaterialColorScheme::dark_from_bevy_color(seed),
-            ThemeMode::Light => MaterialColorScheme::light_from_bevy_color(seed),
-        };
+        Self::from_seed_with(seed, mode, SchemeVariant::TonalSpot, 0.0)
+    }
+
+    /// Create a theme from a seed color with a scheme variant and contrast level.
+    pub fn from_seed_with(
+        seed: Color,
+        mode: ThemeMode,
+        variant: SchemeVariant,
+        contrast: f32,
+    ) -> Self {
+        let contrast = contrast.clamp(-1.0, 1.0);
+        let scheme = MaterialColorScheme::from_seed_with(
+            seed,
+            variant,
+            mode == ThemeMode::Dark,
+            contrast as f64,
+        );
 
         Self {
             mode,
+            seed,
+            variant,
+            contrast,
 
             primary: scheme.primary,
             on_primary: scheme.on_primary,
@@ -198,6 +279,9 @@ impl MaterialTheme {
     pub fn dark() -> Self {
         Self {
             mode: ThemeMode::Dark,
+            seed: DEFAULT_SEED_COLOR,
+            variant: SchemeVariant::TonalSpot,
+            contrast: 0.0,
 
             // Primary - Purple/Violet
             primary: Color::srgb(0.82, 0.71, 1.0), // #D0B4FF
@@ -252,6 +336,9 @@ impl MaterialTheme {
     pub fn light() -> Self {
         Self {
             mode: ThemeMode::Light,
+            seed: DEFAULT_SEED_COLOR,
+            variant: SchemeVariant::TonalSpot,
+            contrast: 0.0,
 
             // Primary - Purple/Violet
             primary: Color::srgb(0.50, 0.35, 0.71), // #7F58B5
@@ -302,12 +389,62 @@ impl MaterialTheme {
         }
     }
 
+    /// Serialize the theme to pretty-printed JSON, with colors as hex strings.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a theme is always representable as JSON")
+    }
+
+    /// Load a theme from JSON written by [`MaterialTheme::to_json`].
+    ///
+    /// Every color role must be present; colors are `"#RRGGBB"` or
+    /// `"#RRGGBBAA"` hex strings.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Set the contrast level (-1.0 to 1.0) and regenerate all color roles
+    /// from the current seed, mode and variant.
+    pub fn set_contrast(&mut self, contrast: f32) {
+        *self = Self::from_seed_with(self.seed, self.mode, self.variant, contrast);
+    }
+
     /// Toggle between light and dark mode
     pub fn toggle_mode(&mut self) {
-        *self = match self.mode {
-            ThemeMode::Light => Self::dark(),
-            ThemeMode::Dark => Self::light(),
+        let mode = match self.mode {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::Light,
         };
+        self.set_mode(mode);
+    }
+
+    /// Switch to light or dark mode, keeping the seed, variant and contrast.
+    ///
+    /// Themes on the default seed switch between [`MaterialTheme::light`] and
+    /// [`MaterialTheme::dark`]; all others are regenerated from their seed.
+    pub fn set_mode(&mut self, mode: ThemeMode) {
+        let builtin = self.seed == DEFAULT_SEED_COLOR
+            && self.variant == SchemeVariant::TonalSpot
+            && self.contrast == 0.0;
+
+        *self = match (builtin, mode) {
+            (true, ThemeMode::Dark) => Self::dark(),
+            (true, ThemeMode::Light) => Self::light(),
+            (false, _) => Self::from_seed_with(self.seed, mode, self.variant, self.contrast),
+        };
+    }
+
+    /// Surface tint color used for MD3 elevation tinting (the primary color)
+    pub fn surface_tint(&self) -> Color {
+        self.primary
+    }
+
+    /// Shift a custom color's hue toward this theme's primary color.
+    ///
+    /// See [`crate::color::harmonize`].
+    pub fn harmonize(&self, color: Color) -> Color {
+        harmonize(color, self.primary)
     }
 
     /// Get the appropriate state layer opacity for a given interaction state
@@ -341,6 +478,24 @@ impl MaterialTheme {
     }
 }
 
+/// Apply [`SetTheme`] messages to the theme resource
+pub(crate) fn apply_set_theme_system(
+    mut messages: MessageReader<SetTheme>,
+    mut theme: ResMut<MaterialTheme>,
+) {
+    for message in messages.read() {
+        match message {
+            SetTheme::Theme(next) => *theme = (**next).clone(),
+            SetTheme::Mode(mode) => theme.set_mode(*mode),
+            SetTheme::ToggleMode => theme.toggle_mode(),
+            SetTheme::Seed(seed) => {
+                *theme =
+                    MaterialTheme::from_seed_with(*seed, theme.mode, theme.variant, theme.contrast);
+            }
+        }
+    }
+}
+
 /// State layer for interaction feedback
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StateLayer {
@@ -382,3 +537,135 @@ pub fn blend_state_layer(base: Color, state_layer_color: Color, opacity: f32) ->
         base_linear.alpha,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn luminance(c: Color) -> f32 {
+        let srgba = c.to_srgba();
+        0.299 * srgba.red + 0.587 * srgba.green + 0.114 * srgba.blue
+    }
+
+    #[test]
+    fn test_set_contrast_regenerates_roles() {
+        let mut theme = MaterialTheme::from_seed(DEFAULT_SEED_COLOR, ThemeMode::Light);
+        let standard_gap =
+            (luminance(theme.primary_container) - luminance(theme.on_primary_container)).abs();
+
+        theme.set_contrast(1.0);
+        assert_eq!(theme.contrast, 1.0);
+        assert_eq!(theme.mode, ThemeMode::Light);
+        let high_gap =
+            (luminance(theme.primary_container) - luminance(theme.on_primary_container)).abs();
+        assert!(high_gap > standard_gap);
+
+        theme.set_contrast(5.0);
+        assert_eq!(theme.contrast, 1.0);
+    }
+
+    #[test]
+    fn test_toggle_mode_keeps_seed() {
+        let seed = Color::srgb(0.1, 0.5, 0.3);
+        let mut theme =
+            MaterialTheme::from_seed_with(seed, ThemeMode::Dark, SchemeVariant::Vibrant, 0.5);
+
+        theme.toggle_mode();
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.seed, seed);
+        assert_eq!(theme.variant, SchemeVariant::Vibrant);
+        assert_eq!(theme.contrast, 0.5);
+
+        let mut builtin = MaterialTheme::dark();
+        builtin.toggle_mode();
+        assert_eq!(builtin.primary, MaterialTheme::light().primary);
+    }
+    #[test]
+    fn test_set_theme_messages_update_the_resource() {
+        let mut app = App::new();
+        app.insert_resource(MaterialTheme::light())
+            .add_message::<SetTheme>()
+            .add_systems(PreUpdate, apply_set_theme_system);
+        let theme = |app: &App| app.world().resource::<MaterialTheme>().clone();
+
+        app.world_mut()
+            .write_message(SetTheme::Mode(ThemeMode::Dark));
+        app.update();
+        assert_eq!(theme(&app).mode, ThemeMode::Dark);
+        assert_eq!(theme(&app).primary, MaterialTheme::dark().primary);
+
+        app.world_mut().write_message(SetTheme::ToggleMode);
+        app.update();
+        assert_eq!(theme(&app).mode, ThemeMode::Light);
+        assert_eq!(theme(&app).primary, MaterialTheme::light().primary);
+
+        let seed = Color::srgb_u8(0x1B, 0x6E, 0x4A);
+        app.world_mut().write_message(SetTheme::Seed(seed));
+        app.update();
+        let seeded = MaterialTheme::from_seed(seed, ThemeMode::Light);
+        assert_eq!(theme(&app).seed, seed);
+        assert_eq!(theme(&app).mode, ThemeMode::Light);
+        assert_eq!(theme(&app).primary, seeded.primary);
+
+        // Toggling a seeded theme regenerates it from the seed.
+        app.world_mut().write_message(SetTheme::ToggleMode);
+        app.update();
+        assert_eq!(theme(&app).seed, seed);
+        assert_eq!(
+            theme(&app).primary,
+            MaterialTheme::from_seed(seed, ThemeMode::Dark).primary
+        );
+
+        let next =
+            MaterialTheme::from_seed_with(seed, ThemeMode::Light, SchemeVariant::Vibrant, 0.5);
+        app.world_mut()
+            .write_message(SetTheme::Theme(Box::new(next.clone())));
+        app.update();
+        assert_eq!(theme(&app).variant, SchemeVariant::Vibrant);
+        assert_eq!(theme(&app).contrast, 0.5);
+        assert_eq!(theme(&app).primary, next.primary);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip_keeps_every_role() {
+        let theme = MaterialTheme::from_seed_with(
+            Color::srgb_u8(0x1B, 0x6E, 0x4A),
+            ThemeMode::Light,
+            SchemeVariant::Expressive,
+            0.5,
+        );
+        let json = theme.to_json();
+        assert!(json.contains("\"mode\": \"Light\""));
+        assert!(json.contains(&format!(
+            "\"primary\": \"{}\"",
+            theme.primary.to_srgba().to_hex()
+        )));
+
+        // Every role reads back as the same hex value, and seeded roles are
+        // 8-bit colors, so they survive exactly.
+        let loaded = MaterialTheme::from_json(&json).unwrap();
+        assert_eq!(loaded.to_json(), json);
+        assert_eq!(loaded.seed, theme.seed);
+        assert_eq!(loaded.primary, theme.primary);
+        assert_eq!(
+            loaded.surface_container_highest,
+            theme.surface_container_highest
+        );
+        assert_eq!(loaded.unselected, theme.unselected);
+
+        // Built-in themes use float colors; their hex form is stable.
+        let dark = MaterialTheme::dark().to_json();
+        assert_eq!(MaterialTheme::from_json(&dark).unwrap().to_json(), dark);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_rejects_bad_colors() {
+        let json = MaterialTheme::light()
+            .to_json()
+            .replacen("\"#", "\"#not-a-color", 1);
+        let err = MaterialTheme::from_json(&json).unwrap_err();
+        assert!(err.to_string().contains("invalid color"));
+    }
+}
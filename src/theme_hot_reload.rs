@@ -0,0 +1,206 @@
+//! Live theme reloading from an asset file
+//!
+//! [`ThemeHotReloadPlugin`] loads a theme file (custom extension:
+//! `.mui_theme`) and copies it into the [`MaterialTheme`] resource when it
+//! loads and every time the file changes on disk. Component theme systems
+//! react to `theme.is_changed()`, so the UI restyles without recompiling.
+//!
+//! The file holds the JSON written by [`MaterialTheme::to_json`]. A file that
+//! fails to load or parse is logged and the current theme is kept.
+//!
+//! Requires the `theme_hot_reload` feature, which also turns on Bevy's
+//! `file_watcher` so edits are picked up while the app runs.
+
+use bevy::asset::{io::Reader, AssetLoadFailedEvent, AssetLoader, LoadContext};
+use bevy::prelude::*;
+
+use crate::theme::MaterialTheme;
+
+/// Plugin that keeps [`MaterialTheme`] in sync with a theme asset file
+pub struct ThemeHotReloadPlugin {
+    path: String,
+}
+
+impl ThemeHotReloadPlugin {
+    /// Watch the theme file at `path`, relative to the assets folder
+    /// (e.g. `"themes/studio.mui_theme"`)
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for ThemeHotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
+            app.add_plugins(crate::MaterialUiCorePlugin);
+        }
+
+        let path = self.path.clone();
+        app.init_asset::<ThemeAsset>()
+            .register_asset_loader(ThemeAssetLoader)
+            .add_systems(
+                Startup,
+                move |mut commands: Commands, asset_server: Res<AssetServer>| {
+                    commands.insert_resource(HotReloadTheme {
+                        handle: asset_server.load(path.clone()),
+                    });
+                },
+            )
+            .add_systems(PreUpdate, apply_hot_reload_theme_system);
+    }
+}
+
+/// A theme loaded from a `.mui_theme` file
+#[derive(Asset, Debug, Clone, TypePath)]
+pub struct ThemeAsset {
+    /// The theme stored in the file
+    pub theme: MaterialTheme,
+}
+
+/// Loader for `.mui_theme` files
+#[derive(Default, TypePath)]
+pub struct ThemeAssetLoader;
+
+/// Errors produced while loading a `.mui_theme` file
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeAssetLoaderError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON parse error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl AssetLoader for ThemeAssetLoader {
+    type Asset = ThemeAsset;
+    type Settings = ();
+    type Error = ThemeAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let theme = serde_json::from_slice(&bytes)?;
+        Ok(ThemeAsset { theme })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mui_theme"]
+    }
+}
+
+/// Handle of the theme file watched by [`ThemeHotReloadPlugin`]
+#[derive(Resource, Debug, Clone)]
+pub struct HotReloadTheme {
+    /// The watched theme asset
+    pub handle: Handle<ThemeAsset>,
+}
+
+/// Copy the watched theme into [`MaterialTheme`] when it loads or changes.
+///
+/// A failed (re)load leaves the asset, and so the theme, as it was.
+fn apply_hot_reload_theme_system(
+    source: Option<Res<HotReloadTheme>>,
+    themes: Res<Assets<ThemeAsset>>,
+    mut events: MessageReader<AssetEvent<ThemeAsset>>,
+    mut failures: MessageReader<AssetLoadFailedEvent<ThemeAsset>>,
+    mut theme: ResMut<MaterialTheme>,
+) {
+    let Some(source) = source else {
+        events.clear();
+        failures.clear();
+        return;
+    };
+    let id = source.handle.id();
+
+    for failure in failures.read() {
+        if failure.id == id {
+            warn!(
+                "Failed to load theme {}: {}; keeping the current theme",
+                failure.path, failure.error
+            );
+        }
+    }
+
+    let mut changed = false;
+    for event in events.read() {
+        changed |= event.is_added(id) || event.is_modified(id);
+    }
+    if changed {
+        if let Some(asset) = themes.get(id) {
+            *theme = asset.theme.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ThemeMode;
+    use bevy::asset::AssetPlugin;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<ThemeAsset>()
+            .insert_resource(MaterialTheme::dark())
+            .add_systems(Update, apply_hot_reload_theme_system);
+        app
+    }
+
+    #[test]
+    fn test_theme_follows_watched_asset() {
+        let mut app = app();
+        let light = MaterialTheme::light();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<ThemeAsset>>()
+            .add(ThemeAsset {
+                theme: light.clone(),
+            });
+        app.insert_resource(HotReloadTheme {
+            handle: handle.clone(),
+        });
+        // Asset events are flushed at the end of the frame they happen in.
+        app.update();
+        app.update();
+
+        let theme = app.world().resource::<MaterialTheme>();
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.primary, light.primary);
+
+        let edited = Color::srgb_u8(0x12, 0x34, 0x56);
+        app.world_mut()
+            .resource_mut::<Assets<ThemeAsset>>()
+            .get_mut(&handle)
+            .unwrap()
+            .theme
+            .primary = edited;
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<MaterialTheme>().primary, edited);
+    }
+
+    #[test]
+    fn test_other_theme_assets_are_ignored() {
+        let mut app = app();
+        let mut assets = app.world_mut().resource_mut::<Assets<ThemeAsset>>();
+        let watched = assets.reserve_handle();
+        assets.add(ThemeAsset {
+            theme: MaterialTheme::light(),
+        });
+        app.insert_resource(HotReloadTheme { handle: watched });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<MaterialTheme>().mode,
+            ThemeMode::Dark
+        );
+    }
+}
@@ -17,28 +17,31 @@ impl ClockFaceLayout {
         }
     }
 
-    /// Calculate position for a clock number
-    pub fn number_position(&self, value: u8, total: u8, is_inner: bool) -> Vec2 {
-        let angle = (value as f32 / total as f32) * 2.0 * PI - PI / 2.0;
-        let r = if is_inner {
+    /// Distance from the center to the numbers of a ring
+    pub fn ring_radius(&self, is_inner: bool) -> f32 {
+        if is_inner {
             self.inner_radius
         } else {
             self.radius * 0.85 // Slightly inside the edge
-        };
+        }
+    }
+
+    /// Calculate position for a clock number
+    pub fn number_position(&self, value: u8, total: u8, is_inner: bool) -> Vec2 {
+        let angle = (value as f32 / total as f32) * 2.0 * PI - PI / 2.0;
+        let r = self.ring_radius(is_inner);
 
         Vec2::new(r * angle.cos(), r * angle.sin())
     }
 
     /// Calculate clock hand rotation and length
+    ///
+    /// The hand ends on the center of the selected number, so inner-ring values get a
+    /// visibly shorter hand than outer-ring ones.
     pub fn hand_transform(&self, value: u8, total: u8, is_inner: bool) -> (f32, f32) {
         let angle = (value as f32 / total as f32) * 2.0 * PI - PI / 2.0;
-        let length = if is_inner {
-            self.inner_radius
-        } else {
-            self.radius * 0.7
-        };
 
-        (angle, length)
+        (angle, self.ring_radius(is_inner))
     }
 }
 
@@ -70,3 +73,18 @@ impl ClockHand {
         Self::new(angle, 120.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_reaches_ring() {
+        let layout = ClockFaceLayout::new(120.0);
+        let (_, inner) = layout.hand_transform(3, 12, true);
+        let (_, outer) = layout.hand_transform(3, 12, false);
+        assert!(inner < outer);
+        assert!((outer - layout.number_position(3, 12, false).length()).abs() < 1e-3);
+        assert!((inner - layout.number_position(3, 12, true).length()).abs() < 1e-3);
+    }
+}
@@ -28,6 +28,22 @@ pub fn hour_12_to_24(hour_12: u8, is_pm: bool) -> u8 {
     }
 }
 
+/// Normalize a minute step to a divisor of 60 (falls back to 1 minute).
+pub fn normalize_minute_step(step: u8) -> u8 {
+    if step == 0 || 60 % step != 0 {
+        1
+    } else {
+        step
+    }
+}
+
+/// Round a minute (0-59) to the nearest multiple of `step`, wrapping 60 back to 0.
+pub fn snap_minute(minute: u8, step: u8) -> u8 {
+    let step = normalize_minute_step(step) as u16;
+    let minute = (minute % 60) as u16;
+    ((((minute + step / 2) / step) * step) % 60) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +67,16 @@ mod tests {
         assert_eq!(hour_12_to_24(1, true), 13); // 1 PM -> 13
         assert_eq!(hour_12_to_24(11, true), 23); // 11 PM -> 23
     }
+
+    #[test]
+    fn test_snap_minute() {
+        assert_eq!(snap_minute(7, 1), 7);
+        assert_eq!(snap_minute(7, 5), 5);
+        assert_eq!(snap_minute(8, 5), 10);
+        assert_eq!(snap_minute(58, 5), 0);
+        assert_eq!(snap_minute(22, 15), 15);
+        // Steps that do not divide an hour fall back to single minutes.
+        assert_eq!(snap_minute(7, 7), 7);
+        assert_eq!(normalize_minute_step(0), 1);
+    }
 }
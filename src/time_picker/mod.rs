@@ -7,8 +7,13 @@ use bevy::prelude::*;
 use bevy::ui::{ComputedNode, FocusPolicy, UiGlobalTransform};
 use std::f32::consts::PI;
 
+use crate::elevation::Elevation;
 use crate::i18n::{MaterialI18n, MaterialLanguage, MaterialLanguageOverride};
 use crate::icons::material_icon_names;
+use crate::overlay::{
+    open_on_shortcut_system, track_overlay_system, DismissOnOutsideClick, OpenableOverlay,
+    OutsideClickEvent, Overlay, OverlayStack, Z_DIALOG,
+};
 use crate::text_field::{
     spawn_text_field_control_with, InputType, MaterialTextField, TextFieldBuilder,
 };
@@ -29,6 +34,10 @@ const CLOCK_NUMBER_OUTER_SIZE: f32 = 48.0;
 const CLOCK_NUMBER_OUTER_HALF: f32 = CLOCK_NUMBER_OUTER_SIZE / 2.0;
 const CLOCK_NUMBER_INNER_SIZE: f32 = 32.0;
 const CLOCK_NUMBER_INNER_HALF: f32 = CLOCK_NUMBER_INNER_SIZE / 2.0;
+const CLOCK_NUMBER_INNER_FONT_SIZE: f32 = 14.0;
+// Unlabelled minute marks between the 5-minute numbers.
+const MINUTE_TICK_SIZE: f32 = 4.0;
+const MINUTE_TICK_SELECTED_SIZE: f32 = 8.0;
 
 /// Plugin for the Time Picker component.
 pub struct TimePickerPlugin;
@@ -44,7 +53,11 @@ impl Plugin for TimePickerPlugin {
                 Update,
                 (
                     time_picker_localization_system,
+                    time_picker_restore_committed_system,
                     time_picker_visibility_system,
+                    track_overlay_system::<MaterialTimePicker>,
+                    open_on_shortcut_system::<MaterialTimePicker>,
+                    time_picker_outside_click_setup_system,
                     time_picker_keyboard_dismiss_system,
                     time_picker_mode_toggle_system,
                     time_picker_keyboard_input_system,
@@ -211,6 +224,8 @@ pub struct MaterialTimePicker {
     pub hour: u8,
     /// Current minute (0-59)
     pub minute: u8,
+    /// Minute granularity (1 or 5; any divisor of 60 works)
+    pub minute_step: u8,
     /// Period for 12H format
     pub period: TimePeriod,
     /// Active selection mode
@@ -219,6 +234,10 @@ pub struct MaterialTimePicker {
     pub dismiss_on_scrim_click: bool,
     /// Dismiss on escape key
     pub dismiss_on_escape: bool,
+    /// Last submitted `(hour, minute)`, restored whenever the picker opens
+    committed: (u8, u8),
+    /// Open state seen by the restore system, to detect the open transition
+    was_open: bool,
 }
 
 /// Builder for Material Time Picker
@@ -230,6 +249,7 @@ pub struct TimePickerBuilder {
     format: TimeFormat,
     initial_hour: u8,
     initial_minute: u8,
+    minute_step: u8,
     dismiss_on_scrim_click: bool,
     dismiss_on_escape: bool,
     width: Val,
@@ -250,6 +270,7 @@ impl TimePickerBuilder {
             format: TimeFormat::H24,
             initial_hour: 0,
             initial_minute: 0,
+            minute_step: 1,
             dismiss_on_scrim_click: true,
             dismiss_on_escape: true,
             width: Val::Px(360.0),
@@ -284,6 +305,13 @@ impl TimePickerBuilder {
         self
     }
 
+    /// Minute granularity: `1` allows any minute and shows every tick mark on the clock,
+    /// `5` snaps to the labelled marks.
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = normalize_minute_step(step);
+        self
+    }
+
     pub fn width(mut self, width: Val) -> Self {
         self.width = width;
         self
@@ -306,17 +334,22 @@ impl TimePickerBuilder {
             TimePeriod::PM
         };
 
+        let minute = snap_minute(self.initial_minute, self.minute_step);
+
         MaterialTimePicker {
             open: false,
             title: self.title.clone(),
             input_mode: self.input_mode,
             format: self.format,
             hour: self.initial_hour,
-            minute: self.initial_minute,
+            minute,
+            minute_step: self.minute_step,
             period,
             selection_mode: TimeSelectionMode::Hour,
             dismiss_on_scrim_click: self.dismiss_on_scrim_click,
             dismiss_on_escape: self.dismiss_on_escape,
+            committed: (self.initial_hour, minute),
+            was_open: false,
         }
     }
 }
@@ -356,6 +389,37 @@ impl MaterialTimePicker {
         self.period = period;
     }
 
+    /// Returns the last submitted `(hour, minute)`, which the picker shows when it opens.
+    pub fn committed_time(&self) -> (u8, u8) {
+        self.committed
+    }
+
+    /// Replace the committed time (and the current one) programmatically.
+    ///
+    /// Use this before opening the picker to reset what it starts from.
+    pub fn set_time(&mut self, hour: u8, minute: u8) {
+        self.committed = (hour % 24, snap_minute(minute, self.minute_step));
+        self.restore_committed();
+    }
+
+    /// Discard in-progress edits and go back to the committed time.
+    fn restore_committed(&mut self) {
+        let (hour, minute) = self.committed;
+        self.hour = hour;
+        self.minute = minute;
+        self.period = if hour < 12 {
+            TimePeriod::AM
+        } else {
+            TimePeriod::PM
+        };
+        self.selection_mode = TimeSelectionMode::Hour;
+    }
+
+    /// Whether `minute` is selectable with the current `minute_step`
+    pub fn accepts_minute(&self, minute: u8) -> bool {
+        minute < 60 && minute.is_multiple_of(normalize_minute_step(self.minute_step))
+    }
+
     /// Format time as string
     pub fn format_time(&self) -> String {
         match self.format {
@@ -371,6 +435,18 @@ impl MaterialTimePicker {
     }
 }
 
+impl Overlay for MaterialTimePicker {
+    fn overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
+impl OpenableOverlay for MaterialTimePicker {
+    fn open_overlay(&mut self) {
+        self.open = true;
+    }
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -392,12 +468,12 @@ pub struct TimePickerCancelEvent {
 // ============================================================================
 
 #[derive(Component)]
-struct TimePickerScrim {
-    picker: Entity,
-}
+struct TimePickerScrim;
 
 #[derive(Component)]
-struct TimePickerDialog;
+struct TimePickerDialog {
+    picker: Entity,
+}
 
 #[derive(Component)]
 struct TimePickerModeToggle {
@@ -480,6 +556,13 @@ struct TimePickerClockNumber {
     format: Option<TimeFormat>,
 }
 
+/// Unlabelled minute mark, shown when the picker accepts single minutes.
+#[derive(Component)]
+struct TimePickerMinuteTick {
+    picker: Entity,
+    minute: u8,
+}
+
 #[derive(Component)]
 struct TimePickerAction {
     picker: Entity,
@@ -490,6 +573,30 @@ struct TimePickerAction {
 // Systems
 // ============================================================================
 
+/// Restores the committed time when a picker opens, so cancelled edits are discarded.
+fn time_picker_restore_committed_system(
+    mut pickers: Query<(Entity, &mut MaterialTimePicker), Changed<MaterialTimePicker>>,
+    mut minute_fields: Query<(&TimePickerMinuteField, &mut MaterialTextField)>,
+) {
+    for (entity, mut picker) in pickers.iter_mut() {
+        if picker.open == picker.was_open {
+            continue;
+        }
+        picker.was_open = picker.open;
+        if !picker.open {
+            continue;
+        }
+
+        picker.restore_committed();
+        for (field, mut text_field) in minute_fields.iter_mut() {
+            if field.picker == entity && text_field.error {
+                text_field.error = false;
+                text_field.error_text = None;
+            }
+        }
+    }
+}
+
 fn time_picker_visibility_system(
     pickers: Query<(Entity, &MaterialTimePicker), Changed<MaterialTimePicker>>,
     mut root_nodes: Query<&mut Node, Without<TimePickerDialog>>,
@@ -505,17 +612,27 @@ fn time_picker_visibility_system(
     }
 }
 
+fn time_picker_outside_click_setup_system(
+    mut commands: Commands,
+    surfaces: Query<Entity, Added<TimePickerDialog>>,
+) {
+    // Presses outside the dialog surface land on the scrim.
+    for entity in surfaces.iter() {
+        commands
+            .entity(entity)
+            .insert(DismissOnOutsideClick::new(entity));
+    }
+}
+
 fn time_picker_keyboard_dismiss_system(
-    keys: Res<ButtonInput<KeyCode>>,
+    stack: Option<Res<OverlayStack>>,
     mut pickers: Query<(Entity, &mut MaterialTimePicker)>,
     mut cancel_events: MessageWriter<TimePickerCancelEvent>,
 ) {
-    if !keys.just_pressed(KeyCode::Escape) {
-        return;
-    }
+    let Some(stack) = stack else { return };
 
     for (entity, mut picker) in pickers.iter_mut() {
-        if picker.open && picker.dismiss_on_escape {
+        if picker.open && picker.dismiss_on_escape && stack.escape_pressed_for(entity) {
             picker.open = false;
             cancel_events.write(TimePickerCancelEvent { entity });
         }
@@ -547,6 +664,7 @@ fn time_picker_keyboard_input_system(
     mut changes: MessageReader<crate::text_field::TextFieldChangeEvent>,
     hour_fields: Query<&TimePickerHourField>,
     minute_fields: Query<&TimePickerMinuteField>,
+    mut text_fields: Query<&mut MaterialTextField>,
 ) {
     for ev in changes.read() {
         if let Ok(field) = hour_fields.get(ev.entity) {
@@ -598,8 +716,27 @@ fn time_picker_keyboard_input_system(
                 continue;
             };
 
+            // Only commit minutes that land on the configured step; flag the rest.
+            let accepted = picker.accepts_minute(minute);
+            if let Ok(mut text_field) = text_fields.get_mut(ev.entity) {
+                if accepted && text_field.error {
+                    text_field.error = false;
+                    text_field.error_text = None;
+                } else if !accepted {
+                    text_field.error = true;
+                    text_field.error_text = Some(if minute > 59 {
+                        "Use 00-59".to_string()
+                    } else {
+                        format!("Use a multiple of {}", picker.minute_step)
+                    });
+                }
+            }
+            if !accepted {
+                continue;
+            }
+
             picker.selection_mode = TimeSelectionMode::Minute;
-            picker.minute = minute.min(59);
+            picker.minute = minute;
         }
     }
 }
@@ -782,9 +919,9 @@ fn time_picker_clock_interaction_system(
                 }
             }
             TimeSelectionMode::Minute => {
-                // Minutes: 0-59, snap to 5-minute increments by default
+                // Minutes: 0-59, snapped to the configured step
                 let value = ((angle / (2.0 * PI) * 60.0).round() as u8) % 60;
-                picker.minute = value;
+                picker.minute = snap_minute(value, picker.minute_step);
             }
         }
     }
@@ -827,7 +964,7 @@ fn time_picker_clock_number_button_system(
             }
             TimePickerClockNumberKind::Minute => {
                 picker.selection_mode = TimeSelectionMode::Minute;
-                picker.minute = number.value.min(59);
+                picker.minute = snap_minute(number.value, picker.minute_step);
             }
         }
     }
@@ -836,21 +973,22 @@ fn time_picker_clock_number_button_system(
 fn time_picker_action_system(
     mut pickers: Query<&mut MaterialTimePicker>,
     actions: Query<(&Interaction, &TimePickerAction), Changed<Interaction>>,
-    scrim: Query<(&Interaction, &TimePickerScrim), Changed<Interaction>>,
+    mut outside_clicks: MessageReader<OutsideClickEvent>,
+    surfaces: Query<&TimePickerDialog>,
     mut submit_events: MessageWriter<TimePickerSubmitEvent>,
     mut cancel_events: MessageWriter<TimePickerCancelEvent>,
 ) {
-    // Handle scrim clicks
-    for (interaction, scrim) in scrim.iter() {
-        if *interaction != Interaction::Pressed {
+    // Handle presses outside the dialog surface (on the scrim)
+    for ev in outside_clicks.read() {
+        let Ok(surface) = surfaces.get(ev.entity) else {
             continue;
-        }
+        };
 
-        if let Ok(mut picker) = pickers.get_mut(scrim.picker) {
+        if let Ok(mut picker) = pickers.get_mut(surface.picker) {
             if picker.open && picker.dismiss_on_scrim_click {
                 picker.open = false;
                 cancel_events.write(TimePickerCancelEvent {
-                    entity: scrim.picker,
+                    entity: surface.picker,
                 });
             }
         }
@@ -869,6 +1007,7 @@ fn time_picker_action_system(
 
             if action.is_confirm {
                 picker.open = false;
+                picker.committed = (picker.hour, picker.minute);
                 submit_events.write(TimePickerSubmitEvent {
                     entity: action.picker,
                     hour: picker.hour,
@@ -902,6 +1041,7 @@ fn time_picker_render_system(
             Option<&TimePickerClockNumber>,
         )>,
         Query<(&TimePickerClockHandLine, &mut Node)>,
+        Query<(&TimePickerMinuteTick, &mut Node, &mut BackgroundColor)>,
     )>,
     mut clock_hand: Query<(&TimePickerClockHand, &mut UiTransform)>,
     mut text_colors: Query<&mut TextColor>,
@@ -1033,14 +1173,17 @@ fn time_picker_render_system(
                                 number.value == picker.hour
                             }
                         }
-                        TimeSelectionMode::Minute => {
-                            let snapped = (((picker.minute as u16 + 2) / 5) * 5) % 60;
-                            number.value == snapped as u8
-                        }
+                        TimeSelectionMode::Minute => number.value == picker.minute,
                     };
 
+                    // The 24h inner ring (00-11) is drawn dimmer so the two rings read apart.
+                    let is_inner_ring = number.kind == TimePickerClockNumberKind::Hour
+                        && number.format == Some(TimeFormat::H24)
+                        && number.value < 12;
                     let (bg_color, text_color) = if is_selected {
                         (theme.primary, theme.on_primary)
+                    } else if is_inner_ring {
+                        (Color::NONE, theme.on_surface_variant)
                     } else {
                         (Color::NONE, theme.on_surface)
                     };
@@ -1055,8 +1198,39 @@ fn time_picker_render_system(
             }
         }
 
-        // Update clock hand rotation.
         let layout = crate::time_picker::clock::ClockFaceLayout::new(120.0);
+
+        // Minute ticks: only meaningful when single minutes are selectable.
+        {
+            let show_ticks = picker.selection_mode == TimeSelectionMode::Minute
+                && normalize_minute_step(picker.minute_step) == 1;
+            let mut ticks = styled_nodes.p2();
+            for (tick, mut node, mut bg) in ticks.iter_mut() {
+                if tick.picker != picker_entity {
+                    continue;
+                }
+
+                node.display = if show_ticks {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+
+                let (size, color) = if tick.minute == picker.minute {
+                    (MINUTE_TICK_SELECTED_SIZE, theme.primary)
+                } else {
+                    (MINUTE_TICK_SIZE, theme.on_surface_variant)
+                };
+                let pos = layout.number_position(tick.minute, 60, false);
+                node.width = Val::Px(size);
+                node.height = Val::Px(size);
+                node.left = Val::Px(120.0 + pos.x - size / 2.0);
+                node.top = Val::Px(120.0 + pos.y - size / 2.0);
+                *bg = BackgroundColor(color);
+            }
+        }
+
+        // Update clock hand rotation.
         let mut desired_hand_length: Option<f32> = None;
         for (hand, mut transform) in clock_hand.iter_mut() {
             if hand.picker != picker_entity {
@@ -1150,11 +1324,17 @@ fn time_picker_theme_system(
         Option<&TimePickerScrim>,
         Option<&TimePickerDialog>,
     )>,
+    mut pickers: Query<&mut MaterialTimePicker>,
 ) {
     if !theme.is_changed() {
         return;
     }
 
+    // Re-run the render system so the clock, inputs and labels pick up the new colors.
+    for mut picker in pickers.iter_mut() {
+        picker.set_changed();
+    }
+
     for (mut bg, scrim, dialog) in backgrounds.iter_mut() {
         if scrim.is_some() {
             *bg = BackgroundColor(theme.scrim.with_alpha(0.32));
@@ -1203,17 +1383,14 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
             },
             Transform::default(),
             GlobalTransform::default(),
-            GlobalZIndex(9999),
+            GlobalZIndex(Z_DIALOG),
         ));
         let entity = root.id();
 
         root.with_children(|root| {
             // Scrim overlay
             root.spawn((
-                TimePickerScrim { picker: entity },
-                // Required for `Interaction` updates so scrim clicks can be detected.
-                Button,
-                Interaction::None,
+                TimePickerScrim,
                 Node {
                     position_type: PositionType::Absolute,
                     left: Val::Px(0.0),
@@ -1233,7 +1410,7 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
 
             // Dialog container
             root.spawn((
-                TimePickerDialog,
+                TimePickerDialog { picker: entity },
                 FocusPolicy::Block,
                 Interaction::None,
                 Node {
@@ -1252,7 +1429,7 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
                     is_hoverable: false,
                 },
                 BorderRadius::all(Val::Px(CornerRadius::EXTRA_LARGE)),
-                BoxShadow::default(),
+                Elevation::level_3().to_box_shadow(),
                 ZIndex(1),
             ))
             .with_children(|dialog| {
@@ -1596,10 +1773,10 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
                                             n.spawn((
                                                 Text::new(format!("{:02}", inner_hour)),
                                                 TextFont {
-                                                    font_size: 16.0,
+                                                    font_size: CLOCK_NUMBER_INNER_FONT_SIZE,
                                                     ..default()
                                                 },
-                                                TextColor(on_surface),
+                                                TextColor(theme.on_surface_variant),
                                             ));
                                         });
 
@@ -1645,6 +1822,29 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
                                         });
                                 }
 
+                                // Minute ticks between the numbers (shown only with a 1-minute step)
+                                for minute in (0..60u8).filter(|m| m % 5 != 0) {
+                                    let pos = layout.number_position(minute, 60, false);
+                                    clock.spawn((
+                                        TimePickerMinuteTick {
+                                            picker: entity,
+                                            minute,
+                                        },
+                                        Node {
+                                            position_type: PositionType::Absolute,
+                                            display: Display::None,
+                                            left: Val::Px(120.0 + pos.x - MINUTE_TICK_SIZE / 2.0),
+                                            top: Val::Px(120.0 + pos.y - MINUTE_TICK_SIZE / 2.0),
+                                            width: Val::Px(MINUTE_TICK_SIZE),
+                                            height: Val::Px(MINUTE_TICK_SIZE),
+                                            ..default()
+                                        },
+                                        BackgroundColor(theme.on_surface_variant),
+                                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                                        Pickable::IGNORE,
+                                    ));
+                                }
+
                                 // Minute numbers: 0,5,...55 (shown only in minute mode)
                                 for idx in 0..12u8 {
                                     let minute = (idx * 5) % 60;
@@ -1839,3 +2039,96 @@ impl SpawnTimePicker for ChildSpawnerCommands<'_> {
         entity
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{apply_set_theme_system, SetTheme, ThemeMode};
+
+    #[test]
+    fn test_set_theme_restyles_the_dialog_in_the_same_frame() {
+        let mut app = App::new();
+        app.insert_resource(MaterialTheme::light())
+            .add_message::<SetTheme>()
+            .add_systems(PreUpdate, apply_set_theme_system)
+            .add_systems(Update, time_picker_theme_system);
+
+        app.world_mut()
+            .commands()
+            .spawn(Node::default())
+            .with_children(|root| {
+                root.spawn_time_picker(&MaterialTheme::light(), TimePickerBuilder::new());
+            });
+        app.world_mut().flush();
+        app.update();
+
+        let dialog_color = |app: &mut App| {
+            let mut dialogs = app
+                .world_mut()
+                .query_filtered::<&BackgroundColor, With<TimePickerDialog>>();
+            dialogs.single(app.world()).unwrap().0
+        };
+        assert_eq!(
+            dialog_color(&mut app),
+            MaterialTheme::light().surface_container_high
+        );
+
+        app.world_mut()
+            .write_message(SetTheme::Mode(ThemeMode::Dark));
+        app.update();
+        assert_eq!(
+            dialog_color(&mut app),
+            MaterialTheme::dark().surface_container_high
+        );
+    }
+
+    #[test]
+    fn test_set_time_commits_snapped_time() {
+        let mut picker = TimePickerBuilder::new().minute_step(5).build_picker();
+        picker.selection_mode = TimeSelectionMode::Minute;
+
+        picker.set_time(26, 37);
+
+        assert_eq!(picker.committed_time(), (2, 35));
+        assert_eq!((picker.hour, picker.minute), (2, 35));
+        assert_eq!(picker.period, TimePeriod::AM);
+        assert_eq!(picker.selection_mode, TimeSelectionMode::Hour);
+    }
+
+    #[test]
+    fn test_cancelled_edits_are_restored_on_reopen() {
+        let mut app = App::new();
+        app.add_systems(Update, time_picker_restore_committed_system);
+
+        let mut picker = TimePickerBuilder::new().build_picker();
+        picker.set_time(9, 30);
+        let entity = app.world_mut().spawn(picker).id();
+
+        let set_open = |app: &mut App, open: bool| {
+            app.world_mut()
+                .get_mut::<MaterialTimePicker>(entity)
+                .unwrap()
+                .open = open;
+            app.update();
+        };
+
+        set_open(&mut app, true);
+        {
+            let mut picker = app
+                .world_mut()
+                .get_mut::<MaterialTimePicker>(entity)
+                .unwrap();
+            picker.set_time_12h(8, 15, TimePeriod::PM);
+            picker.selection_mode = TimeSelectionMode::Minute;
+        }
+        // Cancelling closes the picker without committing the edit.
+        set_open(&mut app, false);
+        set_open(&mut app, true);
+
+        let picker = app.world().get::<MaterialTimePicker>(entity).unwrap();
+        assert_eq!((picker.hour, picker.minute), (9, 30));
+        assert_eq!(picker.period, TimePeriod::AM);
+        assert_eq!(picker.selection_mode, TimeSelectionMode::Hour);
+        assert_eq!(picker.committed_time(), (9, 30));
+    }
+}
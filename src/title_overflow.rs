@@ -0,0 +1,263 @@
+//! Overflow handling for toolbar and app bar titles
+//!
+//! A title wider than the space left for it either ends in "…"
+//! ([`TitleOverflow::Ellipsis`], the Material default) or scrolls
+//! horizontally on a loop ([`TitleOverflow::Marquee`]). The title text sits
+//! in a clipping container; its laid-out width is compared against the
+//! container's each time either one changes.
+
+use bevy::prelude::*;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+
+use crate::motion::ReducedMotion;
+
+/// Marquee scroll speed in logical pixels per second
+pub const TITLE_MARQUEE_SPEED: f32 = 30.0;
+
+/// Seconds a marquee title rests at each end before scrolling on
+pub const TITLE_MARQUEE_PAUSE: f32 = 1.5;
+
+const ELLIPSIS: char = '…';
+
+/// Plugin that ellipsizes or scrolls overflowing titles
+pub struct TitleOverflowPlugin;
+
+impl Plugin for TitleOverflowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, overflow_title_system);
+    }
+}
+
+/// How a title that doesn't fit is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TitleOverflow {
+    /// Truncate the title and end it with "…"
+    #[default]
+    Ellipsis,
+    /// Keep the whole title and scroll it back and forth
+    Marquee,
+}
+
+/// Title text that ellipsizes or scrolls when wider than its parent
+#[derive(Component, Debug, Clone, Default)]
+pub struct OverflowTitle {
+    /// How the title is shown when it doesn't fit
+    pub mode: TitleOverflow,
+    full: String,
+    ellipsized: Option<String>,
+    fitted_width: Option<f32>,
+    marquee_elapsed: f32,
+}
+
+impl OverflowTitle {
+    /// Create an overflow title with the given mode
+    pub fn new(mode: TitleOverflow) -> Self {
+        Self { mode, ..default() }
+    }
+
+    /// The complete title, even while the displayed text is ellipsized
+    pub fn full_text(&self) -> &str {
+        &self.full
+    }
+
+    /// Whether the displayed text is currently ellipsized
+    pub fn is_truncated(&self) -> bool {
+        self.ellipsized.is_some()
+    }
+}
+
+/// Spawn a clipping container laid out with `container`, holding `title` as
+/// single-line text. Returns the text entity.
+///
+/// `title` must not contain a `Node` or `TextLayout`. Centered titles use
+/// auto margins, so they center while they fit and start-align once they
+/// overflow.
+pub(crate) fn spawn_overflow_title(
+    parent: &mut ChildSpawnerCommands,
+    container: Node,
+    mode: TitleOverflow,
+    centered: bool,
+    title: impl Bundle,
+) -> Entity {
+    let margin = if centered {
+        UiRect::horizontal(Val::Auto)
+    } else {
+        UiRect::ZERO
+    };
+
+    let mut text = Entity::PLACEHOLDER;
+    parent
+        .spawn(Node {
+            min_width: Val::Px(0.0),
+            overflow: Overflow::clip_x(),
+            ..container
+        })
+        .with_children(|clip| {
+            text = clip
+                .spawn((
+                    title,
+                    TextLayout::new_with_no_wrap(),
+                    OverflowTitle::new(mode),
+                    Node {
+                        flex_shrink: 0.0,
+                        margin,
+                        ..default()
+                    },
+                ))
+                .id();
+        });
+    text
+}
+
+/// Byte length of the longest prefix whose glyphs end within `budget`
+fn ellipsis_cut(glyphs: &[PositionedGlyph], budget: f32) -> usize {
+    glyphs
+        .iter()
+        .filter(|glyph| glyph.position.x + glyph.size.x / 2.0 <= budget)
+        .map(|glyph| glyph.byte_index + glyph.byte_length)
+        .max()
+        .unwrap_or(0)
+}
+
+/// `text` cut to `len` bytes (backing off to a char boundary) and ended with "…"
+fn ellipsize(text: &str, mut len: usize) -> String {
+    len = len.min(text.len());
+    while !text.is_char_boundary(len) {
+        len -= 1;
+    }
+    format!("{}{ELLIPSIS}", text[..len].trim_end())
+}
+
+/// Marquee scroll offset after `elapsed` seconds for a title `overflow` pixels too wide.
+///
+/// Each loop rests at the start, scrolls to the end, rests, then jumps back.
+fn marquee_offset(overflow: f32, elapsed: f32) -> f32 {
+    let cycle = overflow / TITLE_MARQUEE_SPEED + 2.0 * TITLE_MARQUEE_PAUSE;
+    let scrolling = elapsed.rem_euclid(cycle) - TITLE_MARQUEE_PAUSE;
+    (scrolling * TITLE_MARQUEE_SPEED).clamp(0.0, overflow)
+}
+
+/// Compare each title against its container and ellipsize or scroll it.
+///
+/// Text layout runs in `PostUpdate`, so after the title text changes the
+/// measurement waits a frame for the new layout.
+fn overflow_title_system(
+    time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    mut titles: Query<(
+        &mut OverflowTitle,
+        &mut Text,
+        &TextLayoutInfo,
+        &TextFont,
+        &ComputedNode,
+        &ChildOf,
+        &mut Node,
+        &mut UiTransform,
+    )>,
+    containers: Query<&ComputedNode, Without<OverflowTitle>>,
+) {
+    let reduced_motion = reduced_motion.is_some_and(|r| r.0);
+
+    for (mut title, mut text, layout, font, computed, parent, mut node, mut transform) in
+        titles.iter_mut()
+    {
+        // The app or localization replaced the title.
+        let displayed = title.ellipsized.as_deref().unwrap_or(&title.full);
+        if text.0 != displayed {
+            title.full = text.0.clone();
+            if title.ellipsized.take().is_some() {
+                node.min_width = Val::Auto;
+            }
+            title.fitted_width = None;
+            title.marquee_elapsed = 0.0;
+        }
+        if text.is_changed() {
+            continue;
+        }
+
+        let Ok(container) = containers.get(parent.parent()) else {
+            continue;
+        };
+        let available = container.size().x;
+        if available <= 0.0 {
+            // Not laid out yet.
+            continue;
+        }
+
+        let offset = match title.mode {
+            TitleOverflow::Ellipsis => {
+                if title.fitted_width != Some(available) {
+                    if title.ellipsized.take().is_some() {
+                        // Measure the full title again next frame.
+                        text.0 = title.full.clone();
+                        node.min_width = Val::Auto;
+                    } else {
+                        title.fitted_width = Some(available);
+                        if layout.size.x > available {
+                            let ellipsis_width = font.font_size / computed.inverse_scale_factor;
+                            let cut = ellipsis_cut(&layout.glyphs, available - ellipsis_width);
+                            let truncated = ellipsize(&title.full, cut);
+                            text.0 = truncated.clone();
+                            title.ellipsized = Some(truncated);
+                            // Keep the full width so the container doesn't shrink
+                            // around the shorter text and trigger another fit.
+                            node.min_width = Val::Px(layout.size.x * computed.inverse_scale_factor);
+                        }
+                    }
+                }
+                0.0
+            }
+            TitleOverflow::Marquee => {
+                if title.ellipsized.take().is_some() {
+                    text.0 = title.full.clone();
+                    node.min_width = Val::Auto;
+                    title.fitted_width = None;
+                    continue;
+                }
+
+                let overflow = (layout.size.x - available) * computed.inverse_scale_factor;
+                if overflow <= 0.0 || reduced_motion {
+                    title.marquee_elapsed = 0.0;
+                    0.0
+                } else {
+                    title.marquee_elapsed += time.delta_secs();
+                    marquee_offset(overflow, title.marquee_elapsed)
+                }
+            }
+        };
+
+        let x = Val::Px(-offset);
+        if transform.translation.x != x {
+            transform.translation.x = x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipsize_backs_off_to_char_boundary() {
+        assert_eq!(ellipsize("Settings", 4), "Sett…");
+        assert_eq!(ellipsize("Photo album", 6), "Photo…");
+        // 'é' is two bytes; a cut inside it keeps the whole-char prefix.
+        assert_eq!(ellipsize("Café menu", 4), "Caf…");
+        assert_eq!(ellipsize("Hi", 10), "Hi…");
+    }
+
+    #[test]
+    fn test_marquee_rests_then_scrolls() {
+        let overflow = 60.0;
+        assert_eq!(marquee_offset(overflow, 0.0), 0.0);
+        assert_eq!(marquee_offset(overflow, TITLE_MARQUEE_PAUSE), 0.0);
+        assert_eq!(marquee_offset(overflow, TITLE_MARQUEE_PAUSE + 1.0), 30.0);
+        // Rests at the end before looping back to the start.
+        assert_eq!(
+            marquee_offset(overflow, TITLE_MARQUEE_PAUSE + 2.5),
+            overflow
+        );
+        let cycle = overflow / TITLE_MARQUEE_SPEED + 2.0 * TITLE_MARQUEE_PAUSE;
+        assert_eq!(marquee_offset(overflow, cycle), 0.0);
+    }
+}
@@ -84,6 +84,30 @@ impl Duration {
     pub const EXTRA_LONG4: f32 = 1.0;
 }
 
+/// Opacity tokens for disabled states
+///
+/// Reference: <https://m3.material.io/foundations/interaction/states/applying-states>
+pub struct Opacity;
+
+impl Opacity {
+    /// Disabled content (labels, icons, outlines of selection controls): 38%
+    pub const DISABLED_CONTENT: f32 = 0.38;
+    /// Disabled containers and outlines: 12%
+    pub const DISABLED_CONTAINER: f32 = 0.12;
+}
+
+/// Focus indicator tokens
+///
+/// Reference: <https://m3.material.io/foundations/interaction/states/state-layers#focus-indicator>
+pub struct FocusIndicator;
+
+impl FocusIndicator {
+    /// Ring thickness: 3dp
+    pub const THICKNESS: f32 = 3.0;
+    /// Gap between the component and the ring: 2dp
+    pub const OFFSET: f32 = 2.0;
+}
+
 /// Easing curves for animations
 #[derive(Debug, Clone, Copy)]
 pub enum Easing {
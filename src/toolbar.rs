@@ -8,9 +8,14 @@
 use bevy::prelude::*;
 
 use crate::{
-    icon_button::IconButtonBuilder,
-    icons::{IconStyle, MaterialIcon},
+    action_overflow::{
+        action_overflow_menu_system, spawn_action_overflow, ActionOverflow, ActionOverflowSlot,
+        OverflowAction, OverflowOwner,
+    },
+    icon_button::{IconButtonBuilder, ICON_BUTTON_SIZE},
+    icons::{IconStyle, MaterialIcon, ICON_MORE_VERT},
     theme::MaterialTheme,
+    title_overflow::{spawn_overflow_title, TitleOverflow},
     tokens::Spacing,
 };
 
@@ -22,11 +27,22 @@ impl Plugin for ToolbarPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
+        if !app.is_plugin_added::<crate::menu::MenuPlugin>() {
+            app.add_plugins(crate::menu::MenuPlugin);
+        }
+        if !app.is_plugin_added::<crate::action_overflow::ActionOverflowPlugin>() {
+            app.add_plugins(crate::action_overflow::ActionOverflowPlugin);
+        }
         app.add_message::<ToolbarNavigationEvent>()
             .add_message::<ToolbarActionEvent>()
             .add_systems(
                 Update,
-                (toolbar_interaction_system, toolbar_theme_refresh_system),
+                (
+                    toolbar_interaction_system,
+                    toolbar_overflow_layout_system,
+                    action_overflow_menu_system::<MaterialToolbar>,
+                    toolbar_theme_refresh_system,
+                ),
             );
     }
 }
@@ -42,7 +58,8 @@ pub struct ToolbarNavigationEvent {
     pub toolbar: Entity,
 }
 
-/// Event fired when an action button is clicked.
+/// Event fired when an action is clicked, either in the toolbar row or in the
+/// overflow menu.
 #[derive(Event, Clone, bevy::prelude::Message)]
 pub struct ToolbarActionEvent {
     /// The toolbar entity.
@@ -64,6 +81,8 @@ pub struct MaterialToolbar {
     pub navigation_icon: Option<String>,
     /// Actions to show on the right side.
     pub actions: Vec<ToolbarAction>,
+    /// How a title that doesn't fit is shown.
+    pub title_overflow: TitleOverflow,
 }
 
 impl MaterialToolbar {
@@ -73,6 +92,7 @@ impl MaterialToolbar {
             title: title.into(),
             navigation_icon: None,
             actions: Vec::new(),
+            title_overflow: TitleOverflow::default(),
         }
     }
 
@@ -87,6 +107,12 @@ impl MaterialToolbar {
         self.actions.push(action);
         self
     }
+
+    /// Set how a title that doesn't fit is shown.
+    pub fn with_title_overflow(mut self, overflow: TitleOverflow) -> Self {
+        self.title_overflow = overflow;
+        self
+    }
 }
 
 impl Default for MaterialToolbar {
@@ -102,6 +128,8 @@ pub struct ToolbarAction {
     pub icon: String,
     /// Action identifier.
     pub id: String,
+    /// Label shown when the action is moved into the overflow menu.
+    pub label: Option<String>,
     /// Whether disabled.
     pub disabled: bool,
 }
@@ -112,6 +140,7 @@ impl ToolbarAction {
         Self {
             icon: icon_name.into(),
             id: id.into(),
+            label: None,
             disabled: false,
         }
     }
@@ -121,11 +150,33 @@ impl ToolbarAction {
         Self::new(icon_name, id)
     }
 
+    /// Set the label used in the overflow menu.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Set disabled state.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
+
+    /// Label shown in the overflow menu (falls back to the action id).
+    pub fn menu_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(self.id.as_str())
+    }
+}
+
+/// Overflow state of a toolbar, updated from its measured width.
+pub type ToolbarOverflow = ActionOverflow;
+
+impl OverflowOwner for MaterialToolbar {
+    type Event = ToolbarActionEvent;
+
+    fn action_event(toolbar: Entity, action: String) -> ToolbarActionEvent {
+        ToolbarActionEvent { toolbar, action }
+    }
 }
 
 #[derive(Component)]
@@ -133,6 +184,7 @@ struct ToolbarNavigation;
 
 #[derive(Component)]
 struct ToolbarActionButton {
+    toolbar: Entity,
     id: String,
 }
 
@@ -149,6 +201,9 @@ pub const TOOLBAR_HEIGHT: f32 = 64.0;
 /// Icon size for toolbar buttons.
 pub const TOOLBAR_ICON_SIZE: f32 = 24.0;
 
+/// Width reserved for the title before actions start moving into the overflow menu.
+pub const TOOLBAR_TITLE_MIN_WIDTH: f32 = 96.0;
+
 // ============================================================================
 // Builder
 // ============================================================================
@@ -178,10 +233,24 @@ impl ToolbarBuilder {
         self
     }
 
+    /// Add a fully configured action.
+    pub fn action(mut self, action: ToolbarAction) -> Self {
+        self.toolbar.actions.push(action);
+        self
+    }
+
+    /// Set how a title that doesn't fit is shown.
+    pub fn title_overflow(mut self, overflow: TitleOverflow) -> Self {
+        self.toolbar.title_overflow = overflow;
+        self
+    }
+
     /// Build the toolbar root bundle.
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
+        let overflow = ToolbarOverflow::new(self.toolbar.actions.len());
         (
             self.toolbar,
+            overflow,
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Px(TOOLBAR_HEIGHT),
@@ -212,10 +281,13 @@ impl SpawnToolbarChild for ChildSpawnerCommands<'_> {
     fn spawn_toolbar_with(&mut self, theme: &MaterialTheme, builder: ToolbarBuilder) {
         // Extract a copy of the logical config before we move it into the root bundle.
         let title = builder.toolbar.title.clone();
+        let title_overflow = builder.toolbar.title_overflow;
         let nav_icon = builder.toolbar.navigation_icon.clone();
         let actions = builder.toolbar.actions.clone();
 
-        self.spawn(builder.build(theme)).with_children(|toolbar| {
+        let mut root = self.spawn(builder.build(theme));
+        let toolbar_entity = root.id();
+        root.with_children(|toolbar| {
             if let Some(icon_name) = nav_icon.as_deref() {
                 // Navigation icon button.
                 toolbar
@@ -235,19 +307,24 @@ impl SpawnToolbarChild for ChildSpawnerCommands<'_> {
             }
 
             // Title
-            toolbar.spawn((
-                ToolbarTitle,
-                Text::new(title),
-                TextFont {
-                    font_size: 22.0,
-                    ..default()
-                },
-                TextColor(theme.on_surface),
+            spawn_overflow_title(
+                toolbar,
                 Node {
                     flex_grow: 1.0,
                     ..default()
                 },
-            ));
+                title_overflow,
+                false,
+                (
+                    ToolbarTitle,
+                    Text::new(title),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(theme.on_surface),
+                ),
+            );
 
             // Actions
             if !actions.is_empty() {
@@ -259,18 +336,22 @@ impl SpawnToolbarChild for ChildSpawnerCommands<'_> {
                         ..default()
                     })
                     .with_children(|row| {
-                        for action in actions.iter() {
-                            let mut button_entity = row.spawn((
+                        for (index, action) in actions.iter().enumerate() {
+                            row.spawn((
                                 ToolbarActionButton {
+                                    toolbar: toolbar_entity,
                                     id: action.id.clone(),
                                 },
+                                ActionOverflowSlot {
+                                    owner: toolbar_entity,
+                                    index,
+                                },
                                 IconButtonBuilder::new(action.icon.as_str())
                                     .standard()
                                     .disabled(action.disabled)
                                     .build(theme),
-                            ));
-
-                            button_entity.with_children(|btn| {
+                            ))
+                            .with_children(|btn| {
                                 btn.spawn((
                                     MaterialIcon::from_name(action.icon.as_str())
                                         .expect("embedded toolbar action icon not found"),
@@ -280,6 +361,23 @@ impl SpawnToolbarChild for ChildSpawnerCommands<'_> {
                                 ));
                             });
                         }
+
+                        // Overflow button, hidden until actions no longer fit.
+                        spawn_action_overflow(
+                            row,
+                            theme,
+                            toolbar_entity,
+                            IconButtonBuilder::new(ICON_MORE_VERT)
+                                .standard()
+                                .build(theme),
+                            ICON_BUTTON_SIZE,
+                            actions.iter().map(|action| OverflowAction {
+                                icon: &action.icon,
+                                id: &action.id,
+                                label: action.menu_label(),
+                                disabled: action.disabled,
+                            }),
+                        );
                     });
             }
         });
@@ -296,7 +394,7 @@ impl SpawnToolbarChild for ChildSpawnerCommands<'_> {
 
 fn toolbar_interaction_system(
     nav_buttons: Query<(&Interaction, &ChildOf), (Changed<Interaction>, With<ToolbarNavigation>)>,
-    action_buttons: Query<(&Interaction, &ToolbarActionButton, &ChildOf), Changed<Interaction>>,
+    action_buttons: Query<(&Interaction, &ToolbarActionButton), Changed<Interaction>>,
     toolbars: Query<Entity, With<MaterialToolbar>>,
     mut nav_events: MessageWriter<ToolbarNavigationEvent>,
     mut action_events: MessageWriter<ToolbarActionEvent>,
@@ -309,14 +407,41 @@ fn toolbar_interaction_system(
         }
     }
 
-    for (interaction, action, parent) in action_buttons.iter() {
+    for (interaction, action) in action_buttons.iter() {
         if *interaction == Interaction::Pressed {
-            if let Ok(toolbar) = toolbars.get(parent.parent()) {
-                action_events.write(ToolbarActionEvent {
-                    toolbar,
-                    action: action.id.clone(),
-                });
-            }
+            action_events.write(ToolbarActionEvent {
+                toolbar: action.toolbar,
+                action: action.id.clone(),
+            });
+        }
+    }
+}
+
+/// Measures each toolbar and moves the actions that don't fit into the overflow menu.
+fn toolbar_overflow_layout_system(
+    mut toolbars: Query<(&MaterialToolbar, &ComputedNode, &mut ToolbarOverflow)>,
+) {
+    for (toolbar, computed, mut overflow) in toolbars.iter_mut() {
+        let width = computed.size().x * computed.inverse_scale_factor;
+        if width <= 0.0 {
+            // Not laid out yet.
+            continue;
+        }
+
+        let mut available =
+            width - 2.0 * Spacing::LARGE - TOOLBAR_TITLE_MIN_WIDTH - Spacing::MEDIUM;
+        if toolbar.navigation_icon.is_some() {
+            available -= ICON_BUTTON_SIZE + Spacing::MEDIUM;
+        }
+
+        let next = ToolbarOverflow::fitting(
+            toolbar.actions.len(),
+            available,
+            ICON_BUTTON_SIZE,
+            Spacing::SMALL,
+        );
+        if *overflow != next {
+            *overflow = next;
         }
     }
 }
@@ -347,6 +472,7 @@ fn toolbar_theme_refresh_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::action_overflow::visible_action_count;
     use crate::icons::ICON_MENU;
 
     #[test]
@@ -363,4 +489,24 @@ mod tests {
         assert_eq!(toolbar.actions.len(), 1);
         assert_eq!(toolbar.actions[0].id, "menu");
     }
+
+    #[test]
+    fn test_toolbar_overflow_count() {
+        let slot = ICON_BUTTON_SIZE + Spacing::SMALL;
+        let count =
+            |width, actions| visible_action_count(width, actions, ICON_BUTTON_SIZE, Spacing::SMALL);
+        // Everything fits.
+        assert_eq!(count(3.0 * slot, 3), 3);
+        // One short: the last visible slot goes to the overflow button.
+        assert_eq!(count(3.0 * slot, 4), 2);
+        // No room at all.
+        assert_eq!(count(0.0, 2), 0);
+    }
+
+    #[test]
+    fn test_toolbar_action_menu_label() {
+        let action = ToolbarAction::new(ICON_MENU, "menu");
+        assert_eq!(action.menu_label(), "menu");
+        assert_eq!(action.with_label("Open menu").menu_label(), "Open menu");
+    }
 }
@@ -10,8 +10,13 @@ use bevy::prelude::*;
 use bevy::ui::UiGlobalTransform;
 
 use crate::{
+    focus::Focusable,
     i18n::{MaterialI18n, MaterialLanguage, MaterialLanguageOverride},
-    motion::{ease_standard_accelerate, ease_standard_decelerate},
+    motion::{ease_standard_accelerate, ease_standard_decelerate, ReducedMotion},
+    overlay::{
+        DismissOnOutsideClick, OutsideClickEvent, OverlayReflowSystems, OverlayStack, Z_TOOLTIP,
+    },
+    telemetry::ElementBounds,
     theme::MaterialTheme,
     tokens::{CornerRadius, Duration, Spacing},
 };
@@ -28,15 +33,27 @@ impl Plugin for TooltipPlugin {
         if !app.is_plugin_added::<crate::MaterialUiCorePlugin>() {
             app.add_plugins(crate::MaterialUiCorePlugin);
         }
-        app.add_systems(Startup, setup_tooltip_overlay).add_systems(
-            Update,
-            (
-                tooltip_localization_system,
-                tooltip_hover_system,
-                tooltip_animation_system,
-                tooltip_position_system,
-            ),
-        );
+        app.init_resource::<TooltipConfig>()
+            .add_message::<RichTooltipActionEvent>()
+            .add_systems(Startup, setup_tooltip_overlay)
+            .add_systems(
+                Update,
+                (
+                    tooltip_localization_system,
+                    tooltip_hint_system,
+                    tooltip_hover_system,
+                    rich_tooltip_action_system,
+                    rich_tooltip_outside_click_system,
+                    rich_tooltip_overlay_system,
+                    rich_tooltip_keyboard_dismiss_system,
+                    tooltip_animation_system,
+                    tooltip_position_system,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                tooltip_position_system.in_set(OverlayReflowSystems),
+            );
     }
 }
 
@@ -157,7 +174,7 @@ fn setup_tooltip_overlay(mut commands: Commands) {
             left: Val::Px(0.0),
             ..default()
         },
-        GlobalZIndex(1000),
+        GlobalZIndex(Z_TOOLTIP),
         // Make it not pickable so it doesn't block clicks
         Pickable::IGNORE,
     ));
@@ -189,6 +206,21 @@ pub enum TooltipPosition {
     Left,
     /// Right of the anchor
     Right,
+    /// Below the mouse cursor, tracking it while hovered (useful for large anchors)
+    FollowCursor,
+}
+
+impl TooltipPosition {
+    /// The position on the opposite side of the anchor
+    pub fn opposite(self) -> Self {
+        match self {
+            TooltipPosition::Top => TooltipPosition::Bottom,
+            TooltipPosition::Bottom => TooltipPosition::Top,
+            TooltipPosition::Left => TooltipPosition::Right,
+            TooltipPosition::Right => TooltipPosition::Left,
+            TooltipPosition::FollowCursor => TooltipPosition::FollowCursor,
+        }
+    }
 }
 
 // ============================================================================
@@ -204,9 +236,10 @@ pub struct TooltipTrigger {
     pub variant: TooltipVariant,
     /// Preferred position
     pub position: TooltipPosition,
-    /// Delay before showing (in seconds)
-    pub delay: f32,
-    /// Current hover time
+    /// Delay before showing (in seconds); `None` uses [`TooltipConfig::show_delay`]
+    pub delay: Option<f32>,
+    /// Time since the hover started, or since it ended while the tooltip is
+    /// still showing
     pub hover_time: f32,
     /// Whether currently hovered
     pub hovered: bool,
@@ -221,7 +254,7 @@ impl TooltipTrigger {
             text: text.into(),
             variant: TooltipVariant::default(),
             position: TooltipPosition::default(),
-            delay: TOOLTIP_DELAY_DEFAULT,
+            delay: None,
             hover_time: 0.0,
             hovered: false,
             tooltip_entity: None,
@@ -234,9 +267,10 @@ impl TooltipTrigger {
         self
     }
 
-    /// Set the delay
+    /// Set the delay before showing (in seconds), overriding
+    /// [`TooltipConfig::show_delay`]
     pub fn with_delay(mut self, delay: f32) -> Self {
-        self.delay = delay;
+        self.delay = Some(delay);
         self
     }
 
@@ -265,6 +299,39 @@ impl TooltipTrigger {
     pub fn right(self) -> Self {
         self.with_position(TooltipPosition::Right)
     }
+
+    /// Follow the mouse cursor
+    pub fn follow_cursor(self) -> Self {
+        self.with_position(TooltipPosition::FollowCursor)
+    }
+}
+
+/// A plain tooltip for an existing interactive entity
+///
+/// Insert on any entity with [`Interaction`] (e.g. an already spawned button) to show a
+/// tooltip on hover or keyboard focus. A [`TooltipTrigger`] is added and kept in sync, so the
+/// usual delay and show/hide behavior applies; removing the hint removes the trigger.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TooltipHint {
+    /// The tooltip text
+    pub text: String,
+    /// Preferred position
+    pub position: TooltipPosition,
+}
+
+impl TooltipHint {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            position: TooltipPosition::default(),
+        }
+    }
+
+    /// Set the position
+    pub fn with_position(mut self, position: TooltipPosition) -> Self {
+        self.position = position;
+        self
+    }
 }
 
 /// The tooltip popup component
@@ -353,14 +420,29 @@ impl Tooltip {
 }
 
 /// Rich tooltip with additional content
-#[derive(Component)]
+///
+/// Add it next to a [`TooltipTrigger`] with [`TooltipVariant::Rich`] to show it on hover.
+/// Rich tooltips with actions are persistent: they stay open after the pointer leaves the
+/// anchor (so the user can move into them) until clicked outside or dismissed by an action.
+#[derive(Component, Debug, Clone, PartialEq)]
 pub struct RichTooltip {
     /// Title text
     pub title: Option<String>,
     /// Supporting text
     pub supporting_text: String,
-    /// Action text (optional)
-    pub action: Option<String>,
+    /// Action buttons, in order
+    pub actions: Vec<RichTooltipAction>,
+}
+
+/// A text button in a [`RichTooltip`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichTooltipAction {
+    /// Identifier reported in [`RichTooltipActionEvent`]
+    pub id: String,
+    /// Button label
+    pub label: String,
+    /// Whether clicking the action closes the tooltip
+    pub dismiss: bool,
 }
 
 impl RichTooltip {
@@ -369,7 +451,7 @@ impl RichTooltip {
         Self {
             title: None,
             supporting_text: supporting_text.into(),
-            action: None,
+            actions: Vec::new(),
         }
     }
 
@@ -379,11 +461,57 @@ impl RichTooltip {
         self
     }
 
-    /// Set the action
-    pub fn with_action(mut self, action: impl Into<String>) -> Self {
-        self.action = Some(action.into());
+    /// Add an action whose id is its label
+    pub fn with_action(self, action: impl Into<String>) -> Self {
+        let action = action.into();
+        self.with_action_id(action.clone(), action)
+    }
+
+    /// Add an action with a separate id
+    pub fn with_action_id(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.actions.push(RichTooltipAction {
+            id: id.into(),
+            label: label.into(),
+            dismiss: false,
+        });
+        self
+    }
+
+    /// Add an action (id [`RICH_TOOLTIP_DISMISS_ACTION`]) that closes the tooltip
+    pub fn with_dismiss_action(mut self, label: impl Into<String>) -> Self {
+        self.actions.push(RichTooltipAction {
+            id: RICH_TOOLTIP_DISMISS_ACTION.to_string(),
+            label: label.into(),
+            dismiss: true,
+        });
         self
     }
+
+    /// Whether the tooltip stays open until an outside click or a dismiss action
+    pub fn is_persistent(&self) -> bool {
+        !self.actions.is_empty()
+    }
+}
+
+/// Action id used by [`RichTooltip::with_dismiss_action`]
+pub const RICH_TOOLTIP_DISMISS_ACTION: &str = "dismiss";
+
+/// Action button inside a rich tooltip
+#[derive(Component, Debug, Clone)]
+pub struct RichTooltipActionButton {
+    /// The tooltip entity
+    pub tooltip: Entity,
+    /// The action this button triggers
+    pub action: RichTooltipAction,
+}
+
+/// Written when a rich tooltip action is clicked
+#[derive(Event, bevy::prelude::Message, Clone, Debug)]
+pub struct RichTooltipActionEvent {
+    /// The tooltip entity; its [`Tooltip::anchor`] is the trigger
+    pub tooltip: Entity,
+    /// [`RichTooltipAction::id`] of the clicked action
+    pub action_id: String,
 }
 
 /// Marker for tooltip text
@@ -413,6 +541,29 @@ pub const TOOLTIP_DELAY_DEFAULT: f32 = 0.5;
 /// Short delay (for experienced users)
 pub const TOOLTIP_DELAY_SHORT: f32 = 0.15;
 
+/// App-wide tooltip timing, read by [`TooltipPlugin`]
+///
+/// Touch-first apps usually want a longer show delay (so a tap doesn't flash
+/// a tooltip) and mouse-driven tools a shorter one.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TooltipConfig {
+    /// Hover time before a tooltip shows (in seconds), for triggers without
+    /// their own [`delay`](TooltipTrigger::delay)
+    pub show_delay: f32,
+    /// Time a tooltip stays after the pointer leaves its trigger (in seconds).
+    /// Pressing the trigger always hides it at once.
+    pub hide_delay: f32,
+}
+
+impl Default for TooltipConfig {
+    fn default() -> Self {
+        Self {
+            show_delay: TOOLTIP_DELAY_DEFAULT,
+            hide_delay: 0.0,
+        }
+    }
+}
+
 // ============================================================================
 // Builder
 // ============================================================================
@@ -436,9 +587,13 @@ impl TooltipTriggerBuilder {
         self
     }
 
-    /// Set delay
+    /// Set the delay before showing (in seconds), overriding
+    /// [`TooltipConfig::show_delay`]
+    ///
+    /// Use [`TOOLTIP_DELAY_SHORT`] or the [`Duration`](crate::tokens::Duration)
+    /// tokens for common values.
     pub fn delay(mut self, delay: f32) -> Self {
-        self.trigger.delay = delay;
+        self.trigger.delay = Some(delay);
         self
     }
 
@@ -569,7 +724,7 @@ pub fn spawn_tooltip(commands: &mut Commands, theme: &MaterialTheme, tooltip: To
             },
             BackgroundColor(bg_color),
             BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
-            GlobalZIndex(1000), // Ensure tooltips are on top
+            GlobalZIndex(Z_TOOLTIP),
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -591,57 +746,281 @@ pub fn spawn_rich_tooltip(
     theme: &MaterialTheme,
     tooltip: Tooltip,
     rich: RichTooltip,
+) -> Entity {
+    let tooltip_entity = spawn_rich_tooltip_content(commands, theme, tooltip, rich);
+    commands
+        .entity(tooltip_entity)
+        .insert(GlobalZIndex(Z_TOOLTIP));
+    tooltip_entity
+}
+
+/// Spawn a rich tooltip as a child of the overlay
+fn spawn_rich_tooltip_on_overlay(
+    commands: &mut Commands,
+    theme: &MaterialTheme,
+    tooltip: Tooltip,
+    rich: RichTooltip,
+    overlay: Entity,
+) -> Entity {
+    let tooltip_entity = spawn_rich_tooltip_content(commands, theme, tooltip, rich);
+    commands.entity(overlay).add_child(tooltip_entity);
+    tooltip_entity
+}
+
+fn spawn_rich_tooltip_content(
+    commands: &mut Commands,
+    theme: &MaterialTheme,
+    tooltip: Tooltip,
+    rich: RichTooltip,
 ) -> Entity {
     let text_color = tooltip.text_color(theme);
     let bg_color = tooltip.background_color(theme);
+    let action_color = theme.primary;
+    let persistent = rich.is_persistent();
+    let anchor = tooltip.anchor;
+    let title = rich.title.clone();
+    let supporting_text = rich.supporting_text.clone();
+    let actions = rich.actions.clone();
+
+    let mut root = commands.spawn((
+        tooltip,
+        rich,
+        Node {
+            position_type: PositionType::Absolute,
+            min_height: Val::Px(TOOLTIP_HEIGHT_RICH_MIN),
+            max_width: Val::Px(TOOLTIP_MAX_WIDTH_RICH),
+            padding: UiRect::all(Val::Px(TOOLTIP_PADDING_RICH)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(Spacing::EXTRA_SMALL),
+            // Start offscreen - position system will update
+            top: Val::Px(-1000.0),
+            left: Val::Px(-1000.0),
+            ..default()
+        },
+        BackgroundColor(bg_color),
+        BorderRadius::all(Val::Px(CornerRadius::MEDIUM)),
+    ));
+    // Persistent tooltips take pointer input so the user can move into them and click.
+    if persistent {
+        root.insert((Interaction::None, DismissOnOutsideClick::new(anchor)));
+    } else {
+        root.insert(Pickable::IGNORE);
+    }
 
-    commands
-        .spawn((
-            tooltip,
-            rich,
-            Node {
-                position_type: PositionType::Absolute,
-                min_height: Val::Px(TOOLTIP_HEIGHT_RICH_MIN),
-                max_width: Val::Px(TOOLTIP_MAX_WIDTH_RICH),
-                padding: UiRect::all(Val::Px(TOOLTIP_PADDING_RICH)),
-                flex_direction: FlexDirection::Column,
-                row_gap: Val::Px(Spacing::EXTRA_SMALL),
-                ..default()
-            },
-            BackgroundColor(bg_color),
-            BorderRadius::all(Val::Px(CornerRadius::MEDIUM)),
-            GlobalZIndex(1000),
-        ))
-        .with_children(|parent| {
-            // Title (if present) would go here
-            // Supporting text
+    let tooltip_entity = root.id();
+    root.with_children(|parent| {
+        if let Some(title) = title {
             parent.spawn((
-                TooltipText,
-                Text::new(""), // Rich tooltip content handled separately
+                Text::new(title),
                 TextFont {
-                    font_size: 12.0,
+                    font_size: 14.0,
                     ..default()
                 },
                 TextColor(text_color),
             ));
-        })
-        .id()
+        }
+
+        parent.spawn((
+            TooltipText,
+            Text::new(supporting_text),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+
+        if actions.is_empty() {
+            return;
+        }
+        parent
+            .spawn(Node {
+                column_gap: Val::Px(Spacing::SMALL),
+                margin: UiRect::top(Val::Px(Spacing::EXTRA_SMALL)),
+                // Text buttons sit flush with the content edge.
+                left: Val::Px(-Spacing::SMALL),
+                ..default()
+            })
+            .with_children(|row| {
+                for action in actions {
+                    let label = action.label.clone();
+                    row.spawn((
+                        RichTooltipActionButton {
+                            tooltip: tooltip_entity,
+                            action,
+                        },
+                        Button,
+                        Interaction::None,
+                        Node {
+                            padding: UiRect::axes(
+                                Val::Px(Spacing::SMALL),
+                                Val::Px(Spacing::EXTRA_SMALL),
+                            ),
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(action_color),
+                        ));
+                    });
+                }
+            });
+    });
+
+    tooltip_entity
+}
+
+/// Compute the top-left corner of a tooltip placed next to `anchor`.
+///
+/// All values are logical pixels in the same space as `viewport` (origin at the
+/// top-left). If the tooltip does not fit on the `preferred` side, it flips to the
+/// opposite side when that side has room; it is then clamped along the cross
+/// axis so it stays on screen. [`TOOLTIP_OFFSET`] separates tooltip and anchor
+/// on every side.
+///
+/// [`TooltipPosition::FollowCursor`] is treated as [`TooltipPosition::Bottom`];
+/// pass a zero-sized anchor at the cursor location for that mode.
+///
+/// Returns the side actually used and the tooltip's top-left corner.
+pub fn resolve_tooltip_placement(
+    preferred: TooltipPosition,
+    anchor: &ElementBounds,
+    tooltip_size: Vec2,
+    viewport: Vec2,
+) -> (TooltipPosition, Vec2) {
+    let origin_for = |position: TooltipPosition| -> Vec2 {
+        let center_x = anchor.x + (anchor.width - tooltip_size.x) / 2.0;
+        let center_y = anchor.y + (anchor.height - tooltip_size.y) / 2.0;
+        match position {
+            TooltipPosition::Top => Vec2::new(center_x, anchor.y - TOOLTIP_OFFSET - tooltip_size.y),
+            TooltipPosition::Bottom | TooltipPosition::FollowCursor => {
+                Vec2::new(center_x, anchor.y + anchor.height + TOOLTIP_OFFSET)
+            }
+            TooltipPosition::Left => {
+                Vec2::new(anchor.x - TOOLTIP_OFFSET - tooltip_size.x, center_y)
+            }
+            TooltipPosition::Right => Vec2::new(anchor.x + anchor.width + TOOLTIP_OFFSET, center_y),
+        }
+    };
+
+    let fits = |position: TooltipPosition, origin: Vec2| -> bool {
+        match position {
+            TooltipPosition::Top => origin.y >= 0.0,
+            TooltipPosition::Bottom | TooltipPosition::FollowCursor => {
+                origin.y + tooltip_size.y <= viewport.y
+            }
+            TooltipPosition::Left => origin.x >= 0.0,
+            TooltipPosition::Right => origin.x + tooltip_size.x <= viewport.x,
+        }
+    };
+
+    let side = match preferred {
+        TooltipPosition::FollowCursor => TooltipPosition::Bottom,
+        other => other,
+    };
+
+    let mut resolved = side;
+    let mut origin = origin_for(side);
+    if !fits(side, origin) {
+        let flipped = side.opposite();
+        let flipped_origin = origin_for(flipped);
+        if fits(flipped, flipped_origin) {
+            resolved = flipped;
+            origin = flipped_origin;
+        }
+    }
+
+    // Keep the tooltip on screen along the cross axis.
+    let clamp_axis = |value: f32, size: f32, limit: f32| -> f32 {
+        if size >= limit {
+            0.0
+        } else {
+            value.clamp(0.0, limit - size)
+        }
+    };
+    match resolved {
+        TooltipPosition::Top | TooltipPosition::Bottom | TooltipPosition::FollowCursor => {
+            origin.x = clamp_axis(origin.x, tooltip_size.x, viewport.x);
+        }
+        TooltipPosition::Left | TooltipPosition::Right => {
+            origin.y = clamp_axis(origin.y, tooltip_size.y, viewport.y);
+        }
+    }
+
+    if preferred == TooltipPosition::FollowCursor && resolved == TooltipPosition::Bottom {
+        resolved = TooltipPosition::FollowCursor;
+    }
+
+    (resolved, origin)
 }
 
 // ============================================================================
 // Systems
 // ============================================================================
 
+/// System to attach and update triggers for [`TooltipHint`] entities
+fn tooltip_hint_system(
+    mut commands: Commands,
+    mut hints: Query<(Entity, &TooltipHint, Option<&mut TooltipTrigger>), Changed<TooltipHint>>,
+    mut removed: RemovedComponents<TooltipHint>,
+    triggers: Query<&TooltipTrigger, Without<TooltipHint>>,
+    mut tooltips: Query<&mut Tooltip>,
+) {
+    for (entity, hint, trigger) in hints.iter_mut() {
+        match trigger {
+            Some(mut trigger) => {
+                trigger.text = hint.text.clone();
+                trigger.position = hint.position;
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(TooltipTrigger::new(hint.text.clone()).with_position(hint.position));
+            }
+        }
+    }
+
+    for entity in removed.read() {
+        let Ok(trigger) = triggers.get(entity) else {
+            continue;
+        };
+        if let Some(mut tooltip) = trigger
+            .tooltip_entity
+            .and_then(|e| tooltips.get_mut(e).ok())
+        {
+            tooltip.dismiss();
+        }
+        commands.entity(entity).try_remove::<TooltipTrigger>();
+    }
+}
+
 /// System to handle tooltip hover triggers
+///
+/// Keyboard focus shows the tooltip just like hovering.
 fn tooltip_hover_system(
     mut commands: Commands,
     time: Res<Time>,
     theme: Option<Res<MaterialTheme>>,
-    mut triggers: Query<(Entity, &Interaction, &mut TooltipTrigger)>,
-    mut tooltips: Query<&mut Tooltip>,
+    config: Option<Res<TooltipConfig>>,
+    mut triggers: Query<(
+        Entity,
+        &Interaction,
+        Option<&Focusable>,
+        Option<&RichTooltip>,
+        &mut TooltipTrigger,
+    )>,
+    mut tooltips: Query<(&mut Tooltip, Option<&RichTooltip>)>,
     overlay_query: Query<Entity, With<TooltipOverlay>>,
 ) {
     let Some(theme) = theme else { return };
+    let config = config.map(|config| *config).unwrap_or_default();
 
     // Try to get the overlay entity - silently skip if not available yet
     let mut overlay_iter = overlay_query.iter();
@@ -649,8 +1028,27 @@ fn tooltip_hover_system(
         return;
     };
 
-    for (entity, interaction, mut trigger) in triggers.iter_mut() {
-        match *interaction {
+    for (entity, interaction, focusable, rich, mut trigger) in triggers.iter_mut() {
+        // Forget tooltips dismissed elsewhere (e.g. a persistent tooltip's dismiss action).
+        if let Some(tooltip_entity) = trigger.tooltip_entity {
+            let showing = tooltips.get(tooltip_entity).is_ok_and(|(tooltip, _)| {
+                !matches!(
+                    tooltip.animation_state,
+                    TooltipAnimationState::Exiting | TooltipAnimationState::Hidden
+                )
+            });
+            if !showing {
+                trigger.tooltip_entity = None;
+            }
+        }
+
+        let keyboard_focused = focusable.is_some_and(|f| f.focused && f.focus_visible);
+        let interaction = if keyboard_focused {
+            Interaction::Hovered
+        } else {
+            *interaction
+        };
+        match interaction {
             Interaction::Hovered => {
                 if !trigger.hovered {
                     trigger.hovered = true;
@@ -660,11 +1058,24 @@ fn tooltip_hover_system(
                 trigger.hover_time += time.delta_secs();
 
                 // Show tooltip after delay
-                if trigger.hover_time >= trigger.delay && trigger.tooltip_entity.is_none() {
+                let delay = trigger.delay.unwrap_or(config.show_delay);
+                if trigger.hover_time >= delay && trigger.tooltip_entity.is_none() {
                     let tooltip =
                         Tooltip::new(&trigger.text, entity).with_position(trigger.position);
-                    let tooltip_entity =
-                        spawn_tooltip_on_overlay(&mut commands, &theme, tooltip, overlay_entity);
+                    let tooltip_entity = match rich {
+                        Some(rich) if trigger.variant == TooltipVariant::Rich => {
+                            spawn_rich_tooltip_on_overlay(
+                                &mut commands,
+                                &theme,
+                                tooltip.with_variant(TooltipVariant::Rich),
+                                rich.clone(),
+                                overlay_entity,
+                            )
+                        }
+                        _ => {
+                            spawn_tooltip_on_overlay(&mut commands, &theme, tooltip, overlay_entity)
+                        }
+                    };
                     trigger.tooltip_entity = Some(tooltip_entity);
                 }
             }
@@ -672,29 +1083,118 @@ fn tooltip_hover_system(
                 if trigger.hovered {
                     trigger.hovered = false;
                     trigger.hover_time = 0.0;
+                }
+                let Some(tooltip_entity) = trigger.tooltip_entity else {
+                    continue;
+                };
 
-                    // Dismiss tooltip
-                    if let Some(tooltip_entity) = trigger.tooltip_entity {
-                        if let Ok(mut tooltip) = tooltips.get_mut(tooltip_entity) {
-                            tooltip.dismiss();
-                        }
-                        trigger.tooltip_entity = None;
+                // Leaving waits for the hide delay; pressing hides at once.
+                trigger.hover_time += time.delta_secs();
+                if interaction == Interaction::None && trigger.hover_time < config.hide_delay {
+                    continue;
+                }
+
+                // Dismiss tooltip; persistent rich tooltips wait for an outside click.
+                if let Ok((mut tooltip, rich)) = tooltips.get_mut(tooltip_entity) {
+                    if rich.is_some_and(RichTooltip::is_persistent) {
+                        continue;
                     }
+                    tooltip.dismiss();
                 }
+                trigger.tooltip_entity = None;
+            }
+        }
+    }
+}
+
+/// System to handle rich tooltip action clicks
+fn rich_tooltip_action_system(
+    buttons: Query<(&Interaction, &RichTooltipActionButton), Changed<Interaction>>,
+    mut tooltips: Query<&mut Tooltip>,
+    mut events: MessageWriter<RichTooltipActionEvent>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        events.write(RichTooltipActionEvent {
+            tooltip: button.tooltip,
+            action_id: button.action.id.clone(),
+        });
+        if button.action.dismiss {
+            if let Ok(mut tooltip) = tooltips.get_mut(button.tooltip) {
+                tooltip.dismiss();
             }
         }
     }
 }
 
+/// Close persistent rich tooltips when the user clicks outside them and their anchor.
+fn rich_tooltip_outside_click_system(
+    mut clicks: MessageReader<OutsideClickEvent>,
+    mut tooltips: Query<(&mut Tooltip, &RichTooltip)>,
+) {
+    for ev in clicks.read() {
+        if let Ok((mut tooltip, rich)) = tooltips.get_mut(ev.entity) {
+            if rich.is_persistent() {
+                tooltip.dismiss();
+            }
+        }
+    }
+}
+
+/// Keep persistent rich tooltips on the overlay stack while they are showing.
+///
+/// Plain and transient tooltips follow the pointer, so they never take Escape
+/// away from the overlays underneath.
+fn rich_tooltip_overlay_system(
+    stack: Option<ResMut<OverlayStack>>,
+    tooltips: Query<(Entity, &Tooltip, &RichTooltip), Changed<Tooltip>>,
+    mut removed: RemovedComponents<Tooltip>,
+) {
+    let Some(mut stack) = stack else { return };
+
+    for entity in removed.read() {
+        stack.remove(entity);
+    }
+    for (entity, tooltip, rich) in tooltips.iter() {
+        let showing = matches!(
+            tooltip.animation_state,
+            TooltipAnimationState::Entering | TooltipAnimationState::Visible
+        );
+        stack.sync(entity, showing && rich.is_persistent());
+    }
+}
+
+/// Dismiss the frontmost persistent rich tooltip when Escape is pressed.
+fn rich_tooltip_keyboard_dismiss_system(
+    stack: Option<Res<OverlayStack>>,
+    mut tooltips: Query<(Entity, &mut Tooltip), With<RichTooltip>>,
+) {
+    let Some(stack) = stack else { return };
+
+    for (entity, mut tooltip) in tooltips.iter_mut() {
+        if stack.escape_pressed_for(entity) {
+            tooltip.dismiss();
+        }
+    }
+}
+
 /// System to animate tooltips
 fn tooltip_animation_system(
     mut commands: Commands,
     time: Res<Time>,
+    reduced_motion: Option<Res<ReducedMotion>>,
     mut tooltips: Query<(Entity, &mut Tooltip, &mut BackgroundColor)>,
 ) {
-    for (entity, mut tooltip, mut bg_color) in tooltips.iter_mut() {
-        let dt = time.delta_secs();
+    // Reduced motion shows and hides tooltips without fading.
+    let dt = if reduced_motion.is_some_and(|r| r.0) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
 
+    for (entity, mut tooltip, mut bg_color) in tooltips.iter_mut() {
         match tooltip.animation_state {
             TooltipAnimationState::Entering => {
                 tooltip.animation_progress += dt / Duration::SHORT3;
@@ -726,19 +1226,21 @@ fn tooltip_animation_system(
     }
 }
 
-/// System to position tooltips relative to their anchors
+/// System to position tooltips relative to their anchors.
+///
+/// Tooltips that would be clipped by the window edge are flipped to the opposite
+/// side of their anchor; `FollowCursor` tooltips track the mouse.
 fn tooltip_position_system(
     mut tooltips: Query<(&Tooltip, &mut Node, &ComputedNode)>,
     anchors: Query<(&UiGlobalTransform, &ComputedNode)>,
     overlay_query: Query<(&UiGlobalTransform, &ComputedNode), With<TooltipOverlay>>,
     windows: Query<&Window>,
 ) {
+    let window = windows.iter().next();
+
     // Get window scale factor
-    let scale_factor = windows
-        .iter()
-        .next()
-        .map(|w| w.scale_factor())
-        .unwrap_or(1.0);
+    let scale = window.map(|w| w.scale_factor()).unwrap_or(1.0);
+    let cursor = window.and_then(|w| w.cursor_position());
 
     // Get overlay's position and size to calculate offset
     // UiGlobalTransform gives CENTER of element, so we need to adjust
@@ -751,13 +1253,20 @@ fn tooltip_position_system(
     // Calculate overlay's top-left corner
     let overlay_top_left = overlay_center - overlay_size / 2.0;
 
+    // The overlay covers the window; fall back to the window size before layout.
+    let viewport = if overlay_size.x > 0.0 && overlay_size.y > 0.0 {
+        overlay_size / scale
+    } else {
+        window
+            .map(|w| Vec2::new(w.width(), w.height()))
+            .unwrap_or(Vec2::splat(f32::MAX))
+    };
+
     for (tooltip, mut node, tooltip_computed) in tooltips.iter_mut() {
         let Ok((anchor_transform, anchor_computed)) = anchors.get(tooltip.anchor) else {
             continue;
         };
 
-        let scale = scale_factor;
-
         // Both UiGlobalTransform and ComputedNode.size() are in physical pixels
         let anchor_center_physical = anchor_transform.translation;
         let anchor_size_physical = anchor_computed.size();
@@ -767,55 +1276,45 @@ fn tooltip_position_system(
             continue;
         }
 
-        // Calculate anchor's top-left corner in physical pixels
-        let anchor_top_left_physical = anchor_center_physical - anchor_size_physical / 2.0;
+        // Anchor bounds in logical pixels, relative to the overlay
+        let anchor_top_left =
+            (anchor_center_physical - anchor_size_physical / 2.0 - overlay_top_left) / scale;
+        let anchor_size = anchor_size_physical / scale;
 
-        // Tooltip size is also in physical pixels
+        // Tooltip size (logical) with a fallback until it has been laid out
         let tooltip_size_physical = tooltip_computed.size();
-        let tooltip_width_physical = if tooltip_size_physical.x > 0.0 {
-            tooltip_size_physical.x
-        } else {
-            TOOLTIP_MAX_WIDTH * scale / 2.0
-        };
-        let tooltip_height_physical = if tooltip_size_physical.y > 0.0 {
-            tooltip_size_physical.y
-        } else {
-            TOOLTIP_HEIGHT_PLAIN * scale
-        };
-
-        // Offset in physical pixels
-        let offset_physical = TOOLTIP_OFFSET * scale;
+        let tooltip_size = Vec2::new(
+            if tooltip_size_physical.x > 0.0 {
+                tooltip_size_physical.x / scale
+            } else {
+                TOOLTIP_MAX_WIDTH / 2.0
+            },
+            if tooltip_size_physical.y > 0.0 {
+                tooltip_size_physical.y / scale
+            } else {
+                TOOLTIP_HEIGHT_PLAIN
+            },
+        );
 
-        // Calculate tooltip position in physical pixels
-        let (screen_top_physical, screen_left_physical) = match tooltip.position {
-            TooltipPosition::Top => (
-                anchor_top_left_physical.y - offset_physical - tooltip_height_physical,
-                anchor_top_left_physical.x
-                    + (anchor_size_physical.x - tooltip_width_physical) / 2.0,
-            ),
-            TooltipPosition::Bottom => (
-                anchor_top_left_physical.y + anchor_size_physical.y + offset_physical,
-                anchor_top_left_physical.x
-                    + (anchor_size_physical.x - tooltip_width_physical) / 2.0,
-            ),
-            TooltipPosition::Left => (
-                anchor_top_left_physical.y
-                    + (anchor_size_physical.y - tooltip_height_physical) / 2.0,
-                anchor_top_left_physical.x - offset_physical - tooltip_width_physical,
-            ),
-            TooltipPosition::Right => (
-                anchor_top_left_physical.y
-                    + (anchor_size_physical.y - tooltip_height_physical) / 2.0,
-                anchor_top_left_physical.x + anchor_size_physical.x + offset_physical,
+        let anchor_bounds = match (tooltip.position, cursor) {
+            (TooltipPosition::FollowCursor, Some(cursor)) => {
+                let cursor = cursor - overlay_top_left / scale;
+                ElementBounds::new("tooltip_cursor", cursor.x, cursor.y, 0.0, 0.0)
+            }
+            _ => ElementBounds::new(
+                "tooltip_anchor",
+                anchor_top_left.x,
+                anchor_top_left.y,
+                anchor_size.x,
+                anchor_size.y,
             ),
         };
 
-        // Convert to logical pixels for Node positioning (divide by scale)
-        let top = (screen_top_physical - overlay_top_left.y) / scale;
-        let left = (screen_left_physical - overlay_top_left.x) / scale;
+        let (_, top_left) =
+            resolve_tooltip_placement(tooltip.position, &anchor_bounds, tooltip_size, viewport);
 
-        node.top = Val::Px(top);
-        node.left = Val::Px(left);
+        node.top = Val::Px(top_left.y);
+        node.left = Val::Px(top_left.x);
     }
 }
 
@@ -832,7 +1331,7 @@ mod tests {
         let trigger = TooltipTrigger::new("Help text");
         assert_eq!(trigger.text, "Help text");
         assert_eq!(trigger.position, TooltipPosition::Top);
-        assert!((trigger.delay - TOOLTIP_DELAY_DEFAULT).abs() < 0.001);
+        assert_eq!(trigger.delay, None);
     }
 
     #[test]
@@ -840,7 +1339,7 @@ mod tests {
         let trigger = TooltipTrigger::new("Test").bottom().with_delay(0.2);
 
         assert_eq!(trigger.position, TooltipPosition::Bottom);
-        assert!((trigger.delay - 0.2).abs() < 0.001);
+        assert_eq!(trigger.delay, Some(0.2));
     }
 
     #[test]
@@ -861,7 +1360,9 @@ mod tests {
 
         assert_eq!(rich.title, Some("Title".to_string()));
         assert_eq!(rich.supporting_text, "Supporting text");
-        assert_eq!(rich.action, Some("Learn more".to_string()));
+        assert_eq!(rich.actions.len(), 1);
+        assert_eq!(rich.actions[0].label, "Learn more");
+        assert!(rich.is_persistent());
     }
 
     #[test]
@@ -879,6 +1380,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tooltip_placement_preferred_side() {
+        let anchor = ElementBounds::new("anchor", 100.0, 100.0, 40.0, 40.0);
+        let size = Vec2::new(60.0, TOOLTIP_HEIGHT_PLAIN);
+        let viewport = Vec2::new(800.0, 600.0);
+
+        let (side, origin) =
+            resolve_tooltip_placement(TooltipPosition::Top, &anchor, size, viewport);
+        assert_eq!(side, TooltipPosition::Top);
+        assert_eq!(origin, Vec2::new(90.0, 100.0 - TOOLTIP_OFFSET - size.y));
+    }
+
+    #[test]
+    fn test_tooltip_placement_flips_near_edges() {
+        let size = Vec2::new(60.0, TOOLTIP_HEIGHT_PLAIN);
+        let viewport = Vec2::new(800.0, 600.0);
+
+        // Anchor hugging the top edge: Top flips to Bottom.
+        let top_anchor = ElementBounds::new("anchor", 100.0, 0.0, 40.0, 40.0);
+        let (side, origin) =
+            resolve_tooltip_placement(TooltipPosition::Top, &top_anchor, size, viewport);
+        assert_eq!(side, TooltipPosition::Bottom);
+        assert_eq!(origin.y, 40.0 + TOOLTIP_OFFSET);
+
+        // Anchor hugging the right edge: Right flips to Left.
+        let right_anchor = ElementBounds::new("anchor", 780.0, 100.0, 20.0, 20.0);
+        let (side, origin) =
+            resolve_tooltip_placement(TooltipPosition::Right, &right_anchor, size, viewport);
+        assert_eq!(side, TooltipPosition::Left);
+        assert_eq!(origin.x, 780.0 - TOOLTIP_OFFSET - size.x);
+
+        // Cross axis is clamped to the viewport.
+        let corner_anchor = ElementBounds::new("anchor", 0.0, 300.0, 10.0, 10.0);
+        let (_, origin) =
+            resolve_tooltip_placement(TooltipPosition::Bottom, &corner_anchor, size, viewport);
+        assert_eq!(origin.x, 0.0);
+    }
+
+    #[test]
+    fn test_tooltip_placement_follow_cursor() {
+        let cursor = ElementBounds::new("cursor", 400.0, 590.0, 0.0, 0.0);
+        let size = Vec2::new(60.0, TOOLTIP_HEIGHT_PLAIN);
+        let (side, origin) = resolve_tooltip_placement(
+            TooltipPosition::FollowCursor,
+            &cursor,
+            size,
+            Vec2::new(800.0, 600.0),
+        );
+        // Not enough room below the cursor, so it flips above.
+        assert_eq!(side, TooltipPosition::Top);
+        assert_eq!(origin, Vec2::new(370.0, 590.0 - TOOLTIP_OFFSET - size.y));
+    }
+
     #[test]
     fn test_tooltip_builder() {
         let trigger = TooltipTriggerBuilder::new("Hover me")
@@ -888,7 +1442,42 @@ mod tests {
 
         assert_eq!(trigger.text, "Hover me");
         assert_eq!(trigger.position, TooltipPosition::Right);
-        assert!((trigger.delay - 0.25).abs() < 0.001);
+        assert_eq!(trigger.delay, Some(0.25));
+    }
+
+    #[test]
+    fn test_tooltip_config_show_and_hide_delays() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MaterialTheme::default())
+            .insert_resource(TooltipConfig {
+                show_delay: 0.3,
+                hide_delay: 0.3,
+            })
+            .add_systems(Update, tooltip_hover_system);
+        app.world_mut().spawn(TooltipOverlay);
+        let trigger = app
+            .world_mut()
+            .spawn((Interaction::Hovered, TooltipTrigger::new("Help")))
+            .id();
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(200));
+            app.update();
+            app.world()
+                .get::<TooltipTrigger>(trigger)
+                .unwrap()
+                .tooltip_entity
+                .is_some()
+        };
+        assert!(!step(&mut app));
+        assert!(step(&mut app), "shown after the global show delay");
+
+        *app.world_mut().get_mut::<Interaction>(trigger).unwrap() = Interaction::None;
+        assert!(step(&mut app), "kept during the hide delay");
+        assert!(!step(&mut app));
     }
 
     #[test]
@@ -914,4 +1503,168 @@ mod tests {
         let trigger = TooltipTrigger::new("Test").right().with_delay(0.4);
         assert_eq!(trigger.position, TooltipPosition::Right);
     }
+
+    #[test]
+    fn test_tooltip_hint_shows_on_keyboard_focus_and_cleans_up() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MaterialTheme::default())
+            .insert_resource(TooltipConfig {
+                show_delay: 0.1,
+                hide_delay: 0.0,
+            })
+            .add_systems(Update, (tooltip_hint_system, tooltip_hover_system).chain());
+        app.world_mut().spawn(TooltipOverlay);
+        let button = app
+            .world_mut()
+            .spawn((
+                Interaction::None,
+                Focusable {
+                    focused: true,
+                    focus_visible: true,
+                    ..Focusable::new()
+                },
+                TooltipHint::new("Save").with_position(TooltipPosition::Bottom),
+            ))
+            .id();
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(200));
+            app.update();
+        };
+        step(&mut app);
+        step(&mut app);
+        let trigger = app.world().get::<TooltipTrigger>(button).unwrap();
+        assert_eq!(trigger.text, "Save");
+        assert_eq!(trigger.position, TooltipPosition::Bottom);
+        assert!(trigger.tooltip_entity.is_some(), "focus shows the tooltip");
+
+        // Editing the hint updates the trigger in place.
+        app.world_mut().get_mut::<TooltipHint>(button).unwrap().text = "Save all".into();
+        step(&mut app);
+        assert_eq!(
+            app.world().get::<TooltipTrigger>(button).unwrap().text,
+            "Save all"
+        );
+
+        app.world_mut().entity_mut(button).remove::<TooltipHint>();
+        step(&mut app);
+        assert!(app.world().get::<TooltipTrigger>(button).is_none());
+    }
+
+    #[test]
+    fn test_rich_tooltip_actions_write_events() {
+        let mut app = App::new();
+        app.add_message::<RichTooltipActionEvent>()
+            .add_systems(Update, rich_tooltip_action_system);
+
+        let rich = RichTooltip::new("Body")
+            .with_action_id("learn", "Learn more")
+            .with_dismiss_action("Close");
+        let tooltip = app
+            .world_mut()
+            .spawn((Tooltip::new("Body", Entity::PLACEHOLDER), rich.clone()))
+            .id();
+        let buttons: Vec<Entity> = rich
+            .actions
+            .into_iter()
+            .map(|action| {
+                app.world_mut()
+                    .spawn((
+                        Interaction::None,
+                        RichTooltipActionButton { tooltip, action },
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let press = |app: &mut App, button: Entity| {
+            *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+            app.update();
+            let events = app.world().resource::<Messages<RichTooltipActionEvent>>();
+            let event = events.iter_current_update_messages().last().unwrap();
+            assert_eq!(event.tooltip, tooltip);
+            event.action_id.clone()
+        };
+        let state = |app: &App| app.world().get::<Tooltip>(tooltip).unwrap().animation_state;
+
+        assert_eq!(press(&mut app, buttons[0]), "learn");
+        assert_eq!(state(&app), TooltipAnimationState::Entering);
+
+        assert_eq!(press(&mut app, buttons[1]), RICH_TOOLTIP_DISMISS_ACTION);
+        assert_eq!(state(&app), TooltipAnimationState::Exiting);
+    }
+
+    #[test]
+    fn test_persistent_rich_tooltip_outlives_hover_and_focus() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MaterialTheme::default())
+            .insert_resource(TooltipConfig {
+                show_delay: 0.1,
+                hide_delay: 0.0,
+            })
+            .add_systems(Update, tooltip_hover_system);
+        app.world_mut().spawn(TooltipOverlay);
+        let rich = RichTooltip::new("Body").with_action("Learn more");
+        let hovered = app
+            .world_mut()
+            .spawn((
+                Interaction::Hovered,
+                TooltipTrigger::new("Help").rich(),
+                rich.clone(),
+            ))
+            .id();
+        let focused = app
+            .world_mut()
+            .spawn((
+                Interaction::None,
+                Focusable {
+                    focused: true,
+                    focus_visible: true,
+                    ..Focusable::new()
+                },
+                TooltipTrigger::new("Help").rich(),
+                rich,
+            ))
+            .id();
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(200));
+            app.update();
+        };
+        step(&mut app);
+        let tooltip_of = |app: &App, trigger: Entity| {
+            app.world()
+                .get::<TooltipTrigger>(trigger)
+                .unwrap()
+                .tooltip_entity
+                .expect("rich tooltip is showing")
+        };
+        let shown = [tooltip_of(&app, hovered), tooltip_of(&app, focused)];
+        for tooltip in shown {
+            assert!(app.world().get::<RichTooltip>(tooltip).is_some());
+        }
+
+        // The pointer leaves one anchor and keyboard focus leaves the other.
+        *app.world_mut().get_mut::<Interaction>(hovered).unwrap() = Interaction::None;
+        app.world_mut()
+            .get_mut::<Focusable>(focused)
+            .unwrap()
+            .focused = false;
+        step(&mut app);
+        step(&mut app);
+
+        for (trigger, tooltip) in [hovered, focused].into_iter().zip(shown) {
+            assert_eq!(tooltip_of(&app, trigger), tooltip);
+            assert_ne!(
+                app.world().get::<Tooltip>(tooltip).unwrap().animation_state,
+                TooltipAnimationState::Exiting
+            );
+        }
+    }
 }
@@ -2,7 +2,150 @@
 //!
 //! Reference: <https://m3.material.io/styles/typography/overview>
 
+use std::collections::HashMap;
+
 use bevy::prelude::*;
+use bevy::text::LineHeight;
+
+/// A role in the MD3 type scale
+///
+/// Added to a text entity, the role keeps its `TextFont` in sync with the
+/// [`Typography`] resource.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeRole {
+    DisplayLarge,
+    DisplayMedium,
+    DisplaySmall,
+    HeadlineLarge,
+    HeadlineMedium,
+    HeadlineSmall,
+    TitleLarge,
+    TitleMedium,
+    TitleSmall,
+    LabelLarge,
+    LabelMedium,
+    LabelSmall,
+    BodyLarge,
+    BodyMedium,
+    BodySmall,
+}
+
+impl TypeRole {
+    /// All roles, largest first
+    pub const ALL: [TypeRole; 15] = [
+        TypeRole::DisplayLarge,
+        TypeRole::DisplayMedium,
+        TypeRole::DisplaySmall,
+        TypeRole::HeadlineLarge,
+        TypeRole::HeadlineMedium,
+        TypeRole::HeadlineSmall,
+        TypeRole::TitleLarge,
+        TypeRole::TitleMedium,
+        TypeRole::TitleSmall,
+        TypeRole::LabelLarge,
+        TypeRole::LabelMedium,
+        TypeRole::LabelSmall,
+        TypeRole::BodyLarge,
+        TypeRole::BodyMedium,
+        TypeRole::BodySmall,
+    ];
+
+    /// MD3 default font size in pixels
+    pub fn default_size(&self) -> f32 {
+        match self {
+            TypeRole::DisplayLarge => 57.0,
+            TypeRole::DisplayMedium => 45.0,
+            TypeRole::DisplaySmall => 36.0,
+            TypeRole::HeadlineLarge => 32.0,
+            TypeRole::HeadlineMedium => 28.0,
+            TypeRole::HeadlineSmall => 24.0,
+            TypeRole::TitleLarge => 22.0,
+            TypeRole::TitleMedium => 16.0,
+            TypeRole::TitleSmall => 14.0,
+            TypeRole::LabelLarge => 14.0,
+            TypeRole::LabelMedium => 12.0,
+            TypeRole::LabelSmall => 11.0,
+            TypeRole::BodyLarge => 16.0,
+            TypeRole::BodyMedium => 14.0,
+            TypeRole::BodySmall => 12.0,
+        }
+    }
+
+    /// MD3 default line height in pixels
+    pub fn default_line_height(&self) -> f32 {
+        match self {
+            TypeRole::DisplayLarge => 64.0,
+            TypeRole::DisplayMedium => 52.0,
+            TypeRole::DisplaySmall => 44.0,
+            TypeRole::HeadlineLarge => 40.0,
+            TypeRole::HeadlineMedium => 36.0,
+            TypeRole::HeadlineSmall => 32.0,
+            TypeRole::TitleLarge => 28.0,
+            TypeRole::TitleMedium => 24.0,
+            TypeRole::TitleSmall => 20.0,
+            TypeRole::LabelLarge => 20.0,
+            TypeRole::LabelMedium => 16.0,
+            TypeRole::LabelSmall => 16.0,
+            TypeRole::BodyLarge => 24.0,
+            TypeRole::BodyMedium => 20.0,
+            TypeRole::BodySmall => 16.0,
+        }
+    }
+
+    /// MD3 default letter spacing in pixels
+    pub fn default_letter_spacing(&self) -> f32 {
+        match self {
+            TypeRole::DisplayLarge => -0.25,
+            TypeRole::TitleMedium => 0.15,
+            TypeRole::TitleSmall | TypeRole::LabelLarge => 0.1,
+            TypeRole::LabelMedium | TypeRole::LabelSmall | TypeRole::BodyLarge => 0.5,
+            TypeRole::BodyMedium => 0.25,
+            TypeRole::BodySmall => 0.4,
+            _ => 0.0,
+        }
+    }
+
+    /// `TextFont` for this role using the MD3 defaults
+    pub fn text_font(&self) -> TextFont {
+        TextFont {
+            font_size: self.default_size(),
+            line_height: LineHeight::Px(self.default_line_height()),
+            ..default()
+        }
+    }
+
+    /// Role marker plus default `TextFont`, for spawning text that follows the
+    /// [`Typography`] resource
+    pub fn text_bundle(self) -> (TypeRole, TextFont) {
+        (self, self.text_font())
+    }
+}
+
+/// Per-role overrides on top of the type scale
+///
+/// `None` fields fall back to the [`Typography`] defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TypeStyleOverride {
+    /// Font handle for this role
+    pub font: Option<Handle<Font>>,
+    /// Line height in pixels
+    pub line_height: Option<f32>,
+    /// Letter spacing in pixels
+    pub letter_spacing: Option<f32>,
+}
+
+/// Fully resolved style for a single type role
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeStyle {
+    pub font: Handle<Font>,
+    pub size: f32,
+    pub line_height: f32,
+    /// Letter spacing in pixels.
+    ///
+    /// Bevy text has no tracking support yet, so this is not applied to
+    /// `TextFont` and is provided for custom text rendering.
+    pub letter_spacing: f32,
+}
 
 /// Typography scale resource containing font sizes for MD3 type scale
 #[derive(Resource, Debug, Clone)]
@@ -46,35 +189,43 @@ pub struct Typography {
     pub body_medium: f32,
     /// Body small: 12sp
     pub body_small: f32,
+
+    /// Font used by every role without a per-role font override
+    pub font: Handle<Font>,
+    /// Per-role font, line height, and letter spacing overrides
+    pub overrides: HashMap<TypeRole, TypeStyleOverride>,
 }
 
 impl Default for Typography {
     fn default() -> Self {
         Self {
             // Display
-            display_large: 57.0,
-            display_medium: 45.0,
-            display_small: 36.0,
+            display_large: TypeRole::DisplayLarge.default_size(),
+            display_medium: TypeRole::DisplayMedium.default_size(),
+            display_small: TypeRole::DisplaySmall.default_size(),
 
             // Headline
-            headline_large: 32.0,
-            headline_medium: 28.0,
-            headline_small: 24.0,
+            headline_large: TypeRole::HeadlineLarge.default_size(),
+            headline_medium: TypeRole::HeadlineMedium.default_size(),
+            headline_small: TypeRole::HeadlineSmall.default_size(),
 
             // Title
-            title_large: 22.0,
-            title_medium: 16.0,
-            title_small: 14.0,
+            title_large: TypeRole::TitleLarge.default_size(),
+            title_medium: TypeRole::TitleMedium.default_size(),
+            title_small: TypeRole::TitleSmall.default_size(),
 
             // Label
-            label_large: 14.0,
-            label_medium: 12.0,
-            label_small: 11.0,
+            label_large: TypeRole::LabelLarge.default_size(),
+            label_medium: TypeRole::LabelMedium.default_size(),
+            label_small: TypeRole::LabelSmall.default_size(),
 
             // Body
-            body_large: 16.0,
-            body_medium: 14.0,
-            body_small: 12.0,
+            body_large: TypeRole::BodyLarge.default_size(),
+            body_medium: TypeRole::BodyMedium.default_size(),
+            body_small: TypeRole::BodySmall.default_size(),
+
+            font: Handle::default(),
+            overrides: HashMap::new(),
         }
     }
 }
@@ -99,6 +250,184 @@ impl Typography {
             body_large: default.body_large * scale,
             body_medium: default.body_medium * scale,
             body_small: default.body_small * scale,
+            ..default
+        }
+    }
+
+    /// Font size for a role
+    pub fn size(&self, role: TypeRole) -> f32 {
+        match role {
+            TypeRole::DisplayLarge => self.display_large,
+            TypeRole::DisplayMedium => self.display_medium,
+            TypeRole::DisplaySmall => self.display_small,
+            TypeRole::HeadlineLarge => self.headline_large,
+            TypeRole::HeadlineMedium => self.headline_medium,
+            TypeRole::HeadlineSmall => self.headline_small,
+            TypeRole::TitleLarge => self.title_large,
+            TypeRole::TitleMedium => self.title_medium,
+            TypeRole::TitleSmall => self.title_small,
+            TypeRole::LabelLarge => self.label_large,
+            TypeRole::LabelMedium => self.label_medium,
+            TypeRole::LabelSmall => self.label_small,
+            TypeRole::BodyLarge => self.body_large,
+            TypeRole::BodyMedium => self.body_medium,
+            TypeRole::BodySmall => self.body_small,
+        }
+    }
+
+    /// Set the font size for a role
+    pub fn set_size(&mut self, role: TypeRole, size: f32) {
+        let field = match role {
+            TypeRole::DisplayLarge => &mut self.display_large,
+            TypeRole::DisplayMedium => &mut self.display_medium,
+            TypeRole::DisplaySmall => &mut self.display_small,
+            TypeRole::HeadlineLarge => &mut self.headline_large,
+            TypeRole::HeadlineMedium => &mut self.headline_medium,
+            TypeRole::HeadlineSmall => &mut self.headline_small,
+            TypeRole::TitleLarge => &mut self.title_large,
+            TypeRole::TitleMedium => &mut self.title_medium,
+            TypeRole::TitleSmall => &mut self.title_small,
+            TypeRole::LabelLarge => &mut self.label_large,
+            TypeRole::LabelMedium => &mut self.label_medium,
+            TypeRole::LabelSmall => &mut self.label_small,
+            TypeRole::BodyLarge => &mut self.body_large,
+            TypeRole::BodyMedium => &mut self.body_medium,
+            TypeRole::BodySmall => &mut self.body_small,
+        };
+        *field = size;
+    }
+
+    /// Use a font for every role without its own font override
+    pub fn with_font(mut self, font: Handle<Font>) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Replace all overrides for a role
+    pub fn with_role(mut self, role: TypeRole, style: TypeStyleOverride) -> Self {
+        self.overrides.insert(role, style);
+        self
+    }
+
+    /// Override the font for a role
+    pub fn with_role_font(mut self, role: TypeRole, font: Handle<Font>) -> Self {
+        self.overrides.entry(role).or_default().font = Some(font);
+        self
+    }
+
+    /// Override the font size for a role
+    pub fn with_role_size(mut self, role: TypeRole, size: f32) -> Self {
+        self.set_size(role, size);
+        self
+    }
+
+    /// Override the line height (in pixels) for a role
+    pub fn with_role_line_height(mut self, role: TypeRole, line_height: f32) -> Self {
+        self.overrides.entry(role).or_default().line_height = Some(line_height);
+        self
+    }
+
+    /// Override the letter spacing (in pixels) for a role
+    pub fn with_role_letter_spacing(mut self, role: TypeRole, letter_spacing: f32) -> Self {
+        self.overrides.entry(role).or_default().letter_spacing = Some(letter_spacing);
+        self
+    }
+
+    /// Resolve the style for a role, applying any overrides
+    ///
+    /// Without a line height override, the MD3 line height is scaled with the
+    /// role's font size so custom sizes keep the same proportions.
+    pub fn style(&self, role: TypeRole) -> TypeStyle {
+        let size = self.size(role);
+        let overrides = self.overrides.get(&role);
+        let default_size = role.default_size();
+
+        TypeStyle {
+            font: overrides
+                .and_then(|o| o.font.clone())
+                .unwrap_or_else(|| self.font.clone()),
+            size,
+            line_height: overrides
+                .and_then(|o| o.line_height)
+                .unwrap_or_else(|| role.default_line_height() * size / default_size),
+            letter_spacing: overrides
+                .and_then(|o| o.letter_spacing)
+                .unwrap_or_else(|| role.default_letter_spacing()),
         }
     }
+
+    /// Build a `TextFont` for a role
+    pub fn text_font(&self, role: TypeRole) -> TextFont {
+        let style = self.style(role);
+        TextFont {
+            font: style.font,
+            font_size: style.size,
+            line_height: LineHeight::Px(style.line_height),
+            ..default()
+        }
+    }
+}
+
+/// Keep text tagged with a [`TypeRole`] in sync with the [`Typography`] resource.
+pub(crate) fn typography_text_system(
+    typography: Option<Res<Typography>>,
+    mut texts: Query<(Ref<TypeRole>, &mut TextFont)>,
+) {
+    let Some(typography) = typography else {
+        return;
+    };
+    let typography_changed = typography.is_changed();
+
+    for (role, mut text_font) in texts.iter_mut() {
+        if !typography_changed && !role.is_changed() {
+            continue;
+        }
+        *text_font = typography.text_font(*role);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_text_font_matches_scale() {
+        let typography = Typography::default();
+        let font = typography.text_font(TypeRole::BodyLarge);
+        assert_eq!(font.font_size, 16.0);
+        assert!(matches!(font.line_height, LineHeight::Px(h) if h == 24.0));
+    }
+
+    #[test]
+    fn test_role_text_font_matches_default_typography() {
+        let typography = Typography::default();
+        for role in TypeRole::ALL {
+            let expected = typography.text_font(role);
+            let font = role.text_font();
+            assert_eq!(font.font_size, expected.font_size, "{role:?}");
+            assert_eq!(font.line_height, expected.line_height, "{role:?}");
+        }
+    }
+
+    #[test]
+    fn test_role_overrides() {
+        let font = Handle::<Font>::default();
+        let typography = Typography::default()
+            .with_role_font(TypeRole::DisplayLarge, font.clone())
+            .with_role_size(TypeRole::DisplayLarge, 114.0)
+            .with_role_letter_spacing(TypeRole::LabelLarge, 1.0);
+
+        let display = typography.style(TypeRole::DisplayLarge);
+        assert_eq!(display.font, font);
+        assert_eq!(display.size, 114.0);
+        // Line height scales with size when not overridden
+        assert!((display.line_height - 128.0).abs() < 0.001);
+
+        let label = typography.style(TypeRole::LabelLarge);
+        assert_eq!(label.letter_spacing, 1.0);
+        assert_eq!(label.size, 14.0);
+
+        let typography = typography.with_role_line_height(TypeRole::LabelLarge, 18.0);
+        assert_eq!(typography.style(TypeRole::LabelLarge).line_height, 18.0);
+    }
 }
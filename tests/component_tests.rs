@@ -1,12 +1,12 @@
 //! Integration tests for new MD3 components
 //!
-//! Tests for Motion, Snackbar, Chip, App Bar, Badge, and Tooltip components.
+//! Tests for Motion, Snackbar, Chip, App Bar, Badge, Tooltip, and Select components.
 
 use bevy_material_ui::app_bar::TopAppBar;
 use bevy_material_ui::badge::MaterialBadge;
 use bevy_material_ui::chip::MaterialChip;
 use bevy_material_ui::prelude::*;
-use bevy_material_ui::snackbar::{ShowSnackbar, SnackbarPosition};
+use bevy_material_ui::snackbar::{ShowSnackbar, SnackbarPosition, SnackbarPriority};
 
 // ============================================================================
 // Motion Tests
@@ -120,6 +120,10 @@ mod snackbar_tests {
             duration: Some(5.0),
             dismissible: true,
             position: SnackbarPosition::BottomCenter,
+            icon: None,
+            multiline: false,
+            priority: SnackbarPriority::High,
+            dedup_key: None,
         };
 
         assert_eq!(event.message, "Test message");
@@ -398,7 +402,7 @@ mod tooltip_tests {
         let trigger = TooltipTrigger::new("Help text");
         assert_eq!(trigger.text, "Help text");
         assert_eq!(trigger.position, TooltipPosition::Top); // Default
-        assert!((trigger.delay - TOOLTIP_DELAY_DEFAULT).abs() < 0.001);
+        assert_eq!(trigger.delay, None);
     }
 
     #[test]
@@ -441,7 +445,9 @@ mod tooltip_tests {
 
         assert_eq!(rich.title, Some("Title".to_string()));
         assert_eq!(rich.supporting_text, "Description text");
-        assert_eq!(rich.action, Some("Learn more".to_string()));
+        assert_eq!(rich.actions.len(), 1);
+        assert_eq!(rich.actions[0].label, "Learn more");
+        assert!(rich.is_persistent());
     }
 
     #[test]
@@ -466,6 +472,115 @@ mod tooltip_tests {
     }
 }
 
+// ============================================================================
+// Select Tests
+// ============================================================================
+
+mod select_tests {
+    use super::*;
+    use bevy::math::Vec2;
+
+    const VIEWPORT: Vec2 = Vec2::new(800.0, 600.0);
+
+    #[test]
+    fn test_dropdown_opens_down_when_it_fits() {
+        let field = ElementBounds::new("field", 100.0, 50.0, 200.0, SELECT_HEIGHT);
+        let (direction, max_height) =
+            resolve_select_dropdown_placement(&field, 3.0 * SELECT_OPTION_HEIGHT, VIEWPORT);
+
+        assert_eq!(direction, SelectDropdownDirection::Down);
+        assert_eq!(max_height, 3.0 * SELECT_OPTION_HEIGHT);
+    }
+
+    #[test]
+    fn test_dropdown_flips_up_near_bottom() {
+        let field = ElementBounds::new("field", 100.0, 500.0, 200.0, SELECT_HEIGHT);
+        let (direction, _) =
+            resolve_select_dropdown_placement(&field, 4.0 * SELECT_OPTION_HEIGHT, VIEWPORT);
+
+        assert_eq!(direction, SelectDropdownDirection::Up);
+    }
+
+    #[test]
+    fn test_dropdown_height_clamped_to_space() {
+        // Long list in a short window: open on the roomier side and scroll.
+        let field = ElementBounds::new("field", 100.0, 150.0, 200.0, SELECT_HEIGHT);
+        let viewport = Vec2::new(800.0, 300.0);
+        let (direction, max_height) =
+            resolve_select_dropdown_placement(&field, 20.0 * SELECT_OPTION_HEIGHT, viewport);
+
+        assert_eq!(direction, SelectDropdownDirection::Up);
+        assert!(max_height < 150.0);
+        assert!(max_height >= SELECT_OPTION_HEIGHT);
+    }
+}
+
+// ============================================================================
+// Menu Tests
+// ============================================================================
+
+mod menu_tests {
+    use super::*;
+    use bevy::math::Vec2;
+
+    #[test]
+    fn test_checkable_menu_item_icons() {
+        let mut item = MaterialMenuItem::new("Show grid").checkable(true);
+        assert_eq!(item.toggle, Some(MenuItemToggle::Checkbox));
+        assert_eq!(item.toggle_icon_name(), Some("check_box"));
+        item.checked = false;
+        assert_eq!(item.toggle_icon_name(), Some("check_box_outline_blank"));
+
+        let radio = MaterialMenuItem::new("By name").radio("sort").checked(true);
+        assert_eq!(radio.toggle, Some(MenuItemToggle::Radio("sort".into())));
+        assert_eq!(radio.toggle_icon_name(), Some("radio_button_checked"));
+
+        assert_eq!(MaterialMenuItem::new("Refresh").toggle_icon_name(), None);
+    }
+
+    const VIEWPORT: Vec2 = Vec2::new(800.0, 600.0);
+    const MENU: Vec2 = Vec2::new(200.0, 150.0);
+
+    #[test]
+    fn test_menu_opens_at_requested_corner() {
+        let trigger = ElementBounds::new("trigger", 100.0, 50.0, 120.0, 40.0);
+
+        let (anchor, position) =
+            resolve_menu_placement(MenuAnchor::BottomLeft, &trigger, MENU, VIEWPORT);
+        assert_eq!(anchor, MenuAnchor::BottomLeft);
+        assert_eq!(position, Vec2::new(100.0, 90.0));
+
+        // End-aligned: right edges line up.
+        let (anchor, position) =
+            resolve_menu_placement(MenuAnchor::BottomRight, &trigger, MENU, VIEWPORT);
+        assert_eq!(anchor, MenuAnchor::BottomRight);
+        assert_eq!(position, Vec2::new(20.0, 90.0));
+    }
+
+    #[test]
+    fn test_menu_flips_near_edges() {
+        // Bottom-right corner of the window: open above, align right edges.
+        let trigger = ElementBounds::new("trigger", 700.0, 540.0, 80.0, 40.0);
+        let (anchor, position) =
+            resolve_menu_placement(MenuAnchor::BottomLeft, &trigger, MENU, VIEWPORT);
+
+        assert_eq!(anchor, MenuAnchor::TopRight);
+        assert_eq!(position, Vec2::new(580.0, 390.0));
+    }
+
+    #[test]
+    fn test_menu_clamped_when_neither_side_fits() {
+        let trigger = ElementBounds::new("trigger", 10.0, 100.0, 40.0, 40.0);
+        let viewport = Vec2::new(800.0, 200.0);
+        let (anchor, position) =
+            resolve_menu_placement(MenuAnchor::TopLeft, &trigger, MENU, viewport);
+
+        // Keeps the preferred side and is pushed back on screen.
+        assert_eq!(anchor, MenuAnchor::TopLeft);
+        assert_eq!(position, Vec2::new(10.0, 0.0));
+    }
+}
+
 // ============================================================================
 // Integration Tests
 // ============================================================================
@@ -25,6 +25,14 @@ fn test_material_icon_from_name_matches_lookup() {
     assert_eq!(icon.id, id);
 }
 
+#[test]
+fn test_material_icon_named() {
+    let icon = MaterialIcon::named(ICON_HOME).expect("home icon should exist");
+    assert_eq!(Some(icon.id), icon_by_name(ICON_HOME));
+
+    assert!(MaterialIcon::named("definitely_not_an_icon").is_none());
+}
+
 #[test]
 fn test_icon_pixel_blob_has_expected_size() {
     let (name, id) = material_icons::ALL[0];
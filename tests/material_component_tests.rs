@@ -19,6 +19,7 @@ use bevy_material_ui::checkbox::{CheckboxState, MaterialCheckbox};
 use bevy_material_ui::chip::{ChipElevation, ChipVariant, MaterialChip};
 use bevy_material_ui::dialog::{DialogType, MaterialDialog};
 use bevy_material_ui::fab::{FabColor, FabSize, MaterialFab};
+use bevy_material_ui::locale::{DateFieldOrder, DateInputPattern};
 use bevy_material_ui::progress::{MaterialCircularProgress, MaterialLinearProgress, ProgressMode};
 use bevy_material_ui::radio::MaterialRadio;
 use bevy_material_ui::slider::{MaterialSlider, SliderVariant, TickVisibility};
@@ -608,6 +609,8 @@ mod text_field_tests {
             InputType::Phone,
             InputType::Url,
             InputType::Multiline,
+            InputType::Date,
+            InputType::Time,
         ];
 
         for input_type in types {
@@ -638,6 +641,112 @@ mod text_field_tests {
         let field = MaterialTextField::new().box_stroke_width(2.0);
         assert_eq!(field.box_stroke_width, 2.0);
     }
+
+    fn type_into(field: &mut MaterialTextField, text: &str) {
+        for ch in text.chars() {
+            field.push_input_char(ch);
+        }
+    }
+
+    #[test]
+    fn test_paste_fits_max_length_at_caret() {
+        let mut field = MaterialTextField::new().with_value("ab").max_length(5);
+        field.set_caret(1);
+
+        assert!(field.paste_text("0123456789"));
+        assert_eq!(field.value, "a012b");
+        assert_eq!(field.caret_index(), 4);
+
+        // Full: nothing more fits.
+        assert!(!field.paste_text("xyz"));
+        assert_eq!(field.value, "a012b");
+    }
+
+    #[test]
+    fn test_paste_skips_disallowed_chars() {
+        let mut field = MaterialTextField::new()
+            .input_type(InputType::Number)
+            .max_length(4);
+        assert!(field.paste_text("-1a2\nb3c4d5"));
+        assert_eq!(field.value, "-123");
+
+        let mut field = MaterialTextField::new();
+        field.paste_text("one\r\ntwo");
+        assert_eq!(field.value, "one  two");
+
+        let mut field = MaterialTextField::new().input_type(InputType::Multiline);
+        field.paste_text("one\r\ntwo");
+        assert_eq!(field.value, "one\ntwo");
+    }
+
+    #[test]
+    fn test_typing_and_backspace_at_caret() {
+        let mut field = MaterialTextField::new().with_value("héllo");
+        field.set_caret(2);
+        type_into(&mut field, "X");
+        assert_eq!(field.value, "héXllo");
+
+        field.pop_input_char();
+        field.pop_input_char();
+        assert_eq!(field.value, "hllo");
+        assert_eq!(field.caret_index(), 1);
+
+        // Moving to the end goes back to appending.
+        field.set_caret(field.value.chars().count());
+        assert_eq!(field.caret, None);
+        type_into(&mut field, "!");
+        assert_eq!(field.value, "hllo!");
+    }
+
+    #[test]
+    fn test_date_input_mask() {
+        let mut field = MaterialTextField::new().input_type(InputType::Date);
+        assert_eq!(field.input_mask().as_deref(), Some("##/##/####"));
+
+        type_into(&mut field, "1a2");
+        assert_eq!(field.value, "12/");
+        // Separators are only accepted where the mask has one.
+        type_into(&mut field, "/0/");
+        assert_eq!(field.value, "12/0");
+        field.value = "12".to_string();
+        type_into(&mut field, "/05");
+        assert_eq!(field.value, "12/05/");
+        type_into(&mut field, "2024999");
+        assert_eq!(field.value, "12/05/2024");
+        assert!(field.is_input_complete());
+        assert!(field.is_input_valid());
+
+        // Backspace drops a separator left at the end.
+        field.value = "12/0".to_string();
+        field.pop_input_char();
+        assert_eq!(field.value, "12");
+
+        field.value = "13/45/2024".to_string();
+        assert!(!field.is_input_valid());
+    }
+
+    #[test]
+    fn test_date_input_follows_pattern() {
+        let mut field = MaterialTextField::new()
+            .input_type(InputType::Date)
+            .date_pattern(DateInputPattern::new(DateFieldOrder::Ymd, '-'));
+        type_into(&mut field, "20240229");
+        assert_eq!(field.value, "2024-02-29");
+        assert!(field.is_input_valid());
+    }
+
+    #[test]
+    fn test_time_input_mask() {
+        let mut field = MaterialTextField::new().input_type(InputType::Time);
+        type_into(&mut field, "0930");
+        assert_eq!(field.value, "09:30");
+        assert!(field.is_input_valid());
+
+        field.value.clear();
+        type_into(&mut field, "2460");
+        assert_eq!(field.value, "24:60");
+        assert!(!field.is_input_valid());
+    }
 }
 
 // ============================================================================
@@ -822,7 +931,7 @@ mod tooltip_tests {
     #[test]
     fn test_delay() {
         let trigger = TooltipTrigger::new("Help").with_delay(1.0);
-        assert_eq!(trigger.delay, 1.0);
+        assert_eq!(trigger.delay, Some(1.0));
     }
 
     #[test]
@@ -1025,6 +1134,41 @@ mod progress_tests {
         assert!(progress.four_color);
     }
 
+    #[test]
+    fn test_linear_track_gap_defaults_off() {
+        let progress = MaterialLinearProgress::new();
+        assert!(!progress.has_track_gap());
+        assert!(!progress.rounded_caps);
+
+        let styled = MaterialLinearProgress::new()
+            .with_gap(4.0)
+            .with_rounded_caps(true);
+        assert!(styled.has_track_gap());
+        assert!(styled.rounded_caps);
+    }
+
+    #[test]
+    fn test_linear_indicator_span() {
+        let progress = MaterialLinearProgress::new().with_progress(0.4);
+        assert_eq!(progress.indicator_span(), (0.0, 40.0));
+
+        // The indeterminate segment is clipped to the track at either end.
+        let mut progress = MaterialLinearProgress::new().indeterminate();
+        progress.animation_progress = 0.0;
+        assert_eq!(progress.indicator_span(), (0.0, 0.0));
+        progress.animation_progress = 1.0;
+        assert_eq!(progress.indicator_span(), (100.0, 100.0));
+    }
+
+    #[test]
+    fn test_circular_gap_angle() {
+        let progress = MaterialCircularProgress::new().with_size(48.0);
+        assert_eq!(progress.gap_angle(), 0.0);
+
+        let progress = progress.with_gap(4.0);
+        assert!((progress.gap_angle() - 4.0 / 22.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_circular_default_determinate() {
         let progress = MaterialCircularProgress::new();
@@ -0,0 +1,125 @@
+//! Plugin composition tests
+//!
+//! These tests verify that every component plugin can be added on its own on
+//! top of `MaterialUiCorePlugin`, without pulling in `MaterialUiPlugin`.
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_material_ui::date_picker::DatePickerPlugin;
+use bevy_material_ui::datetime_picker::DateTimePickerPlugin;
+use bevy_material_ui::prelude::*;
+use bevy_material_ui::time_picker::TimePickerPlugin;
+use bevy_material_ui::{adaptive, layout};
+
+/// A headless app with just the core plugin added
+fn core_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        // The loading indicator embeds its shader; headless apps have no render plugin.
+        .init_asset::<Shader>()
+        .add_plugins(MaterialUiCorePlugin);
+    app
+}
+
+fn assert_builds_on_core<P: Plugin>(plugin: P) {
+    let mut app = core_app();
+    app.add_plugins(plugin);
+    app.finish();
+    app.cleanup();
+
+    assert!(app.is_plugin_added::<MaterialUiCorePlugin>());
+    assert!(app.is_plugin_added::<P>());
+    assert!(app.world().contains_resource::<MaterialTheme>());
+}
+
+#[test]
+fn test_core_plugin_alone() {
+    let mut app = core_app();
+    app.finish();
+    app.cleanup();
+
+    assert!(app.world().contains_resource::<MaterialTheme>());
+    assert!(app.world().contains_resource::<OverlayStack>());
+}
+
+#[test]
+fn test_component_plugins_build_on_core() {
+    assert_builds_on_core(ButtonPlugin);
+    assert_builds_on_core(IconButtonPlugin);
+    assert_builds_on_core(FabPlugin);
+    assert_builds_on_core(CardPlugin);
+    assert_builds_on_core(CheckboxPlugin);
+    assert_builds_on_core(RadioPlugin);
+    assert_builds_on_core(SwitchPlugin);
+    assert_builds_on_core(SliderPlugin);
+    assert_builds_on_core(TextFieldPlugin);
+    assert_builds_on_core(ProgressPlugin);
+    assert_builds_on_core(DialogPlugin);
+    assert_builds_on_core(ListPlugin);
+    assert_builds_on_core(MenuPlugin);
+    assert_builds_on_core(TabsPlugin);
+    assert_builds_on_core(SelectPlugin);
+    assert_builds_on_core(ButtonGroupPlugin);
+    assert_builds_on_core(SnackbarPlugin);
+    assert_builds_on_core(ChipPlugin);
+    assert_builds_on_core(AppBarPlugin);
+    assert_builds_on_core(BadgePlugin);
+    assert_builds_on_core(TooltipPlugin);
+    assert_builds_on_core(ScrollPlugin);
+    assert_builds_on_core(DatePickerPlugin);
+    assert_builds_on_core(TimePickerPlugin);
+    assert_builds_on_core(DateTimePickerPlugin);
+    assert_builds_on_core(AutocompletePlugin);
+    assert_builds_on_core(SearchPlugin);
+    assert_builds_on_core(ToolbarPlugin);
+    assert_builds_on_core(LoadingIndicatorPlugin);
+}
+
+#[test]
+fn test_layout_plugins_build_on_core() {
+    assert_builds_on_core(adaptive::WindowSizeClassPlugin);
+    assert_builds_on_core(layout::NavigationSuitePlugin);
+    assert_builds_on_core(layout::ListDetailPlugin);
+    assert_builds_on_core(layout::NavigationRailPlugin);
+    assert_builds_on_core(layout::ScaffoldInsetPlugin);
+}
+
+#[cfg(feature = "theme_hot_reload")]
+#[test]
+fn test_theme_hot_reload_plugin_builds_on_core() {
+    assert_builds_on_core(ThemeHotReloadPlugin::new("themes/test.mui_theme"));
+}
+
+#[test]
+fn test_partial_plugin_set() {
+    let mut app = core_app();
+    app.add_plugins((TextFieldPlugin, DialogPlugin));
+    app.finish();
+    app.cleanup();
+
+    assert!(app.is_plugin_added::<TextFieldPlugin>());
+    assert!(app.is_plugin_added::<DialogPlugin>());
+    assert!(!app.is_plugin_added::<ButtonPlugin>());
+}
+
+#[test]
+fn test_dependencies_added_before_dependents() {
+    let mut app = core_app();
+    app.add_plugins((TextFieldPlugin, MenuPlugin, AutocompletePlugin));
+    app.add_plugins((DatePickerPlugin, TimePickerPlugin, DateTimePickerPlugin));
+    app.finish();
+    app.cleanup();
+
+    assert!(app.is_plugin_added::<AutocompletePlugin>());
+    assert!(app.is_plugin_added::<DateTimePickerPlugin>());
+}
+
+#[test]
+fn test_full_plugin_after_core() {
+    let mut app = core_app();
+    app.add_plugins(MaterialUiPlugin::default());
+    app.finish();
+    app.cleanup();
+
+    assert!(app.is_plugin_added::<MaterialUiPlugin>());
+}